@@ -0,0 +1,208 @@
+//! HWP 차트 OLE → ChartObject 변환
+//!
+//! 한글 차트는 OLE 스토리지(BinData/BINxxxx.OLE)로 저장되며,
+//! 스토리지 안의 `OOXMLChartContents` 스트림에 OOXML `c:chartSpace`가 들어 있다.
+//! 이 스트림을 HWPX와 같은 파서로 읽어 Ole 개체를 Chart 개체로 바꾼다.
+
+use cfb::CompoundFile;
+use hwp_model::chart::Chart;
+use hwp_model::document::{BinaryStore, Document};
+use hwp_model::query::{ContentPath, VisitorMut};
+use hwp_model::shape::{ChartObject, ShapeObject};
+use std::io::{Cursor, Read};
+
+/// 문서 전체(바탕쪽, 모든 하위 목록, 묶음 개체 포함)의 차트 OLE 개체를 ChartObject로 치환
+pub(crate) fn resolve_ole_charts(document: &mut Document) {
+    if document.binaries.items.is_empty() {
+        return;
+    }
+    // walk_mut가 문서 전체를 빌리므로 바이너리 저장소는 잠시 꺼내 둔다
+    let binaries = std::mem::take(&mut document.binaries);
+    document.walk_mut(&mut OleCharts {
+        binaries: &binaries,
+    });
+    document.binaries = binaries;
+}
+
+struct OleCharts<'b> {
+    binaries: &'b BinaryStore,
+}
+
+impl VisitorMut for OleCharts<'_> {
+    fn visit_shape_mut(&mut self, _path: &ContentPath, shape: &mut ShapeObject) {
+        let ShapeObject::Ole(ole) = shape else {
+            return;
+        };
+        let chart = ole
+            .binary_item_id
+            .as_deref()
+            .and_then(|id| self.binaries.items.iter().find(|item| item.id == id))
+            .and_then(|item| chart_from_ole(&item.data));
+        if let Some(chart) = chart {
+            *shape = ShapeObject::Chart(Box::new(ChartObject {
+                common: ole.common.clone(),
+                component: ole.component.clone(),
+                chart_id_ref: None,
+                chart: Some(chart),
+            }));
+        }
+    }
+}
+
+/// OLE 스토리지 바이너리에서 차트 데이터 추출 (차트가 아니면 None)
+pub(crate) fn chart_from_ole(data: &[u8]) -> Option<Chart> {
+    let storage = crate::ole::ole_storage_bytes(data)?;
+    let mut cfb = CompoundFile::open(Cursor::new(storage)).ok()?;
+    let path = cfb
+        .walk()
+        .find(|entry| entry.is_stream() && entry.name().eq_ignore_ascii_case("OOXMLChartContents"))?
+        .path()
+        .to_path_buf();
+    let mut contents = Vec::new();
+    cfb.open_stream(path)
+        .ok()?
+        .read_to_end(&mut contents)
+        .ok()?;
+    let xml = chart_xml(contents)?;
+    hwpx_parser::chart::parse_chart_xml(&xml).ok()
+}
+
+/// 스트림 내용 → 차트 XML (XML 그대로이거나 차트 파트를 담은 ZIP)
+fn chart_xml(contents: Vec<u8>) -> Option<String> {
+    if !contents.starts_with(b"PK") {
+        let text = String::from_utf8(contents).ok()?;
+        return Some(text.trim_start_matches('\u{feff}').to_string());
+    }
+    let mut zip = zip::ZipArchive::new(Cursor::new(contents)).ok()?;
+    let name = zip
+        .file_names()
+        .filter(|name| name.contains("charts/chart") && name.ends_with(".xml"))
+        .min()?
+        .to_string();
+    let mut xml = String::new();
+    zip.by_name(&name).ok()?.read_to_string(&mut xml).ok()?;
+    Some(xml)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hwp_model::chart::ChartType;
+    use std::io::Write;
+
+    const CHART_XML: &str = r#"<c:chartSpace xmlns:c="c"><c:chart><c:plotArea><c:lineChart>
+        <c:ser><c:tx><c:v>매출</c:v></c:tx><c:val><c:numLit><c:pt idx="0"><c:v>1</c:v></c:pt><c:pt idx="1"><c:v>2</c:v></c:pt></c:numLit></c:val></c:ser>
+        </c:lineChart></c:plotArea></c:chart></c:chartSpace>"#;
+
+    fn ole_storage(prefix: bool) -> Vec<u8> {
+        let mut cfb = CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        cfb.create_stream("/Contents")
+            .unwrap()
+            .write_all(&[0; 4])
+            .unwrap();
+        cfb.create_stream("/OOXMLChartContents")
+            .unwrap()
+            .write_all(CHART_XML.as_bytes())
+            .unwrap();
+        cfb.flush().unwrap();
+        let data = cfb.into_inner().into_inner();
+        if prefix {
+            let mut prefixed = (data.len() as u32).to_le_bytes().to_vec();
+            prefixed.extend(data);
+            prefixed
+        } else {
+            data
+        }
+    }
+
+    #[test]
+    fn test_chart_from_ole_storage() {
+        for prefix in [false, true] {
+            let chart = chart_from_ole(&ole_storage(prefix)).unwrap();
+            assert_eq!(chart.chart_type, ChartType::Line);
+            assert_eq!(chart.series[0].name.as_deref(), Some("매출"));
+            assert_eq!(chart.series[0].values, vec![Some(1.0), Some(2.0)]);
+        }
+        assert!(chart_from_ole(b"not an ole storage").is_none());
+    }
+
+    #[test]
+    fn test_resolve_nested_ole_charts() {
+        use hwp_model::document::BinaryItem;
+        use hwp_model::paragraph::{Paragraph, Run, RunContent, SubList};
+        use hwp_model::section::{MasterPage, Section};
+        use hwp_model::shape::{ContainerObject, EllipseObject, OleObject};
+
+        let ole = || {
+            ShapeObject::Ole(Box::new(OleObject {
+                binary_item_id: Some("BIN0001".to_string()),
+                ..Default::default()
+            }))
+        };
+        let paragraph = |shape: ShapeObject| Paragraph {
+            runs: vec![Run {
+                contents: vec![RunContent::Object(shape)],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        // 타원 글상자 안, 묶음 개체 안, 바탕쪽 안
+        let ellipse = ShapeObject::Ellipse(Box::new(EllipseObject {
+            draw_text: Some(SubList {
+                paragraphs: vec![paragraph(ole())],
+                ..Default::default()
+            }),
+            ..Default::default()
+        }));
+        let group = ShapeObject::Container(Box::new(ContainerObject {
+            children: vec![ole()],
+            ..Default::default()
+        }));
+        let mut section = Section {
+            paragraphs: vec![paragraph(ellipse), paragraph(group)],
+            ..Default::default()
+        };
+        section.definition.master_pages.push(MasterPage {
+            content: SubList {
+                paragraphs: vec![paragraph(ole())],
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let mut document = Document {
+            sections: vec![section],
+            ..Default::default()
+        };
+        document.binaries.items.push(BinaryItem {
+            id: "BIN0001".to_string(),
+            src: "BinData/BIN0001.OLE".to_string(),
+            format: Default::default(),
+            data: ole_storage(true),
+        });
+
+        resolve_ole_charts(&mut document);
+
+        let mut shapes = Vec::new();
+        document.for_each_paragraph_list(&mut |paragraphs| {
+            for content in paragraphs
+                .iter()
+                .flat_map(|p| &p.runs)
+                .flat_map(|r| &r.contents)
+            {
+                if let RunContent::Object(shape) = content {
+                    shapes.push(shape);
+                }
+            }
+        });
+        let charts = shapes
+            .iter()
+            .flat_map(|shape| match shape {
+                ShapeObject::Container(c) => c.children.iter().collect(),
+                shape => vec![*shape],
+            })
+            .filter(|shape| matches!(shape, ShapeObject::Chart(_)))
+            .count();
+        assert_eq!(charts, 3);
+        assert_eq!(document.binaries.items.len(), 1);
+    }
+}
@@ -5,25 +5,155 @@
 //! 이 어댑터는 viewer가 Document 기준으로 리팩토링되면
 //! HWP/HWPX 양쪽에서 동일한 viewer를 사용할 수 있게 한다.
 
-use crate::document::HwpDocument;
+use crate::document::{HwpDocument, RawRecord};
+use crate::writer;
 use base64::{engine::general_purpose::STANDARD, Engine as _};
 use hwp_model::document::{
     BinaryItem, BinaryStore, Document, DocumentMeta, DocumentSettings, ImageFormat,
 };
+use hwp_model::hints::{HwpBinCompression, HwpDocumentHints, HwpRawRecord};
 
-mod resources;
+mod chart;
+pub(crate) mod resources;
 mod section;
+mod track_change;
 
 /// HwpDocument를 hwp-model Document로 변환
 pub fn to_document(hwp: &HwpDocument) -> Document {
-    Document {
+    let mut document = Document {
         meta: convert_meta(hwp),
         settings: convert_settings(hwp),
         resources: resources::convert_resources(&hwp.doc_info),
         sections: section::convert_sections(&hwp.body_text, &hwp.doc_info),
         binaries: convert_binaries(hwp),
+        hwp_hints: Some(convert_hints(hwp)),
         ..Default::default()
+    };
+    chart::resolve_ole_charts(&mut document);
+    track_change::resolve_range_tags(&mut document, &hwp.doc_info);
+    document
+}
+
+/// HWP writer가 원본을 복원하는 데 필요한 FileHeader/스크립트/미리보기 정보
+fn convert_hints(hwp: &HwpDocument) -> HwpDocumentHints {
+    let fh = &hwp.file_header;
+    let [major, minor, build, revision] = fh.version.to_be_bytes();
+    let props = hwp.doc_info.document_properties.as_ref();
+
+    HwpDocumentHints {
+        file_header_raw: file_header_raw(hwp),
+        version: (major, minor, build, revision),
+        compressed: fh.is_compressed(),
+        scripts: hwp
+            .scripts
+            .as_ref()
+            .and_then(|s| s.default_script.as_ref())
+            .map(|script| {
+                let mut raw = Vec::new();
+                for part in [
+                    &script.header,
+                    &script.source,
+                    &script.pre_source,
+                    &script.post_source,
+                ] {
+                    let units: Vec<u16> = part.encode_utf16().collect();
+                    raw.extend_from_slice(&(units.len() as u32).to_le_bytes());
+                    raw.extend(units.iter().flat_map(|u| u.to_le_bytes()));
+                }
+                raw.extend_from_slice(&u32::MAX.to_le_bytes());
+                raw
+            }),
+        preview_image: hwp
+            .preview_image
+            .as_ref()
+            .and_then(|p| STANDARD.decode(&p.data).ok()),
+        caret_list_id: props.map(|p| p.list_id),
+        caret_para_id: props.map(|p| p.paragraph_id),
+        caret_char_pos: props.map(|p| p.character_position),
+        extra_doc_info: hwp
+            .raw
+            .as_ref()
+            .map(|raw| extra_doc_info(&raw.doc_info))
+            .unwrap_or_default(),
+        extra_streams: hwp
+            .raw
+            .iter()
+            .flat_map(|raw| &raw.streams)
+            .filter(|stream| !writer::is_generated_stream(&stream.path))
+            .map(|stream| (stream.path.clone(), stream.data.clone()))
+            .collect(),
+        bin_compression: bin_compression(hwp),
+    }
+}
+
+/// BIN_DATA 레코드의 항목별 압축 방식 (convert_binaries와 같은 BinaryItem.id)
+fn bin_compression(hwp: &HwpDocument) -> Vec<(String, HwpBinCompression)> {
+    use crate::document::docinfo::bin_data::CompressionType;
+    use crate::document::docinfo::BinDataRecord;
+
+    hwp.doc_info
+        .bin_data
+        .iter()
+        .filter_map(|record| {
+            let (attributes, id) = match record {
+                BinDataRecord::Embedding {
+                    attributes,
+                    embedding,
+                } => (attributes, embedding.binary_data_id),
+                BinDataRecord::Storage {
+                    attributes,
+                    storage,
+                } => (attributes, storage.binary_data_id),
+                BinDataRecord::Link { .. } => return None,
+            };
+            let compression = match attributes.compression {
+                CompressionType::StorageDefault => HwpBinCompression::StorageDefault,
+                CompressionType::Compress => HwpBinCompression::Compress,
+                CompressionType::NoCompress => HwpBinCompression::NoCompress,
+            };
+            Some((format!("BIN{:04X}", id), compression))
+        })
+        .collect()
+}
+
+/// writer가 다시 만들지 않는 DocInfo 레코드 (하위 레코드 포함)
+fn extra_doc_info(records: &[RawRecord]) -> Vec<HwpRawRecord> {
+    let mut extra = Vec::new();
+    // 보관 중인 레코드의 레벨 (더 깊은 레벨은 그 하위 레코드)
+    let mut kept_level: Option<u16> = None;
+    for record in records {
+        let level = record.header.level;
+        if kept_level.is_some_and(|kept| level <= kept) {
+            kept_level = None;
+        }
+        if kept_level.is_none() && !writer::is_generated_tag(record.header.tag_id) {
+            kept_level = Some(level);
+        }
+        if kept_level.is_some() {
+            extra.push(HwpRawRecord {
+                tag_id: record.header.tag_id,
+                level,
+                data: record.data.clone(),
+            });
+        }
     }
+    extra
+}
+
+/// FileHeader 필드 → 256바이트 원본 (표 3)
+fn file_header_raw(hwp: &HwpDocument) -> Vec<u8> {
+    let fh = &hwp.file_header;
+    let mut raw = vec![0u8; 32];
+    let signature = fh.signature.as_bytes();
+    raw[..signature.len().min(32)].copy_from_slice(&signature[..signature.len().min(32)]);
+    raw.extend_from_slice(&fh.version.to_le_bytes());
+    raw.extend_from_slice(&fh.document_flags.to_le_bytes());
+    raw.extend_from_slice(&fh.license_flags.to_le_bytes());
+    raw.extend_from_slice(&fh.encrypt_version.to_le_bytes());
+    raw.push(fh.kogl_country);
+    raw.extend_from_slice(&fh.reserved);
+    raw.resize(256, 0);
+    raw
 }
 
 fn convert_meta(hwp: &HwpDocument) -> DocumentMeta {
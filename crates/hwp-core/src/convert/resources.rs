@@ -14,9 +14,36 @@ pub fn convert_resources(doc_info: &docinfo::DocInfo) -> hwp_model::resources::R
         para_shapes: convert_para_shapes(&doc_info.para_shapes),
         styles: convert_styles(&doc_info.styles),
         memo_shapes: Vec::new(),
+        track_changes: Vec::new(),
+        track_change_authors: convert_track_change_authors(&doc_info.track_change_authors),
     }
 }
 
+/// 변경 추적 작성자: 레코드 구조가 스펙에 없어 앞머리의 HWP 문자열(WORD 길이 + WCHAR)만 이름으로 읽는다.
+/// 변경 추적 항목(HWPTAG_TRACK_CHANGE)은 구조를 알 수 없어 옮기지 않는다.
+fn convert_track_change_authors(
+    authors: &[docinfo::TrackChangeAuthor],
+) -> Vec<hwp_model::resources::TrackChangeAuthor> {
+    authors
+        .iter()
+        .enumerate()
+        .map(|(i, author)| {
+            let raw = &author.raw_data;
+            let name = raw
+                .get(..2)
+                .map(|len| u16::from_le_bytes([len[0], len[1]]) as usize * 2)
+                .and_then(|len| raw.get(2..2 + len))
+                .and_then(|bytes| crate::types::decode_utf16le(bytes).ok())
+                .unwrap_or_default();
+            hwp_model::resources::TrackChangeAuthor {
+                id: i as u32,
+                name,
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
 fn convert_fonts(face_names: &[docinfo::FaceName]) -> hwp_model::resources::FontFaces {
     use hwp_model::resources::{Font, FontFaces, FontTypeInfo, SubstFont};
     use hwp_model::types::{FontCategory, FontType};
@@ -480,10 +507,11 @@ fn convert_fill_info(
             })
         }
         docinfo::border_fill::FillInfo::Gradient(g) => Some(FillBrush::Gradation {
+            // 표 30: 1 줄무늬형, 2 원형, 3 원뿔형, 4 사각형
             grad_type: match g.gradient_type {
-                1 => GradationType::Radial,
-                2 => GradationType::Conical,
-                3 => GradationType::Square,
+                2 => GradationType::Radial,
+                3 => GradationType::Conical,
+                4 => GradationType::Square,
                 _ => GradationType::Linear,
             },
             angle: g.angle as u16,
@@ -7,7 +7,7 @@ use crate::document::bodytext::ctrl_header::{self as ctrl_header, CtrlId};
 use crate::document::bodytext::{self, BodyText, ParaTextRun, ParagraphRecord};
 use crate::document::docinfo::DocInfo;
 use hwp_model::control::*;
-use hwp_model::hints::LineSegmentInfo;
+use hwp_model::hints::{HwpParagraphHints, HwpRawRecord, LineSegmentInfo};
 use hwp_model::paragraph::*;
 use hwp_model::section::Section;
 use hwp_model::shape::*;
@@ -15,12 +15,14 @@ use hwp_model::table as model_table;
 use hwp_model::types::*;
 
 pub fn convert_sections(body: &BodyText, _doc_info: &DocInfo) -> Vec<Section> {
+    let mut memo_number = 0u32;
     body.sections
         .iter()
         .map(|sec| {
             let outline_shape_id = extract_section_outline_id(&sec.paragraphs);
             let page_def = extract_page_def(&sec.paragraphs);
             let column_def = extract_column_def(&sec.paragraphs);
+            let page_border_fills = extract_page_border_fills(&sec.paragraphs);
             let mut section = Section {
                 paragraphs: sec.paragraphs.iter().flat_map(convert_paragraph).collect(),
                 ..Default::default()
@@ -34,11 +36,56 @@ pub fn convert_sections(body: &BodyText, _doc_info: &DocInfo) -> Vec<Section> {
             if let Some(cd) = column_def {
                 section.definition.columns = Some(cd);
             }
+            section.definition.page_border_fills = page_border_fills;
+            section.definition.master_pages =
+                extract_master_pages(&sec.paragraphs, &section.definition.page);
+            let mut unused: Vec<&bodytext::Memo> = sec.memos.iter().collect();
+            attach_memos(&mut section.paragraphs, &mut unused, &mut memo_number);
             section
         })
         .collect()
 }
 
+/// 구역 끝에 저장된 메모 내용을 본문의 MEMO 필드에 붙이고 문서 전체 순서로 번호를 매긴다.
+/// 메모 인덱스(`ID`)가 같은 메모를 찾고, 없으면 남은 메모를 순서대로 쓴다.
+fn attach_memos(paragraphs: &mut [Paragraph], unused: &mut Vec<&bodytext::Memo>, number: &mut u32) {
+    for content in paragraphs
+        .iter_mut()
+        .flat_map(|para| &mut para.runs)
+        .flat_map(|run| &mut run.contents)
+    {
+        if let RunContent::Control(Control::FieldBegin(field)) = content {
+            if field.field_type == FieldType::Memo {
+                let pos = field
+                    .parameter_str("ID")
+                    .and_then(|index| {
+                        unused.iter().position(|memo| {
+                            memo.memo_list
+                                .memo_index()
+                                .is_some_and(|i| i.to_string() == index)
+                        })
+                    })
+                    .or_else(|| (!unused.is_empty()).then_some(0));
+                if let Some(pos) = pos {
+                    let memo = unused.remove(pos);
+                    field.sub_list = Some(SubList {
+                        paragraphs: convert_hwp_paragraphs(&memo.paragraphs),
+                        ..Default::default()
+                    });
+                }
+                *number += 1;
+                field.parameters.push(FieldParameter::Integer {
+                    name: "Number".to_string(),
+                    value: *number as i64,
+                });
+            }
+        }
+        for list in content.paragraph_lists_mut() {
+            attach_memos(list, unused, number);
+        }
+    }
+}
+
 /// ParagraphRecord에서 PageDef 추출
 fn extract_page_def(paragraphs: &[bodytext::Paragraph]) -> Option<bodytext::PageDef> {
     for para in paragraphs {
@@ -59,6 +106,111 @@ fn extract_page_def(paragraphs: &[bodytext::Paragraph]) -> Option<bodytext::Page
     None
 }
 
+/// 구역 정의의 쪽 테두리/배경 추출 (양쪽, 짝수쪽, 홀수쪽 순서)
+fn extract_page_border_fills(
+    paragraphs: &[bodytext::Paragraph],
+) -> Vec<hwp_model::section::PageBorderFill> {
+    use bodytext::page_border_fill::{FillArea as HwpFillArea, PositionReference};
+    use hwp_model::section::{FillArea, PageBorderFill, PageBorderRef};
+
+    let convert = |pbf: &bodytext::PageBorderFill| PageBorderFill {
+        border_fill_id: pbf.border_fill_id,
+        text_border: match pbf.attributes.position_reference {
+            PositionReference::Paper => PageBorderRef::Paper,
+            PositionReference::BodyText => PageBorderRef::Text,
+        },
+        header_inside: pbf.attributes.include_header,
+        footer_inside: pbf.attributes.include_footer,
+        fill_area: match pbf.attributes.fill_area {
+            HwpFillArea::Paper => FillArea::Paper,
+            HwpFillArea::Page => FillArea::Text,
+            HwpFillArea::Border => FillArea::PaperLine,
+        },
+        offset: Margin {
+            left: pbf.left_spacing as HwpUnit,
+            right: pbf.right_spacing as HwpUnit,
+            top: pbf.top_spacing as HwpUnit,
+            bottom: pbf.bottom_spacing as HwpUnit,
+        },
+    };
+
+    for para in paragraphs {
+        for record in &para.records {
+            if let ParagraphRecord::CtrlHeader { children, .. } = record {
+                let fills: Vec<PageBorderFill> = children
+                    .iter()
+                    .filter_map(|child| match child {
+                        ParagraphRecord::PageBorderFill { page_border_fill } => {
+                            Some(convert(page_border_fill))
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                if !fills.is_empty() {
+                    return fills;
+                }
+            }
+        }
+    }
+    Vec::new()
+}
+
+/// 구역 정의의 바탕쪽 추출
+///
+/// 구역 정의 컨트롤의 쪽 테두리/배경 뒤에 오는 문단 리스트 헤더가 바탕쪽이며,
+/// 적용 쪽은 레코드에 없으므로 쪽 테두리/배경과 같은 양쪽, 짝수쪽, 홀수쪽 순서로 본다.
+/// 글 영역 크기도 저장되지 않으므로 용지에서 여백을 뺀 본문 크기를 사용한다.
+fn extract_master_pages(
+    paragraphs: &[bodytext::Paragraph],
+    page: &hwp_model::section::PageDef,
+) -> Vec<hwp_model::section::MasterPage> {
+    use hwp_model::section::MasterPage;
+
+    let is_section_def = |record: &ParagraphRecord| {
+        matches!(record, ParagraphRecord::CtrlHeader { header, .. }
+            if matches!(header.data, ctrl_header::CtrlHeaderData::SectionDefinition { .. }))
+    };
+    let Some(ParagraphRecord::CtrlHeader { children, .. }) = paragraphs
+        .iter()
+        .flat_map(|p| &p.records)
+        .find(|r| is_section_def(r))
+    else {
+        return Vec::new();
+    };
+
+    let m = &page.margin;
+    let (width, height) = match page.landscape {
+        Landscape::Landscape => (page.height, page.width),
+        _ => (page.width, page.height),
+    };
+    let text_width = width - m.left - m.right - m.gutter;
+    let text_height = height - m.top - m.bottom - m.header - m.footer;
+
+    children
+        .iter()
+        .filter_map(|child| match child {
+            ParagraphRecord::ListHeader { paragraphs, .. } => Some(paragraphs),
+            _ => None,
+        })
+        .enumerate()
+        .map(|(i, paras)| MasterPage {
+            id_ref: Some(i as u16),
+            apply_page_type: match i {
+                1 => PageApplyType::Even,
+                2 => PageApplyType::Odd,
+                _ => PageApplyType::Both,
+            },
+            content: SubList {
+                text_width: Some(text_width),
+                text_height: Some(text_height),
+                paragraphs: convert_hwp_paragraphs(paras),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .collect()
+}
+
 /// ParagraphRecord에서 ColumnDef 추출
 fn extract_column_def(paragraphs: &[bodytext::Paragraph]) -> Option<hwp_model::section::ColumnDef> {
     use hwp_model::section::{ColumnDef, ColumnLine, ColumnSize};
@@ -177,6 +329,8 @@ fn convert_paragraph(para: &bodytext::Paragraph) -> Vec<Paragraph> {
     let mut char_shapes: Vec<bodytext::CharShapeInfo> = Vec::new();
     let mut line_segs: Vec<bodytext::LineSegmentInfo> = Vec::new();
     let mut ctrl_headers: Vec<&ParagraphRecord> = Vec::new();
+    let mut range_tags_raw: Vec<u8> = Vec::new();
+    let mut extra_records: Vec<HwpRawRecord> = Vec::new();
 
     for record in &para.records {
         match record {
@@ -192,6 +346,17 @@ fn convert_paragraph(para: &bodytext::Paragraph) -> Vec<Paragraph> {
             ParagraphRecord::CtrlHeader { .. } => {
                 ctrl_headers.push(record);
             }
+            ParagraphRecord::ParaRangeTag { tags } => {
+                range_tags_raw = encode_range_tags(tags);
+            }
+            // 모델에 없는 레코드는 writer가 다시 기록하도록 보관
+            ParagraphRecord::Other { tag_id, data } => {
+                extra_records.push(HwpRawRecord {
+                    tag_id: *tag_id,
+                    level: 1,
+                    data: data.clone(),
+                });
+            }
             _ => {}
         }
     }
@@ -238,11 +403,29 @@ fn convert_paragraph(para: &bodytext::Paragraph) -> Vec<Paragraph> {
         runs,
         line_segments,
         has_char_shapes: !char_shapes.is_empty(),
+        hwp_hints: Some(HwpParagraphHints {
+            control_mask: header.control_mask.value(),
+            range_tags_raw,
+            extra_records,
+            ..Default::default()
+        }),
     };
 
     vec![main_para]
 }
 
+/// ParaRangeTag → 원본 레코드 바이트 (HWP writer roundtrip용)
+fn encode_range_tags(tags: &[bodytext::RangeTagInfo]) -> Vec<u8> {
+    let mut raw = Vec::with_capacity(tags.len() * 12);
+    for tag in tags {
+        let value = ((tag.tag_type as u32) << 24) | (tag.tag_data & 0x00FF_FFFF);
+        raw.extend_from_slice(&tag.start.to_le_bytes());
+        raw.extend_from_slice(&tag.end.to_le_bytes());
+        raw.extend_from_slice(&value.to_le_bytes());
+    }
+    raw
+}
+
 /// Object(Table/Shape)가 있는 Run에서 Text를 Object 뒤로 재배치
 /// 기존 viewer: ParaText를 마지막에 결합하므로 Object가 먼저 출력됨
 fn reorder_text_after_objects(runs: Vec<Run>) -> Vec<Run> {
@@ -708,6 +891,20 @@ fn non_empty(s: &str) -> Option<String> {
     }
 }
 
+/// 문서 정보 필드 ID → 문서 요약 키 (`Field::name`, hwp-model 필드 계산에서 사용)
+fn summary_key(field_type: &str) -> Option<&'static str> {
+    Some(match field_type {
+        CtrlId::FIELD_DOC_TITLE => "title",
+        CtrlId::FIELD_DOC_AUTHOR => "author",
+        CtrlId::FIELD_DOC_KEYWORD => "keywords",
+        CtrlId::FIELD_DOC_SUBJECT => "subject",
+        CtrlId::FIELD_DOC_COMMENT => "comments",
+        CtrlId::FIELD_DOC_CREATED => "created",
+        CtrlId::FIELD_DOC_LASTSAVED => "lastsaved",
+        _ => return None,
+    })
+}
+
 /// 텍스트 제어 문자에 의해 소비되지 않은 CtrlHeader를 마지막 Run에 추가
 fn append_remaining_ctrl_headers(
     run: &mut Run,
@@ -926,14 +1123,16 @@ fn convert_ctrl_header(record: &ParagraphRecord) -> Vec<RunContent> {
             command,
             id,
             attribute,
+            memo_index,
             ..
         } => {
             let ft = match field_type.as_str() {
-                CtrlId::FIELD_START => FieldType::ClickHere,
+                CtrlId::FIELD_START | CtrlId::FIELD_CLICK_HERE => FieldType::ClickHere,
                 CtrlId::FIELD_HYPERLINK => FieldType::Hyperlink,
                 CtrlId::FIELD_BOOKMARK => FieldType::Bookmark,
-                CtrlId::FIELD_FORMULA => FieldType::Formula,
-                CtrlId::FIELD_DOCSUMMARY => FieldType::Summary,
+                CtrlId::FIELD_FORMULA | CtrlId::FIELD_FORMULA_ALT => FieldType::Formula,
+                CtrlId::FIELD_DOCSUMMARY | CtrlId::FIELD_SUMMARY_ALT => FieldType::Summary,
+                id if summary_key(id).is_some() => FieldType::Summary,
                 CtrlId::FIELD_USER => FieldType::UserInfo,
                 CtrlId::FIELD_DATE => FieldType::Date,
                 CtrlId::FIELD_DOC_DATE => FieldType::DocDate,
@@ -942,15 +1141,27 @@ fn convert_ctrl_header(record: &ParagraphRecord) -> Vec<RunContent> {
                 CtrlId::FIELD_MAIL_MERGE => FieldType::MailMerge,
                 CtrlId::FIELD_OUTLINE => FieldType::Outline,
                 CtrlId::FIELD_PRIVATE_INFO_SECURITY => FieldType::PrivateInfo,
+                CtrlId::FIELD_MEMO => FieldType::Memo,
                 _ => FieldType::ClickHere,
             };
+            // 메모 인덱스는 HWPX와 같은 `ID` 파라미터로 옮겨 두고 구역 변환 끝에 메모 내용을 붙인다
+            let parameters = memo_index
+                .map(|index| {
+                    vec![FieldParameter::String {
+                        name: "ID".to_string(),
+                        value: index.to_string(),
+                    }]
+                })
+                .unwrap_or_default();
             let ctrl = Control::FieldBegin(Field {
                 id: *id as u64,
                 field_type: ft,
-                name: non_empty(command),
+                // 문서 정보 필드(%ttl 등)는 명령이 비어 있으면 요약 키를 이름으로 둔다
+                name: non_empty(command).or_else(|| summary_key(field_type).map(str::to_string)),
                 editable: (*attribute & 0x01) != 0,
                 dirty: (*attribute & 0x02) != 0,
                 field_id: Some(*id),
+                parameters,
                 ..Default::default()
             });
             vec![RunContent::Control(ctrl)]
@@ -987,13 +1198,17 @@ fn convert_ctrl_header(record: &ParagraphRecord) -> Vec<RunContent> {
             prefix,
             suffix,
         } => {
-            let num_type = match (*attribute >> 12) & 0x0F {
-                1 => AutoNumType::Picture,
-                2 => AutoNumType::Table,
-                3 => AutoNumType::Equation,
+            // 표 143: bit 0-3 번호 종류, bit 4-11 번호 모양
+            let num_type = match *attribute & 0x0F {
+                1 => AutoNumType::Footnote,
+                2 => AutoNumType::Endnote,
+                3 => AutoNumType::Picture,
+                4 => AutoNumType::Table,
+                5 => AutoNumType::Equation,
+                6 => AutoNumType::TotalPage,
                 _ => AutoNumType::Page,
             };
-            let number_format = match *attribute & 0x0F {
+            let number_format = match (*attribute >> 4) & 0xFF {
                 1 => NumberType1::CircledDigit,
                 2 => NumberType1::RomanCapital,
                 3 => NumberType1::RomanSmall,
@@ -1013,10 +1228,10 @@ fn convert_ctrl_header(record: &ParagraphRecord) -> Vec<RunContent> {
         }
 
         ctrl_header::CtrlHeaderData::NewNumber { attribute, number } => {
-            let num_type = match (*attribute >> 12) & 0x0F {
-                1 => NumberingType::Picture,
-                2 => NumberingType::Table,
-                3 => NumberingType::Equation,
+            let num_type = match *attribute & 0x0F {
+                3 => NumberingType::Picture,
+                4 => NumberingType::Table,
+                5 => NumberingType::Equation,
                 _ => NumberingType::None,
             };
             let ctrl = Control::NewNum(NewNum {
@@ -1119,6 +1334,7 @@ struct ShapeDrawInfo {
 
 fn collect_shape_parts<'a>(
     children: &'a [ParagraphRecord],
+    component: &bodytext::ShapeComponent,
     common: &ShapeCommon,
     results: &mut Vec<RunContent>,
     has_rect: &mut bool,
@@ -1175,11 +1391,17 @@ fn collect_shape_parts<'a>(
             ParagraphRecord::ShapeComponentRectangle { .. } => {
                 *has_rect = true;
             }
+            ParagraphRecord::ShapeComponentOle {
+                shape_component_ole,
+            } => {
+                results.push(RunContent::Object(convert_ole(common, shape_component_ole)));
+            }
             ParagraphRecord::ShapeComponentLine {
                 shape_component_line,
             } => {
                 let line_obj = hwp_model::shape::LineObject {
                     common: common.clone(),
+                    component: convert_shape_component(component),
                     start_pt: hwp_model::types::Point {
                         x: shape_component_line.start_point.x,
                         y: shape_component_line.start_point.y,
@@ -1199,14 +1421,21 @@ fn collect_shape_parts<'a>(
                 *list_header_paras = Some(lh_paras);
             }
             ParagraphRecord::ShapeComponent {
-                children: nested, ..
+                shape_component: nested_component,
+                children: nested,
+                ..
             } => {
                 // 재귀 탐색: 각 중첩 SC의 Rectangle+ListHeader를 독립적으로 처리
                 let mut nested_rect = false;
                 let mut nested_lh: Option<&'a [bodytext::Paragraph]> = None;
-                if let Some(di) =
-                    collect_shape_parts(nested, common, results, &mut nested_rect, &mut nested_lh)
-                {
+                if let Some(di) = collect_shape_parts(
+                    nested,
+                    nested_component,
+                    common,
+                    results,
+                    &mut nested_rect,
+                    &mut nested_lh,
+                ) {
                     if draw_info.is_none() {
                         draw_info = Some(di);
                     }
@@ -1258,6 +1487,130 @@ fn collect_shape_parts<'a>(
     draw_info
 }
 
+/// 개체 요소(표 83) → ShapeComponentData
+/// 선/다각형 좌표는 생성 시 크기(org_size) 기준이므로 현재 크기와 함께 넘긴다
+fn convert_shape_component(sc: &bodytext::ShapeComponent) -> ShapeComponentData {
+    ShapeComponentData {
+        group_level: sc.group_count as u32,
+        offset: Some(Point {
+            x: sc.group_offset.x,
+            y: sc.group_offset.y,
+        }),
+        org_size: Some(Size {
+            width: sc.initial_width as i32,
+            height: sc.initial_height as i32,
+        }),
+        cur_size: Some(Size {
+            width: sc.width as i32,
+            height: sc.height as i32,
+        }),
+        ..Default::default()
+    }
+}
+
+/// OLE 개체 속성(표 118) → OleObject
+/// 차트 OLE는 변환 후 `convert::chart`에서 바이너리를 열어 ChartObject로 바꾼다.
+fn convert_ole(common: &ShapeCommon, ole: &bodytext::ShapeComponentOle) -> ShapeObject {
+    let attrs = &ole.attributes;
+    ShapeObject::Ole(Box::new(OleObject {
+        common: common.clone(),
+        object_type: match attrs.object_type {
+            1 => OleObjectType::Embedded,
+            2 => OleObjectType::Link,
+            3 => OleObjectType::Static,
+            4 => OleObjectType::Equation,
+            _ => OleObjectType::Unknown,
+        },
+        presentation: match attrs.drawing_aspect {
+            2 => OlePresentation::ThumbNail,
+            4 => OlePresentation::Icon,
+            8 => OlePresentation::DocPrint,
+            _ => OlePresentation::Content,
+        },
+        extent: Size {
+            width: ole.extent_x,
+            height: ole.extent_y,
+        },
+        binary_item_id: Some(format!("BIN{:04X}", ole.bindata_id)),
+        has_moniker: attrs.has_moniker,
+        eq_baseline: (attrs.baseline > 0).then_some(attrs.baseline as i16 - 1),
+        line_shape: (ole.border_width > 0).then(|| ShapeLineInfo {
+            color: Some(ole.border_color.to_rgb()),
+            width: ole.border_width,
+            ..Default::default()
+        }),
+        ..Default::default()
+    }))
+}
+
+/// 양식 개체 레코드(속성 문자열) → FormObject
+fn convert_form_object(common: &ShapeCommon, form: &bodytext::FormObject) -> ShapeObject {
+    use bodytext::FormObjectType;
+
+    let form_type = match form.form_type {
+        FormObjectType::PushButton => FormType::PushButton,
+        FormObjectType::CheckBox => FormType::CheckBox,
+        FormObjectType::RadioButton => FormType::RadioButton,
+        FormObjectType::ComboBox => FormType::ComboBox,
+        FormObjectType::ListBox => FormType::ListBox,
+        FormObjectType::Edit => FormType::Edit,
+        FormObjectType::ScrollBar => FormType::ScrollBar,
+    };
+    let string = |name: &str| form.string(name).map(str::to_string);
+    let color = |name: &str| {
+        form.int(name)
+            .map(|v| crate::types::COLORREF(v as u32).to_rgb())
+    };
+    let value = match form_type {
+        FormType::Edit | FormType::ComboBox | FormType::ListBox => string("Text"),
+        FormType::ScrollBar => form.int("Value").map(|v| v.to_string()),
+        _ => None,
+    };
+    let check_state = match form_type {
+        FormType::CheckBox | FormType::RadioButton => match form.int("Value") {
+            Some(1) => FormCheckState::Checked,
+            Some(2) => FormCheckState::Indeterminate,
+            _ => FormCheckState::Unchecked,
+        },
+        _ => FormCheckState::Unchecked,
+    };
+    // 목록 항목: ListItem* 문자열 속성 (표시 문자열 = 값)
+    let items = form
+        .properties
+        .iter()
+        .filter(|p| p.name.starts_with("ListItem"))
+        .filter_map(|p| match &p.value {
+            bodytext::FormPropertyValue::String(s) => Some(FormListItem {
+                display_text: s.clone(),
+                value: s.clone(),
+            }),
+            _ => None,
+        })
+        .collect();
+
+    ShapeObject::Form(Box::new(FormObject {
+        common: common.clone(),
+        form_type,
+        name: string("Name").unwrap_or_default(),
+        caption: string("Caption"),
+        value,
+        check_state,
+        group_name: string("RadioGroupName")
+            .or_else(|| string("GroupName"))
+            .filter(|g| !g.is_empty()),
+        items,
+        fore_color: color("ForeColor"),
+        back_color: color("BackColor"),
+        enabled: form.bool("Enabled").unwrap_or(true),
+        read_only: form.bool("ReadOnly").unwrap_or(false),
+        multi_line: form.bool("MultiLine").unwrap_or(false),
+        max_length: form.int("MaxLength").filter(|&n| n > 0).map(|n| n as u32),
+        password_char: string("PasswordChar").filter(|c| !c.is_empty()),
+        tab_order: form.int("TabOrder").map(|n| n as u32),
+        char_shape_id: form.int("CharShapeID").map(|n| n as u16),
+    }))
+}
+
 /// 도형/그림 → ShapeObject 변환 (텍스트박스, 그림 등)
 /// 기존 viewer와 동일하게 children과 paragraphs를 모두 순회하여 콘텐츠 수집
 /// 각 도형을 별도 RunContent로 반환 (기존 viewer처럼 paragraph 내 개별 parts로 처리)
@@ -1290,9 +1643,9 @@ fn convert_shape_object(
                 results.push(RunContent::Object(ShapeObject::Picture(Box::new(picture))));
             }
             ParagraphRecord::ShapeComponent {
+                shape_component,
                 children: sc_children,
                 drawing_object_common: ref doc_opt,
-                ..
             } => {
                 let mut has_rect = false;
                 let mut list_header_paras: Option<&[bodytext::Paragraph]> = None;
@@ -1324,8 +1677,10 @@ fn convert_shape_object(
                 }
 
                 // 중첩 ShapeComponent 재귀 탐색
+                let first_new = results.len();
                 let nested_draw = collect_shape_parts(
                     sc_children,
+                    shape_component,
                     &common,
                     &mut results,
                     &mut has_rect,
@@ -1335,6 +1690,15 @@ fn convert_shape_object(
                 // 1레벨 draw_info 우선, 없으면 nested
                 let final_draw = shape_draw.or(nested_draw);
 
+                // 선 개체에 ShapeComponent의 선 모양 적용
+                if let Some(ref di) = final_draw {
+                    for content in &mut results[first_new..] {
+                        if let RunContent::Object(ShapeObject::Line(line)) = content {
+                            line.line_shape = di.line_shape.clone();
+                        }
+                    }
+                }
+
                 // Rectangle + ListHeader → draw_text + fill/line 포함 Rectangle 생성
                 if has_rect {
                     let draw_text = list_header_paras.map(|paras| {
@@ -1376,6 +1740,39 @@ fn convert_shape_object(
                     results.push(RunContent::Object(ShapeObject::Rectangle(Box::new(rect))));
                 }
             }
+            ParagraphRecord::ShapeComponentOle {
+                shape_component_ole,
+            } => {
+                results.push(RunContent::Object(convert_ole(
+                    &common,
+                    shape_component_ole,
+                )));
+            }
+            ParagraphRecord::FormObject { form_object } => {
+                results.push(RunContent::Object(convert_form_object(
+                    &common,
+                    form_object,
+                )));
+            }
+            ParagraphRecord::EqEdit { eqedit } => {
+                let equation = hwp_model::shape::EquationObject {
+                    common: common.clone(),
+                    line_mode: if eqedit.attribute & 0x01 != 0 {
+                        hwp_model::types::EquationLineMode::Line
+                    } else {
+                        hwp_model::types::EquationLineMode::Char
+                    },
+                    script: eqedit.script.clone(),
+                    base_unit: eqedit.character_size.0 as i32,
+                    text_color: Some(eqedit.character_color.to_rgb()),
+                    baseline: eqedit.base_line,
+                    version: Some(eqedit.version_info.clone()).filter(|v| !v.is_empty()),
+                    font: Some(eqedit.font_name.clone()).filter(|f| !f.is_empty()),
+                };
+                results.push(RunContent::Object(ShapeObject::Equation(Box::new(
+                    equation,
+                ))));
+            }
             _ => {}
         }
     }
@@ -1834,3 +2231,63 @@ fn build_shape_common(
         meta_tag: None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::bodytext::MemoList;
+
+    fn memo_field(index: Option<&str>) -> Paragraph {
+        let parameters = index
+            .map(|i| {
+                vec![FieldParameter::String {
+                    name: "ID".to_string(),
+                    value: i.to_string(),
+                }]
+            })
+            .unwrap_or_default();
+        Paragraph {
+            runs: vec![Run {
+                char_shape_id: 0,
+                contents: vec![RunContent::Control(Control::FieldBegin(Field {
+                    field_type: FieldType::Memo,
+                    parameters,
+                    ..Default::default()
+                }))],
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn hwp_memo(index: u32, paragraphs: usize) -> bodytext::Memo {
+        bodytext::Memo {
+            memo_list: MemoList {
+                raw_data: index.to_le_bytes().to_vec(),
+            },
+            paragraphs: vec![bodytext::Paragraph::default(); paragraphs],
+        }
+    }
+
+    fn attached(para: &Paragraph) -> (usize, Option<String>) {
+        match &para.runs[0].contents[0] {
+            RunContent::Control(Control::FieldBegin(field)) => (
+                field.sub_list.as_ref().map_or(0, |sl| sl.paragraphs.len()),
+                field.parameter_str("Number"),
+            ),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn attaches_memos_by_index_then_in_order() {
+        let memos = [hwp_memo(5, 1), hwp_memo(3, 2), hwp_memo(9, 3)];
+        let mut unused: Vec<&bodytext::Memo> = memos.iter().collect();
+        let mut paragraphs = vec![memo_field(Some("3")), memo_field(None)];
+        let mut number = 4;
+        attach_memos(&mut paragraphs, &mut unused, &mut number);
+
+        assert_eq!(attached(&paragraphs[0]), (2, Some("5".to_string())));
+        assert_eq!(attached(&paragraphs[1]), (1, Some("6".to_string())));
+        assert_eq!(unused.len(), 1);
+    }
+}
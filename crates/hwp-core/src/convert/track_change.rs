@@ -0,0 +1,395 @@
+//! HWP 변경 추적 → hwp_model 변경 표시
+//!
+//! HWP 5.0 본문은 변경 구간을 문단의 영역 태그(PARA_RANGE_TAG)로 저장한다. 스펙(표 63)에는
+//! 태그 종류 값이 정의되어 있지 않으므로 HWPX `hh:trackChange` 유형 순서(삽입=1, 삭제=2)를
+//! 따르고, 태그 데이터(하위 24비트)를 변경 추적 항목 ID로 본다. 변경 추적 레코드가 없는
+//! 문서의 영역 태그는 해석하지 않는다 (형광펜 등 다른 용도의 태그를 변경으로 오인하지 않도록).
+//! 태그 종류 값은 변경 추적이 든 HWP 표본으로 아직 확인하지 못했다.
+//!
+//! 태그 위치는 원본 PARA_TEXT의 WCHAR 위치이므로 모델 런에서 원본 길이를 다시 센다
+//! (실제 문서의 문단 머리 글자 수와 맞는지 테스트로 확인).
+
+use crate::document::docinfo::DocInfo;
+use crate::writer::echoed_text;
+use hwp_model::document::Document;
+use hwp_model::paragraph::{Paragraph, RunContent, TextContent, TextElement};
+use hwp_model::query::{ParagraphPath, VisitorMut};
+
+/// 영역 태그 종류: 변경 추적 삽입
+const RANGE_TAG_TRACK_INSERT: u8 = 1;
+/// 영역 태그 종류: 변경 추적 삭제
+const RANGE_TAG_TRACK_DELETE: u8 = 2;
+
+/// 문단 영역 태그의 변경 구간을 InsertBegin/End, DeleteBegin/End 표시로 옮긴다
+pub(crate) fn resolve_range_tags(document: &mut Document, doc_info: &DocInfo) {
+    if doc_info.track_change_contents.is_empty() {
+        return;
+    }
+    document.walk_mut(&mut RangeTagMarks);
+}
+
+/// 문단마다 영역 태그를 변경 표시로 바꾼다
+struct RangeTagMarks;
+
+impl VisitorMut for RangeTagMarks {
+    fn visit_paragraph_mut(&mut self, path: &ParagraphPath, para: &mut Paragraph) {
+        let section_start =
+            path.master_page.is_none() && path.steps.is_empty() && path.paragraph == 0;
+        insert_marks(para, section_start);
+    }
+}
+
+/// 영역 태그 한 항목 (start, end, 종류, 데이터)
+fn decode_range_tags(raw: &[u8]) -> Vec<(u32, u32, u8, u32)> {
+    raw.chunks_exact(12)
+        .map(|c| {
+            let value = u32::from_le_bytes([c[8], c[9], c[10], c[11]]);
+            (
+                u32::from_le_bytes([c[0], c[1], c[2], c[3]]),
+                u32::from_le_bytes([c[4], c[5], c[6], c[7]]),
+                (value >> 24) as u8,
+                value & 0x00FF_FFFF,
+            )
+        })
+        .collect()
+}
+
+/// 구역 정의(secd) 제어 문자 WCHAR 수. 구역 첫 본문 문단의 텍스트는 이것으로 시작하지만
+/// 모델에서는 `Section::definition`으로 옮겨져 런에 남지 않는다
+const SECTION_DEF_WCHARS: u32 = 8;
+
+fn insert_marks(para: &mut Paragraph, section_start: bool) {
+    let Some(tags) = para
+        .hwp_hints
+        .as_ref()
+        .map(|h| decode_range_tags(&h.range_tags_raw))
+    else {
+        return;
+    };
+
+    let text_len = paragraph_wchars(para, section_start);
+
+    // (위치, 끝 표시 우선 순서, 표시)
+    let mut marks: Vec<(u32, u8, TextElement)> = Vec::new();
+    for (i, (start, end, tag_type, tag_data)) in tags.into_iter().enumerate() {
+        let id = i.to_string();
+        let tc_id = Some(tag_data.to_string());
+        // 문단 나누기 문자(끝 위치 = 텍스트 길이)까지 덮으면 문단 끝 포함
+        let para_end = end > text_len;
+        let (start, end) = (start.min(text_len), end.min(text_len));
+        let (begin, finish) = match tag_type {
+            RANGE_TAG_TRACK_INSERT => (
+                TextElement::InsertBegin {
+                    id: id.clone(),
+                    tc_id: tc_id.clone(),
+                    para_end,
+                },
+                TextElement::InsertEnd {
+                    id,
+                    tc_id,
+                    para_end,
+                },
+            ),
+            RANGE_TAG_TRACK_DELETE => (
+                TextElement::DeleteBegin {
+                    id: id.clone(),
+                    tc_id: tc_id.clone(),
+                    para_end,
+                },
+                TextElement::DeleteEnd {
+                    id,
+                    tc_id,
+                    para_end,
+                },
+            ),
+            _ => continue,
+        };
+        marks.push((start, 1, begin));
+        marks.push((end.max(start), 0, finish));
+    }
+    if marks.is_empty() {
+        return;
+    }
+    marks.sort_by_key(|(pos, order, _)| (*pos, *order));
+
+    let mut marks = marks.into_iter().peekable();
+    let mut pos: u32 = if section_start { SECTION_DEF_WCHARS } else { 0 };
+    let mut counter = WcharCounter::default();
+    for run in &mut para.runs {
+        let mut contents = Vec::with_capacity(run.contents.len());
+        for content in run.contents.drain(..) {
+            match content {
+                RunContent::Text(tc) => {
+                    let mut elements = Vec::with_capacity(tc.elements.len());
+                    for element in tc.elements {
+                        let mut rest = element;
+                        let mut width = counter.element(&rest);
+                        // 제어 문자의 표시 문자열은 제어 문자에 붙여 둔다
+                        if width == 0 {
+                            elements.push(rest);
+                            continue;
+                        }
+                        while let Some((at, _, _)) = marks.peek() {
+                            if *at > pos + width {
+                                break;
+                            }
+                            let offset = at.saturating_sub(pos);
+                            if offset == 0 {
+                                elements.push(marks.next().unwrap().2);
+                                continue;
+                            }
+                            // 텍스트 중간이면 나눠서 표시를 끼운다
+                            match &rest {
+                                TextElement::Text(s) if offset < width => {
+                                    let (head, tail) = split_at_wchar(s, offset);
+                                    elements.push(TextElement::Text(head));
+                                    rest = TextElement::Text(tail);
+                                    pos += offset;
+                                    width -= offset;
+                                }
+                                _ => break,
+                            }
+                        }
+                        pos += width;
+                        elements.push(rest);
+                    }
+                    contents.push(RunContent::Text(TextContent {
+                        char_shape_id: tc.char_shape_id,
+                        elements,
+                    }));
+                }
+                other => {
+                    let mut before = Vec::new();
+                    while marks.peek().is_some_and(|(at, _, _)| *at <= pos) {
+                        before.push(marks.next().unwrap().2);
+                    }
+                    if !before.is_empty() {
+                        contents.push(mark_content(before));
+                    }
+                    pos += counter.content(&other);
+                    contents.push(other);
+                }
+            }
+        }
+        run.contents = contents;
+    }
+
+    // 문단 끝에 남은 표시
+    let rest: Vec<TextElement> = marks.map(|(_, _, mark)| mark).collect();
+    if !rest.is_empty() {
+        if para.runs.is_empty() {
+            para.runs.push(Default::default());
+        }
+        if let Some(run) = para.runs.last_mut() {
+            run.contents.push(mark_content(rest));
+        }
+    }
+}
+
+fn mark_content(elements: Vec<TextElement>) -> RunContent {
+    RunContent::Text(TextContent {
+        char_shape_id: None,
+        elements,
+    })
+}
+
+/// 원본 문단 텍스트(PARA_TEXT)에서 차지하는 WCHAR 수 (문단 나누기 제외)
+fn paragraph_wchars(para: &Paragraph, section_start: bool) -> u32 {
+    let mut counter = WcharCounter::default();
+    let contents: u32 = para
+        .runs
+        .iter()
+        .flat_map(|run| &run.contents)
+        .map(|content| counter.content(content))
+        .sum();
+    if section_start {
+        contents + SECTION_DEF_WCHARS
+    } else {
+        contents
+    }
+}
+
+/// 요소별 원본 WCHAR 수를 센다 (인라인/확장 제어 문자는 8).
+/// 제어 문자 뒤 표시 문자열은 writer와 같은 규칙(`echoed_text`)으로 건너뛴다
+#[derive(Default)]
+struct WcharCounter {
+    echo: Option<&'static str>,
+}
+
+impl WcharCounter {
+    fn element(&mut self, element: &TextElement) -> u32 {
+        let echo = self.echo.take();
+        match element {
+            TextElement::Text(s) if echo == Some(s.as_str()) => 0,
+            TextElement::Text(s) => s.encode_utf16().count() as u32,
+            TextElement::Tab { .. } => {
+                self.echo = echoed_text(element);
+                8
+            }
+            TextElement::LineBreak
+            | TextElement::Hyphen
+            | TextElement::NbSpace
+            | TextElement::FwSpace => {
+                self.echo = echoed_text(element);
+                1
+            }
+            // 형광펜/변경 표시는 텍스트에 남지 않는다
+            _ => {
+                self.echo = echo;
+                0
+            }
+        }
+    }
+
+    fn content(&mut self, content: &RunContent) -> u32 {
+        match content {
+            RunContent::Text(tc) => tc.elements.iter().map(|e| self.element(e)).sum(),
+            RunContent::Control(_) | RunContent::Object(_) => {
+                self.echo = None;
+                8
+            }
+        }
+    }
+}
+
+fn split_at_wchar(s: &str, wchars: u32) -> (String, String) {
+    let mut count = 0u32;
+    for (i, c) in s.char_indices() {
+        if count >= wchars {
+            return (s[..i].to_string(), s[i..].to_string());
+        }
+        count += c.len_utf16() as u32;
+    }
+    (s.to_string(), String::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hwp_model::hints::HwpParagraphHints;
+    use hwp_model::paragraph::Run;
+
+    fn tag(start: u32, end: u32, tag_type: u8, data: u32) -> Vec<u8> {
+        let mut raw = Vec::new();
+        raw.extend_from_slice(&start.to_le_bytes());
+        raw.extend_from_slice(&end.to_le_bytes());
+        raw.extend_from_slice(&(((tag_type as u32) << 24) | data).to_le_bytes());
+        raw
+    }
+
+    fn text_para(text: &str, range_tags_raw: Vec<u8>) -> Paragraph {
+        Paragraph {
+            runs: vec![Run {
+                char_shape_id: 0,
+                contents: vec![mark_content(vec![TextElement::Text(text.to_string())])],
+            }],
+            hwp_hints: Some(HwpParagraphHints {
+                range_tags_raw,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn elements(para: &Paragraph) -> Vec<String> {
+        para.runs
+            .iter()
+            .flat_map(|r| &r.contents)
+            .filter_map(|c| match c {
+                RunContent::Text(tc) => Some(&tc.elements),
+                _ => None,
+            })
+            .flatten()
+            .map(|e| match e {
+                TextElement::Text(s) => s.clone(),
+                TextElement::Tab { .. } => "⇥".to_string(),
+                other => {
+                    let mark = other.revision_mark().unwrap();
+                    format!(
+                        "{}{:?}{}",
+                        if mark.begin { "<" } else { "</" },
+                        mark.kind,
+                        if mark.para_end { "¶" } else { "" }
+                    )
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn splits_text_at_tag_boundaries() {
+        let mut raw = tag(2, 4, RANGE_TAG_TRACK_DELETE, 7);
+        raw.extend(tag(4, 6, RANGE_TAG_TRACK_INSERT, 8));
+        let mut para = text_para("계약기간은1년", raw);
+        insert_marks(&mut para, false);
+        assert_eq!(
+            elements(&para),
+            vec!["계약", "<Delete", "기간", "</Delete", "<Insert", "은1", "</Insert", "년"]
+        );
+    }
+
+    #[test]
+    fn tag_past_text_end_covers_paragraph_break() {
+        let mut para = text_para("abc", tag(1, 4, RANGE_TAG_TRACK_DELETE, 1));
+        insert_marks(&mut para, false);
+        assert_eq!(elements(&para), vec!["a", "<Delete¶", "bc", "</Delete¶"]);
+    }
+
+    fn fixture(name: &str) -> (crate::document::HwpDocument, Document) {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(name);
+        let hwp = crate::HwpParser::new()
+            .parse(&std::fs::read(path).unwrap())
+            .unwrap();
+        let doc = crate::convert::to_document(&hwp);
+        (hwp, doc)
+    }
+
+    #[test]
+    fn wchar_count_matches_paragraph_header() {
+        // 탭(표시 문자열 포함), 구역 정의 + 단 정의, 문단 중간 단 정의
+        for name in [
+            "tabdef.hwp",
+            "multicolumns-layout.hwp",
+            "multicolumns-widths.hwp",
+        ] {
+            let (hwp, doc) = fixture(name);
+            for (section, raw) in doc.sections.iter().zip(&hwp.body_text.sections) {
+                for (i, (para, raw)) in section.paragraphs.iter().zip(&raw.paragraphs).enumerate() {
+                    // 문단 머리의 글자 수는 문단 나누기 문자를 포함한다
+                    assert_eq!(
+                        paragraph_wchars(para, i == 0) + 1,
+                        raw.para_header.text_char_count,
+                        "{} 문단 {}",
+                        name,
+                        i
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn tag_after_tabs_lands_on_original_offset() {
+        // tabdef.hwp 둘째 문단: 탭 L 탭 L 탭 L → 두 번째 L은 WCHAR 17
+        let (_, doc) = fixture("tabdef.hwp");
+        let mut para = doc.sections[0].paragraphs[1].clone();
+        para.hwp_hints = Some(HwpParagraphHints {
+            range_tags_raw: tag(17, 18, RANGE_TAG_TRACK_DELETE, 1),
+            ..Default::default()
+        });
+        insert_marks(&mut para, false);
+        assert_eq!(
+            elements(&para),
+            vec!["⇥", "\t", "L", "⇥", "\t", "<Delete", "L", "</Delete", "⇥", "\t", "L"]
+        );
+    }
+
+    #[test]
+    fn other_tag_types_are_ignored() {
+        let mut para = text_para("abc", tag(0, 2, 9, 1));
+        insert_marks(&mut para, false);
+        assert_eq!(elements(&para), vec!["abc"]);
+    }
+}
@@ -68,7 +68,10 @@ impl BinData {
                 BinDataRecord::Embedding { embedding, .. } => {
                     (embedding.binary_data_id, Some(embedding.extension.clone()))
                 }
-                BinDataRecord::Storage { storage, .. } => (storage.binary_data_id, None),
+                // OLE 스토리지는 `BINxxxx.OLE` 스트림 / OLE storages are `BINxxxx.OLE` streams
+                BinDataRecord::Storage { storage, .. } => {
+                    (storage.binary_data_id, Some("OLE".to_string()))
+                }
                 BinDataRecord::Link { .. } => {
                     // LINK 타입은 BinData 스토리지에 없으므로 건너뜀
                     // LINK type is not in BinData storage, so skip
@@ -2,6 +2,7 @@ use crate::error::HwpError;
 use crate::types::decode_utf16le;
 use crate::types::{UINT16, UINT32, UINT8};
 
+use super::ids::CtrlId;
 use super::types::CtrlHeaderData;
 
 /// 필드 파싱 (표 152) / Parse field (Table 152)
@@ -67,6 +68,19 @@ fn parse_field_data(data: &[u8], field_type: String) -> Result<CtrlHeaderData, H
     } else {
         0
     };
+    offset += 4;
+
+    // 메모 필드는 id 뒤에 메모 인덱스(UINT32)가 붙는다 / Memo field has memo index after id
+    let memo_index = if field_type == CtrlId::FIELD_MEMO && offset + 4 <= data.len() {
+        Some(UINT32::from_le_bytes([
+            data[offset],
+            data[offset + 1],
+            data[offset + 2],
+            data[offset + 3],
+        ]))
+    } else {
+        None
+    };
 
     Ok(CtrlHeaderData::Field {
         field_type,
@@ -75,5 +89,6 @@ fn parse_field_data(data: &[u8], field_type: String) -> Result<CtrlHeaderData, H
         command_len: command_len as UINT16,
         command,
         id,
+        memo_index,
     })
 }
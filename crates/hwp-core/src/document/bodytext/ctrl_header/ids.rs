@@ -12,6 +12,12 @@ impl CtrlId {
     /// 일반 그리기 개체 / General shape object
     /// 개체 공통 속성을 가지는 컨트롤 ID / Control ID with object common properties
     pub const SHAPE_OBJECT: &str = "gso ";
+    /// 수식 개체 / Equation object
+    /// 개체 공통 속성을 가지는 컨트롤 ID / Control ID with object common properties
+    pub const EQUATION: &str = "eqed";
+    /// 양식 개체 / Form object
+    /// 개체 공통 속성을 가지는 컨트롤 ID / Control ID with object common properties
+    pub const FORM: &str = "form";
 
     // 표 127: 개체 이외의 컨트롤과 컨트롤 ID / Table 127: Controls other than objects and Control IDs
     /// 구역 정의 / Section definition
@@ -99,4 +105,12 @@ impl CtrlId {
     pub const FIELD_DOC_CHARCOUNT: &str = "%cct";
     pub const FIELD_PRIVATE_INFO_SECURITY: &str = "%cpr";
     pub const FIELD_TABLE_OF_CONTENTS_ALT: &str = "%oc";
+    /// 메모 필드 (표 128에는 없음, 한/글 저장 파일 기준) / Memo field (not listed in Table 128)
+    pub const FIELD_MEMO: &str = "%%me";
+    /// 누름틀 (표 128에는 없음) / Click-here field (not listed in Table 128)
+    pub const FIELD_CLICK_HERE: &str = "%clk";
+    /// 계산식 (한/글 저장 파일 기준, `%eqr`과 같음) / Formula as written by Hangul (same as `%eqr`)
+    pub const FIELD_FORMULA_ALT: &str = "%fmu";
+    /// 문서 요약 (한/글 저장 파일 기준, `%dsm`과 같음) / Summary as written by Hangul (same as `%dsm`)
+    pub const FIELD_SUMMARY_ALT: &str = "%smr";
 }
@@ -44,7 +44,7 @@ impl CtrlHeader {
         let remaining_data = if data.len() > 4 { &data[4..] } else { &[] };
 
         let parsed_data = match ctrl_id.as_str() {
-            CtrlId::TABLE | CtrlId::SHAPE_OBJECT => {
+            CtrlId::TABLE | CtrlId::SHAPE_OBJECT | CtrlId::EQUATION | CtrlId::FORM => {
                 object_common::parse_object_common(remaining_data)?
             }
             CtrlId::COLUMN_DEF => column_definition::parse_column_definition(remaining_data)?,
@@ -97,6 +97,9 @@ pub enum CtrlHeaderData {
         command_len: UINT16,
         command: String,
         id: UINT32,
+        /// 메모 인덱스 (메모 필드 `%%me`만, MEMO_LIST의 인덱스) / Memo index (memo field only)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        memo_index: Option<UINT32>,
     },
     SectionDefinition {
         attribute: UINT32,
@@ -4,8 +4,8 @@
 ///
 /// **구현 상태 / Implementation Status**
 /// - 구현 완료 / Implementation complete
-/// - 테스트 파일(`noori.hwp`)에 EQEDIT 레코드가 없어 실제 파일로 테스트되지 않음
-/// - Implementation complete, but not tested with actual file as test file (`noori.hwp`) does not contain EQEDIT records
+/// - `latex.hwp`의 EQEDIT 레코드로 검증됨
+/// - Verified with EQEDIT records in `latex.hwp`
 use crate::error::HwpError;
 use crate::types::{decode_utf16le, COLORREF, HWPUNIT, INT16, UINT16, UINT32};
 use serde::{Deserialize, Serialize};
@@ -44,20 +44,14 @@ impl EqEdit {
     /// # Note
     /// 스펙 문서 표 104에 따르면 EQEDIT는 다음 구조를 가집니다:
     /// - 개체 공통 속성(표 68 참조) - 가변 길이
-    /// - 수식 개체 속성(표 105 참조) - 가변 길이 (16 + 6×len 바이트)
+    /// - 수식 개체 속성(표 105 참조) - 가변 길이
     ///
     /// 레거시 코드(hwp.js)는 수식 개체 속성의 일부만 파싱하고 있습니다.
     /// According to spec Table 104, EQEDIT has the following structure:
     /// - Object common properties (Table 68) - variable length
-    /// - Equation editor object attributes (Table 105) - variable length (16 + 6×len bytes)
+    /// - Equation editor object attributes (Table 105) - variable length
     ///
     /// Legacy code (hwp.js) only parses part of equation editor object attributes.
-    ///
-    /// **테스트 상태 / Testing Status**
-    /// 현재 테스트 파일(`noori.hwp`)에 EQEDIT 레코드가 없어 실제 파일로 검증되지 않았습니다.
-    /// 실제 HWP 파일에 EQEDIT 레코드가 있으면 자동으로 파싱됩니다.
-    /// Current test file (`noori.hwp`) does not contain EQEDIT records, so it has not been verified with actual files.
-    /// If an actual HWP file contains EQEDIT records, they will be automatically parsed.
     pub fn parse(data: &[u8]) -> Result<Self, HwpError> {
         // 최소 16바이트 필요 (len=0일 때) / Need at least 16 bytes (when len=0)
         if data.len() < 16 {
@@ -82,9 +76,9 @@ impl EqEdit {
         let script_length_usize = script_length as usize;
 
         // 필요한 바이트 수 계산 / Calculate required bytes
-        // UINT32(4) + WORD(2) + WCHAR array[len](2×len) + HWPUNIT(4) + COLORREF(4) + INT16(2) + WCHAR array[len](2×len) + WCHAR array[len](2×len)
-        // = 16 + 6×len
-        let required_bytes = 16 + 6 * script_length_usize;
+        // UINT32(4) + WORD(2) + WCHAR array[len](2×len) + HWPUNIT(4) + COLORREF(4) + INT16(2)
+        // = 16 + 2×len (버전 정보/폰트 이름은 각자의 길이를 가짐 / version and font carry their own length)
+        let required_bytes = 16 + 2 * script_length_usize;
         if data.len() < required_bytes {
             return Err(HwpError::InsufficientData {
                 field: format!("EqEdit (script_length={})", script_length_usize),
@@ -124,18 +118,17 @@ impl EqEdit {
         let base_line = INT16::from_le_bytes([data[offset], data[offset + 1]]);
         offset += 2;
 
-        // 표 105: 수식 버전 정보 (WCHAR array[len], 2×len 바이트) / Table 105: Equation version information (WCHAR array[len], 2×len bytes)
-        let version_bytes = &data[offset..offset + 2 * script_length_usize];
-        let version_info = decode_utf16le(version_bytes).map_err(|e| HwpError::EncodingError {
-            reason: format!("Failed to decode EqEdit version_info: {}", e),
-        })?;
-        offset += 2 * script_length_usize;
+        // 실제 파일은 base line 뒤에 2바이트 예약 영역이 있고, 버전 정보와 폰트 이름은
+        // 스펙 표기(WCHAR array[len])와 달리 각각 WORD 길이 + WCHAR 배열로 저장된다.
+        // Actual files have 2 reserved bytes after base line, and version/font are stored as
+        // WORD length + WCHAR array each, unlike the spec notation (WCHAR array[len]).
+        offset += 2;
 
-        // 표 105: 수식 폰트 이름 (WCHAR array[len], 2×len 바이트) / Table 105: Equation font name (WCHAR array[len], 2×len bytes)
-        let font_bytes = &data[offset..offset + 2 * script_length_usize];
-        let font_name = decode_utf16le(font_bytes).map_err(|e| HwpError::EncodingError {
-            reason: format!("Failed to decode EqEdit font_name: {}", e),
-        })?;
+        // 표 105: 수식 버전 정보 / Table 105: Equation version information
+        let version_info = read_string(data, &mut offset, "version_info")?;
+
+        // 표 105: 수식 폰트 이름 / Table 105: Equation font name
+        let font_name = read_string(data, &mut offset, "font_name")?;
 
         Ok(EqEdit {
             attribute,
@@ -149,3 +142,49 @@ impl EqEdit {
         })
     }
 }
+
+/// WORD 길이 + WCHAR 배열 문자열 읽기 (데이터가 없으면 빈 문자열)
+/// Read WORD length + WCHAR array string (empty if data is missing)
+fn read_string(data: &[u8], offset: &mut usize, field: &str) -> Result<String, HwpError> {
+    if *offset + 2 > data.len() {
+        return Ok(String::new());
+    }
+    let len = UINT16::from_le_bytes([data[*offset], data[*offset + 1]]) as usize;
+    *offset += 2;
+    let end = (*offset + 2 * len).min(data.len());
+    let value = decode_utf16le(&data[*offset..end]).map_err(|e| HwpError::EncodingError {
+        reason: format!("Failed to decode EqEdit {}: {}", field, e),
+    })?;
+    *offset = end;
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wchars(data: &mut Vec<u8>, text: &str) {
+        data.extend(text.encode_utf16().flat_map(u16::to_le_bytes));
+    }
+
+    #[test]
+    fn version_and_font_carry_their_own_length() {
+        let mut data = 0u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&5u16.to_le_bytes());
+        wchars(&mut data, "a+b=c");
+        data.extend_from_slice(&1000u32.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&85i16.to_le_bytes());
+        data.extend_from_slice(&[0, 0]);
+        data.extend_from_slice(&19u16.to_le_bytes());
+        wchars(&mut data, "Equation Version 60");
+        data.extend_from_slice(&7u16.to_le_bytes());
+        wchars(&mut data, "HYhwpEQ");
+
+        let eq = EqEdit::parse(&data).unwrap();
+        assert_eq!(eq.script, "a+b=c");
+        assert_eq!(eq.base_line, 85);
+        assert_eq!(eq.version_info, "Equation Version 60");
+        assert_eq!(eq.font_name, "HYhwpEQ");
+    }
+}
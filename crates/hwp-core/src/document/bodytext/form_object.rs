@@ -1,19 +1,61 @@
 /// FormObject 구조체 / FormObject structure
 ///
 /// **구현 상태 / Implementation Status**
-/// - 구현 완료 / Implementation complete
 /// - 스펙 문서에 상세 구조가 명시되어 있지 않음 / Spec document does not specify detailed structure
-/// - 테스트 파일(`noori.hwp`)에 FORM_OBJECT 레코드가 없어 실제 파일로 테스트되지 않음
-/// - Implementation complete, but not tested with actual file as test file (`noori.hwp`) does not contain FORM_OBJECT records
+/// - 레코드 안의 속성 문자열(`이름:타입:값` 나열)을 찾아 해석하고, 원본 데이터도 그대로 보관
+/// - The property string (a list of `name:type:value`) inside the record is located and decoded;
+///   the raw data is kept as well
 use crate::error::HwpError;
 use serde::{Deserialize, Serialize};
 
+/// 양식 개체 종류 / Form object kind
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FormObjectType {
+    /// 명령 단추 / Push button
+    #[default]
+    PushButton,
+    /// 선택 상자 / Check box
+    CheckBox,
+    /// 라디오 단추 / Radio button
+    RadioButton,
+    /// 목록 상자 / Combo box
+    ComboBox,
+    /// 목록 상자 (펼침) / List box
+    ListBox,
+    /// 입력 상자 / Edit box
+    Edit,
+    /// 스크롤 막대 / Scroll bar
+    ScrollBar,
+}
+
+/// 양식 개체 속성 값 / Form object property value
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FormPropertyValue {
+    Bool(bool),
+    Int(i64),
+    String(String),
+}
+
+/// 양식 개체 속성 / Form object property
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FormProperty {
+    /// 속성 이름 (속한 묶음 이름 없이) / Property name (without the enclosing set name)
+    pub name: String,
+    /// 속성 값 / Property value
+    pub value: FormPropertyValue,
+}
+
 /// 양식 개체 / Form object
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FormObject {
+    /// 양식 개체 종류 / Form object kind
+    pub form_type: FormObjectType,
+    /// 속성 목록 (기록된 순서) / Properties in record order
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub properties: Vec<FormProperty>,
     /// Raw 데이터 / Raw data
-    /// 스펙 문서에 상세 구조가 명시되어 있지 않으므로 raw 데이터로 저장합니다.
-    /// Raw data is stored because the spec document does not specify detailed structure.
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub raw_data: Vec<u8>,
 }
@@ -28,19 +70,216 @@ impl FormObject {
     /// 파싱된 FormObject 구조체 / Parsed FormObject structure
     ///
     /// # Note
-    /// 스펙 문서 표 57에는 "양식 개체"로만 언급되어 있고 상세 구조가 명시되어 있지 않습니다.
-    /// 레거시 코드(hwp.js)도 raw 데이터로만 저장하고 있습니다.
-    /// Spec document Table 57 only mentions "Form object" and does not specify detailed structure.
-    /// Legacy code (hwp.js) also stores only raw data.
-    ///
-    /// **테스트 상태 / Testing Status**
-    /// 현재 테스트 파일(`noori.hwp`)에 FORM_OBJECT 레코드가 없어 실제 파일로 검증되지 않았습니다.
-    /// 실제 HWP 파일에 FORM_OBJECT 레코드가 있으면 자동으로 파싱됩니다.
-    /// Current test file (`noori.hwp`) does not contain FORM_OBJECT records, so it has not been verified with actual files.
-    /// If an actual HWP file contains FORM_OBJECT records, they will be automatically parsed.
+    /// 스펙 문서 표 57에는 "양식 개체"로만 언급되어 있습니다. 레코드에는 앞부분의 식별 정보 뒤에
+    /// `CommonSet:..`으로 시작하는 UTF-16LE 속성 문자열이 들어 있으므로, 그 위치를 찾아 해석합니다.
+    /// 속성 문자열을 찾지 못하면 속성 없이 raw 데이터만 보관합니다.
+    /// Spec document Table 57 only mentions "Form object". After some leading identification data
+    /// the record holds a UTF-16LE property string starting with `CommonSet:..`, which is located
+    /// and decoded. If no property string is found, only the raw data is kept.
     pub fn parse(data: &[u8]) -> Result<Self, HwpError> {
+        let (form_type, properties) = match find_property_string(data) {
+            Some(text) => parse_properties(&text),
+            None => (FormObjectType::default(), Vec::new()),
+        };
         Ok(FormObject {
+            form_type,
+            properties,
             raw_data: data.to_vec(),
         })
     }
+
+    /// 이름으로 속성 값 찾기 / Find a property value by name
+    pub fn property(&self, name: &str) -> Option<&FormPropertyValue> {
+        self.properties
+            .iter()
+            .find(|p| p.name == name)
+            .map(|p| &p.value)
+    }
+
+    /// 문자열 속성 / String property
+    pub fn string(&self, name: &str) -> Option<&str> {
+        match self.property(name)? {
+            FormPropertyValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// 정수 속성 (bool은 0/1) / Integer property (bool as 0/1)
+    pub fn int(&self, name: &str) -> Option<i64> {
+        match self.property(name)? {
+            FormPropertyValue::Int(v) => Some(*v),
+            FormPropertyValue::Bool(b) => Some(*b as i64),
+            FormPropertyValue::String(s) => s.trim().parse().ok(),
+        }
+    }
+
+    /// bool 속성 / Boolean property
+    pub fn bool(&self, name: &str) -> Option<bool> {
+        self.int(name).map(|v| v != 0)
+    }
+}
+
+/// 데이터에서 속성 문자열 찾기 (UTF-16LE, 짝수/홀수 오프셋 모두 시도)
+/// Locate the property string in the data (UTF-16LE, tries both even and odd offsets)
+fn find_property_string(data: &[u8]) -> Option<String> {
+    (0..2).find_map(|offset| {
+        let units: Vec<u16> = data
+            .get(offset..)?
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect();
+        let text = String::from_utf16_lossy(&units);
+        let set = text.find("Set:")?;
+        // 묶음 이름의 시작까지 되돌아감 / Back up to the start of the set name
+        let start = text[..set]
+            .rfind(|c: char| !c.is_ascii_alphanumeric())
+            .map(|i| i + text[i..].chars().next().map_or(1, char::len_utf8))
+            .unwrap_or(0);
+        let end = text.rfind(|c: char| c != '\0').map_or(0, |i| i + 1);
+        (start < end).then(|| text[start..end].to_string())
+    })
+}
+
+/// 속성 문자열 해석 / Decode the property string
+///
+/// 형식 / Format:
+/// - 묶음 / Set: `CommonSet:85:` 또는 `CommonSet:set:85:` (내용이 뒤에 이어짐)
+/// - 문자열 / String: `Name:wstring:6:Button`
+/// - 그 밖 / Others: `TabOrder:int:1`, `Enabled:bool:1`
+///
+/// 항목은 공백으로 구분합니다. / Items are separated by spaces.
+fn parse_properties(text: &str) -> (FormObjectType, Vec<FormProperty>) {
+    let chars: Vec<char> = text.chars().collect();
+    let mut pos = 0;
+    let mut sets: Vec<String> = Vec::new();
+    let mut properties = Vec::new();
+
+    // 다음 ':'까지 읽기 / Read up to the next ':'
+    let token = |pos: &mut usize| -> Option<String> {
+        let start = *pos;
+        while *pos < chars.len() && chars[*pos] != ':' {
+            if chars[*pos] == ' ' {
+                return None;
+            }
+            *pos += 1;
+        }
+        if *pos >= chars.len() {
+            return None;
+        }
+        let s: String = chars[start..*pos].iter().collect();
+        *pos += 1;
+        Some(s)
+    };
+
+    while pos < chars.len() {
+        if chars[pos] == ' ' || chars[pos] == '\0' {
+            pos += 1;
+            continue;
+        }
+        let Some(name) = token(&mut pos) else {
+            break;
+        };
+        let Some(kind) = token(&mut pos) else {
+            break;
+        };
+        if name.ends_with("Set") {
+            // 길이 앞에 "set" 타입이 붙는 경우 / Optional "set" type before the length
+            if kind == "set" && token(&mut pos).is_none() {
+                break;
+            }
+            sets.push(name);
+            continue;
+        }
+        let value = match kind.as_str() {
+            "wstring" => {
+                let Some(len) = token(&mut pos).and_then(|l| l.parse::<usize>().ok()) else {
+                    break;
+                };
+                let end = (pos + len).min(chars.len());
+                let s: String = chars[pos..end].iter().collect();
+                pos = end;
+                FormPropertyValue::String(s)
+            }
+            _ => {
+                let start = pos;
+                while pos < chars.len() && chars[pos] != ' ' {
+                    pos += 1;
+                }
+                let raw: String = chars[start..pos].iter().collect();
+                match kind.as_str() {
+                    "bool" => FormPropertyValue::Bool(raw.trim() != "0"),
+                    _ => match raw.trim().parse::<i64>() {
+                        Ok(v) => FormPropertyValue::Int(v),
+                        Err(_) => FormPropertyValue::String(raw),
+                    },
+                }
+            }
+        };
+        properties.push(FormProperty { name, value });
+    }
+
+    (form_type_from_sets(&sets), properties)
+}
+
+/// 묶음 이름으로 양식 개체 종류 판단 / Determine the form kind from set names
+fn form_type_from_sets(sets: &[String]) -> FormObjectType {
+    let has = |key: &str| sets.iter().any(|s| s.contains(key));
+    if has("Edit") {
+        FormObjectType::Edit
+    } else if has("ComboBox") {
+        FormObjectType::ComboBox
+    } else if has("ListBox") {
+        FormObjectType::ListBox
+    } else if has("ScrollBar") {
+        FormObjectType::ScrollBar
+    } else if has("Radio") {
+        FormObjectType::RadioButton
+    } else if has("CheckBox") {
+        FormObjectType::CheckBox
+    } else {
+        FormObjectType::PushButton
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(prefix: &[u8], text: &str) -> Vec<u8> {
+        let mut data = prefix.to_vec();
+        for unit in text.encode_utf16() {
+            data.extend_from_slice(&unit.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn decodes_check_box_properties() {
+        let text = "CommonSet:set:60:Name:wstring:5:agree ForeColor:int:0 Enabled:bool:1 \
+                    CheckBoxSet:set:40:Caption:wstring:8:동의합니다 있음 Value:int:1";
+        let data = record(b"+cbt\x01\x00\x00\x00\x50\x00", text);
+        let form = FormObject::parse(&data).unwrap();
+        assert_eq!(form.form_type, FormObjectType::CheckBox);
+        assert_eq!(form.string("Name"), Some("agree"));
+        assert_eq!(form.string("Caption"), Some("동의합니다 있음"));
+        assert_eq!(form.int("Value"), Some(1));
+        assert_eq!(form.bool("Enabled"), Some(true));
+        assert_eq!(form.raw_data, data);
+    }
+
+    #[test]
+    fn decodes_edit_text_at_odd_offset() {
+        let text = "CommonSet:30:Name:wstring:4:addr EditSet:20:Text:wstring:9:서울시 종로구 1 MaxLength:int:40";
+        let form = FormObject::parse(&record(b"\x07", text)).unwrap();
+        assert_eq!(form.form_type, FormObjectType::Edit);
+        assert_eq!(form.string("Text"), Some("서울시 종로구 1"));
+        assert_eq!(form.int("MaxLength"), Some(40));
+    }
+
+    #[test]
+    fn keeps_raw_data_without_property_string() {
+        let form = FormObject::parse(&[1, 2, 3, 4]).unwrap();
+        assert!(form.properties.is_empty());
+        assert_eq!(form.raw_data, vec![1, 2, 3, 4]);
+    }
 }
@@ -5,7 +5,9 @@
 /// - 스펙 문서에 상세 구조가 명시되어 있지 않음 / Spec document does not specify detailed structure
 /// - 테스트 파일(`noori.hwp`)에 MEMO_LIST 레코드가 없어 실제 파일로 테스트되지 않음
 /// - Implementation complete, but not tested with actual file as test file (`noori.hwp`) does not contain MEMO_LIST records
+use super::Paragraph;
 use crate::error::HwpError;
+use crate::types::UINT32;
 use serde::{Deserialize, Serialize};
 
 /// 메모 리스트 헤더 / Memo list header
@@ -43,4 +45,23 @@ impl MemoList {
             raw_data: data.to_vec(),
         })
     }
+
+    /// 메모 인덱스 (앞 4바이트, 메모 필드의 메모 인덱스와 대응) / Memo index (first 4 bytes)
+    pub fn memo_index(&self) -> Option<UINT32> {
+        self.raw_data
+            .get(0..4)
+            .map(|b| UINT32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    }
+}
+
+/// 메모 / Memo
+///
+/// 구역 끝(마지막 문단 뒤)에 MEMO_LIST 레코드와 메모 내용 문단들이 이어서 저장됩니다.
+/// Memos are stored after the last paragraph of a section: a MEMO_LIST record followed by its paragraphs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Memo {
+    /// 메모 리스트 헤더 / Memo list header
+    pub memo_list: MemoList,
+    /// 메모 내용 문단 / Memo content paragraphs
+    pub paragraphs: Vec<Paragraph>,
 }
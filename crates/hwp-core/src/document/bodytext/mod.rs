@@ -33,10 +33,10 @@ pub use ctrl_data::CtrlData;
 pub use ctrl_header::{CtrlHeader, CtrlHeaderData, CtrlId, Margin, PageNumberPosition};
 pub use eqedit::EqEdit;
 pub use footnote_shape::{FootnoteShape, NumberShape};
-pub use form_object::FormObject;
+pub use form_object::{FormObject, FormObjectType, FormProperty, FormPropertyValue};
 pub use line_seg::{LineSegmentInfo, ParaLineSeg};
 pub use list_header::ListHeader;
-pub use memo_list::MemoList;
+pub use memo_list::{Memo, MemoList};
 pub use memo_shape::MemoShape;
 pub use page_border_fill::PageBorderFill;
 pub use page_def::{PageDef, PaperDirection};
@@ -83,6 +83,9 @@ pub struct Section {
     pub index: WORD,
     /// Paragraphs in this section
     pub paragraphs: Vec<Paragraph>,
+    /// 구역 끝에 저장된 메모 / Memos stored at the end of this section
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub memos: Vec<Memo>,
 }
 
 /// Paragraph structure
@@ -329,18 +332,51 @@ impl Section {
     /// # Returns
     /// 파싱된 Paragraph 리스트 / Parsed paragraph list
     pub fn parse_data(data: &[u8], version: u32) -> Result<Vec<Paragraph>, HwpError> {
+        Ok(Self::parse_data_with_memos(data, version)?.0)
+    }
+
+    /// Section 데이터를 본문 문단과 메모로 파싱합니다. / Parse section data into body paragraphs and memos.
+    ///
+    /// 첫 MEMO_LIST 이후의 최상위 문단은 직전 메모의 내용으로 봅니다.
+    /// Top-level paragraphs after the first MEMO_LIST belong to the preceding memo.
+    pub fn parse_data_with_memos(
+        data: &[u8],
+        version: u32,
+    ) -> Result<(Vec<Paragraph>, Vec<Memo>), HwpError> {
         // 먼저 레코드를 트리 구조로 파싱 / First parse records into tree structure
         let tree = RecordTreeNode::parse_tree(data)?;
 
         // 트리를 재귀적으로 방문하여 Paragraph 리스트로 변환 / Recursively visit tree to convert to Paragraph list
         let mut paragraphs = Vec::new();
+        let mut memos: Vec<Memo> = Vec::new();
         for child in tree.children() {
-            if child.tag_id() == HwpTag::PARA_HEADER {
-                paragraphs.push(Self::parse_paragraph_from_tree(child, version, data)?);
+            match child.tag_id() {
+                HwpTag::PARA_HEADER => {
+                    let paragraph = Self::parse_paragraph_from_tree(child, version, data)?;
+                    match memos.last_mut() {
+                        Some(memo) => memo.paragraphs.push(paragraph),
+                        None => paragraphs.push(paragraph),
+                    }
+                }
+                HwpTag::MEMO_LIST => {
+                    let mut memo = Memo {
+                        memo_list: MemoList::parse(child.data())?,
+                        paragraphs: Vec::new(),
+                    };
+                    // 메모 문단이 MEMO_LIST의 자식으로 저장된 경우 / Memo paragraphs nested under MEMO_LIST
+                    for grandchild in child.children() {
+                        if grandchild.tag_id() == HwpTag::PARA_HEADER {
+                            memo.paragraphs
+                                .push(Self::parse_paragraph_from_tree(grandchild, version, data)?);
+                        }
+                    }
+                    memos.push(memo);
+                }
+                _ => {}
             }
         }
 
-        Ok(paragraphs)
+        Ok((paragraphs, memos))
     }
 
     /// 트리 노드에서 Paragraph를 파싱합니다. / Parse Paragraph from tree node.
@@ -839,6 +875,41 @@ impl Section {
         })
     }
 
+    /// LIST_HEADER 뒤의 형제 PARA_HEADER들을 문단 리스트로 파싱합니다.
+    /// Parse sibling PARA_HEADERs following a LIST_HEADER as its paragraph list.
+    ///
+    /// LIST_HEADER의 문단은 같은 레벨의 형제 레코드로 저장되므로, 원본 데이터에서 같은 바이트의
+    /// LIST_HEADER를 찾는 방식은 동일한 헤더가 여러 개일 때 엉뚱한 문단을 가져옵니다.
+    /// Searching original data by LIST_HEADER bytes picks the wrong list when headers are identical.
+    ///
+    /// # Returns
+    /// 형제에서 paragraph_count만큼 찾은 경우 문단 목록, 아니면 None
+    /// Paragraphs if paragraph_count siblings were found, None otherwise
+    fn parse_sibling_paragraphs(
+        list_header: &ParagraphRecord,
+        siblings: &[&RecordTreeNode],
+        version: u32,
+        original_data: &[u8],
+    ) -> Result<Option<Vec<super::Paragraph>>, HwpError> {
+        let ParagraphRecord::ListHeader { header, .. } = list_header else {
+            return Ok(None);
+        };
+        let expected = header.paragraph_count.max(0) as usize;
+        let paragraph_nodes: Vec<_> = siblings
+            .iter()
+            .take_while(|sibling| sibling.tag_id() == HwpTag::PARA_HEADER)
+            .take(expected)
+            .collect();
+        if expected == 0 || paragraph_nodes.len() != expected {
+            return Ok(None);
+        }
+        paragraph_nodes
+            .into_iter()
+            .map(|node| Self::parse_paragraph_from_tree(node, version, original_data))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Some)
+    }
+
     /// 테이블 캡션을 파싱합니다. / Parse table caption.
     ///
     /// TABLE가 있는 CTRL_HEADER 내의 첫 번째 LIST_HEADER를 찾아 캡션으로 파싱합니다.
@@ -1064,7 +1135,7 @@ impl Section {
                         let list_header_record =
                             Self::parse_record_from_tree(child, version, original_data)?;
                         // 테이블 셀로 처리하기 위해 paragraphs 추출 / Extract paragraphs for table cell processing
-                        let paragraphs_for_cell = if let ParagraphRecord::ListHeader {
+                        let mut paragraphs_for_cell = if let ParagraphRecord::ListHeader {
                             header: _,
                             paragraphs,
                         } = &list_header_record
@@ -1074,6 +1145,19 @@ impl Section {
                             Vec::new()
                         };
 
+                        // 셀 문단은 LIST_HEADER 바로 뒤의 형제 PARA_HEADER들이다.
+                        // 원본 데이터 검색은 LIST_HEADER 데이터가 같은 셀(다른 표의 같은 위치/크기 셀 등)을
+                        // 구분하지 못하므로, 형제에서 paragraph_count만큼 찾을 수 있으면 그것을 사용한다.
+                        // Cell paragraphs are the sibling PARA_HEADERs right after the LIST_HEADER.
+                        if let Some(sibling_paragraphs) = Self::parse_sibling_paragraphs(
+                            &list_header_record,
+                            &children_slice[idx + 1..],
+                            version,
+                            original_data,
+                        )? {
+                            paragraphs_for_cell = sibling_paragraphs;
+                        }
+
                         // libhwp 방식: TABLE 이전/이후의 LIST_HEADER 모두 children에 추가하지 않음
                         // libhwp approach: Don't add LIST_HEADERs (before or after TABLE) to children
                         // TABLE 이전의 LIST_HEADER는 캡션으로 별도 처리되고,
@@ -1169,7 +1253,20 @@ impl Section {
                             cell_attrs_opt,
                         ));
                     } else {
-                        children.push(Self::parse_record_from_tree(child, version, original_data)?);
+                        let mut record =
+                            Self::parse_record_from_tree(child, version, original_data)?;
+                        // 머리말/꼬리말/각주/미주 등의 LIST_HEADER도 형제 PARA_HEADER를 우선 사용
+                        if let Some(sibling_paragraphs) = Self::parse_sibling_paragraphs(
+                            &record,
+                            &children_slice[idx + 1..],
+                            version,
+                            original_data,
+                        )? {
+                            if let ParagraphRecord::ListHeader { paragraphs, .. } = &mut record {
+                                *paragraphs = sibling_paragraphs;
+                            }
+                        }
+                        children.push(record);
                     }
                 }
 
@@ -1923,11 +2020,13 @@ impl BodyText {
                     }
 
                     // Section 데이터를 Paragraph 리스트로 파싱 / Parse section data into paragraph list
-                    let paragraphs = Section::parse_data(&section_data, file_header.version)?;
+                    let (paragraphs, memos) =
+                        Section::parse_data_with_memos(&section_data, file_header.version)?;
 
                     sections.push(Section {
                         index: i,
                         paragraphs,
+                        memos,
                     });
                 }
                 Err(e) => {
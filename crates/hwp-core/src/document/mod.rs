@@ -13,6 +13,7 @@ pub mod preview_image;
 pub mod preview_image_test;
 pub mod preview_text;
 pub mod preview_text_test;
+pub mod raw;
 pub mod scripts;
 pub mod summary_information;
 pub mod summary_information_test;
@@ -31,6 +32,7 @@ pub use docinfo::{
 pub use fileheader::FileHeader;
 pub use preview_image::PreviewImage;
 pub use preview_text::PreviewText;
+pub use raw::{RawDocument, RawRecord, RawStream};
 pub use scripts::Scripts;
 pub use summary_information::SummaryInformation;
 pub use xml_template::XmlTemplate;
@@ -61,6 +63,10 @@ pub struct HwpDocument {
     pub xml_template: Option<XmlTemplate>,
     /// Summary Information (\005HwpSummaryInformation stream)
     pub summary_information: Option<SummaryInformation>,
+    /// Raw records and streams (only with `HwpParser::preserve_raw_records`)
+    /// 원본 레코드/스트림 (`HwpParser::preserve_raw_records` 사용 시에만)
+    #[serde(skip)]
+    pub raw: Option<RawDocument>,
 }
 
 impl HwpDocument {
@@ -76,6 +82,7 @@ impl HwpDocument {
             scripts: None,
             xml_template: None,
             summary_information: None,
+            raw: None,
         }
     }
 
@@ -95,6 +102,9 @@ impl HwpDocument {
             use_html: options.use_html.unwrap_or(false),
             include_version: options.include_version,
             include_page_info: options.include_page_info,
+            revision_view: Default::default(),
+            evaluate_fields: true,
+            table_of_contents: false,
         };
         crate::viewer::doc_markdown::doc_to_markdown(&document, &doc_options)
     }
@@ -134,6 +144,10 @@ impl HwpDocument {
             inline_style: true,
             image_output_dir: options.image_output_dir.clone(),
             layout: options.layout,
+            keep_metafile: options.keep_metafile,
+            revision_view: options.revision_view,
+            evaluate_fields: true,
+            table_of_contents: false,
         };
         crate::viewer::doc_html::doc_to_html(&document, &doc_options)
     }
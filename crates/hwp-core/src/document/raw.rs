@@ -0,0 +1,190 @@
+/// 원본 레코드 보존 / Raw record preservation
+///
+/// `HwpParser::preserve_raw_records(true)`로 파싱하면 DocInfo와 BodyText/SectionN의 모든 레코드를
+/// 헤더와 데이터 그대로 보관하고, 나머지 스트림은 저장된 바이트 그대로 보관합니다.
+/// 파서가 모델링하지 않는 태그나 레코드 뒤쪽의 남는 바이트도 함께 유지되므로
+/// `HwpWriter::write_raw`로 압축 여부를 제외하고 원본과 같은 바이트로 다시 쓸 수 있습니다.
+/// 레코드로 풀 수 없는 DocInfo/BodyText(암호 문서 등)는 저장된 바이트 그대로 `streams`에 둡니다.
+///
+/// When parsed with `HwpParser::preserve_raw_records(true)`, every record of DocInfo and
+/// BodyText/SectionN is kept with its header and payload, and all other streams are kept as stored.
+/// Unknown tags and trailing bytes survive, so `HwpWriter::write_raw` can re-serialize the
+/// document byte-identically (modulo compression). DocInfo/BodyText streams that cannot be split
+/// into records (e.g. encrypted documents) are kept as stored in `streams`.
+use super::HwpDocument;
+use crate::decompress::decompress_deflate;
+use crate::error::HwpError;
+use crate::types::RecordHeader;
+use cfb::CompoundFile;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::io::{Cursor, Read};
+
+/// 레코드 헤더와 데이터 / Record header and payload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawRecord {
+    /// 레코드 헤더 / Record header
+    pub header: RecordHeader,
+    /// 레코드 데이터 / Record payload
+    pub data: Vec<u8>,
+}
+
+impl RawRecord {
+    /// 레코드 스트림(압축 해제된 데이터)을 레코드 목록으로 분리합니다.
+    /// Split a (decompressed) record stream into records.
+    pub fn parse_stream(data: &[u8]) -> Result<Vec<RawRecord>, HwpError> {
+        let mut records = Vec::new();
+        let mut offset = 0;
+        while offset < data.len() {
+            let (header, header_size) = RecordHeader::parse(&data[offset..])?;
+            offset += header_size;
+            let end = offset + header.size as usize;
+            if end > data.len() {
+                return Err(HwpError::InsufficientData {
+                    field: format!("Record at offset {}", offset),
+                    expected: end,
+                    actual: data.len(),
+                });
+            }
+            records.push(RawRecord {
+                header,
+                data: data[offset..end].to_vec(),
+            });
+            offset = end;
+        }
+        Ok(records)
+    }
+
+    /// 레코드 목록을 스트림 바이트로 직렬화합니다. / Serialize records into stream bytes.
+    pub fn write_stream(records: &[RawRecord]) -> Vec<u8> {
+        let mut out = Vec::new();
+        for record in records {
+            out.extend_from_slice(&record.header.to_bytes());
+            out.extend_from_slice(&record.data);
+        }
+        out
+    }
+}
+
+/// 저장된 바이트 그대로의 스트림 / Stream kept as stored
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawStream {
+    /// CFB 경로 (예: "/BinData/BIN0001.png") / CFB path
+    pub path: String,
+    /// 스트림 데이터 / Stream data
+    pub data: Vec<u8>,
+}
+
+/// 원본 레코드/스트림 보관소 / Raw record and stream store
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RawDocument {
+    /// DocInfo 레코드 / DocInfo records
+    pub doc_info: Vec<RawRecord>,
+    /// BodyText/SectionN 레코드 (구역 순서) / BodyText/SectionN records (in section order)
+    pub sections: Vec<Vec<RawRecord>>,
+    /// 그 밖의 모든 스트림 (FileHeader, BinData, PrvText, ViewText 등)
+    /// All other streams (FileHeader, BinData, PrvText, ViewText, ...)
+    pub streams: Vec<RawStream>,
+    /// 파싱 직후 HwpDocument의 요약값 (수정 여부 확인용)
+    /// Digest of the HwpDocument right after parsing (to detect edits)
+    pub(crate) digest: u64,
+}
+
+impl RawDocument {
+    /// CFB의 모든 스트림을 읽어 보관합니다.
+    /// Read and keep every stream of the CFB.
+    ///
+    /// # Arguments
+    /// * `cfb` - CompoundFile 구조체 / CompoundFile structure
+    /// * `compressed` - DocInfo/BodyText 압축 여부 / Whether DocInfo/BodyText are compressed
+    pub fn read(
+        cfb: &mut CompoundFile<Cursor<&[u8]>>,
+        compressed: bool,
+    ) -> Result<RawDocument, HwpError> {
+        let paths: Vec<String> = cfb
+            .walk()
+            .filter(|entry| entry.is_stream())
+            .map(|entry| entry.path().to_string_lossy().to_string())
+            .collect();
+
+        let record_stream = |data: Vec<u8>| -> Result<Vec<RawRecord>, HwpError> {
+            let data = if compressed {
+                decompress_deflate(&data)?
+            } else {
+                data
+            };
+            RawRecord::parse_stream(&data)
+        };
+
+        let mut raw = RawDocument::default();
+        let mut record_streams = Vec::new();
+        for path in paths {
+            let data = Self::read_path(cfb, &path)?;
+            if path == "/DocInfo" || section_index(&path).is_some() {
+                record_streams.push(RawStream { path, data });
+            } else {
+                raw.streams.push(RawStream { path, data });
+            }
+        }
+
+        let decoded: Result<Vec<Vec<RawRecord>>, HwpError> = record_streams
+            .iter()
+            .map(|stream| record_stream(stream.data.clone()))
+            .collect();
+        let Ok(decoded) = decoded else {
+            // 하나라도 풀 수 없으면 (암호 문서 등) 레코드 스트림 모두 저장된 바이트로 보관
+            raw.streams.extend(record_streams);
+            return Ok(raw);
+        };
+        let mut sections = Vec::new();
+        for (stream, records) in record_streams.into_iter().zip(decoded) {
+            match section_index(&stream.path) {
+                Some(index) => sections.push((index, records)),
+                None => raw.doc_info = records,
+            }
+        }
+        sections.sort_by_key(|(index, _)| *index);
+        raw.sections = sections.into_iter().map(|(_, records)| records).collect();
+        Ok(raw)
+    }
+
+    /// 파싱 직후 상태를 기록합니다 / Record the document state right after parsing
+    pub(crate) fn seal(&mut self, document: &HwpDocument) {
+        self.digest = digest(document);
+    }
+
+    /// 파싱 후 HwpDocument가 바뀌지 않았는지 / Whether the HwpDocument is unchanged since parsing
+    pub fn matches(&self, document: &HwpDocument) -> bool {
+        self.digest == digest(document)
+    }
+
+    fn read_path(cfb: &mut CompoundFile<Cursor<&[u8]>>, path: &str) -> Result<Vec<u8>, HwpError> {
+        let mut stream = cfb
+            .open_stream(path)
+            .map_err(|e| HwpError::stream_read_error(path, e.to_string()))?;
+        let mut buffer = Vec::new();
+        stream
+            .read_to_end(&mut buffer)
+            .map_err(|e| HwpError::stream_read_error(path, e.to_string()))?;
+        Ok(buffer)
+    }
+
+    /// 이름으로 보관된 스트림 찾기 / Find a kept stream by path
+    pub fn stream(&self, path: &str) -> Option<&RawStream> {
+        self.streams.iter().find(|stream| stream.path == path)
+    }
+}
+
+/// 타입 문서(원본 보관소 제외)의 JSON 요약값
+fn digest(document: &HwpDocument) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    serde_json::to_vec(document)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    hasher.finish()
+}
+
+/// "/BodyText/SectionN" → N
+fn section_index(path: &str) -> Option<usize> {
+    path.strip_prefix("/BodyText/Section")?.parse().ok()
+}
@@ -80,6 +80,24 @@ pub enum HwpError {
     #[error("Invalid HWP document signature: expected 'HWP Document File', got '{found}'")]
     InvalidSignature { found: String },
 
+    /// Document is password protected (password decryption is not supported)
+    #[error("Document is password protected: password protected documents are not supported")]
+    PasswordRequired,
+
+    // ===== Writer errors =====
+    /// Raw records were not kept when the document was parsed
+    #[error("Raw records were not preserved: parse with HwpParser::preserve_raw_records(true)")]
+    RawRecordsNotPreserved,
+
+    /// The parsed document was modified, so its raw records no longer match it
+    #[error("Document was modified after parsing: raw records are out of date, write it with HwpWriter::write")]
+    RawRecordsOutdated,
+
+    // ===== Mail merge errors =====
+    /// Mail merge data source (CSV/JSON) could not be read
+    #[error("Failed to read mail merge {format} data: {reason}")]
+    DataSourceError { format: String, reason: String },
+
     // ===== Other errors =====
     /// IO error
     #[error("IO error: {0}")]
@@ -154,6 +172,14 @@ impl HwpError {
         }
     }
 
+    /// Create a `DataSourceError` error
+    pub fn data_source(format: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::DataSourceError {
+            format: format.into(),
+            reason: reason.into(),
+        }
+    }
+
     /// Create a `DecompressError` error
     pub fn decompress_error(format: CompressionFormat, reason: impl Into<String>) -> Self {
         Self::DecompressError {
@@ -186,6 +212,13 @@ impl From<std::io::Error> for HwpError {
     }
 }
 
+/// Conversion from zip::result::ZipError to HwpError
+impl From<zip::result::ZipError> for HwpError {
+    fn from(err: zip::result::ZipError) -> Self {
+        HwpError::Io(err.to_string())
+    }
+}
+
 /// Conversion from serde_json::Error to HwpError
 impl From<serde_json::Error> for HwpError {
     fn from(err: serde_json::Error) -> Self {
@@ -0,0 +1,518 @@
+/// TrueType(glyf) 글꼴 읽기와 서브셋 / TrueType (glyf) font parsing and subsetting
+///
+/// 레이아웃의 글자 폭 측정과 PDF 글꼴 임베딩이 함께 씁니다.
+/// PDF 임베딩에 필요한 테이블만 읽습니다: name, head, hhea, maxp, hmtx, OS/2, post, cmap, loca, glyf.
+/// CFF 윤곽선 글꼴(.otf)은 CIDFontType2로 넣을 수 없으므로 읽지 않습니다.
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+use std::sync::Arc;
+
+/// 글꼴 디렉터리에서 읽는 확장자 / Font file extensions read from the font directory
+/// (.otf/.otc의 CFF 글꼴은 래스터라이저만 쓰고 [`TrueTypeFont`]는 건너뜀)
+const FONT_EXTENSIONS: [&str; 4] = ["ttf", "ttc", "otf", "otc"];
+
+/// 서브셋에 그대로 복사하는 힌팅/메트릭 테이블
+const COPIED_TABLES: [&[u8; 4]; 6] = [b"hhea", b"maxp", b"hmtx", b"cvt ", b"fpgm", b"prep"];
+
+/// 읽어 들인 TrueType 글꼴 하나 / One parsed TrueType font
+pub(crate) struct TrueTypeFont {
+    data: Arc<[u8]>,
+    /// (태그, 오프셋, 길이)
+    tables: Vec<([u8; 4], usize, usize)>,
+    /// 비교용으로 정규화한 글꼴 이름들 (패밀리/전체 이름, 모든 언어)
+    pub(crate) families: Vec<String>,
+    pub(super) postscript_name: String,
+    pub(crate) bold: bool,
+    pub(crate) italic: bool,
+    pub(super) units_per_em: u16,
+    pub(super) bbox: [i16; 4],
+    pub(super) ascent: i16,
+    pub(super) descent: i16,
+    pub(super) cap_height: i16,
+    pub(super) italic_angle: f64,
+    num_glyphs: u16,
+    long_loca: bool,
+    advances: Vec<u16>,
+    cmap: HashMap<u32, u16>,
+}
+
+impl TrueTypeFont {
+    /// 파일 하나(.ttf 또는 .ttc)의 글꼴들을 읽음 — 읽을 수 없는 글꼴은 건너뜀
+    pub(super) fn parse_file(data: Vec<u8>) -> Vec<TrueTypeFont> {
+        let data: Arc<[u8]> = data.into();
+        if data.get(0..4) == Some(b"ttcf") {
+            let count = read_u32(&data, 8).unwrap_or(0) as usize;
+            (0..count.min(64))
+                .filter_map(|i| read_u32(&data, 12 + i * 4))
+                .filter_map(|offset| Self::parse(data.clone(), offset as usize))
+                .collect()
+        } else {
+            Self::parse(data, 0).into_iter().collect()
+        }
+    }
+
+    fn parse(data: Arc<[u8]>, offset: usize) -> Option<TrueTypeFont> {
+        let num_tables = read_u16(&data, offset + 4)? as usize;
+        let mut tables = Vec::with_capacity(num_tables);
+        for i in 0..num_tables {
+            let record = offset + 12 + i * 16;
+            let tag: [u8; 4] = data.get(record..record + 4)?.try_into().ok()?;
+            let table_offset = read_u32(&data, record + 8)? as usize;
+            let length = read_u32(&data, record + 12)? as usize;
+            if table_offset.checked_add(length)? > data.len() {
+                return None;
+            }
+            tables.push((tag, table_offset, length));
+        }
+
+        let bytes = data.clone();
+        let table = |tag: &[u8; 4]| table_in(&bytes, &tables, tag);
+        // glyf 윤곽선이 없으면(CFF 등) 사용하지 않음
+        table(b"glyf")?;
+        table(b"loca")?;
+
+        let mut font = TrueTypeFont {
+            data,
+            tables: tables.clone(),
+            families: Vec::new(),
+            postscript_name: String::new(),
+            bold: false,
+            italic: false,
+            units_per_em: 1000,
+            bbox: [0; 4],
+            ascent: 0,
+            descent: 0,
+            cap_height: 0,
+            italic_angle: 0.0,
+            num_glyphs: 0,
+            long_loca: false,
+            advances: Vec::new(),
+            cmap: HashMap::new(),
+        };
+        let head = table(b"head")?;
+        font.units_per_em = read_u16(head, 18)?.max(16);
+        font.bbox = [
+            read_i16(head, 36)?,
+            read_i16(head, 38)?,
+            read_i16(head, 40)?,
+            read_i16(head, 42)?,
+        ];
+        let mac_style = read_u16(head, 44)?;
+        font.long_loca = read_i16(head, 50)? != 0;
+        font.bold = mac_style & 0x01 != 0;
+        font.italic = mac_style & 0x02 != 0;
+
+        let hhea = table(b"hhea")?;
+        font.ascent = read_i16(hhea, 4)?;
+        font.descent = read_i16(hhea, 6)?;
+        let metric_count = read_u16(hhea, 34)? as usize;
+        font.num_glyphs = read_u16(table(b"maxp")?, 4)?;
+
+        let hmtx = table(b"hmtx")?;
+        let mut advances = Vec::with_capacity(font.num_glyphs as usize);
+        let mut last = 0;
+        for gid in 0..font.num_glyphs as usize {
+            if gid < metric_count {
+                last = read_u16(hmtx, gid * 4).unwrap_or(last);
+            }
+            advances.push(last);
+        }
+        font.advances = advances;
+
+        if let Some(os2) = table(b"OS/2") {
+            let weight = read_u16(os2, 4).unwrap_or(400);
+            let selection = read_u16(os2, 62).unwrap_or(0);
+            font.bold |= weight >= 600 || selection & 0x20 != 0;
+            font.italic |= selection & 0x01 != 0;
+            if read_u16(os2, 0).unwrap_or(0) >= 2 {
+                font.cap_height = read_i16(os2, 88).unwrap_or(0);
+            }
+        }
+        if font.cap_height == 0 {
+            font.cap_height = font.ascent;
+        }
+        if let Some(post) = table(b"post") {
+            font.italic_angle = read_i32(post, 4).unwrap_or(0) as f64 / 65536.0;
+        }
+
+        font.cmap = font.parse_cmap().unwrap_or_default();
+        font.parse_names();
+        if font.postscript_name.is_empty() {
+            font.postscript_name = font.families.first().cloned().unwrap_or_default();
+        }
+        Some(font)
+    }
+
+    fn table(&self, tag: &[u8; 4]) -> Option<&[u8]> {
+        table_in(&self.data, &self.tables, tag)
+    }
+
+    /// 글자 → glyph id (없으면 None)
+    pub(crate) fn glyph(&self, ch: char) -> Option<u16> {
+        self.cmap.get(&(ch as u32)).copied().filter(|&gid| gid != 0)
+    }
+
+    /// glyph 전진 폭 (1000 단위) / Glyph advance in 1/1000 em
+    pub(crate) fn advance(&self, gid: u16) -> f64 {
+        let advance = self
+            .advances
+            .get(gid as usize)
+            .or(self.advances.last())
+            .copied()
+            .unwrap_or(0);
+        advance as f64 * 1000.0 / self.units_per_em as f64
+    }
+
+    /// 1000 단위로 환산 / Scale a font unit value to 1/1000 em
+    pub(super) fn scale(&self, value: i16) -> f64 {
+        value as f64 * 1000.0 / self.units_per_em as f64
+    }
+
+    fn parse_cmap(&self) -> Option<HashMap<u32, u16>> {
+        let cmap = self.table(b"cmap")?;
+        let count = read_u16(cmap, 2)? as usize;
+        // (우선순위, 서브테이블 오프셋) — 전체 유니코드(format 12)를 BMP(format 4)보다 우선
+        let mut best: Option<(u8, usize)> = None;
+        for i in 0..count {
+            let record = 4 + i * 8;
+            let platform = read_u16(cmap, record)?;
+            let encoding = read_u16(cmap, record + 2)?;
+            let offset = read_u32(cmap, record + 4)? as usize;
+            let format = read_u16(cmap, offset).unwrap_or(0);
+            let rank = match (platform, encoding, format) {
+                (3, 10, 12) | (0, _, 12) => 4,
+                (3, 1, 4) => 3,
+                (0, _, 4) => 2,
+                (3, 0, 4) => 1,
+                _ => continue,
+            };
+            if best.map_or(true, |(r, _)| rank > r) {
+                best = Some((rank, offset));
+            }
+        }
+        let (rank, offset) = best?;
+        let sub = cmap.get(offset..)?;
+        let mut map = HashMap::new();
+        if read_u16(sub, 0)? == 12 {
+            let groups = read_u32(sub, 12)? as usize;
+            for g in 0..groups {
+                let start = read_u32(sub, 16 + g * 12)?;
+                let end = read_u32(sub, 20 + g * 12)?;
+                let start_gid = read_u32(sub, 24 + g * 12)?;
+                for code in start..=end.min(start.saturating_add(0x10000)) {
+                    map.insert(code, (start_gid + (code - start)) as u16);
+                }
+            }
+        } else {
+            let seg_count = read_u16(sub, 6)? as usize / 2;
+            let ends = 14;
+            let starts = ends + seg_count * 2 + 2;
+            let deltas = starts + seg_count * 2;
+            let ranges = deltas + seg_count * 2;
+            for s in 0..seg_count {
+                let end = read_u16(sub, ends + s * 2)? as u32;
+                let start = read_u16(sub, starts + s * 2)? as u32;
+                let delta = read_u16(sub, deltas + s * 2)?;
+                let range = read_u16(sub, ranges + s * 2)? as usize;
+                for code in start..=end.min(0xFFFE) {
+                    let gid = if range == 0 {
+                        (code as u16).wrapping_add(delta)
+                    } else {
+                        let at = ranges + s * 2 + range + (code - start) as usize * 2;
+                        match read_u16(sub, at) {
+                            Some(0) | None => 0,
+                            Some(g) => g.wrapping_add(delta),
+                        }
+                    };
+                    if gid != 0 {
+                        map.insert(code, gid);
+                        // 심볼 글꼴(3,0)은 0xF000 영역에 매핑되어 있음
+                        if rank == 1 && (0xF020..=0xF0FF).contains(&code) {
+                            map.entry(code - 0xF000).or_insert(gid);
+                        }
+                    }
+                }
+            }
+        }
+        Some(map)
+    }
+
+    fn parse_names(&mut self) {
+        let Some(name) = self.table(b"name") else {
+            return;
+        };
+        let count = read_u16(name, 2).unwrap_or(0) as usize;
+        let storage = read_u16(name, 4).unwrap_or(0) as usize;
+        let mut families = Vec::new();
+        let mut postscript = String::new();
+        for i in 0..count {
+            let record = 6 + i * 12;
+            let (Some(platform), Some(name_id), Some(length), Some(offset)) = (
+                read_u16(name, record),
+                read_u16(name, record + 6),
+                read_u16(name, record + 8),
+                read_u16(name, record + 10),
+            ) else {
+                break;
+            };
+            if !matches!(name_id, 1 | 4 | 6 | 16) {
+                continue;
+            }
+            let start = storage + offset as usize;
+            let Some(bytes) = name.get(start..start + length as usize) else {
+                continue;
+            };
+            let text = match platform {
+                0 | 3 => String::from_utf16_lossy(
+                    &bytes
+                        .chunks_exact(2)
+                        .map(|c| u16::from_be_bytes([c[0], c[1]]))
+                        .collect::<Vec<_>>(),
+                ),
+                1 => bytes.iter().map(|&b| b as char).collect(),
+                _ => continue,
+            };
+            if name_id == 6 {
+                if postscript.is_empty() {
+                    postscript = text;
+                }
+            } else {
+                let normalized = normalize_family(&text);
+                if !normalized.is_empty() && !families.contains(&normalized) {
+                    families.push(normalized);
+                }
+            }
+        }
+        self.families = families;
+        self.postscript_name = postscript;
+    }
+
+    fn glyph_data(&self, gid: u16) -> &[u8] {
+        let (Some(loca), Some(glyf)) = (self.table(b"loca"), self.table(b"glyf")) else {
+            return &[];
+        };
+        let offset = |i: usize| -> Option<usize> {
+            if self.long_loca {
+                read_u32(loca, i * 4).map(|v| v as usize)
+            } else {
+                read_u16(loca, i * 2).map(|v| v as usize * 2)
+            }
+        };
+        match (offset(gid as usize), offset(gid as usize + 1)) {
+            (Some(start), Some(end)) if start < end => glyf.get(start..end).unwrap_or(&[]),
+            _ => &[],
+        }
+    }
+
+    /// 복합 glyph가 참조하는 구성 glyph 목록
+    fn components(&self, gid: u16) -> Vec<u16> {
+        let data = self.glyph_data(gid);
+        let mut result = Vec::new();
+        if read_i16(data, 0).unwrap_or(0) >= 0 {
+            return result;
+        }
+        let mut pos = 10;
+        while let (Some(flags), Some(component)) = (read_u16(data, pos), read_u16(data, pos + 2)) {
+            result.push(component);
+            pos += 4 + if flags & 0x0001 != 0 { 4 } else { 2 };
+            if flags & 0x0008 != 0 {
+                pos += 2;
+            } else if flags & 0x0040 != 0 {
+                pos += 4;
+            } else if flags & 0x0080 != 0 {
+                pos += 8;
+            }
+            if flags & 0x0020 == 0 {
+                break;
+            }
+        }
+        result
+    }
+
+    /// 사용한 glyph만 윤곽선을 남긴 글꼴 파일 — glyph id는 그대로 유지 (CIDToGIDMap /Identity)
+    /// Build a subset font that keeps glyph ids but drops unused outlines
+    pub(super) fn subset(&self, used: &BTreeSet<u16>) -> Vec<u8> {
+        let mut keep: BTreeSet<u16> = used.clone();
+        keep.insert(0);
+        let mut pending: Vec<u16> = keep.iter().copied().collect();
+        while let Some(gid) = pending.pop() {
+            for component in self.components(gid) {
+                if component < self.num_glyphs && keep.insert(component) {
+                    pending.push(component);
+                }
+            }
+        }
+
+        let mut glyf = Vec::new();
+        let mut loca = Vec::with_capacity((self.num_glyphs as usize + 1) * 4);
+        for gid in 0..self.num_glyphs {
+            loca.extend_from_slice(&(glyf.len() as u32).to_be_bytes());
+            if keep.contains(&gid) {
+                glyf.extend_from_slice(self.glyph_data(gid));
+                while glyf.len() % 4 != 0 {
+                    glyf.push(0);
+                }
+            }
+        }
+        loca.extend_from_slice(&(glyf.len() as u32).to_be_bytes());
+
+        // head: checksumAdjustment는 나중에 계산, loca는 항상 long 형식
+        let mut head = self.table(b"head").unwrap_or_default().to_vec();
+        if head.len() >= 54 {
+            head[8..12].copy_from_slice(&[0; 4]);
+            head[50..52].copy_from_slice(&1i16.to_be_bytes());
+        }
+
+        let mut tables: Vec<([u8; 4], Vec<u8>)> =
+            vec![(*b"head", head), (*b"loca", loca), (*b"glyf", glyf)];
+        for tag in COPIED_TABLES {
+            if let Some(data) = self.table(tag) {
+                tables.push((*tag, data.to_vec()));
+            }
+        }
+        tables.sort_by_key(|a| a.0);
+        build_sfnt(tables)
+    }
+}
+
+fn table_in<'a>(
+    data: &'a [u8],
+    tables: &[([u8; 4], usize, usize)],
+    tag: &[u8; 4],
+) -> Option<&'a [u8]> {
+    tables
+        .iter()
+        .find(|(t, _, _)| t == tag)
+        .map(|&(_, offset, length)| &data[offset..offset + length])
+}
+
+/// 테이블 목록으로 sfnt 파일 조립 (체크섬 포함)
+fn build_sfnt(tables: Vec<([u8; 4], Vec<u8>)>) -> Vec<u8> {
+    let count = tables.len() as u16;
+    let mut pow = 1u16;
+    let mut log = 0u16;
+    while pow * 2 <= count {
+        pow *= 2;
+        log += 1;
+    }
+    let search_range = pow * 16;
+    let mut out = Vec::new();
+    out.extend_from_slice(&0x0001_0000u32.to_be_bytes());
+    out.extend_from_slice(&count.to_be_bytes());
+    out.extend_from_slice(&search_range.to_be_bytes());
+    out.extend_from_slice(&log.to_be_bytes());
+    out.extend_from_slice(&(count * 16 - search_range).to_be_bytes());
+
+    let mut offset = 12 + tables.len() * 16;
+    let mut head_offset = None;
+    for (tag, data) in &tables {
+        out.extend_from_slice(tag);
+        out.extend_from_slice(&checksum(data).to_be_bytes());
+        out.extend_from_slice(&(offset as u32).to_be_bytes());
+        out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        if tag == b"head" {
+            head_offset = Some(offset);
+        }
+        offset += (data.len() + 3) / 4 * 4;
+    }
+    for (_, data) in &tables {
+        out.extend_from_slice(data);
+        while out.len() % 4 != 0 {
+            out.push(0);
+        }
+    }
+    if let Some(head) = head_offset {
+        let adjustment = 0xB1B0_AFBAu32.wrapping_sub(checksum(&out));
+        out[head + 8..head + 12].copy_from_slice(&adjustment.to_be_bytes());
+    }
+    out
+}
+
+fn checksum(data: &[u8]) -> u32 {
+    data.chunks(4).fold(0u32, |sum, chunk| {
+        let mut word = [0u8; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        sum.wrapping_add(u32::from_be_bytes(word))
+    })
+}
+
+/// 디렉터리의 글꼴 파일 내용을 파일 이름 순으로 읽음 (하위 디렉터리 제외)
+/// Read every font file in a directory, ordered by file name (no recursion)
+pub(crate) fn read_font_files(dir: &Path) -> Vec<Vec<u8>> {
+    let mut paths: Vec<_> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| FONT_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+        })
+        .collect();
+    paths.sort();
+    paths
+        .into_iter()
+        .filter_map(|path| std::fs::read(path).ok())
+        .collect()
+}
+
+/// 디렉터리의 TrueType 글꼴을 파일 이름 순으로 읽음
+/// Read every TrueType font in a directory, ordered by file name
+pub(crate) fn load_dir(dir: &Path) -> Vec<TrueTypeFont> {
+    read_font_files(dir)
+        .into_iter()
+        .flat_map(TrueTypeFont::parse_file)
+        .collect()
+}
+
+/// 글꼴 이름 비교용 정규화: 소문자, 공백/하이픈/밑줄 제거
+pub(crate) fn normalize_family(name: &str) -> String {
+    name.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-' && *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        data.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn read_i16(data: &[u8], offset: usize) -> Option<i16> {
+    read_u16(data, offset).map(|v| v as i16)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn read_i32(data: &[u8], offset: usize) -> Option<i32> {
+    read_u32(data, offset).map(|v| v as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_family() {
+        assert_eq!(normalize_family("Liberation Sans"), "liberationsans");
+        assert_eq!(normalize_family("맑은 고딕"), "맑은고딕");
+        assert_eq!(normalize_family("함초롬 바탕"), "함초롬바탕");
+        assert_eq!(normalize_family("Noto_Sans-KR"), "notosanskr");
+    }
+
+    #[test]
+    fn test_checksum_pads_last_word() {
+        assert_eq!(checksum(&[0, 0, 0, 1, 1]), 1 + 0x0100_0000);
+    }
+
+    #[test]
+    fn test_invalid_data_is_skipped() {
+        assert!(TrueTypeFont::parse_file(b"not a font".to_vec()).is_empty());
+        assert!(TrueTypeFont::parse_file(Vec::new()).is_empty());
+    }
+}
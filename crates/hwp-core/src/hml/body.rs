@@ -0,0 +1,922 @@
+//! HWPML `BODY/SECTION` → `Section` / HWPML `BODY/SECTION` → `Section`
+//!
+//! 문단(`P/TEXT/CHAR`), 구역 정의(`SECDEF`), 단 정의(`COLDEF`), 표/그림/그리기 개체/수식과
+//! 머리말·꼬리말, 각주·미주, 자동 번호, 필드, 책갈피를 읽습니다.
+
+use super::head::{read_fill_brush, read_image_ref};
+use super::xml::{enum_value, Element, Node};
+use hwp_model::control::*;
+use hwp_model::paragraph::{Paragraph, Run, RunContent, SubList, TextContent, TextElement};
+use hwp_model::resources::FillBrush;
+use hwp_model::section::*;
+use hwp_model::shape::*;
+use hwp_model::table::{CellZone, Table, TableCell, TableRow};
+use hwp_model::types::*;
+
+/// 중첩 문단 목록 최대 깊이 / Maximum nesting depth of paragraph lists
+const MAX_DEPTH: usize = 64;
+
+/// 본문 읽기 상태 / Body reader state
+#[derive(Default)]
+pub(super) struct BodyReader {
+    next_id: u64,
+    depth: usize,
+    /// 현재 구역의 구역 정의 / Section definition of the current section
+    definition: Option<SectionDef>,
+}
+
+impl BodyReader {
+    fn next_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    /// `SECTION` 하나 읽기 / Read one `SECTION`
+    pub(super) fn read_section(&mut self, section: &Element) -> Section {
+        self.definition = None;
+        let paragraphs = self.read_para_list(section);
+        Section {
+            definition: self.definition.take().unwrap_or_default(),
+            paragraphs,
+        }
+    }
+
+    /// 요소가 직접 담은 `P` 목록 / `P` children of an element
+    fn read_para_list(&mut self, parent: &Element) -> Vec<Paragraph> {
+        if self.depth >= MAX_DEPTH {
+            return Vec::new();
+        }
+        self.depth += 1;
+        let paragraphs = parent
+            .children_named("P")
+            .map(|p| self.read_paragraph(p))
+            .collect();
+        self.depth -= 1;
+        paragraphs
+    }
+
+    /// `PARALIST` → SubList
+    fn read_sub_list(&mut self, list: Option<&Element>) -> SubList {
+        let Some(list) = list else {
+            return SubList::default();
+        };
+        SubList {
+            id: self.next_id(),
+            text_direction: text_direction(list.attr("TextDirection")),
+            vert_align: list.enum_attr("VertAlign"),
+            link_list_id: list.num::<u64>("LinkListID").filter(|&id| id != 0),
+            link_list_next_id: list.num::<u64>("LinkListIDNext").filter(|&id| id != 0),
+            paragraphs: self.read_para_list(list),
+            ..Default::default()
+        }
+    }
+
+    fn read_paragraph(&mut self, p: &Element) -> Paragraph {
+        let mut runs = Vec::new();
+        for text in p.children_named("TEXT") {
+            let mut run = Run {
+                char_shape_id: text.num("CharShape").unwrap_or(0),
+                contents: Vec::new(),
+            };
+            for child in text.elements() {
+                if child.name == "CHAR" {
+                    let elements = text_elements(child);
+                    if !elements.is_empty() {
+                        run.contents.push(RunContent::Text(TextContent {
+                            char_shape_id: None,
+                            elements,
+                        }));
+                    }
+                } else {
+                    run.contents.extend(self.read_control(child));
+                }
+            }
+            runs.push(run);
+        }
+        Paragraph {
+            id: self.next_id(),
+            para_shape_id: p.num("ParaShape").unwrap_or(0),
+            style_id: p.num("Style").unwrap_or(0),
+            page_break: p.flag("PageBreak").unwrap_or(false),
+            column_break: p.flag("ColumnBreak").unwrap_or(false),
+            runs,
+            has_char_shapes: true,
+            ..Default::default()
+        }
+    }
+
+    /// `TEXT` 안의 컨트롤/개체 / Control or object inside `TEXT`
+    fn read_control(&mut self, e: &Element) -> Option<RunContent> {
+        let object = |shape: ShapeObject| Some(RunContent::Object(shape));
+        let control = |control: Control| Some(RunContent::Control(control));
+        match e.name.as_str() {
+            "SECDEF" => {
+                if self.definition.is_none() {
+                    self.definition = Some(read_section_def(e));
+                }
+                None
+            }
+            "COLDEF" => {
+                let column = ColumnControl {
+                    id: 0,
+                    column_type: e.enum_attr("Type"),
+                    col_count: e.num::<u16>("Count").unwrap_or(1).max(1),
+                    layout: e.enum_attr("Layout"),
+                    same_size: e.flag("SameSize").unwrap_or(true),
+                    same_gap: e.unit("SameGap"),
+                };
+                // 구역의 첫 단 정의는 구역 정의에도 기록 (HWP 변환과 동일)
+                if let Some(definition) = self.definition.as_mut() {
+                    if definition.columns.is_none() {
+                        definition.columns = Some(read_column_def(e, &column));
+                    }
+                }
+                control(Control::Column(column))
+            }
+            "TABLE" => object(ShapeObject::Table(Box::new(self.read_table(e)))),
+            "PICTURE" => object(ShapeObject::Picture(Box::new(self.read_picture(e)))),
+            "LINE" => {
+                let (common, component, line_shape, _, shadow, draw_text) = self.drawing(e);
+                object(ShapeObject::Line(Box::new(LineObject {
+                    common,
+                    component,
+                    start_pt: point(e, "StartX", "StartY"),
+                    end_pt: point(e, "EndX", "EndY"),
+                    is_reverse_hv: e.flag("IsReverseHV"),
+                    line_shape,
+                    shadow,
+                    draw_text,
+                })))
+            }
+            "RECTANGLE" => {
+                let (common, component, line_shape, fill, shadow, draw_text) = self.drawing(e);
+                object(ShapeObject::Rectangle(Box::new(RectObject {
+                    common,
+                    component,
+                    ratio: e.num("Ratio").unwrap_or(0),
+                    points: [
+                        point(e, "X0", "Y0"),
+                        point(e, "X1", "Y1"),
+                        point(e, "X2", "Y2"),
+                        point(e, "X3", "Y3"),
+                    ],
+                    line_shape,
+                    fill,
+                    shadow,
+                    draw_text,
+                    is_caption: false,
+                })))
+            }
+            "ELLIPSE" => {
+                let (common, component, line_shape, fill, shadow, draw_text) = self.drawing(e);
+                object(ShapeObject::Ellipse(Box::new(EllipseObject {
+                    common,
+                    component,
+                    center: point(e, "CenterX", "CenterY"),
+                    axis1: point(e, "Axis1X", "Axis1Y"),
+                    axis2: point(e, "Axis2X", "Axis2Y"),
+                    start1: point(e, "Start1X", "Start1Y"),
+                    end1: point(e, "End1X", "End1Y"),
+                    start2: point(e, "Start2X", "Start2Y"),
+                    end2: point(e, "End2X", "End2Y"),
+                    has_arc: e.flag("HasArcProperty").unwrap_or(false),
+                    arc_type: e.enum_attr("ArcType"),
+                    interval_dirty: e.flag("IntervalDirty"),
+                    line_shape,
+                    fill,
+                    shadow,
+                    draw_text,
+                })))
+            }
+            "ARC" => {
+                let (common, component, line_shape, fill, shadow, draw_text) = self.drawing(e);
+                object(ShapeObject::Arc(Box::new(ArcObject {
+                    common,
+                    component,
+                    arc_type: e.enum_attr("Type"),
+                    center: point(e, "CenterX", "CenterY"),
+                    axis1: point(e, "Axis1X", "Axis1Y"),
+                    axis2: point(e, "Axis2X", "Axis2Y"),
+                    line_shape,
+                    fill,
+                    shadow,
+                    draw_text,
+                })))
+            }
+            "POLYGON" => {
+                let (common, component, line_shape, fill, shadow, draw_text) = self.drawing(e);
+                object(ShapeObject::Polygon(Box::new(PolygonObject {
+                    common,
+                    component,
+                    points: e
+                        .children_named("POINT")
+                        .map(|p| point(p, "X", "Y"))
+                        .collect(),
+                    line_shape,
+                    fill,
+                    shadow,
+                    draw_text,
+                })))
+            }
+            "CURVE" => {
+                let (common, component, line_shape, fill, shadow, draw_text) = self.drawing(e);
+                object(ShapeObject::Curve(Box::new(CurveObject {
+                    common,
+                    component,
+                    segments: e
+                        .children_named("SEGMENT")
+                        .map(|s| CurveSegment {
+                            segment_type: s.enum_attr("Type"),
+                            x1: s.unit("X1"),
+                            y1: s.unit("Y1"),
+                            x2: s.unit("X2"),
+                            y2: s.unit("Y2"),
+                        })
+                        .collect(),
+                    line_shape,
+                    fill,
+                    shadow,
+                    draw_text,
+                })))
+            }
+            "CONTAINER" => {
+                let children = e
+                    .elements()
+                    .filter_map(|child| match self.read_control(child) {
+                        Some(RunContent::Object(shape)) => Some(shape),
+                        _ => None,
+                    })
+                    .collect();
+                object(ShapeObject::Container(Box::new(ContainerObject {
+                    common: self.shape_common(e),
+                    component: shape_component(e.child("SHAPECOMPONENT")),
+                    children,
+                })))
+            }
+            "EQUATION" => object(ShapeObject::Equation(Box::new(EquationObject {
+                common: self.shape_common(e),
+                line_mode: e.enum_attr("LineMode"),
+                script: e.child("SCRIPT").map(|s| s.text()).unwrap_or_default(),
+                base_unit: e.unit("BaseUnit"),
+                text_color: e.color("TextColor"),
+                baseline: e.num("BaseLine").unwrap_or(0),
+                version: e.attr("Version").map(str::to_string),
+                font: e.attr("Font").map(str::to_string),
+            }))),
+            "HEADER" | "FOOTER" => {
+                let header_footer = HeaderFooter {
+                    id: self.next_id(),
+                    apply_page_type: e.enum_attr("ApplyPageType"),
+                    content: self.read_sub_list(e.child("PARALIST")),
+                };
+                control(if e.name == "HEADER" {
+                    Control::Header(header_footer)
+                } else {
+                    Control::Footer(header_footer)
+                })
+            }
+            "FOOTNOTE" | "ENDNOTE" => {
+                let note = Note {
+                    id: self.next_id(),
+                    number: e.num("Number"),
+                    content: self.read_sub_list(e.child("PARALIST")),
+                };
+                control(if e.name == "FOOTNOTE" {
+                    Control::FootNote(note)
+                } else {
+                    Control::EndNote(note)
+                })
+            }
+            "AUTONUM" => {
+                let format = e.child("AUTONUMFORMAT");
+                let format_char = |name: &str| {
+                    format
+                        .and_then(|f| f.attr(name))
+                        .filter(|s| !s.is_empty())
+                        .map(str::to_string)
+                };
+                control(Control::AutoNum(AutoNum {
+                    num_type: auto_num_type(e.attr("NumberType")),
+                    number_type: format.map(|f| f.enum_attr("Type")).unwrap_or_default(),
+                    num: e.num("Number").unwrap_or(0),
+                    user_char: format_char("UserChar"),
+                    prefix_char: format_char("PrefixChar"),
+                    suffix_char: format_char("SuffixChar"),
+                }))
+            }
+            "NEWNUM" => control(Control::NewNum(NewNum {
+                num_type: numbering_type(e.attr("NumberType")),
+                num: e.num("Number").unwrap_or(1),
+            })),
+            "PAGENUMCTRL" => control(Control::PageNumCtrl(PageNumCtrl {
+                page_starts_on: e.attr("PageStartsOn").and_then(enum_value),
+                visible: None,
+            })),
+            "PAGEHIDING" => control(Control::PageHiding(PageHiding {
+                hide_header: e.flag("HideHeader").unwrap_or(false),
+                hide_footer: e.flag("HideFooter").unwrap_or(false),
+                hide_master_page: e.flag("HideMasterPage").unwrap_or(false),
+                hide_border: e.flag("HideBorder").unwrap_or(false),
+                hide_fill: e.flag("HideFill").unwrap_or(false),
+                hide_page_num: e.flag("HidePageNum").unwrap_or(false),
+            })),
+            "FIELDBEGIN" => {
+                let mut parameters = Vec::new();
+                if let Some(command) = e.attr("Command").filter(|c| !c.is_empty()) {
+                    parameters.push(FieldParameter::String {
+                        name: "Command".to_string(),
+                        value: command.to_string(),
+                    });
+                }
+                control(Control::FieldBegin(Field {
+                    id: self.next_id(),
+                    field_type: field_type(e.attr("Type").unwrap_or_default()),
+                    name: e.attr("Name").filter(|n| !n.is_empty()).map(str::to_string),
+                    editable: e.flag("Editable").unwrap_or(false),
+                    dirty: e.flag("Dirty").unwrap_or(false),
+                    field_id: e.num("InstId"),
+                    parameters,
+                    ..Default::default()
+                }))
+            }
+            "FIELDEND" => control(Control::FieldEnd),
+            "BOOKMARK" => control(Control::Bookmark(Bookmark {
+                name: e.attr("Name").unwrap_or_default().to_string(),
+            })),
+            _ => None,
+        }
+    }
+
+    /// `SHAPEOBJECT` → ShapeCommon
+    fn shape_common(&mut self, e: &Element) -> ShapeCommon {
+        let Some(so) = e.child("SHAPEOBJECT") else {
+            return ShapeCommon::default();
+        };
+        let size = so.child("SIZE");
+        let pos = so.child("POSITION");
+        let pos_flag = |name: &str| pos.and_then(|p| p.flag(name)).unwrap_or(false);
+        let caption = so.child("CAPTION").map(|c| Caption {
+            side: c.enum_attr("Side"),
+            full_size: c.flag("FullSize").unwrap_or(false),
+            width: c.unit("Width"),
+            gap: c.unit("Gap"),
+            last_width: c.num("LastWidth"),
+            content: self.read_sub_list(c.child("PARALIST")),
+        });
+        ShapeCommon {
+            id: so.num("InstId").unwrap_or(0),
+            z_order: so.num("ZOrder").unwrap_or(0),
+            numbering_type: numbering_type(so.attr("NumberingType")),
+            text_wrap: so.enum_attr("TextWrap"),
+            text_flow: so.enum_attr("TextFlow"),
+            lock: so.flag("Lock").unwrap_or(false),
+            dropcap_style: None,
+            size: ShapeSize {
+                width: size.map(|s| s.unit("Width")).unwrap_or(0),
+                width_rel_to: size.map(|s| s.enum_attr("WidthRelTo")).unwrap_or_default(),
+                height: size.map(|s| s.unit("Height")).unwrap_or(0),
+                height_rel_to: size.map(|s| s.enum_attr("HeightRelTo")).unwrap_or_default(),
+                protect: size.and_then(|s| s.flag("Protect")).unwrap_or(false),
+            },
+            position: ShapePosition {
+                treat_as_char: pos_flag("TreatAsChar"),
+                affect_line_spacing: pos_flag("AffectLSpacing"),
+                flow_with_text: pos_flag("FlowWithText"),
+                allow_overlap: pos_flag("AllowOverlap"),
+                hold_anchor_and_so: pos_flag("HoldAnchorAndSO"),
+                vert_rel_to: pos.map(|p| p.enum_attr("VertRelTo")).unwrap_or_default(),
+                horz_rel_to: pos.map(|p| p.enum_attr("HorzRelTo")).unwrap_or_default(),
+                vert_align: pos.map(|p| p.enum_attr("VertAlign")).unwrap_or_default(),
+                horz_align: pos
+                    .and_then(|p| p.attr("HorzAlign"))
+                    .and_then(enum_value)
+                    .unwrap_or(HAlign::Left),
+                vert_offset: pos.map(|p| p.unit("VertOffset")).unwrap_or(0),
+                horz_offset: pos.map(|p| p.unit("HorzOffset")).unwrap_or(0),
+            },
+            out_margin: so.child("OUTSIDEMARGIN").map(margin),
+            caption,
+            comment: so
+                .child("SHAPECOMMENT")
+                .map(|c| c.text())
+                .filter(|c| !c.is_empty()),
+            meta_tag: None,
+        }
+    }
+
+    /// 그리기 개체 공통 (`SHAPEOBJECT` + `DRAWINGOBJECT`)
+    /// Common drawing object parts (`SHAPEOBJECT` + `DRAWINGOBJECT`)
+    #[allow(clippy::type_complexity)]
+    fn drawing(
+        &mut self,
+        e: &Element,
+    ) -> (
+        ShapeCommon,
+        ShapeComponentData,
+        ShapeLineInfo,
+        Option<FillBrush>,
+        Option<ShapeShadow>,
+        Option<SubList>,
+    ) {
+        let common = self.shape_common(e);
+        let Some(drawing) = e.child("DRAWINGOBJECT") else {
+            return (
+                common,
+                Default::default(),
+                Default::default(),
+                None,
+                None,
+                None,
+            );
+        };
+        let draw_text = match drawing.child("DRAWTEXT") {
+            Some(text) => {
+                let mut list = self.read_sub_list(text.child("PARALIST"));
+                list.text_width = text.num("LastWidth");
+                Some(list)
+            }
+            None => None,
+        };
+        (
+            common,
+            shape_component(drawing.child("SHAPECOMPONENT")),
+            line_shape(drawing.child("LINESHAPE")).unwrap_or_default(),
+            drawing.child("FILLBRUSH").and_then(read_fill_brush),
+            drawing.child("SHADOW").and_then(|s| {
+                let shadow_type: ShapeShadowType = s.enum_attr("Type");
+                (shadow_type != ShapeShadowType::None).then(|| ShapeShadow {
+                    shadow_type,
+                    color: s.color("Color"),
+                    offset_x: s.unit("OffsetX"),
+                    offset_y: s.unit("OffsetY"),
+                    alpha: s.num("Alpha").unwrap_or(0),
+                })
+            }),
+            draw_text,
+        )
+    }
+
+    fn read_table(&mut self, e: &Element) -> Table {
+        let common = self.shape_common(e);
+        let rows = e
+            .children_named("ROW")
+            .map(|row| TableRow {
+                cells: row
+                    .children_named("CELL")
+                    .map(|cell| TableCell {
+                        name: cell
+                            .attr("Name")
+                            .filter(|n| !n.is_empty())
+                            .map(str::to_string),
+                        header: cell.flag("Header").unwrap_or(false),
+                        has_margin: cell.flag("HasMargin"),
+                        protect: cell.flag("Protect").unwrap_or(false),
+                        editable: cell.flag("Editable").unwrap_or(false),
+                        dirty: cell.flag("Dirty"),
+                        border_fill_id: cell.num("BorderFill").unwrap_or(0),
+                        col: cell.num("ColAddr").unwrap_or(0),
+                        row: cell.num("RowAddr").unwrap_or(0),
+                        col_span: cell.num::<u16>("ColSpan").unwrap_or(1).max(1),
+                        row_span: cell.num::<u16>("RowSpan").unwrap_or(1).max(1),
+                        width: cell.unit("Width"),
+                        height: cell.unit("Height"),
+                        cell_margin: cell.child("CELLMARGIN").map(margin).unwrap_or_default(),
+                        content: self.read_sub_list(cell.child("PARALIST")),
+                    })
+                    .collect(),
+            })
+            .collect();
+        Table {
+            common,
+            page_break: e.enum_attr("PageBreak"),
+            repeat_header: e.flag("RepeatHeader").unwrap_or(false),
+            row_count: e.num("RowCount").unwrap_or(0),
+            col_count: e.num("ColCount").unwrap_or(0),
+            cell_spacing: e.unit("CellSpacing"),
+            border_fill_id: e.num("BorderFill").unwrap_or(0),
+            no_adjust: None,
+            in_margin: e.child("INSIDEMARGIN").map(margin).unwrap_or_default(),
+            cell_zones: e
+                .child("CELLZONELIST")
+                .map(|list| {
+                    list.children_named("CELLZONE")
+                        .map(|z| CellZone {
+                            start_row: z.num("StartRowAddr").unwrap_or(0),
+                            start_col: z.num("StartColAddr").unwrap_or(0),
+                            end_row: z.num("EndRowAddr").unwrap_or(0),
+                            end_col: z.num("EndColAddr").unwrap_or(0),
+                            border_fill_id: z.num("BorderFill").unwrap_or(0),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            rows,
+        }
+    }
+
+    fn read_picture(&mut self, e: &Element) -> Picture {
+        Picture {
+            common: self.shape_common(e),
+            component: shape_component(e.child("SHAPECOMPONENT")),
+            reverse: e.flag("Reverse"),
+            line_shape: line_shape(e.child("LINESHAPE")),
+            img_rect: e.child("IMAGERECT").map(|r| {
+                [
+                    point(r, "X0", "Y0"),
+                    point(r, "X1", "Y1"),
+                    point(r, "X2", "Y2"),
+                    point(r, "X3", "Y3"),
+                ]
+            }),
+            img_clip: e.child("IMAGECLIP").map(margin),
+            in_margin: e.child("INSIDEMARGIN").map(margin),
+            img_dim: None,
+            img: e.child("IMAGE").map(read_image_ref).unwrap_or_default(),
+            effects: None,
+        }
+    }
+}
+
+/// `CHAR` 내용 (텍스트와 TAB/LINEBREAK 등) / Content of `CHAR`
+fn text_elements(e: &Element) -> Vec<TextElement> {
+    let mut out = Vec::new();
+    for node in &e.children {
+        match node {
+            Node::Text(text) if !text.is_empty() => match out.last_mut() {
+                Some(TextElement::Text(prev)) => prev.push_str(text),
+                _ => out.push(TextElement::Text(text.clone())),
+            },
+            Node::Text(_) => {}
+            Node::Element(child) => match child.name.as_str() {
+                "TAB" => out.push(TextElement::Tab {
+                    width: child.unit("Width"),
+                    leader: child.enum_attr("Leader"),
+                    tab_type: child.enum_attr("Type"),
+                }),
+                "LINEBREAK" => out.push(TextElement::LineBreak),
+                // HWPML 표기 그대로 (HYPEN) / HWPML spells it HYPEN
+                "HYPEN" | "HYPHEN" => out.push(TextElement::Hyphen),
+                "NBSPACE" => out.push(TextElement::NbSpace),
+                "FWSPACE" => out.push(TextElement::FwSpace),
+                "TITLEMARK" => out.push(TextElement::TitleMark {
+                    ignore: child.flag("Ignore").unwrap_or(false),
+                }),
+                _ => {}
+            },
+        }
+    }
+    out
+}
+
+fn read_section_def(e: &Element) -> SectionDef {
+    let start = e.child("STARTNUMBER");
+    let hide = e.child("HIDE");
+    let hide_flag = |name: &str| hide.and_then(|h| h.flag(name)).unwrap_or(false);
+    let first = |name: &str| {
+        if e.flag(name).unwrap_or(false) {
+            VisibilityValue::HideFirst
+        } else {
+            VisibilityValue::ShowAll
+        }
+    };
+    let page = e.child("PAGEDEF");
+    let page_margin = page.and_then(|p| p.child("PAGEMARGIN"));
+    let page_unit = |name: &str| page_margin.map(|m| m.unit(name)).unwrap_or(0);
+    let line_grid = e.num("LineGrid").unwrap_or(0);
+    let char_grid = e.num("CharGrid").unwrap_or(0);
+    SectionDef {
+        text_direction: text_direction(e.attr("TextDirection")),
+        space_columns: e.unit("SpaceColumns"),
+        tab_stop: e.unit("TabStop"),
+        outline_shape_id: e.num::<u16>("OutlineShape").filter(|&id| id > 0),
+        memo_shape_id: e.num("MemoShapeId"),
+        start_num: start.map(|s| StartNum {
+            page_starts_on: s.enum_attr("PageStartsOn"),
+            page: s.num("Page").unwrap_or(0),
+            pic: s.num("Figure").unwrap_or(0),
+            tbl: s.num("Table").unwrap_or(0),
+            equation: s.num("Equation").unwrap_or(0),
+        }),
+        grid: (line_grid > 0 || char_grid > 0).then_some(Grid {
+            line_grid,
+            char_grid,
+            wonggoji_format: false,
+        }),
+        visibility: hide.map(|_| Visibility {
+            hide_first_header: hide_flag("Header"),
+            hide_first_footer: hide_flag("Footer"),
+            hide_first_master_page: hide_flag("MasterPage"),
+            border: first("FirstBorder"),
+            fill: first("FirstFill"),
+            hide_first_page_num: hide_flag("PageNumPos"),
+            hide_first_empty_line: hide_flag("EmptyLine"),
+            show_line_number: false,
+        }),
+        page: PageDef {
+            // 0: 좁게(세로), 1: 넓게(가로) / 0: portrait, 1: landscape
+            landscape: match page.and_then(|p| p.attr("Landscape")) {
+                Some("1") | Some("true") | Some("Landscape") => Landscape::Landscape,
+                _ => Landscape::Portrait,
+            },
+            width: page.map(|p| p.unit("Width")).unwrap_or(0),
+            height: page.map(|p| p.unit("Height")).unwrap_or(0),
+            gutter_type: page.map(|p| p.enum_attr("GutterType")).unwrap_or_default(),
+            margin: PageMargin {
+                left: page_unit("Left"),
+                right: page_unit("Right"),
+                top: page_unit("Top"),
+                bottom: page_unit("Bottom"),
+                header: page_unit("Header"),
+                footer: page_unit("Footer"),
+                gutter: page_unit("Gutter"),
+            },
+        },
+        footnote: e.child("FOOTNOTESHAPE").map(|f| {
+            let note = NoteShape::read(f);
+            FootNoteDef {
+                numbering_type: note
+                    .numbering
+                    .map(|n| n.enum_attr("Type"))
+                    .unwrap_or_default(),
+                placement: note
+                    .placement
+                    .map(|p| p.enum_attr("Place"))
+                    .unwrap_or_default(),
+                beneath_text: note.beneath_text(),
+                number_format: note.number_format(),
+                start_number: note.start_number(),
+                superscript: note.superscript(),
+                user_char: note.format_char("UserChar"),
+                prefix_char: note.format_char("PrefixChar"),
+                suffix_char: note.format_char("SuffixChar"),
+                note_line: note.note_line(),
+                note_spacing: note.note_spacing(),
+            }
+        }),
+        endnote: e.child("ENDNOTESHAPE").map(|f| {
+            let note = NoteShape::read(f);
+            EndNoteDef {
+                numbering_type: note
+                    .numbering
+                    .map(|n| n.enum_attr("Type"))
+                    .unwrap_or_default(),
+                placement: note
+                    .placement
+                    .map(|p| p.enum_attr("Place"))
+                    .unwrap_or_default(),
+                beneath_text: note.beneath_text(),
+                number_format: note.number_format(),
+                start_number: note.start_number(),
+                superscript: note.superscript(),
+                user_char: note.format_char("UserChar"),
+                prefix_char: note.format_char("PrefixChar"),
+                suffix_char: note.format_char("SuffixChar"),
+                note_line: note.note_line(),
+                note_spacing: note.note_spacing(),
+            }
+        }),
+        page_border_fills: e
+            .children_named("PAGEBORDERFILL")
+            .map(|b| PageBorderFill {
+                // 한글이 "BorferFill"로 잘못 기록하는 경우가 있음 / Hangul sometimes writes "BorferFill"
+                border_fill_id: b.num("BorderFill").or(b.num("BorferFill")).unwrap_or(0),
+                text_border: if b.flag("TextBorder").unwrap_or(false) {
+                    PageBorderRef::Text
+                } else {
+                    PageBorderRef::Paper
+                },
+                header_inside: b.flag("HeaderInside").unwrap_or(false),
+                footer_inside: b.flag("FooterInside").unwrap_or(false),
+                fill_area: match b.attr("FillArea") {
+                    Some("Page") => FillArea::Text,
+                    Some("Border") => FillArea::PaperLine,
+                    _ => FillArea::Paper,
+                },
+                offset: b.child("PAGEOFFSET").map(margin).unwrap_or_default(),
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+/// `FOOTNOTESHAPE` / `ENDNOTESHAPE` 하위 요소 / Children of a note shape
+struct NoteShape<'a> {
+    format: Option<&'a Element>,
+    line: Option<&'a Element>,
+    spacing: Option<&'a Element>,
+    numbering: Option<&'a Element>,
+    placement: Option<&'a Element>,
+}
+
+impl<'a> NoteShape<'a> {
+    fn read(e: &'a Element) -> Self {
+        Self {
+            format: e.child("AUTONUMFORMAT"),
+            line: e.child("NOTELINE"),
+            spacing: e.child("NOTESPACING"),
+            numbering: e.child("NOTENUMBERING"),
+            placement: e.child("NOTEPLACEMENT"),
+        }
+    }
+
+    fn beneath_text(&self) -> bool {
+        self.placement
+            .and_then(|p| p.flag("BeneathText"))
+            .unwrap_or(false)
+    }
+
+    fn number_format(&self) -> NumberType1 {
+        self.format.map(|f| f.enum_attr("Type")).unwrap_or_default()
+    }
+
+    fn start_number(&self) -> u16 {
+        self.numbering.and_then(|n| n.num("NewNumber")).unwrap_or(1)
+    }
+
+    fn superscript(&self) -> bool {
+        self.format
+            .and_then(|f| f.flag("Superscript"))
+            .unwrap_or(false)
+    }
+
+    fn format_char(&self, name: &str) -> Option<char> {
+        self.format
+            .and_then(|f| f.attr(name))
+            .and_then(|s| s.chars().next())
+    }
+
+    fn note_line(&self) -> Option<NoteLine> {
+        self.line.map(|l| NoteLine {
+            length: l.num("Length").unwrap_or(0),
+            line_type: l.enum_attr("Type"),
+            width: l.attr("Width").unwrap_or_default().replace(' ', ""),
+            color: l.color("Color"),
+        })
+    }
+
+    fn note_spacing(&self) -> Option<NoteSpacing> {
+        self.spacing.map(|s| NoteSpacing {
+            between_notes: s.num("BetweenNotes").unwrap_or(0),
+            below_line: s.num("BelowLine").unwrap_or(0),
+            above_line: s.num("AboveLine").unwrap_or(0),
+        })
+    }
+}
+
+fn read_column_def(e: &Element, column: &ColumnControl) -> ColumnDef {
+    let col_sizes: Vec<ColumnSize> = e
+        .children_named("COLUMNTABLE")
+        .flat_map(|t| t.children_named("COLUMN"))
+        .map(|c| ColumnSize {
+            width: c.unit("Width"),
+            gap: c.unit("Gap"),
+        })
+        .collect();
+    ColumnDef {
+        id: column.id,
+        column_type: column.column_type.clone(),
+        col_count: column.col_count,
+        layout: column.layout.clone(),
+        same_size: column.same_size,
+        same_gap: column.same_gap,
+        col_sizes,
+        col_line: e.child("COLUMNLINE").and_then(|l| {
+            let line_type: LineType3 = l.enum_attr("Type");
+            (line_type != LineType3::None).then(|| ColumnLine {
+                line_type,
+                width: l.attr("Width").unwrap_or_default().replace(' ', ""),
+                color: l.color("Color"),
+            })
+        }),
+    }
+}
+
+/// `SHAPECOMPONENT` → ShapeComponentData
+fn shape_component(e: Option<&Element>) -> ShapeComponentData {
+    let Some(e) = e else {
+        return ShapeComponentData::default();
+    };
+    let matrix = |m: Option<&Element>| {
+        let mut out = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+        if let Some(m) = m {
+            for (i, value) in out.iter_mut().enumerate() {
+                if let Some(v) = m.num(&format!("E{}", i + 1)) {
+                    *value = v;
+                }
+            }
+        }
+        out
+    };
+    ShapeComponentData {
+        href: e.attr("Href").map(str::to_string),
+        group_level: e.num("GroupLevel").unwrap_or(0),
+        inst_id: e.num("InstID"),
+        offset: Some(point(e, "XPos", "YPos")),
+        org_size: Some(Size {
+            width: e.unit("OriWidth"),
+            height: e.unit("OriHeight"),
+        }),
+        cur_size: Some(Size {
+            width: e.unit("CurWidth"),
+            height: e.unit("CurHeight"),
+        }),
+        flip: Some(Flip {
+            horizontal: e.flag("HorzFlip").unwrap_or(false),
+            vertical: e.flag("VertFlip").unwrap_or(false),
+        }),
+        rotation: e.child("ROTATIONINFO").map(|r| Rotation {
+            angle: r.num("Angle").unwrap_or(0.0),
+            center_x: r.unit("CenterX"),
+            center_y: r.unit("CenterY"),
+            rotate_image: r.flag("RotateImage").unwrap_or(false),
+        }),
+        rendering_info: e.child("RENDERINGINFO").map(|r| RenderingInfo {
+            trans_matrix: matrix(r.child("TRANSMATRIX")),
+            sca_matrix: matrix(r.child("SCAMATRIX")),
+            rot_matrix: matrix(r.child("ROTMATRIX")),
+        }),
+    }
+}
+
+/// `LINESHAPE` → ShapeLineInfo
+fn line_shape(e: Option<&Element>) -> Option<ShapeLineInfo> {
+    let e = e?;
+    Some(ShapeLineInfo {
+        color: e.color("Color"),
+        width: e.unit("Width"),
+        style: e.enum_attr("Style"),
+        end_cap: e.enum_attr("EndCap"),
+        head_style: e.enum_attr("HeadStyle"),
+        tail_style: e.enum_attr("TailStyle"),
+        head_fill: e.flag("HeadFill").unwrap_or(false),
+        tail_fill: e.flag("TailFill").unwrap_or(false),
+        head_size: e.enum_attr("HeadSize"),
+        tail_size: e.enum_attr("TailSize"),
+        outline_style: e.enum_attr("OutlineStyle"),
+        alpha: e.num("Alpha").unwrap_or(0),
+    })
+}
+
+fn point(e: &Element, x: &str, y: &str) -> Point {
+    Point {
+        x: e.unit(x),
+        y: e.unit(y),
+    }
+}
+
+fn margin(e: &Element) -> Margin {
+    Margin {
+        left: e.unit("Left"),
+        right: e.unit("Right"),
+        top: e.unit("Top"),
+        bottom: e.unit("Bottom"),
+    }
+}
+
+/// 글자 방향: 숫자(0 가로, 1 세로) 또는 이름 / Text direction as a number or a name
+fn text_direction(value: Option<&str>) -> TextDirection {
+    match value {
+        Some("1") | Some("Vertical") => TextDirection::Vertical,
+        Some("2") | Some("VerticalAll") => TextDirection::VerticalAll,
+        _ => TextDirection::Horizontal,
+    }
+}
+
+/// 번호 범주 (HWPML은 그림을 "Figure"로 기록) / Numbering category ("Figure" means picture)
+fn numbering_type(value: Option<&str>) -> NumberingType {
+    match value {
+        Some("Figure") => NumberingType::Picture,
+        Some(v) => enum_value(v).unwrap_or_default(),
+        None => NumberingType::None,
+    }
+}
+
+fn auto_num_type(value: Option<&str>) -> AutoNumType {
+    match value {
+        Some("Figure") => AutoNumType::Picture,
+        Some(v) => enum_value(v).unwrap_or_default(),
+        None => AutoNumType::default(),
+    }
+}
+
+/// 필드 종류 (대소문자 구분 없음) / Field type, case-insensitive
+fn field_type(value: &str) -> FieldType {
+    match value.to_ascii_lowercase().as_str() {
+        "hyperlink" => FieldType::Hyperlink,
+        "bookmark" => FieldType::Bookmark,
+        "formula" => FieldType::Formula,
+        "summary" => FieldType::Summary,
+        "userinfo" => FieldType::UserInfo,
+        "date" => FieldType::Date,
+        "docdate" => FieldType::DocDate,
+        "path" => FieldType::Path,
+        "crossref" => FieldType::CrossRef,
+        "mailmerge" => FieldType::MailMerge,
+        "memo" => FieldType::Memo,
+        "proofreadingmarks" => FieldType::ProofreadingMarks,
+        "privateinfo" => FieldType::PrivateInfo,
+        "metatag" => FieldType::MetaTag,
+        "outline" => FieldType::Outline,
+        _ => FieldType::ClickHere,
+    }
+}
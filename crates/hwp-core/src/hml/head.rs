@@ -0,0 +1,506 @@
+//! HWPML `HEAD` → 문서 정보와 `Resources` / HWPML `HEAD` → document info and `Resources`
+//!
+//! `DOCSUMMARY`, `DOCSETTING/BEGINNUMBER`, `MAPPINGTABLE`(글꼴, 테두리/배경, 글자 모양,
+//! 탭, 문단 번호, 글머리표, 문단 모양, 스타일, 바이너리 목록)을 읽습니다.
+
+use super::xml::{enum_value, Element};
+use hwp_model::document::{DocumentMeta, DocumentSettings};
+use hwp_model::resources::*;
+use hwp_model::types::*;
+use std::collections::HashMap;
+
+/// `HEAD`에서 읽은 정보 / Information read from `HEAD`
+#[derive(Default)]
+pub(super) struct Head {
+    pub meta: DocumentMeta,
+    pub settings: DocumentSettings,
+    pub resources: Resources,
+    /// 바이너리 id → 확장자 (`BINITEM Format`) / Binary id → file extension
+    pub bin_formats: HashMap<String, String>,
+}
+
+/// HWP와 같은 바이너리 id (`BIN0001`) / Binary id in the same form as HWP (`BIN0001`)
+pub(super) fn bin_id(value: &str) -> String {
+    match value.trim().parse::<u32>() {
+        Ok(n) => format!("BIN{:04X}", n),
+        Err(_) => value.to_string(),
+    }
+}
+
+pub(super) fn read_head(head: &Element) -> Head {
+    let mut out = Head::default();
+    if let Some(summary) = head.child("DOCSUMMARY") {
+        out.meta = read_summary(summary);
+    }
+    let begin = head
+        .child("DOCSETTING")
+        .and_then(|s| s.child("BEGINNUMBER"));
+    let start = |name: &str| begin.and_then(|b| b.num::<u16>(name)).unwrap_or(1).max(1);
+    out.settings = DocumentSettings {
+        page_start: start("Page"),
+        footnote_start: start("Footnote"),
+        endnote_start: start("Endnote"),
+        picture_start: start("Picture"),
+        table_start: start("Table"),
+        equation_start: start("Equation"),
+    };
+
+    let Some(mapping) = head.child("MAPPINGTABLE") else {
+        return out;
+    };
+    let res = &mut out.resources;
+    for list in mapping.elements() {
+        match list.name.as_str() {
+            "BINDATALIST" => {
+                for item in list.children_named("BINITEM") {
+                    if let (Some(id), Some(format)) = (item.attr("BinData"), item.attr("Format")) {
+                        out.bin_formats
+                            .insert(bin_id(id), format.to_ascii_lowercase());
+                    }
+                }
+            }
+            "FACENAMELIST" => {
+                for face in list.children_named("FONTFACE") {
+                    let fonts: Vec<Font> = face.children_named("FONT").map(read_font).collect();
+                    match face.attr("Lang").unwrap_or_default() {
+                        "Hangul" => res.fonts.hangul = fonts,
+                        "Latin" => res.fonts.latin = fonts,
+                        "Hanja" => res.fonts.hanja = fonts,
+                        "Japanese" => res.fonts.japanese = fonts,
+                        "Other" => res.fonts.other = fonts,
+                        "Symbol" => res.fonts.symbol = fonts,
+                        "User" => res.fonts.user = fonts,
+                        _ => {}
+                    }
+                }
+            }
+            "BORDERFILLLIST" => {
+                res.border_fills = list
+                    .children_named("BORDERFILL")
+                    .map(read_border_fill)
+                    .collect();
+            }
+            "CHARSHAPELIST" => {
+                res.char_shapes = list
+                    .children_named("CHARSHAPE")
+                    .map(read_char_shape)
+                    .collect();
+            }
+            "TABDEFLIST" => {
+                res.tab_defs = list.children_named("TABDEF").map(read_tab_def).collect();
+            }
+            "NUMBERINGLIST" => {
+                res.numberings = list
+                    .children_named("NUMBERING")
+                    .map(read_numbering)
+                    .collect();
+            }
+            "BULLETLIST" => {
+                res.bullets = list.children_named("BULLET").map(read_bullet).collect();
+            }
+            "PARASHAPELIST" => {
+                res.para_shapes = list
+                    .children_named("PARASHAPE")
+                    .map(read_para_shape)
+                    .collect();
+            }
+            "STYLELIST" => {
+                res.styles = list.children_named("STYLE").map(read_style).collect();
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+fn read_summary(summary: &Element) -> DocumentMeta {
+    let text = |name: &str| {
+        summary
+            .child(name)
+            .map(|e| e.text())
+            .filter(|t| !t.trim().is_empty())
+    };
+    DocumentMeta {
+        title: text("TITLE"),
+        subject: text("SUBJECT"),
+        creator: text("AUTHOR"),
+        created_date: text("DATE"),
+        keywords: text("KEYWORDS"),
+        description: text("COMMENTS"),
+        ..Default::default()
+    }
+}
+
+fn font_type(e: &Element) -> FontType {
+    // HWPML은 소문자 (ttf, hft, rep) / HWPML uses lowercase names
+    let mut name = e.attr("Type").unwrap_or_default().to_ascii_lowercase();
+    if let Some(first) = name.get_mut(0..1) {
+        first.make_ascii_uppercase();
+    }
+    enum_value(&name).unwrap_or_default()
+}
+
+fn read_font(e: &Element) -> Font {
+    Font {
+        id: e.num("Id").unwrap_or(0),
+        face: e.attr("Name").unwrap_or_default().to_string(),
+        font_type: font_type(e),
+        subst_font: e.child("SUBSTFONT").map(|s| SubstFont {
+            face: s.attr("Name").unwrap_or_default().to_string(),
+            font_type: font_type(s),
+            is_embedded: false,
+        }),
+        type_info: e.child("TYPEINFO").map(|t| FontTypeInfo {
+            family_type: match t.num::<u8>("FamilyType").unwrap_or(0) {
+                1 => FontCategory::Myungjo,
+                2 => FontCategory::Gothic,
+                3 => FontCategory::SSerif,
+                4 => FontCategory::BrushScript,
+                5 => FontCategory::NonRectMj,
+                6 => FontCategory::NonRectGt,
+                _ => FontCategory::Unknown,
+            },
+            weight: t.num("Weight").unwrap_or(0),
+            proportion: t.num("Proportion").unwrap_or(0),
+            contrast: t.num("Contrast").unwrap_or(0),
+            stroke_variation: t.num("StrokeVariation").unwrap_or(0),
+            arm_style: t.num("ArmStyle").unwrap_or(0),
+            letterform: t.num("Letterform").unwrap_or(0),
+            midline: t.num("Midline").unwrap_or(0),
+            x_height: t.num("XHeight").unwrap_or(0),
+        }),
+        ..Default::default()
+    }
+}
+
+fn read_line_spec(e: Option<&Element>) -> Option<LineSpec> {
+    let e = e?;
+    let line_type: LineType3 = e.enum_attr("Type");
+    // Type=None이면 테두리 없음 (HWP/HWPX 변환과 동일)
+    if line_type == LineType3::None {
+        return None;
+    }
+    Some(LineSpec {
+        line_type,
+        width: e.attr("Width").unwrap_or_default().replace(' ', ""),
+        color: e.color("Color"),
+    })
+}
+
+fn read_border_fill(e: &Element) -> BorderFill {
+    let slash = |name: &str, is_counter: &str| {
+        let kind = e.num::<u8>(name).unwrap_or(0);
+        (kind != 0).then(|| SlashInfo {
+            slash_type: match kind {
+                1 => SlashType::Center,
+                2 => SlashType::CenterBelow,
+                3 => SlashType::CenterAbove,
+                _ => SlashType::All,
+            },
+            crooked: e.flag("CrookedSlash").unwrap_or(false),
+            is_counter: e.num::<u8>(is_counter).unwrap_or(0) != 0,
+        })
+    };
+    BorderFill {
+        id: e.num("Id").unwrap_or(0),
+        three_d: e.flag("ThreeD").unwrap_or(false),
+        shadow: e.flag("Shadow").unwrap_or(false),
+        center_line: match e.num::<u8>("CenterLine").unwrap_or(0) {
+            1 => CenterLineType::Left,
+            2 => CenterLineType::Right,
+            3 => CenterLineType::Both,
+            _ => CenterLineType::None,
+        },
+        break_cell_separate_line: e.num::<u8>("BreakCellSeparateLine").map(|v| v != 0),
+        slash: slash("Slash", "CounterSlash"),
+        back_slash: slash("BackSlash", "CounterBackSlash"),
+        left_border: read_line_spec(e.child("LEFTBORDER")),
+        right_border: read_line_spec(e.child("RIGHTBORDER")),
+        top_border: read_line_spec(e.child("TOPBORDER")),
+        bottom_border: read_line_spec(e.child("BOTTOMBORDER")),
+        diagonal: read_line_spec(e.child("DIAGONAL")),
+        fill: e.child("FILLBRUSH").and_then(read_fill_brush),
+    }
+}
+
+/// `FILLBRUSH` (단색/그러데이션/그림) / `FILLBRUSH` (solid, gradation, image)
+pub(super) fn read_fill_brush(e: &Element) -> Option<FillBrush> {
+    let win_brush = e.child("WINDOWBRUSH").map(|w| FillBrush::WinBrush {
+        face_color: w.color("FaceColor"),
+        hatch_color: w.color("HatchColor"),
+        hatch_style: w.attr("HatchStyle").and_then(enum_value),
+        alpha: w.num("Alpha").unwrap_or(0),
+    });
+    let gradation = e.child("GRADATION").map(|g| FillBrush::Gradation {
+        grad_type: g.enum_attr("Type"),
+        angle: g.num("Angle").unwrap_or(0),
+        center_x: g.num("CenterX").unwrap_or(0),
+        center_y: g.num("CenterY").unwrap_or(0),
+        step: g.num("Step").unwrap_or(0),
+        color_num: g.num("ColorNum").unwrap_or(0),
+        step_center: g.num("StepCenter").unwrap_or(0),
+        colors: g
+            .children_named("COLOR")
+            .map(|c| c.color("Value"))
+            .collect(),
+        alpha: g.num("Alpha").unwrap_or(0),
+    });
+    let image_brush = e.child("IMAGEBRUSH").map(|b| FillBrush::ImageBrush {
+        mode: b.enum_attr("Mode"),
+        img: b.child("IMAGE").map(read_image_ref).unwrap_or_default(),
+    });
+
+    let count = win_brush.is_some() as u8 + gradation.is_some() as u8 + image_brush.is_some() as u8;
+    if count <= 1 {
+        win_brush.or(gradation).or(image_brush)
+    } else {
+        Some(FillBrush::Combined {
+            win_brush: win_brush.map(Box::new),
+            gradation: gradation.map(Box::new),
+            image_brush: image_brush.map(Box::new),
+        })
+    }
+}
+
+/// `IMAGE` (그림/그림 채우기) / `IMAGE` (picture or image fill)
+pub(super) fn read_image_ref(e: &Element) -> ImageRef {
+    ImageRef {
+        binary_item_id: bin_id(e.attr("BinItem").unwrap_or_default()),
+        bright: e.num("Bright").unwrap_or(0),
+        contrast: e.num("Contrast").unwrap_or(0),
+        effect: e.enum_attr("Effect"),
+        alpha: e.num("Alpha").unwrap_or(0),
+    }
+}
+
+fn lang_group<T: std::str::FromStr + Copy + Default>(e: Option<&Element>) -> LangGroup<T> {
+    let Some(e) = e else {
+        return LangGroup::default();
+    };
+    let get = |name: &str| e.num::<T>(name).unwrap_or_default();
+    LangGroup::new(
+        get("Hangul"),
+        get("Latin"),
+        get("Hanja"),
+        get("Japanese"),
+        get("Other"),
+        get("Symbol"),
+        get("User"),
+    )
+}
+
+fn read_char_shape(e: &Element) -> CharShape {
+    let line_shape = |el: &Element, name: &str| -> LineType3 {
+        el.attr(name)
+            .and_then(enum_value)
+            .unwrap_or(LineType3::Solid)
+    };
+    CharShape {
+        id: e.num("Id").unwrap_or(0),
+        height: e.unit("Height"),
+        text_color: e.color("TextColor"),
+        shade_color: e.color("ShadeColor"),
+        use_font_space: e.flag("UseFontSpace").unwrap_or(false),
+        use_kerning: e.flag("UseKerning").unwrap_or(false),
+        sym_mark: e.enum_attr("SymMark"),
+        border_fill_id: e.num("BorderFillId"),
+        font_ref: lang_group(e.child("FONTID")),
+        ratio: lang_group(e.child("RATIO")),
+        spacing: lang_group(e.child("CHARSPACING")),
+        rel_size: lang_group(e.child("RELSIZE")),
+        offset: lang_group(e.child("CHAROFFSET")),
+        bold: e.child("BOLD").is_some(),
+        italic: e.child("ITALIC").is_some(),
+        underline: e.child("UNDERLINE").map(|u| Underline {
+            underline_type: u.enum_attr("Type"),
+            shape: line_shape(u, "Shape"),
+            color: u.color("Color"),
+        }),
+        strikeout: e
+            .child("STRIKEOUT")
+            .map(|s| Strikeout {
+                shape: line_shape(s, "Type"),
+                color: s.color("Color"),
+            })
+            .filter(|s| s.shape != LineType3::None),
+        outline: e
+            .child("OUTLINE")
+            .map(|o| o.enum_attr::<OutlineType>("Type"))
+            .filter(|t| *t != OutlineType::None),
+        shadow: e.child("SHADOW").map(|s| CharShadow {
+            shadow_type: match s.attr("Type").unwrap_or_default() {
+                "Cont" | "Continuous" => CharShadowType::Continuous,
+                "None" => CharShadowType::None,
+                _ => CharShadowType::Drop,
+            },
+            color: s.color("Color"),
+            offset_x: s.num("OffsetX").unwrap_or(0),
+            offset_y: s.num("OffsetY").unwrap_or(0),
+        }),
+        emboss: e.child("EMBOSS").is_some(),
+        engrave: e.child("ENGRAVE").is_some(),
+        superscript: e.child("SUPERSCRIPT").is_some(),
+        subscript: e.child("SUBSCRIPT").is_some(),
+    }
+}
+
+fn read_tab_def(e: &Element) -> TabDef {
+    TabDef {
+        id: e.num("Id").unwrap_or(0),
+        auto_tab_left: e.flag("AutoTabLeft").unwrap_or(false),
+        auto_tab_right: e.flag("AutoTabRight").unwrap_or(false),
+        items: e
+            .children_named("TABITEM")
+            .map(|t| TabItem {
+                pos: t.unit("Pos"),
+                tab_type: t.enum_attr("Type"),
+                leader: t.enum_attr("Leader"),
+            })
+            .collect(),
+    }
+}
+
+fn read_numbering(e: &Element) -> Numbering {
+    Numbering {
+        id: e.num("Id").unwrap_or(0),
+        start: e.num("Start").unwrap_or(1),
+        levels: e
+            .children_named("PARAHEAD")
+            .map(|h| NumberingLevel {
+                level: h.num("Level").unwrap_or(0),
+                start: h.num("Start").unwrap_or(1),
+                align: h.enum_attr("Alignment"),
+                use_inst_width: h.flag("UseInstWidth").unwrap_or(false),
+                auto_indent: h.flag("AutoIndent").unwrap_or(false),
+                width_adjust: h.unit("WidthAdjust"),
+                text_offset_type: value_unit(h.attr("TextOffsetType")),
+                text_offset: h.unit("TextOffset"),
+                num_format: h.enum_attr("NumFormat"),
+                char_shape_id: h.num::<u32>("CharShape").filter(|&id| id != u32::MAX),
+                checkable: h.flag("Checkable").unwrap_or(false),
+                format_string: h.text(),
+            })
+            .collect(),
+    }
+}
+
+fn read_bullet(e: &Element) -> Bullet {
+    let head = e.child("PARAHEAD");
+    let head_unit = |name: &str| head.map(|h| h.unit(name)).unwrap_or(0);
+    Bullet {
+        id: e.num("Id").unwrap_or(0),
+        bullet_char: e.attr("Char").and_then(|c| c.chars().next()).unwrap_or('●'),
+        checked_char: e.attr("CheckedChar").and_then(|c| c.chars().next()),
+        use_image: e.child("IMAGE").is_some(),
+        para_head: BulletParaHead {
+            level: head.and_then(|h| h.num("Level")).unwrap_or(0),
+            align: head.map(|h| h.enum_attr("Alignment")).unwrap_or_default(),
+            use_inst_width: head.and_then(|h| h.flag("UseInstWidth")).unwrap_or(false),
+            auto_indent: head.and_then(|h| h.flag("AutoIndent")).unwrap_or(false),
+            width_adjust: head_unit("WidthAdjust"),
+            text_offset_type: value_unit(head.and_then(|h| h.attr("TextOffsetType"))),
+            text_offset: head_unit("TextOffset"),
+            char_shape_id: head
+                .and_then(|h| h.num::<u32>("CharShape"))
+                .filter(|&id| id != u32::MAX),
+        },
+        image: e.child("IMAGE").map(|i| BulletImage {
+            binary_item_id: bin_id(i.attr("BinItem").unwrap_or_default()),
+            bright: i.num("Bright").unwrap_or(0),
+            contrast: i.num("Contrast").unwrap_or(0),
+            effect: i.enum_attr("Effect"),
+        }),
+    }
+}
+
+/// `Percent`/`HwpUnit` 단위 값 / Value unit
+fn value_unit(value: Option<&str>) -> ValueUnit {
+    match value {
+        Some("Char") => ValueUnit::Char,
+        _ => ValueUnit::HwpUnit,
+    }
+}
+
+fn read_para_shape(e: &Element) -> ParaShape {
+    let margin = e.child("PARAMARGIN");
+    let value = |name: &str| HwpValue {
+        value: margin.map(|m| m.unit(name)).unwrap_or(0),
+        unit: ValueUnit::HwpUnit,
+    };
+    let heading_type: HeadingType = e.enum_attr("HeadingType");
+    ParaShape {
+        id: e.num("Id").unwrap_or(0),
+        tab_def_id: e.num("TabDef"),
+        condense: e.num("Condense").unwrap_or(0),
+        font_line_height: e.flag("FontLineHeight").unwrap_or(false),
+        snap_to_grid: e.flag("SnapToGrid").unwrap_or(true),
+        align: ParagraphAlign {
+            horizontal: e.enum_attr("Align"),
+            vertical: e.enum_attr("VerAlign"),
+        },
+        heading: (heading_type != HeadingType::None).then(|| Heading {
+            heading_type,
+            id_ref: e.num("Heading").unwrap_or(0),
+            level: e.num("Level").unwrap_or(0),
+        }),
+        break_setting: BreakSetting {
+            break_latin_word: e.enum_attr("BreakLatinWord"),
+            // HWPML은 bool로 기록하기도 함 / Sometimes written as a boolean
+            break_non_latin_word: match e.attr("BreakNonLatinWord") {
+                Some("true") => BreakNonLatinWord::BreakWord,
+                Some("false") => BreakNonLatinWord::KeepWord,
+                Some(v) => enum_value(v).unwrap_or_default(),
+                None => BreakNonLatinWord::default(),
+            },
+            widow_orphan: e.flag("WidowOrphan").unwrap_or(false),
+            keep_with_next: e.flag("KeepWithNext").unwrap_or(false),
+            keep_lines: e.flag("KeepLines").unwrap_or(false),
+            page_break_before: e.flag("PageBreakBefore").unwrap_or(false),
+            line_wrap: e.enum_attr("LineWrap"),
+        },
+        auto_spacing: AutoSpacing {
+            east_asian_eng: e.flag("AutoSpaceEAsianEng").unwrap_or(false),
+            east_asian_num: e.flag("AutoSpaceEAsianNum").unwrap_or(false),
+        },
+        margin: ParagraphMargin {
+            indent: value("Indent"),
+            left: value("Left"),
+            right: value("Right"),
+            prev: value("Prev"),
+            next: value("Next"),
+        },
+        line_spacing: LineSpacing {
+            spacing_type: match margin.and_then(|m| m.attr("LineSpacingType")) {
+                Some("BetweenLines") => LineSpacingType::Between,
+                Some(v) => enum_value(v).unwrap_or_default(),
+                None => LineSpacingType::Percent,
+            },
+            value: margin.and_then(|m| m.num("LineSpacing")).unwrap_or(160),
+            unit: ValueUnit::HwpUnit,
+        },
+        border: e.child("PARABORDER").map(|b| ParagraphBorder {
+            border_fill_id: b.num("BorderFill").unwrap_or(0),
+            offset_left: b.unit("OffsetLeft"),
+            offset_right: b.unit("OffsetRight"),
+            offset_top: b.unit("OffsetTop"),
+            offset_bottom: b.unit("OffsetBottom"),
+            connect: b.flag("Connect").unwrap_or(false),
+            ignore_margin: b.flag("IgnoreMargin").unwrap_or(false),
+        }),
+        ..Default::default()
+    }
+}
+
+fn read_style(e: &Element) -> Style {
+    Style {
+        id: e.num("Id").unwrap_or(0),
+        style_type: e.enum_attr("Type"),
+        name: e.attr("Name").unwrap_or_default().to_string(),
+        eng_name: e.attr("EngName").unwrap_or_default().to_string(),
+        para_shape_id: e.num("ParaShape"),
+        char_shape_id: e.num("CharShape"),
+        next_style_id: e.num("NextStyle"),
+        lang_id: e.num("LangId"),
+        lock_form: e.num::<u8>("LockForm").map(|v| v != 0),
+    }
+}
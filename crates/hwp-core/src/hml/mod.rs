@@ -0,0 +1,235 @@
+//! HWPML(.hml) 문서 파서 / HWPML (.hml) document parser
+//!
+//! 한글의 XML 저장 형식(HWPML 2.x)을 읽어 `hwp_model::Document`로 직접 변환합니다.
+//! Reads Hangul's single-file XML format (HWPML 2.x) straight into `hwp_model::Document`,
+//! so every Document-based viewer works on it.
+//!
+//! 파일 구조 / File layout:
+//! - `HWPML/HEAD` — 문서 요약, 시작 번호, `MAPPINGTABLE`(글꼴, 테두리/배경, 글자/문단 모양, 스타일 등)
+//! - `HWPML/BODY/SECTION` — 문단 목록
+//! - `HWPML/TAIL/BINDATASTORAGE` — base64로 넣은 바이너리 (그림 등)
+
+mod body;
+mod head;
+mod xml;
+
+use crate::decompress::{decompress_deflate, decompress_zlib};
+use crate::error::HwpError;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use body::BodyReader;
+use head::{bin_id, read_head};
+use hwp_model::document::{BinaryItem, BinaryStore, Document, ImageFormat};
+use std::collections::HashMap;
+use xml::{parse_tree, Element};
+
+/// 형식 판별 시 살펴볼 앞부분 길이 / Prefix length inspected when detecting the format
+const SNIFF_LEN: usize = 1024;
+
+/// HWPML 문서인지 확인 (XML 선언 뒤 `HWPML` 루트) / Check whether `data` is an HWPML document
+pub fn is_hml(data: &[u8]) -> bool {
+    let head = &data[..data.len().min(SNIFF_LEN)];
+    let text = if head.starts_with(&[0xFF, 0xFE]) || head.starts_with(&[0xFE, 0xFF]) {
+        decode_utf16(head)
+    } else {
+        String::from_utf8_lossy(head).into_owned()
+    };
+    let text = text.trim_start_matches('\u{feff}').trim_start();
+    text.starts_with('<') && text.contains("<HWPML")
+}
+
+/// HWPML 바이트 배열 → Document / Parse an HWPML file into `Document`
+pub fn parse_hml(data: &[u8]) -> Result<Document, HwpError> {
+    let text = decode(data);
+    let root = parse_tree(text.trim_start_matches('\u{feff}'))?;
+    if root.name != "HWPML" {
+        return Err(HwpError::RecordParseError {
+            record_type: "HWPML".to_string(),
+            reason: format!("unexpected root element <{}>", root.name),
+        });
+    }
+
+    let head = root.child("HEAD").map(read_head).unwrap_or_default();
+    let mut reader = BodyReader::default();
+    let sections = root
+        .child("BODY")
+        .map(|body| {
+            body.children_named("SECTION")
+                .map(|section| reader.read_section(section))
+                .collect()
+        })
+        .unwrap_or_default();
+    let binaries = root
+        .child("TAIL")
+        .and_then(|tail| tail.child("BINDATASTORAGE"))
+        .map(|storage| read_binaries(storage, &head.bin_formats))
+        .unwrap_or_default();
+
+    Ok(Document {
+        meta: head.meta,
+        settings: head.settings,
+        resources: head.resources,
+        sections,
+        binaries,
+        ..Default::default()
+    })
+}
+
+/// UTF-8(BOM 선택) 또는 UTF-16(BOM 필수) 문서 디코딩 / Decode UTF-8 or BOM-marked UTF-16
+fn decode(data: &[u8]) -> String {
+    if data.starts_with(&[0xFF, 0xFE]) || data.starts_with(&[0xFE, 0xFF]) {
+        decode_utf16(data)
+    } else {
+        String::from_utf8_lossy(data).into_owned()
+    }
+}
+
+fn decode_utf16(data: &[u8]) -> String {
+    let big_endian = data.starts_with(&[0xFE, 0xFF]);
+    let units: Vec<u16> = data[2..]
+        .chunks_exact(2)
+        .map(|c| {
+            if big_endian {
+                u16::from_be_bytes([c[0], c[1]])
+            } else {
+                u16::from_le_bytes([c[0], c[1]])
+            }
+        })
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+/// `BINDATASTORAGE/BINDATA` → BinaryStore
+///
+/// `Encoding="Base64"` 내용을 디코딩하고, `Compress="true"`이면 압축을 풉니다
+/// (raw deflate → zlib 순서로 시도, 모두 실패하면 그대로 보관).
+/// Decodes base64 content and inflates it when `Compress="true"`
+/// (raw deflate, then zlib; kept as-is if both fail).
+fn read_binaries(storage: &Element, formats: &HashMap<String, String>) -> BinaryStore {
+    let mut items: Vec<BinaryItem> = storage
+        .children_named("BINDATA")
+        .filter_map(|bin| {
+            let id = bin_id(bin.attr("Id")?);
+            let encoded: String = bin
+                .text()
+                .chars()
+                .filter(|c| !c.is_ascii_whitespace())
+                .collect();
+            let mut data = STANDARD.decode(encoded.as_bytes()).ok()?;
+            if bin.flag("Compress").unwrap_or(false) {
+                if let Ok(inflated) = decompress_deflate(&data).or_else(|_| decompress_zlib(&data))
+                {
+                    data = inflated;
+                }
+            }
+            let format = image_format(&data, formats.get(&id).map_or("", String::as_str));
+            let ext = match &format {
+                ImageFormat::Unknown(ext) => ext.clone(),
+                other => format!("{:?}", other).to_lowercase(),
+            };
+            Some(BinaryItem {
+                src: format!("{}.{}", id, ext),
+                id,
+                format,
+                data,
+            })
+        })
+        .collect();
+    items.sort_by(|a, b| a.id.cmp(&b.id));
+    BinaryStore { items }
+}
+
+/// 매직 바이트 우선, 없으면 `BINITEM Format`으로 이미지 형식 판별
+fn image_format(data: &[u8], ext: &str) -> ImageFormat {
+    if data.starts_with(b"\x89PNG") {
+        ImageFormat::Png
+    } else if data.starts_with(&[0xFF, 0xD8]) {
+        ImageFormat::Jpg
+    } else if data.starts_with(b"BM") {
+        ImageFormat::Bmp
+    } else if data.starts_with(b"GIF8") {
+        ImageFormat::Gif
+    } else if data.starts_with(&[0xD7, 0xCD, 0xC6, 0x9A]) {
+        ImageFormat::Wmf
+    } else {
+        match ext.to_ascii_lowercase().as_str() {
+            "png" => ImageFormat::Png,
+            "jpg" | "jpeg" => ImageFormat::Jpg,
+            "bmp" => ImageFormat::Bmp,
+            "gif" => ImageFormat::Gif,
+            "tif" | "tiff" => ImageFormat::Tiff,
+            "wmf" => ImageFormat::Wmf,
+            "emf" => ImageFormat::Emf,
+            "svg" => ImageFormat::Svg,
+            other => ImageFormat::Unknown(other.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hwp_model::paragraph::RunContent;
+    use hwp_model::shape::ShapeObject;
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n0000";
+
+    fn hml(body: &str, tail: &str) -> String {
+        format!(
+            "\u{feff}<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+             <HWPML Version=\"2.8\"><HEAD SecCnt=\"1\"><MAPPINGTABLE>\
+             <BINDATALIST ItemCnt=\"1\"><BINITEM BinData=\"1\" Format=\"png\" Type=\"Embedding\"/></BINDATALIST>\
+             </MAPPINGTABLE></HEAD><BODY><SECTION Id=\"0\">{}</SECTION></BODY><TAIL>{}</TAIL></HWPML>",
+            body, tail
+        )
+    }
+
+    #[test]
+    fn detects_hml() {
+        assert!(is_hml(hml("", "").as_bytes()));
+        assert!(is_hml(
+            b"  <?xml version=\"1.0\"?>\n<HWPML Version=\"2.8\">"
+        ));
+        assert!(!is_hml(b"<?xml version=\"1.0\"?><html></html>"));
+        assert!(!is_hml(b"PK\x03\x04"));
+    }
+
+    #[test]
+    fn decodes_base64_binaries_and_picture_reference() {
+        let body = "<P ParaShape=\"0\" Style=\"0\"><TEXT CharShape=\"0\">\
+                    <PICTURE><SHAPEOBJECT InstId=\"7\"><SIZE Width=\"1000\" Height=\"500\"/></SHAPEOBJECT>\
+                    <IMAGE BinItem=\"1\" Bright=\"0\" Contrast=\"0\" Effect=\"RealPic\"/></PICTURE>\
+                    <CHAR>a<TAB/>b</CHAR></TEXT></P>";
+        let tail = format!(
+            "<BINDATASTORAGE><BINDATA Encoding=\"Base64\" Id=\"1\" Size=\"{}\">{}</BINDATA></BINDATASTORAGE>",
+            PNG.len(),
+            STANDARD.encode(PNG)
+        );
+        let doc = parse_hml(hml(body, &tail).as_bytes()).unwrap();
+
+        let item = &doc.binaries.items[0];
+        assert_eq!(item.id, "BIN0001");
+        assert_eq!(item.format, ImageFormat::Png);
+        assert_eq!(item.data, PNG);
+
+        let contents = &doc.sections[0].paragraphs[0].runs[0].contents;
+        let RunContent::Object(ShapeObject::Picture(picture)) = &contents[0] else {
+            panic!("expected picture, got {:?}", contents[0]);
+        };
+        assert_eq!(picture.img.binary_item_id, "BIN0001");
+        assert_eq!(picture.common.size.width, 1000);
+        let RunContent::Text(text) = &contents[1] else {
+            panic!("expected text");
+        };
+        let plain: String = text
+            .elements
+            .iter()
+            .filter_map(|e| e.plain_text())
+            .collect();
+        assert_eq!(plain, "a\tb");
+    }
+
+    #[test]
+    fn rejects_other_xml() {
+        assert!(parse_hml(b"<?xml version=\"1.0\"?><html/>").is_err());
+    }
+}
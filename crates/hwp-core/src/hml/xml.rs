@@ -0,0 +1,178 @@
+//! HWPML 요소 트리 / Minimal element tree for HWPML
+//!
+//! HWPML은 한 파일에 헤더/본문/바이너리가 모두 들어 있고 크기가 크지 않으므로,
+//! 한 번에 트리로 읽은 뒤 요소 단위로 모델에 옮깁니다.
+//! An HWPML file holds header, body and binaries in one small document, so it is read
+//! into a tree once and then mapped element by element.
+
+use crate::error::HwpError;
+use hwp_model::types::Color;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde::de::DeserializeOwned;
+use std::str::FromStr;
+
+/// XML 노드 / XML node
+pub(super) enum Node {
+    Element(Element),
+    Text(String),
+}
+
+/// XML 요소 / XML element
+pub(super) struct Element {
+    pub name: String,
+    attrs: Vec<(String, String)>,
+    pub children: Vec<Node>,
+}
+
+/// 문서 전체를 읽어 루트 요소 반환 / Read the whole document and return its root element
+pub(super) fn parse_tree(xml: &str) -> Result<Element, HwpError> {
+    let mut reader = Reader::from_str(xml);
+    // CHAR 안의 공백은 본문이므로 그대로 유지 / Whitespace inside CHAR is content
+    reader.config_mut().trim_text(false);
+
+    let mut stack: Vec<Element> = Vec::new();
+    let mut root: Option<Element> = None;
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| HwpError::RecordParseError {
+                record_type: "HWPML".to_string(),
+                reason: e.to_string(),
+            })?;
+        match event {
+            Event::Start(ref e) => stack.push(Element::from_start(e)),
+            Event::Empty(ref e) => {
+                let element = Element::from_start(e);
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(Node::Element(element)),
+                    None => root = Some(element),
+                }
+            }
+            Event::End(_) => {
+                let Some(element) = stack.pop() else {
+                    continue;
+                };
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(Node::Element(element)),
+                    None => root = Some(element),
+                }
+            }
+            Event::Text(ref t) => {
+                if let Some(parent) = stack.last_mut() {
+                    let text = t.unescape().map(|s| s.into_owned()).unwrap_or_default();
+                    parent.children.push(Node::Text(text));
+                }
+            }
+            Event::CData(ref t) => {
+                if let Some(parent) = stack.last_mut() {
+                    let text = String::from_utf8_lossy(t.as_ref()).into_owned();
+                    parent.children.push(Node::Text(text));
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    root.ok_or_else(|| HwpError::RecordParseError {
+        record_type: "HWPML".to_string(),
+        reason: "empty document".to_string(),
+    })
+}
+
+impl Element {
+    fn from_start(e: &BytesStart) -> Self {
+        let name = String::from_utf8_lossy(e.local_name().as_ref()).into_owned();
+        let attrs = e
+            .attributes()
+            .flatten()
+            .map(|a| {
+                let key = String::from_utf8_lossy(a.key.local_name().as_ref()).into_owned();
+                let value = a
+                    .unescape_value()
+                    .map(|v| v.into_owned())
+                    .unwrap_or_default();
+                (key, value)
+            })
+            .collect();
+        Self {
+            name,
+            attrs,
+            children: Vec::new(),
+        }
+    }
+
+    /// 속성 문자열 / Attribute string
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// 숫자 속성 / Numeric attribute
+    pub fn num<T: FromStr>(&self, name: &str) -> Option<T> {
+        self.attr(name).and_then(|v| v.trim().parse().ok())
+    }
+
+    /// 부호 있는 HWPUNIT 속성 (음수가 부호 없는 값으로 기록된 경우 포함)
+    /// Signed HWPUNIT attribute (also when a negative value is written unsigned)
+    pub fn unit(&self, name: &str) -> i32 {
+        self.num::<i64>(name).map(|v| v as i32).unwrap_or(0)
+    }
+
+    /// bool 속성 ("true"/"1") / Boolean attribute
+    pub fn flag(&self, name: &str) -> Option<bool> {
+        self.attr(name)
+            .map(|v| v.eq_ignore_ascii_case("true") || v == "1")
+    }
+
+    /// 색상 속성 (COLORREF 10진수, 0xFFFFFFFF는 없음) / Color attribute (decimal COLORREF)
+    pub fn color(&self, name: &str) -> Color {
+        let value = self.num::<i64>(name)? as u32;
+        if value == 0xFFFF_FFFF {
+            return None;
+        }
+        Some(crate::types::COLORREF(value & 0x00FF_FFFF).to_rgb())
+    }
+
+    /// 열거형 속성: HWPML 값 이름이 모델 열거형 이름과 같으므로 그대로 역직렬화
+    /// Enum attribute: HWPML value names match the model's variant names
+    pub fn enum_attr<T: DeserializeOwned + Default>(&self, name: &str) -> T {
+        self.attr(name).and_then(enum_value).unwrap_or_default()
+    }
+
+    /// 하위 요소 / Child elements
+    pub fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|node| match node {
+            Node::Element(e) => Some(e),
+            Node::Text(_) => None,
+        })
+    }
+
+    /// 이름이 같은 첫 하위 요소 / First child element with the given name
+    pub fn child(&self, name: &str) -> Option<&Element> {
+        self.elements().find(|e| e.name == name)
+    }
+
+    /// 이름이 같은 하위 요소들 / Child elements with the given name
+    pub fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.elements().filter(move |e| e.name == name)
+    }
+
+    /// 직접 포함한 텍스트 / Directly contained text
+    pub fn text(&self) -> String {
+        self.children
+            .iter()
+            .filter_map(|node| match node {
+                Node::Text(t) => Some(t.as_str()),
+                Node::Element(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// 값 이름 → 모델 열거형 / Value name → model enum
+pub(super) fn enum_value<T: DeserializeOwned>(value: &str) -> Option<T> {
+    serde_json::from_value(serde_json::Value::String(value.to_string())).ok()
+}
@@ -0,0 +1,833 @@
+//! HWP 3.x 문단 리스트 → hwp_model Paragraph
+//!
+//! 문단 리스트는 `글자 수 == 0`인 빈 문단으로 끝나며, 표 셀·머리말·각주 등
+//! 특수 문자 안에서 같은 구조가 재귀적으로 나타납니다.
+
+use std::collections::HashMap;
+
+use super::hchar::{hchar_string, kchar_string, push_hchar};
+use super::reader::Reader;
+use super::resources::{
+    hunit, palette_color, shade_color, ResourceRegistry, CHAR_SHAPE_SIZE, PARA_SHAPE_SIZE,
+};
+use crate::error::HwpError;
+use hwp_model::control::*;
+use hwp_model::paragraph::*;
+use hwp_model::resources::{FillBrush, ImageRef};
+use hwp_model::shape::*;
+use hwp_model::table::{Table, TableCell, TableRow};
+use hwp_model::types::*;
+
+/// 문단 끝
+const CH_END_PARA: u16 = 13;
+/// 줄 정보 레코드 크기
+const LINE_INFO_SIZE: usize = 14;
+/// 셀 정보 레코드 크기
+const CELL_INFO_SIZE: usize = 27;
+/// 중첩 문단 리스트 최대 깊이
+const MAX_DEPTH: usize = 32;
+
+/// 특수 문자가 본문에서 차지하는 hchar 수 (코드 0..32)
+const SPECIAL_CHAR_WIDTH: [usize; 32] = [
+    1, 4, 4, 4, 4, 4, 4, 42, 48, 4, 4, 4, 4, 1, 4, 4, 4, 4, 4, 4, 4, 4, 12, 5, 3, 3, 123, 4, 32, 4,
+    2, 2,
+];
+
+/// 글상자·그림·선이 공통으로 가지는 개체 정보 (62바이트)
+struct FloatingBox {
+    anchor: u8,
+    text_flow: u8,
+    x: i16,
+    y: i16,
+    /// 바깥/안/셀 여백 × 왼쪽/오른쪽/위/아래
+    margins: [[i16; 4]; 3],
+    width: i16,
+    height: i16,
+    caption_width: i16,
+    caption_margin: i16,
+}
+
+impl FloatingBox {
+    fn read(r: &mut Reader) -> Result<Self, HwpError> {
+        let anchor = r.u8("box anchor")?;
+        let text_flow = r.u8("box text flow")?;
+        let x = r.i16("box x")?;
+        let y = r.i16("box y")?;
+        r.skip(4, "box option")?;
+        let mut margins = [[0i16; 4]; 3];
+        for group in margins.iter_mut() {
+            for value in group.iter_mut() {
+                *value = r.i16("box margin")?;
+            }
+        }
+        let width = r.i16("box width")?;
+        let height = r.i16("box height")?;
+        let caption_width = r.i16("caption width")?;
+        r.skip(2, "caption height")?;
+        r.skip(2, "caption length")?;
+        r.skip(4, "box size")?;
+        let caption_margin = r.i16("caption margin")?;
+        // 위치 기준(2) 줄 간격 맞춤(1) 예약(1) 쪽 위치(8)
+        r.skip(12, "box layout")?;
+        Ok(Self {
+            anchor,
+            text_flow,
+            x,
+            y,
+            margins,
+            width,
+            height,
+            caption_width,
+            caption_margin,
+        })
+    }
+
+    fn margin(&self, index: usize) -> Margin {
+        let [left, right, top, bottom] = self.margins[index];
+        Margin {
+            left: hunit(left),
+            right: hunit(right),
+            top: hunit(top),
+            bottom: hunit(bottom),
+        }
+    }
+}
+
+/// 표 셀 정보 (27바이트)
+struct CellInfo {
+    color: u16,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+    vert_align: u8,
+    lines: [u8; 4],
+    shade: u8,
+}
+
+impl CellInfo {
+    fn read(r: &mut Reader) -> Result<Self, HwpError> {
+        let raw = r.bytes(CELL_INFO_SIZE, "table cell")?;
+        let word = |offset: usize| u16::from_le_bytes([raw[offset], raw[offset + 1]]) as i32;
+        Ok(Self {
+            color: word(2) as u16,
+            x: word(4),
+            y: word(6),
+            width: word(8),
+            height: word(10),
+            vert_align: raw[19],
+            lines: [raw[20], raw[21], raw[22], raw[23]],
+            shade: raw[24],
+        })
+    }
+}
+
+/// 같은 글자 모양의 연속 콘텐츠를 Run으로 묶음
+#[derive(Default)]
+struct RunBuilder {
+    runs: Vec<Run>,
+}
+
+impl RunBuilder {
+    fn run(&mut self, char_shape_id: u16) -> &mut Run {
+        if self
+            .runs
+            .last()
+            .map_or(true, |run| run.char_shape_id != char_shape_id)
+        {
+            self.runs.push(Run {
+                char_shape_id,
+                contents: Vec::new(),
+            });
+        }
+        self.runs.last_mut().unwrap()
+    }
+
+    fn text_elements(&mut self, char_shape_id: u16) -> &mut Vec<TextElement> {
+        let run = self.run(char_shape_id);
+        if !matches!(run.contents.last(), Some(RunContent::Text(_))) {
+            run.contents.push(RunContent::Text(TextContent::default()));
+        }
+        match run.contents.last_mut() {
+            Some(RunContent::Text(text)) => &mut text.elements,
+            _ => unreachable!(),
+        }
+    }
+
+    fn push_hchar(&mut self, char_shape_id: u16, code: u16) {
+        let elements = self.text_elements(char_shape_id);
+        if !matches!(elements.last(), Some(TextElement::Text(_))) {
+            elements.push(TextElement::Text(String::new()));
+        }
+        if let Some(TextElement::Text(text)) = elements.last_mut() {
+            push_hchar(text, code);
+        }
+    }
+
+    fn push_text(&mut self, char_shape_id: u16, text: String) {
+        self.text_elements(char_shape_id)
+            .push(TextElement::Text(text));
+    }
+
+    fn push_element(&mut self, char_shape_id: u16, element: TextElement) {
+        self.text_elements(char_shape_id).push(element);
+    }
+
+    fn push_content(&mut self, char_shape_id: u16, content: RunContent) {
+        self.run(char_shape_id).contents.push(content);
+    }
+}
+
+/// 문단 리스트 파서
+pub(crate) struct BodyParser<'a> {
+    registry: &'a mut ResourceRegistry,
+    /// 그림 파일 이름 → BinaryItem id
+    pub(crate) picture_ids: HashMap<String, String>,
+    last_para_shape: u16,
+    next_id: u64,
+    depth: usize,
+}
+
+impl<'a> BodyParser<'a> {
+    pub(crate) fn new(registry: &'a mut ResourceRegistry) -> Self {
+        Self {
+            registry,
+            picture_ids: HashMap::new(),
+            last_para_shape: 0,
+            next_id: 0,
+            depth: 0,
+        }
+    }
+
+    fn next_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    /// 빈 문단으로 끝나는 문단 리스트 읽기
+    pub(crate) fn read_para_list(&mut self, r: &mut Reader) -> Result<Vec<Paragraph>, HwpError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(HwpError::RecordParseError {
+                record_type: "HWP3 paragraph list".to_string(),
+                reason: format!("nesting deeper than {}", MAX_DEPTH),
+            });
+        }
+        self.depth += 1;
+        let mut paragraphs = Vec::new();
+        while let Some(paragraph) = self.read_paragraph(r)? {
+            paragraphs.push(paragraph);
+        }
+        self.depth -= 1;
+        Ok(paragraphs)
+    }
+
+    /// 문단 하나 읽기. 리스트 끝(빈 문단)이면 None
+    fn read_paragraph(&mut self, r: &mut Reader) -> Result<Option<Paragraph>, HwpError> {
+        let reuse_shape = r.u8("paragraph reuse shape")?;
+        let char_count = r.u16("paragraph char count")? as usize;
+        let line_count = r.u16("paragraph line count")? as usize;
+        let contains_char_shapes = r.u8("paragraph char shape flag")?;
+        let etc_flag = r.u8("paragraph flag")?;
+        r.skip(4, "paragraph control mask")?;
+        let style_id = r.u8("paragraph style")?;
+        let rep_char_shape = r.bytes(CHAR_SHAPE_SIZE, "paragraph char shape")?;
+        let para_shape = if reuse_shape == 0 {
+            Some(r.bytes(PARA_SHAPE_SIZE, "paragraph shape")?)
+        } else {
+            None
+        };
+        if char_count == 0 {
+            return Ok(None);
+        }
+
+        let para_shape_id = match para_shape {
+            Some(raw) => self.registry.para_shape(raw),
+            None => self.last_para_shape,
+        };
+        self.last_para_shape = para_shape_id;
+        let rep_char_shape_id = self.registry.char_shape(rep_char_shape);
+
+        r.skip(line_count * LINE_INFO_SIZE, "paragraph line info")?;
+
+        // 글자별 글자 모양: 1이면 대표 글자 모양, 0이면 모양 31바이트가 이어짐
+        let mut char_shape_ids = vec![rep_char_shape_id; char_count];
+        if contains_char_shapes != 0 {
+            for id in char_shape_ids.iter_mut() {
+                if r.u8("char shape flag")? == 0 {
+                    *id = self
+                        .registry
+                        .char_shape(r.bytes(CHAR_SHAPE_SIZE, "char shape")?);
+                }
+            }
+        }
+
+        let mut builder = RunBuilder::default();
+        let mut pos = 0usize;
+        loop {
+            let code = r.u16("paragraph text")?;
+            if code == CH_END_PARA {
+                break;
+            }
+            let char_shape_id = char_shape_ids[pos.min(char_count - 1)];
+            if code >= 32 {
+                builder.push_hchar(char_shape_id, code);
+                pos += 1;
+            } else {
+                self.read_special(r, code, char_shape_id, &mut builder)?;
+                pos += SPECIAL_CHAR_WIDTH[code as usize];
+            }
+        }
+
+        let mut runs = builder.runs;
+        if runs.is_empty() {
+            runs.push(Run {
+                char_shape_id: rep_char_shape_id,
+                contents: Vec::new(),
+            });
+        }
+
+        Ok(Some(Paragraph {
+            id: self.next_id(),
+            para_shape_id,
+            style_id: style_id as u16,
+            page_break: etc_flag & 0x06 != 0,
+            column_break: etc_flag & 0x06 == 0 && etc_flag & 0x01 != 0,
+            runs,
+            has_char_shapes: true,
+            ..Default::default()
+        }))
+    }
+
+    /// `[코드][길이 dword][코드]` 머리 읽기. 길이를 반환
+    fn read_block_head(r: &mut Reader, code: u16) -> Result<usize, HwpError> {
+        let len = r.u32("special char length")? as usize;
+        let check = r.u16("special char code")?;
+        if check != code {
+            return Err(HwpError::UnexpectedValue {
+                field: "HWP3 special char".to_string(),
+                expected: code.to_string(),
+                found: check.to_string(),
+            });
+        }
+        Ok(len)
+    }
+
+    fn read_special(
+        &mut self,
+        r: &mut Reader,
+        code: u16,
+        cs: u16,
+        out: &mut RunBuilder,
+    ) -> Result<(), HwpError> {
+        match code {
+            // 필드 코드, 책갈피, 그 외 길이로 건너뛰는 블록
+            1..=6 | 12 | 27 | 29 => {
+                let len = Self::read_block_head(r, code)?;
+                let data = r.bytes(len, "special char data")?;
+                if code == 6 && data.len() >= 30 {
+                    let name: Vec<u16> = data[..30]
+                        .chunks_exact(2)
+                        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                        .collect();
+                    out.push_content(
+                        cs,
+                        RunContent::Control(Control::Bookmark(Bookmark {
+                            name: hchar_string(&name),
+                        })),
+                    );
+                }
+            }
+            // 날짜 형식
+            7 => r.skip(82, "date format")?,
+            // 날짜 코드: 형식(40 hchar) + 연/월/요일/일/시/분
+            8 => {
+                r.skip(80, "date code format")?;
+                let date = r.hchars(6, "date code")?;
+                r.skip(2, "date code")?;
+                out.push_text(cs, format!("{}년 {}월 {}일", date[0], date[1], date[3]));
+            }
+            9 => {
+                let width = r.u16("tab width")?;
+                let leader = r.u16("tab leader")?;
+                r.skip(2, "tab")?;
+                out.push_element(
+                    cs,
+                    TextElement::Tab {
+                        width: hunit(width),
+                        leader: if leader != 0 {
+                            LineType2::Dot
+                        } else {
+                            LineType2::None
+                        },
+                        tab_type: TabType::Left,
+                    },
+                );
+            }
+            10 => {
+                Self::read_block_head(r, code)?;
+                if let Some(object) = self.read_text_box(r)? {
+                    out.push_content(cs, RunContent::Object(object));
+                }
+            }
+            11 => {
+                let follow_len = Self::read_block_head(r, code)?;
+                if let Some(object) = self.read_picture(r, follow_len)? {
+                    out.push_content(cs, RunContent::Object(object));
+                }
+            }
+            14 => {
+                Self::read_block_head(r, code)?;
+                let object = self.read_line(r)?;
+                out.push_content(cs, RunContent::Object(object));
+            }
+            15 => {
+                Self::read_block_head(r, code)?;
+                r.skip(8, "hidden info")?;
+                let paragraphs = self.read_para_list(r)?;
+                out.push_content(
+                    cs,
+                    RunContent::Control(Control::HiddenDesc(HiddenDesc { paragraphs })),
+                );
+            }
+            16 => {
+                Self::read_block_head(r, code)?;
+                r.skip(8, "header info")?;
+                let kind = r.u8("header kind")?;
+                let apply = r.u8("header apply page")?;
+                let paragraphs = self.read_para_list(r)?;
+                let header_footer = HeaderFooter {
+                    id: self.next_id(),
+                    apply_page_type: match apply {
+                        1 => PageApplyType::Even,
+                        2 => PageApplyType::Odd,
+                        _ => PageApplyType::Both,
+                    },
+                    content: SubList {
+                        paragraphs,
+                        ..Default::default()
+                    },
+                };
+                let control = if kind == 0 {
+                    Control::Header(header_footer)
+                } else {
+                    Control::Footer(header_footer)
+                };
+                out.push_content(cs, RunContent::Control(control));
+            }
+            17 => {
+                Self::read_block_head(r, code)?;
+                r.skip(8, "note info")?;
+                let number = r.u16("note number")?;
+                let kind = r.u16("note kind")?;
+                r.skip(2, "note width")?;
+                let paragraphs = self.read_para_list(r)?;
+                let note = Note {
+                    id: self.next_id(),
+                    number: Some(number),
+                    content: SubList {
+                        paragraphs,
+                        ..Default::default()
+                    },
+                };
+                let control = if kind == 1 {
+                    Control::EndNote(note)
+                } else {
+                    Control::FootNote(note)
+                };
+                out.push_content(cs, RunContent::Control(control));
+            }
+            18 => {
+                let kind = r.u16("auto number kind")?;
+                let number = r.u16("auto number")?;
+                r.skip(2, "auto number")?;
+                let num_type = match kind {
+                    0 => AutoNumType::Page,
+                    1 => AutoNumType::Footnote,
+                    2 => AutoNumType::Endnote,
+                    4 => AutoNumType::Table,
+                    5 => AutoNumType::Equation,
+                    _ => AutoNumType::Picture,
+                };
+                out.push_content(
+                    cs,
+                    RunContent::Control(Control::AutoNum(AutoNum {
+                        num_type,
+                        num: number,
+                        ..Default::default()
+                    })),
+                );
+            }
+            19 => {
+                let kind = r.u16("new number kind")?;
+                let number = r.u16("new number")?;
+                r.skip(2, "new number")?;
+                let num_type = match kind {
+                    3 => NumberingType::Picture,
+                    4 => NumberingType::Table,
+                    5 => NumberingType::Equation,
+                    // 쪽/각주 번호는 모델에 대응 항목이 없음
+                    _ => return Ok(()),
+                };
+                out.push_content(
+                    cs,
+                    RunContent::Control(Control::NewNum(NewNum {
+                        num_type,
+                        num: number,
+                    })),
+                );
+            }
+            // 쪽 번호 위치, 홀수쪽 시작/감추기
+            20 | 21 => r.skip(6, "page number control")?,
+            // 메일 머지
+            22 => r.skip(22, "mail merge")?,
+            23 => {
+                let chars = r.hchars(3, "compose")?;
+                r.skip(2, "compose")?;
+                out.push_content(
+                    cs,
+                    RunContent::Control(Control::Compose(Compose {
+                        compose_text: Some(hchar_string(&chars)),
+                        ..Default::default()
+                    })),
+                );
+            }
+            24 => {
+                r.skip(4, "hyphen")?;
+                out.push_element(cs, TextElement::Hyphen);
+            }
+            // 차례 표시, 찾아보기 표시, 개요
+            25 => r.skip(4, "toc mark")?,
+            26 => r.skip(244, "index mark")?,
+            28 => r.skip(62, "outline")?,
+            30 => {
+                r.skip(2, "keep space")?;
+                out.push_element(cs, TextElement::NbSpace);
+            }
+            31 => {
+                r.skip(2, "fixed space")?;
+                out.push_element(cs, TextElement::FwSpace);
+            }
+            // 그 밖의 코드는 추가 데이터 없는 1 hchar로 취급
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn shape_common(
+        &mut self,
+        fbox: &FloatingBox,
+        numbering_type: NumberingType,
+        caption: Option<Caption>,
+    ) -> ShapeCommon {
+        let rel_to = if fbox.anchor == 1 {
+            RelativeTo::Page
+        } else {
+            RelativeTo::Para
+        };
+        ShapeCommon {
+            id: self.next_id(),
+            numbering_type,
+            text_wrap: match fbox.text_flow {
+                1 => TextWrap::InFrontOfText,
+                2 => TextWrap::Square,
+                _ => TextWrap::TopAndBottom,
+            },
+            size: ShapeSize {
+                width: hunit(fbox.width),
+                height: hunit(fbox.height),
+                ..Default::default()
+            },
+            position: ShapePosition {
+                treat_as_char: fbox.anchor == 2,
+                affect_line_spacing: fbox.anchor == 2,
+                vert_rel_to: rel_to.clone(),
+                horz_rel_to: rel_to,
+                vert_align: VAlign::Top,
+                horz_align: HAlign::Left,
+                vert_offset: hunit(fbox.y),
+                horz_offset: hunit(fbox.x),
+                ..Default::default()
+            },
+            out_margin: Some(fbox.margin(0)),
+            caption,
+            ..Default::default()
+        }
+    }
+
+    /// 캡션 문단 리스트 → Caption (내용이 없으면 None)
+    fn caption(fbox: &FloatingBox, position: u16, paragraphs: Vec<Paragraph>) -> Option<Caption> {
+        let has_text = paragraphs
+            .iter()
+            .any(|p| !paragraph_text(p).trim().is_empty());
+        has_text.then(|| Caption {
+            side: if position % 2 == 1 {
+                CaptionSide::Top
+            } else {
+                CaptionSide::Bottom
+            },
+            full_size: false,
+            width: hunit(fbox.caption_width),
+            gap: hunit(fbox.caption_margin),
+            last_width: None,
+            content: SubList {
+                paragraphs,
+                ..Default::default()
+            },
+        })
+    }
+
+    /// 표/글상자/수식/버튼 (고정 84바이트 + 셀 정보 + 셀별 문단 리스트 + 캡션)
+    fn read_text_box(&mut self, r: &mut Reader) -> Result<Option<ShapeObject>, HwpError> {
+        let fbox = FloatingBox::read(r)?;
+        let caption_position = r.u16("caption position")?;
+        r.skip(6, "text box")?;
+        let kind = r.u16("text box kind")?;
+        let cell_count = r.u16("cell count")? as usize;
+        r.skip(2, "cell protect")?;
+        if cell_count == 0 {
+            return Err(HwpError::UnexpectedValue {
+                field: "HWP3 text box cell count".to_string(),
+                expected: "> 0".to_string(),
+                found: "0".to_string(),
+            });
+        }
+        let cells = (0..cell_count)
+            .map(|_| CellInfo::read(r))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut lists = Vec::with_capacity(cell_count);
+        for _ in 0..cell_count {
+            lists.push(self.read_para_list(r)?);
+        }
+        let caption_paras = self.read_para_list(r)?;
+        let caption = Self::caption(&fbox, caption_position, caption_paras);
+
+        let object = match kind {
+            0 => {
+                let common = self.shape_common(&fbox, NumberingType::Table, caption);
+                ShapeObject::Table(Box::new(self.build_table(&fbox, common, cells, lists)))
+            }
+            2 => {
+                let common = self.shape_common(&fbox, NumberingType::Equation, caption);
+                let script = lists
+                    .into_iter()
+                    .flatten()
+                    .map(|p| paragraph_text(&p))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                ShapeObject::Equation(Box::new(EquationObject {
+                    common,
+                    script,
+                    base_unit: 1000,
+                    text_color: Some(0),
+                    ..Default::default()
+                }))
+            }
+            _ => {
+                let common = self.shape_common(&fbox, NumberingType::None, caption);
+                let cell = &cells[0];
+                let width = common.size.width;
+                let height = common.size.height;
+                let paragraphs = lists.into_iter().next().unwrap_or_default();
+                ShapeObject::Rectangle(Box::new(RectObject {
+                    common,
+                    points: [
+                        Point { x: 0, y: 0 },
+                        Point { x: width, y: 0 },
+                        Point {
+                            x: width,
+                            y: height,
+                        },
+                        Point { x: 0, y: height },
+                    ],
+                    line_shape: ShapeLineInfo {
+                        color: Some(0),
+                        width: if cell.lines.iter().any(|&l| l != 0) {
+                            33
+                        } else {
+                            0
+                        },
+                        style: if cell.lines.iter().any(|&l| l != 0) {
+                            LineType1::Solid
+                        } else {
+                            LineType1::None
+                        },
+                        ..Default::default()
+                    },
+                    fill: (cell.shade > 0).then(|| FillBrush::WinBrush {
+                        face_color: Some(shade_color(palette_color(cell.color as u8), cell.shade)),
+                        hatch_color: None,
+                        hatch_style: None,
+                        alpha: 0,
+                    }),
+                    draw_text: Some(SubList {
+                        vert_align: vert_align(cell.vert_align),
+                        paragraphs,
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }))
+            }
+        };
+        Ok(Some(object))
+    }
+
+    /// 셀 좌표(x, y, 너비, 높이)로부터 행/열 격자를 복원
+    fn build_table(
+        &mut self,
+        fbox: &FloatingBox,
+        common: ShapeCommon,
+        cells: Vec<CellInfo>,
+        lists: Vec<Vec<Paragraph>>,
+    ) -> Table {
+        let mut xs: Vec<i32> = cells.iter().flat_map(|c| [c.x, c.x + c.width]).collect();
+        let mut ys: Vec<i32> = cells.iter().flat_map(|c| [c.y, c.y + c.height]).collect();
+        xs.sort_unstable();
+        xs.dedup();
+        ys.sort_unstable();
+        ys.dedup();
+        let index = |edges: &[i32], v: i32| edges.binary_search(&v).unwrap_or(0) as u16;
+
+        let cell_margin = fbox.margin(2);
+        let mut rows: Vec<TableRow> = (1..ys.len()).map(|_| TableRow::default()).collect();
+        for (cell, paragraphs) in cells.iter().zip(lists) {
+            let col = index(&xs, cell.x);
+            let row = index(&ys, cell.y);
+            let border_fill_id = self
+                .registry
+                .border_fill(cell.lines, cell.shade, cell.color);
+            let model_cell = TableCell {
+                has_margin: Some(true),
+                editable: true,
+                border_fill_id,
+                col,
+                row,
+                col_span: index(&xs, cell.x + cell.width).saturating_sub(col).max(1),
+                row_span: index(&ys, cell.y + cell.height).saturating_sub(row).max(1),
+                width: hunit(cell.width),
+                height: hunit(cell.height),
+                cell_margin: cell_margin.clone(),
+                content: SubList {
+                    vert_align: vert_align(cell.vert_align),
+                    paragraphs,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            if let Some(target) = rows.get_mut(row as usize) {
+                target.cells.push(model_cell);
+            }
+        }
+        for row in rows.iter_mut() {
+            row.cells.sort_by_key(|c| c.col);
+        }
+
+        Table {
+            common,
+            row_count: ys.len().saturating_sub(1) as u16,
+            col_count: xs.len().saturating_sub(1) as u16,
+            border_fill_id: self.registry.border_fill([0; 4], 0, 0),
+            in_margin: fbox.margin(1),
+            rows,
+            ..Default::default()
+        }
+    }
+
+    /// 그림 (고정 348바이트 + 추가 정보 + 캡션)
+    fn read_picture(
+        &mut self,
+        r: &mut Reader,
+        follow_len: usize,
+    ) -> Result<Option<ShapeObject>, HwpError> {
+        let fbox = FloatingBox::read(r)?;
+        let caption_position = r.u16("caption position")?;
+        r.skip(2, "picture number")?;
+        r.skip(1, "picture type")?;
+        r.skip(8, "picture crop/scale")?;
+        let path = kchar_string(r.bytes(256, "picture path")?);
+        r.skip(9, "picture reserved")?;
+        r.skip(follow_len, "picture follow block")?;
+        let caption_paras = self.read_para_list(r)?;
+        if path.is_empty() {
+            // 그리기 개체는 아직 지원하지 않음
+            return Ok(None);
+        }
+        let caption = Self::caption(&fbox, caption_position, caption_paras);
+        let next = self.picture_ids.len() + 1;
+        let binary_item_id = self
+            .picture_ids
+            .entry(path)
+            .or_insert_with(|| format!("BIN{:04X}", next))
+            .clone();
+        let common = self.shape_common(&fbox, NumberingType::Picture, caption);
+        let size = Size {
+            width: common.size.width,
+            height: common.size.height,
+        };
+        Ok(Some(ShapeObject::Picture(Box::new(Picture {
+            common,
+            in_margin: Some(fbox.margin(1)),
+            img_dim: Some(size),
+            img: ImageRef {
+                binary_item_id,
+                ..Default::default()
+            },
+            ..Default::default()
+        }))))
+    }
+
+    /// 선 (고정 84바이트)
+    fn read_line(&mut self, r: &mut Reader) -> Result<ShapeObject, HwpError> {
+        let fbox = FloatingBox::read(r)?;
+        let mut coords = [0i16; 4];
+        for value in coords.iter_mut() {
+            *value = r.i16("line point")?;
+        }
+        let width = r.u16("line width")?;
+        r.skip(2, "line shade")?;
+        let color = r.u16("line color")?;
+        let common = self.shape_common(&fbox, NumberingType::None, None);
+        Ok(ShapeObject::Line(Box::new(LineObject {
+            common,
+            start_pt: Point {
+                x: hunit(coords[0]),
+                y: hunit(coords[1]),
+            },
+            end_pt: Point {
+                x: hunit(coords[2]),
+                y: hunit(coords[3]),
+            },
+            line_shape: ShapeLineInfo {
+                color: Some(palette_color(color as u8)),
+                width: hunit(width as i32).max(1),
+                style: LineType1::Solid,
+                ..Default::default()
+            },
+            ..Default::default()
+        })))
+    }
+}
+
+fn vert_align(value: u8) -> VAlign {
+    match value {
+        1 => VAlign::Center,
+        2 => VAlign::Bottom,
+        _ => VAlign::Top,
+    }
+}
+
+/// 문단의 일반 텍스트만 이어 붙임 (수식 스크립트, 캡션 판별용)
+fn paragraph_text(paragraph: &Paragraph) -> String {
+    let mut text = String::new();
+    for run in &paragraph.runs {
+        for content in &run.contents {
+            if let RunContent::Text(t) = content {
+                for element in &t.elements {
+                    if let TextElement::Text(s) = element {
+                        text.push_str(s);
+                    }
+                }
+            }
+        }
+    }
+    text
+}
@@ -0,0 +1,198 @@
+//! HWP 3.x 내부 문자 코드 변환 / HWP 3.x internal character code conversion
+//!
+//! HWP 3.x 본문은 2바이트 `hchar` 단위로 저장됩니다.
+//! - `0x0000..0x0080`: ASCII
+//! - 최상위 비트가 켜진 코드: 조합형 한글 (초성 5비트, 중성 5비트, 종성 5비트)
+//! - `0x3400..`: KS C 5601 기호 (1~12행, 행당 94자)
+//! - `0x4000..`: KS C 5601 한자 (4888자)
+//!
+//! `kchar` 문자열(글꼴 이름, 스타일 이름, 그림 경로)은 1바이트 ASCII와
+//! 2바이트(빅 엔디언) 내부 코드가 섞인 형태이므로 같은 변환을 사용합니다.
+
+use encoding_rs::EUC_KR;
+
+const SYMBOL_BASE: u16 = 0x3400;
+const SYMBOL_COUNT: u16 = 94 * 12;
+const HANJA_BASE: u16 = 0x4000;
+const HANJA_COUNT: u16 = 4888;
+
+/// 호환용 자모 초성 (ㄱ..ㅎ)
+const COMPAT_CHOSEONG: [u32; 19] = [
+    0x3131, 0x3132, 0x3134, 0x3137, 0x3138, 0x3139, 0x3141, 0x3142, 0x3143, 0x3145, 0x3146, 0x3147,
+    0x3148, 0x3149, 0x314A, 0x314B, 0x314C, 0x314D, 0x314E,
+];
+
+/// 호환용 자모 종성 (ㄱ..ㅎ, 채움 제외)
+const COMPAT_JONGSEONG: [u32; 27] = [
+    0x3131, 0x3132, 0x3133, 0x3134, 0x3135, 0x3136, 0x3137, 0x3139, 0x313A, 0x313B, 0x313C, 0x313D,
+    0x313E, 0x313F, 0x3140, 0x3141, 0x3142, 0x3144, 0x3145, 0x3146, 0x3147, 0x3148, 0x314A, 0x314B,
+    0x314C, 0x314D, 0x314E,
+];
+
+/// 조합형 초성 5비트 → 현대 한글 초성 인덱스 (0..19), 채움이면 None
+fn choseong_index(bits: u16) -> Option<u32> {
+    match bits {
+        2..=20 => Some((bits - 2) as u32),
+        _ => None,
+    }
+}
+
+/// 조합형 중성 5비트 → 중성 인덱스 (0..21), 채움이면 None
+fn jungseong_index(bits: u16) -> Option<u32> {
+    match bits {
+        3..=7 => Some((bits - 3) as u32),
+        10..=15 => Some((bits - 5) as u32),
+        18..=23 => Some((bits - 7) as u32),
+        26..=29 => Some((bits - 9) as u32),
+        _ => None,
+    }
+}
+
+/// 조합형 종성 5비트 → 종성 인덱스 (1..28), 채움이면 None
+fn jongseong_index(bits: u16) -> Option<u32> {
+    match bits {
+        2..=17 => Some((bits - 1) as u32),
+        19..=29 => Some((bits - 2) as u32),
+        _ => None,
+    }
+}
+
+/// KS C 5601 2바이트 코드를 EUC-KR로 디코딩
+fn decode_ksc(hi: u8, lo: u8) -> Option<char> {
+    let bytes = [hi, lo];
+    let (text, had_errors) = EUC_KR.decode_without_bom_handling(&bytes);
+    if had_errors {
+        return None;
+    }
+    text.chars().next()
+}
+
+/// 조합형 한글 코드 하나를 출력 문자열에 추가
+fn push_johab(out: &mut String, code: u16) -> bool {
+    let cho = choseong_index((code >> 10) & 0x1F);
+    let jung = jungseong_index((code >> 5) & 0x1F);
+    let jong = jongseong_index(code & 0x1F);
+
+    let push = |out: &mut String, cp: u32| {
+        if let Some(c) = char::from_u32(cp) {
+            out.push(c);
+        }
+    };
+    match (cho, jung, jong) {
+        (Some(l), Some(v), t) => push(out, 0xAC00 + (l * 21 + v) * 28 + t.unwrap_or(0)),
+        (Some(l), None, None) => push(out, COMPAT_CHOSEONG[l as usize]),
+        (None, Some(v), None) => push(out, 0x314F + v),
+        (None, None, Some(t)) => push(out, COMPAT_JONGSEONG[t as usize - 1]),
+        (None, None, None) => return false,
+        // 옛 조합(초성+종성 등)은 첫가끝 자모로 풀어서 표현
+        (l, v, t) => {
+            push(out, l.map(|l| 0x1100 + l).unwrap_or(0x115F));
+            push(out, v.map(|v| 0x1161 + v).unwrap_or(0x1160));
+            if let Some(t) = t {
+                push(out, 0x11A7 + t);
+            }
+        }
+    }
+    true
+}
+
+/// hchar 하나를 유니코드로 변환해 추가. 변환할 수 없는 코드는 U+FFFD로 표시
+pub(crate) fn push_hchar(out: &mut String, code: u16) {
+    let ok = match code {
+        0x0020..=0x007F => {
+            out.push(code as u8 as char);
+            true
+        }
+        c if c & 0x8000 != 0 => push_johab(out, c),
+        c if (SYMBOL_BASE..SYMBOL_BASE + SYMBOL_COUNT).contains(&c) => {
+            let index = c - SYMBOL_BASE;
+            decode_ksc((0xA1 + index / 94) as u8, (0xA1 + index % 94) as u8)
+                .map(|ch| out.push(ch))
+                .is_some()
+        }
+        c if (HANJA_BASE..HANJA_BASE + HANJA_COUNT).contains(&c) => {
+            let index = c - HANJA_BASE;
+            decode_ksc((0xCA + index / 94) as u8, (0xA1 + index % 94) as u8)
+                .map(|ch| out.push(ch))
+                .is_some()
+        }
+        _ => false,
+    };
+    if !ok {
+        out.push(char::REPLACEMENT_CHARACTER);
+    }
+}
+
+/// NUL로 끝나는 hchar 배열 → String
+pub(crate) fn hchar_string(codes: &[u16]) -> String {
+    let mut out = String::new();
+    for &code in codes.iter().take_while(|&&c| c != 0) {
+        push_hchar(&mut out, code);
+    }
+    out.trim_end().to_string()
+}
+
+/// NUL로 끝나는 kchar 바이트열 → String
+pub(crate) fn kchar_string(bytes: &[u8]) -> String {
+    let mut out = String::new();
+    let mut i = 0;
+    while i < bytes.len() && bytes[i] != 0 {
+        let b = bytes[i];
+        if b < 0x80 {
+            out.push(b as char);
+            i += 1;
+        } else if i + 1 < bytes.len() && bytes[i + 1] != 0 {
+            push_hchar(&mut out, u16::from_be_bytes([b, bytes[i + 1]]));
+            i += 2;
+        } else {
+            break;
+        }
+    }
+    out.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(code: u16) -> String {
+        let mut out = String::new();
+        push_hchar(&mut out, code);
+        out
+    }
+
+    #[test]
+    fn test_johab_syllables() {
+        assert_eq!(convert(0x8861), "가");
+        assert_eq!(convert(0xD065), "한");
+        assert_eq!(convert(0x8B69), "글");
+    }
+
+    #[test]
+    fn test_johab_single_jamo() {
+        // 초성 ㄱ + 중성/종성 채움
+        assert_eq!(convert(0x8841), "ㄱ");
+        // 초성 채움 + 중성 ㅏ + 종성 채움
+        assert_eq!(convert(0x8461), "ㅏ");
+    }
+
+    #[test]
+    fn test_ascii_and_unknown() {
+        assert_eq!(convert(b'A' as u16), "A");
+        assert_eq!(convert(0x0005), "\u{FFFD}");
+    }
+
+    #[test]
+    fn test_hanja_and_symbol() {
+        // KS C 5601 한자 첫 글자 (0xCAA1) = 伽
+        assert_eq!(convert(HANJA_BASE), "伽");
+        // KS C 5601 기호 첫 행 두 번째 (0xA1A2) = 、
+        assert_eq!(convert(SYMBOL_BASE + 1), "、");
+    }
+
+    #[test]
+    fn test_kchar_string() {
+        let bytes = [b'A', 0x88, 0x61, b' ', 0xD0, 0x65, 0, b'x'];
+        assert_eq!(kchar_string(&bytes), "A가 한");
+    }
+}
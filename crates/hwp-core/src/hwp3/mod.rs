@@ -0,0 +1,284 @@
+//! HWP 3.x 문서 파서 / HWP 3.x ("HWP Document File V3.00") parser
+//!
+//! 한글 97 이전의 단일 바이너리 형식을 읽어 `hwp_model::Document`로 직접 변환합니다.
+//! Reads the pre-HWP 5.0 single binary format straight into `hwp_model::Document`,
+//! so every Document-based viewer (`doc_html`, `doc_markdown`, PDF, SVG, DOCX...) works on it.
+//!
+//! 파일 구조 / File layout:
+//! 1. 파일 인식 정보 (30바이트)
+//! 2. 문서 정보 (128바이트) — 용지 설정, 압축 여부, 정보 블록 길이
+//! 3. 문서 요약 (1008바이트) — 제목/주제/지은이/날짜/키워드/기타 (hchar 56자 × 9)
+//! 4. 정보 블록 (가변)
+//! 5. (압축 시 raw deflate) 글꼴 이름, 스타일, 문단 리스트, 추가 정보 블록
+
+mod body;
+mod hchar;
+mod reader;
+mod resources;
+
+use crate::decompress::decompress_deflate;
+use crate::error::HwpError;
+use body::BodyParser;
+use hchar::{hchar_string, kchar_string};
+use hwp_model::document::{
+    BinaryItem, BinaryStore, Document, DocumentMeta, DocumentSettings, ImageFormat,
+};
+use hwp_model::section::{ColumnDef, ColumnSize, PageDef, PageMargin, Section, SectionDef};
+use hwp_model::types::{HwpUnit, Landscape};
+use reader::Reader;
+use resources::{hunit, ResourceRegistry};
+
+/// HWP 3.x 파일 인식 정보 (30바이트)
+pub const HWP3_SIGNATURE: &[u8; 30] = b"HWP Document File V3.00 \x1a\x01\x02\x03\x04\x05";
+
+const SIGNATURE_PREFIX: &[u8] = b"HWP Document File V";
+const DOC_INFO_SIZE: usize = 128;
+const SUMMARY_SIZE: usize = 1008;
+/// 추가 정보 블록: 그림 포함 데이터
+const TAG_EMBEDDED_PICTURE: u32 = 1;
+
+/// HWP 3.x 문서인지 확인 / Check whether `data` starts with the HWP 3.x signature
+pub fn is_hwp3(data: &[u8]) -> bool {
+    data.starts_with(&HWP3_SIGNATURE[..23])
+}
+
+/// HWP 3.x 바이트 배열 → Document / Parse an HWP 3.x file into `Document`
+pub fn parse_hwp3(data: &[u8]) -> Result<Document, HwpError> {
+    check_signature(data)?;
+    let mut r = Reader::new(&data[HWP3_SIGNATURE.len()..]);
+
+    let info = DocInfo::read(r.bytes(DOC_INFO_SIZE, "HWP3 document info")?);
+    if info.encrypted {
+        return Err(HwpError::UnsupportedVersion {
+            version: "3.00 (password protected)".to_string(),
+            supported_versions: "3.00 without password".to_string(),
+        });
+    }
+    let meta = read_summary(r.bytes(SUMMARY_SIZE, "HWP3 document summary")?);
+    r.skip(info.info_block_len, "HWP3 information block")?;
+
+    let rest = r.bytes(r.remaining(), "HWP3 body")?;
+    let inflated;
+    let body = if info.compressed {
+        inflated = decompress_deflate(rest)?;
+        &inflated[..]
+    } else {
+        rest
+    };
+
+    let mut r = Reader::new(body);
+    let mut registry = ResourceRegistry::default();
+    registry.read_fonts(&mut r)?;
+    registry.read_styles(&mut r)?;
+    let mut parser = BodyParser::new(&mut registry);
+    let paragraphs = parser.read_para_list(&mut r)?;
+    let picture_ids = std::mem::take(&mut parser.picture_ids);
+    let binaries = read_embedded_pictures(&mut r, &picture_ids);
+
+    let mut definition = SectionDef {
+        page: info.page,
+        ..Default::default()
+    };
+    if let Some(&(count, gap)) = paragraphs
+        .first()
+        .and_then(|p| registry.para_columns.get(p.para_shape_id as usize))
+    {
+        if count > 1 {
+            definition.columns = Some(ColumnDef {
+                col_count: count as u16,
+                same_size: true,
+                same_gap: hunit(gap),
+                col_sizes: vec![ColumnSize::default(); count as usize],
+                ..Default::default()
+            });
+        }
+    }
+
+    Ok(Document {
+        meta,
+        settings: DocumentSettings {
+            page_start: info.page_start,
+            footnote_start: info.footnote_start,
+            endnote_start: 1,
+            picture_start: 1,
+            table_start: 1,
+            equation_start: 1,
+        },
+        resources: registry.resources,
+        sections: vec![Section {
+            definition,
+            paragraphs,
+        }],
+        binaries,
+        ..Default::default()
+    })
+}
+
+fn check_signature(data: &[u8]) -> Result<(), HwpError> {
+    if data.starts_with(HWP3_SIGNATURE) {
+        return Ok(());
+    }
+    if data.starts_with(SIGNATURE_PREFIX) {
+        let version = data[SIGNATURE_PREFIX.len()..]
+            .iter()
+            .take_while(|b| b.is_ascii_graphic())
+            .map(|&b| b as char)
+            .collect();
+        return Err(HwpError::UnsupportedVersion {
+            version,
+            supported_versions: "3.00".to_string(),
+        });
+    }
+    Err(HwpError::InvalidSignature {
+        found: String::from_utf8_lossy(&data[..data.len().min(23)]).to_string(),
+    })
+}
+
+/// 문서 정보 (128바이트) 중 필요한 값
+struct DocInfo {
+    page: PageDef,
+    encrypted: bool,
+    page_start: u16,
+    footnote_start: u16,
+    compressed: bool,
+    info_block_len: usize,
+}
+
+impl DocInfo {
+    fn read(raw: &[u8]) -> Self {
+        let word = |offset: usize| u16::from_le_bytes([raw[offset], raw[offset + 1]]);
+        let unit = |offset: usize| -> HwpUnit { hunit(word(offset)) };
+        Self {
+            page: PageDef {
+                landscape: if raw[5] == 1 {
+                    Landscape::Landscape
+                } else {
+                    Landscape::Portrait
+                },
+                height: unit(6),
+                width: unit(8),
+                margin: PageMargin {
+                    top: unit(10),
+                    bottom: unit(12),
+                    left: unit(14),
+                    right: unit(16),
+                    header: unit(18),
+                    footer: unit(20),
+                    gutter: unit(22),
+                },
+                ..Default::default()
+            },
+            encrypted: word(96) != 0,
+            page_start: word(98).max(1),
+            footnote_start: word(100).max(1),
+            compressed: raw[124] != 0,
+            info_block_len: word(126) as usize,
+        }
+    }
+}
+
+/// 문서 요약: 제목, 주제, 지은이, 날짜, 키워드 2개, 기타 3개 (각 hchar 56자)
+fn read_summary(raw: &[u8]) -> DocumentMeta {
+    let fields: Vec<String> = raw
+        .chunks_exact(112)
+        .map(|chunk| {
+            let codes: Vec<u16> = chunk
+                .chunks_exact(2)
+                .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                .collect();
+            hchar_string(&codes)
+        })
+        .collect();
+    let some = |s: &String| (!s.is_empty()).then(|| s.clone());
+    let join = |items: &[String]| {
+        let joined = items
+            .iter()
+            .filter(|s| !s.is_empty())
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(" ");
+        (!joined.is_empty()).then_some(joined)
+    };
+    DocumentMeta {
+        title: some(&fields[0]),
+        subject: some(&fields[1]),
+        creator: some(&fields[2]),
+        created_date: some(&fields[3]),
+        keywords: join(&fields[4..6]),
+        description: join(&fields[6..9]),
+        ..Default::default()
+    }
+}
+
+/// 추가 정보 블록 #1에서 포함된 그림을 읽어 BinaryStore 구성
+///
+/// 블록은 `[종류 dword][길이 dword][데이터]`의 반복이며 종류 0에서 끝납니다.
+/// 그림 포함 블록은 `이름(16) + 종류(16) + 이미지 데이터` 입니다.
+fn read_embedded_pictures(
+    r: &mut Reader,
+    picture_ids: &std::collections::HashMap<String, String>,
+) -> BinaryStore {
+    let mut items = Vec::new();
+    while r.remaining() >= 8 {
+        let (Ok(tag), Ok(len)) = (r.u32("tag"), r.u32("tag length")) else {
+            break;
+        };
+        if tag == 0 {
+            break;
+        }
+        let Ok(data) = r.bytes(len as usize, "tag data") else {
+            break;
+        };
+        if tag != TAG_EMBEDDED_PICTURE || data.len() < 32 {
+            continue;
+        }
+        let name = kchar_string(&data[..16]);
+        let kind = kchar_string(&data[16..32]);
+        let Some(id) = picture_ids.iter().find_map(|(path, id)| {
+            let file_name = path.rsplit(['\\', '/']).next().unwrap_or(path);
+            (path.eq_ignore_ascii_case(&name) || file_name.eq_ignore_ascii_case(&name))
+                .then(|| id.clone())
+        }) else {
+            continue;
+        };
+        let image = data[32..].to_vec();
+        let format = image_format(&image, &kind);
+        let ext = match &format {
+            ImageFormat::Unknown(ext) => ext.clone(),
+            other => format!("{:?}", other).to_lowercase(),
+        };
+        items.push(BinaryItem {
+            src: format!("{}.{}", id, ext),
+            id,
+            format,
+            data: image,
+        });
+    }
+    items.sort_by(|a, b| a.id.cmp(&b.id));
+    BinaryStore { items }
+}
+
+/// 매직 바이트 우선, 없으면 블록의 종류 문자열로 이미지 형식 판별
+fn image_format(data: &[u8], kind: &str) -> ImageFormat {
+    if data.starts_with(b"\x89PNG") {
+        ImageFormat::Png
+    } else if data.starts_with(&[0xFF, 0xD8]) {
+        ImageFormat::Jpg
+    } else if data.starts_with(b"BM") {
+        ImageFormat::Bmp
+    } else if data.starts_with(b"GIF8") {
+        ImageFormat::Gif
+    } else if data.starts_with(&[0xD7, 0xCD, 0xC6, 0x9A]) {
+        ImageFormat::Wmf
+    } else {
+        match kind.to_ascii_lowercase().as_str() {
+            "png" => ImageFormat::Png,
+            "jpg" | "jpeg" => ImageFormat::Jpg,
+            "bmp" => ImageFormat::Bmp,
+            "gif" => ImageFormat::Gif,
+            "tif" | "tiff" => ImageFormat::Tiff,
+            "wmf" => ImageFormat::Wmf,
+            other => ImageFormat::Unknown(other.to_string()),
+        }
+    }
+}
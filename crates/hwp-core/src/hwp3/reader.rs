@@ -0,0 +1,58 @@
+//! HWP 3.x 바이트 읽기 도우미 / Little-endian byte cursor for HWP 3.x data
+
+use crate::error::HwpError;
+
+/// 리틀 엔디언 바이트 커서 / Little-endian byte cursor
+pub(crate) struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    pub(crate) fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    pub(crate) fn bytes(&mut self, len: usize, field: &str) -> Result<&'a [u8], HwpError> {
+        if self.remaining() < len {
+            return Err(HwpError::insufficient_data(field, len, self.remaining()));
+        }
+        let slice = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(slice)
+    }
+
+    pub(crate) fn skip(&mut self, len: usize, field: &str) -> Result<(), HwpError> {
+        self.bytes(len, field).map(|_| ())
+    }
+
+    pub(crate) fn u8(&mut self, field: &str) -> Result<u8, HwpError> {
+        Ok(self.bytes(1, field)?[0])
+    }
+
+    pub(crate) fn u16(&mut self, field: &str) -> Result<u16, HwpError> {
+        let b = self.bytes(2, field)?;
+        Ok(u16::from_le_bytes([b[0], b[1]]))
+    }
+
+    pub(crate) fn i16(&mut self, field: &str) -> Result<i16, HwpError> {
+        Ok(self.u16(field)? as i16)
+    }
+
+    pub(crate) fn u32(&mut self, field: &str) -> Result<u32, HwpError> {
+        let b = self.bytes(4, field)?;
+        Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    /// hchar 배열 읽기 / Read `count` hchar (u16) values
+    pub(crate) fn hchars(&mut self, count: usize, field: &str) -> Result<Vec<u16>, HwpError> {
+        let b = self.bytes(count * 2, field)?;
+        Ok(b.chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect())
+    }
+}
@@ -0,0 +1,319 @@
+//! HWP 3.x 글꼴/글자 모양/문단 모양/스타일 → hwp_model Resources
+//!
+//! HWP 3.x는 글자 모양과 문단 모양을 문단·글자마다 그대로 기록하므로
+//! 원본 바이트 기준으로 중복을 제거해 공유 자원 목록을 만듭니다.
+
+use std::collections::HashMap;
+
+use super::hchar::kchar_string;
+use super::reader::Reader;
+use crate::error::HwpError;
+use hwp_model::resources::*;
+use hwp_model::types::*;
+
+/// 글자 모양 레코드 크기
+pub(crate) const CHAR_SHAPE_SIZE: usize = 31;
+/// 문단 모양 레코드 크기
+pub(crate) const PARA_SHAPE_SIZE: usize = 187;
+/// 글꼴 이름 길이
+const FONT_NAME_LEN: usize = 40;
+/// 스타일 이름 길이
+const STYLE_NAME_LEN: usize = 20;
+/// 문단 모양의 탭 개수
+const MAX_TABS: usize = 40;
+
+/// HWP 3.x 기본 색상표 (검정, 파랑, 초록, 청록, 빨강, 자주, 노랑, 흰색)
+const PALETTE: [u32; 8] = [
+    0x000000, 0x0000FF, 0x00FF00, 0x00FFFF, 0xFF0000, 0xFF00FF, 0xFFFF00, 0xFFFFFF,
+];
+
+/// hunit(1/1800 inch) → HWPUNIT(1/7200 inch)
+pub(crate) fn hunit(value: impl Into<i32>) -> HwpUnit {
+    value.into() * 4
+}
+
+pub(crate) fn palette_color(index: u8) -> u32 {
+    PALETTE[(index & 0x07) as usize]
+}
+
+/// 색을 흰색과 섞어 음영 비율(%)만큼의 농도로 만듦
+pub(crate) fn shade_color(color: u32, percent: u8) -> u32 {
+    let percent = percent.min(100) as u32;
+    let mix = |c: u32| 255 - (255 - c) * percent / 100;
+    (mix(color >> 16 & 0xFF) << 16) | (mix(color >> 8 & 0xFF) << 8) | mix(color & 0xFF)
+}
+
+/// 원본 바이트 기준 중복 제거 자원 모음
+#[derive(Default)]
+pub(crate) struct ResourceRegistry {
+    pub(crate) resources: Resources,
+    char_shape_ids: HashMap<Vec<u8>, u16>,
+    para_shape_ids: HashMap<Vec<u8>, u16>,
+    tab_def_ids: HashMap<Vec<u8>, u16>,
+    border_fill_ids: HashMap<([u8; 4], u8, u16), u16>,
+    /// 문단 모양별 다단 수 (문단 모양 id 순서)
+    pub(crate) para_columns: Vec<(u8, u16)>,
+}
+
+impl ResourceRegistry {
+    /// 글꼴 이름 목록 (7개 언어)
+    pub(crate) fn read_fonts(&mut self, r: &mut Reader) -> Result<(), HwpError> {
+        let fonts = &mut self.resources.fonts;
+        for list in [
+            &mut fonts.hangul,
+            &mut fonts.latin,
+            &mut fonts.hanja,
+            &mut fonts.japanese,
+            &mut fonts.other,
+            &mut fonts.symbol,
+            &mut fonts.user,
+        ] {
+            let count = r.u16("font count")? as usize;
+            for id in 0..count {
+                let name = r.bytes(FONT_NAME_LEN, "font name")?;
+                list.push(Font {
+                    id: id as u16,
+                    face: kchar_string(name),
+                    ..Default::default()
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// 스타일 목록: 이름 + 글자 모양 + 문단 모양
+    pub(crate) fn read_styles(&mut self, r: &mut Reader) -> Result<(), HwpError> {
+        let count = r.u16("style count")?;
+        for id in 0..count {
+            let name = kchar_string(r.bytes(STYLE_NAME_LEN, "style name")?);
+            let char_shape_id = self.char_shape(r.bytes(CHAR_SHAPE_SIZE, "style char shape")?);
+            let para_shape_id = self.para_shape(r.bytes(PARA_SHAPE_SIZE, "style para shape")?);
+            self.resources.styles.push(Style {
+                id,
+                style_type: StyleType::Para,
+                name: name.clone(),
+                eng_name: name,
+                para_shape_id: Some(para_shape_id),
+                char_shape_id: Some(char_shape_id),
+                next_style_id: Some(id),
+                ..Default::default()
+            });
+        }
+        Ok(())
+    }
+
+    /// 글자 모양(31바이트) 등록 후 id 반환
+    pub(crate) fn char_shape(&mut self, raw: &[u8]) -> u16 {
+        if let Some(&id) = self.char_shape_ids.get(raw) {
+            return id;
+        }
+        let id = self.resources.char_shapes.len() as u16;
+        let mut shape = convert_char_shape(raw);
+        shape.id = id;
+        self.resources.char_shapes.push(shape);
+        self.char_shape_ids.insert(raw.to_vec(), id);
+        id
+    }
+
+    /// 문단 모양(187바이트) 등록 후 id 반환
+    pub(crate) fn para_shape(&mut self, raw: &[u8]) -> u16 {
+        if let Some(&id) = self.para_shape_ids.get(raw) {
+            return id;
+        }
+        let id = self.resources.para_shapes.len() as u16;
+        let tab_def_id = self.tab_def(&raw[12..12 + MAX_TABS * 4]);
+        let mut shape = convert_para_shape(raw);
+        shape.id = id;
+        shape.tab_def_id = Some(tab_def_id);
+        self.resources.para_shapes.push(shape);
+        self.para_columns
+            .push((raw[172], u16::from_le_bytes([raw[174], raw[175]])));
+        self.para_shape_ids.insert(raw.to_vec(), id);
+        id
+    }
+
+    fn tab_def(&mut self, raw: &[u8]) -> u16 {
+        if let Some(&id) = self.tab_def_ids.get(raw) {
+            return id;
+        }
+        let id = self.resources.tab_defs.len() as u16;
+        let items = raw
+            .chunks_exact(4)
+            .map(|t| (t[0], t[1], u16::from_le_bytes([t[2], t[3]])))
+            .take_while(|&(_, _, pos)| pos != 0)
+            .map(|(kind, leader, pos)| TabItem {
+                pos: hunit(pos),
+                tab_type: match kind {
+                    1 => TabType::Right,
+                    2 => TabType::Center,
+                    3 => TabType::Decimal,
+                    _ => TabType::Left,
+                },
+                leader: if leader != 0 {
+                    LineType2::Dot
+                } else {
+                    LineType2::None
+                },
+            })
+            .collect();
+        self.resources.tab_defs.push(TabDef {
+            id,
+            auto_tab_left: false,
+            auto_tab_right: false,
+            items,
+        });
+        self.tab_def_ids.insert(raw.to_vec(), id);
+        id
+    }
+
+    /// 표 셀 테두리/음영 등록 후 1-based id 반환
+    ///
+    /// `lines`: 왼쪽/오른쪽/위/아래 선 종류, `shade`: 음영 비율(%), `color`: 음영 색 번호
+    pub(crate) fn border_fill(&mut self, lines: [u8; 4], shade: u8, color: u16) -> u16 {
+        let key = (lines, shade, color);
+        if let Some(&id) = self.border_fill_ids.get(&key) {
+            return id;
+        }
+        let id = self.resources.border_fills.len() as u16 + 1;
+        let [left, right, top, bottom] = lines.map(cell_line);
+        self.resources.border_fills.push(BorderFill {
+            id,
+            left_border: left,
+            right_border: right,
+            top_border: top,
+            bottom_border: bottom,
+            fill: (shade > 0).then(|| FillBrush::WinBrush {
+                face_color: Some(shade_color(palette_color(color as u8), shade)),
+                hatch_color: None,
+                hatch_style: None,
+                alpha: 0,
+            }),
+            ..Default::default()
+        });
+        self.border_fill_ids.insert(key, id);
+        id
+    }
+}
+
+/// 셀 선 종류: 0 없음, 1 실선, 2 굵은 실선, 3 점선, 4 이중선
+fn cell_line(kind: u8) -> Option<LineSpec> {
+    let (line_type, width) = match kind {
+        0 => return None,
+        2 => (LineType3::Solid, "0.4mm"),
+        3 => (LineType3::Dot, "0.12mm"),
+        4 => (LineType3::DoubleSlim, "0.5mm"),
+        _ => (LineType3::Solid, "0.12mm"),
+    };
+    Some(LineSpec {
+        line_type,
+        width: width.to_string(),
+        color: Some(0),
+    })
+}
+
+/// 글자 모양 31바이트
+///
+/// 크기(2) 글꼴(7) 장평(7) 자간(7) 글자색/음영색(2) 음영 비율(1) 속성(1) 예약(4)
+fn convert_char_shape(raw: &[u8]) -> CharShape {
+    let lang = |offset: usize| {
+        LangGroup::new(
+            raw[offset],
+            raw[offset + 1],
+            raw[offset + 2],
+            raw[offset + 3],
+            raw[offset + 4],
+            raw[offset + 5],
+            raw[offset + 6],
+        )
+    };
+    let size = u16::from_le_bytes([raw[0], raw[1]]);
+    let text_color = palette_color(raw[23]);
+    let shade = raw[25];
+    let attr = raw[26];
+
+    CharShape {
+        height: hunit(size),
+        text_color: Some(text_color),
+        shade_color: (shade > 0).then(|| shade_color(palette_color(raw[24]), shade)),
+        font_ref: lang(2).map(|&v| v as u16),
+        ratio: lang(9),
+        spacing: lang(16).map(|&v| v as i8),
+        rel_size: LangGroup::all(100),
+        offset: LangGroup::all(0),
+        italic: attr & 0x01 != 0,
+        bold: attr & 0x02 != 0,
+        underline: (attr & 0x04 != 0).then_some(Underline {
+            underline_type: UnderlineType::Bottom,
+            shape: LineType3::Solid,
+            color: Some(text_color),
+        }),
+        outline: (attr & 0x08 != 0).then_some(OutlineType::Solid),
+        shadow: (attr & 0x10 != 0).then_some(CharShadow {
+            shadow_type: CharShadowType::Drop,
+            color: Some(0xB2B2B2),
+            offset_x: 10,
+            offset_y: 10,
+        }),
+        superscript: attr & 0x20 != 0,
+        subscript: attr & 0x40 != 0,
+        ..Default::default()
+    }
+}
+
+/// 문단 모양 187바이트
+///
+/// 왼쪽/오른쪽 여백(2+2) 들여쓰기(2) 줄 간격(2) 문단 아래 간격(2) 낱말 간격(1) 정렬(1)
+/// 탭(40×4) 단 정의(8) 음영(1) 개요(2) 문단 위 간격(2) 예약(2)
+fn convert_para_shape(raw: &[u8]) -> ParaShape {
+    let word = |offset: usize| u16::from_le_bytes([raw[offset], raw[offset + 1]]);
+    // HWP 5.0 변환과 같이 문단 여백은 2배 값으로 보관
+    let margin = |value: i32| HwpValue {
+        value: hunit(value) * 2,
+        unit: ValueUnit::HwpUnit,
+    };
+    let line_spacing = word(6) as i16;
+
+    ParaShape {
+        condense: raw[10],
+        align: ParagraphAlign {
+            horizontal: match raw[11] {
+                1 => HAlign::Left,
+                2 => HAlign::Right,
+                3 => HAlign::Center,
+                4 => HAlign::Distribute,
+                5 => HAlign::DistributeSpace,
+                _ => HAlign::Justify,
+            },
+            vertical: VAlign::Baseline,
+        },
+        break_setting: BreakSetting {
+            widow_orphan: true,
+            ..Default::default()
+        },
+        margin: ParagraphMargin {
+            left: margin(word(0) as i32),
+            right: margin(word(2) as i32),
+            indent: margin(word(4) as i16 as i32),
+            prev: margin(word(183) as i32),
+            next: margin(word(8) as i32),
+        },
+        line_spacing: match line_spacing {
+            v if v < 0 => LineSpacing {
+                spacing_type: LineSpacingType::Fixed,
+                value: hunit(-(v as i32)),
+                unit: ValueUnit::HwpUnit,
+            },
+            0 => LineSpacing {
+                spacing_type: LineSpacingType::Percent,
+                value: 160,
+                unit: ValueUnit::HwpUnit,
+            },
+            v => LineSpacing {
+                spacing_type: LineSpacingType::Percent,
+                value: v as i32,
+                unit: ValueUnit::HwpUnit,
+            },
+        },
+        ..Default::default()
+    }
+}
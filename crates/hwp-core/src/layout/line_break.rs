@@ -0,0 +1,454 @@
+/// 줄 나누기 / Line breaking
+///
+/// 글자 폭이 정해진 항목 열을 받아 문단 여백/들여쓰기, 탭 위치, 줄 나눔 기준(영어/한글 단위)에 따라
+/// 줄을 나누고, 줄 간격 종류에 맞춰 한글 워드프로세서와 같은 의미의 `LineSegmentInfo`를 만듭니다.
+///
+/// - `line_height`/`text_height`: 줄에서 가장 큰 글자(또는 글자처럼 취급한 개체) 높이
+/// - `baseline_distance`: 줄 높이의 85%
+/// - `line_spacing`: 다음 줄까지 더하는 간격 (글자에 따라 `(값-100)%`, 고정 값, 여백만, 최소)
+/// - `column_start_pos`/`segment_width`: 왼쪽 여백 / 단 너비에서 좌우 여백을 뺀 너비
+/// - `vertical_pos`: 문단 첫 줄 기준 (쪽/단 배치는 호출자가 더함)
+use super::metrics::is_wide;
+use hwp_model::hints::LineSegmentInfo;
+use hwp_model::resources::TabType;
+use hwp_model::types::{BreakLatinWord, BreakNonLatinWord, LineSpacingType};
+
+/// 한 줄의 첫 조각이자 마지막 조각 (줄마다 조각이 하나)
+const FLAG_WHOLE_LINE: u32 = 0x0002_0000 | 0x0004_0000;
+/// 자동 하이픈으로 나뉜 줄
+const FLAG_HYPHENATION: u32 = 0x0008_0000;
+/// 들여쓰기/내어쓰기가 적용된 줄
+const FLAG_INDENTATION: u32 = 0x0010_0000;
+/// 글자 높이 대비 기준선 위치
+const BASELINE_RATIO: f64 = 0.85;
+
+/// 줄에 놓이는 항목 / An item placed on a line
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct LineItem {
+    /// 문단 안의 원본 위치 (WCHAR) — 줄 시작이면 `text_start_pos`가 됨
+    pub pos: u32,
+    pub kind: ItemKind,
+    /// 전진 폭 (HWPUNIT, 탭은 배치할 때 계산)
+    pub width: f64,
+    /// 글자 크기 또는 개체 높이 (HWPUNIT)
+    pub height: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ItemKind {
+    Char(char),
+    /// 공백 — 줄 끝에 걸쳐도 넘침으로 보지 않음
+    Space,
+    Tab,
+    /// 강제 줄 바꿈
+    LineBreak,
+    /// 글자처럼 취급한 개체
+    Object,
+}
+
+/// 문단 줄 나누기 설정 (길이는 모두 HWPUNIT) / Paragraph line-breaking parameters
+#[derive(Debug, Clone)]
+pub(crate) struct LineParams {
+    /// 단/셀 너비
+    pub width: i32,
+    pub left: i32,
+    pub right: i32,
+    /// 양수면 첫 줄 들여쓰기, 음수면 둘째 줄부터 내어쓰기
+    pub indent: i32,
+    pub spacing_type: LineSpacingType,
+    /// 글자에 따라이면 %, 그 밖에는 HWPUNIT
+    pub spacing: i32,
+    pub break_latin: BreakLatinWord,
+    pub break_non_latin: BreakNonLatinWord,
+    /// 탭 위치 (문단 왼쪽 여백 기준, 오름차순)
+    pub tabs: Vec<(i32, TabType)>,
+    /// 탭 위치가 없을 때의 기본 탭 간격
+    pub default_tab: i32,
+    /// 글자가 없는 줄의 글자 크기
+    pub empty_height: i32,
+}
+
+impl Default for LineParams {
+    fn default() -> Self {
+        Self {
+            width: 0,
+            left: 0,
+            right: 0,
+            indent: 0,
+            spacing_type: LineSpacingType::Percent,
+            spacing: 160,
+            break_latin: BreakLatinWord::KeepWord,
+            break_non_latin: BreakNonLatinWord::KeepWord,
+            tabs: Vec::new(),
+            default_tab: 8000,
+            empty_height: super::metrics::DEFAULT_HEIGHT,
+        }
+    }
+}
+
+/// 항목 열을 줄로 나눔 / Break items into line segments
+pub(crate) fn break_lines(items: &[LineItem], params: &LineParams) -> Vec<LineSegmentInfo> {
+    let segment_width = (params.width - params.left - params.right).max(0);
+    let mut segments = Vec::new();
+    let mut vertical = 0;
+    let mut start = 0;
+    loop {
+        let first = segments.is_empty();
+        let offset = line_offset(params, first);
+        let available = (segment_width - offset) as f64;
+        let end = fit_line(items, start, available, offset, params);
+
+        let line = &items[start..end];
+        let text_start_pos = match line.first() {
+            Some(item) => item.pos,
+            // 강제 줄 바꿈 뒤의 빈 줄
+            None => items.last().map_or(0, |item| item.pos + 1),
+        };
+        let hyphenated = params.break_latin == BreakLatinWord::Hyphenation
+            && end < items.len()
+            && end > start
+            && matches!(
+                (&items[end - 1].kind, &items[end].kind),
+                (ItemKind::Char(a), ItemKind::Char(b)) if is_latin_word(*a) && is_latin_word(*b)
+            );
+        let mut segment = line_metrics(line, params);
+        segment.text_start_pos = text_start_pos;
+        segment.vertical_pos = vertical;
+        segment.column_start_pos = params.left;
+        segment.segment_width = segment_width;
+        segment.flags = FLAG_WHOLE_LINE;
+        if offset > 0 || (first && params.indent > 0) {
+            segment.flags |= FLAG_INDENTATION;
+        }
+        if hyphenated {
+            segment.flags |= FLAG_HYPHENATION;
+        }
+        vertical += segment.line_height + segment.line_spacing;
+        segments.push(segment);
+
+        if end >= items.len() {
+            // 마지막 항목이 강제 줄 바꿈이면 빈 줄이 하나 더 생김
+            let trailing_break = end > start && items[end - 1].kind == ItemKind::LineBreak;
+            if !trailing_break || start == items.len() {
+                break;
+            }
+        }
+        start = end;
+    }
+    segments
+}
+
+/// 줄 시작 위치 (문단 왼쪽 여백 기준) / Start offset of a line
+fn line_offset(params: &LineParams, first: bool) -> i32 {
+    match (first, params.indent) {
+        (true, indent) if indent > 0 => indent,
+        (false, indent) if indent < 0 => -indent,
+        _ => 0,
+    }
+}
+
+/// `start`부터 한 줄에 들어가는 항목의 끝 (다음 줄 시작)
+fn fit_line(
+    items: &[LineItem],
+    start: usize,
+    available: f64,
+    offset: i32,
+    params: &LineParams,
+) -> usize {
+    let mut x = 0.0;
+    let mut last_break: Option<usize> = None;
+    let mut i = start;
+    while i < items.len() {
+        let item = &items[i];
+        let width = match item.kind {
+            ItemKind::LineBreak => return i + 1,
+            ItemKind::Tab => tab_width(items, i, x + offset as f64, params),
+            _ => item.width,
+        };
+        // 공백은 줄 끝에 걸쳐도 됨
+        if item.kind != ItemKind::Space && x + width > available + 0.5 && i > start {
+            return last_break.unwrap_or(i);
+        }
+        x += width;
+        if i + 1 < items.len() && can_break_between(item, &items[i + 1], params) {
+            last_break = Some(i + 1);
+        }
+        i += 1;
+    }
+    items.len()
+}
+
+/// 탭 폭: 다음 탭 위치까지 (오른쪽/가운데/소수점 탭은 뒤따르는 글자 폭을 뺌)
+fn tab_width(items: &[LineItem], index: usize, position: f64, params: &LineParams) -> f64 {
+    let stop = params
+        .tabs
+        .iter()
+        .find(|(pos, _)| *pos as f64 > position + 0.5)
+        .cloned()
+        .unwrap_or_else(|| {
+            let step = params.default_tab.max(1) as f64;
+            let next = ((position + 0.5) / step).floor() as i32 + 1;
+            ((next as f64 * step) as i32, TabType::Left)
+        });
+    let following = |until_decimal: bool| -> f64 {
+        items[index + 1..]
+            .iter()
+            .take_while(|item| match item.kind {
+                ItemKind::Tab | ItemKind::LineBreak => false,
+                ItemKind::Char('.') => !until_decimal,
+                _ => true,
+            })
+            .map(|item| item.width)
+            .sum()
+    };
+    let gap = stop.0 as f64 - position;
+    let width = match stop.1 {
+        TabType::Left => gap,
+        TabType::Right => gap - following(false),
+        TabType::Center => gap - following(false) / 2.0,
+        TabType::Decimal => gap - following(true),
+    };
+    width.max(0.0)
+}
+
+/// 두 항목 사이에서 줄을 나눌 수 있는지
+fn can_break_between(a: &LineItem, b: &LineItem, params: &LineParams) -> bool {
+    match (a.kind, b.kind) {
+        // 공백은 줄 끝에 붙음
+        (_, ItemKind::Space) | (_, ItemKind::LineBreak) => false,
+        (ItemKind::Space, _) | (ItemKind::Tab, _) => true,
+        (ItemKind::Object, _) | (_, ItemKind::Object) | (_, ItemKind::Tab) => true,
+        (ItemKind::Char(a), ItemKind::Char(b)) => {
+            if is_no_break_before(b) || is_no_break_after(a) {
+                return false;
+            }
+            if a == '-' || a == '/' {
+                return true;
+            }
+            let latin_rule = params.break_latin != BreakLatinWord::KeepWord;
+            let non_latin_rule = params.break_non_latin == BreakNonLatinWord::BreakWord;
+            match (is_wide(a), is_wide(b)) {
+                (false, false) => latin_rule || !(is_latin_word(a) && is_latin_word(b)),
+                _ => non_latin_rule,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// 영어 단어를 이루는 글자 (글자, 숫자)
+fn is_latin_word(ch: char) -> bool {
+    ch.is_alphanumeric() && !is_wide(ch)
+}
+
+/// 줄 머리에 올 수 없는 글자 (닫는 괄호, 문장 부호)
+fn is_no_break_before(ch: char) -> bool {
+    matches!(
+        ch,
+        '.' | ','
+            | ':'
+            | ';'
+            | '!'
+            | '?'
+            | ')'
+            | ']'
+            | '}'
+            | '%'
+            | '’'
+            | '”'
+            | '」'
+            | '』'
+            | '》'
+            | '〉'
+            | '】'
+            | '。'
+            | '、'
+            | '·'
+            | '…'
+    )
+}
+
+/// 줄 끝에 올 수 없는 글자 (여는 괄호)
+fn is_no_break_after(ch: char) -> bool {
+    matches!(
+        ch,
+        '(' | '[' | '{' | '‘' | '“' | '「' | '『' | '《' | '〈' | '【'
+    )
+}
+
+/// 줄 높이, 기준선, 줄 간격 / Vertical metrics of one line
+fn line_metrics(line: &[LineItem], params: &LineParams) -> LineSegmentInfo {
+    let text_height = line
+        .iter()
+        .filter(|item| item.kind != ItemKind::Object)
+        .map(|item| item.height)
+        .max()
+        .unwrap_or(params.empty_height);
+    let object_height = line
+        .iter()
+        .filter(|item| item.kind == ItemKind::Object)
+        .map(|item| item.height)
+        .max()
+        .unwrap_or(0);
+    let line_height = text_height.max(object_height);
+    let line_spacing = match params.spacing_type {
+        LineSpacingType::Percent => {
+            (text_height as f64 * (params.spacing - 100) as f64 / 100.0).round() as i32
+        }
+        LineSpacingType::Fixed => params.spacing - line_height,
+        LineSpacingType::Between => params.spacing,
+        LineSpacingType::AtLeast => (params.spacing - line_height).max(0),
+    };
+    LineSegmentInfo {
+        line_height,
+        text_height: line_height,
+        baseline_distance: (line_height as f64 * BASELINE_RATIO).round() as i32,
+        line_spacing,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(text: &str, width: f64) -> Vec<LineItem> {
+        text.chars()
+            .enumerate()
+            .map(|(i, ch)| LineItem {
+                pos: i as u32,
+                kind: match ch {
+                    ' ' => ItemKind::Space,
+                    '\t' => ItemKind::Tab,
+                    '\n' => ItemKind::LineBreak,
+                    _ => ItemKind::Char(ch),
+                },
+                width: if ch == ' ' { width / 2.0 } else { width },
+                height: 1000,
+            })
+            .collect()
+    }
+
+    fn params(width: i32) -> LineParams {
+        LineParams {
+            width,
+            ..Default::default()
+        }
+    }
+
+    fn starts(segments: &[LineSegmentInfo]) -> Vec<u32> {
+        segments.iter().map(|s| s.text_start_pos).collect()
+    }
+
+    #[test]
+    fn test_empty_paragraph_has_one_line() {
+        let segments = break_lines(&[], &params(10000));
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].line_height, 1000);
+        assert_eq!(segments[0].line_spacing, 600);
+        assert_eq!(segments[0].baseline_distance, 850);
+        assert_eq!(segments[0].flags, 0x60000);
+    }
+
+    #[test]
+    fn test_breaks_at_spaces_for_keep_word() {
+        // "다단 " 반복, 단 너비 8500: 한 줄에 "다단 다단 다단 " (7000 + 공백)
+        let items = chars(&"다단 ".repeat(6), 1000.0);
+        let segments = break_lines(&items, &params(8500));
+        assert_eq!(starts(&segments), vec![0, 9]);
+        assert_eq!(segments[1].vertical_pos, 1600);
+    }
+
+    #[test]
+    fn test_break_word_splits_inside_words() {
+        // 어절 단위라도 나눌 곳이 없으면 글자 단위로 넘김
+        let items = chars("가나다라마바사", 1000.0);
+        let mut p = params(3500);
+        assert_eq!(starts(&break_lines(&items, &p)), vec![0, 3, 6]);
+
+        let items = chars("가나 다라마", 1000.0);
+        assert_eq!(starts(&break_lines(&items, &p)), vec![0, 3]);
+        p.break_non_latin = BreakNonLatinWord::BreakWord;
+        assert_eq!(starts(&break_lines(&items, &p)), vec![0, 4]);
+    }
+
+    #[test]
+    fn test_latin_words_and_punctuation() {
+        let items = chars("abc defg.", 1000.0);
+        let mut p = params(6000);
+        // "defg."는 한 단어로 넘어감
+        assert_eq!(starts(&break_lines(&items, &p)), vec![0, 4]);
+        p.break_latin = BreakLatinWord::BreakWord;
+        p.width = 7500;
+        // 마침표는 줄 머리에 오지 않음
+        assert_eq!(starts(&break_lines(&items, &p)), vec![0, 7]);
+        p.break_latin = BreakLatinWord::Hyphenation;
+        p.width = 6000;
+        let segments = break_lines(&items, &p);
+        assert_eq!(starts(&segments), vec![0, 6]);
+        assert_ne!(segments[0].flags & FLAG_HYPHENATION, 0);
+    }
+
+    #[test]
+    fn test_margins_and_indent() {
+        let items = chars(&"가 ".repeat(10), 1000.0);
+        let mut p = params(10000);
+        p.left = 1000;
+        p.right = 1000;
+        p.indent = -2000;
+        let segments = break_lines(&items, &p);
+        assert_eq!(segments[0].column_start_pos, 1000);
+        assert_eq!(segments[0].segment_width, 8000);
+        // 첫 줄 8000: "가 " × 5, 둘째 줄부터 6000: "가 " × 4
+        assert_eq!(starts(&segments), vec![0, 10, 18]);
+        assert_eq!(segments[0].flags & FLAG_INDENTATION, 0);
+        assert_ne!(segments[1].flags & FLAG_INDENTATION, 0);
+    }
+
+    #[test]
+    fn test_tabs_use_tab_stops() {
+        let items = chars("\ta\tb", 500.0);
+        let mut p = params(40000);
+        p.tabs = vec![(4000, TabType::Left), (8000, TabType::Right)];
+        assert_eq!(tab_width(&items, 0, 0.0, &p), 4000.0);
+        // 오른쪽 탭: 뒤 글자 폭만큼 앞에서 멈춤
+        assert_eq!(tab_width(&items, 2, 4500.0, &p), 3000.0);
+        // 탭 위치를 넘으면 기본 탭 간격
+        p.tabs.clear();
+        assert_eq!(tab_width(&items, 0, 100.0, &p), 7900.0);
+    }
+
+    #[test]
+    fn test_line_breaks_and_spacing_types() {
+        let items = chars("가\n", 1000.0);
+        let mut p = params(10000);
+        let segments = break_lines(&items, &p);
+        assert_eq!(starts(&segments), vec![0, 2]);
+
+        p.spacing_type = LineSpacingType::Fixed;
+        p.spacing = 1500;
+        assert_eq!(break_lines(&items, &p)[0].line_spacing, 500);
+        p.spacing_type = LineSpacingType::Between;
+        p.spacing = 300;
+        assert_eq!(break_lines(&items, &p)[1].vertical_pos, 1300);
+        p.spacing_type = LineSpacingType::AtLeast;
+        p.spacing = 800;
+        assert_eq!(break_lines(&items, &p)[0].line_spacing, 0);
+    }
+
+    #[test]
+    fn test_inline_object_sets_line_height() {
+        let mut items = chars("가", 1000.0);
+        items.push(LineItem {
+            pos: 1,
+            kind: ItemKind::Object,
+            width: 3000.0,
+            height: 5000,
+        });
+        let segments = break_lines(&items, &params(10000));
+        assert_eq!(segments[0].line_height, 5000);
+        assert_eq!(segments[0].baseline_distance, 4250);
+        // 줄 간격은 글자 크기 기준
+        assert_eq!(segments[0].line_spacing, 600);
+    }
+}
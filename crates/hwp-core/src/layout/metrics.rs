@@ -0,0 +1,238 @@
+/// 글자 폭 측정 / Glyph advance measurement
+///
+/// 줄 나누기는 글자마다 전진 폭(em 비율)을 묻습니다. 글꼴 파일이 없으면 글자 종류별
+/// 평균 폭으로 추정하고(`EstimatedMetrics`), 글꼴 디렉터리가 주어지면 TrueType `hmtx`
+/// 폭을 씁니다(`TrueTypeMetrics`).
+use crate::font::{load_dir, normalize_family, TrueTypeFont};
+use hwp_model::resources::{CharShape, FontFaces};
+use hwp_model::types::LangGroup;
+use std::path::Path;
+
+/// 글자 전진 폭 제공자 / Provider of glyph advances
+pub trait FontMetrics {
+    /// `face` 글꼴에서 `ch`의 전진 폭 (em 비율) / Advance of `ch` in `face`, in em
+    fn advance(&self, face: &str, ch: char) -> f64;
+}
+
+/// 글자 종류별 평균 폭으로 추정하는 메트릭 / Metrics estimated from character classes
+///
+/// 한글/한자/가나 등 전각 글자는 1em, 공백은 0.5em, 라틴 글자는 모양에 따라 0.3~0.8em.
+/// 고정폭 글꼴(이름에 `Courier`, `Mono`, `체` 등)은 반각 글자를 모두 0.5em으로 봅니다.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EstimatedMetrics;
+
+impl FontMetrics for EstimatedMetrics {
+    fn advance(&self, face: &str, ch: char) -> f64 {
+        if is_wide(ch) {
+            return 1.0;
+        }
+        if is_monospace(face) {
+            return 0.5;
+        }
+        match ch {
+            ' ' | '\u{00a0}' => 0.5,
+            'i' | 'j' | 'l' | '.' | ',' | ':' | ';' | '!' | '\'' | '|' | '`' => 0.28,
+            'f' | 't' | 'r' | 'I' | '(' | ')' | '[' | ']' | '{' | '}' | '-' | '"' => 0.36,
+            'm' | 'w' | 'M' | 'W' | '@' | '%' => 0.82,
+            '0'..='9' => 0.55,
+            'A'..='Z' => 0.66,
+            'a'..='z' => 0.52,
+            _ => 0.55,
+        }
+    }
+}
+
+/// TrueType 글꼴 폭을 쓰는 메트릭 / Metrics read from TrueType fonts
+///
+/// 글꼴 이름이 맞고 그 글자가 있는 글꼴(보통 모양 우선)의 `hmtx` 폭을 쓰고,
+/// 없으면 `EstimatedMetrics`로 추정합니다.
+pub struct TrueTypeMetrics {
+    fonts: Vec<TrueTypeFont>,
+}
+
+impl TrueTypeMetrics {
+    /// 디렉터리의 .ttf/.ttc 글꼴을 읽음 / Load the fonts of a directory
+    pub fn load(dir: &Path) -> Self {
+        let mut fonts = load_dir(dir);
+        fonts.sort_by_key(|font| font.bold as u8 + font.italic as u8);
+        Self { fonts }
+    }
+
+    /// 읽은 글꼴 수 / Number of loaded fonts
+    pub fn len(&self) -> usize {
+        self.fonts.len()
+    }
+
+    /// 읽은 글꼴이 없는지 / Whether no font was loaded
+    pub fn is_empty(&self) -> bool {
+        self.fonts.is_empty()
+    }
+}
+
+impl FontMetrics for TrueTypeMetrics {
+    fn advance(&self, face: &str, ch: char) -> f64 {
+        let family = normalize_family(face);
+        self.fonts
+            .iter()
+            .filter(|font| font.families.contains(&family))
+            .find_map(|font| font.glyph(ch).map(|gid| font.advance(gid) / 1000.0))
+            .unwrap_or_else(|| EstimatedMetrics.advance(face, ch))
+    }
+}
+
+/// 글자 모양의 언어 구분 / Language slot of a character in a CharShape
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Lang {
+    Hangul,
+    Latin,
+    Hanja,
+    Japanese,
+    Other,
+    Symbol,
+}
+
+impl Lang {
+    pub(crate) fn of(ch: char) -> Self {
+        match ch as u32 {
+            0x1100..=0x11FF | 0x3130..=0x318F | 0xA960..=0xA97F | 0xAC00..=0xD7FF => Lang::Hangul,
+            0x0000..=0x024F | 0x1E00..=0x1EFF => Lang::Latin,
+            0x2E80..=0x2FDF | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xF900..=0xFAFF => Lang::Hanja,
+            0x3040..=0x30FF | 0x31F0..=0x31FF | 0xFF66..=0xFF9F => Lang::Japanese,
+            0x2000..=0x2BFF | 0x3000..=0x303F | 0xFF00..=0xFF65 => Lang::Symbol,
+            _ => Lang::Other,
+        }
+    }
+
+    pub(crate) fn pick<T: Copy>(self, group: &LangGroup<T>) -> T {
+        match self {
+            Lang::Hangul => group.hangul,
+            Lang::Latin => group.latin,
+            Lang::Hanja => group.hanja,
+            Lang::Japanese => group.japanese,
+            Lang::Other => group.other,
+            Lang::Symbol => group.symbol,
+        }
+    }
+}
+
+/// 글자 모양을 적용한 글자 폭 (HWPUNIT) / Advance of `ch` under a CharShape, in HWPUNIT
+///
+/// 기준 크기 × 상대 크기 × 장평에 자간(기준 크기의 %)을 더합니다.
+pub(crate) fn char_width(
+    metrics: &dyn FontMetrics,
+    fonts: &FontFaces,
+    shape: Option<&CharShape>,
+    ch: char,
+) -> f64 {
+    let Some(shape) = shape else {
+        return EstimatedMetrics.advance("", ch) * DEFAULT_HEIGHT as f64;
+    };
+    let lang = Lang::of(ch);
+    let size = shape.height as f64 * percent(lang.pick(&shape.rel_size));
+    let ratio = percent(lang.pick(&shape.ratio));
+    let spacing = lang.pick(&shape.spacing) as f64 / 100.0;
+    let face = face_name(fonts, lang, lang.pick(&shape.font_ref));
+    let em = metrics.advance(face, ch);
+    (em * ratio + spacing) * size
+}
+
+/// 기본 글자 크기 (10pt) / Default character height
+pub(crate) const DEFAULT_HEIGHT: i32 = 1000;
+
+/// 0이면 100%로 보는 백분율 / Percentage where 0 means 100%
+fn percent(value: u8) -> f64 {
+    if value == 0 {
+        1.0
+    } else {
+        value as f64 / 100.0
+    }
+}
+
+fn face_name(fonts: &FontFaces, lang: Lang, id: u16) -> &str {
+    let list = match lang {
+        Lang::Hangul => &fonts.hangul,
+        Lang::Latin => &fonts.latin,
+        Lang::Hanja => &fonts.hanja,
+        Lang::Japanese => &fonts.japanese,
+        Lang::Other => &fonts.other,
+        Lang::Symbol => &fonts.symbol,
+    };
+    list.get(id as usize).map(|f| f.face.as_str()).unwrap_or("")
+}
+
+/// 전각 글자 (한글, 한자, 가나, 전각 기호) / Full-width characters
+pub(crate) fn is_wide(ch: char) -> bool {
+    matches!(
+        ch as u32,
+        0x1100..=0x11FF
+            | 0x2E80..=0x303F
+            | 0x3040..=0x30FF
+            | 0x3130..=0x318F
+            | 0x3400..=0x4DBF
+            | 0x4E00..=0x9FFF
+            | 0xA960..=0xA97F
+            | 0xAC00..=0xD7FF
+            | 0xF900..=0xFAFF
+            | 0xFF01..=0xFF60
+    )
+}
+
+fn is_monospace(face: &str) -> bool {
+    let lower = face.to_lowercase();
+    lower.contains("courier")
+        || lower.contains("mono")
+        || lower.contains("consol")
+        || lower.contains("coding")
+        || face.ends_with('체')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimated_widths() {
+        let m = EstimatedMetrics;
+        assert_eq!(m.advance("함초롬바탕", '가'), 1.0);
+        assert_eq!(m.advance("함초롬바탕", ' '), 0.5);
+        assert!(m.advance("Arial", 'i') < m.advance("Arial", 'W'));
+        assert_eq!(m.advance("Courier New", 'i'), 0.5);
+        assert_eq!(m.advance("굴림체", 'W'), 0.5);
+    }
+
+    #[test]
+    fn test_char_width_applies_ratio_and_spacing() {
+        let fonts = FontFaces::default();
+        let mut shape = CharShape {
+            height: 1000,
+            ..Default::default()
+        };
+        assert_eq!(
+            char_width(&EstimatedMetrics, &fonts, Some(&shape), '가'),
+            1000.0
+        );
+        shape.ratio.hangul = 50;
+        shape.spacing.hangul = -10;
+        assert_eq!(
+            char_width(&EstimatedMetrics, &fonts, Some(&shape), '가'),
+            400.0
+        );
+        // 라틴 글자는 latin 값을 씀
+        assert_eq!(
+            char_width(&EstimatedMetrics, &fonts, Some(&shape), 'a'),
+            520.0
+        );
+    }
+
+    #[test]
+    fn test_truetype_metrics_fall_back_without_face() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/fonts");
+        let metrics = TrueTypeMetrics::load(&dir);
+        assert!(!metrics.is_empty());
+        // Liberation Sans의 'i'는 0.222em
+        let i = metrics.advance("Liberation Sans", 'i');
+        assert!((i - 0.222).abs() < 0.01, "{}", i);
+        // 목록에 없는 글꼴은 추정 폭
+        assert_eq!(metrics.advance("없는 글꼴", '가'), 1.0);
+    }
+}
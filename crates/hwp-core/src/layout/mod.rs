@@ -0,0 +1,569 @@
+/// 문단 줄 배치 엔진 / Text layout engine
+///
+/// 글꼴 메트릭으로 글자 폭을 재고, 문단 모양(여백, 들여쓰기, 줄 간격 종류, 영어/한글 줄 나눔 기준),
+/// 탭 설정, 쪽/단 크기에 따라 줄을 나눠 문단마다 `LineSegmentInfo`를 만듭니다.
+/// 줄 정보(HWPTAG_PARA_LINE_SEG, `linesegarray`)가 없는 문서나, 편집으로 레이아웃 캐시가
+/// 낡은 문서도 레이아웃 HTML/Canvas/PDF와 페이지 나누기가 올바르게 동작하도록 합니다.
+///
+/// ```ignore
+/// use hwp_core::layout::{layout_document, LayoutOptions};
+///
+/// // 본문을 고친 뒤 모든 문단을 다시 배치
+/// layout_document(&mut doc, &LayoutOptions { relayout_all: true, ..Default::default() });
+/// ```
+pub(crate) mod line_break;
+pub mod metrics;
+pub(crate) mod paragraph;
+
+pub use metrics::{EstimatedMetrics, FontMetrics, TrueTypeMetrics};
+
+use crate::viewer::doc_utils::shape_common;
+use hwp_model::control::Control;
+use hwp_model::document::Document;
+use hwp_model::hints::LineSegmentInfo;
+use hwp_model::paragraph::{Paragraph, RunContent, SubList};
+use hwp_model::resources::Resources;
+use hwp_model::section::{PageDef, SectionDef};
+use hwp_model::shape::ShapeObject;
+use hwp_model::table::Table;
+use hwp_model::types::{HwpUnit, Landscape, RelativeTo, TextWrap};
+use line_break::break_lines;
+use paragraph::{line_params, paragraph_items, paragraph_spacing};
+use std::path::PathBuf;
+
+/// 배치 옵션 / Layout options
+#[derive(Debug, Clone, Default)]
+pub struct LayoutOptions {
+    /// true면 모든 문단을 다시 배치, false면 줄 정보가 없는 문단만 채움
+    /// Relayout every paragraph (stale caches) instead of only those without line segments
+    pub relayout_all: bool,
+    /// 글자 폭을 잴 TrueType 글꼴 디렉터리 (None이면 글자 종류별 추정 폭)
+    /// Directory with TrueType fonts for glyph advances (None = estimated widths)
+    pub font_dir: Option<PathBuf>,
+}
+
+/// 문서의 모든 문단(본문, 표 셀, 글상자, 캡션, 머리말/꼬리말, 각주/미주)에 줄 정보를 만듦
+/// Compute line segments for every paragraph of the document
+pub fn layout_document(doc: &mut Document, options: &LayoutOptions) {
+    match &options.font_dir {
+        Some(dir) => layout_document_with_metrics(doc, options, &TrueTypeMetrics::load(dir)),
+        None => layout_document_with_metrics(doc, options, &EstimatedMetrics),
+    }
+}
+
+/// 지정한 글꼴 메트릭으로 문서를 배치 / Lay out a document with the given font metrics
+pub fn layout_document_with_metrics(
+    doc: &mut Document,
+    options: &LayoutOptions,
+    metrics: &dyn FontMetrics,
+) {
+    let Document {
+        resources,
+        sections,
+        ..
+    } = doc;
+    for section in sections.iter_mut() {
+        let mut flow = BodyFlow::new(&section.definition);
+        let ctx = Context {
+            resources,
+            metrics,
+            relayout_all: options.relayout_all,
+            default_tab: section.definition.tab_stop,
+            page_width: flow.total_width,
+        };
+        for para in &mut section.paragraphs {
+            flow.paragraph(para, &ctx);
+        }
+    }
+}
+
+/// 문단 하나를 주어진 너비로 배치 (첫 줄 vertical_pos = 0, 쪽 나눔 없음)
+/// Lay out one paragraph into `width` (first line at 0, no page breaking)
+pub fn layout_paragraph(
+    para: &Paragraph,
+    resources: &Resources,
+    metrics: &dyn FontMetrics,
+    width: HwpUnit,
+) -> Vec<LineSegmentInfo> {
+    let ctx = Context {
+        resources,
+        metrics,
+        relayout_all: true,
+        default_tab: 0,
+        page_width: width,
+    };
+    ctx.break_paragraph(para, width)
+}
+
+/// 배치에 쓰는 문서 공통 정보
+struct Context<'a> {
+    resources: &'a Resources,
+    metrics: &'a dyn FontMetrics,
+    relayout_all: bool,
+    default_tab: HwpUnit,
+    /// 머리말/꼬리말, 각주/미주의 너비 (쪽 본문 너비)
+    page_width: HwpUnit,
+}
+
+impl Context<'_> {
+    fn needs_layout(&self, para: &Paragraph) -> bool {
+        self.relayout_all || para.line_segments.is_empty()
+    }
+
+    /// 줄 나누기만 (vertical_pos는 문단 첫 줄 기준)
+    fn break_paragraph(&self, para: &Paragraph, width: HwpUnit) -> Vec<LineSegmentInfo> {
+        let items = paragraph_items(para, self.resources, self.metrics, &|object| {
+            self.object_extent(object)
+        });
+        let params = line_params(para, self.resources, width, self.default_tab);
+        break_lines(&items, &params)
+    }
+
+    /// 문단 안의 하위 문단 목록(표 셀, 글상자, 캡션, 머리말/꼬리말, 주석)을 먼저 배치
+    fn layout_nested(&self, para: &mut Paragraph) {
+        for run in &mut para.runs {
+            for content in &mut run.contents {
+                match content {
+                    RunContent::Object(object) => self.layout_object(object),
+                    RunContent::Control(Control::Header(hf) | Control::Footer(hf)) => {
+                        self.layout_list(&mut hf.content, self.page_width)
+                    }
+                    RunContent::Control(Control::FootNote(note) | Control::EndNote(note)) => {
+                        self.layout_list(&mut note.content, self.page_width)
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn layout_object(&self, object: &mut ShapeObject) {
+        let object_width = shape_common(object).size.width;
+        match object {
+            ShapeObject::Table(table) => {
+                for row in &mut table.rows {
+                    for cell in &mut row.cells {
+                        let margin = if cell.has_margin == Some(true) {
+                            cell.cell_margin.clone()
+                        } else {
+                            table.in_margin.clone()
+                        };
+                        let width = (cell.width - margin.left - margin.right).max(0);
+                        self.layout_list(&mut cell.content, width);
+                    }
+                }
+            }
+            ShapeObject::Container(container) => {
+                for child in &mut container.children {
+                    self.layout_object(child);
+                }
+            }
+            _ => {
+                if let Some(list) = draw_text_mut(object) {
+                    let width = list.text_width.unwrap_or(object_width);
+                    self.layout_list(list, width);
+                }
+            }
+        }
+        if let Some(caption) = common_mut(object).caption.as_mut() {
+            let width = if caption.width > 0 {
+                caption.width
+            } else {
+                object_width
+            };
+            self.layout_list(&mut caption.content, width);
+        }
+    }
+
+    /// 하위 문단 목록: 0부터 아래로 쌓음 (쪽 나눔 없음)
+    fn layout_list(&self, list: &mut SubList, width: HwpUnit) {
+        let mut y = 0;
+        for para in &mut list.paragraphs {
+            self.layout_nested(para);
+            let (before, after) =
+                paragraph_spacing(self.resources.para_shapes.get(para.para_shape_id as usize));
+            if self.needs_layout(para) {
+                let mut segments = self.break_paragraph(para, width);
+                y += before;
+                for segment in &mut segments {
+                    segment.vertical_pos += y;
+                }
+                y = segments.last().map_or(y, line_bottom) + after;
+                para.line_segments = segments;
+            } else if let Some(last) = para.line_segments.last() {
+                y = line_bottom(last) + after;
+            }
+        }
+    }
+
+    /// 글자처럼 취급한 개체의 바깥 여백을 포함한 (너비, 높이)
+    fn object_extent(&self, object: &ShapeObject) -> (HwpUnit, HwpUnit) {
+        let common = shape_common(object);
+        let height = match object {
+            ShapeObject::Table(table) => table_height(table),
+            _ => common.size.height,
+        };
+        let margin = common.out_margin.clone().unwrap_or_default();
+        (
+            common.size.width + margin.left + margin.right,
+            height + margin.top + margin.bottom,
+        )
+    }
+
+    /// 문단 기준으로 놓여 본문을 아래로 미는 개체(자리 차지)의 아래 끝 (문단 첫 줄 기준)
+    fn pushed_bottom(&self, para: &Paragraph) -> HwpUnit {
+        para.runs
+            .iter()
+            .flat_map(|run| &run.contents)
+            .filter_map(|content| match content {
+                RunContent::Object(object) => Some(object),
+                _ => None,
+            })
+            .filter(|object| {
+                let common = shape_common(object);
+                !common.position.treat_as_char
+                    && common.text_wrap == TextWrap::TopAndBottom
+                    && common.position.vert_rel_to == RelativeTo::Para
+            })
+            .map(|object| shape_common(object).position.vert_offset + self.object_extent(object).1)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// 다음 줄이 시작할 위치 (줄 높이 + 줄 간격)
+fn line_bottom(segment: &LineSegmentInfo) -> HwpUnit {
+    segment.vertical_pos + segment.line_height + segment.line_spacing
+}
+
+/// 셀 내용에 맞춘 표 높이: 행마다 (셀 높이, 내용 + 안 여백) 중 큰 값의 합
+fn table_height(table: &Table) -> HwpUnit {
+    let rows: HwpUnit = table
+        .rows
+        .iter()
+        .map(|row| {
+            row.cells
+                .iter()
+                .filter(|cell| cell.row_span <= 1)
+                .map(|cell| {
+                    let margin = if cell.has_margin == Some(true) {
+                        &cell.cell_margin
+                    } else {
+                        &table.in_margin
+                    };
+                    let content = cell
+                        .content
+                        .paragraphs
+                        .iter()
+                        .filter_map(|p| p.line_segments.last())
+                        .map(|s| s.vertical_pos + s.line_height)
+                        .max()
+                        .unwrap_or(0);
+                    cell.height.max(content + margin.top + margin.bottom)
+                })
+                .max()
+                .unwrap_or(0)
+        })
+        .sum();
+    rows.max(table.common.size.height)
+}
+
+fn common_mut(object: &mut ShapeObject) -> &mut hwp_model::shape::ShapeCommon {
+    match object {
+        ShapeObject::Table(o) => &mut o.common,
+        ShapeObject::Picture(o) => &mut o.common,
+        ShapeObject::Line(o) => &mut o.common,
+        ShapeObject::Rectangle(o) => &mut o.common,
+        ShapeObject::Ellipse(o) => &mut o.common,
+        ShapeObject::Arc(o) => &mut o.common,
+        ShapeObject::Polygon(o) => &mut o.common,
+        ShapeObject::Curve(o) => &mut o.common,
+        ShapeObject::ConnectLine(o) => &mut o.common,
+        ShapeObject::TextArt(o) => &mut o.common,
+        ShapeObject::Container(o) => &mut o.common,
+        ShapeObject::Ole(o) => &mut o.common,
+        ShapeObject::Equation(o) => &mut o.common,
+        ShapeObject::Chart(o) => &mut o.common,
+        ShapeObject::Video(o) => &mut o.common,
+        ShapeObject::Form(o) => &mut o.common,
+    }
+}
+
+fn draw_text_mut(object: &mut ShapeObject) -> Option<&mut SubList> {
+    match object {
+        ShapeObject::Line(o) => o.draw_text.as_mut(),
+        ShapeObject::Rectangle(o) => o.draw_text.as_mut(),
+        ShapeObject::Ellipse(o) => o.draw_text.as_mut(),
+        ShapeObject::Arc(o) => o.draw_text.as_mut(),
+        ShapeObject::Polygon(o) => o.draw_text.as_mut(),
+        ShapeObject::Curve(o) => o.draw_text.as_mut(),
+        _ => None,
+    }
+}
+
+/// 본문 흐름: 단을 채우고 넘치면 다음 단, 마지막 단이면 다음 쪽 (vertical_pos는 쪽/단마다 0부터)
+struct BodyFlow {
+    /// 본문 높이 (쪽 높이 - 위/아래/머리말/꼬리말 여백)
+    body_height: HwpUnit,
+    /// 본문 너비 (쪽 폭 - 좌우/제본 여백)
+    total_width: HwpUnit,
+    column_widths: Vec<HwpUnit>,
+    column: usize,
+    y: HwpUnit,
+    /// 현재 다단 영역이 시작한 위치 (쪽 중간에서 단 모양이 바뀐 경우)
+    region_top: HwpUnit,
+    started: bool,
+}
+
+impl BodyFlow {
+    fn new(def: &SectionDef) -> Self {
+        let (total_width, body_height) = body_size(&def.page);
+        let column_widths = match &def.columns {
+            Some(columns) => column_widths(
+                total_width,
+                columns.col_count,
+                columns.same_gap,
+                if columns.same_size {
+                    &[]
+                } else {
+                    &columns.col_sizes
+                },
+            ),
+            None => vec![total_width],
+        };
+        Self {
+            body_height,
+            total_width,
+            column_widths,
+            column: 0,
+            y: 0,
+            region_top: 0,
+            started: false,
+        }
+    }
+
+    fn column_width(&self) -> HwpUnit {
+        self.column_widths
+            .get(self.column)
+            .copied()
+            .unwrap_or(self.total_width)
+    }
+
+    fn next_column(&mut self) {
+        self.column += 1;
+        if self.column >= self.column_widths.len() {
+            self.new_page();
+        } else {
+            self.y = self.region_top;
+        }
+    }
+
+    fn new_page(&mut self) {
+        self.column = 0;
+        self.region_top = 0;
+        self.y = 0;
+    }
+
+    fn paragraph(&mut self, para: &mut Paragraph, ctx: &Context) {
+        ctx.layout_nested(para);
+
+        if self.started {
+            if para.page_break {
+                self.new_page();
+            } else if para.column_break {
+                self.next_column();
+            }
+        }
+        // 단 정의: 이후 내용은 새 다단 영역에 배치
+        let column_control = para
+            .runs
+            .iter()
+            .flat_map(|r| &r.contents)
+            .find_map(|c| match c {
+                RunContent::Control(Control::Column(column)) => Some(column),
+                _ => None,
+            });
+        if let Some(column) = column_control {
+            if self.started {
+                self.region_top = self.y;
+            }
+            self.column = 0;
+            self.column_widths =
+                column_widths(self.total_width, column.col_count, column.same_gap, &[]);
+        }
+        self.started = true;
+
+        let (before, after) =
+            paragraph_spacing(ctx.resources.para_shapes.get(para.para_shape_id as usize));
+        if !ctx.needs_layout(para) {
+            if let Some(last) = para.line_segments.last() {
+                self.y = line_bottom(last) + after;
+            }
+            return;
+        }
+
+        let mut segments = ctx.break_paragraph(para, self.column_width());
+        self.y += before;
+        let top = self.y;
+        for segment in &mut segments {
+            if self.y + segment.line_height > self.body_height && self.y > self.region_top {
+                self.next_column();
+            }
+            segment.vertical_pos = self.y;
+            self.y += segment.line_height + segment.line_spacing;
+        }
+        para.line_segments = segments;
+        self.y = (self.y + after).max(top + ctx.pushed_bottom(para));
+    }
+}
+
+/// 쪽의 본문 (너비, 높이) — 가로 방향이면 폭과 높이를 바꿔 씀
+fn body_size(page: &PageDef) -> (HwpUnit, HwpUnit) {
+    let (width, height) = match page.landscape {
+        Landscape::Landscape => (page.height, page.width),
+        _ => (page.width, page.height),
+    };
+    let m = &page.margin;
+    (
+        (width - m.left - m.right - m.gutter).max(0),
+        (height - m.top - m.bottom - m.header - m.footer).max(0),
+    )
+}
+
+/// 단 너비 목록: 너비가 지정되어 있으면 그대로, 아니면 간격을 뺀 나머지를 똑같이 나눔
+fn column_widths(
+    total: HwpUnit,
+    count: u16,
+    gap: HwpUnit,
+    sizes: &[hwp_model::section::ColumnSize],
+) -> Vec<HwpUnit> {
+    if !sizes.is_empty() {
+        return sizes.iter().map(|size| size.width).collect();
+    }
+    let count = count.max(1) as HwpUnit;
+    vec![((total - gap * (count - 1)) / count).max(0); count as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hwp_model::paragraph::{Run, TextContent, TextElement};
+    use hwp_model::resources::{CharShape, ParaShape};
+    use hwp_model::section::{PageMargin, Section};
+
+    fn text_para(text: &str) -> Paragraph {
+        Paragraph {
+            runs: vec![Run {
+                char_shape_id: 0,
+                contents: vec![RunContent::Text(TextContent {
+                    char_shape_id: None,
+                    elements: vec![TextElement::Text(text.to_string())],
+                })],
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn document(paragraphs: Vec<Paragraph>) -> Document {
+        let mut doc = Document::default();
+        doc.resources.char_shapes.push(CharShape {
+            height: 1000,
+            ..Default::default()
+        });
+        let mut shape = ParaShape::default();
+        shape.line_spacing.value = 160;
+        doc.resources.para_shapes.push(shape);
+        let mut section = Section {
+            paragraphs,
+            ..Default::default()
+        };
+        section.definition.page = PageDef {
+            width: 20000,
+            height: 10000,
+            margin: PageMargin::default(),
+            ..Default::default()
+        };
+        doc.sections.push(section);
+        doc
+    }
+
+    #[test]
+    fn test_layout_fills_missing_segments_and_wraps_pages() {
+        // 본문 20000 × 10000: 한 줄 20글자, 6줄(1600 간격)이면 쪽을 넘김
+        let mut doc = document(vec![text_para(&"가".repeat(100)), text_para("끝")]);
+        layout_document(&mut doc, &LayoutOptions::default());
+        let first = &doc.sections[0].paragraphs[0].line_segments;
+        assert_eq!(first.len(), 5);
+        assert_eq!(first[1].text_start_pos, 20);
+        let vertical: Vec<i32> = first.iter().map(|s| s.vertical_pos).collect();
+        assert_eq!(vertical, vec![0, 1600, 3200, 4800, 6400]);
+        // 다음 문단 첫 줄은 8000에서 시작 (9000 ≤ 10000)
+        let second = &doc.sections[0].paragraphs[1].line_segments;
+        assert_eq!(second[0].vertical_pos, 8000);
+
+        // 줄이 쪽 끝을 넘으면 0으로 돌아감
+        let mut doc = document(vec![text_para(&"가".repeat(140))]);
+        layout_document(&mut doc, &LayoutOptions::default());
+        let segments = &doc.sections[0].paragraphs[0].line_segments;
+        assert_eq!(segments.len(), 7);
+        assert_eq!(segments[6].vertical_pos, 0);
+    }
+
+    #[test]
+    fn test_layout_keeps_existing_segments_unless_relayout() {
+        let mut doc = document(vec![text_para("가나다")]);
+        let cached = LineSegmentInfo {
+            line_height: 42,
+            ..Default::default()
+        };
+        doc.sections[0].paragraphs[0].line_segments = vec![cached];
+        layout_document(&mut doc, &LayoutOptions::default());
+        assert_eq!(
+            doc.sections[0].paragraphs[0].line_segments[0].line_height,
+            42
+        );
+
+        let options = LayoutOptions {
+            relayout_all: true,
+            ..Default::default()
+        };
+        layout_document(&mut doc, &options);
+        assert_eq!(
+            doc.sections[0].paragraphs[0].line_segments[0].line_height,
+            1000
+        );
+    }
+
+    #[test]
+    fn test_columns_split_body_width() {
+        let mut doc = document(vec![text_para("가")]);
+        doc.sections[0].definition.columns = Some(hwp_model::section::ColumnDef {
+            col_count: 2,
+            same_size: true,
+            same_gap: 2000,
+            ..Default::default()
+        });
+        layout_document(&mut doc, &LayoutOptions::default());
+        assert_eq!(
+            doc.sections[0].paragraphs[0].line_segments[0].segment_width,
+            9000
+        );
+    }
+
+    #[test]
+    fn test_layout_paragraph_margins() {
+        let mut doc = document(vec![]);
+        doc.resources.para_shapes[0].margin.left.value = 2200;
+        doc.resources.para_shapes[0].margin.right.value = 2400;
+        let segments = layout_paragraph(
+            &text_para("문단1"),
+            &doc.resources,
+            &EstimatedMetrics,
+            42520,
+        );
+        // 한글 문서와 같이 여백은 절반 값: column_start 1100, 너비 42520 - 1100 - 1200
+        assert_eq!(segments[0].column_start_pos, 1100);
+        assert_eq!(segments[0].segment_width, 40220);
+        assert_eq!(segments[0].flags, 0x60000);
+    }
+}
@@ -0,0 +1,148 @@
+/// Document 문단 → 줄 나누기 입력 / Paragraph → line-breaking input
+///
+/// 항목 위치는 `doc_html::flat_text`와 같이 원본 WCHAR 기준으로 셉니다
+/// (글자·탭·줄 바꿈 1, 컨트롤·개체 8, 단 정의 16).
+use super::line_break::{ItemKind, LineItem, LineParams};
+use super::metrics::{char_width, FontMetrics, DEFAULT_HEIGHT};
+use hwp_model::control::Control;
+use hwp_model::paragraph::{Paragraph, RunContent, TextElement};
+use hwp_model::resources::{ParaShape, Resources};
+use hwp_model::shape::ShapeObject;
+
+/// 문단 모양 → 줄 나누기 설정 / Line parameters of a paragraph
+///
+/// 문단 여백과 고정 줄 간격은 HWPUNIT의 두 배로 저장되어 있어 절반으로 씁니다
+/// (레이아웃 HTML/Canvas의 들여쓰기 처리와 같음).
+pub(crate) fn line_params(
+    para: &Paragraph,
+    resources: &Resources,
+    width: i32,
+    default_tab: i32,
+) -> LineParams {
+    let mut params = LineParams {
+        width,
+        default_tab: if default_tab > 0 { default_tab } else { 8000 },
+        empty_height: paragraph_end_height(para, resources),
+        ..Default::default()
+    };
+    let Some(shape) = resources.para_shapes.get(para.para_shape_id as usize) else {
+        return params;
+    };
+    params.left = shape.margin.left.value / 2;
+    params.right = shape.margin.right.value / 2;
+    params.indent = shape.margin.indent.value / 2;
+    params.spacing_type = shape.line_spacing.spacing_type.clone();
+    params.spacing = match shape.line_spacing.spacing_type {
+        hwp_model::types::LineSpacingType::Percent => shape.line_spacing.value,
+        _ => shape.line_spacing.value / 2,
+    };
+    params.break_latin = shape.break_setting.break_latin_word.clone();
+    params.break_non_latin = shape.break_setting.break_non_latin_word.clone();
+    if let Some(tab_def) = shape
+        .tab_def_id
+        .and_then(|id| resources.tab_defs.get(id as usize))
+    {
+        params.tabs = tab_def
+            .items
+            .iter()
+            .map(|item| (item.pos, item.tab_type.clone()))
+            .collect();
+        params.tabs.sort_by_key(|(pos, _)| *pos);
+    }
+    params
+}
+
+/// 문단 앞/뒤 간격 (HWPUNIT) / Spacing before and after a paragraph
+pub(crate) fn paragraph_spacing(shape: Option<&ParaShape>) -> (i32, i32) {
+    shape.map_or((0, 0), |s| {
+        (s.margin.prev.value / 2, s.margin.next.value / 2)
+    })
+}
+
+/// 문단 끝 글자 크기 — 빈 줄의 높이 / Height of the paragraph-end character
+fn paragraph_end_height(para: &Paragraph, resources: &Resources) -> i32 {
+    para.runs
+        .last()
+        .and_then(|run| resources.char_shapes.get(run.char_shape_id as usize))
+        .map_or(DEFAULT_HEIGHT, |cs| cs.height)
+}
+
+/// 문단의 줄 항목 / Line items of a paragraph
+///
+/// `object_size`는 글자처럼 취급한 개체의 (너비, 높이)를 돌려줍니다.
+pub(crate) fn paragraph_items(
+    para: &Paragraph,
+    resources: &Resources,
+    metrics: &dyn FontMetrics,
+    object_size: &dyn Fn(&ShapeObject) -> (i32, i32),
+) -> Vec<LineItem> {
+    let mut items = Vec::new();
+    let mut pos: u32 = 0;
+    for run in &para.runs {
+        for content in &run.contents {
+            match content {
+                RunContent::Text(text) => {
+                    let shape_id = text.char_shape_id.unwrap_or(run.char_shape_id);
+                    let shape = resources.char_shapes.get(shape_id as usize);
+                    let height = shape.map_or(DEFAULT_HEIGHT, |cs| cs.height);
+                    let width = |ch: char| char_width(metrics, &resources.fonts, shape, ch);
+                    let mut push = |pos: u32, kind: ItemKind, width: f64| {
+                        items.push(LineItem {
+                            pos,
+                            kind,
+                            width,
+                            height,
+                        })
+                    };
+                    for element in &text.elements {
+                        match element {
+                            TextElement::Text(s) => {
+                                for ch in s.chars() {
+                                    let kind = if ch == ' ' {
+                                        ItemKind::Space
+                                    } else {
+                                        ItemKind::Char(ch)
+                                    };
+                                    push(pos, kind, width(ch));
+                                    pos += 1;
+                                }
+                                continue;
+                            }
+                            TextElement::Tab { .. } => push(pos, ItemKind::Tab, 0.0),
+                            TextElement::LineBreak => push(pos, ItemKind::LineBreak, 0.0),
+                            TextElement::NbSpace => {
+                                push(pos, ItemKind::Char('\u{00a0}'), width(' '))
+                            }
+                            TextElement::FwSpace => push(pos, ItemKind::Space, width(' ')),
+                            TextElement::Hyphen => push(pos, ItemKind::Char('-'), width('-')),
+                            _ => {}
+                        }
+                        pos += 1;
+                    }
+                }
+                RunContent::Control(control) => {
+                    pos += match control {
+                        Control::Column(_) => 16,
+                        _ => 8,
+                    };
+                }
+                RunContent::Object(object) => {
+                    if crate::viewer::doc_utils::shape_common(object)
+                        .position
+                        .treat_as_char
+                    {
+                        let (width, height) = object_size(object);
+                        items.push(LineItem {
+                            pos,
+                            kind: ItemKind::Object,
+                            width: width as f64,
+                            height,
+                        });
+                    }
+                    pos += 8;
+                }
+            }
+        }
+    }
+    items
+}
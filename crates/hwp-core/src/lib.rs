@@ -7,8 +7,15 @@ pub mod convert;
 pub mod decompress;
 pub mod document;
 pub mod error;
+pub(crate) mod font;
+pub mod hml;
+pub mod hwp3;
+pub mod layout;
+pub mod mail_merge;
+pub mod ole;
 pub mod types;
 pub mod viewer;
+pub mod writer;
 
 use ::cfb::CompoundFile;
 use std::io::Cursor;
@@ -17,24 +24,42 @@ pub use cfb::CfbParser;
 pub use decompress::{decompress_deflate, decompress_zlib};
 pub use document::{
     BinData, BinDataRecord, BodyText, BorderFill, Bullet, CharShape, DocInfo, DocumentProperties,
-    FaceName, FileHeader, HwpDocument, IdMappings, Numbering, ParaShape, Section,
-    SummaryInformation, TabDef,
+    FaceName, FileHeader, HwpDocument, IdMappings, Numbering, ParaShape, RawDocument, RawRecord,
+    RawStream, Section, SummaryInformation, TabDef,
 };
 pub use error::{CompressionFormat, HwpError};
 pub use types::{
     RecordHeader, BYTE, COLORREF, DWORD, HWPUNIT, HWPUNIT16, INT16, INT32, INT8, SHWPUNIT, UINT,
     UINT16, UINT32, UINT8, WCHAR, WORD,
 };
+pub use writer::HwpWriter;
 
 /// Main HWP parser structure
 pub struct HwpParser {
-    // Placeholder for future implementation
+    /// 원본 레코드/스트림 보존 여부 / Whether to keep raw records and streams
+    preserve_raw: bool,
 }
 
 impl HwpParser {
     /// Create a new HWP parser
     pub fn new() -> Self {
-        Self {}
+        Self {
+            preserve_raw: false,
+        }
+    }
+
+    /// 원본 레코드 보존 모드 설정 / Set raw record preservation mode
+    ///
+    /// 켜면 `HwpDocument::raw`에 모든 레코드(알 수 없는 태그 포함)와 스트림을 보관하여
+    /// `HwpWriter::write_raw`로 원본과 같은 바이트로 다시 쓸 수 있습니다. `convert::to_document`로
+    /// 변환한 문서를 `HwpWriter::write`로 쓸 때도 모델에 없는 DocInfo 레코드와 스트림이 함께 기록됩니다.
+    /// When enabled, `HwpDocument::raw` keeps every record (including unknown tags) and stream
+    /// so that `HwpWriter::write_raw` can re-serialize the document byte-identically. Documents
+    /// converted with `convert::to_document` also carry the unmodeled DocInfo records and streams
+    /// into `HwpWriter::write`.
+    pub fn preserve_raw_records(mut self, preserve: bool) -> Self {
+        self.preserve_raw = preserve;
+        self
     }
 
     /// Parse HWP file from byte array
@@ -44,12 +69,18 @@ impl HwpParser {
     ///
     /// # Returns
     /// Parsed HWP document structure
+    /// 암호가 설정된 문서는 `HwpError::PasswordRequired`를 반환합니다.
+    /// Password-protected documents return `HwpError::PasswordRequired`.
     pub fn parse(&self, data: &[u8]) -> Result<HwpDocument, HwpError> {
         // Parse CFB structure
         let mut cfb = CfbParser::parse(data)?;
 
         // Parse required streams
         let fileheader = self.parse_fileheader(&mut cfb)?;
+        // 한컴의 암호 문서 암호화 방식은 공개 스펙에 없어 복호화하지 않습니다
+        if fileheader.is_encrypted() {
+            return Err(HwpError::PasswordRequired);
+        }
         let mut document = HwpDocument::new(fileheader.clone());
         document.doc_info = self.parse_docinfo(&mut cfb, &fileheader)?;
         document.body_text = self.parse_bodytext(&mut cfb, &fileheader, &document.doc_info)?;
@@ -76,9 +107,18 @@ impl HwpParser {
         // Parse optional streams
         self.parse_optional_streams(&mut cfb, &fileheader, &mut document, data);
 
+        if self.preserve_raw {
+            document.raw = Some(RawDocument::read(&mut cfb, fileheader.is_compressed())?);
+        }
+
         // Resolve derived display texts (e.g., AUTO_NUMBER in captions) for JSON/viewers.
         document.resolve_display_texts();
 
+        if let Some(mut raw) = document.raw.take() {
+            raw.seal(&document);
+            document.raw = Some(raw);
+        }
+
         Ok(document)
     }
 
@@ -148,11 +188,13 @@ impl HwpParser {
                     )?;
 
                     // Parse section data into paragraph list
-                    let paragraphs = Section::parse_data(&section_data, fileheader.version)?;
+                    let (paragraphs, memos) =
+                        Section::parse_data_with_memos(&section_data, fileheader.version)?;
 
                     sections.push(Section {
                         index: i,
                         paragraphs,
+                        memos,
                     });
                 }
                 Err(e) => {
@@ -441,13 +483,18 @@ impl HwpParser {
     /// 파싱 레벨에서 처리하므로 렌더링, 페이지네이션, 테이블 셀 등
     /// 모든 downstream 코드가 자동으로 혜택을 받는다.
     ///
-    /// 줄바꿈 위치는 글꼴 메트릭 없이는 알 수 없으므로 문단 전체를 1개 세그먼트로 처리한다.
+    /// 줄바꿈 위치는 `layout` 모듈의 줄 나누기로 정하고, 본문 줄이 쪽 본문 높이를 넘으면
+    /// vertical_position을 0으로 되돌려 한글 문서처럼 쪽 나눔을 표시한다.
     fn synthesize_missing_line_segments(document: &mut HwpDocument) {
         use document::bodytext::ParagraphRecord;
 
         // doc_info에 대한 참조를 먼저 분리하여 borrow 충돌 방지
         let doc_info = &document.doc_info as *const DocInfo;
 
+        // 줄 나누기에 쓸 문서 모델 리소스 (글꼴, 글자/문단 모양, 탭 정의)
+        // SAFETY: doc_info는 body_text 수정 중에 변경되지 않으므로 안전
+        let resources = convert::resources::convert_resources(unsafe { &*doc_info });
+
         // 현재 PageDef 추적 (섹션별로 다를 수 있음)
         let mut current_page_def: Option<document::bodytext::PageDef> = None;
 
@@ -472,6 +519,7 @@ impl HwpParser {
                 Self::synthesize_for_paragraph(
                     paragraph,
                     di,
+                    &resources,
                     &current_page_def,
                     &mut accumulated_vertical,
                     true, // 본문 레벨: vertical_position 누적
@@ -489,6 +537,7 @@ impl HwpParser {
     fn synthesize_for_paragraph(
         paragraph: &mut document::Paragraph,
         doc_info: &DocInfo,
+        resources: &hwp_model::resources::Resources,
         page_def: &Option<document::bodytext::PageDef>,
         accumulated_vertical: &mut i32,
         is_body_level: bool,
@@ -512,6 +561,7 @@ impl HwpParser {
                         Self::synthesize_for_paragraph(
                             para,
                             doc_info,
+                            resources,
                             page_def,
                             &mut sub_vert,
                             false,
@@ -529,6 +579,7 @@ impl HwpParser {
                                     Self::synthesize_for_paragraph(
                                         para,
                                         doc_info,
+                                        resources,
                                         page_def,
                                         &mut list_vert,
                                         false,
@@ -586,6 +637,7 @@ impl HwpParser {
                                         Self::synthesize_for_paragraph(
                                             para,
                                             doc_info,
+                                            resources,
                                             page_def,
                                             &mut cell_vert,
                                             false,
@@ -619,6 +671,7 @@ impl HwpParser {
                         Self::synthesize_for_paragraph(
                             para,
                             doc_info,
+                            resources,
                             page_def,
                             &mut list_vert,
                             false,
@@ -638,6 +691,7 @@ impl HwpParser {
                                 Self::synthesize_for_paragraph(
                                     para,
                                     doc_info,
+                                    resources,
                                     page_def,
                                     &mut list_vert,
                                     false,
@@ -662,17 +716,40 @@ impl HwpParser {
             let mut segments = Self::create_synthetic_line_segments(
                 paragraph,
                 doc_info,
+                resources,
                 page_def,
                 content_width_override_hu,
             );
-            // 본문/하위 문단 모두 vertical_position을 누적
+            // 본문/하위 문단 모두 vertical_position을 누적 (줄 높이 + 줄 간격, 문단 위/아래 간격)
             // 하위(셀) 문단에서도 줄 위치가 올바르게 계산되어야 렌더링이 정확함
+            // 본문은 쪽 본문 높이를 넘는 줄에서 0으로 되돌림 (쪽 나눔)
+            let body_height = page_def.as_ref().filter(|_| is_body_level).map(|pd| {
+                let paper_height = match pd.attributes.paper_direction {
+                    document::bodytext::PaperDirection::Horizontal => pd.paper_width.0,
+                    document::bodytext::PaperDirection::Vertical => pd.paper_height.0,
+                };
+                paper_height as i32
+                    - pd.top_margin.0 as i32
+                    - pd.bottom_margin.0 as i32
+                    - pd.header_margin.0 as i32
+                    - pd.footer_margin.0 as i32
+            });
+            let (before, after) = layout::paragraph::paragraph_spacing(
+                resources
+                    .para_shapes
+                    .get(paragraph.para_header.para_shape_id as usize),
+            );
+            let mut y = *accumulated_vertical + before;
             for seg in &mut segments {
-                seg.vertical_position += *accumulated_vertical;
-            }
-            if let Some(last) = segments.last() {
-                *accumulated_vertical = last.vertical_position + last.line_spacing;
+                if body_height.is_some_and(|h| y > 0 && y + seg.line_height > h) {
+                    y = 0;
+                    seg.tag.is_first_line_of_page = true;
+                    seg.tag.is_first_line_of_column = true;
+                }
+                seg.vertical_position = y;
+                y += seg.line_height + seg.line_spacing;
             }
+            *accumulated_vertical = y + after;
             let insert_pos = paragraph
                 .records
                 .iter()
@@ -694,15 +771,14 @@ impl HwpParser {
     }
 
     /// ParaShape/CharShape/PageDef 기반으로 합성 LineSegmentInfo를 생성한다.
-    /// 글자 폭을 근사 계산하여 줄바꿈 위치를 추정하고, 줄 수만큼 세그먼트를 생성한다.
-    ///
-    /// 글자 폭 근사:
-    /// - 한글/CJK (U+1100~U+FFEF): 폭 ≈ font_size (전각)
-    /// - ASCII/Latin: 폭 ≈ font_size × 0.5 (반각)
-    /// - 공백: 폭 ≈ font_size × 0.25
+    /// 텍스트 문단은 `layout` 모듈의 줄 나누기(글자 폭 추정, 여백/들여쓰기, 줄 간격 종류,
+    /// 영어/한글 줄 나눔 기준, 탭)로 줄 수와 줄 시작 위치를 정하고,
+    /// 개체가 있는 문단은 개체 높이로 1개 세그먼트를 만든다.
+    /// vertical_position은 문단 첫 줄 기준(0부터)이다.
     fn create_synthetic_line_segments(
         paragraph: &document::Paragraph,
         doc_info: &DocInfo,
+        resources: &hwp_model::resources::Resources,
         page_def: &Option<document::bodytext::PageDef>,
         content_width_override_hu: Option<i32>,
     ) -> Vec<document::bodytext::line_seg::LineSegmentInfo> {
@@ -739,9 +815,6 @@ impl HwpParser {
         // base_size는 pt×100 단위, HWPUNIT: 1pt = 100 HWPUNIT
         let font_size_hu = base_size;
         let text_height_hu = font_size_hu;
-        // line_height: fixture 역산 기준 text_height × 0.75 (폰트 메트릭 근사)
-        // HWP 원본의 line_height는 font metric 기반이며 text_height보다 작음
-        let line_height_hu = (text_height_hu as f64 * 0.75).round() as i32;
         let text_line_height_hu = (font_size_hu as f64 * line_spacing_pct / 100.0).round() as i32;
         let baseline_distance_hu = (text_height_hu as f64 * 0.85).round() as i32;
 
@@ -860,15 +933,15 @@ impl HwpParser {
         // 개체가 있으면 1개 세그먼트로 처리 (개체 높이 사용)
         if has_object {
             let obj_height_hu = object_height_hu.max(text_line_height_hu);
-            // 줄 간격: 개체 높이 + 마진×2 (fixture에서 개체 상하 마진이 내부+외부에 이중 적용)
-            let obj_spacing_hu = obj_height_hu + object_margin_hu * 2;
+            // 줄 간격: 마진×2 (fixture에서 개체 상하 마진이 내부+외부에 이중 적용)
+            let obj_spacing_hu = object_margin_hu * 2;
             return vec![LineSegmentInfo {
                 text_start_position: 0,
                 vertical_position: 0,
                 line_height: obj_height_hu,
                 text_height: text_height_hu,
                 baseline_distance: baseline_distance_hu,
-                line_spacing: obj_spacing_hu, // 개체 높이 + 마진을 줄 간격으로 사용
+                line_spacing: obj_spacing_hu,
                 column_start_position: 0,
                 segment_width: content_width_hu,
                 tag: LineSegmentTag {
@@ -896,43 +969,17 @@ impl HwpParser {
                 }
             })
             .unwrap_or("");
-
-        // 글자 폭 근사 계산으로 줄바꿈 위치 추정
-        // 각 줄의 시작 문자 위치(WCHAR 인덱스)를 반환
-        // indent_width_hu: 내어쓰기 시 2줄째부터 줄어드는 너비 (양수)
-        let estimate_line_breaks =
-            |text: &str, line_width_hu: i32, font_hu: i32, indent_width_hu: i32| -> Vec<u32> {
-                let mut line_starts: Vec<u32> = vec![0];
-                if text.is_empty() || line_width_hu <= 0 || font_hu <= 0 {
-                    return line_starts;
-                }
-                let mut current_width: i32 = 0;
-                let mut line_num = 1usize;
-                for (char_idx, ch) in text.chars().enumerate() {
-                    let char_width = if ch == ' ' {
-                        (font_hu as f64 * 0.10) as i32
-                    } else if ch == '\t' {
-                        font_hu * 2
-                    } else if Self::is_fullwidth_char(ch) {
-                        (font_hu as f64 * 0.85) as i32
-                    } else {
-                        (font_hu as f64 * 0.425) as i32
-                    };
-                    current_width += char_width;
-                    // 내어쓰기: 2줄째부터 너비가 줄어듬
-                    let effective_width = if line_num > 1 && indent_width_hu > 0 {
-                        line_width_hu - indent_width_hu
-                    } else {
-                        line_width_hu
-                    };
-                    if current_width > effective_width {
-                        line_starts.push(char_idx as u32);
-                        current_width = char_width;
-                        line_num += 1;
-                    }
+        let char_shapes = paragraph
+            .records
+            .iter()
+            .find_map(|r| {
+                if let ParagraphRecord::ParaCharShape { shapes } = r {
+                    Some(shapes.as_slice())
+                } else {
+                    None
                 }
-                line_starts
-            };
+            })
+            .unwrap_or(&[]);
 
         // gso 이미지가 있으면 텍스트 영역을 이미지 오른쪽으로 밀기
         // fixture: text_option=square일 때 column_start_position=이미지 너비, width=셀너비-이미지너비
@@ -945,76 +992,103 @@ impl HwpParser {
             (0, content_width_hu)
         };
 
-        // ParaShape의 indent로 들여쓰기/내어쓰기 판단
-        let indent_val = para_shape.map(|ps| ps.indent).unwrap_or(0);
-
-        // 내어쓰기(indent<0)일 때 2줄째부터 줄어드는 너비
-        // render_line_segment에서 indent / 2.0으로 padding-left를 계산하므로 여기서도 동일하게 적용
-        let indent_padding_hu = if indent_val < 0 {
-            indent_val.unsigned_abs() as i32 / 2
-        } else {
-            0
-        };
-
-        // 줄바꿈 추정은 텍스트 영역 너비 기준
-        let line_starts =
-            estimate_line_breaks(para_text, text_seg_width, font_size_hu, indent_padding_hu);
-        let line_count = line_starts.len();
-
-        let mut segments = Vec::with_capacity(line_count);
-        for i in 0..line_count {
-            segments.push(LineSegmentInfo {
-                text_start_position: line_starts[i],
-                vertical_position: (i as i32) * text_line_height_hu,
-                line_height: line_height_hu,
-                text_height: text_height_hu,
-                baseline_distance: baseline_distance_hu,
-                line_spacing: text_line_height_hu,
-                column_start_position: text_col_start,
-                segment_width: text_seg_width,
+        // 글꼴 메트릭과 문단 모양(여백, 들여쓰기, 줄 간격, 줄 나눔 기준, 탭)으로 줄 나누기
+        let layout_para =
+            Self::layout_input(paragraph.para_header.para_shape_id, para_text, char_shapes);
+        let items = layout::paragraph::paragraph_items(
+            &layout_para,
+            resources,
+            &layout::EstimatedMetrics,
+            &|_| (0, 0),
+        );
+        let params = layout::paragraph::line_params(&layout_para, resources, text_seg_width, 0);
+        layout::line_break::break_lines(&items, &params)
+            .into_iter()
+            .enumerate()
+            .map(|(i, seg)| LineSegmentInfo {
+                text_start_position: seg.text_start_pos,
+                vertical_position: seg.vertical_pos,
+                line_height: seg.line_height,
+                text_height: seg.text_height,
+                baseline_distance: seg.baseline_distance,
+                line_spacing: seg.line_spacing,
+                column_start_position: seg.column_start_pos + text_col_start,
+                segment_width: seg.segment_width,
                 tag: LineSegmentTag {
                     is_first_line_of_page: i == 0,
                     is_first_line_of_column: i == 0,
                     is_empty_segment: false,
-                    is_first_segment_of_line: true,
-                    is_last_segment_of_line: true,
-                    has_auto_hyphenation: false,
-                    // 내어쓰기(indent<0): 2번째 줄부터 padding-left 적용
-                    // 들여쓰기(indent>0): 첫 줄에만 padding-left 적용
-                    has_indentation: if indent_val < 0 {
-                        i > 0
-                    } else if indent_val > 0 {
-                        i == 0
-                    } else {
-                        false
-                    },
+                    is_first_segment_of_line: seg.flags & 0x20000 != 0,
+                    is_last_segment_of_line: seg.flags & 0x40000 != 0,
+                    has_auto_hyphenation: seg.flags & 0x80000 != 0,
+                    has_indentation: seg.flags & 0x100000 != 0,
                     has_paragraph_header_shape: false,
                 },
-            });
-        }
-
-        segments
+            })
+            .collect()
     }
 
-    /// 전각 문자 판별 (한글, CJK, 전각 기호 등)
-    fn is_fullwidth_char(ch: char) -> bool {
-        let cp = ch as u32;
-        // 한글 자모
-        (0x1100..=0x11FF).contains(&cp)
-        // 한글 호환 자모
-        || (0x3130..=0x318F).contains(&cp)
-        // 한글 음절
-        || (0xAC00..=0xD7AF).contains(&cp)
-        // CJK 통합 한자
-        || (0x4E00..=0x9FFF).contains(&cp)
-        // CJK 호환 한자
-        || (0xF900..=0xFAFF).contains(&cp)
-        // 전각 기호
-        || (0xFF01..=0xFF60).contains(&cp)
-        // CJK 기호 및 구두점
-        || (0x3000..=0x303F).contains(&cp)
-        // 히라가나/가타카나
-        || (0x3040..=0x30FF).contains(&cp)
+    /// 레거시 문단 텍스트를 글자 모양 구간별 Run으로 나눈 layout 입력 문단
+    /// Layout input paragraph built from legacy ParaText split at ParaCharShape positions
+    fn layout_input(
+        para_shape_id: u16,
+        text: &str,
+        char_shapes: &[document::bodytext::CharShapeInfo],
+    ) -> hwp_model::paragraph::Paragraph {
+        use hwp_model::paragraph::{Run, RunContent, TextContent, TextElement};
+
+        let chars: Vec<char> = text.chars().collect();
+        let fallback = [document::bodytext::CharShapeInfo {
+            position: 0,
+            shape_id: 0,
+        }];
+        let char_shapes = if char_shapes.is_empty() {
+            &fallback[..]
+        } else {
+            char_shapes
+        };
+        let mut runs = Vec::new();
+        for (i, shape) in char_shapes.iter().enumerate() {
+            let start = (shape.position as usize).min(chars.len());
+            let end = char_shapes.get(i + 1).map_or(chars.len(), |next| {
+                (next.position as usize).min(chars.len())
+            });
+            let mut elements = Vec::new();
+            let mut buf = String::new();
+            for &ch in chars.get(start..end).unwrap_or(&[]) {
+                let element = match ch {
+                    '\t' => TextElement::Tab {
+                        width: 0,
+                        leader: hwp_model::types::LineType2::None,
+                        tab_type: hwp_model::paragraph::TabType::Left,
+                    },
+                    '\n' => TextElement::LineBreak,
+                    _ => {
+                        buf.push(ch);
+                        continue;
+                    }
+                };
+                if !buf.is_empty() {
+                    elements.push(TextElement::Text(std::mem::take(&mut buf)));
+                }
+                elements.push(element);
+            }
+            if !buf.is_empty() {
+                elements.push(TextElement::Text(buf));
+            }
+            runs.push(Run {
+                char_shape_id: shape.shape_id as u16,
+                contents: vec![RunContent::Text(TextContent {
+                    char_shape_id: None,
+                    elements,
+                })],
+            });
+        }
+        hwp_model::paragraph::Paragraph {
+            para_shape_id,
+            runs,
+            ..Default::default()
+        }
     }
 }
 
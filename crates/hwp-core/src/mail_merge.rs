@@ -0,0 +1,180 @@
+//! 메일 머지 데이터 원본 / Mail merge data sources
+//!
+//! CSV(첫 줄이 필드 이름)나 JSON(객체 배열)을 필드 이름 → 값 레코드 목록으로 읽습니다.
+//! 읽은 레코드는 `Document::fill_fields_batch`로 문서마다 채웁니다.
+//! Reads CSV (header row = field names) or JSON (array of objects) into field records
+//! for `Document::fill_fields_batch`.
+
+use crate::error::HwpError;
+use hwp_model::document::Document;
+use hwp_model::mail_merge::FieldRecord;
+
+/// CSV → 레코드 목록 / Parse CSV into field records
+///
+/// RFC 4180 형식(쉼표 구분, `"`로 감싼 값 안의 `""`는 따옴표)을 읽습니다.
+/// 첫 줄은 필드 이름이고, 빈 줄은 건너뜁니다. 열 개수가 첫 줄과 다른 행은 오류입니다.
+pub fn records_from_csv(text: &str) -> Result<Vec<FieldRecord>, HwpError> {
+    let mut rows = parse_csv(text.trim_start_matches('\u{feff}'))?.into_iter();
+    let Some((_, header)) = rows.next() else {
+        return Ok(Vec::new());
+    };
+    rows.filter(|(_, row)| row.iter().any(|cell| !cell.is_empty()))
+        .map(|(line, row)| {
+            if row.len() != header.len() {
+                return Err(HwpError::data_source(
+                    "CSV",
+                    format!(
+                        "line {}: expected {} columns, found {}",
+                        line,
+                        header.len(),
+                        row.len()
+                    ),
+                ));
+            }
+            Ok(header.iter().cloned().zip(row).collect())
+        })
+        .collect()
+}
+
+/// JSON → 레코드 목록 / Parse JSON into field records
+///
+/// 객체 배열(또는 객체 하나)을 받습니다. 문자열은 그대로, 숫자/불리언은 문자열로,
+/// null은 빈 문자열로 바꿉니다.
+pub fn records_from_json(text: &str) -> Result<Vec<FieldRecord>, HwpError> {
+    let value: serde_json::Value = serde_json::from_str(text).map_err(|e| json_error(&e))?;
+    let items = match value {
+        serde_json::Value::Array(items) => items,
+        object @ serde_json::Value::Object(_) => vec![object],
+        _ => return Err(json_error(&"expected an object or an array of objects")),
+    };
+    items
+        .into_iter()
+        .map(|item| match item {
+            serde_json::Value::Object(map) => Ok(map
+                .into_iter()
+                .map(|(key, value)| (key, json_text(value)))
+                .collect()),
+            _ => Err(json_error(&"expected an object in the array")),
+        })
+        .collect()
+}
+
+/// 레코드마다 채운 문서 / Fill a copy of `doc` for each CSV row
+pub fn merge_csv(doc: &Document, csv: &str) -> Result<Vec<Document>, HwpError> {
+    Ok(doc.fill_fields_batch(&records_from_csv(csv)?))
+}
+
+/// 레코드마다 채운 문서 / Fill a copy of `doc` for each JSON object
+pub fn merge_json(doc: &Document, json: &str) -> Result<Vec<Document>, HwpError> {
+    Ok(doc.fill_fields_batch(&records_from_json(json)?))
+}
+
+fn json_text(value: serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(s) => s,
+        other => other.to_string(),
+    }
+}
+
+fn json_error(reason: &dyn std::fmt::Display) -> HwpError {
+    HwpError::data_source("JSON", reason.to_string())
+}
+
+/// CSV 행 목록 (행이 시작하는 줄 번호, 값) / CSV rows with their 1-based start line
+fn parse_csv(text: &str) -> Result<Vec<(usize, Vec<String>)>, HwpError> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut line = 1;
+    let mut row_line = 1;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\n' || (c == '\r' && chars.peek() != Some(&'\n')) {
+            line += 1;
+        }
+        if quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    cell.push('"');
+                }
+                '"' => quoted = false,
+                _ => cell.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if cell.is_empty() => quoted = true,
+            ',' => row.push(std::mem::take(&mut cell)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                row.push(std::mem::take(&mut cell));
+                rows.push((row_line, std::mem::take(&mut row)));
+                row_line = line;
+            }
+            _ => cell.push(c),
+        }
+    }
+    if quoted {
+        return Err(HwpError::data_source(
+            "CSV",
+            format!("line {}: unterminated quoted value", row_line),
+        ));
+    }
+    if !cell.is_empty() || !row.is_empty() {
+        row.push(cell);
+        rows.push((row_line, row));
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_csv_with_quotes_and_blank_lines() {
+        let csv = "\u{feff}성명,주소,비고\r\n홍길동,\"서울시 중구, 세종대로 110\",\"\"\"우선\"\"\"\r\n\r\n김철수,부산,\"두 줄\n메모\"\n";
+        let records = records_from_csv(csv).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["성명"], "홍길동");
+        assert_eq!(records[0]["주소"], "서울시 중구, 세종대로 110");
+        assert_eq!(records[0]["비고"], "\"우선\"");
+        assert_eq!(records[1]["비고"], "두 줄\n메모");
+        assert!(matches!(
+            records_from_csv("a\n\"unterminated"),
+            Err(HwpError::DataSourceError { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_csv_rows_with_wrong_column_count() {
+        let short = records_from_csv("성명,주소\n홍길동,서울\n\"김\n철수\"\n").unwrap_err();
+        assert_eq!(
+            short.to_string(),
+            "Failed to read mail merge CSV data: line 3: expected 2 columns, found 1"
+        );
+        let long = records_from_csv("성명,주소\r\n홍길동,서울,중구\r\n").unwrap_err();
+        assert_eq!(
+            long.to_string(),
+            "Failed to read mail merge CSV data: line 2: expected 2 columns, found 3"
+        );
+    }
+
+    #[test]
+    fn reads_json_objects() {
+        let records =
+            records_from_json(r#"[{"성명":"홍길동","나이":42,"회원":true,"메모":null}]"#).unwrap();
+        assert_eq!(records[0]["성명"], "홍길동");
+        assert_eq!(records[0]["나이"], "42");
+        assert_eq!(records[0]["회원"], "true");
+        assert_eq!(records[0]["메모"], "");
+        assert_eq!(records_from_json(r#"{"a":"1"}"#).unwrap().len(), 1);
+        assert!(matches!(
+            records_from_json("[1, 2]"),
+            Err(HwpError::DataSourceError { .. })
+        ));
+    }
+}
@@ -0,0 +1,544 @@
+/// OLE object extraction module
+///
+/// HWP의 `BinData/BINxxxx.OLE`와 HWPX의 `BinData/*.ole`는 OLE 복합 파일(CFB)입니다.
+/// 이 모듈은 그 안에서 원본 개체(예: 엑셀 시트)의 CLSID/ProgID/원본 스트림과
+/// 미리보기로 저장된 프레젠테이션 캐시(WMF/EMF/비트맵)를 꺼냅니다.
+///
+/// 스펙 문서 매핑: [MS-OLEDS] 2.3.3 CompObjStream, 2.3.4 OLEPresentationStream, 2.3.6 OLENativeStream
+use crate::error::HwpError;
+use cfb::CompoundFile;
+use hwp_model::document::{BinaryStore, ImageFormat};
+use std::io::{Cursor, Read};
+
+const CFB_SIGNATURE: [u8; 8] = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+/// Aldus placeable metafile 키 / Placeable WMF key
+const WMF_PLACEABLE_KEY: u32 = 0x9AC6_CDD7;
+
+/// 클립보드 형식 / Standard clipboard formats
+const CF_METAFILEPICT: u32 = 3;
+const CF_DIB: u32 = 8;
+const CF_ENHMETAFILE: u32 = 14;
+
+/// OLE 개체에서 꺼낸 내용 / Contents of an embedded OLE object
+#[derive(Debug, Clone, Default)]
+pub struct OleContents {
+    /// 루트 스토리지 CLSID (예: "{00020820-0000-0000-C000-000000000046}")
+    /// Root storage CLSID
+    pub clsid: Option<String>,
+    /// `\x01CompObj`의 ProgID (예: "Excel.Sheet.8")
+    /// ProgID from the `\x01CompObj` stream
+    pub prog_id: Option<String>,
+    /// `\x01CompObj`의 사용자 표시 형식 이름 (예: "Microsoft Excel 워크시트")
+    /// User type name from the `\x01CompObj` stream
+    pub user_type: Option<String>,
+    /// 원본 개체 데이터 / Native object data
+    pub native: Option<OleNative>,
+    /// 프레젠테이션 캐시 (미리보기) / Cached presentation (preview)
+    pub preview: Option<OlePreview>,
+}
+
+/// 원본 개체 데이터 / Native data of an embedded object
+#[derive(Debug, Clone)]
+pub struct OleNative {
+    /// 데이터를 읽은 스트림 이름 (스토리지 전체면 빈 문자열)
+    /// Stream the data was read from (empty for the whole storage)
+    pub stream: String,
+    /// 파일로 저장할 때의 확장자 (예: "xlsx", "xls", "pdf", "bin")
+    /// File extension to save the data with
+    pub extension: String,
+    pub data: Vec<u8>,
+}
+
+/// 프레젠테이션 캐시 / Cached presentation of an embedded object
+#[derive(Debug, Clone)]
+pub struct OlePreview {
+    /// Wmf(placeable 헤더 포함), Emf 또는 Bmp(파일 헤더 포함)
+    /// Wmf (with placeable header), Emf or Bmp (with file header)
+    pub format: ImageFormat,
+    /// 크기 (HIMETRIC, 0.01mm) / Size in HIMETRIC units
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+/// BinData 바이트에서 OLE 복합 파일 부분 (앞의 4바이트 크기가 있으면 건너뜀)
+/// Returns the compound file part of a BinData item (skipping a 4-byte size prefix)
+pub fn ole_storage_bytes(data: &[u8]) -> Option<&[u8]> {
+    if data.starts_with(&CFB_SIGNATURE) {
+        Some(data)
+    } else if data.get(4..12) == Some(&CFB_SIGNATURE[..]) {
+        Some(&data[4..])
+    } else {
+        None
+    }
+}
+
+/// OLE 개체 바이너리에서 CLSID, 원본 데이터, 미리보기를 꺼냅니다.
+/// Extracts the CLSID, native data and preview from an OLE object binary.
+pub fn extract_ole(data: &[u8]) -> Result<OleContents, HwpError> {
+    let storage = ole_storage_bytes(data).ok_or_else(|| HwpError::UnexpectedValue {
+        field: "OLE storage signature".to_string(),
+        expected: "D0 CF 11 E0 A1 B1 1A E1".to_string(),
+        found: format!("{:02X?}", &data[..data.len().min(8)]),
+    })?;
+    let mut cfb =
+        CompoundFile::open(Cursor::new(storage)).map_err(|e| HwpError::CfbParse(e.to_string()))?;
+
+    let clsid = *cfb.root_entry().clsid();
+    let clsid = (!clsid.is_nil()).then(|| format!("{{{}}}", clsid).to_uppercase());
+    let streams: Vec<String> = cfb
+        .read_root_storage()
+        .filter(|entry| entry.is_stream())
+        .map(|entry| entry.name().to_string())
+        .collect();
+    let mut read = |name: &str| -> Option<Vec<u8>> {
+        let mut buffer = Vec::new();
+        cfb.open_stream(format!("/{}", name))
+            .ok()?
+            .read_to_end(&mut buffer)
+            .ok()?;
+        Some(buffer)
+    };
+
+    let (user_type, prog_id) = read("\u{1}CompObj")
+        .map(|data| parse_comp_obj(&data))
+        .unwrap_or_default();
+
+    // 미리보기: \x02OlePres000, \x02OlePres001, ... 중 처음 읽을 수 있는 것
+    let mut pres: Vec<&String> = streams
+        .iter()
+        .filter(|name| name.starts_with("\u{2}OlePres"))
+        .collect();
+    pres.sort();
+    let preview = pres
+        .into_iter()
+        .find_map(|name| read(name).and_then(|data| parse_presentation(&data)));
+
+    let native = native_data(&streams, &mut read, prog_id.as_deref(), storage);
+
+    Ok(OleContents {
+        clsid,
+        prog_id,
+        user_type,
+        native,
+        preview,
+    })
+}
+
+/// 문서의 OLE 바이너리 항목을 모두 꺼냅니다 (BinData id, 내용).
+/// Extracts every OLE binary item of a document (BinData id, contents).
+pub fn extract_document_ole(binaries: &BinaryStore) -> Vec<(String, OleContents)> {
+    binaries
+        .items
+        .iter()
+        .filter(|item| ole_storage_bytes(&item.data).is_some())
+        .filter_map(|item| Some((item.id.clone(), extract_ole(&item.data).ok()?)))
+        .collect()
+}
+
+/// 원본 데이터 스트림 선택
+/// - `Package`: OOXML 파일(xlsx/docx/pptx) 그대로
+/// - `\x01Ole10Native`: 패키지 개체 등 OLE1 원본 데이터
+/// - `CONTENTS`/`Contents`: PDF 등 단일 스트림 원본
+/// - `Workbook`/`WordDocument`/... : 스토리지 자체가 xls/doc/ppt/hwp 파일
+fn native_data(
+    streams: &[String],
+    read: &mut dyn FnMut(&str) -> Option<Vec<u8>>,
+    prog_id: Option<&str>,
+    storage: &[u8],
+) -> Option<OleNative> {
+    let has = |name: &str| streams.iter().any(|s| s.eq_ignore_ascii_case(name));
+    let native = |stream: &str, data: Vec<u8>, extension: &str| OleNative {
+        stream: stream.to_string(),
+        extension: extension.to_string(),
+        data,
+    };
+
+    if has("Package") {
+        let data = read("Package")?;
+        let extension = match prog_id.unwrap_or("") {
+            p if p.starts_with("Excel.") => "xlsx",
+            p if p.starts_with("Word.") => "docx",
+            p if p.starts_with("PowerPoint.") => "pptx",
+            _ => sniff_extension(&data),
+        };
+        return Some(native("Package", data, extension));
+    }
+    if has("\u{1}Ole10Native") {
+        let data = read("\u{1}Ole10Native")?;
+        let size = u32_at(&data, 0).unwrap_or(0) as usize;
+        let body = data.get(4..4 + size).unwrap_or(data.get(4..)?);
+        // 패키지 개체: 레이블/경로 뒤에 원본 파일 (이름의 확장자를 씀)
+        if let Some((name, contents)) = parse_packager(body) {
+            let extension = name
+                .rsplit_once('.')
+                .map(|(_, ext)| ext.to_lowercase())
+                .unwrap_or_else(|| sniff_extension(contents).to_string());
+            return Some(native("\u{1}Ole10Native", contents.to_vec(), &extension));
+        }
+        return Some(native(
+            "\u{1}Ole10Native",
+            body.to_vec(),
+            sniff_extension(body),
+        ));
+    }
+    for name in ["CONTENTS", "Contents"] {
+        if has(name) {
+            let data = read(name)?;
+            let extension = sniff_extension(&data);
+            return Some(native(name, data, extension));
+        }
+    }
+    let extension = if has("Workbook") || has("Book") {
+        "xls"
+    } else if has("WordDocument") {
+        "doc"
+    } else if has("PowerPoint Document") {
+        "ppt"
+    } else if has("FileHeader") {
+        "hwp"
+    } else {
+        return None;
+    };
+    Some(native("", storage.to_vec(), extension))
+}
+
+/// 매직 바이트로 확장자 추정
+fn sniff_extension(data: &[u8]) -> &'static str {
+    if data.starts_with(b"%PDF") {
+        "pdf"
+    } else if data.starts_with(b"PK\x03\x04") {
+        "zip"
+    } else if data.starts_with(&CFB_SIGNATURE) {
+        "cfb"
+    } else if data.starts_with(b"\x89PNG") {
+        "png"
+    } else if data.starts_with(b"\xFF\xD8") {
+        "jpg"
+    } else {
+        "bin"
+    }
+}
+
+/// OLE1 패키지 개체(Packager) 본문 → (원본 파일 이름, 데이터)
+/// u16 서명(2), 레이블\0, 원본 경로\0, u32, u32, u32 임시 경로 길이, 임시 경로, u32 크기, 데이터
+fn parse_packager(body: &[u8]) -> Option<(String, &[u8])> {
+    if body.get(..2)? != [2, 0] {
+        return None;
+    }
+    let mut pos = 2;
+    let cstring = |pos: &mut usize| -> Option<String> {
+        let end = *pos + body.get(*pos..)?.iter().position(|&b| b == 0)?;
+        let text = String::from_utf8_lossy(&body[*pos..end]).to_string();
+        *pos = end + 1;
+        Some(text)
+    };
+    let label = cstring(&mut pos)?;
+    let _source = cstring(&mut pos)?;
+    pos += 4;
+    let temp_len = u32_at(body, pos)? as usize;
+    pos += 4 + temp_len;
+    let size = u32_at(body, pos)? as usize;
+    pos += 4;
+    let data = body.get(pos..pos + size)?;
+    Some((label, data))
+}
+
+/// `\x01CompObj` → (사용자 형식 이름, ProgID)
+/// 헤더 28바이트, AnsiUserType, AnsiClipboardFormat, Reserved1(= ProgID)
+fn parse_comp_obj(data: &[u8]) -> (Option<String>, Option<String>) {
+    let mut pos = 28;
+    let user_type = ansi_string(data, &mut pos);
+    // 클립보드 형식: 0, 0xFFFFFFFF/0xFFFFFFFE + u32, 또는 길이 + 문자열
+    match u32_at(data, pos) {
+        Some(0xFFFF_FFFF | 0xFFFF_FFFE) => pos += 8,
+        Some(_) => {
+            ansi_string(data, &mut pos);
+        }
+        None => return (user_type, None),
+    }
+    let prog_id = ansi_string(data, &mut pos);
+    (user_type, prog_id)
+}
+
+/// 길이(u32, 널 포함) + ANSI 문자열
+fn ansi_string(data: &[u8], pos: &mut usize) -> Option<String> {
+    let len = u32_at(data, *pos)? as usize;
+    *pos += 4;
+    let bytes = data.get(*pos..*pos + len)?;
+    *pos += len;
+    let bytes = bytes.split(|&b| b == 0).next().unwrap_or(&[]);
+    if bytes.is_empty() {
+        return None;
+    }
+    let (text, _, _) = encoding_rs::EUC_KR.decode(bytes);
+    Some(text.into_owned())
+}
+
+/// `\x02OlePresXXX` → 미리보기 (WMF/EMF/DIB만)
+fn parse_presentation(data: &[u8]) -> Option<OlePreview> {
+    let mut pos = 0;
+    let format = match u32_at(data, 0)? {
+        0xFFFF_FFFF | 0xFFFF_FFFE => {
+            pos += 8;
+            u32_at(data, 4)?
+        }
+        _ => return None,
+    };
+    // TargetDeviceSize는 자신(4바이트)을 포함
+    let target_device = u32_at(data, pos)? as usize;
+    pos += target_device.max(4);
+    // Aspect, Lindex, Advf, Reserved1
+    pos += 16;
+    let width = u32_at(data, pos)?;
+    let height = u32_at(data, pos + 4)?;
+    let size = u32_at(data, pos + 8)? as usize;
+    pos += 12;
+    let bytes = data.get(pos..pos + size)?;
+
+    let (format, data) = match format {
+        CF_ENHMETAFILE => (ImageFormat::Emf, bytes.to_vec()),
+        CF_METAFILEPICT if bytes.get(40..44) == Some(b" EMF") => (ImageFormat::Emf, bytes.to_vec()),
+        CF_METAFILEPICT => (ImageFormat::Wmf, placeable_wmf(bytes, width, height)),
+        CF_DIB => (ImageFormat::Bmp, dib_to_bmp(bytes)?),
+        _ => return None,
+    };
+    Some(OlePreview {
+        format,
+        width,
+        height,
+        data,
+    })
+}
+
+/// WMF에 placeable 헤더가 없으면 HIMETRIC 크기로 만들어 붙입니다 (1인치 = 2540 단위).
+fn placeable_wmf(data: &[u8], width: u32, height: u32) -> Vec<u8> {
+    if u32_at(data, 0) == Some(WMF_PLACEABLE_KEY) {
+        return data.to_vec();
+    }
+    let mut words: Vec<u16> = vec![
+        (WMF_PLACEABLE_KEY & 0xFFFF) as u16,
+        (WMF_PLACEABLE_KEY >> 16) as u16,
+        0,
+        0,
+        0,
+        width.min(i16::MAX as u32) as u16,
+        height.min(i16::MAX as u32) as u16,
+        2540,
+        0,
+        0,
+    ];
+    // 배율을 유지하면서 16비트 범위에 맞춤
+    let scale = (width.max(height) as f64 / i16::MAX as f64).max(1.0);
+    if scale > 1.0 {
+        words[5] = (width as f64 / scale) as u16;
+        words[6] = (height as f64 / scale) as u16;
+        words[7] = (2540.0 / scale).max(1.0) as u16;
+    }
+    let checksum = words.iter().fold(0u16, |acc, w| acc ^ w);
+    let mut out: Vec<u8> = words.iter().flat_map(|w| w.to_le_bytes()).collect();
+    out.extend_from_slice(&checksum.to_le_bytes());
+    out.extend_from_slice(data);
+    out
+}
+
+/// DIB(BITMAPINFOHEADER + 색상표 + 픽셀) → BMP 파일
+pub(crate) fn dib_to_bmp(dib: &[u8]) -> Option<Vec<u8>> {
+    let header_size = u32_at(dib, 0)? as usize;
+    if header_size < 12 || dib.len() < header_size {
+        return None;
+    }
+    let (bits, compression, colors_used) = if header_size == 12 {
+        (u16::from_le_bytes([dib[10], dib[11]]) as usize, 0, 0)
+    } else {
+        (
+            u16::from_le_bytes([*dib.get(14)?, *dib.get(15)?]) as usize,
+            u32_at(dib, 16)?,
+            u32_at(dib, 32)? as usize,
+        )
+    };
+    let entry = if header_size == 12 { 3 } else { 4 };
+    let palette = if colors_used > 0 {
+        colors_used
+    } else if bits <= 8 {
+        1 << bits
+    } else {
+        0
+    };
+    // BI_BITFIELDS(3)는 BITMAPINFOHEADER 뒤에 마스크 3개
+    let masks = if compression == 3 && header_size == 40 {
+        12
+    } else {
+        0
+    };
+    let offset = 14 + header_size + masks + palette * entry;
+    let mut bmp = Vec::with_capacity(14 + dib.len());
+    bmp.extend_from_slice(b"BM");
+    bmp.extend_from_slice(&((14 + dib.len()) as u32).to_le_bytes());
+    bmp.extend_from_slice(&[0; 4]);
+    bmp.extend_from_slice(&(offset as u32).to_le_bytes());
+    bmp.extend_from_slice(dib);
+    Some(bmp)
+}
+
+fn u32_at(data: &[u8], pos: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(data.get(pos..pos + 4)?.try_into().ok()?))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::Write;
+
+    const EXCEL_CLSID: &str = "00020820-0000-0000-C000-000000000046";
+
+    fn comp_obj(user_type: &str, prog_id: &str) -> Vec<u8> {
+        let ansi = |data: &mut Vec<u8>, text: &str| {
+            data.extend_from_slice(&(text.len() as u32 + 1).to_le_bytes());
+            data.extend_from_slice(text.as_bytes());
+            data.push(0);
+        };
+        let mut data = vec![0u8; 28];
+        ansi(&mut data, user_type);
+        // 클립보드 형식 없음
+        data.extend_from_slice(&0u32.to_le_bytes());
+        ansi(&mut data, prog_id);
+        data
+    }
+
+    fn presentation(format: u32, width: u32, height: u32, bytes: &[u8]) -> Vec<u8> {
+        let mut data = Vec::new();
+        for value in [0xFFFF_FFFF, format, 4, 1, 0xFFFF_FFFF, 2, 0, width, height] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+        data.extend_from_slice(bytes);
+        data
+    }
+
+    fn storage(streams: &[(&str, Vec<u8>)], clsid: Option<&str>) -> Vec<u8> {
+        let mut cfb = CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        if let Some(clsid) = clsid {
+            cfb.set_storage_clsid("/", clsid.parse().unwrap()).unwrap();
+        }
+        for (name, data) in streams {
+            cfb.create_stream(format!("/{}", name))
+                .unwrap()
+                .write_all(data)
+                .unwrap();
+        }
+        cfb.flush().unwrap();
+        cfb.into_inner().into_inner()
+    }
+
+    /// 엑셀 시트 개체 (CLSID, CompObj, Workbook, 2x1 비트맵 미리보기)
+    pub(crate) fn excel_sheet_storage() -> Vec<u8> {
+        let mut dib = vec![0u8; 40];
+        dib[0] = 40;
+        dib[4] = 2;
+        dib[8] = 1;
+        dib[12] = 1;
+        dib[14] = 24;
+        dib.extend_from_slice(&[0xFF, 0, 0, 0, 0xFF, 0, 0, 0]);
+        storage(
+            &[
+                (
+                    "\u{1}CompObj",
+                    comp_obj("Microsoft Excel Worksheet", "Excel.Sheet.8"),
+                ),
+                ("Workbook", vec![9; 16]),
+                ("\u{2}OlePres000", presentation(CF_DIB, 2000, 1000, &dib)),
+            ],
+            Some(EXCEL_CLSID),
+        )
+    }
+
+    #[test]
+    fn test_extract_excel_sheet_with_bitmap_preview() {
+        let data = excel_sheet_storage();
+        // BinData의 4바이트 크기 접두어
+        let mut prefixed = (data.len() as u32).to_le_bytes().to_vec();
+        prefixed.extend_from_slice(&data);
+
+        let ole = extract_ole(&prefixed).unwrap();
+        assert_eq!(
+            ole.clsid.as_deref(),
+            Some("{00020820-0000-0000-C000-000000000046}")
+        );
+        assert_eq!(ole.prog_id.as_deref(), Some("Excel.Sheet.8"));
+        assert_eq!(ole.user_type.as_deref(), Some("Microsoft Excel Worksheet"));
+
+        let native = ole.native.unwrap();
+        assert_eq!(
+            (native.stream.as_str(), native.extension.as_str()),
+            ("", "xls")
+        );
+        assert_eq!(native.data, data);
+
+        let preview = ole.preview.unwrap();
+        assert_eq!(preview.format, ImageFormat::Bmp);
+        assert_eq!((preview.width, preview.height), (2000, 1000));
+        assert_eq!(&preview.data[..2], b"BM");
+        assert_eq!(u32_at(&preview.data, 10), Some(54));
+        let (w, h, rgb) = crate::viewer::doc_utils::decode_bmp(&preview.data).unwrap();
+        assert_eq!((w, h), (2, 1));
+        assert_eq!(&rgb[..3], &[0, 0, 0xFF]);
+    }
+
+    #[test]
+    fn test_extract_package_and_metafile_preview() {
+        let wmf = [1u8, 0, 9, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let data = storage(
+            &[
+                (
+                    "\u{1}CompObj",
+                    comp_obj("Microsoft Excel Worksheet", "Excel.Sheet.12"),
+                ),
+                ("Package", b"PK\x03\x04xlsx".to_vec()),
+                (
+                    "\u{2}OlePres000",
+                    presentation(CF_METAFILEPICT, 500, 300, &wmf),
+                ),
+            ],
+            None,
+        );
+        let ole = extract_ole(&data).unwrap();
+        assert_eq!(ole.clsid, None);
+        let native = ole.native.unwrap();
+        assert_eq!(native.stream, "Package");
+        assert_eq!(native.extension, "xlsx");
+
+        let preview = ole.preview.unwrap();
+        assert_eq!(preview.format, ImageFormat::Wmf);
+        assert_eq!(u32_at(&preview.data, 0), Some(WMF_PLACEABLE_KEY));
+        assert_eq!(&preview.data[22..], &wmf);
+        let checksum = (0..10).fold(0u16, |acc, i| {
+            acc ^ u16::from_le_bytes([preview.data[i * 2], preview.data[i * 2 + 1]])
+        });
+        assert_eq!(
+            u16::from_le_bytes([preview.data[20], preview.data[21]]),
+            checksum
+        );
+    }
+
+    #[test]
+    fn test_extract_packager_object() {
+        let mut body = vec![2u8, 0];
+        body.extend_from_slice(b"report.pdf\0C:\\report.pdf\0");
+        body.extend_from_slice(&[0, 0, 3, 0]);
+        body.extend_from_slice(&4u32.to_le_bytes());
+        body.extend_from_slice(b"tmp\0");
+        body.extend_from_slice(&5u32.to_le_bytes());
+        body.extend_from_slice(b"%PDF-");
+        let mut stream = (body.len() as u32).to_le_bytes().to_vec();
+        stream.extend_from_slice(&body);
+
+        let ole = extract_ole(&storage(&[("\u{1}Ole10Native", stream)], None)).unwrap();
+        let native = ole.native.unwrap();
+        assert_eq!(native.extension, "pdf");
+        assert_eq!(native.data, b"%PDF-");
+        assert!(ole.preview.is_none());
+
+        assert!(extract_ole(b"not an ole object").is_err());
+    }
+}
@@ -175,6 +175,20 @@ impl RecordHeader {
             header_len,
         ))
     }
+
+    /// 레코드 헤더 직렬화. 원본에서 확장 크기를 썼다면 크기가 작아도 확장 크기로 기록한다.
+    pub fn to_bytes(self) -> Vec<u8> {
+        let base = (self.tag_id as u32 & 0x3FF) | ((self.level as u32 & 0x3FF) << 10);
+        if self.has_extended_size || self.size >= RECORD_HEADER_EXTENDED_SIZE {
+            let mut bytes = (base | (RECORD_HEADER_EXTENDED_SIZE << 20))
+                .to_le_bytes()
+                .to_vec();
+            bytes.extend_from_slice(&self.size.to_le_bytes());
+            bytes
+        } else {
+            (base | (self.size << 20)).to_le_bytes().to_vec()
+        }
+    }
 }
 
 // ========== HWPUNIT16 → mm 변환 트레이트 ==========
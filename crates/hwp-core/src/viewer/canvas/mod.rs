@@ -1,14 +1,640 @@
-//! Canvas converter for HWP documents
-//! HWP 문서를 Canvas로 변환하는 모듈
+//! Canvas display list for HWP documents
+//! HWP 문서를 Canvas 그리기 명령 목록(display list)으로 변환하는 모듈
 //!
-//! This module provides functionality to convert HWP documents to Canvas format.
-//! Canvas output can be used for rendering in web browsers or exporting as images.
-//! 이 모듈은 HWP 문서를 Canvas 형식으로 변환하는 기능을 제공합니다.
-//! Canvas 출력은 웹 브라우저에서 렌더링하거나 이미지로 내보내는 데 사용할 수 있습니다.
+//! `hwp_model::Document`를 doc_html 레이아웃 모드와 같은 페이지 배치로 계산한 뒤,
+//! 페이지마다 `fillRect` / `strokePath` / `drawText` / `drawImage` / `clip` / `transform`
+//! 명령 목록을 만듭니다. 결과는 serde로 직렬화할 수 있으며, 웹/React Native 뷰어는
+//! 2D canvas 컨텍스트에서 명령을 순서대로 재생하기만 하면 됩니다.
 //!
-//! # Status / 상태
-//! This module is planned for future implementation.
-//! 이 모듈은 향후 구현 예정입니다.
+//! Computes the same page layout as doc_html layout mode and emits, per page, a list of
+//! canvas drawing commands. The list is serializable so web and React Native viewers can
+//! replay it on a 2D canvas context.
+//!
+//! # 좌표 / Coordinates
+//! 모든 좌표와 크기(폰트 크기 포함)는 페이지 좌상단 기준 mm 단위입니다.
+//! 재생할 때 `ctx.scale(px_per_mm, px_per_mm)`을 먼저 적용하면 그대로 사용할 수 있습니다.
+//! `drawText`의 `y`는 글자 기준선(alphabetic baseline)입니다.
+//!
+//! All coordinates and sizes (font sizes included) are in mm from the page's top-left corner.
+//! `drawText.y` is the alphabetic baseline.
+mod shape;
+pub(crate) mod table;
+mod text;
+
+use crate::viewer::doc_html::styles::{hwpunit_to_mm, round_mm};
+use crate::viewer::doc_html::{layout_page, layout_pagination};
+use crate::viewer::doc_utils;
+use hwp_model::control::Control;
+use hwp_model::document::{BinaryStore, Document};
+use hwp_model::paragraph::{Paragraph, RunContent};
+use hwp_model::resources::{FillBrush, Resources};
+use hwp_model::section::{FillArea, PageBorderFill, PageBorderRef, PageDef};
+use hwp_model::shape::ShapeObject;
+use serde::{Deserialize, Serialize};
+
+/// 한 페이지의 그리기 명령 목록 / Drawing commands of one page
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CanvasPage {
+    /// 페이지 너비 (mm) / Page width (mm)
+    pub width: f64,
+    /// 페이지 높이 (mm) / Page height (mm)
+    pub height: f64,
+    /// 재생 순서대로의 명령 / Commands in replay order
+    pub commands: Vec<DrawCommand>,
+}
+
+/// Canvas 그리기 명령 / Canvas drawing command
+///
+/// JSON으로는 `{"op": "fillRect", ...}` 형태로 직렬화됩니다.
+/// Serialized as `{"op": "fillRect", ...}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "camelCase")]
+pub enum DrawCommand {
+    /// 상태 저장 (`ctx.save()`) / Save state
+    Save,
+    /// 상태 복원 (`ctx.restore()`) / Restore state
+    Restore,
+    /// 변환 행렬 곱하기 (`ctx.transform(a, b, c, d, e, f)`) / Multiply the transform
+    Transform {
+        a: f64,
+        b: f64,
+        c: f64,
+        d: f64,
+        e: f64,
+        f: f64,
+    },
+    /// 사각형 클리핑 (`ctx.rect(...)` + `ctx.clip()`) / Clip to a rectangle
+    Clip {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    /// 사각형 채우기 / Fill a rectangle
+    FillRect {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        color: String,
+    },
+    /// 경로 채우기 / Fill a path
+    FillPath { path: Vec<PathOp>, color: String },
+    /// 경로 선 그리기 / Stroke a path
+    StrokePath {
+        path: Vec<PathOp>,
+        color: String,
+        /// 선 굵기 (mm) / Line width (mm)
+        width: f64,
+        /// `ctx.setLineDash` 값 (mm, 비어 있으면 실선) / Dash pattern (mm, empty = solid)
+        dash: Vec<f64>,
+    },
+    /// 글자 그리기 (`y`는 기준선) / Draw text (`y` is the baseline)
+    DrawText {
+        x: f64,
+        y: f64,
+        text: String,
+        font: CanvasFont,
+        color: String,
+    },
+    /// 이미지 그리기 / Draw an image
+    DrawImage {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        /// `Document.binaries`의 BinaryItem id / BinaryItem id in `Document.binaries`
+        binary_id: String,
+        /// 이미지 MIME 타입 / Image MIME type
+        mime: String,
+    },
+}
+
+/// 경로 구성 요소 / Path segment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum PathOp {
+    MoveTo {
+        x: f64,
+        y: f64,
+    },
+    LineTo {
+        x: f64,
+        y: f64,
+    },
+    BezierTo {
+        cp1x: f64,
+        cp1y: f64,
+        cp2x: f64,
+        cp2y: f64,
+        x: f64,
+        y: f64,
+    },
+    Close,
+}
+
+/// 글꼴 정보 / Font
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CanvasFont {
+    /// 글꼴 이름 / Font family
+    pub family: String,
+    /// 글자 크기 (mm) / Font size (mm)
+    pub size: f64,
+    pub bold: bool,
+    pub italic: bool,
+}
+
+/// Document를 페이지별 Canvas 명령 목록으로 변환
+/// Convert a Document into per-page canvas display lists
+pub fn doc_to_canvas(doc: &Document) -> Vec<CanvasPage> {
+    let default_page_def = default_page_def();
+    let mut painter = Painter::new(&doc.resources, &doc.binaries);
+    let mut pages = Vec::new();
+    // 쪽 번호 위치 계산용 페이지별 아래쪽 여백 (mm)
+    let mut bottom_margins = Vec::new();
+    let mut has_page_number = false;
+
+    for section in &doc.sections {
+        let page_def = if section.definition.page.width > 0 {
+            &section.definition.page
+        } else {
+            &default_page_def
+        };
+        let page_left = layout_page::content_left_abs_mm(page_def);
+        let page_top = layout_page::content_top_abs_mm(page_def);
+        // 쪽 테두리/배경은 양쪽(첫 항목) 설정을 모든 페이지에 적용
+        let page_border = section.definition.page_border_fills.first();
+
+        let mut pag_ctx = layout_pagination::PaginationContext {
+            prev_vertical_mm: None,
+            current_max_vertical_mm: 0.0,
+            content_height_mm: layout_pagination::content_height_mm(page_def),
+            page_vertical_offset_mm: 0.0,
+        };
+        let bottom_margin = hwpunit_to_mm(page_def.margin.bottom);
+        let mut page = PageState::default();
+        let section_start = pages.len();
+
+        for para in &section.paragraphs {
+            let break_result = layout_pagination::check_page_break(para, &pag_ctx);
+            if break_result.should_break && page.has_blocks {
+                pages.push(painter.finish_page(page_def, page_border, &page, &pag_ctx));
+                bottom_margins.push(bottom_margin);
+                page.notes.clear();
+                page.has_blocks = false;
+
+                if let Some(vp) = layout_pagination::last_vertical_pos_mm(para) {
+                    if break_result.reason
+                        == Some(layout_pagination::PageBreakReason::VerticalReset)
+                    {
+                        pag_ctx.page_vertical_offset_mm = vp;
+                    }
+                }
+                pag_ctx.current_max_vertical_mm = 0.0;
+            }
+
+            if let Some(vp) = layout_pagination::last_vertical_pos_mm(para) {
+                let rel_vp = vp - pag_ctx.page_vertical_offset_mm;
+                pag_ctx.prev_vertical_mm = Some(rel_vp);
+                if rel_vp > pag_ctx.current_max_vertical_mm {
+                    pag_ctx.current_max_vertical_mm = rel_vp;
+                }
+            }
+
+            // 레이아웃 HTML의 페이지 블록(hls/개체)이 생기는 문단인지 — 페이지 나누기 판단에 사용
+            page.has_blocks |= para
+                .line_segments
+                .iter()
+                .any(|seg| !seg.decode_flags().is_empty_segment)
+                || para.runs.iter().any(|run| {
+                    run.contents
+                        .iter()
+                        .any(|content| matches!(content, RunContent::Object(_)))
+                });
+
+            // 텍스트 먼저, 개체(표/도형)는 그 위에 (레이아웃 HTML과 같은 순서)
+            painter.paragraph_text(para, page_left, page_top);
+
+            let mut inline_x = page_left;
+            for run in &para.runs {
+                for content in &run.contents {
+                    match content {
+                        RunContent::Object(ShapeObject::Table(table)) => {
+                            // vert_offset=0인 표: 이전 콘텐츠 아래에 배치
+                            let table_top = if table.common.position.vert_offset == 0 {
+                                page_top + pag_ctx.current_max_vertical_mm
+                            } else {
+                                page_top
+                            };
+                            pag_ctx.current_max_vertical_mm +=
+                                round_mm(hwpunit_to_mm(table.common.size.height));
+                            painter.table(
+                                table,
+                                page_left + hwpunit_to_mm(table.common.position.horz_offset),
+                                table_top + hwpunit_to_mm(table.common.position.vert_offset),
+                            );
+                        }
+                        RunContent::Object(object) => {
+                            // 글자처럼 취급하는 개체는 문단 첫 줄에, 나머지는 페이지 절대좌표에 배치
+                            let common = doc_utils::shape_common(object);
+                            if common.position.treat_as_char {
+                                let y = page_top + first_line_top_mm(para);
+                                painter.shape(object, inline_x, y);
+                                inline_x += hwpunit_to_mm(common.size.width);
+                            } else {
+                                painter.shape(
+                                    object,
+                                    hwpunit_to_mm(common.position.horz_offset),
+                                    hwpunit_to_mm(common.position.vert_offset),
+                                );
+                            }
+                        }
+                        RunContent::Control(control) => match control {
+                            Control::Header(hf) => page.header = Some(&hf.content.paragraphs),
+                            Control::Footer(hf) => page.footer = Some(&hf.content.paragraphs),
+                            Control::FootNote(note) => {
+                                page.footnote_counter += 1;
+                                let id = note.number.unwrap_or(page.footnote_counter);
+                                page.notes.push((id, &note.content.paragraphs));
+                            }
+                            Control::EndNote(note) => {
+                                page.endnote_counter += 1;
+                                let id = note.number.unwrap_or(page.endnote_counter);
+                                page.notes.push((id, &note.content.paragraphs));
+                            }
+                            Control::PageNumCtrl(_) => has_page_number = true,
+                            _ => {}
+                        },
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        // 마지막 페이지 (비어 있어도 구역당 최소 1페이지)
+        if page.has_blocks || pages.len() == section_start {
+            pages.push(painter.finish_page(page_def, page_border, &page, &pag_ctx));
+            bottom_margins.push(bottom_margin);
+        }
+    }
+
+    if has_page_number {
+        for (index, (page, bottom)) in pages.iter_mut().zip(bottom_margins).enumerate() {
+            painter.page_number(page, index + 1, bottom);
+        }
+    }
+    pages
+}
+
+/// A4 기본 PageDef (PageDef가 비어 있는 경우 fallback, 레이아웃 HTML과 동일)
+fn default_page_def() -> PageDef {
+    PageDef {
+        width: 59528,  // 210mm
+        height: 84188, // 297mm
+        margin: hwp_model::section::PageMargin {
+            left: 8504, // 30mm
+            right: 8504,
+            top: 5669,    // 20mm
+            bottom: 4252, // 15mm
+            header: 4252, // 15mm
+            footer: 4252,
+            gutter: 0,
+        },
+        ..Default::default()
+    }
+}
+
+/// 페이지 크기 (mm, 가로 방향 보정) / Page size in mm (landscape-aware)
+fn page_size_mm(page_def: &PageDef) -> (f64, f64) {
+    let width = round_mm(hwpunit_to_mm(page_def.width));
+    let height = round_mm(hwpunit_to_mm(page_def.height));
+    match page_def.landscape {
+        hwp_model::types::Landscape::Landscape => (height, width),
+        _ => (width, height),
+    }
+}
+
+/// 문단 첫 줄의 top (mm) / Top of the paragraph's first line (mm)
+fn first_line_top_mm(para: &Paragraph) -> f64 {
+    para.line_segments
+        .first()
+        .map(|seg| hwpunit_to_mm(seg.vertical_pos))
+        .unwrap_or(0.0)
+}
+
+/// 문단 목록의 높이 (mm, 마지막 줄 아래까지) / Height of a paragraph list (mm)
+fn paragraphs_height_mm(paragraphs: &[Paragraph]) -> f64 {
+    paragraphs
+        .iter()
+        .flat_map(|para| &para.line_segments)
+        .map(|seg| hwpunit_to_mm(seg.vertical_pos + seg.line_height))
+        .fold(0.0, f64::max)
+}
+
+/// 0xRRGGBB → "#RRGGBB"
+fn css_color(color: u32) -> String {
+    format!(
+        "#{:02X}{:02X}{:02X}",
+        (color >> 16) & 0xFF,
+        (color >> 8) & 0xFF,
+        color & 0xFF
+    )
+}
+
+/// 단색 채우기 색 (흰색은 채우지 않음, 레이아웃 HTML과 동일)
+/// Solid fill color (white is treated as no fill, like layout HTML)
+fn solid_fill_color(fill: &FillBrush) -> Option<u32> {
+    match fill {
+        FillBrush::WinBrush { face_color, .. } => face_color.filter(|&c| c != 0xFFFFFF),
+        FillBrush::Combined {
+            win_brush: Some(brush),
+            ..
+        } => solid_fill_color(brush),
+        _ => None,
+    }
+}
+
+/// 사각형 경로 / Rectangle path
+fn rect_path(x: f64, y: f64, width: f64, height: f64) -> Vec<PathOp> {
+    vec![
+        PathOp::MoveTo { x, y },
+        PathOp::LineTo { x: x + width, y },
+        PathOp::LineTo {
+            x: x + width,
+            y: y + height,
+        },
+        PathOp::LineTo { x, y: y + height },
+        PathOp::Close,
+    ]
+}
+
+/// 현재 페이지에 걸쳐 유지되는 상태 (머리말/꼬리말, 각주)
+#[derive(Default)]
+struct PageState<'a> {
+    header: Option<&'a [Paragraph]>,
+    footer: Option<&'a [Paragraph]>,
+    notes: Vec<(u16, &'a [Paragraph])>,
+    footnote_counter: u16,
+    endnote_counter: u16,
+    /// 마지막 페이지 나누기 이후 그릴 내용이 있었는지
+    has_blocks: bool,
+}
+
+/// 명령 목록을 쌓는 그리기 컨텍스트
+struct Painter<'a> {
+    resources: &'a Resources,
+    binaries: &'a BinaryStore,
+    commands: Vec<DrawCommand>,
+}
+
+impl<'a> Painter<'a> {
+    fn new(resources: &'a Resources, binaries: &'a BinaryStore) -> Self {
+        Self {
+            resources,
+            binaries,
+            commands: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, command: DrawCommand) {
+        self.commands.push(command);
+    }
+
+    fn fill_rect(&mut self, x: f64, y: f64, width: f64, height: f64, color: u32) {
+        self.push(DrawCommand::FillRect {
+            x: round_mm(x),
+            y: round_mm(y),
+            width: round_mm(width),
+            height: round_mm(height),
+            color: css_color(color),
+        });
+    }
+
+    fn stroke_line(&mut self, from: (f64, f64), to: (f64, f64), color: u32, width: f64) {
+        self.push(DrawCommand::StrokePath {
+            path: vec![
+                PathOp::MoveTo {
+                    x: round_mm(from.0),
+                    y: round_mm(from.1),
+                },
+                PathOp::LineTo {
+                    x: round_mm(to.0),
+                    y: round_mm(to.1),
+                },
+            ],
+            color: css_color(color),
+            width,
+            dash: Vec::new(),
+        });
+    }
+
+    fn clip(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        self.push(DrawCommand::Clip {
+            x: round_mm(x),
+            y: round_mm(y),
+            width: round_mm(width),
+            height: round_mm(height),
+        });
+    }
+
+    /// 본문 명령에 머리말/꼬리말/각주/쪽 테두리를 더해 페이지를 완성
+    fn finish_page(
+        &mut self,
+        page_def: &PageDef,
+        page_border: Option<&PageBorderFill>,
+        page: &PageState,
+        pag_ctx: &layout_pagination::PaginationContext,
+    ) -> CanvasPage {
+        let (width, height) = page_size_mm(page_def);
+        let left = layout_page::content_left_abs_mm(page_def);
+
+        if let Some(header) = page.header {
+            self.paragraphs(header, left, round_mm(hwpunit_to_mm(page_def.margin.top)));
+        }
+        if let Some(footer) = page.footer {
+            let footer_top = height
+                - hwpunit_to_mm(page_def.margin.bottom)
+                - hwpunit_to_mm(page_def.margin.footer);
+            self.paragraphs(footer, left, round_mm(footer_top));
+        }
+        if !page.notes.is_empty() {
+            // 각주 구분선 (50mm) 뒤에 "N)" 번호와 각주 문단
+            let mut y = layout_page::content_top_abs_mm(page_def) + pag_ctx.current_max_vertical_mm;
+            self.stroke_line((left, y), (left + 50.0, y), 0, 0.12);
+            y += 1.0;
+            for (id, paragraphs) in &page.notes {
+                let number_cs = paragraphs
+                    .first()
+                    .and_then(|para| para.runs.first())
+                    .map(|run| run.char_shape_id);
+                self.plain_text(&format!("{})", id), left, y, number_cs);
+                self.paragraphs(paragraphs, left + 2.93, y);
+                y += paragraphs_height_mm(paragraphs).max(3.53);
+            }
+        }
+        if let Some(border) = page_border {
+            self.page_border(page_def, border, width, height);
+        }
+
+        CanvasPage {
+            width,
+            height,
+            commands: std::mem::take(&mut self.commands),
+        }
+    }
+
+    /// 쪽 테두리/배경 — 배경은 페이지 맨 아래, 테두리는 맨 위에 그림
+    fn page_border(
+        &mut self,
+        page_def: &PageDef,
+        border: &PageBorderFill,
+        width: f64,
+        height: f64,
+    ) {
+        let resources = self.resources;
+        let Some(bf) = (border.border_fill_id as usize)
+            .checked_sub(1)
+            .and_then(|index| resources.border_fills.get(index))
+        else {
+            return;
+        };
+        let margin = &page_def.margin;
+        let offset = &border.offset;
+        let (left, top, right, bottom) = match border.text_border {
+            PageBorderRef::Paper => (
+                hwpunit_to_mm(offset.left),
+                hwpunit_to_mm(offset.top),
+                width - hwpunit_to_mm(offset.right),
+                height - hwpunit_to_mm(offset.bottom),
+            ),
+            PageBorderRef::Text => {
+                // 본문 영역 기준 (머리말/꼬리말 포함 여부 반영) 바깥으로 간격만큼
+                let top = hwpunit_to_mm(margin.top)
+                    + if border.header_inside {
+                        0.0
+                    } else {
+                        hwpunit_to_mm(margin.header)
+                    };
+                let bottom = height
+                    - hwpunit_to_mm(margin.bottom)
+                    - if border.footer_inside {
+                        0.0
+                    } else {
+                        hwpunit_to_mm(margin.footer)
+                    };
+                (
+                    layout_page::content_left_abs_mm(page_def) - hwpunit_to_mm(offset.left),
+                    top - hwpunit_to_mm(offset.top),
+                    width - hwpunit_to_mm(margin.right) + hwpunit_to_mm(offset.right),
+                    bottom + hwpunit_to_mm(offset.bottom),
+                )
+            }
+        };
+
+        if let Some(color) = bf.fill.as_ref().and_then(solid_fill_color) {
+            let (x, y, w, h) = match border.fill_area {
+                FillArea::Paper => (0.0, 0.0, width, height),
+                _ => (left, top, right - left, bottom - top),
+            };
+            self.fill_rect(x, y, w, h, color);
+            if let Some(fill) = self.commands.pop() {
+                self.commands.insert(0, fill);
+            }
+        }
+
+        let edges = [
+            (&bf.top_border, (left, top), (right, top)),
+            (&bf.bottom_border, (left, bottom), (right, bottom)),
+            (&bf.left_border, (left, top), (left, bottom)),
+            (&bf.right_border, (right, top), (right, bottom)),
+        ];
+        let commands: Vec<DrawCommand> = edges
+            .into_iter()
+            .filter_map(|(spec, from, to)| table::border_command(spec.as_ref()?, from, to))
+            .collect();
+        self.commands.extend(commands);
+    }
+
+    /// 쪽 번호 "- N -"를 아래쪽 여백 가운데에 그림
+    fn page_number(&self, page: &mut CanvasPage, number: usize, bottom_margin: f64) {
+        let label = format!("- {} -", number);
+        let font = self.font(self.resources.char_shapes.first());
+        let x = page.width / 2.0 - text::estimate_width(&label, &font, None) / 2.0;
+        let y = page.height - bottom_margin + font.size;
+        page.commands.push(DrawCommand::DrawText {
+            x: round_mm(x),
+            y: round_mm(y),
+            text: label,
+            font,
+            color: css_color(0),
+        });
+    }
+
+    /// 문단 목록(셀, 글상자, 머리말 등)을 그림 — 텍스트와 문단 안 개체 포함
+    /// `x`, `y`는 문단 목록의 좌상단 (line segment의 기준점)
+    fn paragraphs(&mut self, paragraphs: &[Paragraph], x: f64, y: f64) {
+        for para in paragraphs {
+            self.paragraph_text(para, x, y);
+            let mut inline_x = x;
+            for run in &para.runs {
+                for content in &run.contents {
+                    let RunContent::Object(object) = content else {
+                        continue;
+                    };
+                    let common = doc_utils::shape_common(object);
+                    if common.position.treat_as_char {
+                        self.shape(object, inline_x, y + first_line_top_mm(para));
+                        inline_x += hwpunit_to_mm(common.size.width);
+                    } else {
+                        self.shape(
+                            object,
+                            x + hwpunit_to_mm(common.position.horz_offset),
+                            y + hwpunit_to_mm(common.position.vert_offset),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_document_has_one_a4_page() {
+        let mut doc = Document::default();
+        doc.sections.push(Default::default());
+        let pages = doc_to_canvas(&doc);
+        assert_eq!(pages.len(), 1);
+        assert!((pages[0].width - 210.0).abs() < 0.1);
+        assert!((pages[0].height - 297.0).abs() < 0.1);
+        assert!(pages[0].commands.is_empty());
+    }
+
+    #[test]
+    fn test_command_serialization() {
+        let command = DrawCommand::FillRect {
+            x: 1.0,
+            y: 2.0,
+            width: 3.0,
+            height: 4.0,
+            color: css_color(0xFF0000),
+        };
+        let json = serde_json::to_string(&command).unwrap();
+        assert_eq!(
+            json,
+            r##"{"op":"fillRect","x":1.0,"y":2.0,"width":3.0,"height":4.0,"color":"#FF0000"}"##
+        );
+        let back: DrawCommand = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, command);
+    }
 
-// TODO: Implement Canvas viewer
-// TODO: Canvas 뷰어 구현
+    #[test]
+    fn test_rect_path_is_closed() {
+        let path = rect_path(0.0, 0.0, 10.0, 5.0);
+        assert_eq!(path.first(), Some(&PathOp::MoveTo { x: 0.0, y: 0.0 }));
+        assert_eq!(path.last(), Some(&PathOp::Close));
+    }
+}
@@ -0,0 +1,645 @@
+/// 그리기 개체 → drawImage / fillPath / strokePath
+/// 개체 좌표는 호출자가 계산한 좌상단 (x, y) 기준. 선/호/다각형/곡선/연결선의 경로와 화살표는
+/// 인라인 SVG(`doc_html::shape_svg`)와 같은 기하를 써서 원본 크기 → 현재 크기 배율까지 맞춤
+use super::text::{estimate_width, DEFAULT_FONT_FAMILY};
+use super::{css_color, rect_path, solid_fill_color, CanvasFont, DrawCommand, Painter, PathOp};
+use crate::viewer::doc_html::shape_svg::{
+    self, ArrowShape, Segment, ShapeOutline, CHART_LABEL, MIN_STROKE_MM, PLACEHOLDER_DASH,
+    PLACEHOLDER_FILL, PLACEHOLDER_FONT_MM, PLACEHOLDER_LINE, PLACEHOLDER_TEXT, VIDEO_LABEL,
+};
+use crate::viewer::doc_html::styles::{hwpunit_to_mm, round_mm};
+use crate::viewer::doc_utils;
+use hwp_model::resources::FillBrush;
+use hwp_model::shape::{ShapeLineInfo, ShapeObject, TextArtObject};
+use hwp_model::types::LineType1;
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+/// 베지어 곡선으로 1/4 원을 근사할 때의 제어점 비율
+const KAPPA: f64 = 0.552_284_75;
+
+impl Painter<'_> {
+    /// 개체를 (x, y)를 좌상단으로 그림
+    pub(super) fn shape(&mut self, object: &ShapeObject, x: f64, y: f64) {
+        let component = doc_utils::shape_component(object);
+        let (size_w, size_h) = shape_svg::shape_size(doc_utils::shape_common(object), component);
+        let width = hwpunit_to_mm(size_w).max(0.0);
+        let height = hwpunit_to_mm(size_h).max(0.0);
+        let scale = component
+            .map(|c| shape_svg::point_scale(c, size_w, size_h))
+            .unwrap_or((1.0, 1.0));
+
+        // 회전: 개체 중심 기준 회전 행렬
+        let angle = component
+            .and_then(|c| c.rotation.as_ref())
+            .map(|r| r.angle as f64)
+            .filter(|a| a.abs() > f64::EPSILON);
+        if let Some(angle) = angle {
+            let (sin, cos) = angle.to_radians().sin_cos();
+            let (cx, cy) = (x + width / 2.0, y + height / 2.0);
+            self.push(DrawCommand::Save);
+            self.push(DrawCommand::Transform {
+                a: cos,
+                b: sin,
+                c: -sin,
+                d: cos,
+                e: round_mm(cx - cos * cx + sin * cy),
+                f: round_mm(cy - sin * cx - cos * cy),
+            });
+        }
+
+        match object {
+            ShapeObject::Table(table) => self.table(table, x, y),
+            ShapeObject::Picture(pic) => {
+                self.image(&pic.img.binary_item_id, x, y, width, height);
+                if let Some(line) = &pic.line_shape {
+                    self.outline(rect_path(x, y, width, height), line);
+                }
+            }
+            ShapeObject::Rectangle(rect) => {
+                self.fill_path(rect_path(x, y, width, height), rect.fill.as_ref());
+                self.outline(rect_path(x, y, width, height), &rect.line_shape);
+                if let Some(text) = &rect.draw_text {
+                    self.paragraphs(&text.paragraphs, x, y);
+                }
+            }
+            ShapeObject::Ellipse(ellipse) if !ellipse.has_arc => {
+                let path = ellipse_path(x, y, width, height);
+                self.fill_path(path.clone(), ellipse.fill.as_ref());
+                self.outline(path, &ellipse.line_shape);
+                if let Some(text) = &ellipse.draw_text {
+                    self.paragraphs(&text.paragraphs, x, y);
+                }
+            }
+            ShapeObject::Line(_)
+            | ShapeObject::Ellipse(_)
+            | ShapeObject::Arc(_)
+            | ShapeObject::Polygon(_)
+            | ShapeObject::Curve(_)
+            | ShapeObject::ConnectLine(_) => {
+                if let Some(outline) = shape_svg::shape_outline(object, scale, width, height) {
+                    self.shape_outline(&outline, x, y);
+                }
+            }
+            ShapeObject::TextArt(text_art) => self.text_art(text_art, x, y, width, height),
+            ShapeObject::Ole(ole) => {
+                // OLE 미리보기는 BinData 항목이 아니므로 개체 형식 이름으로 자리 표시
+                let contents = shape_svg::ole_contents(ole, self.binaries);
+                let label = shape_svg::ole_label(contents.as_ref());
+                self.placeholder(label, ole.line_shape.as_ref(), x, y, width, height);
+            }
+            // 차트 그림은 인라인 SVG 문자열로만 만들어지므로 자리 표시
+            ShapeObject::Chart(_) => self.placeholder(CHART_LABEL, None, x, y, width, height),
+            ShapeObject::Video(video) => {
+                let poster = video
+                    .image_id_ref
+                    .as_deref()
+                    .filter(|id| doc_utils::find_binary_item(id, self.binaries).is_some());
+                match poster {
+                    Some(id) => self.image(id, x, y, width, height),
+                    None => self.placeholder(VIDEO_LABEL, None, x, y, width, height),
+                }
+            }
+            ShapeObject::Container(container) => {
+                // 묶음 개체: 원점을 옮긴 뒤 하위 개체는 묶음 내부 오프셋으로 배치
+                self.push(DrawCommand::Save);
+                self.push(DrawCommand::Transform {
+                    a: 1.0,
+                    b: 0.0,
+                    c: 0.0,
+                    d: 1.0,
+                    e: round_mm(x),
+                    f: round_mm(y),
+                });
+                for child in &container.children {
+                    let (cx, cy) = doc_utils::shape_component(child)
+                        .and_then(|c| c.offset.as_ref())
+                        .map(|pt| (hwpunit_to_mm(pt.x), hwpunit_to_mm(pt.y)))
+                        .unwrap_or((0.0, 0.0));
+                    self.shape(child, cx, cy);
+                }
+                self.push(DrawCommand::Restore);
+            }
+            // 수식과 양식 개체는 글자 흐름(본문 텍스트)에서 처리
+            ShapeObject::Equation(_) | ShapeObject::Form(_) => {}
+        }
+
+        if angle.is_some() {
+            self.push(DrawCommand::Restore);
+        }
+    }
+
+    /// BinData 이미지 (항목이 없으면 그리지 않음)
+    fn image(&mut self, binary_item_id: &str, x: f64, y: f64, width: f64, height: f64) {
+        if let Some(item) = doc_utils::find_binary_item(binary_item_id, self.binaries) {
+            self.push(DrawCommand::DrawImage {
+                x: round_mm(x),
+                y: round_mm(y),
+                width: round_mm(width),
+                height: round_mm(height),
+                binary_id: item.id.clone(),
+                mime: doc_utils::image_format_to_mime(&item.format).to_string(),
+            });
+        }
+    }
+
+    /// 선/호/다각형/곡선/연결선: 채우기, 외곽선, 화살표 순서
+    fn shape_outline(&mut self, outline: &ShapeOutline, x: f64, y: f64) {
+        let path = path_ops(&outline.segments, x, y);
+        self.fill_path(path.clone(), outline.fill);
+        self.outline(path, outline.line);
+
+        let line = outline.line;
+        let color = line.color.unwrap_or(0);
+        let width = shape_svg::stroke_width(line);
+        for mark in shape_svg::arrow_marks(&outline.arrow_points, line, outline.arrow_ends) {
+            let path = match mark.shape {
+                ArrowShape::Polygon(points) => {
+                    let mut segments = shape_svg::polyline(&points);
+                    segments.push(Segment::Close);
+                    path_ops(&segments, x, y)
+                }
+                ArrowShape::Circle { center, radius } => ellipse_path(
+                    x + center.0 - radius,
+                    y + center.1 - radius,
+                    radius * 2.0,
+                    radius * 2.0,
+                ),
+            };
+            self.push(DrawCommand::FillPath {
+                path: path.clone(),
+                color: css_color(if mark.filled { color } else { 0xFFFFFF }),
+            });
+            self.push(DrawCommand::StrokePath {
+                path,
+                color: css_color(color),
+                width,
+                dash: Vec::new(),
+            });
+        }
+    }
+
+    /// 글맵시: 개체 크기에 맞춰 가로로 늘린 글자
+    fn text_art(&mut self, text_art: &TextArtObject, x: f64, y: f64, width: f64, height: f64) {
+        if text_art.text.is_empty() {
+            return;
+        }
+        let font = CanvasFont {
+            family: text_art
+                .font_name
+                .clone()
+                .unwrap_or_else(|| DEFAULT_FONT_FAMILY.to_string()),
+            size: round_mm(height * 0.8),
+            bold: false,
+            italic: false,
+        };
+        let color = text_art
+            .fill
+            .as_ref()
+            .and_then(solid_fill_color)
+            .or(text_art.line_shape.color)
+            .unwrap_or(0);
+        let text_width = estimate_width(&text_art.text, &font, None);
+        if text_width <= 0.0 || width <= 0.0 {
+            return;
+        }
+        self.push(DrawCommand::Save);
+        self.push(DrawCommand::Transform {
+            a: width / text_width,
+            b: 0.0,
+            c: 0.0,
+            d: 1.0,
+            e: round_mm(x),
+            f: round_mm(y),
+        });
+        self.push(DrawCommand::DrawText {
+            x: 0.0,
+            y: font.size,
+            text: text_art.text.clone(),
+            font,
+            color: css_color(color),
+        });
+        self.push(DrawCommand::Restore);
+    }
+
+    /// 내용을 그릴 수 없는 개체(OLE, 차트 등)의 자리 표시 상자 (인라인 SVG와 같은 모양)
+    fn placeholder(
+        &mut self,
+        label: &str,
+        line: Option<&ShapeLineInfo>,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    ) {
+        self.fill_rect(x, y, width, height, PLACEHOLDER_FILL);
+        match line {
+            Some(line) if line.style != LineType1::None => {
+                self.outline(rect_path(x, y, width, height), line)
+            }
+            _ => self.push(DrawCommand::StrokePath {
+                path: rect_path(x, y, width, height),
+                color: css_color(PLACEHOLDER_LINE),
+                width: MIN_STROKE_MM,
+                dash: PLACEHOLDER_DASH.to_vec(),
+            }),
+        }
+        let font = CanvasFont {
+            family: DEFAULT_FONT_FAMILY.to_string(),
+            size: PLACEHOLDER_FONT_MM,
+            bold: false,
+            italic: false,
+        };
+        let text_width = estimate_width(label, &font, None);
+        // 세로 가운데: 기준선을 글자 크기의 1/3만큼 내림
+        self.push(DrawCommand::DrawText {
+            x: round_mm(x + (width - text_width) / 2.0),
+            y: round_mm(y + height / 2.0 + font.size / 3.0),
+            text: label.to_string(),
+            font,
+            color: css_color(PLACEHOLDER_TEXT),
+        });
+    }
+
+    /// 단색 채우기 (그 밖의 채우기는 무시)
+    fn fill_path(&mut self, path: Vec<PathOp>, fill: Option<&FillBrush>) {
+        if let Some(color) = fill.and_then(solid_fill_color) {
+            self.push(DrawCommand::FillPath {
+                path,
+                color: css_color(color),
+            });
+        }
+    }
+
+    /// 개체 외곽선 (선 종류 None이면 그리지 않음)
+    fn outline(&mut self, path: Vec<PathOp>, line: &ShapeLineInfo) {
+        if line.style == LineType1::None {
+            return;
+        }
+        let width = shape_svg::stroke_width(line);
+        self.push(DrawCommand::StrokePath {
+            path,
+            color: css_color(line.color.unwrap_or(0)),
+            width,
+            dash: shape_svg::dash_pattern(&line.style, width),
+        });
+    }
+}
+
+/// 개체 내부 경로 → (x, y)로 옮긴 canvas 경로. 타원호는 베지어로 바꿈
+fn path_ops(segments: &[Segment], x: f64, y: f64) -> Vec<PathOp> {
+    let at = |p: (f64, f64)| (round_mm(x + p.0), round_mm(y + p.1));
+    let mut ops = Vec::with_capacity(segments.len());
+    let (mut current, mut start) = ((0.0, 0.0), (0.0, 0.0));
+    for segment in segments {
+        match *segment {
+            Segment::Move(p) => {
+                let (x, y) = at(p);
+                ops.push(PathOp::MoveTo { x, y });
+                (current, start) = (p, p);
+            }
+            Segment::Line(p) => {
+                let (x, y) = at(p);
+                ops.push(PathOp::LineTo { x, y });
+                current = p;
+            }
+            Segment::Cubic(cp1, cp2, p) => {
+                let ((cp1x, cp1y), (cp2x, cp2y), (x, y)) = (at(cp1), at(cp2), at(p));
+                ops.push(PathOp::BezierTo {
+                    cp1x,
+                    cp1y,
+                    cp2x,
+                    cp2y,
+                    x,
+                    y,
+                });
+                current = p;
+            }
+            Segment::Arc {
+                rx,
+                ry,
+                rotation,
+                large_arc,
+                sweep,
+                to,
+            } => {
+                for (cp1, cp2, p) in arc_beziers(current, rx, ry, rotation, large_arc, sweep, to) {
+                    let ((cp1x, cp1y), (cp2x, cp2y), (x, y)) = (at(cp1), at(cp2), at(p));
+                    ops.push(PathOp::BezierTo {
+                        cp1x,
+                        cp1y,
+                        cp2x,
+                        cp2y,
+                        x,
+                        y,
+                    });
+                }
+                current = to;
+            }
+            Segment::Close => {
+                ops.push(PathOp::Close);
+                current = start;
+            }
+        }
+    }
+    ops
+}
+
+type Bezier = ((f64, f64), (f64, f64), (f64, f64));
+
+/// SVG 끝점 표기 타원호 → 3차 베지어 (90도 이하 조각, SVG 1.1 F.6.5 중심 변환)
+/// 반지름이 0이면 직선, 시작점과 끝점이 같으면 SVG처럼 그리지 않음
+fn arc_beziers(
+    from: (f64, f64),
+    rx: f64,
+    ry: f64,
+    rotation: f64,
+    large_arc: bool,
+    sweep: bool,
+    to: (f64, f64),
+) -> Vec<Bezier> {
+    if from == to {
+        return Vec::new();
+    }
+    let (mut rx, mut ry) = (rx.abs(), ry.abs());
+    if rx == 0.0 || ry == 0.0 {
+        return vec![(from, to, to)];
+    }
+    let (sin, cos) = rotation.to_radians().sin_cos();
+    let (dx, dy) = ((from.0 - to.0) / 2.0, (from.1 - to.1) / 2.0);
+    let x1 = cos * dx + sin * dy;
+    let y1 = -sin * dx + cos * dy;
+
+    // 끝점이 타원에 닿지 않으면 반지름을 늘림
+    let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+    if lambda > 1.0 {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+    let num = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+    let den = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+    let mut coef = (num / den).max(0.0).sqrt();
+    if large_arc == sweep {
+        coef = -coef;
+    }
+    let (cx1, cy1) = (coef * rx * y1 / ry, -coef * ry * x1 / rx);
+    let cx = cos * cx1 - sin * cy1 + (from.0 + to.0) / 2.0;
+    let cy = sin * cx1 + cos * cy1 + (from.1 + to.1) / 2.0;
+
+    let theta1 = ((y1 - cy1) / ry).atan2((x1 - cx1) / rx);
+    let theta2 = ((-y1 - cy1) / ry).atan2((-x1 - cx1) / rx);
+    let mut delta = (theta2 - theta1).rem_euclid(TAU);
+    if !sweep && delta > 0.0 {
+        delta -= TAU;
+    }
+
+    let point = |t: f64| {
+        let (st, ct) = t.sin_cos();
+        (
+            cx + rx * cos * ct - ry * sin * st,
+            cy + rx * sin * ct + ry * cos * st,
+        )
+    };
+    let tangent = |t: f64| {
+        let (st, ct) = t.sin_cos();
+        (
+            -rx * cos * st - ry * sin * ct,
+            -rx * sin * st + ry * cos * ct,
+        )
+    };
+    let count = (delta.abs() / FRAC_PI_2 - 1e-9).ceil().max(1.0) as usize;
+    let step = delta / count as f64;
+    let k = 4.0 / 3.0 * (step / 4.0).tan();
+    (0..count)
+        .map(|i| {
+            let t1 = theta1 + step * i as f64;
+            let t2 = t1 + step;
+            let (p1, d1) = (point(t1), tangent(t1));
+            let (p2, d2) = (point(t2), tangent(t2));
+            let end = if i + 1 == count { to } else { p2 };
+            (
+                (p1.0 + k * d1.0, p1.1 + k * d1.1),
+                (p2.0 - k * d2.0, p2.1 - k * d2.1),
+                end,
+            )
+        })
+        .collect()
+}
+
+/// 사각형에 내접하는 타원 (베지어 4개) / Ellipse inscribed in a rectangle
+fn ellipse_path(x: f64, y: f64, width: f64, height: f64) -> Vec<PathOp> {
+    let (rx, ry) = (width / 2.0, height / 2.0);
+    let (cx, cy) = (x + rx, y + ry);
+    let (kx, ky) = (rx * KAPPA, ry * KAPPA);
+    let r = round_mm;
+    vec![
+        PathOp::MoveTo {
+            x: r(cx + rx),
+            y: r(cy),
+        },
+        PathOp::BezierTo {
+            cp1x: r(cx + rx),
+            cp1y: r(cy + ky),
+            cp2x: r(cx + kx),
+            cp2y: r(cy + ry),
+            x: r(cx),
+            y: r(cy + ry),
+        },
+        PathOp::BezierTo {
+            cp1x: r(cx - kx),
+            cp1y: r(cy + ry),
+            cp2x: r(cx - rx),
+            cp2y: r(cy + ky),
+            x: r(cx - rx),
+            y: r(cy),
+        },
+        PathOp::BezierTo {
+            cp1x: r(cx - rx),
+            cp1y: r(cy - ky),
+            cp2x: r(cx - kx),
+            cp2y: r(cy - ry),
+            x: r(cx),
+            y: r(cy - ry),
+        },
+        PathOp::BezierTo {
+            cp1x: r(cx + kx),
+            cp1y: r(cy - ry),
+            cp2x: r(cx + rx),
+            cp2y: r(cy - ky),
+            x: r(cx + rx),
+            y: r(cy),
+        },
+        PathOp::Close,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hwp_model::document::BinaryStore;
+    use hwp_model::resources::Resources;
+    use hwp_model::shape::{
+        ArcObject, ChartObject, PolygonObject, ShapeCommon, ShapeComponentData, VideoObject,
+    };
+    use hwp_model::types::{ArcType, ArrowType, Point, Size};
+
+    fn common(width: i32, height: i32) -> ShapeCommon {
+        let mut common = ShapeCommon::default();
+        common.size.width = width;
+        common.size.height = height;
+        common
+    }
+
+    fn solid_line() -> ShapeLineInfo {
+        ShapeLineInfo {
+            style: LineType1::Solid,
+            width: 283, // 1mm
+            ..Default::default()
+        }
+    }
+
+    fn draw(object: ShapeObject) -> Vec<DrawCommand> {
+        let resources = Resources::default();
+        let binaries = BinaryStore::default();
+        let mut painter = Painter::new(&resources, &binaries);
+        painter.shape(&object, 10.0, 20.0);
+        painter.commands
+    }
+
+    fn stroke_path(commands: &[DrawCommand]) -> &[PathOp] {
+        commands
+            .iter()
+            .find_map(|c| match c {
+                DrawCommand::StrokePath { path, .. } => Some(path.as_slice()),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_polygon_points_scale_to_current_size() {
+        // 원본 크기의 절반으로 줄인 다각형 (인라인 SVG와 같은 좌표)
+        let polygon = PolygonObject {
+            common: common(3600, 3600),
+            component: ShapeComponentData {
+                org_size: Some(Size {
+                    width: 7200,
+                    height: 7200,
+                }),
+                ..Default::default()
+            },
+            points: vec![
+                Point { x: 0, y: 0 },
+                Point { x: 7200, y: 0 },
+                Point { x: 7200, y: 7200 },
+                Point { x: 0, y: 0 },
+            ],
+            line_shape: ShapeLineInfo {
+                style: LineType1::Dash,
+                ..solid_line()
+            },
+            ..Default::default()
+        };
+        let commands = draw(ShapeObject::Polygon(Box::new(polygon)));
+        assert_eq!(
+            stroke_path(&commands),
+            &[
+                PathOp::MoveTo { x: 10.0, y: 20.0 },
+                PathOp::LineTo { x: 22.7, y: 20.0 },
+                PathOp::LineTo { x: 22.7, y: 32.7 },
+                PathOp::LineTo { x: 10.0, y: 20.0 },
+                PathOp::Close,
+            ]
+        );
+        let DrawCommand::StrokePath { width, dash, .. } = &commands[0] else {
+            panic!("{:?}", commands);
+        };
+        assert_eq!((*width, dash.as_slice()), (1.0, &[4.0, 2.0][..]));
+    }
+
+    #[test]
+    fn test_arc_to_bezier() {
+        // 중심 (0, 0)에서 (25.4, 0) → (0, 25.4)로 도는 1/4 부채꼴, 끝에 화살표
+        let arc = ArcObject {
+            common: common(7200, 7200),
+            arc_type: ArcType::Pie,
+            center: Point { x: 0, y: 0 },
+            axis1: Point { x: 7200, y: 0 },
+            axis2: Point { x: 0, y: 7200 },
+            line_shape: ShapeLineInfo {
+                tail_style: ArrowType::Arrow,
+                ..solid_line()
+            },
+            ..Default::default()
+        };
+        let commands = draw(ShapeObject::Arc(Box::new(arc)));
+        let path = stroke_path(&commands);
+        assert_eq!(path.len(), 4);
+        assert_eq!(path[0], PathOp::MoveTo { x: 35.4, y: 20.0 });
+        let PathOp::BezierTo {
+            cp1x,
+            cp1y,
+            cp2x,
+            cp2y,
+            x,
+            y,
+        } = path[1]
+        else {
+            panic!("{:?}", path);
+        };
+        let k = 25.4 * KAPPA;
+        assert!((cp1x - 35.4).abs() < 0.01 && (cp1y - (20.0 + k)).abs() < 0.01);
+        assert!((cp2x - (10.0 + k)).abs() < 0.01 && (cp2y - 45.4).abs() < 0.01);
+        assert_eq!((x, y), (10.0, 45.4));
+        assert_eq!(path[2], PathOp::LineTo { x: 10.0, y: 20.0 });
+        assert_eq!(path[3], PathOp::Close);
+        // 끝점 화살표: 채운 삼각형 + 외곽선
+        assert_eq!(
+            commands
+                .iter()
+                .filter(|c| matches!(c, DrawCommand::FillPath { .. }))
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_placeholder_shapes() {
+        let chart = ChartObject {
+            common: common(7200, 3600),
+            ..Default::default()
+        };
+        let commands = draw(ShapeObject::Chart(Box::new(chart)));
+        assert!(commands
+            .iter()
+            .any(|c| matches!(c, DrawCommand::DrawText { text, .. } if text == CHART_LABEL)));
+
+        let video = VideoObject {
+            common: common(7200, 3600),
+            ..Default::default()
+        };
+        let commands = draw(ShapeObject::Video(Box::new(video)));
+        assert!(commands
+            .iter()
+            .any(|c| matches!(c, DrawCommand::DrawText { text, .. } if text == VIDEO_LABEL)));
+    }
+
+    #[test]
+    fn test_text_art_stretches_to_width() {
+        let text_art = TextArtObject {
+            common: common(7200, 3600),
+            text: "한글".to_string(),
+            ..Default::default()
+        };
+        let commands = draw(ShapeObject::TextArt(Box::new(text_art)));
+        assert_eq!(commands.len(), 4);
+        let DrawCommand::Transform { a, e, f, .. } = commands[1] else {
+            panic!("{:?}", commands);
+        };
+        assert!(a > 0.0);
+        assert_eq!((e, f), (10.0, 20.0));
+        assert!(
+            matches!(&commands[2], DrawCommand::DrawText { text, font, .. }
+            if text == "한글" && (font.size - 10.16).abs() < 0.01)
+        );
+    }
+}
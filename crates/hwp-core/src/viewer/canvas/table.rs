@@ -0,0 +1,286 @@
+/// 표 → 셀 배경(fillRect) + 셀 내용(clip) + 테두리(strokePath)
+use super::{css_color, solid_fill_color, DrawCommand, Painter, PathOp};
+use crate::viewer::doc_html::styles::{hwpunit_to_mm, round_mm};
+use hwp_model::resources::LineSpec;
+use hwp_model::table::{Table, TableCell};
+use hwp_model::types::{LineType3, VAlign};
+
+/// 셀 안쪽 여백이 0일 때의 기본값 (mm, 레이아웃 HTML과 동일)
+const DEFAULT_CELL_MARGIN_MM: f64 = 0.5;
+/// 테두리 굵기 문자열을 읽을 수 없을 때의 기본값 (mm)
+const DEFAULT_BORDER_WIDTH_MM: f64 = 0.12;
+
+/// 배치가 끝난 셀 / Laid-out cell
+struct CellBox<'a> {
+    cell: &'a TableCell,
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+impl Painter<'_> {
+    /// 표를 (x, y)를 좌상단으로 그림
+    pub(super) fn table(&mut self, table: &Table, x: f64, y: f64) {
+        let cells: Vec<&TableCell> = table.rows.iter().flat_map(|row| &row.cells).collect();
+        if cells.is_empty() {
+            return;
+        }
+
+        let col_count = cells
+            .iter()
+            .map(|c| (c.col + c.col_span.max(1)) as usize)
+            .max()
+            .unwrap_or(0)
+            .max(table.col_count as usize);
+        let row_count = cells
+            .iter()
+            .map(|c| (c.row + c.row_span.max(1)) as usize)
+            .max()
+            .unwrap_or(0)
+            .max(table.row_count as usize);
+
+        let col_pos = resolve_positions(
+            col_count,
+            cells.iter().map(|c| {
+                (
+                    c.col as usize,
+                    c.col_span.max(1) as usize,
+                    hwpunit_to_mm(c.width),
+                )
+            }),
+        );
+        let row_pos = resolve_positions(
+            row_count,
+            cells.iter().map(|c| {
+                (
+                    c.row as usize,
+                    c.row_span.max(1) as usize,
+                    cell_height_mm(c),
+                )
+            }),
+        );
+
+        let boxes: Vec<CellBox> = cells
+            .into_iter()
+            .map(|cell| {
+                let col = cell.col as usize;
+                let row = cell.row as usize;
+                let col_end = col + cell.col_span.max(1) as usize;
+                let row_end = row + cell.row_span.max(1) as usize;
+                CellBox {
+                    cell,
+                    x: x + col_pos[col],
+                    y: y + row_pos[row],
+                    width: col_pos[col_end] - col_pos[col],
+                    height: row_pos[row_end] - row_pos[row],
+                }
+            })
+            .collect();
+
+        // 1) 배경
+        for b in &boxes {
+            let fill = self
+                .border_fill(b.cell.border_fill_id)
+                .and_then(|bf| bf.fill.as_ref())
+                .and_then(solid_fill_color);
+            if let Some(color) = fill {
+                self.fill_rect(b.x, b.y, b.width, b.height, color);
+            }
+        }
+
+        // 2) 내용 (셀 영역으로 클리핑)
+        for b in &boxes {
+            let cell = b.cell;
+            if cell.content.paragraphs.is_empty() {
+                continue;
+            }
+            let margin_left = margin_mm(cell.cell_margin.left);
+            let margin_top = margin_mm(cell.cell_margin.top);
+            let margin_bottom = margin_mm(cell.cell_margin.bottom);
+            let content_height = super::paragraphs_height_mm(&cell.content.paragraphs);
+            let free = (b.height - margin_top - margin_bottom - content_height).max(0.0);
+            let offset = match cell.content.vert_align {
+                VAlign::Center => free / 2.0,
+                VAlign::Bottom => free,
+                _ => 0.0,
+            };
+
+            self.push(DrawCommand::Save);
+            self.clip(b.x, b.y, b.width, b.height);
+            self.paragraphs(
+                &cell.content.paragraphs,
+                b.x + margin_left,
+                b.y + margin_top + offset,
+            );
+            self.push(DrawCommand::Restore);
+        }
+
+        // 3) 테두리
+        for b in &boxes {
+            let Some(bf) = self.border_fill(b.cell.border_fill_id) else {
+                continue;
+            };
+            let (left, right, top, bottom) = (b.x, b.x + b.width, b.y, b.y + b.height);
+            let edges = [
+                (&bf.top_border, (left, top), (right, top)),
+                (&bf.bottom_border, (left, bottom), (right, bottom)),
+                (&bf.left_border, (left, top), (left, bottom)),
+                (&bf.right_border, (right, top), (right, bottom)),
+            ];
+            let commands: Vec<DrawCommand> = edges
+                .into_iter()
+                .filter_map(|(spec, from, to)| border_command(spec.as_ref()?, from, to))
+                .collect();
+            self.commands.extend(commands);
+        }
+    }
+
+    /// border_fill_id (1부터 시작) → BorderFill
+    fn border_fill(&self, id: u16) -> Option<&hwp_model::resources::BorderFill> {
+        self.resources
+            .border_fills
+            .get((id as usize).checked_sub(1)?)
+    }
+}
+
+/// 셀 높이 (mm) — 파싱된 높이가 비정상적으로 작으면 내용 높이 사용
+fn cell_height_mm(cell: &TableCell) -> f64 {
+    let parsed = hwpunit_to_mm(cell.height);
+    if parsed < 1.0 && cell.height != 0 {
+        let content = super::paragraphs_height_mm(&cell.content.paragraphs)
+            + margin_mm(cell.cell_margin.top)
+            + margin_mm(cell.cell_margin.bottom);
+        parsed.max(content)
+    } else {
+        parsed
+    }
+}
+
+fn margin_mm(value: i32) -> f64 {
+    if value != 0 {
+        hwpunit_to_mm(value)
+    } else {
+        DEFAULT_CELL_MARGIN_MM
+    }
+}
+
+/// 셀 (시작 인덱스, 병합 수, 크기)로부터 행/열 경계 위치 계산
+///
+/// 병합되지 않은 셀의 최대 크기를 우선 사용하고, 병합 셀로 나머지 경계를 채웁니다.
+/// 끝까지 정해지지 않은 경계는 앞 경계와 같은 위치로 둡니다.
+pub(crate) fn resolve_positions(
+    count: usize,
+    extents: impl Iterator<Item = (usize, usize, f64)>,
+) -> Vec<f64> {
+    let extents: Vec<(usize, usize, f64)> = extents.collect();
+    let mut single: Vec<Option<f64>> = vec![None; count];
+    for &(start, span, size) in &extents {
+        if span == 1 && start < count {
+            single[start] = Some(single[start].map_or(size, |s: f64| s.max(size)));
+        }
+    }
+
+    let mut pos: Vec<Option<f64>> = vec![None; count + 1];
+    pos[0] = Some(0.0);
+    loop {
+        let mut changed = false;
+        for i in 0..count {
+            if let (Some(p), Some(size), None) = (pos[i], single[i], pos[i + 1]) {
+                pos[i + 1] = Some(p + size);
+                changed = true;
+            }
+        }
+        for &(start, span, size) in &extents {
+            let end = start + span;
+            if end > count {
+                continue;
+            }
+            if let (Some(p), None) = (pos[start], pos[end]) {
+                pos[end] = Some(p + size);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let mut last = 0.0;
+    pos.into_iter()
+        .map(|p| {
+            last = p.unwrap_or(last);
+            round_mm(last)
+        })
+        .collect()
+}
+
+/// 테두리 한 변 → strokePath (선 없음/색 없음이면 None)
+pub(super) fn border_command(
+    spec: &LineSpec,
+    from: (f64, f64),
+    to: (f64, f64),
+) -> Option<DrawCommand> {
+    if spec.line_type == LineType3::None {
+        return None;
+    }
+    let color = spec.color?;
+    let width = spec
+        .width
+        .trim()
+        .trim_end_matches("mm")
+        .trim()
+        .parse::<f64>()
+        .unwrap_or(DEFAULT_BORDER_WIDTH_MM);
+    Some(DrawCommand::StrokePath {
+        path: vec![
+            PathOp::MoveTo {
+                x: round_mm(from.0),
+                y: round_mm(from.1),
+            },
+            PathOp::LineTo {
+                x: round_mm(to.0),
+                y: round_mm(to.1),
+            },
+        ],
+        color: css_color(color),
+        width,
+        dash: border_dash(&spec.line_type),
+    })
+}
+
+/// 선 종류 → setLineDash 패턴 (mm)
+fn border_dash(line_type: &LineType3) -> Vec<f64> {
+    match line_type {
+        LineType3::Dot => vec![0.2, 0.3],
+        LineType3::Dash => vec![1.0, 0.5],
+        LineType3::DashDot => vec![1.0, 0.3, 0.2, 0.3],
+        LineType3::DashDotDot => vec![1.0, 0.3, 0.2, 0.3, 0.2, 0.3],
+        LineType3::LongDash => vec![2.0, 0.5],
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_positions_simple() {
+        let pos = resolve_positions(2, [(0, 1, 10.0), (1, 1, 20.0)].into_iter());
+        assert_eq!(pos, vec![0.0, 10.0, 30.0]);
+    }
+
+    #[test]
+    fn test_resolve_positions_with_span() {
+        // 첫 행은 병합 셀 하나, 둘째 행이 열 폭을 결정
+        let pos = resolve_positions(2, [(0, 2, 30.0), (0, 1, 12.0), (1, 1, 18.0)].into_iter());
+        assert_eq!(pos, vec![0.0, 12.0, 30.0]);
+    }
+
+    #[test]
+    fn test_resolve_positions_only_spans() {
+        let pos = resolve_positions(2, [(0, 2, 30.0)].into_iter());
+        assert_eq!(pos, vec![0.0, 0.0, 30.0]);
+    }
+}
@@ -0,0 +1,366 @@
+/// 문단 텍스트 → drawText 명령
+/// 라인 세그먼트(LineSegmentInfo) 위치에 CharShape 구간별로 글자를 배치
+use super::{css_color, CanvasFont, DrawCommand, Painter, PathOp};
+use crate::viewer::doc_html::flat_text::{self, FlatCharShapeInfo};
+use crate::viewer::doc_html::styles::{hwpunit_to_mm, round_mm};
+use hwp_model::paragraph::Paragraph;
+use hwp_model::resources::CharShape;
+use hwp_model::types::{HAlign, LineType3, UnderlineType};
+
+/// HWP 기본 탭 간격 (40pt)
+const DEFAULT_TAB_WIDTH: i32 = 4000;
+/// 글꼴 정보가 없을 때의 글꼴 / Fallback font family
+pub(super) const DEFAULT_FONT_FAMILY: &str = "serif";
+
+/// 한 줄 안에서 같은 CharShape를 쓰는 글자 조각 (탭으로 나뉨)
+struct TextPiece {
+    shape_id: u16,
+    text: String,
+    /// 줄 시작 기준 x (mm)
+    x: f64,
+    width: f64,
+}
+
+impl Painter<'_> {
+    /// 문단 텍스트를 라인 세그먼트 위치에 그림
+    /// `x`, `y`는 line segment 좌표의 기준점 (콘텐츠 영역 좌상단)
+    pub(super) fn paragraph_text(&mut self, para: &Paragraph, x: f64, y: f64) {
+        if para.line_segments.is_empty() {
+            return;
+        }
+        let flat = flat_text::extract_flat_text(para);
+        let chars: Vec<char> = flat.text.chars().collect();
+        if chars.iter().all(|c| c.is_whitespace()) {
+            return;
+        }
+        let para_shape = self.resources.para_shapes.get(para.para_shape_id as usize);
+        let map = |pos: u32| flat_text::map_original_to_extracted(&flat.wchar_map, pos) as usize;
+
+        let segments = &para.line_segments;
+        for (index, seg) in segments.iter().enumerate() {
+            let flags = seg.decode_flags();
+            if flags.is_empty_segment {
+                continue;
+            }
+            let start = map(seg.text_start_pos).min(chars.len());
+            let end = segments
+                .get(index + 1)
+                .map(|next| map(next.text_start_pos).min(chars.len()))
+                .unwrap_or(chars.len())
+                .max(start);
+
+            let pieces = self.layout_line(&chars, &flat.char_shapes, start, end);
+            if pieces.is_empty() {
+                continue;
+            }
+
+            // 줄 시작 x: column_start_pos가 있으면 그 위치, 들여쓰기는 레이아웃 HTML과 같이 절반
+            let mut left = x;
+            if seg.column_start_pos > 0 {
+                left += hwpunit_to_mm(seg.column_start_pos);
+            }
+            if flags.has_indentation {
+                if let Some(ps) = para_shape {
+                    left += hwpunit_to_mm(ps.margin.indent.value.abs()) / 2.0;
+                }
+            }
+            let line_width: f64 = pieces.last().map(|p| p.x + p.width).unwrap_or(0.0);
+            let free = (hwpunit_to_mm(seg.segment_width) - line_width).max(0.0);
+            left += match para_shape.map(|ps| &ps.align.horizontal) {
+                Some(HAlign::Right) => free,
+                Some(HAlign::Center) => free / 2.0,
+                _ => 0.0,
+            };
+
+            let baseline = y
+                + hwpunit_to_mm(seg.vertical_pos)
+                + if seg.baseline_distance > 0 {
+                    hwpunit_to_mm(seg.baseline_distance)
+                } else {
+                    hwpunit_to_mm(seg.text_height) * 0.85
+                };
+            for piece in pieces {
+                self.text_piece(&piece, left, baseline);
+            }
+        }
+    }
+
+    /// 글자 하나의 CharShape로 짧은 텍스트를 그림 (각주 번호, 쪽 번호 등)
+    pub(super) fn plain_text(&mut self, text: &str, x: f64, top: f64, shape_id: Option<u16>) {
+        let char_shape = shape_id.and_then(|id| self.resources.char_shapes.get(id as usize));
+        let font = self.font(char_shape);
+        let baseline = top + font.size;
+        let color = char_shape.and_then(|cs| cs.text_color).unwrap_or(0);
+        self.push(DrawCommand::DrawText {
+            x: round_mm(x),
+            y: round_mm(baseline),
+            text: text.to_string(),
+            font,
+            color: css_color(color),
+        });
+    }
+
+    /// CharShape → 글꼴 (크기는 mm)
+    pub(super) fn font(&self, char_shape: Option<&CharShape>) -> CanvasFont {
+        let Some(cs) = char_shape else {
+            return CanvasFont {
+                family: DEFAULT_FONT_FAMILY.to_string(),
+                size: round_mm(pt_to_mm(10.0)),
+                bold: false,
+                italic: false,
+            };
+        };
+        let family = self
+            .resources
+            .fonts
+            .hangul
+            .get(cs.font_ref.hangul as usize)
+            .map(|font| font.face.clone())
+            .filter(|face| !face.is_empty())
+            .unwrap_or_else(|| DEFAULT_FONT_FAMILY.to_string());
+        let rel_size = if cs.rel_size.hangul > 0 {
+            cs.rel_size.hangul as f64 / 100.0
+        } else {
+            1.0
+        };
+        let mut size = pt_to_mm(cs.height as f64 / 100.0) * rel_size;
+        if cs.superscript || cs.subscript {
+            size *= 0.7;
+        }
+        CanvasFont {
+            family,
+            size: round_mm(size),
+            bold: cs.bold,
+            italic: cs.italic,
+        }
+    }
+
+    /// [start, end) 구간을 CharShape/탭 경계로 잘라 줄 안의 x 위치를 계산
+    fn layout_line(
+        &self,
+        chars: &[char],
+        char_shapes: &[FlatCharShapeInfo],
+        start: usize,
+        end: usize,
+    ) -> Vec<TextPiece> {
+        let tab_width = hwpunit_to_mm(DEFAULT_TAB_WIDTH);
+        let mut pieces: Vec<TextPiece> = Vec::new();
+        let mut cursor = 0.0;
+        let mut split = true;
+
+        for (index, &ch) in chars.iter().enumerate().take(end).skip(start) {
+            if ch == '\t' {
+                cursor = ((cursor / tab_width).floor() + 1.0) * tab_width;
+                split = true;
+                continue;
+            }
+            if ch.is_control() {
+                continue;
+            }
+            let shape_id = shape_id_at(char_shapes, index);
+            let char_shape = self.resources.char_shapes.get(shape_id as usize);
+            let advance = estimate_width(&ch.to_string(), &self.font(char_shape), char_shape);
+
+            match pieces.last_mut() {
+                Some(piece) if !split && piece.shape_id == shape_id => {
+                    piece.text.push(ch);
+                    piece.width += advance;
+                }
+                _ => pieces.push(TextPiece {
+                    shape_id,
+                    text: ch.to_string(),
+                    x: cursor,
+                    width: advance,
+                }),
+            }
+            cursor += advance;
+            split = false;
+        }
+
+        // 줄 끝 공백은 정렬 폭 계산에서 제외
+        if let Some(piece) = pieces.last_mut() {
+            let trimmed = piece.text.trim_end();
+            if trimmed.len() != piece.text.len() {
+                let char_shape = self.resources.char_shapes.get(piece.shape_id as usize);
+                piece.text = trimmed.to_string();
+                piece.width = estimate_width(&piece.text, &self.font(char_shape), char_shape);
+            }
+        }
+        pieces.retain(|piece| !piece.text.is_empty());
+        pieces
+    }
+
+    /// 글자 조각 하나 그리기: 음영 → 글자 → 밑줄/취소선
+    fn text_piece(&mut self, piece: &TextPiece, left: f64, baseline: f64) {
+        let char_shape = self.resources.char_shapes.get(piece.shape_id as usize);
+        let font = self.font(char_shape);
+        let x = left + piece.x;
+        let size = font.size;
+        let baseline = match char_shape {
+            Some(cs) if cs.superscript => baseline - size * 0.5,
+            Some(cs) if cs.subscript => baseline + size * 0.2,
+            _ => baseline,
+        };
+
+        if let Some(shade) = char_shape.and_then(|cs| cs.shade_color) {
+            if shade != 0xFFFFFF {
+                self.fill_rect(x, baseline - size, piece.width, size * 1.2, shade);
+            }
+        }
+
+        let color = char_shape.and_then(|cs| cs.text_color).unwrap_or(0);
+        self.push(DrawCommand::DrawText {
+            x: round_mm(x),
+            y: round_mm(baseline),
+            text: piece.text.clone(),
+            font,
+            color: css_color(color),
+        });
+
+        let Some(cs) = char_shape else {
+            return;
+        };
+        let stroke_width = round_mm((size * 0.06).max(0.1));
+        if let Some(underline) = &cs.underline {
+            if underline.shape != LineType3::None {
+                let y = match underline.underline_type {
+                    UnderlineType::Bottom => baseline + size * 0.1,
+                    UnderlineType::Center => baseline - size * 0.3,
+                    UnderlineType::Top => baseline - size * 0.85,
+                };
+                self.decoration(
+                    x,
+                    y,
+                    piece.width,
+                    underline.color.unwrap_or(color),
+                    stroke_width,
+                );
+            }
+        }
+        if let Some(strikeout) = &cs.strikeout {
+            if strikeout.shape != LineType3::None {
+                let y = baseline - size * 0.3;
+                self.decoration(
+                    x,
+                    y,
+                    piece.width,
+                    strikeout.color.unwrap_or(color),
+                    stroke_width,
+                );
+            }
+        }
+    }
+
+    fn decoration(&mut self, x: f64, y: f64, width: f64, color: u32, stroke_width: f64) {
+        self.push(DrawCommand::StrokePath {
+            path: vec![
+                PathOp::MoveTo {
+                    x: round_mm(x),
+                    y: round_mm(y),
+                },
+                PathOp::LineTo {
+                    x: round_mm(x + width),
+                    y: round_mm(y),
+                },
+            ],
+            color: css_color(color),
+            width: stroke_width,
+            dash: Vec::new(),
+        });
+    }
+}
+
+/// 위치에 적용되는 CharShape ID (변경점 목록에서 마지막으로 시작된 것)
+fn shape_id_at(char_shapes: &[FlatCharShapeInfo], index: usize) -> u16 {
+    char_shapes
+        .iter()
+        .take_while(|cs| cs.position as usize <= index)
+        .last()
+        .or_else(|| char_shapes.first())
+        .map(|cs| cs.shape_id)
+        .unwrap_or(0)
+}
+
+fn pt_to_mm(pt: f64) -> f64 {
+    pt * 25.4 / 72.0
+}
+
+/// 글자 폭 추정 (mm) — 전각(한글/한자/가나) 1em, 공백 0.33em, 그 밖의 반각 0.55em
+/// 장평(ratio)과 자간(spacing)을 반영
+pub(super) fn estimate_width(text: &str, font: &CanvasFont, char_shape: Option<&CharShape>) -> f64 {
+    let (ratio, spacing) = char_shape
+        .map(|cs| {
+            let ratio = if cs.ratio.hangul > 0 {
+                cs.ratio.hangul as f64 / 100.0
+            } else {
+                1.0
+            };
+            (ratio, cs.spacing.hangul as f64 / 100.0)
+        })
+        .unwrap_or((1.0, 0.0));
+    text.chars()
+        .map(|ch| {
+            let em = if is_wide(ch) {
+                1.0
+            } else if ch == ' ' {
+                0.33
+            } else {
+                0.55
+            };
+            font.size * (em * ratio + spacing)
+        })
+        .sum()
+}
+
+fn is_wide(ch: char) -> bool {
+    matches!(
+        ch as u32,
+        0x1100..=0x11FF
+            | 0x2E80..=0x303F
+            | 0x3040..=0x30FF
+            | 0x3130..=0x318F
+            | 0x3400..=0x9FFF
+            | 0xAC00..=0xD7AF
+            | 0xF900..=0xFAFF
+            | 0xFF00..=0xFF60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn font(size: f64) -> CanvasFont {
+        CanvasFont {
+            family: DEFAULT_FONT_FAMILY.to_string(),
+            size,
+            bold: false,
+            italic: false,
+        }
+    }
+
+    #[test]
+    fn test_estimate_width_wide_and_narrow() {
+        let f = font(4.0);
+        assert!((estimate_width("가나", &f, None) - 8.0).abs() < 1e-9);
+        assert!((estimate_width("ab", &f, None) - 4.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_shape_id_at() {
+        let shapes = vec![
+            FlatCharShapeInfo {
+                position: 0,
+                shape_id: 3,
+            },
+            FlatCharShapeInfo {
+                position: 5,
+                shape_id: 7,
+            },
+        ];
+        assert_eq!(shape_id_at(&shapes, 0), 3);
+        assert_eq!(shape_id_at(&shapes, 4), 3);
+        assert_eq!(shape_id_at(&shapes, 5), 7);
+        assert_eq!(shape_id_at(&[], 2), 0);
+    }
+}
@@ -0,0 +1,700 @@
+/// 차트 데이터 → SVG 요소
+/// 막대/꺾은선/영역/원형/도넛/분산형/방사형을 개체 크기(mm)에 맞춰 그림
+///
+/// 한글의 차트 서식(글꼴, 축 눈금 설정, 데이터 레이블 등)은 재현하지 않고,
+/// 계열 값과 색, 제목, 축 제목, 범례만으로 단순한 그래프를 그립니다.
+use super::shape_svg::{css_color, num};
+use crate::viewer::doc_utils::html_escape;
+use hwp_model::chart::{Chart, ChartGrouping, ChartType};
+use std::f64::consts::PI;
+use std::fmt::Write;
+
+/// 계열 색이 없을 때 쓰는 기본 색 (오피스 기본 테마 순서)
+const PALETTE: [u32; 6] = [0x4F81BD, 0xC0504D, 0x9BBB59, 0x8064A2, 0x4BACC6, 0xF79646];
+/// 레이블 글자 크기 (mm)
+const FONT: f64 = 2.5;
+/// 제목 글자 크기 (mm)
+const TITLE_FONT: f64 = 3.5;
+/// 바깥 여백 (mm)
+const PAD: f64 = 1.5;
+
+#[derive(Clone, Copy)]
+struct Area {
+    x: f64,
+    y: f64,
+    w: f64,
+    h: f64,
+}
+
+/// 차트를 `width` x `height` mm 영역에 그린 SVG 요소들
+pub(crate) fn render_chart(chart: &Chart, width: f64, height: f64) -> String {
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        r##"<rect x="0" y="0" width="{}" height="{}" fill="#FFFFFF" stroke="#BFBFBF" stroke-width="0.12"/>"##,
+        num(width),
+        num(height)
+    );
+
+    let mut top = PAD;
+    if let Some(title) = chart.title.as_deref().filter(|t| !t.trim().is_empty()) {
+        text(
+            &mut out,
+            width / 2.0,
+            top + TITLE_FONT * 0.8,
+            TITLE_FONT,
+            "middle",
+            title,
+        );
+        top += TITLE_FONT + PAD;
+    }
+
+    let mut right = width - PAD;
+    let labels = legend_labels(chart);
+    if chart.legend && !labels.is_empty() {
+        let legend_w = (labels
+            .iter()
+            .map(|(label, _)| text_width(label, FONT))
+            .fold(0.0, f64::max)
+            + FONT * 1.5)
+            .min(width * 0.35);
+        right -= legend_w;
+        let line_h = FONT * 1.5;
+        let mut y = top + ((height - top - PAD) - line_h * labels.len() as f64).max(0.0) / 2.0;
+        for (label, color) in &labels {
+            let _ = write!(
+                out,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                num(right),
+                num(y + FONT * 0.2),
+                num(FONT * 0.8),
+                num(FONT * 0.8),
+                css_color(*color)
+            );
+            text(&mut out, right + FONT * 1.2, y + FONT, FONT, "start", label);
+            y += line_h;
+        }
+        right -= PAD;
+    }
+
+    let area = Area {
+        x: PAD,
+        y: top,
+        w: right - PAD,
+        h: height - PAD - top,
+    };
+    if area.w <= FONT || area.h <= FONT {
+        return out;
+    }
+    match chart.chart_type {
+        ChartType::Pie | ChartType::Doughnut => pie(&mut out, chart, area),
+        ChartType::Radar => radar(&mut out, chart, area),
+        _ => axis_chart(&mut out, chart, area),
+    }
+    out
+}
+
+/// 범례 항목 (원형/도넛은 항목별, 나머지는 계열별)
+fn legend_labels(chart: &Chart) -> Vec<(String, u32)> {
+    if matches!(chart.chart_type, ChartType::Pie | ChartType::Doughnut) {
+        return chart
+            .categories
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.clone(), PALETTE[i % PALETTE.len()]))
+            .collect();
+    }
+    chart
+        .series
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            let name = s.name.clone().unwrap_or_else(|| format!("계열{}", i + 1));
+            (name, series_color(chart, i))
+        })
+        .collect()
+}
+
+fn series_color(chart: &Chart, index: usize) -> u32 {
+    chart.series[index]
+        .color
+        .unwrap_or(PALETTE[index % PALETTE.len()])
+}
+
+fn category_count(chart: &Chart) -> usize {
+    chart
+        .series
+        .iter()
+        .map(|s| s.values.len())
+        .fold(chart.categories.len(), usize::max)
+}
+
+fn value(chart: &Chart, series: usize, category: usize) -> f64 {
+    chart.series[series]
+        .values
+        .get(category)
+        .copied()
+        .flatten()
+        .filter(|v| v.is_finite())
+        .unwrap_or(0.0)
+}
+
+// ── 축이 있는 차트 (막대/꺾은선/영역/분산형) ──
+
+fn axis_chart(out: &mut String, chart: &Chart, area: Area) {
+    let count = category_count(chart);
+    if count == 0 || chart.series.is_empty() {
+        return;
+    }
+    let horizontal = chart.chart_type == ChartType::Bar;
+    let stacked = matches!(
+        chart.grouping,
+        ChartGrouping::Stacked | ChartGrouping::PercentStacked
+    ) && chart.chart_type != ChartType::Scatter;
+    let percent = stacked && chart.grouping == ChartGrouping::PercentStacked;
+
+    // 누적이면 항목별 합계 기준으로 값 변환
+    let totals: Vec<f64> = (0..count)
+        .map(|c| {
+            (0..chart.series.len())
+                .map(|s| value(chart, s, c).abs())
+                .sum()
+        })
+        .collect();
+    let scaled = |s: usize, c: usize| {
+        let v = value(chart, s, c);
+        if percent && totals[c] > 0.0 {
+            v / totals[c] * 100.0
+        } else {
+            v
+        }
+    };
+    // 계열별 누적 시작값 (양수/음수 따로)
+    let mut bases = vec![vec![0.0; count]; chart.series.len()];
+    let (mut lo, mut hi) = (0.0f64, 0.0f64);
+    for c in 0..count {
+        let (mut pos, mut neg) = (0.0, 0.0);
+        for (s, base) in bases.iter_mut().enumerate() {
+            let v = scaled(s, c);
+            if stacked {
+                base[c] = if v >= 0.0 { pos } else { neg };
+                if v >= 0.0 {
+                    pos += v;
+                } else {
+                    neg += v;
+                }
+                hi = hi.max(pos);
+                lo = lo.min(neg);
+            } else {
+                hi = hi.max(v);
+                lo = lo.min(v);
+            }
+        }
+    }
+    let (lo, hi, step) = nice_range(lo, hi);
+    let ticks: Vec<f64> = (0..)
+        .map(|i| lo + step * i as f64)
+        .take_while(|v| *v <= hi + step * 1e-6)
+        .collect();
+
+    // 분산형: 항목을 숫자 X 값으로 (숫자가 아니면 순번)
+    let xs: Option<Vec<f64>> = (chart.chart_type == ChartType::Scatter).then(|| {
+        (0..count)
+            .map(|c| {
+                chart
+                    .categories
+                    .get(c)
+                    .and_then(|v| v.trim().parse().ok())
+                    .unwrap_or((c + 1) as f64)
+            })
+            .collect()
+    });
+
+    // 축 레이블/제목 자리
+    let tick_labels: Vec<String> = ticks.iter().map(|v| format_value(*v)).collect();
+    let category_labels: Vec<String> = (0..count)
+        .map(|c| chart.categories.get(c).cloned().unwrap_or_default())
+        .collect();
+    let mut plot = area;
+    let (bottom_title, left_title) = if horizontal {
+        (&chart.value_axis_title, &chart.category_axis_title)
+    } else {
+        (&chart.category_axis_title, &chart.value_axis_title)
+    };
+    if let Some(title) = bottom_title {
+        text(
+            out,
+            plot.x + plot.w / 2.0,
+            plot.y + plot.h - FONT * 0.2,
+            FONT,
+            "middle",
+            title,
+        );
+        plot.h -= FONT * 1.4;
+    }
+    if let Some(title) = left_title {
+        let (x, y) = (plot.x + FONT * 0.8, plot.y + plot.h / 2.0);
+        let _ = write!(
+            out,
+            r##"<text x="{x}" y="{y}" font-size="{}" text-anchor="middle" fill="#404040" transform="rotate(-90 {x} {y})">{}</text>"##,
+            num(FONT),
+            html_escape(title),
+            x = num(x),
+            y = num(y),
+        );
+        plot.x += FONT * 1.4;
+        plot.w -= FONT * 1.4;
+    }
+    let left_labels = if horizontal {
+        &category_labels
+    } else {
+        &tick_labels
+    };
+    let left_w = left_labels
+        .iter()
+        .map(|l| text_width(l, FONT))
+        .fold(0.0, f64::max)
+        .min(plot.w * 0.3)
+        + 1.0;
+    plot.x += left_w;
+    plot.w -= left_w;
+    plot.h -= FONT * 1.4;
+    if plot.w <= 0.0 || plot.h <= 0.0 {
+        return;
+    }
+
+    // 값 → 좌표 (세로형은 y, 가로 막대는 x)
+    let value_pos = |v: f64| {
+        let t = (v - lo) / (hi - lo);
+        if horizontal {
+            plot.x + t * plot.w
+        } else {
+            plot.y + plot.h - t * plot.h
+        }
+    };
+    // 항목 → (시작, 폭)
+    let band = |c: usize| {
+        let size = if horizontal { plot.h } else { plot.w } / count as f64;
+        let start = if horizontal { plot.y } else { plot.x };
+        (start + size * c as f64, size)
+    };
+    let x_range = xs.as_ref().map(|xs| {
+        let min = xs.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = xs.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        nice_range(min.min(0.0), max)
+    });
+    let category_pos = |c: usize| match (&xs, x_range) {
+        (Some(xs), Some((min, max, _))) => plot.x + (xs[c] - min) / (max - min) * plot.w,
+        _ => {
+            let (start, size) = band(c);
+            start + size / 2.0
+        }
+    };
+
+    // 눈금선 + 값 레이블
+    for (v, label) in ticks.iter().zip(&tick_labels) {
+        let p = value_pos(*v);
+        if horizontal {
+            grid_line(out, p, plot.y, p, plot.y + plot.h);
+            text(out, p, plot.y + plot.h + FONT * 1.1, FONT, "middle", label);
+        } else {
+            grid_line(out, plot.x, p, plot.x + plot.w, p);
+            text(out, plot.x - 0.8, p + FONT * 0.35, FONT, "end", label);
+        }
+    }
+    // 항목 레이블
+    for (c, label) in category_labels.iter().enumerate() {
+        if label.is_empty() {
+            continue;
+        }
+        if horizontal {
+            let (start, size) = band(c);
+            let y = start + size / 2.0 + FONT * 0.35;
+            text(out, plot.x - 0.8, y, FONT, "end", label);
+        } else if xs.is_none() {
+            let y = plot.y + plot.h + FONT * 1.1;
+            text(out, category_pos(c), y, FONT, "middle", label);
+        }
+    }
+    if let Some((min, max, step)) = x_range {
+        let mut x = min;
+        while x <= max + step * 1e-6 {
+            let p = plot.x + (x - min) / (max - min) * plot.w;
+            let y = plot.y + plot.h + FONT * 1.1;
+            text(out, p, y, FONT, "middle", &format_value(x));
+            x += step;
+        }
+    }
+
+    // 계열
+    let series_count = chart.series.len();
+    for s in 0..series_count {
+        let color = css_color(series_color(chart, s));
+        match chart.chart_type {
+            ChartType::Column | ChartType::Bar => {
+                for c in 0..count {
+                    let (start, size) = band(c);
+                    let group = size * 0.7;
+                    let (offset, thickness) = if stacked {
+                        (0.0, group)
+                    } else {
+                        let w = group / series_count as f64;
+                        (w * s as f64, w)
+                    };
+                    let base = if stacked { bases[s][c] } else { 0.0 };
+                    let (a, b) = (value_pos(base), value_pos(base + scaled(s, c)));
+                    let along = start + (size - group) / 2.0 + offset;
+                    let (x, y, w, h) = if horizontal {
+                        (a.min(b), along, (b - a).abs(), thickness)
+                    } else {
+                        (along, a.min(b), thickness, (b - a).abs())
+                    };
+                    let _ = write!(
+                        out,
+                        r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                        num(x),
+                        num(y),
+                        num(w),
+                        num(h),
+                        color
+                    );
+                }
+            }
+            ChartType::Line | ChartType::Area => {
+                let base = |c: usize| if stacked { bases[s][c] } else { 0.0 };
+                let top: Vec<String> = (0..count)
+                    .map(|c| point(category_pos(c), value_pos(base(c) + scaled(s, c))))
+                    .collect();
+                if chart.chart_type == ChartType::Area {
+                    let bottom: Vec<String> = (0..count)
+                        .rev()
+                        .map(|c| point(category_pos(c), value_pos(base(c))))
+                        .collect();
+                    let _ = write!(
+                        out,
+                        r#"<polygon points="{} {}" fill="{}" fill-opacity="0.8"/>"#,
+                        top.join(" "),
+                        bottom.join(" "),
+                        color
+                    );
+                } else {
+                    let _ = write!(
+                        out,
+                        r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="0.5"/>"#,
+                        top.join(" "),
+                        color
+                    );
+                }
+            }
+            _ => {
+                for c in 0..count {
+                    if chart.series[s].values.get(c).copied().flatten().is_none() {
+                        continue;
+                    }
+                    let _ = write!(
+                        out,
+                        r#"<circle cx="{}" cy="{}" r="0.8" fill="{}"/>"#,
+                        num(category_pos(c)),
+                        num(value_pos(scaled(s, c))),
+                        color
+                    );
+                }
+            }
+        }
+    }
+
+    // 항목 축 (값 0 위치)
+    let zero = value_pos(0.0_f64.clamp(lo, hi));
+    let axis = if horizontal {
+        (zero, plot.y, zero, plot.y + plot.h)
+    } else {
+        (plot.x, zero, plot.x + plot.w, zero)
+    };
+    let _ = write!(
+        out,
+        r##"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="#808080" stroke-width="0.2"/>"##,
+        num(axis.0),
+        num(axis.1),
+        num(axis.2),
+        num(axis.3)
+    );
+}
+
+// ── 원형/도넛 ──
+
+fn pie(out: &mut String, chart: &Chart, area: Area) {
+    let Some(series) = chart.series.first() else {
+        return;
+    };
+    let values: Vec<f64> = series
+        .values
+        .iter()
+        .map(|v| v.filter(|v| v.is_finite() && *v > 0.0).unwrap_or(0.0))
+        .collect();
+    let total: f64 = values.iter().sum();
+    if total <= 0.0 {
+        return;
+    }
+    let (cx, cy) = (area.x + area.w / 2.0, area.y + area.h / 2.0);
+    let r = area.w.min(area.h) / 2.0;
+    let inner = if chart.chart_type == ChartType::Doughnut {
+        r * 0.5
+    } else {
+        0.0
+    };
+    let mut angle = -PI / 2.0;
+    for (i, v) in values.iter().enumerate().filter(|(_, v)| **v > 0.0) {
+        let color = css_color(PALETTE[i % PALETTE.len()]);
+        let sweep = v / total * 2.0 * PI;
+        if sweep >= 2.0 * PI - 1e-9 {
+            // 한 항목이 전부면 원 (도넛은 굵은 테두리)
+            let _ = if inner > 0.0 {
+                write!(
+                    out,
+                    r#"<circle cx="{}" cy="{}" r="{}" fill="none" stroke="{}" stroke-width="{}"/>"#,
+                    num(cx),
+                    num(cy),
+                    num((r + inner) / 2.0),
+                    color,
+                    num(r - inner)
+                )
+            } else {
+                write!(
+                    out,
+                    r#"<circle cx="{}" cy="{}" r="{}" fill="{}"/>"#,
+                    num(cx),
+                    num(cy),
+                    num(r),
+                    color
+                )
+            };
+            return;
+        }
+        let end = angle + sweep;
+        let large = if sweep > PI { 1 } else { 0 };
+        let at = |radius: f64, a: f64| point(cx + radius * a.cos(), cy + radius * a.sin());
+        let d = if inner > 0.0 {
+            format!(
+                "M{} A{r},{r} 0 {large} 1 {} L{} A{i},{i} 0 {large} 0 {} Z",
+                at(r, angle),
+                at(r, end),
+                at(inner, end),
+                at(inner, angle),
+                r = num(r),
+                i = num(inner),
+            )
+        } else {
+            format!(
+                "M{} L{} A{r},{r} 0 {large} 1 {} Z",
+                point(cx, cy),
+                at(r, angle),
+                at(r, end),
+                r = num(r),
+            )
+        };
+        let _ = write!(
+            out,
+            r##"<path d="{}" fill="{}" stroke="#FFFFFF" stroke-width="0.2"/>"##,
+            d, color
+        );
+        angle = end;
+    }
+}
+
+// ── 방사형 ──
+
+fn radar(out: &mut String, chart: &Chart, area: Area) {
+    let count = category_count(chart);
+    if count < 3 {
+        return axis_chart(out, chart, area);
+    }
+    let max = (0..chart.series.len())
+        .flat_map(|s| (0..count).map(move |c| (s, c)))
+        .map(|(s, c)| value(chart, s, c))
+        .fold(0.0, f64::max);
+    let (_, hi, step) = nice_range(0.0, max);
+    let (cx, cy) = (area.x + area.w / 2.0, area.y + area.h / 2.0);
+    let r = (area.w.min(area.h) / 2.0 - FONT * 1.5).max(FONT);
+    let at = |c: usize, radius: f64| {
+        let a = -PI / 2.0 + 2.0 * PI * c as f64 / count as f64;
+        (cx + radius * a.cos(), cy + radius * a.sin())
+    };
+
+    let mut level = step;
+    while level <= hi + step * 1e-6 {
+        let points: Vec<String> = (0..count)
+            .map(|c| {
+                let (x, y) = at(c, r * level / hi);
+                point(x, y)
+            })
+            .collect();
+        let _ = write!(
+            out,
+            r##"<polygon points="{}" fill="none" stroke="#D9D9D9" stroke-width="0.12"/>"##,
+            points.join(" ")
+        );
+        level += step;
+    }
+    for c in 0..count {
+        let (x, y) = at(c, r);
+        grid_line(out, cx, cy, x, y);
+        if let Some(label) = chart.categories.get(c) {
+            let (lx, ly) = at(c, r + FONT);
+            text(out, lx, ly + FONT * 0.35, FONT, "middle", label);
+        }
+    }
+    for s in 0..chart.series.len() {
+        let points: Vec<String> = (0..count)
+            .map(|c| {
+                let (x, y) = at(c, r * value(chart, s, c).max(0.0) / hi);
+                point(x, y)
+            })
+            .collect();
+        let _ = write!(
+            out,
+            r#"<polygon points="{}" fill="none" stroke="{}" stroke-width="0.5"/>"#,
+            points.join(" "),
+            css_color(series_color(chart, s))
+        );
+    }
+}
+
+// ── 공통 ──
+
+/// 보기 좋은 눈금 범위 (최소, 최대, 간격)
+fn nice_range(lo: f64, hi: f64) -> (f64, f64, f64) {
+    let (lo, hi) = if hi - lo < 1e-9 {
+        (lo.min(0.0), lo.max(0.0) + 1.0)
+    } else {
+        (lo, hi)
+    };
+    let raw = (hi - lo) / 5.0;
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let step = [1.0, 2.0, 5.0, 10.0]
+        .iter()
+        .map(|m| m * magnitude)
+        .find(|s| *s >= raw)
+        .unwrap_or(10.0 * magnitude);
+    ((lo / step).floor() * step, (hi / step).ceil() * step, step)
+}
+
+/// 눈금 값 표시 (불필요한 소수점 제거)
+fn format_value(value: f64) -> String {
+    let rounded = (value * 100.0).round() / 100.0;
+    if rounded == rounded.trunc() {
+        format!("{}", rounded as i64)
+    } else {
+        rounded.to_string()
+    }
+}
+
+/// 글자 폭 추정 (mm, 전각은 글자 크기, 반각은 절반 남짓)
+fn text_width(text: &str, size: f64) -> f64 {
+    text.chars()
+        .map(|c| if c.is_ascii() { 0.55 } else { 1.0 })
+        .sum::<f64>()
+        * size
+}
+
+fn text(out: &mut String, x: f64, y: f64, size: f64, anchor: &str, content: &str) {
+    let _ = write!(
+        out,
+        r##"<text x="{}" y="{}" font-size="{}" text-anchor="{}" fill="#404040">{}</text>"##,
+        num(x),
+        num(y),
+        num(size),
+        anchor,
+        html_escape(content)
+    );
+}
+
+fn grid_line(out: &mut String, x1: f64, y1: f64, x2: f64, y2: f64) {
+    let _ = write!(
+        out,
+        r##"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="#D9D9D9" stroke-width="0.12"/>"##,
+        num(x1),
+        num(y1),
+        num(x2),
+        num(y2)
+    );
+}
+
+fn point(x: f64, y: f64) -> String {
+    format!("{},{}", num(x), num(y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hwp_model::chart::ChartSeries;
+
+    fn chart(chart_type: ChartType) -> Chart {
+        Chart {
+            chart_type,
+            title: Some("분기 <매출>".to_string()),
+            categories: vec![
+                "1분기".to_string(),
+                "2분기".to_string(),
+                "3분기".to_string(),
+            ],
+            series: vec![
+                ChartSeries {
+                    name: Some("A".to_string()),
+                    values: vec![Some(10.0), Some(20.0), None],
+                    color: Some(0x112233),
+                },
+                ChartSeries {
+                    name: None,
+                    values: vec![Some(5.0), Some(5.0), Some(30.0)],
+                    color: None,
+                },
+            ],
+            legend: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_render_column_chart() {
+        let svg = render_chart(&chart(ChartType::Column), 80.0, 50.0);
+        assert!(svg.contains(">분기 &lt;매출&gt;</text>"));
+        // 범례: 이름 없는 계열은 "계열N", 색이 없으면 기본 팔레트
+        assert!(svg.contains(">계열2</text>"));
+        assert!(svg.contains(r##"fill="#C0504D""##));
+        // 계열 A의 막대 2개 (빈 값은 높이 0)
+        assert_eq!(svg.matches(r##"fill="#112233"/>"##).count(), 3 + 1);
+        assert!(svg.contains(">1분기</text>"));
+        assert!(svg.contains(">30</text>"));
+    }
+
+    #[test]
+    fn test_render_other_chart_types() {
+        let line = render_chart(&chart(ChartType::Line), 80.0, 50.0);
+        assert_eq!(line.matches("<polyline").count(), 2);
+
+        let mut stacked = chart(ChartType::Area);
+        stacked.grouping = ChartGrouping::PercentStacked;
+        let area = render_chart(&stacked, 80.0, 50.0);
+        assert_eq!(area.matches("<polygon").count(), 2);
+        assert!(area.contains(">100</text>"));
+
+        let pie = render_chart(&chart(ChartType::Pie), 80.0, 50.0);
+        assert_eq!(pie.matches("<path").count(), 2);
+        // 원형 범례는 항목별
+        assert!(pie.contains(">3분기</text>"));
+
+        let radar = render_chart(&chart(ChartType::Radar), 80.0, 50.0);
+        assert!(radar.contains(r##"stroke="#112233""##));
+
+        let scatter = render_chart(&chart(ChartType::Scatter), 80.0, 50.0);
+        assert_eq!(scatter.matches("<circle").count(), 5);
+    }
+
+    #[test]
+    fn test_nice_range() {
+        assert_eq!(nice_range(0.0, 30.0), (0.0, 30.0, 10.0));
+        assert_eq!(nice_range(-3.0, 18.0), (-5.0, 20.0, 5.0));
+        assert_eq!(nice_range(0.0, 0.0), (0.0, 1.0, 0.2));
+        assert_eq!(format_value(0.30000000000000004), "0.3");
+    }
+}
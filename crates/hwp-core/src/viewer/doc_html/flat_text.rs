@@ -109,6 +109,9 @@ pub fn extract_flat_text(para: &Paragraph) -> FlatTextResult {
                                 wchar_pos += 1;
                                 original_wchar_pos += 1;
                             }
+                            _ if elem.revision_mark().is_some() => {
+                                // 변경 추적 표시는 원본 텍스트에서 자리를 차지하지 않음
+                            }
                             _ => {
                                 // 기타 TextElement도 원본 위치 증가 (ColumnBreak 등)
                                 original_wchar_pos += 1;
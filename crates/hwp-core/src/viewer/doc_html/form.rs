@@ -0,0 +1,127 @@
+/// Document 기반 양식 개체 → HTML 입력 요소 렌더러
+/// 선택 상자/라디오 단추/입력 상자/목록 상자/명령 단추를 `<input>`/`<select>`/`<textarea>`/`<button>`으로 그림
+///
+/// 값을 읽을 때의 키는 `name` 속성입니다 (라디오 단추는 묶음 이름, 값은 단추 이름).
+/// 이 규칙은 `hwp_model::form`의 `Document::form_values` / `apply_form_values`와 같습니다.
+use super::shape_svg::css_color;
+use super::styles::{hwpunit_to_mm, round_mm};
+use crate::viewer::doc_utils::html_escape;
+use hwp_model::shape::{FormCheckState, FormObject, FormType};
+
+/// 양식 개체 → 인라인 HTML
+pub(super) fn render_form_html(form: &FormObject, prefix: &str) -> String {
+    let name = html_escape(&form.name);
+    let style = form_style(form);
+    let disabled = if form.enabled { "" } else { " disabled" };
+    let caption = html_escape(form.caption.as_deref().unwrap_or(&form.name));
+
+    match form.form_type {
+        FormType::CheckBox | FormType::RadioButton => {
+            let (input_type, input_name, value) = if form.form_type == FormType::CheckBox {
+                ("checkbox", name.clone(), "on".to_string())
+            } else {
+                let group = form.group_name.as_deref().unwrap_or(&form.name);
+                ("radio", html_escape(group), name.clone())
+            };
+            let checked = match form.check_state {
+                FormCheckState::Checked => " checked",
+                FormCheckState::Indeterminate => " data-indeterminate=\"true\"",
+                FormCheckState::Unchecked => "",
+            };
+            format!(
+                "<label class=\"{}form-{}\" style=\"{}\"><input type=\"{}\" name=\"{}\" value=\"{}\"{}{}> {}</label>",
+                prefix, input_type, style, input_type, input_name, value, checked, disabled, caption
+            )
+        }
+        FormType::ComboBox | FormType::ListBox => {
+            let size = if form.form_type == FormType::ListBox {
+                format!(" size=\"{}\"", form.items.len().max(2))
+            } else {
+                String::new()
+            };
+            let selected = form.value.as_deref();
+            let mut html = format!(
+                "<select class=\"{}form-select\" name=\"{}\"{}{} style=\"{}\">",
+                prefix, name, size, disabled, style
+            );
+            let has_selected = form.items.iter().any(|item| Some(&*item.value) == selected);
+            if let (Some(value), false) = (selected, has_selected) {
+                // 목록에 없는 값을 직접 입력한 경우
+                html.push_str(&format!(
+                    "<option value=\"{0}\" selected>{0}</option>",
+                    html_escape(value)
+                ));
+            }
+            for item in &form.items {
+                html.push_str(&format!(
+                    "<option value=\"{}\"{}>{}</option>",
+                    html_escape(&item.value),
+                    if Some(&*item.value) == selected {
+                        " selected"
+                    } else {
+                        ""
+                    },
+                    html_escape(&item.display_text)
+                ));
+            }
+            html.push_str("</select>");
+            html
+        }
+        FormType::Edit => {
+            let text = html_escape(form.value.as_deref().unwrap_or_default());
+            let mut attrs = String::new();
+            if let Some(max) = form.max_length {
+                attrs.push_str(&format!(" maxlength=\"{}\"", max));
+            }
+            if form.read_only {
+                attrs.push_str(" readonly");
+            }
+            attrs.push_str(disabled);
+            if form.multi_line {
+                format!(
+                    "<textarea class=\"{}form-edit\" name=\"{}\"{} style=\"{}\">{}</textarea>",
+                    prefix, name, attrs, style, text
+                )
+            } else {
+                let input_type = if form.password_char.is_some() {
+                    "password"
+                } else {
+                    "text"
+                };
+                format!(
+                    "<input class=\"{}form-edit\" type=\"{}\" name=\"{}\" value=\"{}\"{} style=\"{}\">",
+                    prefix, input_type, name, text, attrs, style
+                )
+            }
+        }
+        FormType::ScrollBar => format!(
+            "<input class=\"{}form-scrollbar\" type=\"range\" name=\"{}\" value=\"{}\"{} style=\"{}\">",
+            prefix,
+            name,
+            html_escape(form.value.as_deref().unwrap_or("0")),
+            disabled,
+            style
+        ),
+        FormType::PushButton => format!(
+            "<button class=\"{}form-button\" type=\"button\" name=\"{}\"{} style=\"{}\">{}</button>",
+            prefix, name, disabled, style, caption
+        ),
+    }
+}
+
+/// 개체 크기와 글자/배경 색
+fn form_style(form: &FormObject) -> String {
+    let size = &form.common.size;
+    let mut style = format!(
+        "display: inline-block; width: {:.2}mm; height: {:.2}mm",
+        round_mm(hwpunit_to_mm(size.width)),
+        round_mm(hwpunit_to_mm(size.height))
+    );
+    if let Some(color) = form.fore_color {
+        style.push_str(&format!("; color: {}", css_color(color)));
+    }
+    if let Some(color) = form.back_color {
+        style.push_str(&format!("; background-color: {}", css_color(color)));
+    }
+    style
+}
@@ -47,10 +47,11 @@ fn get_image_src(binary_item_id: &str, binaries: &BinaryStore) -> String {
         if item.data.is_empty() {
             return item.src.clone();
         }
-        let mime = doc_utils::image_format_to_mime(&item.format);
+        // WMF/EMF는 SVG로 변환
+        let (data, mime) = doc_utils::displayable_image(&item.format, &item.data, false);
         let b64 = {
             use base64::Engine;
-            base64::engine::general_purpose::STANDARD.encode(&item.data)
+            base64::engine::general_purpose::STANDARD.encode(data)
         };
         format!("data:{};base64,{}", mime, b64)
     } else {
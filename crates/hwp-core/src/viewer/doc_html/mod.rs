@@ -1,6 +1,8 @@
 /// Document(hwp-model) 기반 HTML viewer
 /// HWP/HWPX 양쪽에서 생성된 Document를 HTML로 변환
+mod chart_svg;
 pub(crate) mod flat_text;
+mod form;
 pub(crate) mod layout_image;
 pub(crate) mod layout_line_segment;
 pub(crate) mod layout_page;
@@ -8,9 +10,15 @@ pub(crate) mod layout_pagination;
 pub(crate) mod layout_table;
 pub(crate) mod layout_text;
 mod paragraph;
+pub(crate) mod shape_svg;
 pub(crate) mod styles;
 
 use hwp_model::document::Document;
+use hwp_model::outline::OutlineEntry;
+
+use crate::viewer::core::outline::format_outline_number;
+use crate::viewer::doc_utils::{html_escape, with_evaluated_fields};
+use crate::viewer::revision::{apply_revision_view, RevisionView};
 
 /// HTML 변환 옵션
 #[derive(Debug, Clone)]
@@ -24,6 +32,15 @@ pub struct DocHtmlOptions {
     /// 레이아웃 모드: true이면 pixel-accurate 레이아웃 (hpa/hls/hcD 구조)
     /// false이면 시맨틱 HTML (기본값)
     pub layout: bool,
+    /// WMF/EMF 그림을 원본 그대로 유지할지 여부 (기본값 false: 브라우저가 표시할 수 있도록 SVG로 변환)
+    pub keep_metafile: bool,
+    /// 변경 추적 보기 모드 (기본값: 변경 표시).
+    /// 레이아웃 모드는 저장된 줄 배치를 따르므로 변경 표시 대신 최종본으로 그린다.
+    pub revision_view: RevisionView,
+    /// 계산식/문서 요약/상호 참조 필드 결과를 다시 계산해서 그릴지 여부 (기본값 true)
+    pub evaluate_fields: bool,
+    /// 개요 제목으로 만든 차례(`<nav>`)를 본문 앞에 넣을지 여부 (기본값 false, 시맨틱 모드만)
+    pub table_of_contents: bool,
 }
 
 impl Default for DocHtmlOptions {
@@ -33,16 +50,30 @@ impl Default for DocHtmlOptions {
             inline_style: true,
             image_output_dir: None,
             layout: false,
+            keep_metafile: false,
+            revision_view: RevisionView::Markup,
+            evaluate_fields: true,
+            table_of_contents: false,
         }
     }
 }
 
 /// Document를 HTML로 변환
 pub fn doc_to_html(doc: &Document, options: &DocHtmlOptions) -> String {
+    // 레이아웃 모드는 저장된 줄 배치를 따르므로 변경 표시 대신 최종본으로 그린다
+    let view = match options.revision_view {
+        RevisionView::Markup if options.layout => RevisionView::Final,
+        view => view,
+    };
+    let mut doc = apply_revision_view(doc, view);
+    if options.evaluate_fields {
+        doc = with_evaluated_fields(doc);
+    }
     if options.layout {
-        return doc_to_html_layout(doc, options);
+        doc_to_html_layout(&doc, options)
+    } else {
+        doc_to_html_semantic(&doc, options)
     }
-    doc_to_html_semantic(doc, options)
 }
 
 /// 레이아웃 모드 HTML 생성 (pixel-accurate, hpa/hls/hcD 구조)
@@ -126,6 +157,14 @@ fn doc_to_html_layout(doc: &Document, _options: &DocHtmlOptions) -> String {
                 let page_html =
                     append_footnotes_to_page(page_html, &footnote_blocks, &endnote_blocks, fn_top);
                 _page_number += 1;
+                let page_html = insert_master_page(
+                    page_html,
+                    doc,
+                    &section.definition,
+                    page_def,
+                    _page_number,
+                    pages_html.len() + 1,
+                );
                 pages_html.push(page_html);
                 current_page_blocks.clear();
                 footnote_blocks.clear();
@@ -344,6 +383,14 @@ fn doc_to_html_layout(doc: &Document, _options: &DocHtmlOptions) -> String {
             let page_html =
                 append_footnotes_to_page(page_html, &footnote_blocks, &endnote_blocks, fn_top_last);
             _page_number += 1;
+            let page_html = insert_master_page(
+                page_html,
+                doc,
+                &section.definition,
+                page_def,
+                _page_number,
+                pages_html.len() + 1,
+            );
             pages_html.push(page_html);
         }
     }
@@ -521,6 +568,115 @@ fn generate_heading_marker(
     }
 }
 
+/// 쪽에 적용할 바탕쪽 선택
+///
+/// 첫 쪽(임의 쪽) 바탕쪽이 우선하고, 다음으로 문서 쪽 번호의 홀짝에 맞는 바탕쪽, 마지막으로 양쪽 바탕쪽.
+fn select_master_page(
+    def: &hwp_model::section::SectionDef,
+    section_page: usize,
+    doc_page: usize,
+) -> Option<&hwp_model::section::MasterPage> {
+    use hwp_model::types::PageApplyType;
+
+    let hide_first = def
+        .visibility
+        .as_ref()
+        .is_some_and(|v| v.hide_first_master_page);
+    if hide_first && section_page == 1 {
+        return None;
+    }
+    let find = |apply: PageApplyType| {
+        def.master_pages
+            .iter()
+            .find(|mp| mp.apply_page_type == apply)
+    };
+    def.master_pages
+        .iter()
+        .find(|mp| {
+            mp.apply_page_type == PageApplyType::First
+                && mp.page_number.max(1) as usize == section_page
+        })
+        .or_else(|| {
+            find(if doc_page % 2 == 0 {
+                PageApplyType::Even
+            } else {
+                PageApplyType::Odd
+            })
+        })
+        .or_else(|| find(PageApplyType::Both))
+}
+
+/// 바탕쪽을 페이지 맨 앞(본문 뒤)에 삽입
+fn insert_master_page(
+    mut page_html: String,
+    doc: &Document,
+    def: &hwp_model::section::SectionDef,
+    page_def: &hwp_model::section::PageDef,
+    section_page: usize,
+    doc_page: usize,
+) -> String {
+    let Some(master) = select_master_page(def, section_page, doc_page) else {
+        return page_html;
+    };
+    let master_html = render_master_page_layout(master, doc, page_def);
+    if master_html.is_empty() {
+        return page_html;
+    }
+    if let Some(pos) = page_html.find('>') {
+        page_html.insert_str(pos + 1, &master_html);
+    }
+    page_html
+}
+
+/// 바탕쪽 내용: 글은 본문 영역(hcD)에, 그림/도형은 쪽 절대 좌표에 배치
+fn render_master_page_layout(
+    master: &hwp_model::section::MasterPage,
+    doc: &Document,
+    page_def: &hwp_model::section::PageDef,
+) -> String {
+    use hwp_model::paragraph::RunContent;
+    use hwp_model::shape::ShapeObject;
+
+    let paragraphs = &master.content.paragraphs;
+    let mut html = String::new();
+    let text_html = render_sublist_layout(paragraphs, &doc.resources);
+    if !text_html.is_empty() {
+        html.push_str(&format!(
+            r#"<div class="hcD" style="left:{};top:{};"><div class="hcI">{}</div></div>"#,
+            styles::fmt_mm(layout_page::content_left_abs_mm(page_def)),
+            styles::fmt_mm(layout_page::content_top_abs_mm(page_def)),
+            text_html
+        ));
+    }
+
+    for content in paragraphs
+        .iter()
+        .flat_map(|p| &p.runs)
+        .flat_map(|r| &r.contents)
+    {
+        let RunContent::Object(shape) = content else {
+            continue;
+        };
+        html.push_str(&match shape {
+            ShapeObject::Picture(pic) => layout_image::render_layout_picture(pic, &doc.binaries),
+            ShapeObject::Rectangle(rect) => match rect.draw_text {
+                Some(ref dt) => layout_image::render_layout_textbox(
+                    &rect.common,
+                    &dt.paragraphs,
+                    &doc.resources,
+                ),
+                None => layout_image::render_layout_rect_svg(rect),
+            },
+            ShapeObject::Line(line) => layout_image::render_layout_line(line),
+            ShapeObject::Container(container) => {
+                render_container_layout(container, &doc.resources, &doc.binaries)
+            }
+            _ => String::new(),
+        });
+    }
+    html
+}
+
 /// 페이지 HTML에 각주/미주 블록을 삽입 (</div> 앞)
 fn append_footnotes_to_page(
     mut page_html: String,
@@ -757,6 +913,14 @@ fn doc_to_html_semantic(doc: &Document, options: &DocHtmlOptions) -> String {
         String::new()
     };
 
+    // 차례: 제목 문단 (구역, 문단) → 링크 대상 id
+    let outline = if options.table_of_contents {
+        doc.outline()
+    } else {
+        Vec::new()
+    };
+    let anchors = toc_anchors(&outline, &options.css_class_prefix);
+
     for (section_idx, section) in doc.sections.iter().enumerate() {
         // 섹션 간 구분선
         if section_idx > 0 && !body_parts.is_empty() {
@@ -766,7 +930,7 @@ fn doc_to_html_semantic(doc: &Document, options: &DocHtmlOptions) -> String {
             ));
         }
 
-        for para in &section.paragraphs {
+        for (para_idx, para) in section.paragraphs.iter().enumerate() {
             // 페이지 구분선
             if para.page_break && !body_parts.is_empty() {
                 body_parts.push(format!(
@@ -784,10 +948,13 @@ fn doc_to_html_semantic(doc: &Document, options: &DocHtmlOptions) -> String {
                 &mut endnote_counter,
                 &mut outline_tracker,
                 &mut number_tracker,
+                anchors.get(&(section_idx, para_idx)).map(String::as_str),
             );
 
             // 각주/미주 인라인 참조를 수집 (본문 뒤에 붙임)
             let mut inline_refs = String::new();
+            // 메모는 문단 앞에 띄워 문단 옆 여백에 놓이게 한다
+            let mut memo_asides = String::new();
             for part in ctrl_parts {
                 match part {
                     HtmlControlPart::Header(html) => header_parts.push(html),
@@ -812,8 +979,12 @@ fn doc_to_html_semantic(doc: &Document, options: &DocHtmlOptions) -> String {
                             id, options.css_class_prefix, id, html
                         ));
                     }
+                    HtmlControlPart::Memo(html) => memo_asides.push_str(&html),
                 }
             }
+            if !memo_asides.is_empty() {
+                body_parts.push(memo_asides);
+            }
 
             if !body_html.is_empty() {
                 if !inline_refs.is_empty() {
@@ -850,6 +1021,14 @@ fn doc_to_html_semantic(doc: &Document, options: &DocHtmlOptions) -> String {
         ));
     }
 
+    if !outline.is_empty() {
+        html.push_str(&render_toc_nav(
+            &outline,
+            &anchors,
+            &options.css_class_prefix,
+        ));
+    }
+
     html.push_str("<div class=\"");
     html.push_str(&options.css_class_prefix);
     html.push_str("body\">\n");
@@ -883,12 +1062,78 @@ fn doc_to_html_semantic(doc: &Document, options: &DocHtmlOptions) -> String {
     html
 }
 
+/// 차례 항목(문서 순서)마다 제목 요소에 붙일 id (`{prefix}toc-1`, `{prefix}toc-2`, ...)
+fn toc_anchors(
+    outline: &[OutlineEntry],
+    prefix: &str,
+) -> std::collections::HashMap<(usize, usize), String> {
+    outline
+        .iter()
+        .flat_map(OutlineEntry::iter)
+        .enumerate()
+        .map(|(i, entry)| {
+            (
+                (entry.section, entry.paragraph),
+                format!("{}toc-{}", prefix, i + 1),
+            )
+        })
+        .collect()
+}
+
+/// 차례 `<nav>`: 개요 수준대로 중첩한 목록, 항목마다 본문 제목으로 링크
+fn render_toc_nav(
+    outline: &[OutlineEntry],
+    anchors: &std::collections::HashMap<(usize, usize), String>,
+    prefix: &str,
+) -> String {
+    fn render_list(
+        entries: &[OutlineEntry],
+        anchors: &std::collections::HashMap<(usize, usize), String>,
+        prefix: &str,
+        out: &mut String,
+    ) {
+        out.push_str("<ul>");
+        for entry in entries {
+            out.push_str("<li>");
+            let id = &anchors[&(entry.section, entry.paragraph)];
+            out.push_str(&format!("<a href=\"#{}\">", html_escape(id)));
+            if let Some(number) = entry.number {
+                out.push_str(&format!(
+                    "<span class=\"{}outline-number\">{}</span> ",
+                    prefix,
+                    html_escape(&format_outline_number(entry.level, number))
+                ));
+            }
+            out.push_str(&html_escape(&entry.title));
+            out.push_str("</a>");
+            if !entry.children.is_empty() {
+                render_list(&entry.children, anchors, prefix, out);
+            }
+            out.push_str("</li>");
+        }
+        out.push_str("</ul>");
+    }
+
+    let mut out = format!("<nav class=\"{}toc\">\n", prefix);
+    render_list(outline, anchors, prefix, &mut out);
+    out.push_str("\n</nav>\n");
+    out
+}
+
 /// HTML 컨트롤에서 추출된 문서 부분
 pub(crate) enum HtmlControlPart {
     Header(String),
     Footer(String),
-    Footnote { id: u16, html: String },
-    Endnote { id: u16, html: String },
+    Footnote {
+        id: u16,
+        html: String,
+    },
+    Endnote {
+        id: u16,
+        html: String,
+    },
+    /// 메모 여백 주석 (`<aside>`)
+    Memo(String),
 }
 
 /// SubList 내부의 문단들을 HTML로 렌더링
@@ -1,16 +1,22 @@
 /// Document 기반 문단 → HTML 변환
 use hwp_model::document::BinaryStore;
+use hwp_model::memo::Memo;
 use hwp_model::paragraph::{Paragraph, Run, RunContent, TextContent, TextElement};
 use hwp_model::resources::Resources;
-use hwp_model::shape::ShapeObject;
+use hwp_model::shape::{ContainerObject, ShapeObject};
 use hwp_model::table::Table;
 use hwp_model::types::HeadingType;
 
+use super::styles::{hwpunit_to_mm, round_mm};
+use super::{form, shape_svg};
 use super::{render_sublist_paragraphs, DocHtmlOptions, HtmlControlPart};
 use crate::viewer::core::outline::{
     format_outline_number, format_with_numbering, OutlineNumberTracker,
 };
 use crate::viewer::doc_utils;
+use crate::viewer::doc_utils::html_escape;
+use crate::viewer::revision::RevisionStyle;
+use hwp_model::revision::RevisionKind;
 
 /// 개요/번호 추적기를 사용하여 문단을 HTML로 렌더링
 #[allow(clippy::too_many_arguments)]
@@ -23,6 +29,7 @@ pub fn render_paragraph_with_tracker(
     endnote_counter: &mut u16,
     outline_tracker: &mut OutlineNumberTracker,
     number_tracker: &mut std::collections::HashMap<u16, OutlineNumberTracker>,
+    anchor: Option<&str>,
 ) -> (String, Vec<HtmlControlPart>) {
     let pc = render_paragraph_content(
         para,
@@ -37,6 +44,11 @@ pub fn render_paragraph_with_tracker(
         return (String::new(), pc.controls);
     }
 
+    // 차례 링크 대상 (id 속성)
+    let id_attr = anchor
+        .map(|id| format!(" id=\"{}\"", html_escape(id)))
+        .unwrap_or_default();
+
     // 개요/번호/글머리표 적용 (content를 직접 사용, strip_p_tag 불필요)
     if let Some(ps) = resources.para_shapes.get(para.para_shape_id as usize) {
         if let Some(ref heading) = ps.heading {
@@ -51,8 +63,8 @@ pub fn render_paragraph_with_tracker(
                         "p".to_string()
                     };
                     let html = format!(
-                        "<{} class=\"{}outline-{}\"><span class=\"{}outline-number\">{}</span> {}</{}>",
-                        tag, options.css_class_prefix, level,
+                        "<{}{} class=\"{}outline-{}\"><span class=\"{}outline-number\">{}</span> {}</{}>",
+                        tag, id_attr, options.css_class_prefix, level,
                         options.css_class_prefix,
                         html_escape(&num_str), pc.content, tag
                     );
@@ -60,8 +72,8 @@ pub fn render_paragraph_with_tracker(
                 }
                 HeadingType::Bullet => {
                     let html = format!(
-                        "<p class=\"{}bullet\">{}</p>",
-                        options.css_class_prefix, pc.content
+                        "<p{} class=\"{}bullet\">{}</p>",
+                        id_attr, options.css_class_prefix, pc.content
                     );
                     return (html, pc.controls);
                 }
@@ -71,7 +83,8 @@ pub fn render_paragraph_with_tracker(
                     let number = tracker.get_and_increment(level);
                     let num_str = format_with_numbering(heading.id_ref, level, number, resources);
                     let html = format!(
-                        "<p class=\"{}number-{}\"><span class=\"{}number\">{}</span> {}</p>",
+                        "<p{} class=\"{}number-{}\"><span class=\"{}number\">{}</span> {}</p>",
+                        id_attr,
                         options.css_class_prefix,
                         level,
                         options.css_class_prefix,
@@ -87,11 +100,17 @@ pub fn render_paragraph_with_tracker(
 
     // heading 미적용: 일반 문단으로 렌더링
     let body = if pc.has_block {
-        pc.content
+        match anchor {
+            Some(id) => format!("<a id=\"{}\"></a>{}", html_escape(id), pc.content),
+            None => pc.content,
+        }
     } else if pc.para_style.is_empty() {
-        format!("<p>{}</p>", pc.content)
+        format!("<p{}>{}</p>", id_attr, pc.content)
     } else {
-        format!("<p style=\"{}\">{}</p>", pc.para_style, pc.content)
+        format!(
+            "<p{} style=\"{}\">{}</p>",
+            id_attr, pc.para_style, pc.content
+        )
     };
 
     (body, pc.controls)
@@ -122,7 +141,8 @@ fn render_paragraph_content(
     let mut controls: Vec<HtmlControlPart> = Vec::new();
     let mut has_block = false;
 
-    let mut in_hyperlink = false;
+    let mut open_fields: Vec<&'static str> = Vec::new();
+    let mut revisions = OpenRevisions::default();
     for run in &para.runs {
         let (run_html, mut run_controls, is_block) = render_run(
             run,
@@ -131,7 +151,8 @@ fn render_paragraph_content(
             options,
             footnote_counter,
             endnote_counter,
-            &mut in_hyperlink,
+            &mut open_fields,
+            &mut revisions,
         );
         if is_block {
             has_block = true;
@@ -142,8 +163,8 @@ fn render_paragraph_content(
         controls.append(&mut run_controls);
     }
     // FieldEnd 없이 문단이 끝난 경우 방어적 닫기
-    if in_hyperlink {
-        content.push_str("</a>");
+    while let Some(close) = open_fields.pop() {
+        content.push_str(close);
     }
     let para_style = if has_block || content.is_empty() {
         String::new()
@@ -233,6 +254,7 @@ fn build_para_style(para: &Paragraph, resources: &Resources, _options: &DocHtmlO
 }
 
 /// Run 하나를 HTML로 렌더링. (html, controls, is_block)
+#[allow(clippy::too_many_arguments)]
 fn render_run(
     run: &Run,
     resources: &Resources,
@@ -240,7 +262,8 @@ fn render_run(
     options: &DocHtmlOptions,
     footnote_counter: &mut u16,
     endnote_counter: &mut u16,
-    in_hyperlink: &mut bool,
+    open_fields: &mut Vec<&'static str>,
+    revisions: &mut OpenRevisions,
 ) -> (String, Vec<HtmlControlPart>, bool) {
     let mut html_buf = String::new();
     let mut control_parts: Vec<HtmlControlPart> = Vec::new();
@@ -261,7 +284,7 @@ fn render_run(
     for content in &run.contents {
         match content {
             RunContent::Text(tc) => {
-                let text = render_text_content_html(tc);
+                let text = render_text_content_html(tc, revisions, resources, options);
                 text_accum.push_str(&text);
             }
             RunContent::Control(control) => {
@@ -292,15 +315,28 @@ fn render_run(
                             html_buf.push_str(&html_escape(&url));
                             html_buf.push_str("\">");
                             html_buf.push_str(&display);
-                            *in_hyperlink = true;
+                            open_fields.push("</a>");
+                        } else {
+                            open_fields.push("");
                         }
                         continue;
                     }
+                    // 메모: 구간은 강조하고 메모 내용은 여백 주석으로 뺀다
+                    if let Some(memo) = field.memo() {
+                        let style = MemoStyle::new(memo.memo_shape_id, resources);
+                        html_buf.push_str(&memo_anchor_open_tag(&memo, &style, options));
+                        open_fields.push("</span>");
+                        control_parts.push(HtmlControlPart::Memo(render_memo_aside(
+                            &memo, &style, resources, binaries, options,
+                        )));
+                        continue;
+                    }
+                    open_fields.push("");
+                    continue;
                 }
                 if let hwp_model::control::Control::FieldEnd = control {
-                    if *in_hyperlink {
-                        html_buf.push_str("</a>");
-                        *in_hyperlink = false;
+                    if let Some(close) = open_fields.pop() {
+                        html_buf.push_str(close);
                     }
                     continue;
                 }
@@ -335,10 +371,152 @@ fn render_run(
     (html_buf, control_parts, is_block)
 }
 
-/// TextContent를 HTML로 변환
-fn render_text_content_html(tc: &TextContent) -> String {
-    let mut result = String::new();
+/// 메모 색 (메모 모양이 없으면 노란 메모지 색)
+struct MemoStyle {
+    line: u32,
+    fill: u32,
+}
+
+impl MemoStyle {
+    fn new(memo_shape_id: Option<u16>, resources: &Resources) -> Self {
+        let shape = memo_shape_id.and_then(|id| resources.memo_shapes.iter().find(|s| s.id == id));
+        Self {
+            line: shape.and_then(|s| s.line_color).unwrap_or(0xE0B000),
+            fill: shape.and_then(|s| s.fill_color).unwrap_or(0xFFF5C0),
+        }
+    }
+}
+
+/// 메모가 달린 본문 구간을 감싸는 여는 태그
+fn memo_anchor_open_tag(memo: &Memo, style: &MemoStyle, options: &DocHtmlOptions) -> String {
+    let data = memo
+        .number
+        .map(|n| format!(" data-memo=\"{}\"", n))
+        .unwrap_or_default();
+    format!(
+        "<span class=\"{}memo-anchor\"{} style=\"background-color: #{:06x}\">",
+        options.css_class_prefix, data, style.fill
+    )
+}
+
+/// 여백 주석으로 띄우는 메모 상자 (번호, 작성자, 일시, 내용)
+fn render_memo_aside(
+    memo: &Memo,
+    style: &MemoStyle,
+    resources: &Resources,
+    binaries: &BinaryStore,
+    options: &DocHtmlOptions,
+) -> String {
+    let prefix = &options.css_class_prefix;
+    let id = memo
+        .number
+        .map(|n| format!(" id=\"memo-{}\"", n))
+        .unwrap_or_default();
+    let mut header = match memo.number {
+        Some(n) => format!("메모 {}", n),
+        None => "메모".to_string(),
+    };
+    if let Some(author) = &memo.author {
+        header.push_str(&format!(
+            " <span class=\"{}memo-author\">{}</span>",
+            prefix,
+            html_escape(author)
+        ));
+    }
+    if let Some(date) = &memo.date {
+        header.push_str(&format!(
+            " <time class=\"{}memo-date\">{}</time>",
+            prefix,
+            html_escape(date)
+        ));
+    }
+    let content = render_sublist_paragraphs(&memo.content, resources, binaries, options);
+    format!(
+        "<aside class=\"{p}memo\"{id} style=\"float: right; clear: right; width: 30%; margin: 0 0 4pt 8pt; padding: 2pt 4pt; border-left: 2pt solid #{line:06x}; background-color: #{fill:06x}; font-size: 9pt\"><div class=\"{p}memo-header\">{header}</div>{content}</aside>",
+        p = prefix,
+        id = id,
+        line = style.line,
+        fill = style.fill,
+        header = header,
+        content = content
+    )
+}
+
+/// 문단 안에서 열려 있는 변경 구간 (본문 표시 ID, 표시 스타일)
+#[derive(Default)]
+struct OpenRevisions(Vec<(String, RevisionStyle)>);
+
+impl OpenRevisions {
+    fn open_tags(&self, prefix: &str) -> String {
+        self.0
+            .iter()
+            .map(|(_, style)| revision_open_tag(style, prefix))
+            .collect()
+    }
+
+    fn close_tags(&self) -> String {
+        self.0
+            .iter()
+            .rev()
+            .map(|(_, style)| revision_close_tag(style))
+            .collect()
+    }
+}
+
+/// `<ins>`/`<del>` 여는 태그 (작성자 색, 툴팁은 "작성자 (일시)")
+fn revision_open_tag(style: &RevisionStyle, prefix: &str) -> String {
+    let (tag, decoration) = match style.kind {
+        RevisionKind::Insert => ("ins", "underline"),
+        RevisionKind::Delete => ("del", "line-through"),
+    };
+    let title = style.title();
+    let title_attr = if title.is_empty() {
+        String::new()
+    } else {
+        format!(" title=\"{}\"", html_escape(&title))
+    };
+    format!(
+        "<{} class=\"{}revision\" style=\"color: #{:06x}; text-decoration: {}\"{}>",
+        tag, prefix, style.color, decoration, title_attr
+    )
+}
+
+fn revision_close_tag(style: &RevisionStyle) -> &'static str {
+    match style.kind {
+        RevisionKind::Insert => "</ins>",
+        RevisionKind::Delete => "</del>",
+    }
+}
+
+/// TextContent를 HTML로 변환.
+/// 변경 구간은 TextContent마다 닫았다가 다시 열어 글자 모양 태그와 엇갈리지 않게 한다.
+fn render_text_content_html(
+    tc: &TextContent,
+    revisions: &mut OpenRevisions,
+    resources: &Resources,
+    options: &DocHtmlOptions,
+) -> String {
+    let prefix = &options.css_class_prefix;
+    let mut result = revisions.open_tags(prefix);
+    let mut has_content = false;
     for elem in &tc.elements {
+        if let Some(mark) = elem.revision_mark() {
+            if mark.begin {
+                let style = RevisionStyle::new(&mark, resources);
+                result.push_str(&revision_open_tag(&style, prefix));
+                revisions.0.push((mark.id.to_string(), style));
+            } else if let Some(pos) = revisions.0.iter().rposition(|(id, _)| id == mark.id) {
+                // 안쪽 구간을 닫고 해당 구간을 닫은 뒤 안쪽 구간을 다시 연다
+                let inner = OpenRevisions(revisions.0.split_off(pos + 1));
+                result.push_str(&inner.close_tags());
+                let (_, style) = revisions.0.pop().unwrap();
+                result.push_str(revision_close_tag(&style));
+                result.push_str(&inner.open_tags(prefix));
+                revisions.0.extend(inner.0);
+            }
+            continue;
+        }
+        has_content = true;
         match elem {
             TextElement::Text(s) => {
                 if s.contains('\t') {
@@ -357,6 +535,10 @@ fn render_text_content_html(tc: &TextContent) -> String {
             _ => {}
         }
     }
+    if !has_content {
+        return String::new();
+    }
+    result.push_str(&revisions.close_tags());
     result
 }
 
@@ -475,6 +657,7 @@ fn render_shape_object_html(
                 &pic.img.binary_item_id,
                 binaries,
                 options.image_output_dir.as_deref(),
+                options.keep_metafile,
             );
             // Picture 캡션 렌더링
             if let Some(ref caption) = pic.common.caption {
@@ -518,18 +701,25 @@ fn render_shape_object_html(
                 }
                 (html, true)
             } else {
-                (String::new(), false)
+                render_drawing_svg(shape, binaries, options)
             }
         }
         ShapeObject::Container(container) => {
-            let mut parts = Vec::new();
-            for child in &container.children {
-                let (html, _) = render_shape_object_html(child, resources, binaries, options);
-                if !html.is_empty() {
-                    parts.push(html);
+            if container.children.iter().any(is_drawing) {
+                (
+                    render_shape_group_html(container, resources, binaries, options),
+                    true,
+                )
+            } else {
+                let mut parts = Vec::new();
+                for child in &container.children {
+                    let (html, _) = render_shape_object_html(child, resources, binaries, options);
+                    if !html.is_empty() {
+                        parts.push(html);
+                    }
                 }
+                (parts.join(""), true)
             }
-            (parts.join(""), true)
         }
         ShapeObject::Equation(eq) => {
             if eq.script.is_empty() {
@@ -539,14 +729,101 @@ fn render_shape_object_html(
                     format!(
                         "<span class=\"{}equation\">{}</span>",
                         options.css_class_prefix,
-                        html_escape(&eq.script)
+                        crate::viewer::equation::equation_to_mathml(&eq.script)
                     ),
                     false,
                 )
             }
         }
-        _ => (String::new(), false),
+        ShapeObject::Form(form) => (
+            form::render_form_html(form, &options.css_class_prefix),
+            false,
+        ),
+        _ => render_drawing_svg(shape, binaries, options),
+    }
+}
+
+/// 그리기 개체(선, 타원, 연결선 등) → 인라인 SVG. (html, is_block)
+fn render_drawing_svg(
+    shape: &ShapeObject,
+    binaries: &BinaryStore,
+    options: &DocHtmlOptions,
+) -> (String, bool) {
+    match shape_svg::render_shape_svg(shape, binaries, &options.css_class_prefix) {
+        Some(svg) => (svg, false),
+        None => (String::new(), false),
+    }
+}
+
+/// 글상자 없이 SVG로만 그리는 개체인지 (묶음 안의 하위 개체 포함)
+fn is_drawing(shape: &ShapeObject) -> bool {
+    match shape {
+        ShapeObject::Table(_)
+        | ShapeObject::Picture(_)
+        | ShapeObject::Equation(_)
+        | ShapeObject::Form(_) => false,
+        ShapeObject::Rectangle(rect) => rect.draw_text.is_none(),
+        ShapeObject::Container(container) => container.children.iter().any(is_drawing),
+        _ => true,
+    }
+}
+
+/// 도형이 섞인 묶음 개체: 하위 개체를 묶음 내부 오프셋에 절대 배치
+/// (조직도/순서도처럼 상자와 연결선의 위치 관계가 중요한 경우)
+fn render_shape_group_html(
+    container: &ContainerObject,
+    resources: &Resources,
+    binaries: &BinaryStore,
+    options: &DocHtmlOptions,
+) -> String {
+    let (width, height) = shape_svg::shape_size(&container.common, Some(&container.component));
+    let (sx, sy) = shape_svg::point_scale(&container.component, width, height);
+    let mut html = format!(
+        "<div class=\"{}shape-group\" style=\"position: relative; width: {:.2}mm; height: {:.2}mm\">",
+        options.css_class_prefix,
+        round_mm(hwpunit_to_mm(width)),
+        round_mm(hwpunit_to_mm(height))
+    );
+    for child in &container.children {
+        let (child_width, child_height) = shape_svg::shape_size(
+            doc_utils::shape_common(child),
+            doc_utils::shape_component(child),
+        );
+        let (x, y) = doc_utils::shape_component(child)
+            .and_then(|c| c.offset.as_ref())
+            .map(|pt| (hwpunit_to_mm(pt.x) * sx, hwpunit_to_mm(pt.y) * sy))
+            .unwrap_or((0.0, 0.0));
+        let child_html = match child {
+            ShapeObject::Container(sub) => {
+                render_shape_group_html(sub, resources, binaries, options)
+            }
+            ShapeObject::Rectangle(rect) if rect.draw_text.is_some() => {
+                // 글상자: SVG 테두리/배경 위에 글을 겹침
+                let (text, _) = render_shape_object_html(child, resources, binaries, options);
+                match shape_svg::render_shape_svg(child, binaries, &options.css_class_prefix) {
+                    Some(frame) => format!(
+                        "<div style=\"position: absolute; left: 0; top: 0\">{}</div><div style=\"position: relative\">{}</div>",
+                        frame, text
+                    ),
+                    None => text,
+                }
+            }
+            _ => render_shape_object_html(child, resources, binaries, options).0,
+        };
+        if child_html.is_empty() {
+            continue;
+        }
+        html.push_str(&format!(
+            "<div style=\"position: absolute; left: {:.2}mm; top: {:.2}mm; width: {:.2}mm; height: {:.2}mm\">{}</div>",
+            round_mm(x),
+            round_mm(y),
+            round_mm(hwpunit_to_mm(child_width)),
+            round_mm(hwpunit_to_mm(child_height)),
+            child_html
+        ));
     }
+    html.push_str("</div>");
+    html
 }
 
 /// Table을 HTML <table>로 변환
@@ -636,46 +913,42 @@ fn render_picture_html(
     binary_item_id: &str,
     binaries: &BinaryStore,
     image_output_dir: Option<&str>,
+    keep_metafile: bool,
 ) -> String {
     if let Some(item) = doc_utils::find_binary_item(binary_item_id, binaries) {
         if item.data.is_empty() {
-            format!("<img src=\"{}\" alt=\"이미지\">", html_escape(&item.src))
-        } else if let Some(dir) = image_output_dir {
+            return format!("<img src=\"{}\" alt=\"이미지\">", html_escape(&item.src));
+        }
+        // WMF/EMF는 브라우저가 표시할 수 있도록 SVG로 변환
+        let (data, mime) = doc_utils::displayable_image(&item.format, &item.data, keep_metafile);
+        if let Some(dir) = image_output_dir {
             // 이미지를 파일로 저장
-            let ext = match doc_utils::image_format_to_mime(&item.format) {
+            let ext = match mime {
                 "image/jpeg" => "jpg",
                 "image/png" => "png",
                 "image/gif" => "gif",
                 "image/bmp" => "bmp",
                 "image/tiff" => "tiff",
-                "image/wmf" => "wmf",
-                "image/emf" => "emf",
+                "image/svg+xml" => "svg",
+                "image/x-wmf" => "wmf",
+                "image/x-emf" => "emf",
                 _ => "bin",
             };
             let file_name = format!("{}.{}", binary_item_id, ext);
             let file_path = std::path::Path::new(dir).join(&file_name);
-            if std::fs::write(&file_path, &item.data).is_ok() {
-                format!(
+            if std::fs::write(&file_path, &data).is_ok() {
+                return format!(
                     "<img src=\"{}\" alt=\"이미지\">",
                     html_escape(&file_path.display().to_string())
-                )
-            } else {
-                // 파일 저장 실패 시 base64 fallback
-                let mime = doc_utils::image_format_to_mime(&item.format);
-                let b64 = {
-                    use base64::Engine;
-                    base64::engine::general_purpose::STANDARD.encode(&item.data)
-                };
-                format!("<img src=\"data:{};base64,{}\" alt=\"이미지\">", mime, b64)
+                );
             }
-        } else {
-            let mime = doc_utils::image_format_to_mime(&item.format);
-            let b64 = {
-                use base64::Engine;
-                base64::engine::general_purpose::STANDARD.encode(&item.data)
-            };
-            format!("<img src=\"data:{};base64,{}\" alt=\"이미지\">", mime, b64)
+            // 파일 저장 실패 시 base64 fallback
         }
+        let b64 = {
+            use base64::Engine;
+            base64::engine::general_purpose::STANDARD.encode(&data)
+        };
+        format!("<img src=\"data:{};base64,{}\" alt=\"이미지\">", mime, b64)
     } else if !binary_item_id.is_empty() {
         format!(
             "<img src=\"{}\" alt=\"이미지\">",
@@ -824,11 +1097,3 @@ fn extract_html_control_part(
         _ => None,
     }
 }
-
-/// HTML 이스케이프
-fn html_escape(s: &str) -> String {
-    s.replace('&', "&amp;")
-        .replace('<', "&lt;")
-        .replace('>', "&gt;")
-        .replace('"', "&quot;")
-}
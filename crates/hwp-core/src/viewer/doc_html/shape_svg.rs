@@ -0,0 +1,1334 @@
+/// Document 기반 그리기 개체 → 인라인 SVG 렌더러
+/// 선/사각형/타원/호/다각형/곡선/연결선/글맵시를 개체 크기의 `<svg>` 하나로 그림
+///
+/// 좌표는 mm 단위(개체 좌상단 원점)이며, 개체 내부 점은 원본 크기(`org_size`)에서
+/// 현재 크기(`common.size`)로 늘려 배치합니다. 회전/뒤집기는 `<g transform>`으로 적용합니다.
+use super::chart_svg;
+use super::styles::{hwpunit_to_mm, round_mm};
+use crate::ole::OleContents;
+use crate::viewer::doc_utils;
+use crate::viewer::doc_utils::html_escape;
+use base64::Engine;
+use hwp_model::document::BinaryStore;
+use hwp_model::resources::FillBrush;
+use hwp_model::shape::{
+    ArcObject, ConnectLineObject, CurveObject, EllipseObject, OleObject, RectObject, ShapeCommon,
+    ShapeComponentData, ShapeLineInfo, ShapeObject, TextArtObject,
+};
+use hwp_model::types::{
+    ArcType, ArrowSize, ArrowType, ConnectLineType, CurveSegmentType, GradationType, LineEndCap,
+    LineType1, Point,
+};
+use std::fmt::Write;
+
+/// 선 굵기가 0일 때 쓰는 굵기 (mm, 레이아웃 HTML과 동일)
+pub(crate) const MIN_STROKE_MM: f64 = 0.12;
+/// 한글에서 고를 수 있는 가장 굵은 선보다 충분히 큰 값 (mm)
+const MAX_STROKE_MM: f64 = 20.0;
+
+const NO_FILL: &str = r#"fill="none""#;
+const NO_STROKE: &str = r#"stroke="none""#;
+
+/// 그리기 개체를 인라인 SVG로 렌더링
+/// 표/그림/수식/묶음 개체는 None (호출자가 HTML로 처리). 글상자가 있는 사각형은 테두리/배경만 그림
+pub fn render_shape_svg(
+    shape: &ShapeObject,
+    binaries: &BinaryStore,
+    class_prefix: &str,
+) -> Option<String> {
+    let (size_w, size_h) = shape_size(
+        doc_utils::shape_common(shape),
+        doc_utils::shape_component(shape),
+    );
+    let width = hwpunit_to_mm(size_w).max(0.0);
+    let height = hwpunit_to_mm(size_h).max(0.0);
+    let mut svg = ShapeSvg {
+        binaries,
+        class_prefix,
+        width,
+        height,
+        scale: doc_utils::shape_component(shape)
+            .map(|c| point_scale(c, size_w, size_h))
+            .unwrap_or((1.0, 1.0)),
+        defs: String::new(),
+        body: String::new(),
+        margin: 0.0,
+    };
+
+    match shape {
+        ShapeObject::Line(_)
+        | ShapeObject::Arc(_)
+        | ShapeObject::Polygon(_)
+        | ShapeObject::Curve(_)
+        | ShapeObject::ConnectLine(_) => {
+            if let Some(outline) = shape_outline(shape, svg.scale, width, height) {
+                svg.outline(&outline);
+            }
+        }
+        ShapeObject::Ellipse(ellipse) if ellipse.has_arc => {
+            if let Some(outline) = shape_outline(shape, svg.scale, width, height) {
+                svg.outline(&outline);
+            }
+        }
+        ShapeObject::Rectangle(rect) => svg.rect(rect),
+        ShapeObject::Ellipse(ellipse) => svg.ellipse(ellipse),
+        ShapeObject::TextArt(text_art) => svg.text_art(text_art),
+        ShapeObject::Ole(ole) => svg.ole(ole),
+        ShapeObject::Chart(chart) => match &chart.chart {
+            Some(data) if !data.series.is_empty() => {
+                svg.body = chart_svg::render_chart(data, width, height)
+            }
+            _ => svg.placeholder(CHART_LABEL, None),
+        },
+        ShapeObject::Video(video) => {
+            let poster = video
+                .image_id_ref
+                .as_deref()
+                .and_then(|id| svg.image_href(id));
+            match poster {
+                Some(href) => {
+                    let _ = write!(
+                        svg.body,
+                        r#"<image x="0" y="0" width="{}" height="{}" preserveAspectRatio="none" href="{}"/>"#,
+                        num(width),
+                        num(height),
+                        href
+                    );
+                }
+                None => svg.placeholder(VIDEO_LABEL, None),
+            }
+        }
+        _ => return None,
+    }
+
+    if svg.body.is_empty() {
+        return None;
+    }
+    Some(svg.finish(doc_utils::shape_component(shape)))
+}
+
+/// 개체 하나의 SVG 버퍼
+struct ShapeSvg<'a> {
+    binaries: &'a BinaryStore,
+    class_prefix: &'a str,
+    /// 개체 크기 (mm)
+    width: f64,
+    height: f64,
+    /// 개체 내부 점 → 현재 크기 배율 (가로, 세로)
+    scale: (f64, f64),
+    defs: String,
+    body: String,
+    /// 선 굵기/화살표가 개체 밖으로 나가는 여백 (mm)
+    margin: f64,
+}
+
+impl ShapeSvg<'_> {
+    /// `<svg>` 요소로 마무리 (회전/뒤집기 포함)
+    fn finish(self, component: Option<&ShapeComponentData>) -> String {
+        let (w, h) = (round_mm(self.width), round_mm(self.height));
+        let mut transforms = Vec::new();
+        if let Some(component) = component {
+            if let Some(angle) = component
+                .rotation
+                .as_ref()
+                .map(|r| r.angle as f64)
+                .filter(|a| a.abs() > f64::EPSILON)
+            {
+                transforms.push(format!(
+                    "rotate({} {} {})",
+                    num(angle),
+                    num(w / 2.0),
+                    num(h / 2.0)
+                ));
+            }
+            if let Some(flip) = &component.flip {
+                if flip.horizontal {
+                    transforms.push(format!("matrix(-1 0 0 1 {} 0)", num(w)));
+                }
+                if flip.vertical {
+                    transforms.push(format!("matrix(1 0 0 -1 0 {})", num(h)));
+                }
+            }
+        }
+
+        let m = round_mm(self.margin.max(MIN_STROKE_MM));
+        let mut out = format!(
+            r#"<svg class="{}shape" xmlns="http://www.w3.org/2000/svg" width="{}mm" height="{}mm" viewBox="{} {} {} {}" style="margin: -{}mm; overflow: visible">"#,
+            self.class_prefix,
+            num(w + m * 2.0),
+            num(h + m * 2.0),
+            num(-m),
+            num(-m),
+            num(w + m * 2.0),
+            num(h + m * 2.0),
+            num(m)
+        );
+        if !self.defs.is_empty() {
+            let _ = write!(out, "<defs>{}</defs>", self.defs);
+        }
+        if transforms.is_empty() {
+            out.push_str(&self.body);
+        } else {
+            let _ = write!(
+                out,
+                r#"<g transform="{}">{}</g>"#,
+                transforms.join(" "),
+                self.body
+            );
+        }
+        out.push_str("</svg>");
+        out
+    }
+
+    /// 선/호/다각형/곡선/연결선 경로와 화살표
+    fn outline(&mut self, outline: &ShapeOutline) {
+        self.path(&path_d(&outline.segments), outline.fill, outline.line);
+        self.arrows(&outline.arrow_points, outline.line, outline.arrow_ends);
+    }
+
+    fn rect(&mut self, rect: &RectObject) {
+        // 모서리 곡률: 0 직각, 20 둥근 모양, 50 반원
+        let radius = round_mm(self.width.min(self.height) * rect.ratio.min(50) as f64 / 100.0);
+        let fill = self.fill(rect.fill.as_ref());
+        let stroke = self.stroke(&rect.line_shape);
+        if fill == NO_FILL && stroke == NO_STROKE {
+            return;
+        }
+        let rounded = if radius > 0.0 {
+            format!(r#" rx="{}""#, num(radius))
+        } else {
+            String::new()
+        };
+        let _ = write!(
+            self.body,
+            r#"<rect x="0" y="0" width="{}" height="{}"{} {} {}/>"#,
+            num(self.width),
+            num(self.height),
+            rounded,
+            fill,
+            stroke
+        );
+    }
+
+    fn ellipse(&mut self, ellipse: &EllipseObject) {
+        let (center, rx, ry, angle) = ellipse_axes(ellipse, self.scale, self.width, self.height);
+        let fill = self.fill(ellipse.fill.as_ref());
+        let stroke = self.stroke(&ellipse.line_shape);
+        if fill == NO_FILL && stroke == NO_STROKE {
+            return;
+        }
+        let rotate = if angle.abs() > 0.01 {
+            format!(
+                r#" transform="rotate({} {} {})""#,
+                num(round_mm(angle)),
+                num(center.0),
+                num(center.1)
+            )
+        } else {
+            String::new()
+        };
+        let _ = write!(
+            self.body,
+            r#"<ellipse cx="{}" cy="{}" rx="{}" ry="{}"{} {} {}/>"#,
+            num(center.0),
+            num(center.1),
+            num(round_mm(rx)),
+            num(round_mm(ry)),
+            rotate,
+            fill,
+            stroke
+        );
+    }
+
+    /// 글맵시: 개체 크기에 맞춰 늘린 글자
+    fn text_art(&mut self, text_art: &TextArtObject) {
+        if text_art.text.is_empty() {
+            return;
+        }
+        let fill = match text_art.fill {
+            Some(ref fill) => self.fill(Some(fill)),
+            None => format!(
+                r#"fill="{}""#,
+                css_color(text_art.line_shape.color.unwrap_or(0))
+            ),
+        };
+        let stroke = self.stroke(&text_art.line_shape);
+        let font_size = round_mm(self.height * 0.8);
+        let font = text_art
+            .font_name
+            .as_deref()
+            .map(|name| {
+                format!(
+                    r#" font-family="'{}'""#,
+                    html_escape(&doc_utils::escape_css_font_name(name))
+                )
+            })
+            .unwrap_or_default();
+        let _ = write!(
+            self.body,
+            r#"<text x="{}" y="{}" font-size="{}"{} text-anchor="middle" textLength="{}" lengthAdjust="spacingAndGlyphs" {} {}>{}</text>"#,
+            num(round_mm(self.width / 2.0)),
+            num(font_size),
+            num(font_size),
+            font,
+            num(self.width),
+            fill,
+            stroke,
+            html_escape(&text_art.text)
+        );
+    }
+
+    /// OLE 개체: 저장된 미리보기(비트맵)를 그리고, 없으면 개체 형식 이름으로 자리 표시
+    fn ole(&mut self, ole: &OleObject) {
+        let contents = ole_contents(ole, self.binaries);
+        // 메타파일 미리보기는 SVG로 변환해서 사용
+        let preview = contents
+            .as_ref()
+            .and_then(|c| c.preview.as_ref())
+            .map(|p| doc_utils::displayable_image(&p.format, &p.data, false))
+            .filter(|(_, mime)| matches!(*mime, "image/bmp" | "image/svg+xml"));
+        let Some((data, mime)) = preview else {
+            return self.placeholder(ole_label(contents.as_ref()), ole.line_shape.as_ref());
+        };
+        let _ = write!(
+            self.body,
+            r#"<image x="0" y="0" width="{}" height="{}" preserveAspectRatio="none" href="data:{};base64,{}"/>"#,
+            num(self.width),
+            num(self.height),
+            mime,
+            base64::engine::general_purpose::STANDARD.encode(data)
+        );
+        if let Some(line) = ole
+            .line_shape
+            .as_ref()
+            .filter(|l| l.style != LineType1::None)
+        {
+            let stroke = self.stroke(line);
+            let _ = write!(
+                self.body,
+                r#"<rect x="0" y="0" width="{}" height="{}" {} {}/>"#,
+                num(self.width),
+                num(self.height),
+                NO_FILL,
+                stroke
+            );
+        }
+    }
+
+    /// 내용을 그릴 수 없는 개체(OLE, 차트 등)의 자리 표시 상자
+    fn placeholder(&mut self, label: &str, line: Option<&ShapeLineInfo>) {
+        let stroke = match line {
+            Some(line) if line.style != LineType1::None => self.stroke(line),
+            _ => format!(
+                r#"stroke="{}" stroke-width="{}" stroke-dasharray="{}""#,
+                css_color(PLACEHOLDER_LINE),
+                num(MIN_STROKE_MM),
+                PLACEHOLDER_DASH.map(num).join(" ")
+            ),
+        };
+        let _ = write!(
+            self.body,
+            r#"<rect x="0" y="0" width="{}" height="{}" fill="{}" {}/><text x="{}" y="{}" font-size="{}" text-anchor="middle" dominant-baseline="middle" fill="{}">{}</text>"#,
+            num(self.width),
+            num(self.height),
+            css_color(PLACEHOLDER_FILL),
+            stroke,
+            num(round_mm(self.width / 2.0)),
+            num(round_mm(self.height / 2.0)),
+            num(PLACEHOLDER_FONT_MM),
+            css_color(PLACEHOLDER_TEXT),
+            html_escape(label)
+        );
+    }
+
+    fn path(&mut self, d: &str, fill: Option<&FillBrush>, line: &ShapeLineInfo) {
+        let fill = self.fill(fill);
+        let stroke = self.stroke(line);
+        // 선도 채우기도 없으면 보이지 않으므로 생략
+        if fill == NO_FILL && stroke == NO_STROKE {
+            return;
+        }
+        let _ = write!(self.body, r#"<path d="{}" {} {}/>"#, d, fill, stroke);
+    }
+
+    /// 선 모양 → stroke 속성 (여백도 함께 갱신)
+    fn stroke(&mut self, line: &ShapeLineInfo) -> String {
+        if line.style == LineType1::None {
+            return NO_STROKE.to_string();
+        }
+        let width = stroke_width(line);
+        self.margin = self.margin.max(width / 2.0);
+        let mut attrs = format!(
+            r#"stroke="{}" stroke-width="{}""#,
+            css_color(line.color.unwrap_or(0)),
+            num(width)
+        );
+        if line.end_cap == LineEndCap::Round {
+            attrs.push_str(r#" stroke-linecap="round""#);
+        }
+        let dash = stroke_dash(&line.style, width);
+        if !dash.is_empty() {
+            let _ = write!(attrs, r#" stroke-dasharray="{}""#, dash);
+        }
+        attrs
+    }
+
+    /// 채우기 → fill 속성 (그러데이션/그림은 `<defs>`에 정의)
+    fn fill(&mut self, fill: Option<&FillBrush>) -> String {
+        let Some(fill) = fill else {
+            return NO_FILL.to_string();
+        };
+        match fill {
+            FillBrush::WinBrush {
+                face_color: Some(color),
+                ..
+            } => format!(r#"fill="{}""#, css_color(*color)),
+            FillBrush::Gradation {
+                grad_type,
+                angle,
+                center_x,
+                center_y,
+                colors,
+                ..
+            } if !colors.is_empty() => {
+                let id = self.def_id("grad", fill);
+                let stops = gradient_stops(colors);
+                if *grad_type == GradationType::Linear {
+                    let _ = write!(
+                        self.defs,
+                        r#"<linearGradient id="{}" gradientTransform="rotate({} 0.5 0.5)">{}</linearGradient>"#,
+                        id, angle, stops
+                    );
+                } else {
+                    let _ = write!(
+                        self.defs,
+                        r#"<radialGradient id="{}" cx="{}%" cy="{}%" r="75%">{}</radialGradient>"#,
+                        id, center_x, center_y, stops
+                    );
+                }
+                format!(r#"fill="url(#{})""#, id)
+            }
+            FillBrush::ImageBrush { img, .. } => match self.image_href(&img.binary_item_id) {
+                Some(href) => {
+                    let id = self.def_id("img", fill);
+                    let _ = write!(
+                        self.defs,
+                        r#"<pattern id="{}" patternUnits="objectBoundingBox" patternContentUnits="objectBoundingBox" width="1" height="1"><image width="1" height="1" preserveAspectRatio="none" href="{}"/></pattern>"#,
+                        id, href
+                    );
+                    format!(r#"fill="url(#{})""#, id)
+                }
+                None => NO_FILL.to_string(),
+            },
+            FillBrush::Combined {
+                win_brush,
+                gradation,
+                image_brush,
+            } => {
+                let brush = image_brush
+                    .as_deref()
+                    .or(gradation.as_deref())
+                    .or(win_brush.as_deref());
+                self.fill(brush)
+            }
+            _ => NO_FILL.to_string(),
+        }
+    }
+
+    /// `<defs>` 항목 id: 같은 채우기는 같은 id가 되므로 한 HTML 안에서 겹쳐도 결과가 같음
+    fn def_id(&self, kind: &str, fill: &FillBrush) -> String {
+        format!(
+            "{}{}-{:08x}",
+            self.class_prefix,
+            kind,
+            fnv1a(format!("{:?}", fill).as_bytes())
+        )
+    }
+
+    /// BinaryItem → data URI
+    fn image_href(&self, binary_item_id: &str) -> Option<String> {
+        let item = doc_utils::find_binary_item(binary_item_id, self.binaries)?;
+        if item.data.is_empty() {
+            return (!item.src.is_empty()).then(|| html_escape(&item.src));
+        }
+        let (data, mime) = doc_utils::displayable_image(&item.format, &item.data, false);
+        Some(format!(
+            "data:{};base64,{}",
+            mime,
+            base64::engine::general_purpose::STANDARD.encode(data)
+        ))
+    }
+
+    /// 선 양 끝 화살표 (head = 시작점, tail = 끝점)
+    fn arrows(&mut self, points: &[(f64, f64)], line: &ShapeLineInfo, ends: Option<(bool, bool)>) {
+        let color = css_color(line.color.unwrap_or(0));
+        let width = stroke_width(line);
+        for mark in arrow_marks(points, line, ends) {
+            self.margin = self.margin.max(mark.extent);
+            let fill = if mark.filled {
+                color.as_str()
+            } else {
+                "#FFFFFF"
+            };
+            let style = format!(
+                r#"fill="{}" stroke="{}" stroke-width="{}""#,
+                fill,
+                color,
+                num(width)
+            );
+            match mark.shape {
+                ArrowShape::Circle { center, radius } => {
+                    let _ = write!(
+                        self.body,
+                        r#"<circle cx="{}" cy="{}" r="{}" {}/>"#,
+                        num(center.0),
+                        num(center.1),
+                        num(radius),
+                        style
+                    );
+                }
+                ArrowShape::Polygon(points) => {
+                    let points: Vec<String> = points.into_iter().map(xy).collect();
+                    let _ = write!(
+                        self.body,
+                        r#"<polygon points="{}" {}/>"#,
+                        points.join(" "),
+                        style
+                    );
+                }
+            }
+        }
+    }
+}
+
+// ═══════════════════════════════════════════
+// 도형 기하 (인라인 SVG와 canvas display list 공용)
+// ═══════════════════════════════════════════
+
+/// 경로 구성 요소 (개체 좌상단 기준 mm)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Segment {
+    Move((f64, f64)),
+    Line((f64, f64)),
+    /// 3차 베지어 (제어점1, 제어점2, 끝점)
+    Cubic((f64, f64), (f64, f64), (f64, f64)),
+    /// SVG `A` 명령과 같은 끝점 표기 타원호 (rotation은 도 단위)
+    Arc {
+        rx: f64,
+        ry: f64,
+        rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        to: (f64, f64),
+    },
+    Close,
+}
+
+/// 선/호/다각형/곡선/연결선/호 모양 타원의 경로와 선 모양
+pub(crate) struct ShapeOutline<'a> {
+    pub segments: Vec<Segment>,
+    pub fill: Option<&'a FillBrush>,
+    pub line: &'a ShapeLineInfo,
+    /// 화살표 방향을 구하는 점 (닫힌 도형이면 비어 있음)
+    pub arrow_points: Vec<(f64, f64)>,
+    /// 연결선 종류가 정하는 (시작, 끝) 화살표 여부
+    pub arrow_ends: Option<(bool, bool)>,
+}
+
+/// 화살표 모양 (개체 좌상단 기준 mm)
+pub(crate) enum ArrowShape {
+    Polygon(Vec<(f64, f64)>),
+    Circle { center: (f64, f64), radius: f64 },
+}
+
+pub(crate) struct ArrowMark {
+    pub shape: ArrowShape,
+    /// 선 색으로 채우는지 (아니면 흰색)
+    pub filled: bool,
+    /// 끝점에서 화살표가 뻗는 최대 거리 (mm)
+    pub extent: f64,
+}
+
+/// 자리 표시 상자 색/선/글자 크기
+pub(crate) const PLACEHOLDER_FILL: u32 = 0xF2F2F2;
+pub(crate) const PLACEHOLDER_LINE: u32 = 0xA6A6A6;
+pub(crate) const PLACEHOLDER_TEXT: u32 = 0x7F7F7F;
+pub(crate) const PLACEHOLDER_DASH: [f64; 2] = [0.5, 0.5];
+pub(crate) const PLACEHOLDER_FONT_MM: f64 = 3.0;
+pub(crate) const CHART_LABEL: &str = "차트";
+pub(crate) const VIDEO_LABEL: &str = "동영상";
+
+/// 개체 내부 좌표 → mm (원본 크기 → 현재 크기 배율 적용)
+pub(crate) fn scaled_point(scale: (f64, f64), x: i32, y: i32) -> (f64, f64) {
+    (
+        round_mm(hwpunit_to_mm(x) * scale.0),
+        round_mm(hwpunit_to_mm(y) * scale.1),
+    )
+}
+
+/// 경로로 그리는 개체의 외곽 경로. 그 밖의 개체와 호가 아닌 타원은 None
+/// `width`, `height`는 개체 크기 (mm, 타원 축 정보가 없을 때 사용)
+pub(crate) fn shape_outline(
+    shape: &ShapeObject,
+    scale: (f64, f64),
+    width: f64,
+    height: f64,
+) -> Option<ShapeOutline<'_>> {
+    let pt = |point: &Point| scaled_point(scale, point.x, point.y);
+    match shape {
+        ShapeObject::Line(line) => {
+            let start = pt(&line.start_pt);
+            let end = pt(&line.end_pt);
+            Some(ShapeOutline {
+                segments: vec![Segment::Move(start), Segment::Line(end)],
+                fill: None,
+                line: &line.line_shape,
+                arrow_points: vec![start, end],
+                arrow_ends: None,
+            })
+        }
+        ShapeObject::Arc(arc) => arc_outline(arc, scale),
+        ShapeObject::Ellipse(ellipse) if ellipse.has_arc => {
+            let (center, rx, ry, angle) = ellipse_axes(ellipse, scale, width, height);
+            let start = pt(&ellipse.start1);
+            let end = pt(&ellipse.end1);
+            let a1 = ellipse_angle(center, start, angle);
+            let a2 = ellipse_angle(center, end, angle);
+            let sweep = (a2 - a1).rem_euclid(std::f64::consts::TAU);
+            let mut segments = vec![
+                Segment::Move(start),
+                Segment::Arc {
+                    rx: round_mm(rx),
+                    ry: round_mm(ry),
+                    rotation: round_mm(angle),
+                    large_arc: sweep > std::f64::consts::PI,
+                    sweep: true,
+                    to: end,
+                },
+            ];
+            segments.extend(arc_close(&ellipse.arc_type, center));
+            Some(ShapeOutline {
+                segments,
+                fill: (ellipse.arc_type != ArcType::Normal)
+                    .then_some(ellipse.fill.as_ref())
+                    .flatten(),
+                line: &ellipse.line_shape,
+                arrow_points: Vec::new(),
+                arrow_ends: None,
+            })
+        }
+        ShapeObject::Polygon(polygon) => {
+            if polygon.points.len() < 2 {
+                return None;
+            }
+            let points: Vec<(f64, f64)> = polygon.points.iter().map(pt).collect();
+            let mut segments = polyline(&points);
+            let closed = points.first() == points.last();
+            if closed {
+                segments.push(Segment::Close);
+            }
+            Some(ShapeOutline {
+                segments,
+                fill: polygon.fill.as_ref(),
+                line: &polygon.line_shape,
+                arrow_points: if closed { Vec::new() } else { points },
+                arrow_ends: None,
+            })
+        }
+        ShapeObject::Curve(curve) => curve_outline(curve, scale),
+        ShapeObject::ConnectLine(connect) => Some(connect_line_outline(connect, scale)),
+        _ => None,
+    }
+}
+
+/// 호: 중심에서 제1축 끝점부터 제2축 끝점까지
+fn arc_outline(arc: &ArcObject, scale: (f64, f64)) -> Option<ShapeOutline<'_>> {
+    let pt = |point: &Point| scaled_point(scale, point.x, point.y);
+    let center = pt(&arc.center);
+    let start = pt(&arc.axis1);
+    let end = pt(&arc.axis2);
+    let (rx, ry) = (distance(center, start), distance(center, end));
+    if rx <= 0.0 || ry <= 0.0 {
+        return None;
+    }
+    let angle = (start.1 - center.1).atan2(start.0 - center.0).to_degrees();
+    // 화면 좌표(y 아래 방향)에서 외적이 양수이면 시계 방향
+    let cross =
+        (start.0 - center.0) * (end.1 - center.1) - (start.1 - center.1) * (end.0 - center.0);
+    let mut segments = vec![
+        Segment::Move(start),
+        Segment::Arc {
+            rx: round_mm(rx),
+            ry: round_mm(ry),
+            rotation: round_mm(angle),
+            large_arc: false,
+            sweep: cross >= 0.0,
+            to: end,
+        },
+    ];
+    segments.extend(arc_close(&arc.arc_type, center));
+    Some(ShapeOutline {
+        segments,
+        fill: (arc.arc_type != ArcType::Normal)
+            .then_some(arc.fill.as_ref())
+            .flatten(),
+        line: &arc.line_shape,
+        arrow_points: vec![start, end],
+        arrow_ends: None,
+    })
+}
+
+/// 곡선: 연속된 CURVE 세그먼트 3개가 3차 베지어 하나 (시작점→제어점1→제어점2→끝점)
+fn curve_outline(curve: &CurveObject, scale: (f64, f64)) -> Option<ShapeOutline<'_>> {
+    let point = |x: i32, y: i32| scaled_point(scale, x, y);
+    let first = curve.segments.first()?;
+    let start = point(first.x1, first.y1);
+    let mut segments = vec![Segment::Move(start)];
+    let mut points = vec![start];
+    let mut i = 0;
+    while i < curve.segments.len() {
+        let seg = &curve.segments[i];
+        let bezier = seg.segment_type == CurveSegmentType::Curve
+            && curve.segments[i..]
+                .iter()
+                .take(3)
+                .filter(|s| s.segment_type == CurveSegmentType::Curve)
+                .count()
+                == 3;
+        if bezier {
+            let cp1 = point(seg.x2, seg.y2);
+            let cp2 = point(curve.segments[i + 1].x2, curve.segments[i + 1].y2);
+            let end = point(curve.segments[i + 2].x2, curve.segments[i + 2].y2);
+            segments.push(Segment::Cubic(cp1, cp2, end));
+            points.extend([cp2, end]);
+            i += 3;
+        } else {
+            let end = point(seg.x2, seg.y2);
+            segments.push(Segment::Line(end));
+            points.push(end);
+            i += 1;
+        }
+    }
+    let closed = points.first() == points.last();
+    if closed {
+        segments.push(Segment::Close);
+    }
+    Some(ShapeOutline {
+        segments,
+        fill: curve.fill.as_ref(),
+        line: &curve.line_shape,
+        arrow_points: if closed { Vec::new() } else { points },
+        arrow_ends: None,
+    })
+}
+
+/// 연결선: 직선 / 꺾인 선(제어점 경유) / 곡선
+fn connect_line_outline(connect: &ConnectLineObject, scale: (f64, f64)) -> ShapeOutline<'_> {
+    let point = |x: i32, y: i32| scaled_point(scale, x, y);
+    let start = point(connect.start_pt.x, connect.start_pt.y);
+    let end = point(connect.end_pt.x, connect.end_pt.y);
+    let controls: Vec<(f64, f64)> = connect
+        .control_points
+        .iter()
+        .map(|p| point(p.x, p.y))
+        .filter(|&p| p != start && p != end)
+        .collect();
+
+    use ConnectLineType::*;
+    let (segments, points) = match connect.connect_type {
+        StraightNoArrow | StraightOneWay | StraightBoth => (
+            vec![Segment::Move(start), Segment::Line(end)],
+            vec![start, end],
+        ),
+        StrokeNoArrow | StrokeOneWay | StrokeBoth => {
+            let mut points = vec![start];
+            if controls.is_empty() {
+                // 제어점이 없으면 가운데에서 한 번 꺾음
+                let mid_x = round_mm((start.0 + end.0) / 2.0);
+                points.extend([(mid_x, start.1), (mid_x, end.1)]);
+            } else {
+                points.extend(controls.iter().copied());
+            }
+            points.push(end);
+            (polyline(&points), points)
+        }
+        ArcNoArrow | ArcOneWay | ArcBoth => {
+            let (cp1, cp2) = match controls.as_slice() {
+                [cp1, .., cp2] => (*cp1, *cp2),
+                [cp] => (*cp, *cp),
+                [] => {
+                    let mid_x = round_mm((start.0 + end.0) / 2.0);
+                    ((mid_x, start.1), (mid_x, end.1))
+                }
+            };
+            (
+                vec![Segment::Move(start), Segment::Cubic(cp1, cp2, end)],
+                vec![start, cp1, cp2, end],
+            )
+        }
+    };
+
+    // 연결선 종류의 화살표 방향이 선 모양의 화살표보다 우선
+    let ends = match connect.connect_type {
+        StraightOneWay | StrokeOneWay | ArcOneWay => Some((false, true)),
+        StraightBoth | StrokeBoth | ArcBoth => Some((true, true)),
+        _ => None,
+    };
+    ShapeOutline {
+        segments,
+        fill: None,
+        line: &connect.line_shape,
+        arrow_points: points,
+        arrow_ends: ends,
+    }
+}
+
+/// 타원 (중심, 제1축 반지름, 제2축 반지름, 제1축 각도). 축 정보가 없으면 개체 크기에 내접
+fn ellipse_axes(
+    ellipse: &EllipseObject,
+    scale: (f64, f64),
+    width: f64,
+    height: f64,
+) -> ((f64, f64), f64, f64, f64) {
+    let pt = |point: &Point| scaled_point(scale, point.x, point.y);
+    let center = pt(&ellipse.center);
+    let axis1 = pt(&ellipse.axis1);
+    let axis2 = pt(&ellipse.axis2);
+    let (rx, ry) = (distance(center, axis1), distance(center, axis2));
+    if rx <= 0.0 || ry <= 0.0 {
+        return ((width / 2.0, height / 2.0), width / 2.0, height / 2.0, 0.0);
+    }
+    let angle = (axis1.1 - center.1).atan2(axis1.0 - center.0).to_degrees();
+    (center, rx, ry, angle)
+}
+
+/// 선 양 끝 화살표 (head = 시작점, tail = 끝점)
+/// `ends`가 있으면 (시작, 끝) 화살표 여부를 강제 (연결선 종류)
+pub(crate) fn arrow_marks(
+    points: &[(f64, f64)],
+    line: &ShapeLineInfo,
+    ends: Option<(bool, bool)>,
+) -> Vec<ArrowMark> {
+    if line.style == LineType1::None || points.len() < 2 {
+        return Vec::new();
+    }
+    let (head, tail) = match ends {
+        Some((head, tail)) => (
+            forced_arrow(&line.head_style, head),
+            forced_arrow(&line.tail_style, tail),
+        ),
+        None => (line.head_style.clone(), line.tail_style.clone()),
+    };
+    let width = stroke_width(line);
+
+    // 끝점과 다른 가장 가까운 점에서 방향을 구함
+    let mut marks = Vec::new();
+    let first = points[0];
+    if let Some(&from) = points.iter().find(|&&p| p != first) {
+        marks.extend(arrow_mark(
+            first,
+            from,
+            &head,
+            &line.head_size,
+            line.head_fill,
+            width,
+        ));
+    }
+    let last = points[points.len() - 1];
+    if let Some(&from) = points.iter().rev().find(|&&p| p != last) {
+        marks.extend(arrow_mark(
+            last,
+            from,
+            &tail,
+            &line.tail_size,
+            line.tail_fill,
+            width,
+        ));
+    }
+    marks
+}
+
+fn arrow_mark(
+    tip: (f64, f64),
+    from: (f64, f64),
+    kind: &ArrowType,
+    size: &ArrowSize,
+    fill_flag: bool,
+    stroke_width: f64,
+) -> Option<ArrowMark> {
+    if *kind == ArrowType::Normal {
+        return None;
+    }
+    let length = distance(from, tip);
+    if length <= 0.0 {
+        return None;
+    }
+    let (ux, uy) = ((tip.0 - from.0) / length, (tip.1 - from.1) / length);
+    let (nx, ny) = (-uy, ux);
+    let (w, l) = arrow_dimensions(size, stroke_width);
+    let at = |along: f64, across: f64| {
+        (
+            round_mm(tip.0 - ux * along + nx * across),
+            round_mm(tip.1 - uy * along + ny * across),
+        )
+    };
+
+    let filled = fill_flag
+        || matches!(
+            kind,
+            ArrowType::Arrow
+                | ArrowType::Spear
+                | ArrowType::ConcaveArrow
+                | ArrowType::FilledDiamond
+                | ArrowType::FilledCircle
+                | ArrowType::FilledBox
+        );
+    let shape = match kind {
+        ArrowType::Arrow => {
+            ArrowShape::Polygon(vec![at(0.0, 0.0), at(l, w / 2.0), at(l, -w / 2.0)])
+        }
+        ArrowType::Spear => {
+            ArrowShape::Polygon(vec![at(0.0, 0.0), at(l, w / 3.0), at(l, -w / 3.0)])
+        }
+        ArrowType::ConcaveArrow => ArrowShape::Polygon(vec![
+            at(0.0, 0.0),
+            at(l, w / 2.0),
+            at(l * 0.7, 0.0),
+            at(l, -w / 2.0),
+        ]),
+        ArrowType::EmptyDiamond | ArrowType::FilledDiamond => ArrowShape::Polygon(vec![
+            at(-l / 2.0, 0.0),
+            at(0.0, w / 2.0),
+            at(l / 2.0, 0.0),
+            at(0.0, -w / 2.0),
+        ]),
+        ArrowType::EmptyBox | ArrowType::FilledBox => ArrowShape::Polygon(vec![
+            at(-w / 2.0, w / 2.0),
+            at(w / 2.0, w / 2.0),
+            at(w / 2.0, -w / 2.0),
+            at(-w / 2.0, -w / 2.0),
+        ]),
+        ArrowType::EmptyCircle | ArrowType::FilledCircle => ArrowShape::Circle {
+            center: tip,
+            radius: round_mm(w / 2.0),
+        },
+        ArrowType::Normal => return None,
+    };
+    Some(ArrowMark {
+        shape,
+        filled,
+        extent: w.max(l),
+    })
+}
+
+/// OLE 개체 내용 (BinData에서 추출)
+pub(crate) fn ole_contents(ole: &OleObject, binaries: &BinaryStore) -> Option<OleContents> {
+    ole.binary_item_id
+        .as_deref()
+        .and_then(|id| doc_utils::find_binary_item(id, binaries))
+        .and_then(|item| crate::ole::extract_ole(&item.data).ok())
+}
+
+/// OLE 자리 표시 이름: 개체 형식 이름, 없으면 "OLE"
+pub(crate) fn ole_label(contents: Option<&OleContents>) -> &str {
+    contents
+        .and_then(|c| c.user_type.as_deref().or(c.prog_id.as_deref()))
+        .unwrap_or("OLE")
+}
+
+/// 개체 크기 (HwpUnit). 묶음 안의 하위 개체처럼 `sz`가 비어 있으면 현재/원본 크기를 사용
+pub fn shape_size(common: &ShapeCommon, component: Option<&ShapeComponentData>) -> (i32, i32) {
+    let size = &common.size;
+    if size.width > 0 || size.height > 0 {
+        return (size.width, size.height);
+    }
+    component
+        .and_then(|c| {
+            c.cur_size
+                .as_ref()
+                .filter(|s| s.width > 0 || s.height > 0)
+                .or(c.org_size.as_ref())
+        })
+        .map(|s| (s.width, s.height))
+        .unwrap_or((0, 0))
+}
+
+/// 개체 내부 좌표 배율: 현재 크기 / 원본 크기
+pub fn point_scale(component: &ShapeComponentData, width: i32, height: i32) -> (f64, f64) {
+    let ratio = |cur: i32, org: i32| {
+        if org > 0 && cur > 0 {
+            cur as f64 / org as f64
+        } else {
+            1.0
+        }
+    };
+    match component.org_size {
+        Some(ref org) => (ratio(width, org.width), ratio(height, org.height)),
+        None => (1.0, 1.0),
+    }
+}
+
+/// 연결선 종류가 화살표를 요구하면 선 모양의 화살표(없으면 기본 화살표)를 사용
+fn forced_arrow(style: &ArrowType, enabled: bool) -> ArrowType {
+    match (enabled, style) {
+        (false, _) => ArrowType::Normal,
+        (true, ArrowType::Normal) => ArrowType::Arrow,
+        (true, style) => style.clone(),
+    }
+}
+
+/// 화살표 (폭, 길이) mm: 크기 이름은 "폭-길이" 순서
+fn arrow_dimensions(size: &ArrowSize, stroke_width: f64) -> (f64, f64) {
+    let unit = (stroke_width * 2.0).max(0.8);
+    let (w, l) = match size {
+        ArrowSize::SmallSmall => (1.0, 1.0),
+        ArrowSize::SmallMedium => (1.0, 1.5),
+        ArrowSize::SmallLarge => (1.0, 2.0),
+        ArrowSize::MediumSmall => (1.5, 1.0),
+        ArrowSize::MediumMedium => (1.5, 1.5),
+        ArrowSize::MediumLarge => (1.5, 2.0),
+        ArrowSize::LargeSmall => (2.0, 1.0),
+        ArrowSize::LargeMedium => (2.0, 1.5),
+        ArrowSize::LargeLarge => (2.0, 2.0),
+    };
+    (round_mm(w * unit), round_mm(l * unit))
+}
+
+/// 선 굵기 (mm). 0이나 비정상적으로 큰 값은 기본 굵기로 대체
+pub(crate) fn stroke_width(line: &ShapeLineInfo) -> f64 {
+    let width = round_mm(hwpunit_to_mm(line.width));
+    if width > 0.0 && width <= MAX_STROKE_MM {
+        width.max(MIN_STROKE_MM)
+    } else {
+        MIN_STROKE_MM
+    }
+}
+
+/// 선 종류 → 점선 패턴 (mm, 선 굵기 배수). 실선은 빈 목록
+pub(crate) fn dash_pattern(style: &LineType1, width: f64) -> Vec<f64> {
+    let pattern: &[f64] = match style {
+        LineType1::Dot => &[1.0, 2.0],
+        LineType1::Dash => &[4.0, 2.0],
+        LineType1::DashDot => &[4.0, 2.0, 1.0, 2.0],
+        LineType1::DashDotDot => &[4.0, 2.0, 1.0, 2.0, 1.0, 2.0],
+        _ => &[],
+    };
+    pattern.iter().map(|v| round_mm(v * width)).collect()
+}
+
+/// 선 종류 → stroke-dasharray
+fn stroke_dash(style: &LineType1, width: f64) -> String {
+    dash_pattern(style, width)
+        .into_iter()
+        .map(num)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn gradient_stops(colors: &[hwp_model::types::Color]) -> String {
+    let last = colors.len().saturating_sub(1).max(1) as f64;
+    colors
+        .iter()
+        .enumerate()
+        .map(|(i, color)| {
+            format!(
+                r#"<stop offset="{}" stop-color="{}"/>"#,
+                num(round_mm(i as f64 / last)),
+                css_color(color.unwrap_or(0))
+            )
+        })
+        .collect()
+}
+
+/// 부채꼴은 중심을 거쳐, 활꼴은 바로 닫음
+fn arc_close(arc_type: &ArcType, center: (f64, f64)) -> Vec<Segment> {
+    match arc_type {
+        ArcType::Normal => Vec::new(),
+        ArcType::Pie => vec![Segment::Line(center), Segment::Close],
+        ArcType::Chord => vec![Segment::Close],
+    }
+}
+
+/// 타원 축 회전을 뺀 중심 기준 각도 (rad)
+fn ellipse_angle(center: (f64, f64), point: (f64, f64), axis_degrees: f64) -> f64 {
+    (point.1 - center.1).atan2(point.0 - center.0) - axis_degrees.to_radians()
+}
+
+pub(crate) fn polyline(points: &[(f64, f64)]) -> Vec<Segment> {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, &p)| {
+            if i == 0 {
+                Segment::Move(p)
+            } else {
+                Segment::Line(p)
+            }
+        })
+        .collect()
+}
+
+/// 경로 → SVG path `d`
+fn path_d(segments: &[Segment]) -> String {
+    segments
+        .iter()
+        .map(|segment| match *segment {
+            Segment::Move(p) => format!("M{}", xy(p)),
+            Segment::Line(p) => format!("L{}", xy(p)),
+            Segment::Cubic(cp1, cp2, p) => format!("C{} {} {}", xy(cp1), xy(cp2), xy(p)),
+            Segment::Arc {
+                rx,
+                ry,
+                rotation,
+                large_arc,
+                sweep,
+                to,
+            } => format!(
+                "A{} {} {} {} {} {}",
+                num(rx),
+                num(ry),
+                num(rotation),
+                u8::from(large_arc),
+                u8::from(sweep),
+                xy(to)
+            ),
+            Segment::Close => "Z".to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+    (b.0 - a.0).hypot(b.1 - a.1)
+}
+
+pub(super) fn css_color(color: u32) -> String {
+    format!(
+        "#{:02X}{:02X}{:02X}",
+        (color >> 16) & 0xFF,
+        (color >> 8) & 0xFF,
+        color & 0xFF
+    )
+}
+
+fn xy(point: (f64, f64)) -> String {
+    format!("{},{}", num(point.0), num(point.1))
+}
+
+/// mm 값을 불필요한 0 없이 출력
+pub(super) fn num(value: f64) -> String {
+    let value = round_mm(value);
+    if value == 0.0 {
+        "0".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// 32비트 FNV-1a (실행마다 같은 값이 필요한 id용)
+fn fnv1a(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c_9dc5, |hash: u32, &b| {
+        (hash ^ b as u32).wrapping_mul(0x0100_0193)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hwp_model::document::ImageFormat;
+    use hwp_model::shape::{ConnectPoint, CurveSegment, PolygonObject};
+    use hwp_model::types::{Point, Size};
+
+    fn common(width: i32, height: i32) -> ShapeCommon {
+        let mut common = ShapeCommon::default();
+        common.size.width = width;
+        common.size.height = height;
+        common
+    }
+
+    fn solid_line() -> ShapeLineInfo {
+        ShapeLineInfo {
+            style: LineType1::Solid,
+            width: 283, // 1mm
+            ..Default::default()
+        }
+    }
+
+    fn render(shape: ShapeObject) -> String {
+        render_shape_svg(&shape, &BinaryStore::default(), "hwp-").unwrap()
+    }
+
+    #[test]
+    fn test_ellipse_and_fill() {
+        let ellipse = EllipseObject {
+            common: common(7200, 3600),
+            center: Point { x: 3600, y: 1800 },
+            axis1: Point { x: 7200, y: 1800 },
+            axis2: Point { x: 3600, y: 0 },
+            line_shape: solid_line(),
+            fill: Some(FillBrush::WinBrush {
+                face_color: Some(0xFF0000),
+                hatch_color: None,
+                hatch_style: None,
+                alpha: 0,
+            }),
+            ..Default::default()
+        };
+        let svg = render(ShapeObject::Ellipse(Box::new(ellipse)));
+        assert!(svg.starts_with(r#"<svg class="hwp-shape""#));
+        assert!(svg.contains(
+            r##"<ellipse cx="12.7" cy="6.35" rx="12.7" ry="6.35" fill="#FF0000" stroke="#000000" stroke-width="1"/>"##
+        ));
+    }
+
+    #[test]
+    fn test_points_scale_to_current_size() {
+        // 원본 크기의 절반으로 줄인 다각형
+        let polygon = PolygonObject {
+            common: common(3600, 3600),
+            component: ShapeComponentData {
+                org_size: Some(Size {
+                    width: 7200,
+                    height: 7200,
+                }),
+                ..Default::default()
+            },
+            points: vec![
+                Point { x: 0, y: 0 },
+                Point { x: 7200, y: 0 },
+                Point { x: 7200, y: 7200 },
+                Point { x: 0, y: 0 },
+            ],
+            line_shape: solid_line(),
+            ..Default::default()
+        };
+        let svg = render(ShapeObject::Polygon(Box::new(polygon)));
+        assert!(svg.contains(r#"d="M0,0 L12.7,0 L12.7,12.7 L0,0 Z""#));
+    }
+
+    #[test]
+    fn test_connect_line_with_arrow() {
+        let connect = ConnectLineObject {
+            common: common(7200, 3600),
+            connect_type: ConnectLineType::StrokeOneWay,
+            start_pt: ConnectPoint {
+                x: 0,
+                y: 0,
+                ..Default::default()
+            },
+            end_pt: ConnectPoint {
+                x: 7200,
+                y: 3600,
+                ..Default::default()
+            },
+            line_shape: solid_line(),
+            ..Default::default()
+        };
+        let svg = render(ShapeObject::ConnectLine(Box::new(connect)));
+        // 제어점이 없으면 가운데에서 꺾고, 끝점에만 화살표
+        assert!(svg.contains(r#"d="M0,0 L12.7,0 L12.7,12.7 L25.4,12.7""#));
+        assert_eq!(svg.matches("<polygon").count(), 1);
+        assert!(svg.contains(r#"<polygon points="25.4,12.7 22.4,14.2 22.4,11.2""#));
+    }
+
+    #[test]
+    fn test_curve_and_rotation() {
+        let segment = |segment_type, x1, y1, x2, y2| CurveSegment {
+            segment_type,
+            x1,
+            y1,
+            x2,
+            y2,
+        };
+        let curve = CurveObject {
+            common: common(7200, 7200),
+            component: ShapeComponentData {
+                rotation: Some(hwp_model::shape::Rotation {
+                    angle: 90.0,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            segments: vec![
+                segment(CurveSegmentType::Curve, 0, 7200, 0, 0),
+                segment(CurveSegmentType::Curve, 0, 0, 7200, 0),
+                segment(CurveSegmentType::Curve, 7200, 0, 7200, 7200),
+                segment(CurveSegmentType::Line, 7200, 7200, 0, 7200),
+            ],
+            line_shape: solid_line(),
+            ..Default::default()
+        };
+        let svg = render(ShapeObject::Curve(Box::new(curve)));
+        assert!(svg.contains(r#"d="M0,25.4 C0,0 25.4,0 25.4,25.4 L0,25.4 Z""#));
+        assert!(svg.contains(r#"<g transform="rotate(90 12.7 12.7)">"#));
+    }
+
+    #[test]
+    fn test_gradient_defs_and_placeholders() {
+        let fill = FillBrush::Gradation {
+            grad_type: GradationType::Linear,
+            angle: 90,
+            center_x: 0,
+            center_y: 0,
+            step: 0,
+            color_num: 2,
+            step_center: 50,
+            colors: vec![Some(0x000000), Some(0xFFFFFF)],
+            alpha: 0,
+        };
+        let rect = RectObject {
+            common: common(7200, 7200),
+            fill: Some(fill),
+            ..Default::default()
+        };
+        let svg = render(ShapeObject::Rectangle(Box::new(rect)));
+        assert!(svg.contains(r#"<linearGradient id="hwp-grad-"#));
+        assert!(svg.contains(r##"gradientTransform="rotate(90 0.5 0.5)"><stop offset="0" stop-color="#000000"/><stop offset="1" stop-color="#FFFFFF"/>"##));
+        assert!(svg.contains(r#"fill="url(#hwp-grad-"#));
+
+        let chart = hwp_model::shape::ChartObject {
+            common: common(7200, 7200),
+            ..Default::default()
+        };
+        assert!(render(ShapeObject::Chart(Box::new(chart.clone()))).contains(">차트</text>"));
+        let chart = hwp_model::shape::ChartObject {
+            chart: Some(hwp_model::chart::Chart {
+                series: vec![hwp_model::chart::ChartSeries {
+                    values: vec![Some(1.0)],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            ..chart
+        };
+        let svg = render(ShapeObject::Chart(Box::new(chart)));
+        assert!(!svg.contains(">차트</text>") && svg.contains(r##"fill="#4F81BD""##));
+
+        // OLE: 비트맵 미리보기가 있으면 이미지, 없으면 형식 이름
+        let ole = hwp_model::shape::OleObject {
+            common: common(7200, 3600),
+            binary_item_id: Some("BIN0001".to_string()),
+            ..Default::default()
+        };
+        let mut binaries = BinaryStore::default();
+        binaries.items.push(hwp_model::document::BinaryItem {
+            id: "BIN0001".to_string(),
+            src: "BIN0001.ole".to_string(),
+            format: ImageFormat::Unknown("ole".to_string()),
+            data: crate::ole::tests::excel_sheet_storage(),
+        });
+        let shape = ShapeObject::Ole(Box::new(ole));
+        let svg = render_shape_svg(&shape, &binaries, "hwp-").unwrap();
+        assert!(svg.contains(r#"<image x="0" y="0" width="25.4" height="12.7" preserveAspectRatio="none" href="data:image/bmp;base64,Qk0"#));
+        assert!(render(shape).contains(">OLE</text>"));
+
+        // 표/그림은 HTML로 그림
+        let picture = hwp_model::shape::Picture::default();
+        assert!(render_shape_svg(
+            &ShapeObject::Picture(Box::new(picture)),
+            &BinaryStore::default(),
+            "hwp-"
+        )
+        .is_none());
+    }
+}
@@ -4,6 +4,11 @@ mod paragraph;
 
 use hwp_model::control::Control;
 use hwp_model::document::Document;
+use hwp_model::outline::OutlineEntry;
+
+use crate::viewer::core::outline::format_outline_number;
+use crate::viewer::doc_utils::with_evaluated_fields;
+use crate::viewer::revision::{apply_revision_view, RevisionView};
 
 /// Markdown 변환 옵션
 #[derive(Debug, Clone)]
@@ -16,6 +21,12 @@ pub struct DocMarkdownOptions {
     pub include_version: Option<bool>,
     /// 페이지 정보 포함 여부
     pub include_page_info: Option<bool>,
+    /// 변경 추적 보기 모드 (기본값: 변경 표시 — use_html이면 <ins>/<del>, 아니면 CriticMarkup {++ ++}/{-- --})
+    pub revision_view: RevisionView,
+    /// 계산식/문서 요약/상호 참조 필드 결과를 다시 계산해서 쓸지 여부 (기본값 true)
+    pub evaluate_fields: bool,
+    /// 개요 제목으로 만든 차례(제목 링크 목록)를 본문 앞에 넣을지 여부 (기본값 false)
+    pub table_of_contents: bool,
 }
 
 impl Default for DocMarkdownOptions {
@@ -25,12 +36,20 @@ impl Default for DocMarkdownOptions {
             use_html: false,
             include_version: None,
             include_page_info: None,
+            revision_view: RevisionView::Markup,
+            evaluate_fields: true,
+            table_of_contents: false,
         }
     }
 }
 
 /// Document를 Markdown으로 변환
 pub fn doc_to_markdown(doc: &Document, options: &DocMarkdownOptions) -> String {
+    let mut doc = apply_revision_view(doc, options.revision_view);
+    if options.evaluate_fields {
+        doc = with_evaluated_fields(doc);
+    }
+    let doc = &*doc;
     let mut lines: Vec<String> = Vec::new();
 
     // 문서 헤더
@@ -39,11 +58,7 @@ pub fn doc_to_markdown(doc: &Document, options: &DocMarkdownOptions) -> String {
 
     // 버전 정보
     if options.include_version != Some(false) {
-        if let Some(ref hints) = doc.hwp_hints {
-            let (a, b, c, d) = hints.version;
-            lines.push(format!("**버전**: {}.{:02}.{:02}.{:02}", a, b, c, d));
-            lines.push(String::new());
-        } else if let Some(ref hints) = doc.hwpx_hints {
+        if let Some(ref hints) = doc.hwpx_hints {
             if let Some(ref ver) = hints.app_version {
                 lines.push(format!("**버전**: {}", ver));
                 lines.push(String::new());
@@ -73,6 +88,9 @@ pub fn doc_to_markdown(doc: &Document, options: &DocMarkdownOptions) -> String {
     let mut footers: Vec<String> = Vec::new();
     let mut footnotes: Vec<String> = Vec::new();
     let mut endnotes: Vec<String> = Vec::new();
+    // 차례 링크용: 제목 문단 (구역, 문단) → Markdown 제목 줄
+    let mut heading_lines: std::collections::BTreeMap<(usize, usize), String> =
+        std::collections::BTreeMap::new();
 
     for (section_idx, section) in doc.sections.iter().enumerate() {
         // 섹션 간 구분선 (기존 viewer의 render_page_break와 동일하게 "---\n")
@@ -83,7 +101,7 @@ pub fn doc_to_markdown(doc: &Document, options: &DocMarkdownOptions) -> String {
             }
         }
 
-        for para in &section.paragraphs {
+        for (para_idx, para) in section.paragraphs.iter().enumerate() {
             // 페이지 구분선
             if para.page_break && !body_lines.is_empty() {
                 let last = body_lines.last().map(String::as_str).unwrap_or("");
@@ -101,7 +119,10 @@ pub fn doc_to_markdown(doc: &Document, options: &DocMarkdownOptions) -> String {
                 &mut number_tracker,
             );
 
-            let has_header_footer_note = !ctrl_parts.is_empty();
+            let has_header_footer_note = ctrl_parts
+                .iter()
+                .any(|part| !matches!(part, ControlPart::Memo(_)));
+            let mut memo_blocks: Vec<String> = Vec::new();
 
             for part in ctrl_parts {
                 match part {
@@ -142,6 +163,7 @@ pub fn doc_to_markdown(doc: &Document, options: &DocMarkdownOptions) -> String {
                         }
                         endnotes.push(format!("[^{}]{}", ref_num, text));
                     }
+                    ControlPart::Memo(block) => memo_blocks.push(block),
                 }
             }
 
@@ -164,9 +186,24 @@ pub fn doc_to_markdown(doc: &Document, options: &DocMarkdownOptions) -> String {
                     body.trim_start().to_string()
                 };
                 if !body.is_empty() && !body.trim().is_empty() {
+                    if has_heading && body.starts_with('#') {
+                        heading_lines.insert((section_idx, para_idx), body.clone());
+                    }
                     body_lines.push(body);
                 }
             }
+            // 메모는 문단 바로 뒤에 인용 블록으로
+            body_lines.extend(memo_blocks);
+        }
+    }
+
+    if options.table_of_contents {
+        let outline = doc.outline();
+        if !outline.is_empty() {
+            lines.push(format!("## {}", TOC_TITLE));
+            lines.push(String::new());
+            lines.push(render_toc_list(&outline, &heading_lines));
+            lines.push(String::new());
         }
     }
 
@@ -215,12 +252,101 @@ pub fn doc_to_markdown(doc: &Document, options: &DocMarkdownOptions) -> String {
     lines.join("\n\n")
 }
 
+/// 차례 제목
+const TOC_TITLE: &str = "차례";
+
+/// 차례 목록: 개요 수준대로 들여 쓴 글머리표 목록.
+/// Markdown 제목이 된 항목은 제목 앵커(GitHub 방식 id)로 링크한다.
+fn render_toc_list(
+    outline: &[OutlineEntry],
+    heading_lines: &std::collections::BTreeMap<(usize, usize), String>,
+) -> String {
+    // 문서 순서대로 앵커를 매긴다 (같은 id는 -1, -2 …). 문서 머리와 차례 제목이 먼저 나온다
+    let mut seen: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+    heading_anchor("HWP 문서", &mut seen);
+    heading_anchor(TOC_TITLE, &mut seen);
+    let anchors: std::collections::HashMap<(usize, usize), String> = heading_lines
+        .iter()
+        .map(|(key, line)| {
+            (
+                *key,
+                heading_anchor(line.trim_start_matches('#'), &mut seen),
+            )
+        })
+        .collect();
+
+    fn push_items(
+        entries: &[OutlineEntry],
+        depth: usize,
+        anchors: &std::collections::HashMap<(usize, usize), String>,
+        out: &mut Vec<String>,
+    ) {
+        for entry in entries {
+            let text = match entry.number {
+                Some(number) => {
+                    format!(
+                        "{} {}",
+                        format_outline_number(entry.level, number),
+                        entry.title
+                    )
+                }
+                None => entry.title.clone(),
+            };
+            let item = match anchors.get(&(entry.section, entry.paragraph)) {
+                Some(anchor) => format!("[{}](#{})", escape_link_text(&text), anchor),
+                None => text,
+            };
+            out.push(format!("{}- {}", "  ".repeat(depth), item));
+            push_items(&entry.children, depth + 1, anchors, out);
+        }
+    }
+
+    let mut items = Vec::new();
+    push_items(outline, 0, &anchors, &mut items);
+    items.join("\n")
+}
+
+/// 제목 텍스트 → 앵커 id (GitHub 방식: 소문자, 문자/숫자/`-`/`_`만 남기고 공백은 `-`)
+fn heading_anchor(text: &str, seen: &mut std::collections::HashMap<String, usize>) -> String {
+    let slug: String = text
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            '-' | '_' => Some(c),
+            c if c.is_alphanumeric() => Some(c),
+            _ => None,
+        })
+        .collect();
+    let count = seen.entry(slug.clone()).or_insert(0);
+    let anchor = if *count == 0 {
+        slug
+    } else {
+        format!("{}-{}", slug, count)
+    };
+    *count += 1;
+    anchor
+}
+
+fn escape_link_text(text: &str) -> String {
+    text.replace('[', "\\[").replace(']', "\\]")
+}
+
 /// 컨트롤에서 추출된 문서 부분
 pub(crate) enum ControlPart {
     Header(String),
     Footer(String),
-    Footnote { ref_num: u16, text: String },
-    Endnote { ref_num: u16, text: String },
+    Footnote {
+        ref_num: u16,
+        text: String,
+    },
+    Endnote {
+        ref_num: u16,
+        text: String,
+    },
+    /// 메모 인용 블록 (`> ` 줄들)
+    Memo(String),
 }
 
 /// SubList 내부의 문단들을 렌더링
@@ -1,16 +1,18 @@
 /// Document 기반 문단 → Markdown 변환
 use hwp_model::document::BinaryStore;
+use hwp_model::memo::Memo;
 use hwp_model::paragraph::{Paragraph, Run, RunContent, TextContent, TextElement};
 use hwp_model::resources::Resources;
 use hwp_model::shape::ShapeObject;
 use hwp_model::table::Table;
 use hwp_model::types::HeadingType;
 
-use super::{extract_control_parts, ControlPart, DocMarkdownOptions};
+use super::{extract_control_parts, render_sublist_paragraphs, ControlPart, DocMarkdownOptions};
 use crate::viewer::core::outline::{
     format_outline_number, format_with_numbering, OutlineNumberTracker,
 };
 use crate::viewer::doc_utils;
+use hwp_model::revision::RevisionKind;
 
 /// 문단 하나를 Markdown으로 렌더링.
 /// (본문 텍스트, 추출된 컨트롤 파트들, heading 적용 여부) 반환.
@@ -107,6 +109,8 @@ fn render_paragraph_inner(
     };
 
     let mut prev_run_had_objects = false;
+    let mut revisions: Vec<(String, RevisionKind)> = Vec::new();
+    let mut memo_fields: Vec<Option<String>> = Vec::new();
     for run in &para.runs {
         let (run_text, mut run_controls) = render_run(
             run,
@@ -118,6 +122,8 @@ fn render_paragraph_inner(
             &mut endnote_counter,
             &mut hyperlink_url,
             &mut hyperlink_text_parts,
+            &mut revisions,
+            &mut memo_fields,
         );
         let current_has_objects = run
             .contents
@@ -157,6 +163,9 @@ fn render_paragraph_inner(
         }
     }
 
+    // 메모 구간이 FieldEnd 없이 끝난 경우: 문단 끝에 참조 표시
+    text_parts.extend(memo_fields.into_iter().rev().flatten());
+
     let body = text_parts.join("");
     // 연속 탭을 단일 탭으로 (convert에서 중복 생성 방지)
     let body = body.replace("\t\t", "\t");
@@ -165,6 +174,8 @@ fn render_paragraph_inner(
 
 /// Run 하나를 Markdown으로 렌더링
 /// hyperlink_url/hyperlink_text_parts: paragraph 레벨에서 Run 간 하이퍼링크 상태 공유
+/// revisions: paragraph 레벨에서 열려 있는 변경 구간 (본문 표시 ID, 종류)
+/// memo_fields: 열려 있는 필드 (메모 필드이면 구간 끝에 붙일 참조 표시)
 #[allow(clippy::too_many_arguments)]
 fn render_run(
     run: &Run,
@@ -176,6 +187,8 @@ fn render_run(
     endnote_counter: &mut u16,
     hyperlink_url: &mut Option<String>,
     hyperlink_text_parts: &mut Vec<String>,
+    revisions: &mut Vec<(String, RevisionKind)>,
+    memo_fields: &mut Vec<Option<String>>,
 ) -> (String, Vec<ControlPart>) {
     let mut text_parts: Vec<String> = Vec::new();
     let mut control_parts: Vec<ControlPart> = Vec::new();
@@ -205,7 +218,7 @@ fn render_run(
     for content in &run.contents {
         match content {
             RunContent::Text(text_content) => {
-                let text = render_text_content(text_content);
+                let text = render_text_content(text_content, revisions, options.use_html);
                 if !text.is_empty() {
                     if hyperlink_url.is_some() {
                         // 하이퍼링크 범위 내 텍스트 수집 (paragraph 레벨)
@@ -219,6 +232,15 @@ fn render_run(
             RunContent::Control(control) => {
                 // 하이퍼링크 시작
                 if let hwp_model::control::Control::FieldBegin(field) = control {
+                    // 메모: 구간 끝에 참조를 달고 내용은 문단 뒤 인용 블록으로
+                    if let Some(memo) = field.memo() {
+                        memo_fields.push(Some(memo_reference(memo.number)));
+                        let text =
+                            render_sublist_paragraphs(&memo.content, resources, binaries, options);
+                        control_parts.push(ControlPart::Memo(render_memo_block(&memo, &text)));
+                        continue;
+                    }
+                    memo_fields.push(None);
                     if field.field_type == hwp_model::types::FieldType::Hyperlink {
                         // 이전 하이퍼링크가 끝나지 않았으면 먼저 출력
                         if let Some(prev_url) = hyperlink_url.take() {
@@ -242,6 +264,14 @@ fn render_run(
 
                 // 하이퍼링크 종료
                 if let hwp_model::control::Control::FieldEnd = control {
+                    if let Some(reference) = memo_fields.pop().flatten() {
+                        if hyperlink_url.is_some() {
+                            hyperlink_text_parts.push(reference);
+                        } else {
+                            text_parts.push(reference);
+                        }
+                        continue;
+                    }
                     if let Some(url) = hyperlink_url.take() {
                         let display = hyperlink_text_parts.join("");
                         // 필드 경계 오류 감지: 이전 텍스트가 단어 중간에서 끊겼으면
@@ -323,19 +353,92 @@ fn render_run(
     (text_parts.join(""), control_parts)
 }
 
-/// TextContent를 Markdown 텍스트로 변환
-fn render_text_content(tc: &TextContent) -> String {
-    let mut parts = Vec::new();
+/// 본문에 다는 메모 참조 표시
+fn memo_reference(number: Option<u32>) -> String {
+    match number {
+        Some(n) => format!("[메모 {}]", n),
+        None => "[메모]".to_string(),
+    }
+}
+
+/// 메모 인용 블록: 첫 줄은 "**[메모 N]** 작성자 (일시)", 이어서 메모 내용
+fn render_memo_block(memo: &Memo, text: &str) -> String {
+    let mut header = format!("**{}**", memo_reference(memo.number));
+    match (&memo.author, &memo.date) {
+        (Some(author), Some(date)) => header.push_str(&format!(" {} ({})", author, date)),
+        (Some(author), None) => header.push_str(&format!(" {}", author)),
+        (None, Some(date)) => header.push_str(&format!(" ({})", date)),
+        (None, None) => {}
+    }
+    let mut lines = vec![format!("> {}", header)];
+    for line in text.lines() {
+        if line.is_empty() {
+            lines.push(">".to_string());
+        } else {
+            lines.push(format!("> {}", line));
+        }
+    }
+    lines.join("\n")
+}
+
+/// TextContent를 Markdown 텍스트로 변환.
+/// 변경 구간은 TextContent마다 닫았다가 다시 열어 강조 표시와 엇갈리지 않게 한다.
+fn render_text_content(
+    tc: &TextContent,
+    revisions: &mut Vec<(String, RevisionKind)>,
+    use_html: bool,
+) -> String {
+    let open = |kind: RevisionKind| match (kind, use_html) {
+        (RevisionKind::Insert, true) => "<ins>",
+        (RevisionKind::Delete, true) => "<del>",
+        (RevisionKind::Insert, false) => "{++",
+        (RevisionKind::Delete, false) => "{--",
+    };
+    let close = |kind: RevisionKind| match (kind, use_html) {
+        (RevisionKind::Insert, true) => "</ins>",
+        (RevisionKind::Delete, true) => "</del>",
+        (RevisionKind::Insert, false) => "++}",
+        (RevisionKind::Delete, false) => "--}",
+    };
+
+    let mut parts: Vec<String> = revisions
+        .iter()
+        .map(|(_, kind)| open(*kind).to_string())
+        .collect();
+    let mut has_content = false;
     for elem in &tc.elements {
-        match elem {
-            TextElement::Text(s) => parts.push(s.clone()),
-            TextElement::Tab { .. } => parts.push("\t".to_string()),
-            TextElement::LineBreak => parts.push("  \n".to_string()),
-            TextElement::NbSpace => parts.push("\u{00a0}".to_string()),
-            TextElement::FwSpace => parts.push(" ".to_string()),
-            TextElement::Hyphen => parts.push("-".to_string()),
-            _ => {} // MarkpenBegin/End, TitleMark, InsertBegin/End 등 무시
+        if let Some(mark) = elem.revision_mark() {
+            if mark.begin {
+                parts.push(open(mark.kind).to_string());
+                revisions.push((mark.id.to_string(), mark.kind));
+            } else if let Some(pos) = revisions.iter().rposition(|(id, _)| id == mark.id) {
+                for (_, kind) in revisions[pos..].iter().rev() {
+                    parts.push(close(*kind).to_string());
+                }
+                revisions.remove(pos);
+                for (_, kind) in &revisions[pos..] {
+                    parts.push(open(*kind).to_string());
+                }
+            }
+            continue;
         }
+        let text = match elem {
+            TextElement::Text(s) => s.clone(),
+            TextElement::Tab { .. } => "\t".to_string(),
+            TextElement::LineBreak => "  \n".to_string(),
+            TextElement::NbSpace => "\u{00a0}".to_string(),
+            TextElement::FwSpace => " ".to_string(),
+            TextElement::Hyphen => "-".to_string(),
+            _ => continue, // MarkpenBegin/End, TitleMark 등 무시
+        };
+        has_content = true;
+        parts.push(text);
+    }
+    if !has_content {
+        return String::new();
+    }
+    for (_, kind) in revisions.iter().rev() {
+        parts.push(close(*kind).to_string());
     }
     parts.join("")
 }
@@ -417,13 +520,55 @@ fn render_shape_object(
             if eq.script.is_empty() {
                 String::new()
             } else {
-                format!("`{}`", eq.script)
+                // 수식 스크립트는 사용자 입력이라 HTML 태그가 그대로 나가지 않게 이스케이프
+                format!(
+                    "${}$",
+                    doc_utils::html_escape(&crate::viewer::equation::equation_to_latex(&eq.script))
+                )
             }
         }
+        ShapeObject::Chart(chart) => chart.chart.as_ref().map(render_chart).unwrap_or_default(),
         _ => String::new(),
     }
 }
 
+/// 차트 데이터를 Markdown 표로 변환 (행: 항목, 열: 계열)
+/// 표와 같은 포맷(앞뒤 빈 줄)이며, 첫 열 머리글은 차트 제목 (없으면 "항목")
+fn render_chart(chart: &hwp_model::chart::Chart) -> String {
+    if chart.series.is_empty() {
+        return String::new();
+    }
+    let cell = |text: &str| text.replace('|', "\\|").replace('\n', " ");
+    let rows = chart
+        .series
+        .iter()
+        .map(|s| s.values.len())
+        .fold(chart.categories.len(), usize::max);
+
+    let mut header = vec![cell(chart.title.as_deref().unwrap_or("항목"))];
+    header.extend(chart.series.iter().enumerate().map(|(i, s)| match &s.name {
+        Some(name) => cell(name),
+        None => format!("계열{}", i + 1),
+    }));
+    let mut lines = vec![
+        format!("| {} |", header.join(" | ")),
+        format!("|{}", "---|".repeat(header.len())),
+    ];
+    for row in 0..rows {
+        let mut cells = vec![cell(chart.categories.get(row).map_or("", |c| c.as_str()))];
+        cells.extend(chart.series.iter().map(|s| {
+            s.values
+                .get(row)
+                .copied()
+                .flatten()
+                .map(|v| v.to_string())
+                .unwrap_or_default()
+        }));
+        lines.push(format!("| {} |", cells.join(" | ")));
+    }
+    format!("\n{}\n", lines.join("\n"))
+}
+
 /// Table을 Markdown 표로 변환
 /// 기존 viewer와 동일한 포맷: 앞뒤 빈 줄, 빈 셀은 공백, 구분선은 |---|
 fn render_table(
@@ -1,6 +1,38 @@
 /// Document 기반 viewer 공통 유틸리티
 use hwp_model::control::{Field, FieldParameter};
-use hwp_model::document::{BinaryStore, ImageFormat};
+use hwp_model::document::{BinaryStore, Document, ImageFormat};
+use hwp_model::field::FieldContext;
+use hwp_model::shape::{ShapeCommon, ShapeComponentData, ShapeObject};
+use std::borrow::Cow;
+
+/// 필드 결과(계산식, 문서 요약, 상호 참조 등)를 다시 계산한 문서.
+/// 계산할 필드가 없으면 그대로 둔다. 날짜 필드는 저장된 결과를 유지한다.
+pub fn with_evaluated_fields(doc: Cow<'_, Document>) -> Cow<'_, Document> {
+    if !doc.has_computed_fields() {
+        return doc;
+    }
+    let mut doc = doc.into_owned();
+    doc.evaluate_fields(&FieldContext::default());
+    Cow::Owned(doc)
+}
+
+/// HTML/XML 텍스트·속성 값 이스케이프 (`&`, `<`, `>`, `"`).
+/// XML에서 쓸 수 없는 제어 문자(탭/줄바꿈 제외)는 뺀다
+pub fn html_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\t' | '\n' | '\r' => out.push(c),
+            c if c.is_control() => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
 
 /// 하이퍼링크 필드에서 URL 추출
 /// HWP: Field.name에 "%hlk" command 문자열 (URL;타입 형식)
@@ -124,6 +156,21 @@ pub fn image_format_to_mime(format: &ImageFormat) -> &'static str {
     }
 }
 
+/// 브라우저에 넣을 이미지 데이터와 MIME type
+/// WMF/EMF는 SVG로 변환하고, `keep_metafile`이거나 변환할 수 없으면 원본 그대로
+pub fn displayable_image<'a>(
+    format: &ImageFormat,
+    data: &'a [u8],
+    keep_metafile: bool,
+) -> (Cow<'a, [u8]>, &'static str) {
+    if !keep_metafile && matches!(format, ImageFormat::Wmf | ImageFormat::Emf) {
+        if let Some(svg) = crate::viewer::metafile::metafile_to_svg(data) {
+            return (Cow::Owned(svg.into_bytes()), "image/svg+xml");
+        }
+    }
+    (Cow::Borrowed(data), image_format_to_mime(format))
+}
+
 /// BinaryStore에서 ID로 아이템 찾기
 pub fn find_binary_item<'a>(
     binary_item_id: &str,
@@ -135,11 +182,115 @@ pub fn find_binary_item<'a>(
     binaries.items.iter().find(|b| b.id == binary_item_id)
 }
 
+/// 개체 공통 속성 / Common properties of an object
+pub fn shape_common(object: &ShapeObject) -> &ShapeCommon {
+    match object {
+        ShapeObject::Table(o) => &o.common,
+        ShapeObject::Picture(o) => &o.common,
+        ShapeObject::Line(o) => &o.common,
+        ShapeObject::Rectangle(o) => &o.common,
+        ShapeObject::Ellipse(o) => &o.common,
+        ShapeObject::Arc(o) => &o.common,
+        ShapeObject::Polygon(o) => &o.common,
+        ShapeObject::Curve(o) => &o.common,
+        ShapeObject::ConnectLine(o) => &o.common,
+        ShapeObject::TextArt(o) => &o.common,
+        ShapeObject::Container(o) => &o.common,
+        ShapeObject::Ole(o) => &o.common,
+        ShapeObject::Equation(o) => &o.common,
+        ShapeObject::Chart(o) => &o.common,
+        ShapeObject::Video(o) => &o.common,
+        ShapeObject::Form(o) => &o.common,
+    }
+}
+
+/// 도형 요소 속성 (표/수식/비디오는 없음) / Shape component data, if the object has one
+pub fn shape_component(object: &ShapeObject) -> Option<&ShapeComponentData> {
+    Some(match object {
+        ShapeObject::Picture(o) => &o.component,
+        ShapeObject::Line(o) => &o.component,
+        ShapeObject::Rectangle(o) => &o.component,
+        ShapeObject::Ellipse(o) => &o.component,
+        ShapeObject::Arc(o) => &o.component,
+        ShapeObject::Polygon(o) => &o.component,
+        ShapeObject::Curve(o) => &o.component,
+        ShapeObject::ConnectLine(o) => &o.component,
+        ShapeObject::TextArt(o) => &o.component,
+        ShapeObject::Container(o) => &o.component,
+        ShapeObject::Ole(o) => &o.component,
+        ShapeObject::Chart(o) => &o.component,
+        _ => return None,
+    })
+}
+
 /// CSS font-family 값 이스케이핑 (single quote 내부용)
 pub fn escape_css_font_name(name: &str) -> String {
     name.replace('\\', "\\\\").replace('\'', "\\'")
 }
 
+/// BMP → (너비, 높이, RGB 8비트 픽셀)
+/// BI_RGB 1/4/8/24/32비트와 기본 마스크의 32비트 BI_BITFIELDS만 지원합니다.
+pub fn decode_bmp(data: &[u8]) -> Option<(usize, usize, Vec<u8>)> {
+    let u16_at = |pos: usize| -> Option<u16> {
+        Some(u16::from_le_bytes(data.get(pos..pos + 2)?.try_into().ok()?))
+    };
+    let u32_at = |pos: usize| -> Option<u32> {
+        Some(u32::from_le_bytes(data.get(pos..pos + 4)?.try_into().ok()?))
+    };
+
+    let pixel_offset = u32_at(10)? as usize;
+    let header_size = u32_at(14)? as usize;
+    let width = u32_at(18)? as i32;
+    let height = u32_at(22)? as i32;
+    let bits = u16_at(28)? as usize;
+    let compression = u32_at(30)?;
+    if width <= 0 || height == 0 || !(compression == 0 || (compression == 3 && bits == 32)) {
+        return None;
+    }
+    let (width, top_down) = (width as usize, height < 0);
+    let height = height.unsigned_abs() as usize;
+
+    let palette: Vec<[u8; 3]> = if bits <= 8 {
+        let used = u32_at(46).unwrap_or(0) as usize;
+        let count = if used > 0 { used } else { 1 << bits };
+        (0..count)
+            .filter_map(|i| {
+                let at = 14 + header_size + i * 4;
+                let entry = data.get(at..at + 3)?;
+                Some([entry[2], entry[1], entry[0]])
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let stride = (width * bits + 31) / 32 * 4;
+    let mut rgb = Vec::with_capacity(width * height * 3);
+    for y in 0..height {
+        let row = if top_down { y } else { height - 1 - y };
+        let start = pixel_offset + row * stride;
+        let line = data.get(start..start + stride)?;
+        for x in 0..width {
+            let pixel = match bits {
+                24 | 32 => {
+                    let at = x * bits / 8;
+                    [line[at + 2], line[at + 1], line[at]]
+                }
+                1 | 4 | 8 => {
+                    let bit = x * bits;
+                    let byte = line[bit / 8];
+                    let shift = 8 - bits - bit % 8;
+                    let index = (byte >> shift) as usize & ((1 << bits) - 1);
+                    *palette.get(index)?
+                }
+                _ => return None,
+            };
+            rgb.extend_from_slice(&pixel);
+        }
+    }
+    Some((width, height, rgb))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,6 +369,14 @@ mod tests {
         assert_eq!(extract_hyperlink_url(&field), "");
     }
 
+    #[test]
+    fn test_html_escape() {
+        assert_eq!(
+            html_escape("a<b & \"c\"\u{1}\t"),
+            "a&lt;b &amp; &quot;c&quot;\t"
+        );
+    }
+
     #[test]
     fn test_escape_css_font_name() {
         assert_eq!(escape_css_font_name("맑은 고딕"), "맑은 고딕");
@@ -0,0 +1,1034 @@
+/// document.xml 본문 / 머리말·꼬리말 / 각주·미주 작성
+/// Body, header/footer and note parts
+use super::styles::{
+    border_fill, borders, paragraph_properties, run_properties, shading, style_id, ParagraphExtras,
+    ENDNOTE_REFERENCE, FOOTNOTE_REFERENCE,
+};
+use super::{
+    emu, media_type, new_part, twips, w_val, DocxOptions, Rels, A_NS, PIC_NS, REL_FOOTER,
+    REL_HEADER, REL_IMAGE, R_NS, WP_NS, W_NS,
+};
+use crate::viewer::canvas::table::resolve_positions;
+use crate::viewer::doc_utils::{extract_hyperlink_url, find_binary_item};
+use hwp_model::control::{Control, HeaderFooter, Note};
+use hwp_model::document::Document;
+use hwp_model::paragraph::{Paragraph, RunContent, TextContent, TextElement};
+use hwp_model::section::Section;
+use hwp_model::shape::{Caption, ShapeCommon, ShapeObject};
+use hwp_model::table::{Table, TableCell};
+use hwp_model::types::{AutoNumType, CaptionSide, FieldType, Landscape, PageApplyType, VAlign};
+use hwpx_parser::xml::{Attrs, XmlWriter};
+use std::collections::HashMap;
+
+/// 그림 파일 (word/media/*)
+pub(super) struct Media {
+    pub(super) binary_id: String,
+    /// word/ 기준 경로 (media/image1.png)
+    pub(super) path: String,
+    pub(super) extension: &'static str,
+    pub(super) content_type: &'static str,
+    pub(super) data: Vec<u8>,
+}
+
+/// 머리말/꼬리말 파트 (word/header1.xml 등)
+pub(super) struct HeaderPart {
+    pub(super) name: String,
+    pub(super) footer: bool,
+    pub(super) xml: String,
+    pub(super) rels: Rels,
+}
+
+/// 본문/머리말/각주 등 XML 파트 하나와 그 관계 목록
+struct Part {
+    xml: XmlWriter,
+    rels: Rels,
+}
+
+/// 작성 중인 문단 상태 / State of the paragraph being written
+struct ParaState {
+    /// 아직 `<w:p>`로 내보내지 않은 run 조각
+    runs: XmlWriter,
+    /// 문단을 처음 내보내는지 (쪽 나눔은 첫 조각에만)
+    first: bool,
+    /// 열린 필드마다 필드 코드를 썼는지
+    fields: Vec<bool>,
+    /// 형광펜 색
+    markpen: Option<u32>,
+    /// 바로 앞 요소가 탭이었는지 (탭 제어 문자와 겹쳐 나오는 '\t' 무시용)
+    after_tab: bool,
+}
+
+/// Document → WordprocessingML 파트 작성기
+pub(super) struct BodyWriter<'a> {
+    doc: &'a Document,
+    options: &'a DocxOptions,
+    pub(super) media: Vec<Media>,
+    pub(super) headers: Vec<HeaderPart>,
+    /// 짝수/홀수 쪽 머리말이 따로 있는지 (settings.xml)
+    pub(super) even_and_odd: bool,
+    footnotes: XmlWriter,
+    footnote_rels: Rels,
+    endnotes: XmlWriter,
+    endnote_rels: Rels,
+    next_footnote: u32,
+    next_endnote: u32,
+    next_drawing: u32,
+    next_bookmark: u32,
+    /// 각주/미주 내용을 쓰는 중이면 참조 스타일 (중첩 주석은 무시)
+    in_note: Option<&'static str>,
+    /// 다음 문단 맨 앞에 넣을 각주/미주 번호 run
+    pending_note_ref: Option<&'static str>,
+}
+
+impl<'a> BodyWriter<'a> {
+    pub(super) fn new(doc: &'a Document, options: &'a DocxOptions) -> Self {
+        Self {
+            doc,
+            options,
+            media: Vec::new(),
+            headers: Vec::new(),
+            even_and_odd: false,
+            footnotes: XmlWriter::fragment(),
+            footnote_rels: Rels::default(),
+            endnotes: XmlWriter::fragment(),
+            endnote_rels: Rels::default(),
+            next_footnote: 1,
+            next_endnote: 1,
+            next_drawing: 1,
+            next_bookmark: 0,
+            in_note: None,
+            pending_note_ref: None,
+        }
+    }
+
+    /// word/document.xml과 그 관계 목록
+    pub(super) fn document(&mut self) -> (String, Rels) {
+        let mut part = Part {
+            xml: new_part(),
+            rels: Rels::default(),
+        };
+        part.xml.start("w:document", root_namespaces());
+        part.xml.start("w:body", Attrs::new());
+
+        let sections = &self.doc.sections;
+        for (index, section) in sections.iter().enumerate() {
+            let sect_pr = self.section_properties(section, &mut part.rels);
+            let last_section = index + 1 == sections.len();
+            let count = section.paragraphs.len();
+            for (i, para) in section.paragraphs.iter().enumerate() {
+                let sect = (!last_section && i + 1 == count).then_some(sect_pr.as_str());
+                self.paragraph(&mut part, para, sect);
+            }
+            if last_section {
+                part.xml.raw(&sect_pr);
+            } else if count == 0 {
+                part.xml.start("w:p", Attrs::new());
+                part.xml.start("w:pPr", Attrs::new());
+                part.xml.raw(&sect_pr);
+                part.xml.end("w:pPr");
+                part.xml.end("w:p");
+            }
+        }
+        if sections.is_empty() {
+            part.xml.empty("w:p", Attrs::new());
+        }
+
+        part.xml.end("w:body");
+        part.xml.end("w:document");
+        (part.xml.finish(), part.rels)
+    }
+
+    /// word/footnotes.xml 또는 endnotes.xml (구분선 -1, 0 포함)
+    pub(super) fn notes_part(&mut self, endnote: bool) -> (String, Rels) {
+        let (root, note, notes, rels) = if endnote {
+            (
+                "w:endnotes",
+                "w:endnote",
+                std::mem::replace(&mut self.endnotes, XmlWriter::fragment()),
+                std::mem::take(&mut self.endnote_rels),
+            )
+        } else {
+            (
+                "w:footnotes",
+                "w:footnote",
+                std::mem::replace(&mut self.footnotes, XmlWriter::fragment()),
+                std::mem::take(&mut self.footnote_rels),
+            )
+        };
+        let mut w = new_part();
+        w.start(root, root_namespaces());
+        for (id, kind, mark) in [
+            (-1, "separator", "w:separator"),
+            (0, "continuationSeparator", "w:continuationSeparator"),
+        ] {
+            w.start(note, Attrs::new().add("w:type", kind).add("w:id", id));
+            w.start("w:p", Attrs::new());
+            w.start("w:pPr", Attrs::new());
+            w.empty(
+                "w:spacing",
+                Attrs::new()
+                    .add("w:after", 0)
+                    .add("w:line", 240)
+                    .add("w:lineRule", "auto"),
+            );
+            w.end("w:pPr");
+            w.start("w:r", Attrs::new());
+            w.empty(mark, Attrs::new());
+            w.end("w:r");
+            w.end("w:p");
+            w.end(note);
+        }
+        w.raw(&notes.finish());
+        w.end(root);
+        (w.finish(), rels)
+    }
+
+    // ── 구역 ──
+
+    /// 구역 설정 `<w:sectPr>` (머리말/꼬리말 파트도 이때 만듦)
+    fn section_properties(&mut self, section: &Section, rels: &mut Rels) -> String {
+        let mut refs: Vec<(bool, &'static str, String)> = Vec::new();
+        let mut title_page = false;
+        for (footer, hf) in collect_header_footers(section) {
+            let kind = match hf.apply_page_type {
+                PageApplyType::Both | PageApplyType::Odd => "default",
+                PageApplyType::Even => "even",
+                PageApplyType::First => "first",
+            };
+            self.even_and_odd |= kind == "even";
+            title_page |= kind == "first";
+            let name = format!(
+                "{}{}.xml",
+                if footer { "footer" } else { "header" },
+                self.headers.len() + 1
+            );
+            let rel_type = if footer { REL_FOOTER } else { REL_HEADER };
+            let id = rels.add(rel_type, name.as_str());
+            let (xml, part_rels) = self.header_footer(hf, footer);
+            self.headers.push(HeaderPart {
+                name,
+                footer,
+                xml,
+                rels: part_rels,
+            });
+            // 같은 종류가 여러 번 나오면 마지막 것을 씀
+            refs.retain(|(f, k, _)| !(*f == footer && *k == kind));
+            refs.push((footer, kind, id));
+        }
+        // headerReference가 footerReference보다 앞에 와야 함
+        refs.sort_by_key(|(footer, _, _)| *footer);
+
+        let page = &section.definition.page;
+        let (width, height) = match page.landscape {
+            Landscape::Landscape => (page.height, page.width),
+            _ => (page.width, page.height),
+        };
+        let margin = &page.margin;
+
+        let mut w = XmlWriter::fragment();
+        w.start("w:sectPr", Attrs::new());
+        for (footer, kind, id) in refs {
+            w.empty(
+                if footer {
+                    "w:footerReference"
+                } else {
+                    "w:headerReference"
+                },
+                Attrs::new().add("w:type", kind).add("r:id", id),
+            );
+        }
+        if width > 0 && height > 0 {
+            w.empty(
+                "w:pgSz",
+                Attrs::new()
+                    .add("w:w", twips(width))
+                    .add("w:h", twips(height))
+                    .opt(
+                        "w:orient",
+                        (page.landscape == Landscape::Landscape).then_some("landscape"),
+                    ),
+            );
+            w.empty(
+                "w:pgMar",
+                Attrs::new()
+                    .add("w:top", twips(margin.top + margin.header))
+                    .add("w:right", twips(margin.right))
+                    .add("w:bottom", twips(margin.bottom + margin.footer))
+                    .add("w:left", twips(margin.left))
+                    .add("w:header", twips(margin.top))
+                    .add("w:footer", twips(margin.bottom))
+                    .add("w:gutter", twips(margin.gutter)),
+            );
+        }
+        if let Some(columns) = section
+            .definition
+            .columns
+            .as_ref()
+            .filter(|c| c.col_count > 1)
+        {
+            w.empty(
+                "w:cols",
+                Attrs::new()
+                    .add("w:num", columns.col_count)
+                    .add("w:space", twips(columns.same_gap)),
+            );
+        }
+        if title_page {
+            w.empty("w:titlePg", Attrs::new());
+        }
+        w.end("w:sectPr");
+        w.finish()
+    }
+
+    /// 머리말/꼬리말 파트 XML과 관계 목록
+    fn header_footer(&mut self, hf: &HeaderFooter, footer: bool) -> (String, Rels) {
+        let root = if footer { "w:ftr" } else { "w:hdr" };
+        let mut part = Part {
+            xml: new_part(),
+            rels: Rels::default(),
+        };
+        part.xml.start(root, root_namespaces());
+        self.block_paragraphs(&mut part, &hf.content.paragraphs);
+        part.xml.end(root);
+        (part.xml.finish(), part.rels)
+    }
+
+    // ── 문단 ──
+
+    /// 문단 목록. 셀/머리말처럼 문단이 하나 이상 있어야 하는 곳에 씀
+    fn block_paragraphs(&mut self, part: &mut Part, paragraphs: &[Paragraph]) {
+        for para in paragraphs {
+            self.paragraph(part, para, None);
+        }
+        if paragraphs.is_empty() {
+            part.xml.empty("w:p", Attrs::new());
+        }
+    }
+
+    /// 문단 하나. 표/글상자/캡션이 있으면 그 앞뒤로 문단을 나눠 씀
+    fn paragraph(&mut self, part: &mut Part, para: &Paragraph, sect_pr: Option<&str>) {
+        let mut state = ParaState {
+            runs: XmlWriter::fragment(),
+            first: true,
+            fields: Vec::new(),
+            markpen: None,
+            after_tab: false,
+        };
+        if let Some(style) = self.pending_note_ref.take() {
+            let note_ref = if style == FOOTNOTE_REFERENCE {
+                "w:footnoteRef"
+            } else {
+                "w:endnoteRef"
+            };
+            state.runs.start("w:r", Attrs::new());
+            state.runs.start("w:rPr", Attrs::new());
+            state.runs.empty("w:rStyle", w_val(style));
+            state.runs.end("w:rPr");
+            state.runs.empty(note_ref, Attrs::new());
+            state.runs.end("w:r");
+        }
+        if para.column_break {
+            state.runs.start("w:r", Attrs::new());
+            state
+                .runs
+                .empty("w:br", Attrs::new().add("w:type", "column"));
+            state.runs.end("w:r");
+        }
+
+        for run in &para.runs {
+            for content in &run.contents {
+                match content {
+                    RunContent::Text(text) => self.text(&mut state, run.char_shape_id, text),
+                    RunContent::Control(control) => {
+                        self.control(&mut state, run.char_shape_id, control)
+                    }
+                    RunContent::Object(object) => self.object(part, &mut state, para, object),
+                }
+            }
+        }
+        for emitted in std::mem::take(&mut state.fields) {
+            if emitted {
+                field_char(&mut state.runs, "end");
+            }
+        }
+        self.flush(part, &mut state, para, sect_pr);
+    }
+
+    /// 모은 run을 `<w:p>` 하나로 내보냄
+    fn flush(&self, part: &mut Part, state: &mut ParaState, para: &Paragraph, sect: Option<&str>) {
+        let resources = &self.doc.resources;
+        let extras = ParagraphExtras {
+            style: resources
+                .styles
+                .get(para.style_id as usize)
+                .map(|style| style_id(style.id)),
+            page_break_before: state.first && para.page_break,
+            mark: para
+                .runs
+                .last()
+                .and_then(|run| resources.char_shapes.get(run.char_shape_id as usize)),
+            sect_pr: sect,
+        };
+        part.xml.start("w:p", Attrs::new());
+        match resources.para_shapes.get(para.para_shape_id as usize) {
+            Some(ps) => paragraph_properties(&mut part.xml, self.doc, ps, &extras),
+            None => {
+                part.xml.start("w:pPr", Attrs::new());
+                if let Some(style) = &extras.style {
+                    part.xml.empty("w:pStyle", w_val(style));
+                }
+                if extras.page_break_before {
+                    part.xml.empty("w:pageBreakBefore", Attrs::new());
+                }
+                if let Some(sect_pr) = sect {
+                    part.xml.raw(sect_pr);
+                }
+                part.xml.end("w:pPr");
+            }
+        }
+        let runs = std::mem::replace(&mut state.runs, XmlWriter::fragment());
+        part.xml.raw(&runs.finish());
+        part.xml.end("w:p");
+        state.first = false;
+    }
+
+    /// 문단 중간에 블록(표/글상자 문단)을 넣기 전에 앞부분을 내보냄
+    fn flush_pending(&self, part: &mut Part, state: &mut ParaState, para: &Paragraph) {
+        if !state.runs.is_empty() {
+            self.flush(part, state, para, None);
+        }
+    }
+
+    // ── 글자 ──
+
+    /// `<w:r>` + `<w:rPr>` 시작
+    fn start_run(
+        &self,
+        w: &mut XmlWriter,
+        char_shape_id: u16,
+        style: Option<&str>,
+        mark: Option<u32>,
+    ) {
+        w.start("w:r", Attrs::new());
+        match self.doc.resources.char_shapes.get(char_shape_id as usize) {
+            Some(cs) => run_properties(w, self.doc, cs, style, mark),
+            None => {
+                if let Some(style) = style {
+                    w.start("w:rPr", Attrs::new());
+                    w.empty("w:rStyle", w_val(style));
+                    w.end("w:rPr");
+                }
+            }
+        }
+    }
+
+    fn text(&self, state: &mut ParaState, run_char_shape: u16, text: &TextContent) {
+        let char_shape_id = text.char_shape_id.unwrap_or(run_char_shape);
+        let mut open = false;
+        for element in &text.elements {
+            match element {
+                TextElement::MarkpenBegin { color } => {
+                    close_run(&mut state.runs, &mut open);
+                    state.markpen = Some(color.unwrap_or(0xFFFF00));
+                    continue;
+                }
+                TextElement::MarkpenEnd => {
+                    close_run(&mut state.runs, &mut open);
+                    state.markpen = None;
+                    continue;
+                }
+                TextElement::Text(_)
+                | TextElement::Tab { .. }
+                | TextElement::LineBreak
+                | TextElement::Hyphen
+                | TextElement::NbSpace
+                | TextElement::FwSpace => {}
+                _ => continue,
+            }
+            if !open {
+                self.start_run(&mut state.runs, char_shape_id, None, state.markpen);
+                open = true;
+            }
+            let w = &mut state.runs;
+            match element {
+                TextElement::Text(s) => {
+                    let mut chunk = String::new();
+                    for ch in s.chars() {
+                        match ch {
+                            '\t' | '\n' | '\r' => {
+                                write_t(w, &std::mem::take(&mut chunk));
+                                if ch == '\t' && !state.after_tab {
+                                    w.empty("w:tab", Attrs::new());
+                                } else if ch == '\n' {
+                                    w.empty("w:br", Attrs::new());
+                                }
+                                state.after_tab = false;
+                            }
+                            _ => {
+                                chunk.push(ch);
+                                state.after_tab = false;
+                            }
+                        }
+                    }
+                    write_t(w, &chunk);
+                }
+                TextElement::Tab { .. } => {
+                    w.empty("w:tab", Attrs::new());
+                    state.after_tab = true;
+                    continue;
+                }
+                TextElement::LineBreak => w.empty("w:br", Attrs::new()),
+                TextElement::Hyphen => write_t(w, "-"),
+                TextElement::NbSpace => write_t(w, "\u{00A0}"),
+                TextElement::FwSpace => write_t(w, "\u{2007}"),
+                _ => {}
+            }
+            if !matches!(element, TextElement::Text(_)) {
+                state.after_tab = false;
+            }
+        }
+        close_run(&mut state.runs, &mut open);
+    }
+
+    // ── 제어 ──
+
+    fn control(&mut self, state: &mut ParaState, char_shape_id: u16, control: &Control) {
+        match control {
+            Control::FieldBegin(field) => {
+                let url = if field.field_type == FieldType::Hyperlink {
+                    extract_hyperlink_url(field)
+                } else {
+                    String::new()
+                };
+                if url.is_empty() {
+                    state.fields.push(false);
+                    return;
+                }
+                let instr = match url.strip_prefix('#') {
+                    Some(bookmark) => format!(" HYPERLINK \\l \"{}\" ", bookmark),
+                    None => format!(" HYPERLINK \"{}\" ", url.replace('"', "%22")),
+                };
+                field_char(&mut state.runs, "begin");
+                state.runs.start("w:r", Attrs::new());
+                state.runs.text_element(
+                    "w:instrText",
+                    Attrs::new().add("xml:space", "preserve"),
+                    &instr,
+                );
+                state.runs.end("w:r");
+                field_char(&mut state.runs, "separate");
+                state.fields.push(true);
+            }
+            Control::FieldEnd => {
+                let emitted = state.fields.pop().unwrap_or(false);
+                if emitted {
+                    field_char(&mut state.runs, "end");
+                }
+            }
+            Control::Bookmark(bookmark) => {
+                let id = self.next_bookmark;
+                self.next_bookmark += 1;
+                state.runs.empty(
+                    "w:bookmarkStart",
+                    Attrs::new().add("w:id", id).add("w:name", &bookmark.name),
+                );
+                state
+                    .runs
+                    .empty("w:bookmarkEnd", Attrs::new().add("w:id", id));
+            }
+            Control::FootNote(note) => self.note(state, char_shape_id, note, false),
+            Control::EndNote(note) => self.note(state, char_shape_id, note, true),
+            Control::AutoNum(auto_num) => {
+                let instr = match auto_num.num_type {
+                    AutoNumType::Page => " PAGE ",
+                    AutoNumType::TotalPage => " NUMPAGES ",
+                    // 주석 번호는 각주/미주 참조가 대신함
+                    AutoNumType::Footnote | AutoNumType::Endnote => return,
+                    _ => {
+                        self.start_run(&mut state.runs, char_shape_id, None, state.markpen);
+                        write_t(&mut state.runs, &auto_num.num.to_string());
+                        state.runs.end("w:r");
+                        return;
+                    }
+                };
+                state
+                    .runs
+                    .start("w:fldSimple", Attrs::new().add("w:instr", instr));
+                self.start_run(&mut state.runs, char_shape_id, None, state.markpen);
+                write_t(&mut state.runs, "1");
+                state.runs.end("w:r");
+                state.runs.end("w:fldSimple");
+            }
+            _ => {}
+        }
+    }
+
+    /// 각주/미주 참조 run을 쓰고 내용은 footnotes.xml/endnotes.xml에 모음
+    fn note(&mut self, state: &mut ParaState, char_shape_id: u16, note: &Note, endnote: bool) {
+        if self.in_note.is_some() {
+            return;
+        }
+        let (style, reference) = if endnote {
+            (ENDNOTE_REFERENCE, "w:endnoteReference")
+        } else {
+            (FOOTNOTE_REFERENCE, "w:footnoteReference")
+        };
+        let id = if endnote {
+            &mut self.next_endnote
+        } else {
+            &mut self.next_footnote
+        };
+        let note_id = *id;
+        *id += 1;
+
+        self.start_run(&mut state.runs, char_shape_id, Some(style), None);
+        state
+            .runs
+            .empty(reference, Attrs::new().add("w:id", note_id));
+        state.runs.end("w:r");
+
+        let (xml, rels) = if endnote {
+            (&mut self.endnotes, &mut self.endnote_rels)
+        } else {
+            (&mut self.footnotes, &mut self.footnote_rels)
+        };
+        let mut part = Part {
+            xml: std::mem::replace(xml, XmlWriter::fragment()),
+            rels: std::mem::take(rels),
+        };
+        let tag = if endnote { "w:endnote" } else { "w:footnote" };
+        part.xml.start(tag, Attrs::new().add("w:id", note_id));
+        self.in_note = Some(style);
+        self.pending_note_ref = Some(style);
+        self.block_paragraphs(&mut part, &note.content.paragraphs);
+        self.pending_note_ref = None;
+        self.in_note = None;
+        part.xml.end(tag);
+
+        let (xml, rels) = if endnote {
+            (&mut self.endnotes, &mut self.endnote_rels)
+        } else {
+            (&mut self.footnotes, &mut self.footnote_rels)
+        };
+        *xml = part.xml;
+        *rels = part.rels;
+    }
+
+    // ── 개체 ──
+
+    fn object(
+        &mut self,
+        part: &mut Part,
+        state: &mut ParaState,
+        para: &Paragraph,
+        object: &ShapeObject,
+    ) {
+        let caption = common(object).and_then(|c| c.caption.as_ref());
+        let caption_first = caption.is_some_and(|c| c.side == CaptionSide::Top);
+        match object {
+            ShapeObject::Table(table) => {
+                self.flush_pending(part, state, para);
+                if caption_first {
+                    self.caption(part, caption);
+                }
+                self.table(part, table);
+                if !caption_first {
+                    self.caption(part, caption);
+                }
+            }
+            ShapeObject::Picture(pic) => {
+                if caption_first {
+                    self.flush_pending(part, state, para);
+                    self.caption(part, caption);
+                }
+                if self.options.embed_images {
+                    let size = &pic.common.size;
+                    self.picture(
+                        &mut state.runs,
+                        &mut part.rels,
+                        &pic.img.binary_item_id,
+                        size.width,
+                        size.height,
+                    );
+                }
+                if caption.is_some() && !caption_first {
+                    self.flush_pending(part, state, para);
+                    self.caption(part, caption);
+                }
+            }
+            ShapeObject::Rectangle(_)
+            | ShapeObject::Ellipse(_)
+            | ShapeObject::Polygon(_)
+            | ShapeObject::Curve(_) => {
+                let draw_text = match object {
+                    ShapeObject::Rectangle(o) => o.draw_text.as_ref(),
+                    ShapeObject::Ellipse(o) => o.draw_text.as_ref(),
+                    ShapeObject::Polygon(o) => o.draw_text.as_ref(),
+                    ShapeObject::Curve(o) => o.draw_text.as_ref(),
+                    _ => None,
+                };
+                let paragraphs = draw_text.map_or(&[][..], |text| &text.paragraphs);
+                if !paragraphs.is_empty() || caption.is_some() {
+                    self.flush_pending(part, state, para);
+                    for inner in paragraphs {
+                        self.paragraph(part, inner, None);
+                    }
+                    self.caption(part, caption);
+                }
+            }
+            ShapeObject::Container(container) => {
+                for child in &container.children {
+                    self.object(part, state, para, child);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn caption(&mut self, part: &mut Part, caption: Option<&Caption>) {
+        for para in caption.iter().flat_map(|c| &c.content.paragraphs) {
+            self.paragraph(part, para, None);
+        }
+    }
+
+    /// 글자처럼 취급한 그림 (`wp:inline`)
+    fn picture(
+        &mut self,
+        w: &mut XmlWriter,
+        rels: &mut Rels,
+        binary_id: &str,
+        width: i32,
+        height: i32,
+    ) {
+        let Some(path) = self.media_path(binary_id) else {
+            return;
+        };
+        let rel_id = rels.add(REL_IMAGE, path.as_str());
+        let id = self.next_drawing;
+        self.next_drawing += 1;
+        // 크기가 없으면 1인치 정사각형
+        let cx = emu(if width > 0 { width } else { 7200 });
+        let cy = emu(if height > 0 { height } else { 7200 });
+        let name = format!("Picture {}", id);
+
+        w.start("w:r", Attrs::new());
+        w.start("w:drawing", Attrs::new());
+        w.start(
+            "wp:inline",
+            Attrs::new()
+                .add("distT", 0)
+                .add("distB", 0)
+                .add("distL", 0)
+                .add("distR", 0),
+        );
+        w.empty("wp:extent", Attrs::new().add("cx", cx).add("cy", cy));
+        w.empty("wp:docPr", Attrs::new().add("id", id).add("name", &name));
+        w.start("wp:cNvGraphicFramePr", Attrs::new());
+        w.empty("a:graphicFrameLocks", Attrs::new().add("noChangeAspect", 1));
+        w.end("wp:cNvGraphicFramePr");
+        w.start("a:graphic", Attrs::new());
+        w.start("a:graphicData", Attrs::new().add("uri", PIC_NS));
+        w.start("pic:pic", Attrs::new());
+        w.start("pic:nvPicPr", Attrs::new());
+        w.empty("pic:cNvPr", Attrs::new().add("id", id).add("name", &name));
+        w.empty("pic:cNvPicPr", Attrs::new());
+        w.end("pic:nvPicPr");
+        w.start("pic:blipFill", Attrs::new());
+        w.empty("a:blip", Attrs::new().add("r:embed", rel_id));
+        w.start("a:stretch", Attrs::new());
+        w.empty("a:fillRect", Attrs::new());
+        w.end("a:stretch");
+        w.end("pic:blipFill");
+        w.start("pic:spPr", Attrs::new());
+        w.start("a:xfrm", Attrs::new());
+        w.empty("a:off", Attrs::new().add("x", 0).add("y", 0));
+        w.empty("a:ext", Attrs::new().add("cx", cx).add("cy", cy));
+        w.end("a:xfrm");
+        w.start("a:prstGeom", Attrs::new().add("prst", "rect"));
+        w.empty("a:avLst", Attrs::new());
+        w.end("a:prstGeom");
+        w.end("pic:spPr");
+        w.end("pic:pic");
+        w.end("a:graphicData");
+        w.end("a:graphic");
+        w.end("wp:inline");
+        w.end("w:drawing");
+        w.end("w:r");
+    }
+
+    /// 그림 데이터를 word/media에 한 번만 넣고 경로를 돌려줌
+    fn media_path(&mut self, binary_id: &str) -> Option<String> {
+        if let Some(media) = self.media.iter().find(|m| m.binary_id == binary_id) {
+            return Some(media.path.clone());
+        }
+        let item = find_binary_item(binary_id, &self.doc.binaries)?;
+        if item.data.is_empty() {
+            return None;
+        }
+        let (extension, content_type) = media_type(&item.format)?;
+        let path = format!("media/image{}.{}", self.media.len() + 1, extension);
+        self.media.push(Media {
+            binary_id: binary_id.to_string(),
+            path: path.clone(),
+            extension,
+            content_type,
+            data: item.data.clone(),
+        });
+        Some(path)
+    }
+
+    // ── 표 ──
+
+    /// 표 → `<w:tbl>`. 병합으로 가려진 칸은 vMerge 이어짐 셀로 채움
+    fn table(&mut self, part: &mut Part, table: &Table) {
+        let cells: Vec<&TableCell> = table.rows.iter().flat_map(|row| &row.cells).collect();
+        if cells.is_empty() {
+            return;
+        }
+        let col_count = cells
+            .iter()
+            .map(|c| (c.col + c.col_span.max(1)) as usize)
+            .max()
+            .unwrap_or(0)
+            .max(table.col_count as usize);
+        let row_count = cells
+            .iter()
+            .map(|c| (c.row + c.row_span.max(1)) as usize)
+            .max()
+            .unwrap_or(0)
+            .max(table.row_count as usize);
+        let col_pos = resolve_positions(
+            col_count,
+            cells
+                .iter()
+                .map(|c| (c.col as usize, c.col_span.max(1) as usize, c.width as f64)),
+        );
+        let grid: Vec<i32> = col_pos
+            .windows(2)
+            .map(|pair| twips((pair[1] - pair[0]).round() as i32).max(1))
+            .collect();
+
+        // (행, 열) → 그 칸을 차지하는 셀
+        let mut origin: HashMap<(usize, usize), &TableCell> = HashMap::new();
+        for cell in &cells {
+            origin.insert((cell.row as usize, cell.col as usize), cell);
+        }
+        let mut covered: HashMap<(usize, usize), &TableCell> = HashMap::new();
+        for cell in &cells {
+            for row in cell.row as usize + 1..(cell.row + cell.row_span.max(1)) as usize {
+                covered.insert((row, cell.col as usize), cell);
+            }
+        }
+
+        let w = &mut part.xml;
+        w.start("w:tbl", Attrs::new());
+        w.start("w:tblPr", Attrs::new());
+        w.empty(
+            "w:tblW",
+            Attrs::new()
+                .add("w:w", grid.iter().sum::<i32>())
+                .add("w:type", "dxa"),
+        );
+        w.empty("w:tblLayout", Attrs::new().add("w:type", "fixed"));
+        let margin = &table.in_margin;
+        w.start("w:tblCellMar", Attrs::new());
+        for (name, value) in [
+            ("w:top", margin.top),
+            ("w:left", margin.left),
+            ("w:bottom", margin.bottom),
+            ("w:right", margin.right),
+        ] {
+            w.empty(
+                name,
+                Attrs::new().add("w:w", twips(value)).add("w:type", "dxa"),
+            );
+        }
+        w.end("w:tblCellMar");
+        w.end("w:tblPr");
+        w.start("w:tblGrid", Attrs::new());
+        for width in &grid {
+            w.empty("w:gridCol", Attrs::new().add("w:w", width));
+        }
+        w.end("w:tblGrid");
+
+        for row in 0..row_count {
+            part.xml.start("w:tr", Attrs::new());
+            let height = cells
+                .iter()
+                .filter(|c| c.row as usize == row && c.row_span <= 1)
+                .map(|c| c.height)
+                .max()
+                .unwrap_or(0);
+            let header =
+                table.repeat_header && cells.iter().any(|c| c.row as usize == row && c.header);
+            part.xml.start("w:trPr", Attrs::new());
+            if height > 0 {
+                part.xml.empty(
+                    "w:trHeight",
+                    Attrs::new()
+                        .add("w:val", twips(height))
+                        .add("w:hRule", "atLeast"),
+                );
+            }
+            if header {
+                part.xml.empty("w:tblHeader", Attrs::new());
+            }
+            part.xml.end("w:trPr");
+
+            let mut col = 0;
+            while col < col_count {
+                if let Some(cell) = origin.get(&(row, col)) {
+                    let span = (cell.col_span.max(1) as usize).min(col_count - col);
+                    let merge = (cell.row_span > 1).then_some("restart");
+                    self.cell(part, cell, &grid[col..col + span], merge, false);
+                    col += span;
+                } else if let Some(cell) = covered.get(&(row, col)) {
+                    let span = (cell.col_span.max(1) as usize).min(col_count - col);
+                    self.cell(part, cell, &grid[col..col + span], Some("continue"), true);
+                    col += span;
+                } else {
+                    // 셀 정보가 빠진 칸
+                    part.xml.start("w:tc", Attrs::new());
+                    part.xml.start("w:tcPr", Attrs::new());
+                    part.xml.empty(
+                        "w:tcW",
+                        Attrs::new().add("w:w", grid[col]).add("w:type", "dxa"),
+                    );
+                    part.xml.end("w:tcPr");
+                    part.xml.empty("w:p", Attrs::new());
+                    part.xml.end("w:tc");
+                    col += 1;
+                }
+            }
+            part.xml.end("w:tr");
+        }
+        part.xml.end("w:tbl");
+    }
+
+    fn cell(
+        &mut self,
+        part: &mut Part,
+        cell: &TableCell,
+        grid: &[i32],
+        merge: Option<&str>,
+        continued: bool,
+    ) {
+        let w = &mut part.xml;
+        w.start("w:tc", Attrs::new());
+        w.start("w:tcPr", Attrs::new());
+        w.empty(
+            "w:tcW",
+            Attrs::new()
+                .add("w:w", grid.iter().sum::<i32>())
+                .add("w:type", "dxa"),
+        );
+        if grid.len() > 1 {
+            w.empty("w:gridSpan", w_val(grid.len()));
+        }
+        if let Some(merge) = merge {
+            w.empty("w:vMerge", w_val(merge));
+        }
+        if let Some(bf) = border_fill(self.doc, cell.border_fill_id) {
+            w.start("w:tcBorders", Attrs::new());
+            borders(
+                w,
+                &[
+                    ("w:top", &bf.top_border),
+                    ("w:left", &bf.left_border),
+                    ("w:bottom", &bf.bottom_border),
+                    ("w:right", &bf.right_border),
+                ],
+            );
+            w.end("w:tcBorders");
+            shading(w, bf);
+        }
+        if cell.has_margin == Some(true) {
+            let margin = &cell.cell_margin;
+            w.start("w:tcMar", Attrs::new());
+            for (name, value) in [
+                ("w:top", margin.top),
+                ("w:left", margin.left),
+                ("w:bottom", margin.bottom),
+                ("w:right", margin.right),
+            ] {
+                w.empty(
+                    name,
+                    Attrs::new().add("w:w", twips(value)).add("w:type", "dxa"),
+                );
+            }
+            w.end("w:tcMar");
+        }
+        let valign = match cell.content.vert_align {
+            VAlign::Center => "center",
+            VAlign::Bottom => "bottom",
+            _ => "top",
+        };
+        w.empty("w:vAlign", w_val(valign));
+        w.end("w:tcPr");
+        if continued {
+            part.xml.empty("w:p", Attrs::new());
+        } else {
+            self.block_paragraphs(part, &cell.content.paragraphs);
+        }
+        part.xml.end("w:tc");
+    }
+}
+
+/// document/header/footnotes 루트에 붙는 네임스페이스
+fn root_namespaces() -> Attrs {
+    Attrs::new()
+        .add("xmlns:w", W_NS)
+        .add("xmlns:r", R_NS)
+        .add("xmlns:wp", WP_NS)
+        .add("xmlns:a", A_NS)
+        .add("xmlns:pic", PIC_NS)
+}
+
+/// 구역 최상위 문단의 머리말/꼬리말 (꼬리말이면 true)
+fn collect_header_footers(section: &Section) -> Vec<(bool, &HeaderFooter)> {
+    section
+        .paragraphs
+        .iter()
+        .flat_map(|para| &para.runs)
+        .flat_map(|run| &run.contents)
+        .filter_map(|content| match content {
+            RunContent::Control(Control::Header(hf)) => Some((false, hf)),
+            RunContent::Control(Control::Footer(hf)) => Some((true, hf)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn common(object: &ShapeObject) -> Option<&ShapeCommon> {
+    Some(match object {
+        ShapeObject::Table(o) => &o.common,
+        ShapeObject::Picture(o) => &o.common,
+        ShapeObject::Rectangle(o) => &o.common,
+        ShapeObject::Ellipse(o) => &o.common,
+        ShapeObject::Polygon(o) => &o.common,
+        ShapeObject::Curve(o) => &o.common,
+        ShapeObject::Container(o) => &o.common,
+        _ => return None,
+    })
+}
+
+fn close_run(w: &mut XmlWriter, open: &mut bool) {
+    if *open {
+        w.end("w:r");
+        *open = false;
+    }
+}
+
+/// `<w:t>` (앞뒤 공백 보존)
+fn write_t(w: &mut XmlWriter, text: &str) {
+    if !text.is_empty() {
+        w.text_element("w:t", Attrs::new().add("xml:space", "preserve"), text);
+    }
+}
+
+/// 복합 필드 경계 run (`begin` / `separate` / `end`)
+fn field_char(w: &mut XmlWriter, kind: &str) {
+    w.start("w:r", Attrs::new());
+    w.empty("w:fldChar", Attrs::new().add("w:fldCharType", kind));
+    w.end("w:r");
+}
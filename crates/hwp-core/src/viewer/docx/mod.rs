@@ -0,0 +1,428 @@
+//! DOCX export for HWP documents
+//! HWP 문서를 DOCX(WordprocessingML)로 내보내는 모듈
+//!
+//! `hwp_model::Document`의 문단/글자 모양, 표, 그림, 머리말/꼬리말, 각주/미주를
+//! WordprocessingML로 옮겨 .docx 패키지(ZIP)로 묶습니다. [`crate::convert::to_document`]나
+//! HWPX 파서가 만든 Document를 그대로 쓰므로 HWP/HWPX 입력 모두에서 동작합니다.
+//!
+//! Maps paragraphs, runs, char/para shapes, tables, pictures, headers/footers and notes of a
+//! `Document` into WordprocessingML and packages them as a .docx file.
+//!
+//! # 변환 규칙 / Mapping
+//! - 구역(Section)마다 `<w:sectPr>`를 두고 용지 크기/여백/단 수/머리말·꼬리말을 옮깁니다.
+//! - 스타일은 HWP 스타일 이름 그대로 styles.xml에 넣고, 문단/글자 모양은 직접 서식으로도 씁니다.
+//! - 표는 문단 밖의 `<w:tbl>`로 꺼내며, 병합 셀은 gridSpan/vMerge로 나타냅니다.
+//! - 그림은 위치와 관계없이 글자처럼 취급(`wp:inline`)합니다. JPEG/PNG/GIF/BMP/TIFF/WMF/EMF만 넣습니다.
+//! - 글상자/도형 안 글과 캡션은 도형 자리 뒤에 일반 문단으로 씁니다.
+//! - 하이퍼링크는 HYPERLINK 필드, 쪽 번호/전체 쪽 수는 PAGE/NUMPAGES 필드가 됩니다.
+//!
+//! 날짜 등 실행마다 바뀌는 값을 넣지 않으므로 같은 입력이면 같은 바이트가 나옵니다.
+mod body;
+mod styles;
+
+use crate::error::HwpError;
+use body::BodyWriter;
+use hwp_model::document::{Document, DocumentMeta, ImageFormat};
+use hwp_model::types::HwpUnit;
+use hwpx_parser::xml::{Attrs, XmlWriter};
+use std::io::{Cursor, Write};
+use zip::write::SimpleFileOptions;
+use zip::CompressionMethod;
+
+/// XML 선언 (Word가 저장하는 형식과 동일)
+const XML_DECL: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n";
+
+/// XML 선언으로 시작하는 새 파트
+fn new_part() -> XmlWriter {
+    XmlWriter::with_declaration(XML_DECL)
+}
+
+/// `w:val` 하나만 있는 속성 (`<w:jc w:val="center"/>` 등)
+fn w_val(value: impl ToString) -> Attrs {
+    Attrs::new().add("w:val", value)
+}
+
+const W_NS: &str = "http://schemas.openxmlformats.org/wordprocessingml/2006/main";
+const R_NS: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+const WP_NS: &str = "http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing";
+const A_NS: &str = "http://schemas.openxmlformats.org/drawingml/2006/main";
+const PIC_NS: &str = "http://schemas.openxmlformats.org/drawingml/2006/picture";
+const PKG_REL_NS: &str = "http://schemas.openxmlformats.org/package/2006/relationships";
+
+const REL_OFFICE_DOCUMENT: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument";
+const REL_CORE: &str =
+    "http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties";
+const REL_APP: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/extended-properties";
+const REL_STYLES: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles";
+const REL_SETTINGS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/settings";
+const REL_FOOTNOTES: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/footnotes";
+const REL_ENDNOTES: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/endnotes";
+const REL_HEADER: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/header";
+const REL_FOOTER: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/footer";
+const REL_IMAGE: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships/image";
+
+const CT_WML: &str = "application/vnd.openxmlformats-officedocument.wordprocessingml";
+
+/// DOCX 변환 옵션 / DOCX conversion options
+#[derive(Debug, Clone)]
+pub struct DocxOptions {
+    /// 그림을 넣을지 여부 (false면 그림을 건너뜀) / Embed pictures (false = skip them)
+    pub embed_images: bool,
+}
+
+impl Default for DocxOptions {
+    fn default() -> Self {
+        Self { embed_images: true }
+    }
+}
+
+/// Document를 DOCX 바이트로 변환 / Convert a Document to DOCX bytes
+pub fn to_docx(doc: &Document, options: &DocxOptions) -> Result<Vec<u8>, HwpError> {
+    let mut body = BodyWriter::new(doc, options);
+    let (document_xml, mut document_rels) = body.document();
+
+    document_rels.add(REL_STYLES, "styles.xml");
+    document_rels.add(REL_SETTINGS, "settings.xml");
+    document_rels.add(REL_FOOTNOTES, "footnotes.xml");
+    document_rels.add(REL_ENDNOTES, "endnotes.xml");
+
+    let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let deflated = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let mut add = |name: &str, data: &[u8]| -> Result<(), HwpError> {
+        zip.start_file(name, deflated)?;
+        zip.write_all(data)?;
+        Ok(())
+    };
+
+    add("[Content_Types].xml", content_types(&body).as_bytes())?;
+    add("_rels/.rels", package_rels().as_bytes())?;
+    add("docProps/core.xml", core_properties(&doc.meta).as_bytes())?;
+    add("docProps/app.xml", app_properties().as_bytes())?;
+    add("word/document.xml", document_xml.as_bytes())?;
+    add(
+        "word/_rels/document.xml.rels",
+        document_rels.finish().as_bytes(),
+    )?;
+    add("word/styles.xml", styles::write_styles(doc).as_bytes())?;
+    add("word/settings.xml", settings(body.even_and_odd).as_bytes())?;
+
+    let (footnotes, footnote_rels) = body.notes_part(false);
+    add("word/footnotes.xml", footnotes.as_bytes())?;
+    add(
+        "word/_rels/footnotes.xml.rels",
+        footnote_rels.finish().as_bytes(),
+    )?;
+    let (endnotes, endnote_rels) = body.notes_part(true);
+    add("word/endnotes.xml", endnotes.as_bytes())?;
+    add(
+        "word/_rels/endnotes.xml.rels",
+        endnote_rels.finish().as_bytes(),
+    )?;
+
+    for part in std::mem::take(&mut body.headers) {
+        add(&format!("word/{}", part.name), part.xml.as_bytes())?;
+        add(
+            &format!("word/_rels/{}.rels", part.name),
+            part.rels.finish().as_bytes(),
+        )?;
+    }
+    for media in &body.media {
+        add(&format!("word/{}", media.path), &media.data)?;
+    }
+
+    Ok(zip.finish()?.into_inner())
+}
+
+/// 파트 하나의 관계 목록 (`*.rels`) / Relationships of one part
+#[derive(Default)]
+struct Rels(Vec<(&'static str, String)>);
+
+impl Rels {
+    /// 관계를 추가하고 rId를 돌려줌 (같은 대상이면 기존 rId)
+    fn add(&mut self, rel_type: &'static str, target: impl Into<String>) -> String {
+        let target = target.into();
+        let index = match self
+            .0
+            .iter()
+            .position(|(t, existing)| *t == rel_type && *existing == target)
+        {
+            Some(index) => index,
+            None => {
+                self.0.push((rel_type, target));
+                self.0.len() - 1
+            }
+        };
+        format!("rId{}", index + 1)
+    }
+
+    fn finish(self) -> String {
+        let mut w = new_part();
+        w.start("Relationships", Attrs::new().add("xmlns", PKG_REL_NS));
+        for (index, (rel_type, target)) in self.0.into_iter().enumerate() {
+            w.empty(
+                "Relationship",
+                Attrs::new()
+                    .add("Id", format!("rId{}", index + 1))
+                    .add("Type", rel_type)
+                    .add("Target", target),
+            );
+        }
+        w.end("Relationships");
+        w.finish()
+    }
+}
+
+/// [Content_Types].xml
+fn content_types(body: &BodyWriter) -> String {
+    let mut w = new_part();
+    w.start(
+        "Types",
+        Attrs::new().add(
+            "xmlns",
+            "http://schemas.openxmlformats.org/package/2006/content-types",
+        ),
+    );
+    let mut defaults = vec![
+        (
+            "rels",
+            "application/vnd.openxmlformats-package.relationships+xml",
+        ),
+        ("xml", "application/xml"),
+    ];
+    for media in &body.media {
+        let default = (media.extension, media.content_type);
+        if !defaults.contains(&default) {
+            defaults.push(default);
+        }
+    }
+    for (extension, content_type) in defaults {
+        w.empty(
+            "Default",
+            Attrs::new()
+                .add("Extension", extension)
+                .add("ContentType", content_type),
+        );
+    }
+
+    let mut overrides = vec![
+        (
+            "/word/document.xml".to_string(),
+            format!("{}.document.main+xml", CT_WML),
+        ),
+        (
+            "/word/styles.xml".to_string(),
+            format!("{}.styles+xml", CT_WML),
+        ),
+        (
+            "/word/settings.xml".to_string(),
+            format!("{}.settings+xml", CT_WML),
+        ),
+        (
+            "/word/footnotes.xml".to_string(),
+            format!("{}.footnotes+xml", CT_WML),
+        ),
+        (
+            "/word/endnotes.xml".to_string(),
+            format!("{}.endnotes+xml", CT_WML),
+        ),
+        (
+            "/docProps/core.xml".to_string(),
+            "application/vnd.openxmlformats-package.core-properties+xml".to_string(),
+        ),
+        (
+            "/docProps/app.xml".to_string(),
+            "application/vnd.openxmlformats-officedocument.extended-properties+xml".to_string(),
+        ),
+    ];
+    for part in &body.headers {
+        let kind = if part.footer { "footer" } else { "header" };
+        overrides.push((
+            format!("/word/{}", part.name),
+            format!("{}.{}+xml", CT_WML, kind),
+        ));
+    }
+    for (part_name, content_type) in overrides {
+        w.empty(
+            "Override",
+            Attrs::new()
+                .add("PartName", part_name)
+                .add("ContentType", content_type),
+        );
+    }
+    w.end("Types");
+    w.finish()
+}
+
+/// _rels/.rels
+fn package_rels() -> String {
+    let mut rels = Rels::default();
+    rels.add(REL_OFFICE_DOCUMENT, "word/document.xml");
+    rels.add(REL_CORE, "docProps/core.xml");
+    rels.add(REL_APP, "docProps/app.xml");
+    rels.finish()
+}
+
+/// docProps/core.xml (Dublin Core 메타데이터)
+fn core_properties(meta: &DocumentMeta) -> String {
+    let mut w = new_part();
+    w.start(
+        "cp:coreProperties",
+        Attrs::new()
+            .add(
+                "xmlns:cp",
+                "http://schemas.openxmlformats.org/package/2006/metadata/core-properties",
+            )
+            .add("xmlns:dc", "http://purl.org/dc/elements/1.1/")
+            .add("xmlns:dcterms", "http://purl.org/dc/terms/")
+            .add("xmlns:xsi", "http://www.w3.org/2001/XMLSchema-instance"),
+    );
+    for (name, value) in [
+        ("dc:title", &meta.title),
+        ("dc:subject", &meta.subject),
+        ("dc:creator", &meta.creator),
+        ("cp:keywords", &meta.keywords),
+        ("dc:description", &meta.description),
+        ("dc:language", &meta.language),
+    ] {
+        if let Some(value) = value.as_deref().filter(|v| !v.is_empty()) {
+            w.text_element(name, Attrs::new(), value);
+        }
+    }
+    // W3CDTF 형식이 아닌 날짜는 Word가 파일을 거부하므로 넣지 않음
+    for (name, value) in [
+        ("dcterms:created", &meta.created_date),
+        ("dcterms:modified", &meta.modified_date),
+    ] {
+        if let Some(value) = value.as_deref().filter(|v| is_w3cdtf(v)) {
+            w.text_element(name, Attrs::new().add("xsi:type", "dcterms:W3CDTF"), value);
+        }
+    }
+    w.end("cp:coreProperties");
+    w.finish()
+}
+
+/// `YYYY-MM-DDTHH:MM:SSZ` 형식인지
+fn is_w3cdtf(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    bytes.len() == 20
+        && bytes.iter().enumerate().all(|(i, &b)| match i {
+            4 | 7 => b == b'-',
+            10 => b == b'T',
+            13 | 16 => b == b':',
+            19 => b == b'Z',
+            _ => b.is_ascii_digit(),
+        })
+}
+
+/// docProps/app.xml
+fn app_properties() -> String {
+    let mut w = new_part();
+    w.start(
+        "Properties",
+        Attrs::new().add(
+            "xmlns",
+            "http://schemas.openxmlformats.org/officeDocument/2006/extended-properties",
+        ),
+    );
+    w.text_element("Application", Attrs::new(), "hwpjs");
+    w.end("Properties");
+    w.finish()
+}
+
+/// word/settings.xml (각주/미주 구분선 id -1, 0을 예약)
+fn settings(even_and_odd: bool) -> String {
+    let mut w = new_part();
+    w.start("w:settings", Attrs::new().add("xmlns:w", W_NS));
+    if even_and_odd {
+        w.empty("w:evenAndOddHeaders", Attrs::new());
+    }
+    for (pr, note) in [("w:footnotePr", "w:footnote"), ("w:endnotePr", "w:endnote")] {
+        w.start(pr, Attrs::new());
+        w.empty(note, Attrs::new().add("w:id", -1));
+        w.empty(note, Attrs::new().add("w:id", 0));
+        w.end(pr);
+    }
+    w.start("w:compat", Attrs::new());
+    w.empty(
+        "w:compatSetting",
+        Attrs::new()
+            .add("w:name", "compatibilityMode")
+            .add("w:uri", "http://schemas.microsoft.com/office/word")
+            .add("w:val", 15),
+    );
+    w.end("w:compat");
+    w.end("w:settings");
+    w.finish()
+}
+
+/// 그림 형식 → (확장자, content type). Word가 읽지 못하는 형식은 None
+fn media_type(format: &ImageFormat) -> Option<(&'static str, &'static str)> {
+    Some(match format {
+        ImageFormat::Png => ("png", "image/png"),
+        ImageFormat::Jpg => ("jpeg", "image/jpeg"),
+        ImageFormat::Gif => ("gif", "image/gif"),
+        ImageFormat::Bmp => ("bmp", "image/bmp"),
+        ImageFormat::Tiff => ("tiff", "image/tiff"),
+        ImageFormat::Wmf => ("wmf", "image/x-wmf"),
+        ImageFormat::Emf => ("emf", "image/x-emf"),
+        ImageFormat::Svg | ImageFormat::Unknown(_) => return None,
+    })
+}
+
+/// HWPUNIT(1/7200 inch) → twips(1/1440 inch)
+fn twips(value: HwpUnit) -> i32 {
+    value / 5
+}
+
+/// HWPUNIT → EMU(1/914400 inch)
+fn emu(value: HwpUnit) -> i64 {
+    value as i64 * 127
+}
+
+/// 0xRRGGBB → "RRGGBB"
+fn hex_color(color: u32) -> String {
+    format!("{:06X}", color & 0xFF_FFFF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_units() {
+        // A4 폭 59528 HWPUNIT = 210mm = 11905 twips
+        assert_eq!(twips(59528), 11905);
+        assert_eq!(emu(7200), 914_400);
+        assert_eq!(hex_color(0x00FF_8000), "FF8000");
+    }
+
+    #[test]
+    fn test_rels_dedup() {
+        let mut rels = Rels::default();
+        assert_eq!(rels.add(REL_IMAGE, "media/image1.png"), "rId1");
+        assert_eq!(rels.add(REL_STYLES, "styles.xml"), "rId2");
+        assert_eq!(rels.add(REL_IMAGE, "media/image1.png"), "rId1");
+        assert!(rels.finish().contains(r#"Id="rId2""#));
+    }
+
+    #[test]
+    fn test_is_w3cdtf() {
+        assert!(is_w3cdtf("2024-01-31T09:05:00Z"));
+        assert!(!is_w3cdtf("2024년 1월 31일"));
+        assert!(!is_w3cdtf("2024-01-31"));
+    }
+
+    #[test]
+    fn test_empty_document() {
+        let bytes = to_docx(&Document::default(), &DocxOptions::default()).unwrap();
+        assert!(bytes.starts_with(b"PK"));
+    }
+}
@@ -0,0 +1,442 @@
+use super::{hex_color, new_part, twips, w_val, W_NS};
+use hwp_model::document::Document;
+use hwp_model::resources::{BorderFill, CharShape, FillBrush, LineSpec, ParaShape, TabType};
+use hwp_model::types::{
+    HAlign, HeadingType, LineSpacingType, LineType2, LineType3, StyleType, UnderlineType,
+};
+/// styles.xml과 문단/글자 속성(pPr/rPr) 변환
+/// styles.xml and paragraph/run property mapping
+use hwpx_parser::xml::{Attrs, XmlWriter};
+
+/// 각주/미주 참조 글자 스타일
+pub(super) const FOOTNOTE_REFERENCE: &str = "FootnoteReference";
+pub(super) const ENDNOTE_REFERENCE: &str = "EndnoteReference";
+
+/// HWP 스타일 id → WordprocessingML styleId
+pub(super) fn style_id(id: u16) -> String {
+    format!("S{}", id)
+}
+
+/// word/styles.xml
+///
+/// HWP 스타일은 이름을 그대로 쓰는 문단/글자 스타일이 되고, 0번(바탕글)이 기본 문단 스타일입니다.
+pub(super) fn write_styles(doc: &Document) -> String {
+    let resources = &doc.resources;
+    let mut w = new_part();
+    w.start("w:styles", Attrs::new().add("xmlns:w", W_NS));
+
+    w.start("w:docDefaults", Attrs::new());
+    w.start("w:rPrDefault", Attrs::new());
+    match resources.char_shapes.first() {
+        Some(cs) => run_properties(&mut w, doc, cs, None, None),
+        None => w.empty("w:rPr", Attrs::new()),
+    }
+    w.end("w:rPrDefault");
+    w.start("w:pPrDefault", Attrs::new());
+    w.empty("w:pPr", Attrs::new());
+    w.end("w:pPrDefault");
+    w.end("w:docDefaults");
+
+    let mut has_default = false;
+    for style in &resources.styles {
+        let is_para = style.style_type == StyleType::Para;
+        let is_default = is_para && !has_default;
+        has_default |= is_default;
+        let name = if style.name.is_empty() {
+            style.eng_name.as_str()
+        } else {
+            style.name.as_str()
+        };
+        w.start(
+            "w:style",
+            Attrs::new()
+                .add("w:type", if is_para { "paragraph" } else { "character" })
+                .opt("w:default", is_default.then_some("1"))
+                .add("w:styleId", style_id(style.id)),
+        );
+        w.empty("w:name", w_val(name));
+        if let Some(next) = style.next_style_id.filter(|_| is_para) {
+            w.empty("w:next", w_val(style_id(next)));
+        }
+        w.empty("w:qFormat", Attrs::new());
+        if is_para {
+            if let Some(ps) = style
+                .para_shape_id
+                .and_then(|id| resources.para_shapes.get(id as usize))
+            {
+                paragraph_properties(&mut w, doc, ps, &ParagraphExtras::default());
+            }
+        }
+        if let Some(cs) = style
+            .char_shape_id
+            .and_then(|id| resources.char_shapes.get(id as usize))
+        {
+            run_properties(&mut w, doc, cs, None, None);
+        }
+        w.end("w:style");
+    }
+    if !has_default {
+        w.start(
+            "w:style",
+            Attrs::new()
+                .add("w:type", "paragraph")
+                .add("w:default", "1")
+                .add("w:styleId", "Normal"),
+        );
+        w.empty("w:name", w_val("Normal"));
+        w.empty("w:qFormat", Attrs::new());
+        w.end("w:style");
+    }
+
+    for (id, name) in [
+        (FOOTNOTE_REFERENCE, "footnote reference"),
+        (ENDNOTE_REFERENCE, "endnote reference"),
+    ] {
+        w.start(
+            "w:style",
+            Attrs::new().add("w:type", "character").add("w:styleId", id),
+        );
+        w.empty("w:name", w_val(name));
+        w.start("w:rPr", Attrs::new());
+        w.empty("w:vertAlign", w_val("superscript"));
+        w.end("w:rPr");
+        w.end("w:style");
+    }
+
+    w.end("w:styles");
+    w.finish()
+}
+
+/// 문단마다 달라지는 pPr 항목 (스타일, 쪽 나눔, 구역 설정)
+#[derive(Default)]
+pub(super) struct ParagraphExtras<'a> {
+    pub(super) style: Option<String>,
+    pub(super) page_break_before: bool,
+    /// 문단 부호의 글자 모양 (빈 문단의 줄 높이)
+    pub(super) mark: Option<&'a CharShape>,
+    /// 구역의 마지막 문단에 넣는 `<w:sectPr>` 조각
+    pub(super) sect_pr: Option<&'a str>,
+}
+
+/// ParaShape → `<w:pPr>` (CT_PPr 요소 순서를 따름)
+///
+/// HWP 문단 여백/들여쓰기는 HWPUNIT의 2배 값으로 저장되므로 절반으로 나눠 변환합니다.
+pub(super) fn paragraph_properties(
+    w: &mut XmlWriter,
+    doc: &Document,
+    ps: &ParaShape,
+    extras: &ParagraphExtras,
+) {
+    w.start("w:pPr", Attrs::new());
+    if let Some(style) = &extras.style {
+        w.empty("w:pStyle", w_val(style));
+    }
+    if ps.break_setting.keep_with_next {
+        w.empty("w:keepNext", Attrs::new());
+    }
+    if ps.break_setting.keep_lines {
+        w.empty("w:keepLines", Attrs::new());
+    }
+    if ps.break_setting.page_break_before || extras.page_break_before {
+        w.empty("w:pageBreakBefore", Attrs::new());
+    }
+    w.empty(
+        "w:widowControl",
+        w_val(if ps.break_setting.widow_orphan { 1 } else { 0 }),
+    );
+
+    if let Some(bf) = ps
+        .border
+        .as_ref()
+        .and_then(|border| border_fill(doc, border.border_fill_id))
+    {
+        let sides = [
+            ("w:top", &bf.top_border),
+            ("w:left", &bf.left_border),
+            ("w:bottom", &bf.bottom_border),
+            ("w:right", &bf.right_border),
+        ];
+        if sides
+            .iter()
+            .any(|(_, spec)| spec.as_ref().is_some_and(has_line))
+        {
+            w.start("w:pBdr", Attrs::new());
+            borders(w, &sides);
+            w.end("w:pBdr");
+        }
+        shading(w, bf);
+    }
+
+    if let Some(tab_def) = ps
+        .tab_def_id
+        .and_then(|id| doc.resources.tab_defs.get(id as usize))
+        .filter(|tab_def| !tab_def.items.is_empty())
+    {
+        w.start("w:tabs", Attrs::new());
+        for item in &tab_def.items {
+            let val = match item.tab_type {
+                TabType::Left => "left",
+                TabType::Right => "right",
+                TabType::Center => "center",
+                TabType::Decimal => "decimal",
+            };
+            let leader = match item.leader {
+                LineType2::None => "none",
+                LineType2::Dot | LineType2::Circle => "dot",
+                LineType2::Dash | LineType2::LongDash | LineType2::DashDot => "hyphen",
+                LineType2::DashDotDot => "middleDot",
+                _ => "underscore",
+            };
+            w.empty(
+                "w:tab",
+                Attrs::new()
+                    .add("w:val", val)
+                    .add("w:leader", leader)
+                    .add("w:pos", twips(item.pos)),
+            );
+        }
+        w.end("w:tabs");
+    }
+
+    let spacing = &ps.line_spacing;
+    let (line, rule) = match spacing.spacing_type {
+        LineSpacingType::Percent => (spacing.value * 240 / 100, "auto"),
+        LineSpacingType::Fixed => (twips(spacing.value), "exact"),
+        LineSpacingType::AtLeast => (twips(spacing.value), "atLeast"),
+        LineSpacingType::Between => (240, "auto"),
+    };
+    w.empty(
+        "w:spacing",
+        Attrs::new()
+            .add("w:before", twips(ps.margin.prev.value / 2).max(0))
+            .add("w:after", twips(ps.margin.next.value / 2).max(0))
+            .add("w:line", line.max(1))
+            .add("w:lineRule", rule),
+    );
+
+    let indent = twips(ps.margin.indent.value / 2);
+    let mut ind = Attrs::new()
+        .add("w:left", twips(ps.margin.left.value / 2))
+        .add("w:right", twips(ps.margin.right.value / 2));
+    ind = if indent < 0 {
+        ind.add("w:hanging", -indent)
+    } else {
+        ind.add("w:firstLine", indent)
+    };
+    w.empty("w:ind", ind);
+
+    let jc = match ps.align.horizontal {
+        HAlign::Left | HAlign::Inside => "left",
+        HAlign::Right | HAlign::Outside => "right",
+        HAlign::Center => "center",
+        HAlign::Justify => "both",
+        HAlign::Distribute | HAlign::DistributeSpace => "distribute",
+    };
+    w.empty("w:jc", w_val(jc));
+
+    if let Some(heading) = &ps.heading {
+        if heading.heading_type == HeadingType::Outline {
+            w.empty("w:outlineLvl", w_val(heading.level.min(8)));
+        }
+    }
+    if let Some(cs) = extras.mark {
+        run_properties(w, doc, cs, None, None);
+    }
+    if let Some(sect_pr) = extras.sect_pr {
+        w.raw(sect_pr);
+    }
+    w.end("w:pPr");
+}
+
+/// CharShape → `<w:rPr>` (CT_RPr 요소 순서를 따름)
+///
+/// 글꼴은 영문(ascii/hAnsi)과 한글(eastAsia)을 따로 지정하고, 장평/자간은 한글 값을 씁니다.
+pub(super) fn run_properties(
+    w: &mut XmlWriter,
+    doc: &Document,
+    cs: &CharShape,
+    style: Option<&str>,
+    highlight: Option<u32>,
+) {
+    let fonts = &doc.resources.fonts;
+    w.start("w:rPr", Attrs::new());
+    if let Some(style) = style {
+        w.empty("w:rStyle", w_val(style));
+    }
+    let face = |list: &[hwp_model::resources::Font], id: u16| {
+        list.get(id as usize)
+            .map(|font| font.face.clone())
+            .filter(|face| !face.is_empty())
+    };
+    let latin = face(&fonts.latin, cs.font_ref.latin);
+    let hangul = face(&fonts.hangul, cs.font_ref.hangul);
+    if latin.is_some() || hangul.is_some() {
+        w.empty(
+            "w:rFonts",
+            Attrs::new()
+                .opt("w:ascii", latin.clone())
+                .opt("w:hAnsi", latin.clone())
+                .opt("w:eastAsia", hangul)
+                .opt("w:cs", latin),
+        );
+    }
+    if cs.bold {
+        w.empty("w:b", Attrs::new());
+        w.empty("w:bCs", Attrs::new());
+    }
+    if cs.italic {
+        w.empty("w:i", Attrs::new());
+        w.empty("w:iCs", Attrs::new());
+    }
+    let center_line = cs
+        .underline
+        .as_ref()
+        .filter(|u| u.underline_type == UnderlineType::Center);
+    let strike_shape = cs
+        .strikeout
+        .as_ref()
+        .map(|s| &s.shape)
+        .or(center_line.map(|u| &u.shape));
+    match strike_shape {
+        Some(LineType3::None) | None => {}
+        Some(LineType3::DoubleSlim) => w.empty("w:dstrike", Attrs::new()),
+        Some(_) => w.empty("w:strike", Attrs::new()),
+    }
+    if cs.outline.is_some() {
+        w.empty("w:outline", Attrs::new());
+    }
+    if cs.shadow.is_some() {
+        w.empty("w:shadow", Attrs::new());
+    }
+    if cs.emboss {
+        w.empty("w:emboss", Attrs::new());
+    }
+    if cs.engrave {
+        w.empty("w:imprint", Attrs::new());
+    }
+    if let Some(color) = cs.text_color {
+        w.empty("w:color", w_val(hex_color(color)));
+    }
+    if cs.spacing.hangul != 0 {
+        // 자간: 글자 크기에 대한 % → twips (1pt = 20twips, height는 1/100pt)
+        let spacing = cs.height as i64 * cs.spacing.hangul as i64 / 500;
+        w.empty("w:spacing", w_val(spacing));
+    }
+    if cs.ratio.hangul != 0 && cs.ratio.hangul != 100 {
+        w.empty("w:w", w_val(cs.ratio.hangul));
+    }
+    if cs.height > 0 {
+        let half_points = (cs.height + 25) / 50;
+        w.empty("w:sz", w_val(half_points));
+        w.empty("w:szCs", w_val(half_points));
+    }
+    if let Some(underline) = cs
+        .underline
+        .as_ref()
+        .filter(|u| u.underline_type == UnderlineType::Bottom)
+    {
+        let val = match underline.shape {
+            LineType3::None | LineType3::Solid => "single",
+            LineType3::Dot | LineType3::Circle => "dotted",
+            LineType3::Dash => "dash",
+            LineType3::DashDot => "dotDash",
+            LineType3::DashDotDot => "dotDotDash",
+            LineType3::LongDash => "dashLong",
+            LineType3::DoubleSlim => "double",
+            LineType3::Wave => "wave",
+            _ => "thick",
+        };
+        w.empty(
+            "w:u",
+            w_val(val).opt("w:color", underline.color.map(hex_color)),
+        );
+    }
+    let shade = highlight.or(cs.shade_color.filter(|&c| c != 0xFFFFFF));
+    if let Some(fill) = shade {
+        w.empty(
+            "w:shd",
+            w_val("clear")
+                .add("w:color", "auto")
+                .add("w:fill", hex_color(fill)),
+        );
+    }
+    if cs.superscript {
+        w.empty("w:vertAlign", w_val("superscript"));
+    } else if cs.subscript {
+        w.empty("w:vertAlign", w_val("subscript"));
+    }
+    w.end("w:rPr");
+}
+
+/// 1부터 시작하는 테두리/배경 id로 BorderFill 찾기
+pub(super) fn border_fill(doc: &Document, id: u16) -> Option<&BorderFill> {
+    doc.resources
+        .border_fills
+        .get((id as usize).checked_sub(1)?)
+}
+
+/// 선이 실제로 그려지는지 (종류가 있고 색이 있음)
+fn has_line(spec: &LineSpec) -> bool {
+    spec.line_type != LineType3::None && spec.color.is_some()
+}
+
+/// 테두리 변 목록 → `<w:top .../>` 등 (선이 없으면 nil)
+pub(super) fn borders(w: &mut XmlWriter, sides: &[(&str, &Option<LineSpec>)]) {
+    for (name, spec) in sides {
+        let Some(spec) = spec.as_ref().filter(|spec| has_line(spec)) else {
+            w.empty(name, w_val("nil"));
+            continue;
+        };
+        let val = match spec.line_type {
+            LineType3::Dot | LineType3::Circle => "dotted",
+            LineType3::Dash | LineType3::LongDash => "dashed",
+            LineType3::DashDot => "dotDash",
+            LineType3::DashDotDot => "dotDotDash",
+            LineType3::DoubleSlim => "double",
+            LineType3::SlimThick => "thinThickSmallGap",
+            LineType3::ThickSlim => "thickThinSmallGap",
+            LineType3::SlimThickSlim => "thinThickThinSmallGap",
+            LineType3::Wave => "wave",
+            _ => "single",
+        };
+        // 굵기 "0.12 mm" → 1/8pt 단위 (Word 허용 범위 2..96)
+        let width_mm = spec
+            .width
+            .trim()
+            .trim_end_matches("mm")
+            .trim()
+            .parse::<f64>()
+            .unwrap_or(0.12);
+        let size = (width_mm * 72.0 / 25.4 * 8.0).round().clamp(2.0, 96.0) as i32;
+        w.empty(
+            name,
+            w_val(val)
+                .add("w:sz", size)
+                .add("w:space", 0)
+                .add("w:color", spec.color.map_or("auto".to_string(), hex_color)),
+        );
+    }
+}
+
+/// BorderFill 단색 채우기 → `<w:shd>`
+pub(super) fn shading(w: &mut XmlWriter, bf: &BorderFill) {
+    if let Some(color) = bf.fill.as_ref().and_then(solid_color) {
+        w.empty(
+            "w:shd",
+            w_val("clear")
+                .add("w:color", "auto")
+                .add("w:fill", hex_color(color)),
+        );
+    }
+}
+
+fn solid_color(fill: &FillBrush) -> Option<u32> {
+    match fill {
+        FillBrush::WinBrush { face_color, .. } => face_color.filter(|&c| c != 0xFFFFFF),
+        FillBrush::Combined {
+            win_brush: Some(brush),
+            ..
+        } => solid_color(brush),
+        _ => None,
+    }
+}
@@ -0,0 +1,203 @@
+/// 수식 트리 → LaTeX / Equation tree to LaTeX
+use super::parser::{Accent, FontStyle, Node, SpaceKind, TableKind};
+
+pub fn render(node: &Node) -> String {
+    let mut writer = LatexWriter { out: String::new() };
+    writer.node(node);
+    writer.out.trim().to_string()
+}
+
+struct LatexWriter {
+    out: String,
+}
+
+impl LatexWriter {
+    /// 명령어(`\pi`) 바로 뒤에 글자나 숫자가 오면 공백으로 구분
+    fn push(&mut self, text: &str) {
+        let ends_with_command = self.out.rfind('\\').is_some_and(|i| {
+            let tail = &self.out[i + 1..];
+            !tail.is_empty() && tail.chars().all(|c| c.is_ascii_alphabetic())
+        });
+        if ends_with_command && text.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+            self.out.push(' ');
+        }
+        self.out.push_str(text);
+    }
+
+    /// `{...}`로 감싼 인자
+    fn group(&mut self, node: &Node) {
+        self.out.push('{');
+        self.node(node);
+        self.out.push('}');
+    }
+
+    fn node(&mut self, node: &Node) {
+        match node {
+            Node::Row(items) => {
+                for item in items {
+                    self.node(item);
+                }
+            }
+            Node::Ident(name) => self.push(name),
+            Node::Number(n) => self.push(n),
+            Node::Operator(op) => self.push(&escape(op)),
+            Node::Symbol(sym) => self.push(sym.latex),
+            Node::Text(text) => {
+                self.push("\\text{");
+                self.out.push_str(&escape_text(text));
+                self.out.push('}');
+            }
+            Node::Space(SpaceKind::Normal) => self.push("\\ "),
+            Node::Space(SpaceKind::Thin) => self.push("\\,"),
+            Node::Frac { num, den, line } => {
+                self.push(if *line {
+                    "\\frac"
+                } else {
+                    "\\genfrac{}{}{0pt}{}"
+                });
+                self.group(num);
+                self.group(den);
+            }
+            Node::Sqrt { index, body } => {
+                self.push("\\sqrt");
+                if let Some(index) = index {
+                    self.out.push('[');
+                    self.node(index);
+                    self.out.push(']');
+                }
+                self.group(body);
+            }
+            Node::Scripts { base, sub, sup } => {
+                // 첨자 위치를 분명히 하기 위해 밑이 비어 있거나 복합 항목이면 묶음
+                match **base {
+                    Node::Row(ref items) if items.len() != 1 => self.group(base),
+                    Node::Scripts { .. } | Node::Frac { .. } => self.group(base),
+                    _ => self.node(base),
+                }
+                if let Some(sub) = sub {
+                    self.out.push('_');
+                    self.group(sub);
+                }
+                if let Some(sup) = sup {
+                    self.out.push('^');
+                    self.group(sup);
+                }
+            }
+            Node::Accent { accent, body } => {
+                let command = match accent {
+                    Accent::Hat => "\\hat",
+                    Accent::Bar => "\\overline",
+                    Accent::Vec => "\\vec",
+                    Accent::Dot => "\\dot",
+                    Accent::DDot => "\\ddot",
+                    Accent::Tilde => "\\tilde",
+                    Accent::Check => "\\check",
+                    Accent::Acute => "\\acute",
+                    Accent::Grave => "\\grave",
+                    Accent::Dyad => "\\overleftrightarrow",
+                    Accent::Arch => "\\overset{\\frown}",
+                    Accent::Under => "\\underline",
+                };
+                self.push(command);
+                self.group(body);
+            }
+            Node::Fenced { open, close, body } => {
+                self.push("\\left");
+                self.out.push_str(&delimiter(open));
+                self.node(body);
+                self.push("\\right");
+                self.out.push_str(&delimiter(close));
+            }
+            Node::Table { kind, rows } => self.table(*kind, rows),
+            Node::Style { style, body } => {
+                self.push(match style {
+                    FontStyle::Roman => "\\mathrm",
+                    FontStyle::Italic => "\\mathit",
+                    FontStyle::Bold => "\\mathbf",
+                });
+                self.group(body);
+            }
+        }
+    }
+
+    fn table(&mut self, kind: TableKind, rows: &[Vec<Node>]) {
+        let aligned = rows.iter().any(|r| r.len() > 1);
+        let (begin, end) = match kind {
+            TableKind::Matrix => ("\\begin{matrix}", "\\end{matrix}"),
+            TableKind::PMatrix => ("\\begin{pmatrix}", "\\end{pmatrix}"),
+            TableKind::BMatrix => ("\\begin{bmatrix}", "\\end{bmatrix}"),
+            TableKind::DMatrix => ("\\begin{vmatrix}", "\\end{vmatrix}"),
+            TableKind::Cases => ("\\begin{cases}", "\\end{cases}"),
+            TableKind::Pile => ("\\begin{array}{c}", "\\end{array}"),
+            TableKind::LPile => ("\\begin{array}{l}", "\\end{array}"),
+            TableKind::RPile => ("\\begin{array}{r}", "\\end{array}"),
+            TableKind::EqAlign => ("\\begin{aligned}", "\\end{aligned}"),
+            TableKind::Lines if aligned => ("\\begin{aligned}", "\\end{aligned}"),
+            TableKind::Lines => ("\\begin{gathered}", "\\end{gathered}"),
+        };
+        self.push(begin);
+        for (i, row) in rows.iter().enumerate() {
+            if i > 0 {
+                self.out.push_str(" \\\\ ");
+            }
+            for (j, cell) in row.iter().enumerate() {
+                if j > 0 {
+                    self.out.push_str(" & ");
+                }
+                self.node(cell);
+            }
+        }
+        self.push(end);
+    }
+}
+
+/// LaTeX 특수 문자 이스케이프
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '{' | '}' | '#' | '%' | '&' | '$' | '_' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\\' => out.push_str("\\backslash "),
+            '^' => out.push_str("\\hat{}"),
+            '~' => out.push_str("\\sim "),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// `\text{}` 안의 특수 문자 이스케이프
+fn escape_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '{' | '}' | '#' | '%' | '&' | '$' | '_' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\\' => out.push_str("\\textbackslash{}"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// `\left`/`\right` 뒤의 괄호
+fn delimiter(delimiter: &str) -> String {
+    match delimiter {
+        "" => ".".to_string(),
+        "{" => "\\{".to_string(),
+        "}" => "\\}".to_string(),
+        "⟨" => "\\langle ".to_string(),
+        "⟩" => "\\rangle ".to_string(),
+        "⌈" => "\\lceil ".to_string(),
+        "⌉" => "\\rceil ".to_string(),
+        "⌊" => "\\lfloor ".to_string(),
+        "⌋" => "\\rfloor ".to_string(),
+        "‖" => "\\| ".to_string(),
+        d => d.to_string(),
+    }
+}
@@ -0,0 +1,238 @@
+/// 수식 트리 → MathML / Equation tree to MathML
+use super::parser::{Accent, FontStyle, Node, SpaceKind, TableKind};
+use super::symbols::SymbolKind;
+use crate::viewer::doc_utils::html_escape;
+
+pub fn render(node: &Node) -> String {
+    let mut writer = MathMlWriter {
+        out: String::new(),
+        variant: None,
+    };
+    writer.node(node);
+    format!(
+        r#"<math xmlns="http://www.w3.org/1998/Math/MathML">{}</math>"#,
+        writer.out
+    )
+}
+
+struct MathMlWriter {
+    out: String,
+    /// `rm`/`it`/`bold`로 바뀐 글자 모양 (mathvariant)
+    variant: Option<&'static str>,
+}
+
+impl MathMlWriter {
+    fn token(&mut self, tag: &str, text: &str) {
+        self.out.push('<');
+        self.out.push_str(tag);
+        if let Some(variant) = self.variant {
+            self.out.push_str(&format!(r#" mathvariant="{}""#, variant));
+        }
+        self.out.push('>');
+        self.out.push_str(&html_escape(text));
+        self.out.push_str("</");
+        self.out.push_str(tag);
+        self.out.push('>');
+    }
+
+    /// 자식 하나로 쓰이는 인자: 여러 항목이면 `<mrow>`로 묶음
+    fn arg(&mut self, node: &Node) {
+        match node {
+            Node::Row(items) if items.len() == 1 => self.arg(&items[0]),
+            Node::Row(items) => {
+                self.out.push_str("<mrow>");
+                for item in items {
+                    self.node(item);
+                }
+                self.out.push_str("</mrow>");
+            }
+            Node::Ident(name) if name.chars().count() > 1 => {
+                self.out.push_str("<mrow>");
+                self.node(node);
+                self.out.push_str("</mrow>");
+            }
+            _ => self.node(node),
+        }
+    }
+
+    fn node(&mut self, node: &Node) {
+        match node {
+            Node::Row(items) => {
+                if items.len() == 1 {
+                    self.node(&items[0]);
+                } else {
+                    self.arg(node);
+                }
+            }
+            Node::Ident(name) => {
+                // 한글 수식처럼 여러 글자 변수도 글자마다 기울임
+                for c in name.chars() {
+                    self.token("mi", &c.to_string());
+                }
+            }
+            Node::Number(n) => self.token("mn", n),
+            Node::Operator(op) => {
+                let text = if op == "'" { "′" } else { op.as_str() };
+                self.token("mo", text);
+            }
+            Node::Symbol(sym) => match sym.kind {
+                SymbolKind::Identifier | SymbolKind::Function => self.token("mi", sym.text),
+                SymbolKind::BigOp if sym.text.chars().count() > 1 => self.token("mi", sym.text),
+                _ => self.token("mo", sym.text),
+            },
+            Node::Text(text) => self.token("mtext", text),
+            Node::Space(kind) => {
+                let width = match kind {
+                    SpaceKind::Normal => "0.333em",
+                    SpaceKind::Thin => "0.167em",
+                };
+                self.out
+                    .push_str(&format!(r#"<mspace width="{}"/>"#, width));
+            }
+            Node::Frac { num, den, line } => {
+                self.out.push_str(if *line {
+                    "<mfrac>"
+                } else {
+                    r#"<mfrac linethickness="0">"#
+                });
+                self.arg(num);
+                self.arg(den);
+                self.out.push_str("</mfrac>");
+            }
+            Node::Sqrt { index: None, body } => {
+                self.out.push_str("<msqrt>");
+                self.arg(body);
+                self.out.push_str("</msqrt>");
+            }
+            Node::Sqrt {
+                index: Some(index),
+                body,
+            } => {
+                self.out.push_str("<mroot>");
+                self.arg(body);
+                self.arg(index);
+                self.out.push_str("</mroot>");
+            }
+            Node::Scripts { base, sub, sup } => {
+                // ∑, ∏, lim 등은 한계를 위/아래에, 적분과 일반 항목은 오른쪽에 붙임
+                let limits = matches!(
+                    **base,
+                    Node::Symbol(sym) if sym.kind == SymbolKind::BigOp
+                );
+                let tag = match (sub.is_some(), sup.is_some(), limits) {
+                    (true, true, true) => "munderover",
+                    (true, false, true) => "munder",
+                    (false, _, true) => "mover",
+                    (true, true, false) => "msubsup",
+                    (true, false, false) => "msub",
+                    (false, _, false) => "msup",
+                };
+                self.out.push_str(&format!("<{}>", tag));
+                self.arg(base);
+                if let Some(sub) = sub {
+                    self.arg(sub);
+                }
+                if let Some(sup) = sup {
+                    self.arg(sup);
+                }
+                self.out.push_str(&format!("</{}>", tag));
+            }
+            Node::Accent { accent, body } => {
+                let mark = match accent {
+                    Accent::Hat => "^",
+                    Accent::Bar => "¯",
+                    Accent::Vec => "→",
+                    Accent::Dot => "˙",
+                    Accent::DDot => "¨",
+                    Accent::Tilde => "˜",
+                    Accent::Check => "ˇ",
+                    Accent::Acute => "´",
+                    Accent::Grave => "`",
+                    Accent::Dyad => "↔",
+                    Accent::Arch => "⌒",
+                    Accent::Under => "_",
+                };
+                if *accent == Accent::Under {
+                    self.out.push_str(r#"<munder accentunder="true">"#);
+                    self.arg(body);
+                    self.out.push_str(&format!("<mo>{}</mo></munder>", mark));
+                } else {
+                    self.out.push_str(r#"<mover accent="true">"#);
+                    self.arg(body);
+                    self.out.push_str(&format!("<mo>{}</mo></mover>", mark));
+                }
+            }
+            Node::Fenced { open, close, body } => {
+                self.out.push_str("<mrow>");
+                self.fence(open, "prefix");
+                self.node(body);
+                self.fence(close, "postfix");
+                self.out.push_str("</mrow>");
+            }
+            Node::Table { kind, rows } => self.table(*kind, rows),
+            Node::Style { style, body } => {
+                let saved = self.variant;
+                self.variant = Some(match style {
+                    FontStyle::Roman => "normal",
+                    FontStyle::Italic => "italic",
+                    FontStyle::Bold => "bold",
+                });
+                self.node(body);
+                self.variant = saved;
+            }
+        }
+    }
+
+    fn fence(&mut self, delimiter: &str, form: &str) {
+        if !delimiter.is_empty() {
+            self.out.push_str(&format!(
+                r#"<mo fence="true" form="{}">{}</mo>"#,
+                form,
+                html_escape(delimiter)
+            ));
+        }
+    }
+
+    fn table(&mut self, kind: TableKind, rows: &[Vec<Node>]) {
+        let (open, close) = match kind {
+            TableKind::PMatrix => ("(", ")"),
+            TableKind::BMatrix => ("[", "]"),
+            TableKind::DMatrix => ("|", "|"),
+            TableKind::Cases => ("{", ""),
+            _ => ("", ""),
+        };
+        let columnalign = match kind {
+            TableKind::Cases | TableKind::LPile => Some("left"),
+            TableKind::RPile => Some("right"),
+            TableKind::EqAlign => Some("right left"),
+            TableKind::Lines if rows.iter().any(|r| r.len() > 1) => Some("right left"),
+            _ => None,
+        };
+
+        let fenced = !open.is_empty() || !close.is_empty();
+        if fenced {
+            self.out.push_str("<mrow>");
+            self.fence(open, "prefix");
+        }
+        match columnalign {
+            Some(align) => self
+                .out
+                .push_str(&format!(r#"<mtable columnalign="{}">"#, align)),
+            None => self.out.push_str("<mtable>"),
+        }
+        for row in rows {
+            self.out.push_str("<mtr>");
+            for cell in row {
+                self.out.push_str("<mtd>");
+                self.node(cell);
+                self.out.push_str("</mtd>");
+            }
+            self.out.push_str("</mtr>");
+        }
+        self.out.push_str("</mtable>");
+        if fenced {
+            self.fence(close, "postfix");
+            self.out.push_str("</mrow>");
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Equation script conversion
+//! 한글 수식 스크립트를 MathML/LaTeX로 바꾸는 모듈
+//!
+//! `EquationObject.script`(HWPX `<hp:script>`, HWP `EqEdit.script`)는 한글 수식 편집기 언어로
+//! 저장됩니다 (`{a} over {b}`, `sqrt`, `sum from a to b`, `matrix{a & b # c & d}`,
+//! `left ( ... right )`, `rm`/`bold`, 그리스 문자 등). 스크립트를 수식 트리로 파싱한 뒤
+//! HTML용 MathML과 Markdown용 LaTeX(`$...$`)로 출력합니다.
+//!
+//! Parses the Hancom equation language into a tree and renders it as MathML (for HTML) or
+//! LaTeX (for Markdown). Malformed scripts never fail: unmatched braces and unknown commands are
+//! kept as plain identifiers so that the output stays readable.
+mod latex;
+mod mathml;
+mod parser;
+mod symbols;
+
+pub use parser::{parse, Accent, FontStyle, Node, SpaceKind, TableKind};
+pub use symbols::{Symbol, SymbolKind};
+
+/// 수식 스크립트 → `<math>` 요소 / Equation script to a MathML `<math>` element
+pub fn equation_to_mathml(script: &str) -> String {
+    mathml::render(&parse(script))
+}
+
+/// 수식 스크립트 → LaTeX (`$` 구분자 제외) / Equation script to LaTeX (without `$` delimiters)
+pub fn equation_to_latex(script: &str) -> String {
+    latex::render(&parse(script))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fraction_and_sqrt() {
+        assert_eq!(equation_to_latex("{a} over {b}"), "\\frac{a}{b}");
+        assert_eq!(
+            equation_to_latex("Y= sqrt {{gL} over {2 pi }} = {gT} over {2 pi }"),
+            "Y=\\sqrt{\\frac{gL}{2\\pi}}=\\frac{gT}{2\\pi}"
+        );
+        // over는 바로 앞 항목만 분자로 삼음
+        assert_eq!(equation_to_latex("x+1 over 2"), "x+\\frac{1}{2}");
+        assert_eq!(equation_to_latex("root 3 of x"), "\\sqrt[3]{x}");
+        assert_eq!(
+            equation_to_mathml("{a} over {b}"),
+            r#"<math xmlns="http://www.w3.org/1998/Math/MathML"><mfrac><mi>a</mi><mi>b</mi></mfrac></math>"#
+        );
+    }
+
+    #[test]
+    fn test_scripts_and_big_operators() {
+        assert_eq!(
+            equation_to_latex("sum _{k=1} ^{n} k  ^{3}"),
+            "\\sum_{k=1}^{n}k^{3}"
+        );
+        assert_eq!(
+            equation_to_latex("sum from {k=1} to n k"),
+            "\\sum_{k=1}^{n}k"
+        );
+        assert_eq!(
+            equation_to_latex("Q= lim _{TRIANGLE t -> 0} {TRIANGLE s} over {TRIANGLE t}"),
+            "Q=\\lim_{\\triangle t\\rightarrow 0}\\frac{\\triangle s}{\\triangle t}"
+        );
+        assert_eq!(equation_to_latex("int _{0} ^{q} qdq"), "\\int_{0}^{q}qdq");
+
+        let mathml = equation_to_mathml("sum from {k=1} to n k");
+        assert!(mathml.contains("<munderover><mo>∑</mo><mrow><mi>k</mi><mo>=</mo><mn>1</mn></mrow><mi>n</mi></munderover>"));
+        let mathml = equation_to_mathml("int _{0} ^{q} x");
+        assert!(mathml.contains("<msubsup><mo>∫</mo><mn>0</mn><mi>q</mi></msubsup>"));
+        assert!(equation_to_mathml("x^2").contains("<msup><mi>x</mi><mn>2</mn></msup>"));
+        // 여러 글자 변수는 첨자의 밑 하나로 묶음
+        assert!(equation_to_mathml("mr^2")
+            .contains("<msup><mrow><mi>m</mi><mi>r</mi></mrow><mn>2</mn></msup>"));
+    }
+
+    #[test]
+    fn test_keywords_ignore_case() {
+        assert_eq!(
+            equation_to_latex("0.9 TIMES 10 ^{3} GEQ 1"),
+            "0.9\\times 10^{3}\\ge 1"
+        );
+        assert_eq!(equation_to_latex("a RARROW b"), "a\\Rightarrow b");
+        assert_eq!(equation_to_latex("a rarrow b"), "a\\rightarrow b");
+        assert_eq!(equation_to_latex("GAMMA gamma"), "\\Gamma\\gamma");
+    }
+
+    #[test]
+    fn test_fences_and_tables() {
+        assert_eq!(
+            equation_to_latex(
+                "sqrt {a ^{2}} = LEFT | a RIGHT | = {cases{a&(a GEQ 0)#-a&(a<0)}}"
+            ),
+            "\\sqrt{a^{2}}=\\left|a\\right|=\\begin{cases}a & (a\\ge 0) \\\\ -a & (a<0)\\end{cases}"
+        );
+        assert_eq!(
+            equation_to_latex("(abc) {pmatrix{p#q#r}}"),
+            "(abc)\\begin{pmatrix}p \\\\ q \\\\ r\\end{pmatrix}"
+        );
+        assert_eq!(equation_to_latex("left { x right ."), "\\left\\{x\\right.");
+
+        let mathml = equation_to_mathml("matrix{1 & 2 # 3 & 4}");
+        assert!(mathml.contains(
+            "<mtable><mtr><mtd><mn>1</mn></mtd><mtd><mn>2</mn></mtd></mtr><mtr><mtd><mn>3</mn></mtd><mtd><mn>4</mn></mtd></mtr></mtable>"
+        ));
+        let mathml = equation_to_mathml("cases{a # b}");
+        assert!(
+            mathml.contains(r#"<mo fence="true" form="prefix">{</mo><mtable columnalign="left">"#)
+        );
+    }
+
+    #[test]
+    fn test_accents_styles_and_text() {
+        assert_eq!(
+            equation_to_latex("vec{F} = bar{AB}"),
+            "\\vec{F}=\\overline{AB}"
+        );
+        assert_eq!(
+            equation_to_latex("rm {kg} cdot bold x"),
+            "\\mathrm{kg}\\cdot\\mathbf{x}"
+        );
+        assert_eq!(equation_to_latex("\"if \" x>0"), "\\text{if }x>0");
+        assert_eq!(equation_to_latex("x 이면"), "x\\text{이면}");
+        assert_eq!(equation_to_latex("a`b~c"), "a\\,b\\ c");
+
+        let mathml = equation_to_mathml("rm {kg} < 5");
+        assert!(mathml.contains(r#"<mi mathvariant="normal">k</mi><mi mathvariant="normal">g</mi><mo>&lt;</mo><mn>5</mn>"#));
+        assert!(equation_to_mathml("hat a")
+            .contains(r#"<mover accent="true"><mi>a</mi><mo>^</mo></mover>"#));
+    }
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(
+            equation_to_latex("a=b # c=d"),
+            "\\begin{gathered}a=b \\\\ c=d\\end{gathered}"
+        );
+        assert_eq!(
+            equation_to_latex("x &= 1 # y &= 2"),
+            "\\begin{aligned}x & =1 \\\\ y & =2\\end{aligned}"
+        );
+    }
+
+    #[test]
+    fn test_malformed_scripts() {
+        // 짝이 맞지 않는 괄호나 빈 인자도 멈추지 않고 출력
+        assert_eq!(equation_to_latex("a } b"), "ab");
+        assert_eq!(equation_to_latex("{a over"), "\\frac{a}{}");
+        assert_eq!(equation_to_latex("sqrt"), "\\sqrt{}");
+        assert_eq!(equation_to_latex("x^"), "x^{}");
+        assert_eq!(equation_to_latex("right )"), ")");
+        assert_eq!(equation_to_latex(""), "");
+        assert!(equation_to_mathml("matrix{a &").contains("<mtable>"));
+    }
+}
@@ -0,0 +1,528 @@
+/// 수식 스크립트 파서 / Equation script parser
+///
+/// 한글 수식 스크립트(`{a} over {b}`, `sqrt`, `sum from`, `matrix{...}` 등)를 [`Node`] 트리로 바꿉니다.
+/// 한글처럼 문법 오류가 있어도 멈추지 않고, 읽을 수 있는 만큼 트리를 만듭니다.
+use super::symbols::{self, Symbol, SymbolKind};
+
+/// 수식 트리 노드 / Equation tree node
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    /// 나란히 놓인 항목들
+    Row(Vec<Node>),
+    /// 변수 (기울임)
+    Ident(String),
+    Number(String),
+    /// 명령어가 아닌 연산 문자 (`+`, `=`, `(` 등)
+    Operator(String),
+    /// 명령어 기호 (그리스 문자, 연산 기호, 큰 연산자, 함수)
+    Symbol(&'static Symbol),
+    /// 따옴표 안의 글자나 한글 (정체)
+    Text(String),
+    Space(SpaceKind),
+    /// `over` (분수선 있음) / `atop` (분수선 없음)
+    Frac {
+        num: Box<Node>,
+        den: Box<Node>,
+        line: bool,
+    },
+    /// `sqrt x` / `root n of x`
+    Sqrt {
+        index: Option<Box<Node>>,
+        body: Box<Node>,
+    },
+    /// 아래/위 첨자, 큰 연산자의 `from`/`to` 한계
+    Scripts {
+        base: Box<Node>,
+        sub: Option<Box<Node>>,
+        sup: Option<Box<Node>>,
+    },
+    Accent {
+        accent: Accent,
+        body: Box<Node>,
+    },
+    /// `left ( ... right )` (빈 문자열은 괄호 없음)
+    Fenced {
+        open: String,
+        close: String,
+        body: Box<Node>,
+    },
+    /// 행렬/경우/쌓기. 행은 `#`, 칸은 `&`로 나뉨
+    Table {
+        kind: TableKind,
+        rows: Vec<Vec<Node>>,
+    },
+    Style {
+        style: FontStyle,
+        body: Box<Node>,
+    },
+}
+
+/// `~` (보통 간격) / `` ` `` (1/4 간격)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpaceKind {
+    Normal,
+    Thin,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Accent {
+    Hat,
+    Bar,
+    Vec,
+    Dot,
+    DDot,
+    Tilde,
+    Check,
+    Acute,
+    Grave,
+    Dyad,
+    Arch,
+    Under,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableKind {
+    /// `matrix` (괄호 없음)
+    Matrix,
+    /// `pmatrix` ( )
+    PMatrix,
+    /// `bmatrix` [ ]
+    BMatrix,
+    /// `dmatrix` | |
+    DMatrix,
+    /// `cases` (왼쪽 중괄호)
+    Cases,
+    /// `pile` / `lpile` / `rpile` (가운데/왼쪽/오른쪽 정렬)
+    Pile,
+    LPile,
+    RPile,
+    /// `eqalign`: `&` 기준 정렬
+    EqAlign,
+    /// 최상위의 `#` 줄 바꿈
+    Lines,
+}
+
+/// `rm` (정체) / `it` (기울임) / `bold` (굵게)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontStyle {
+    Roman,
+    Italic,
+    Bold,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// 영문 단어 (명령어 또는 변수)
+    Word(String),
+    Number(String),
+    /// 따옴표 글자, 한글
+    Text(String),
+    /// 여러 글자 연산자 (`<=`, `->` 등)
+    Op(&'static Symbol),
+    LBrace,
+    RBrace,
+    Char(char),
+}
+
+fn tokenize(script: &str) -> Vec<Token> {
+    let chars: Vec<char> = script.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_alphabetic() {
+            let start = i;
+            while i < chars.len() && chars[i].is_ascii_alphabetic() {
+                i += 1;
+            }
+            tokens.push(Token::Word(chars[start..i].iter().collect()));
+        } else if c.is_ascii_digit()
+            || (c == '.' && chars.get(i + 1).is_some_and(|n| n.is_ascii_digit()))
+        {
+            let start = i;
+            let mut seen_dot = false;
+            while i < chars.len() {
+                let ch = chars[i];
+                if ch.is_ascii_digit() {
+                    i += 1;
+                } else if ch == '.'
+                    && !seen_dot
+                    && chars.get(i + 1).is_some_and(|n| n.is_ascii_digit())
+                {
+                    seen_dot = true;
+                    i += 1;
+                } else {
+                    break;
+                }
+            }
+            tokens.push(Token::Number(chars[start..i].iter().collect()));
+        } else if c == '"' {
+            let start = i + 1;
+            i = start;
+            while i < chars.len() && chars[i] != '"' {
+                i += 1;
+            }
+            tokens.push(Token::Text(chars[start..i].iter().collect()));
+            i += 1;
+        } else if is_hangul(c) {
+            let start = i;
+            while i < chars.len() && is_hangul(chars[i]) {
+                i += 1;
+            }
+            tokens.push(Token::Text(chars[start..i].iter().collect()));
+        } else if c == '{' {
+            tokens.push(Token::LBrace);
+            i += 1;
+        } else if c == '}' {
+            tokens.push(Token::RBrace);
+            i += 1;
+        } else {
+            let rest: String = chars[i..chars.len().min(i + 3)].iter().collect();
+            let op = symbols::MULTI_CHAR_OPERATORS
+                .iter()
+                .find(|op| rest.starts_with(**op));
+            match op.and_then(|op| symbols::lookup(op).map(|s| (op, s))) {
+                Some((op, sym)) => {
+                    tokens.push(Token::Op(sym));
+                    i += op.len();
+                }
+                None => {
+                    tokens.push(Token::Char(c));
+                    i += 1;
+                }
+            }
+        }
+    }
+    tokens
+}
+
+fn is_hangul(c: char) -> bool {
+    matches!(c, '\u{AC00}'..='\u{D7A3}' | '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}')
+}
+
+/// 수식 스크립트 → 수식 트리
+///
+/// 최상위 `#`(줄 바꿈)이나 `&`(정렬)가 있으면 [`TableKind::Lines`] 표가 됩니다.
+pub fn parse(script: &str) -> Node {
+    let mut parser = Parser {
+        tokens: tokenize(script),
+        pos: 0,
+    };
+
+    let mut rows: Vec<Vec<Node>> = Vec::new();
+    let mut cells: Vec<Node> = Vec::new();
+    let mut continue_cell = false;
+    loop {
+        let row = parser.row();
+        match cells.last_mut() {
+            Some(Node::Row(cell)) if continue_cell => cell.extend(row),
+            _ => cells.push(Node::Row(row)),
+        }
+        continue_cell = false;
+        match parser.next() {
+            None => break,
+            Some(Token::Char('#')) => rows.push(std::mem::take(&mut cells)),
+            Some(Token::Char('&')) => {}
+            // 짝이 없는 `}`/`right`는 건너뛰고 같은 칸을 이어 읽음
+            Some(_) => continue_cell = true,
+        }
+    }
+    rows.push(cells);
+
+    if rows.len() == 1 && rows[0].len() == 1 {
+        rows.pop()
+            .and_then(|mut r| r.pop())
+            .unwrap_or(Node::Row(Vec::new()))
+    } else {
+        Node::Table {
+            kind: TableKind::Lines,
+            rows,
+        }
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        if token.is_some() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(w)) if w.eq_ignore_ascii_case(keyword))
+    }
+
+    /// 행 끝(`}`, `&`, `#`, `right`, 입력 끝)인지
+    fn at_row_end(&self) -> bool {
+        matches!(
+            self.peek(),
+            None | Some(Token::RBrace) | Some(Token::Char('&')) | Some(Token::Char('#'))
+        ) || self.peek_keyword("right")
+    }
+
+    /// 행 끝까지 항목 읽기. `over`/`atop`은 바로 앞 항목을 분자로 삼음
+    fn row(&mut self) -> Vec<Node> {
+        let mut items = Vec::new();
+        while !self.at_row_end() {
+            if self.peek_keyword("over") || self.peek_keyword("atop") {
+                let line = self.peek_keyword("over");
+                self.pos += 1;
+                let num = items.pop().unwrap_or(Node::Row(Vec::new()));
+                let den = self.scripted();
+                items.push(Node::Frac {
+                    num: Box::new(num),
+                    den: Box::new(den),
+                    line,
+                });
+            } else {
+                items.push(self.scripted());
+            }
+        }
+        items
+    }
+
+    fn at_sup(&self) -> bool {
+        matches!(self.peek(), Some(Token::Char('^'))) || self.peek_keyword("sup")
+    }
+
+    fn at_sub(&self) -> bool {
+        matches!(self.peek(), Some(Token::Char('_'))) || self.peek_keyword("sub")
+    }
+
+    /// 항목 + 첨자 (`x^2`, `x_i`, `x sub i sup 2`, `sum from a to b`)
+    fn scripted(&mut self) -> Node {
+        let base = if self.at_sup() || self.at_sub() {
+            Node::Row(Vec::new())
+        } else {
+            self.primary()
+        };
+        let is_big_op = matches!(
+            base,
+            Node::Symbol(Symbol {
+                kind: SymbolKind::BigOp | SymbolKind::Integral,
+                ..
+            })
+        );
+
+        let mut sub = None;
+        let mut sup = None;
+        loop {
+            if self.at_sup() || (is_big_op && self.peek_keyword("to")) {
+                self.pos += 1;
+                sup = Some(Box::new(self.primary()));
+            } else if self.at_sub() || (is_big_op && self.peek_keyword("from")) {
+                self.pos += 1;
+                sub = Some(Box::new(self.primary()));
+            } else {
+                break;
+            }
+        }
+
+        if sub.is_none() && sup.is_none() {
+            base
+        } else {
+            Node::Scripts {
+                base: Box::new(base),
+                sub,
+                sup,
+            }
+        }
+    }
+
+    /// 첨자를 붙이기 전의 한 항목
+    fn primary(&mut self) -> Node {
+        if self.at_row_end() {
+            return Node::Row(Vec::new());
+        }
+        match self.next() {
+            Some(Token::LBrace) => Node::Row(self.group()),
+            Some(Token::Number(n)) => Node::Number(n),
+            Some(Token::Text(t)) => Node::Text(t),
+            Some(Token::Op(sym)) => Node::Symbol(sym),
+            Some(Token::Char('~')) => Node::Space(SpaceKind::Normal),
+            Some(Token::Char('`')) => Node::Space(SpaceKind::Thin),
+            Some(Token::Char(c)) if c.is_alphabetic() => Node::Ident(c.to_string()),
+            Some(Token::Char(c)) => Node::Operator(c.to_string()),
+            Some(Token::Word(w)) => self.word(w),
+            Some(Token::RBrace) | None => Node::Row(Vec::new()),
+        }
+    }
+
+    /// `{` 다음부터 짝이 맞는 `}`까지
+    fn group(&mut self) -> Vec<Node> {
+        let mut items = Vec::new();
+        loop {
+            items.extend(self.row());
+            match self.next() {
+                Some(Token::RBrace) | None => break,
+                // 묶음 안의 `&`, `#`, `right`는 무시
+                Some(_) => {}
+            }
+        }
+        items
+    }
+
+    fn word(&mut self, word: String) -> Node {
+        let keyword = word.to_ascii_lowercase();
+        match keyword.as_str() {
+            "sqrt" => Node::Sqrt {
+                index: None,
+                body: Box::new(self.primary()),
+            },
+            "root" => {
+                let index = self.primary();
+                if self.peek_keyword("of") {
+                    self.pos += 1;
+                }
+                Node::Sqrt {
+                    index: Some(Box::new(index)),
+                    body: Box::new(self.primary()),
+                }
+            }
+            // 분자 없이 시작한 분수
+            "over" | "atop" => Node::Frac {
+                num: Box::new(Node::Row(Vec::new())),
+                den: Box::new(self.scripted()),
+                line: keyword == "over",
+            },
+            "left" => self.fenced(),
+            "rm" | "it" | "bold" => Node::Style {
+                style: match keyword.as_str() {
+                    "rm" => FontStyle::Roman,
+                    "it" => FontStyle::Italic,
+                    _ => FontStyle::Bold,
+                },
+                body: Box::new(self.primary()),
+            },
+            _ => {
+                if let Some(kind) = table_kind(&keyword) {
+                    return self.table(kind);
+                }
+                if let Some(accent) = accent_kind(&keyword) {
+                    return Node::Accent {
+                        accent,
+                        body: Box::new(self.primary()),
+                    };
+                }
+                match symbols::lookup(&word) {
+                    Some(sym) => Node::Symbol(sym),
+                    None => Node::Ident(word),
+                }
+            }
+        }
+    }
+
+    /// `left` 다음부터 `right 괄호`까지
+    fn fenced(&mut self) -> Node {
+        let open = self.delimiter();
+        let mut items = Vec::new();
+        let close = loop {
+            items.extend(self.row());
+            if self.peek_keyword("right") {
+                self.pos += 1;
+                break self.delimiter();
+            }
+            match self.peek() {
+                // 닫는 괄호 없이 묶음이 끝남
+                None | Some(Token::RBrace) => break String::new(),
+                _ => self.pos += 1,
+            }
+        };
+        Node::Fenced {
+            open,
+            close,
+            body: Box::new(Node::Row(items)),
+        }
+    }
+
+    /// `left`/`right` 뒤의 괄호 (`.`은 괄호 없음)
+    fn delimiter(&mut self) -> String {
+        let delimiter = match self.peek() {
+            Some(Token::LBrace) => "{".to_string(),
+            Some(Token::RBrace) => "}".to_string(),
+            Some(Token::Char('.')) => String::new(),
+            Some(Token::Char(c)) if !matches!(c, '&' | '#') => c.to_string(),
+            Some(Token::Word(w)) => match w.to_ascii_lowercase().as_str() {
+                "langle" => "⟨".to_string(),
+                "rangle" => "⟩".to_string(),
+                "lceil" => "⌈".to_string(),
+                "rceil" => "⌉".to_string(),
+                "lfloor" => "⌊".to_string(),
+                "rfloor" => "⌋".to_string(),
+                _ => return String::new(),
+            },
+            _ => return String::new(),
+        };
+        self.pos += 1;
+        delimiter
+    }
+
+    /// `matrix{a & b # c & d}` 등
+    fn table(&mut self, kind: TableKind) -> Node {
+        let mut rows = Vec::new();
+        if !matches!(self.peek(), Some(Token::LBrace)) {
+            return Node::Table { kind, rows };
+        }
+        self.pos += 1;
+
+        let mut cells = Vec::new();
+        loop {
+            cells.push(Node::Row(self.row()));
+            match self.next() {
+                Some(Token::Char('&')) => {}
+                Some(Token::Char('#')) => rows.push(std::mem::take(&mut cells)),
+                Some(Token::RBrace) | None => break,
+                // 짝이 없는 `right`
+                Some(_) => {}
+            }
+        }
+        rows.push(cells);
+        Node::Table { kind, rows }
+    }
+}
+
+fn table_kind(keyword: &str) -> Option<TableKind> {
+    Some(match keyword {
+        "matrix" => TableKind::Matrix,
+        "pmatrix" => TableKind::PMatrix,
+        "bmatrix" => TableKind::BMatrix,
+        "dmatrix" => TableKind::DMatrix,
+        "cases" => TableKind::Cases,
+        "pile" => TableKind::Pile,
+        "lpile" => TableKind::LPile,
+        "rpile" => TableKind::RPile,
+        "eqalign" => TableKind::EqAlign,
+        _ => return None,
+    })
+}
+
+fn accent_kind(keyword: &str) -> Option<Accent> {
+    Some(match keyword {
+        "hat" => Accent::Hat,
+        "bar" => Accent::Bar,
+        "vec" => Accent::Vec,
+        "dot" => Accent::Dot,
+        "ddot" => Accent::DDot,
+        "tilde" => Accent::Tilde,
+        "check" => Accent::Check,
+        "acute" => Accent::Acute,
+        "grave" => Accent::Grave,
+        "dyad" => Accent::Dyad,
+        "arch" => Accent::Arch,
+        "under" => Accent::Under,
+        _ => return None,
+    })
+}
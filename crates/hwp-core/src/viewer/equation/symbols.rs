@@ -0,0 +1,255 @@
+//! 수식 스크립트 기호 표 / Equation script symbol table
+//!
+//! 한글 수식 편집기 명령어(그리스 문자, 연산 기호, 큰 연산자, 함수 이름)를
+//! 유니코드 문자와 LaTeX 명령으로 대응시킵니다.
+
+/// 기호 종류 / Symbol kind
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolKind {
+    /// 문자처럼 쓰는 기호 (그리스 문자, ∞ 등) → `<mi>`
+    Identifier,
+    /// 연산/관계 기호 → `<mo>`
+    Operator,
+    /// 아래/위 한계를 갖는 큰 연산자 (∑, ∏, lim 등)
+    BigOp,
+    /// 적분류: 한계를 오른쪽 아래/위에 붙임
+    Integral,
+    /// 함수 이름 (sin, log 등) → 정체
+    Function,
+}
+
+/// 수식 기호 / Equation symbol
+#[derive(Debug, PartialEq, Eq)]
+pub struct Symbol {
+    /// 수식 스크립트 명령어 / Script keyword
+    pub name: &'static str,
+    /// 출력 문자 / Output text
+    pub text: &'static str,
+    /// LaTeX 표현 / LaTeX form
+    pub latex: &'static str,
+    pub kind: SymbolKind,
+}
+
+const fn sym(
+    name: &'static str,
+    text: &'static str,
+    latex: &'static str,
+    kind: SymbolKind,
+) -> Symbol {
+    Symbol {
+        name,
+        text,
+        latex,
+        kind,
+    }
+}
+
+use SymbolKind::*;
+
+/// 명령어 기호 표 (대소문자 구분, 찾지 못하면 소문자로 다시 찾음)
+static SYMBOLS: &[Symbol] = &[
+    // 그리스 소문자
+    sym("alpha", "α", "\\alpha", Identifier),
+    sym("beta", "β", "\\beta", Identifier),
+    sym("gamma", "γ", "\\gamma", Identifier),
+    sym("delta", "δ", "\\delta", Identifier),
+    sym("epsilon", "ε", "\\epsilon", Identifier),
+    sym("zeta", "ζ", "\\zeta", Identifier),
+    sym("eta", "η", "\\eta", Identifier),
+    sym("theta", "θ", "\\theta", Identifier),
+    sym("iota", "ι", "\\iota", Identifier),
+    sym("kappa", "κ", "\\kappa", Identifier),
+    sym("lambda", "λ", "\\lambda", Identifier),
+    sym("mu", "μ", "\\mu", Identifier),
+    sym("nu", "ν", "\\nu", Identifier),
+    sym("xi", "ξ", "\\xi", Identifier),
+    sym("omicron", "ο", "o", Identifier),
+    sym("pi", "π", "\\pi", Identifier),
+    sym("rho", "ρ", "\\rho", Identifier),
+    sym("sigma", "σ", "\\sigma", Identifier),
+    sym("tau", "τ", "\\tau", Identifier),
+    sym("upsilon", "υ", "\\upsilon", Identifier),
+    sym("phi", "φ", "\\phi", Identifier),
+    sym("chi", "χ", "\\chi", Identifier),
+    sym("psi", "ψ", "\\psi", Identifier),
+    sym("omega", "ω", "\\omega", Identifier),
+    // 그리스 대문자
+    sym("ALPHA", "Α", "A", Identifier),
+    sym("BETA", "Β", "B", Identifier),
+    sym("GAMMA", "Γ", "\\Gamma", Identifier),
+    sym("DELTA", "Δ", "\\Delta", Identifier),
+    sym("EPSILON", "Ε", "E", Identifier),
+    sym("ZETA", "Ζ", "Z", Identifier),
+    sym("ETA", "Η", "H", Identifier),
+    sym("THETA", "Θ", "\\Theta", Identifier),
+    sym("IOTA", "Ι", "I", Identifier),
+    sym("KAPPA", "Κ", "K", Identifier),
+    sym("LAMBDA", "Λ", "\\Lambda", Identifier),
+    sym("MU", "Μ", "M", Identifier),
+    sym("NU", "Ν", "N", Identifier),
+    sym("XI", "Ξ", "\\Xi", Identifier),
+    sym("OMICRON", "Ο", "O", Identifier),
+    sym("PI", "Π", "\\Pi", Identifier),
+    sym("RHO", "Ρ", "P", Identifier),
+    sym("SIGMA", "Σ", "\\Sigma", Identifier),
+    sym("TAU", "Τ", "T", Identifier),
+    sym("UPSILON", "Υ", "\\Upsilon", Identifier),
+    sym("PHI", "Φ", "\\Phi", Identifier),
+    sym("CHI", "Χ", "X", Identifier),
+    sym("PSI", "Ψ", "\\Psi", Identifier),
+    sym("OMEGA", "Ω", "\\Omega", Identifier),
+    // 문자형 기호
+    sym("inf", "∞", "\\infty", Identifier),
+    sym("infty", "∞", "\\infty", Identifier),
+    sym("infinity", "∞", "\\infty", Identifier),
+    sym("partial", "∂", "\\partial", Identifier),
+    sym("nabla", "∇", "\\nabla", Identifier),
+    sym("emptyset", "∅", "\\emptyset", Identifier),
+    sym("hbar", "ℏ", "\\hbar", Identifier),
+    sym("ell", "ℓ", "\\ell", Identifier),
+    sym("aleph", "ℵ", "\\aleph", Identifier),
+    sym("wp", "℘", "\\wp", Identifier),
+    sym("Re", "ℜ", "\\Re", Identifier),
+    sym("Im", "ℑ", "\\Im", Identifier),
+    sym("angle", "∠", "\\angle", Identifier),
+    sym("triangle", "△", "\\triangle", Identifier),
+    sym("TRIANGLE", "△", "\\triangle", Identifier),
+    sym("deg", "°", "^{\\circ}", Identifier),
+    sym("prime", "′", "\\prime", Identifier),
+    sym("dagger", "†", "\\dagger", Identifier),
+    // 연산/관계 기호
+    sym("times", "×", "\\times", Operator),
+    sym("div", "÷", "\\div", Operator),
+    sym("pm", "±", "\\pm", Operator),
+    sym("+-", "±", "\\pm", Operator),
+    sym("mp", "∓", "\\mp", Operator),
+    sym("-+", "∓", "\\mp", Operator),
+    sym("cdot", "·", "\\cdot", Operator),
+    sym("circ", "∘", "\\circ", Operator),
+    sym("bullet", "•", "\\bullet", Operator),
+    sym("star", "⋆", "\\star", Operator),
+    sym("le", "≤", "\\le", Operator),
+    sym("leq", "≤", "\\le", Operator),
+    sym("<=", "≤", "\\le", Operator),
+    sym("ge", "≥", "\\ge", Operator),
+    sym("geq", "≥", "\\ge", Operator),
+    sym(">=", "≥", "\\ge", Operator),
+    sym("ne", "≠", "\\ne", Operator),
+    sym("neq", "≠", "\\ne", Operator),
+    sym("!=", "≠", "\\ne", Operator),
+    sym("<<", "≪", "\\ll", Operator),
+    sym(">>", "≫", "\\gg", Operator),
+    sym("approx", "≈", "\\approx", Operator),
+    sym("image", "≒", "\\fallingdotseq", Operator),
+    sym("equiv", "≡", "\\equiv", Operator),
+    sym("sim", "∼", "\\sim", Operator),
+    sym("simeq", "≃", "\\simeq", Operator),
+    sym("cong", "≅", "\\cong", Operator),
+    sym("propto", "∝", "\\propto", Operator),
+    sym("therefore", "∴", "\\therefore", Operator),
+    sym("because", "∵", "\\because", Operator),
+    sym("forall", "∀", "\\forall", Operator),
+    sym("exist", "∃", "\\exists", Operator),
+    sym("exists", "∃", "\\exists", Operator),
+    sym("in", "∈", "\\in", Operator),
+    sym("notin", "∉", "\\notin", Operator),
+    sym("owns", "∋", "\\ni", Operator),
+    sym("ni", "∋", "\\ni", Operator),
+    sym("subset", "⊂", "\\subset", Operator),
+    sym("supset", "⊃", "\\supset", Operator),
+    sym("subseteq", "⊆", "\\subseteq", Operator),
+    sym("supseteq", "⊇", "\\supseteq", Operator),
+    sym("cup", "∪", "\\cup", Operator),
+    sym("cap", "∩", "\\cap", Operator),
+    sym("perp", "⊥", "\\perp", Operator),
+    sym("bot", "⊥", "\\bot", Operator),
+    sym("parallel", "∥", "\\parallel", Operator),
+    sym("lnot", "¬", "\\neg", Operator),
+    sym("neg", "¬", "\\neg", Operator),
+    sym("wedge", "∧", "\\wedge", Operator),
+    sym("land", "∧", "\\land", Operator),
+    sym("vee", "∨", "\\vee", Operator),
+    sym("lor", "∨", "\\lor", Operator),
+    sym("oplus", "⊕", "\\oplus", Operator),
+    sym("xor", "⊕", "\\oplus", Operator),
+    sym("ominus", "⊖", "\\ominus", Operator),
+    sym("otimes", "⊗", "\\otimes", Operator),
+    sym("odot", "⊙", "\\odot", Operator),
+    sym("cdots", "⋯", "\\cdots", Operator),
+    sym("ldots", "…", "\\ldots", Operator),
+    sym("dots", "…", "\\ldots", Operator),
+    sym("vdots", "⋮", "\\vdots", Operator),
+    sym("ddots", "⋱", "\\ddots", Operator),
+    // 화살표 (소문자는 가는 화살표, 대문자는 겹화살표)
+    sym("rarrow", "→", "\\rightarrow", Operator),
+    sym("->", "→", "\\rightarrow", Operator),
+    sym("larrow", "←", "\\leftarrow", Operator),
+    sym("<-", "←", "\\leftarrow", Operator),
+    sym("lrarrow", "↔", "\\leftrightarrow", Operator),
+    sym("<->", "↔", "\\leftrightarrow", Operator),
+    sym("uparrow", "↑", "\\uparrow", Operator),
+    sym("downarrow", "↓", "\\downarrow", Operator),
+    sym("RARROW", "⇒", "\\Rightarrow", Operator),
+    sym("=>", "⇒", "\\Rightarrow", Operator),
+    sym("LARROW", "⇐", "\\Leftarrow", Operator),
+    sym("LRARROW", "⇔", "\\Leftrightarrow", Operator),
+    sym("<=>", "⇔", "\\Leftrightarrow", Operator),
+    sym("UPARROW", "⇑", "\\Uparrow", Operator),
+    sym("DOWNARROW", "⇓", "\\Downarrow", Operator),
+    sym("mapsto", "↦", "\\mapsto", Operator),
+    // 큰 연산자
+    sym("sum", "∑", "\\sum", BigOp),
+    sym("prod", "∏", "\\prod", BigOp),
+    sym("coprod", "∐", "\\coprod", BigOp),
+    sym("union", "⋃", "\\bigcup", BigOp),
+    sym("bigcup", "⋃", "\\bigcup", BigOp),
+    sym("inter", "⋂", "\\bigcap", BigOp),
+    sym("bigcap", "⋂", "\\bigcap", BigOp),
+    sym("lim", "lim", "\\lim", BigOp),
+    sym("Lim", "Lim", "\\operatorname*{Lim}", BigOp),
+    sym("int", "∫", "\\int", Integral),
+    sym("dint", "∬", "\\iint", Integral),
+    sym("tint", "∭", "\\iiint", Integral),
+    sym("oint", "∮", "\\oint", Integral),
+    // 함수
+    sym("sin", "sin", "\\sin", Function),
+    sym("cos", "cos", "\\cos", Function),
+    sym("tan", "tan", "\\tan", Function),
+    sym("cot", "cot", "\\cot", Function),
+    sym("sec", "sec", "\\sec", Function),
+    sym("csc", "csc", "\\csc", Function),
+    sym("arcsin", "arcsin", "\\arcsin", Function),
+    sym("arccos", "arccos", "\\arccos", Function),
+    sym("arctan", "arctan", "\\arctan", Function),
+    sym("sinh", "sinh", "\\sinh", Function),
+    sym("cosh", "cosh", "\\cosh", Function),
+    sym("tanh", "tanh", "\\tanh", Function),
+    sym("coth", "coth", "\\coth", Function),
+    sym("log", "log", "\\log", Function),
+    sym("ln", "ln", "\\ln", Function),
+    sym("lg", "lg", "\\lg", Function),
+    sym("exp", "exp", "\\exp", Function),
+    sym("det", "det", "\\det", Function),
+    sym("max", "max", "\\max", Function),
+    sym("min", "min", "\\min", Function),
+    sym("gcd", "gcd", "\\gcd", Function),
+    sym("lcm", "lcm", "\\operatorname{lcm}", Function),
+    sym("arg", "arg", "\\arg", Function),
+    sym("dim", "dim", "\\dim", Function),
+    sym("ker", "ker", "\\ker", Function),
+    sym("hom", "hom", "\\hom", Function),
+    sym("mod", "mod", "\\bmod", Function),
+];
+
+/// 명령어 → 기호 (정확히 일치하는 항목 우선, 없으면 소문자로 찾음)
+pub fn lookup(name: &str) -> Option<&'static Symbol> {
+    SYMBOLS.iter().find(|s| s.name == name).or_else(|| {
+        let lower = name.to_ascii_lowercase();
+        SYMBOLS.iter().find(|s| s.name == lower)
+    })
+}
+
+/// 여러 글자로 된 연산자 (긴 것부터 검사)
+pub const MULTI_CHAR_OPERATORS: &[&str] = &[
+    "<=>", "<->", "<=", ">=", "!=", "<<", ">>", "->", "<-", "=>", "+-", "-+",
+];
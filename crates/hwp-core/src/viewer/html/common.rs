@@ -1,5 +1,6 @@
 /// HTML 뷰어 공통 유틸리티 함수 / HTML viewer common utility functions
 use crate::document::{BinDataRecord, HwpDocument};
+use crate::viewer::html::image;
 use crate::{HwpError, WORD};
 use base64::{engine::general_purpose::STANDARD, Engine as _};
 use std::fs;
@@ -29,6 +30,8 @@ pub fn get_mime_type_from_bindata_id(document: &HwpDocument, bindata_id: WORD) -
                     "png" => "image/png",
                     "gif" => "image/gif",
                     "bmp" => "image/bmp",
+                    "wmf" => "image/x-wmf",
+                    "emf" => "image/x-emf",
                     _ => "image/jpeg",
                 }
                 .to_string();
@@ -56,14 +59,24 @@ pub fn save_image_to_file(
     // 파일명 생성 / Generate filename
     let extension = get_extension_from_bindata_id(document, bindata_id);
     let file_name = format!("BIN{:04X}.{}", bindata_id, extension);
-    let file_path = Path::new(dir_path).join(&file_name);
+    write_image_file(dir_path, &file_name, &image_data)
+}
+
+/// Write image bytes to `dir_path/file_name` and return file path
+/// 이미지 바이트를 `dir_path/file_name`에 저장하고 파일 경로 반환
+fn write_image_file(
+    dir_path: &str,
+    file_name: &str,
+    image_data: &[u8],
+) -> Result<String, HwpError> {
+    let file_path = Path::new(dir_path).join(file_name);
 
     // 디렉토리 생성 / Create directory
     fs::create_dir_all(dir_path)
         .map_err(|e| HwpError::Io(format!("Failed to create directory '{}': {}", dir_path, e)))?;
 
     // 파일 저장 / Save file
-    fs::write(&file_path, &image_data).map_err(|e| {
+    fs::write(&file_path, image_data).map_err(|e| {
         HwpError::Io(format!(
             "Failed to write file '{}': {}",
             file_path.display(),
@@ -81,6 +94,7 @@ pub fn get_image_url(
     bindata_id: WORD,
     image_output_dir: Option<&str>,
     html_output_dir: Option<&str>,
+    keep_metafile: bool,
 ) -> String {
     // BinData에서 이미지 데이터 찾기 / Find image data from BinData
     let base64_data = document
@@ -95,41 +109,29 @@ pub fn get_image_url(
         return String::new();
     }
 
+    // WMF/EMF는 SVG로 변환 (keep_metafile이면 원본 유지) / Convert WMF/EMF to SVG unless keep_metafile
+    if !keep_metafile {
+        let extension = get_extension_from_bindata_id(document, bindata_id);
+        let svg = STANDARD
+            .decode(base64_data)
+            .ok()
+            .and_then(|data| image::metafile_to_svg_image(&extension, &data));
+        if let Some(svg) = svg {
+            let file_name = format!("BIN{:04X}.svg", bindata_id);
+            return match image_output_dir
+                .map(|dir_path| write_image_file(dir_path, &file_name, svg.as_bytes()))
+            {
+                Some(Ok(file_path)) => image_file_url(file_path, html_output_dir),
+                _ => format!("data:image/svg+xml;base64,{}", STANDARD.encode(svg)),
+            };
+        }
+    }
+
     match image_output_dir {
         Some(dir_path) => {
             // 이미지를 파일로 저장 / Save image as file
             match save_image_to_file(document, bindata_id, base64_data, dir_path) {
-                Ok(file_path) => {
-                    // HTML 출력 디렉토리가 있으면 상대 경로 계산 / Calculate relative path if HTML output directory is provided
-                    if let Some(html_dir) = html_output_dir {
-                        let image_path = Path::new(&file_path);
-                        let html_path = Path::new(html_dir);
-
-                        // 상대 경로 계산 / Calculate relative path
-                        match pathdiff::diff_paths(image_path, html_path) {
-                            Some(relative_path) => {
-                                // 경로 구분자를 슬래시로 통일 / Normalize path separators to forward slashes
-                                relative_path.to_string_lossy().replace('\\', "/")
-                            }
-                            None => {
-                                // 상대 경로 계산 실패 시 파일명만 반환 / Return filename only if relative path calculation fails
-                                image_path
-                                    .file_name()
-                                    .and_then(|n| n.to_str())
-                                    .map(|s| s.to_string())
-                                    .unwrap_or_else(|| file_path)
-                            }
-                        }
-                    } else {
-                        // HTML 출력 디렉토리가 없으면 파일명만 반환 / Return filename only if HTML output directory is not provided
-                        let file_path_obj = Path::new(&file_path);
-                        file_path_obj
-                            .file_name()
-                            .and_then(|n| n.to_str())
-                            .unwrap_or(&file_path)
-                            .to_string()
-                    }
-                }
+                Ok(file_path) => image_file_url(file_path, html_output_dir),
                 Err(_) => {
                     // 실패 시 base64로 폴백 / Fallback to base64 on failure
                     let mime_type = get_mime_type_from_bindata_id(document, bindata_id);
@@ -144,3 +146,37 @@ pub fn get_image_url(
         }
     }
 }
+
+/// Saved image path to URL used in HTML
+/// 저장한 이미지 경로 → HTML에서 쓸 URL
+fn image_file_url(file_path: String, html_output_dir: Option<&str>) -> String {
+    // HTML 출력 디렉토리가 있으면 상대 경로 계산 / Calculate relative path if HTML output directory is provided
+    if let Some(html_dir) = html_output_dir {
+        let image_path = Path::new(&file_path);
+        let html_path = Path::new(html_dir);
+
+        // 상대 경로 계산 / Calculate relative path
+        match pathdiff::diff_paths(image_path, html_path) {
+            Some(relative_path) => {
+                // 경로 구분자를 슬래시로 통일 / Normalize path separators to forward slashes
+                relative_path.to_string_lossy().replace('\\', "/")
+            }
+            None => {
+                // 상대 경로 계산 실패 시 파일명만 반환 / Return filename only if relative path calculation fails
+                image_path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| file_path)
+            }
+        }
+    } else {
+        // HTML 출력 디렉토리가 없으면 파일명만 반환 / Return filename only if HTML output directory is not provided
+        let file_path_obj = Path::new(&file_path);
+        file_path_obj
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or(&file_path)
+            .to_string()
+    }
+}
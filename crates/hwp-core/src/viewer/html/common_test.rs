@@ -172,6 +172,7 @@ mod tests {
             0x0E00u16,
             Some("test_output_dir"),
             Some("test_output_dir"),
+            false,
         );
 
         // Should return a file path
@@ -213,7 +214,7 @@ mod tests {
         });
         doc.bin_data = bin_data;
 
-        let result = common::get_image_url(&doc, 0x0F00u16, None, None);
+        let result = common::get_image_url(&doc, 0x0F00u16, None, None, false);
 
         // Should return data URI
         assert!(result.starts_with("data:"));
@@ -245,7 +246,7 @@ mod tests {
         }];
 
         // Request non-existent bindata_id
-        let result = common::get_image_url(&doc, 0x0A00u16, Some("test_dir"), None);
+        let result = common::get_image_url(&doc, 0x0A00u16, Some("test_dir"), None, false);
 
         // Should return empty string when no data found
         assert_eq!(result, "");
@@ -282,7 +283,7 @@ mod tests {
         });
         doc.bin_data = bin_data;
 
-        let result = common::get_image_url(&doc, 0x5678u16, Some("test_dir"), None);
+        let result = common::get_image_url(&doc, 0x5678u16, Some("test_dir"), None, false);
 
         // Should return empty string when base64 is empty
         assert_eq!(result, "");
@@ -1562,6 +1562,7 @@ fn collect_images_from_records(
                     bindata_id,
                     options.image_output_dir.as_deref(),
                     options.html_output_dir.as_deref(),
+                    options.keep_metafile,
                 );
                 if !image_url.is_empty() {
                     let br_width = (shape_component_picture.border_rectangle_x.right
@@ -440,6 +440,7 @@ pub(crate) fn render_cells(
                             bindata_id,
                             options.image_output_dir.as_deref(),
                             options.html_output_dir.as_deref(),
+                            options.keep_metafile,
                         );
                         if !image_url.is_empty() {
                             // ShapeComponentPicture가 직접 올 때는 border_rectangle 사용 (부모 ShapeComponent가 없음)
@@ -494,6 +495,7 @@ pub(crate) fn render_cells(
                                 bindata_id,
                                 options.image_output_dir.as_deref(),
                                 options.html_output_dir.as_deref(),
+                                options.keep_metafile,
                             );
                             if !image_url.is_empty() {
                                 // border_rectangle가 유효하면 사용, 아니면 shape_component 사용
@@ -43,3 +43,13 @@ pub fn render_image_with_style(
         top_mm, left_mm, margin_bottom_mm, margin_right_mm, width_mm, height_mm, image_url
     )
 }
+
+/// WMF/EMF 이미지를 SVG로 변환 / Convert WMF/EMF image to SVG
+/// 브라우저는 메타파일을 표시하지 못하므로 HTML에는 변환한 SVG를 넣습니다.
+/// 확장자가 wmf/emf가 아니거나 해석할 수 없으면 None
+pub fn metafile_to_svg_image(extension: &str, data: &[u8]) -> Option<String> {
+    if !matches!(extension.to_ascii_lowercase().as_str(), "wmf" | "emf") {
+        return None;
+    }
+    crate::viewer::metafile::metafile_to_svg(data)
+}
@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use crate::viewer::html::image::{render_image, render_image_with_style};
+    use crate::viewer::html::image::{
+        metafile_to_svg_image, render_image, render_image_with_style,
+    };
 
     #[test]
     fn test_render_image_basic() {
@@ -125,4 +127,25 @@ mod tests {
         assert!(result.contains("background-repeat:no-repeat"));
         assert!(!result.contains("background-size:contain"));
     }
+
+    #[test]
+    fn test_metafile_to_svg_image() {
+        // placeable WMF: 경계 (0,0)-(100,100), 사각형 하나
+        let mut wmf = vec![0xD7, 0xCD, 0xC6, 0x9A, 0, 0];
+        for v in [0i16, 0, 100, 100, 1440, 0, 0, 0] {
+            wmf.extend_from_slice(&v.to_le_bytes());
+        }
+        for v in [1u16, 9, 0x300, 0, 0, 0, 0, 0, 0] {
+            wmf.extend_from_slice(&v.to_le_bytes());
+        }
+        for v in [7u16, 0, 0x041B, 90, 90, 10, 10, 3, 0, 0] {
+            wmf.extend_from_slice(&v.to_le_bytes());
+        }
+
+        let svg = metafile_to_svg_image("WMF", &wmf).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains(r#"d="M10 10L90 10L90 90L10 90Z""#));
+        assert!(metafile_to_svg_image("png", &wmf).is_none());
+        assert!(metafile_to_svg_image("emf", b"broken").is_none());
+    }
 }
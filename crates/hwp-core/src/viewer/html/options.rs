@@ -23,6 +23,14 @@ pub struct HtmlOptions {
     /// false이면 시맨틱 HTML (기본값)
     /// Layout mode: true for pixel-accurate layout HTML, false for semantic HTML (default)
     pub layout: bool,
+
+    /// WMF/EMF 그림을 원본 그대로 유지할지 여부 (기본값: false - SVG로 변환)
+    /// Keep WMF/EMF images as-is instead of converting them to SVG (default: false)
+    pub keep_metafile: bool,
+
+    /// 변경 추적 보기 모드 (기본값: 변경 표시)
+    /// Track changes view: final, original, or markup (default)
+    pub revision_view: crate::viewer::RevisionView,
 }
 
 impl Default for HtmlOptions {
@@ -34,6 +42,8 @@ impl Default for HtmlOptions {
             include_page_info: Some(false),
             css_class_prefix: String::new(),
             layout: false,
+            keep_metafile: false,
+            revision_view: Default::default(),
         }
     }
 }
@@ -62,4 +72,16 @@ impl HtmlOptions {
         self.css_class_prefix = prefix.to_string();
         self
     }
+
+    /// WMF/EMF 원본 유지 설정 / Set whether to keep WMF/EMF images as-is
+    pub fn with_keep_metafile(mut self, keep: bool) -> Self {
+        self.keep_metafile = keep;
+        self
+    }
+
+    /// 변경 추적 보기 모드 설정 / Set track changes view
+    pub fn with_revision_view(mut self, view: crate::viewer::RevisionView) -> Self {
+        self.revision_view = view;
+        self
+    }
 }
@@ -22,6 +22,8 @@ mod tests {
             include_page_info: Some(false),
             css_class_prefix: String::new(),
             layout: false,
+            keep_metafile: false,
+            revision_view: Default::default(),
         };
 
         assert_eq!(options.image_output_dir, Some("/images".to_string()));
@@ -36,6 +38,8 @@ mod tests {
             include_page_info: Some(false),
             css_class_prefix: String::new(),
             layout: false,
+            keep_metafile: false,
+            revision_view: Default::default(),
         };
 
         assert_eq!(options.html_output_dir, Some("/html".to_string()));
@@ -50,6 +54,8 @@ mod tests {
             include_page_info: Some(false),
             css_class_prefix: String::new(),
             layout: false,
+            keep_metafile: false,
+            revision_view: Default::default(),
         };
 
         assert_eq!(options.image_output_dir, Some("/images".to_string()));
@@ -67,6 +73,8 @@ mod tests {
             include_page_info: Some(true),
             css_class_prefix: "prefix".to_string(),
             layout: false,
+            keep_metafile: false,
+            revision_view: Default::default(),
         };
 
         assert_eq!(options.image_output_dir, Some("/img".to_string()));
@@ -85,6 +93,8 @@ mod tests {
             include_page_info: Some(false),
             css_class_prefix: "".to_string(),
             layout: false,
+            keep_metafile: false,
+            revision_view: Default::default(),
         };
 
         assert_eq!(options.css_class_prefix, "");
@@ -99,6 +109,8 @@ mod tests {
             include_page_info: Some(false),
             css_class_prefix: "my-app".to_string(),
             layout: false,
+            keep_metafile: false,
+            revision_view: Default::default(),
         };
 
         assert_eq!(options.css_class_prefix, "my-app");
@@ -113,6 +125,8 @@ mod tests {
             include_page_info: Some(false),
             css_class_prefix: "hwp-viewer-2.0".to_string(),
             layout: false,
+            keep_metafile: false,
+            revision_view: Default::default(),
         };
 
         assert_eq!(options.css_class_prefix, "hwp-viewer-2.0");
@@ -127,6 +141,8 @@ mod tests {
             include_page_info: Some(true),
             css_class_prefix: "site".to_string(),
             layout: false,
+            keep_metafile: false,
+            revision_view: Default::default(),
         };
 
         assert_eq!(
@@ -150,6 +166,8 @@ mod tests {
             include_page_info: None,
             css_class_prefix: "prefix".to_string(),
             layout: false,
+            keep_metafile: false,
+            revision_view: Default::default(),
         };
 
         assert!(options.image_output_dir.is_none());
@@ -341,6 +341,7 @@ fn collect_images(
                             bindata_id,
                             options.image_output_dir.as_deref(),
                             options.html_output_dir.as_deref(),
+                            options.keep_metafile,
                         );
                         if !image_url.is_empty() {
                             let br_width = (shape_component_picture.border_rectangle_x.right
@@ -375,6 +376,7 @@ fn collect_images(
                     bindata_id,
                     options.image_output_dir.as_deref(),
                     options.html_output_dir.as_deref(),
+                    options.keep_metafile,
                 );
                 if !image_url.is_empty() {
                     let width = (shape_component_picture.border_rectangle_x.right
@@ -498,6 +500,7 @@ pub fn render_paragraph(
                             bindata_id,
                             options.image_output_dir.as_deref(),
                             options.html_output_dir.as_deref(),
+                            options.keep_metafile,
                         );
                         if !image_url.is_empty() {
                             let br_width = (shape_component_picture.border_rectangle_x.right
@@ -532,6 +535,7 @@ pub fn render_paragraph(
                     bindata_id,
                     options.image_output_dir.as_deref(),
                     options.html_output_dir.as_deref(),
+                    options.keep_metafile,
                 );
                 if !image_url.is_empty() {
                     // ShapeComponentPicture가 직접 올 때는 border_rectangle 사용 (부모 ShapeComponent가 없음)
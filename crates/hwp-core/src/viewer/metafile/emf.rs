@@ -0,0 +1,486 @@
+/// EMF(Enhanced Metafile) 레코드 해석
+/// 헤더의 rclFrame(0.01mm)을 장치 픽셀로 환산한 영역을 출력 영역으로 삼습니다.
+use super::player::{Brush, Font, GdiObject, Pen, Player};
+use super::{bitmap_to_bmp, decode_ansi, f32_at, i16_at, i32_at, u32_at};
+
+/// 래스터 연산: 브러시로 채우기 / 검정 / 흰색
+const PATCOPY: u32 = 0x00F0_0021;
+const BLACKNESS: u32 = 0x0000_0042;
+const WHITENESS: u32 = 0x00FF_0062;
+
+/// 스톡 개체 번호 표시 비트
+const STOCK_OBJECT: u32 = 0x8000_0000;
+
+/// EMF 바이트 → SVG 문서
+/// Convert an EMF to SVG
+pub fn emf_to_svg(data: &[u8]) -> Option<String> {
+    if !super::is_emf(data) {
+        return None;
+    }
+    let rect = |offset: usize| [0, 4, 8, 12].map(|i| f64::from(i32_at(data, offset + i)));
+    let bounds = rect(8);
+    let frame = rect(24);
+    let device = (f64::from(i32_at(data, 72)), f64::from(i32_at(data, 76)));
+    let millimeters = (f64::from(i32_at(data, 80)), f64::from(i32_at(data, 84)));
+    let units_per_mm =
+        if device.0 > 0.0 && device.1 > 0.0 && millimeters.0 > 0.0 && millimeters.1 > 0.0 {
+            (device.0 / millimeters.0, device.1 / millimeters.1)
+        } else {
+            (96.0 / 25.4, 96.0 / 25.4)
+        };
+
+    // 출력 영역: rclFrame → 장치 픽셀, 비어 있으면 rclBounds
+    let (view, size_mm) = if frame[2] > frame[0] && frame[3] > frame[1] {
+        let view = [
+            frame[0] / 100.0 * units_per_mm.0,
+            frame[1] / 100.0 * units_per_mm.1,
+            (frame[2] - frame[0]) / 100.0 * units_per_mm.0,
+            (frame[3] - frame[1]) / 100.0 * units_per_mm.1,
+        ];
+        (
+            view,
+            ((frame[2] - frame[0]) / 100.0, (frame[3] - frame[1]) / 100.0),
+        )
+    } else if bounds[2] >= bounds[0] && bounds[3] >= bounds[1] {
+        let (w, h) = (bounds[2] - bounds[0] + 1.0, bounds[3] - bounds[1] + 1.0);
+        (
+            [bounds[0], bounds[1], w, h],
+            (w / units_per_mm.0, h / units_per_mm.1),
+        )
+    } else {
+        return None;
+    };
+
+    let mut player = Player::new(view, units_per_mm);
+    let mut pos = 0;
+    while pos + 8 <= data.len() {
+        let kind = u32_at(data, pos);
+        let size = u32_at(data, pos + 4) as usize;
+        if size < 8 || pos + size > data.len() || kind == 14 {
+            break;
+        }
+        play(&mut player, kind, &data[pos..pos + size]);
+        pos += size;
+    }
+    Some(player.finish(size_mm.0, size_mm.1))
+}
+
+/// 레코드 하나 재생 (`rec`: 8바이트 레코드 헤더 포함)
+fn play(p: &mut Player, kind: u32, rec: &[u8]) {
+    // 매개변수 오프셋은 레코드 헤더 뒤부터
+    let i = |offset: usize| f64::from(i32_at(rec, 8 + offset));
+    let u = |offset: usize| u32_at(rec, 8 + offset);
+    let rect = || (i(0), i(4), i(8), i(12));
+    match kind {
+        // POLYBEZIER / POLYGON / POLYLINE / POLYBEZIERTO / POLYLINETO (32비트, 16비트)
+        2..=6 | 85..=89 => {
+            let wide = kind <= 6;
+            let points = points(rec, 28, u(16) as usize, wide);
+            match if wide { kind } else { kind - 83 } {
+                2 => p.poly_bezier(&points),
+                3 => p.polygon(&points),
+                4 => p.polyline(&points),
+                5 => p.poly_bezier_to(&points),
+                _ => p.poly_line_to(&points),
+            }
+        }
+        // POLYPOLYLINE / POLYPOLYGON (32비트, 16비트)
+        7 | 8 | 90 | 91 => {
+            let wide = kind <= 8;
+            let count = u(16) as usize;
+            let mut offset = 32 + count * 4;
+            let mut shapes = Vec::new();
+            for k in 0..count.min(rec.len() / 4) {
+                let n = u(24 + k * 4) as usize;
+                shapes.push(points(rec, offset, n, wide));
+                offset += n * if wide { 8 } else { 4 };
+            }
+            if matches!(kind, 8 | 91) {
+                p.poly_polygon(&shapes);
+            } else {
+                p.poly_polyline(&shapes);
+            }
+        }
+        // SETWINDOWEXTEX / SETWINDOWORGEX / SETVIEWPORTEXTEX / SETVIEWPORTORGEX
+        9 => p.dc.window_ext = (i(0), i(4)),
+        10 => p.dc.window_org = (i(0), i(4)),
+        11 => p.dc.viewport_ext = (i(0), i(4)),
+        12 => p.dc.viewport_org = (i(0), i(4)),
+        // SETPIXELV
+        15 => p.fill_rect(i(0), i(4), i(0) + 1.0, i(4) + 1.0, u(8)),
+        // SETMAPMODE / SETBKMODE / SETPOLYFILLMODE / SETTEXTALIGN / SETTEXTCOLOR / SETBKCOLOR
+        17 => p.dc.map_mode = u(0),
+        18 => p.dc.bk_mode = u(0),
+        19 => p.dc.poly_fill_mode = u(0),
+        22 => p.dc.text_align = u(0),
+        24 => p.dc.text_color = u(0),
+        25 => p.dc.bk_color = u(0),
+        // MOVETOEX / LINETO
+        27 => p.move_to(i(0), i(4)),
+        54 => p.line_to(i(0), i(4)),
+        // INTERSECTCLIPRECT
+        30 => p.intersect_clip(i(0), i(4), i(8), i(12)),
+        // SCALEVIEWPORTEXTEX / SCALEWINDOWEXTEX
+        31 | 32 if i(4) != 0.0 && i(12) != 0.0 => {
+            let ext = if kind == 31 {
+                &mut p.dc.viewport_ext
+            } else {
+                &mut p.dc.window_ext
+            };
+            ext.0 = ext.0 * i(0) / i(4);
+            ext.1 = ext.1 * i(8) / i(12);
+        }
+        // SAVEDC / RESTOREDC
+        33 => p.save_dc(),
+        34 => p.restore_dc(i32_at(rec, 8)),
+        // SETWORLDTRANSFORM / MODIFYWORLDTRANSFORM
+        35 => p.dc.world = xform(rec, 8),
+        36 => {
+            let xf = xform(rec, 8);
+            p.dc.world = match u(24) {
+                1 => [1.0, 0.0, 0.0, 1.0, 0.0, 0.0],
+                2 => multiply(xf, p.dc.world),
+                3 => multiply(p.dc.world, xf),
+                4 => xf,
+                _ => p.dc.world,
+            };
+        }
+        // SELECTOBJECT / DELETEOBJECT
+        37 => {
+            let index = u(0);
+            if index & STOCK_OBJECT != 0 {
+                if let Some(object) = stock_object(index & !STOCK_OBJECT) {
+                    p.select(&object);
+                }
+            } else {
+                p.select_index(index as usize);
+            }
+        }
+        40 => p.delete_object(u(0) as usize),
+        // CREATEPEN
+        38 => p.set_object(
+            u(0) as usize,
+            GdiObject::Pen(Pen {
+                style: u(4),
+                width: i(8),
+                color: u(16),
+            }),
+        ),
+        // EXTCREATEPEN (코스메틱 펜은 가는 선)
+        95 => {
+            let pen = if u(28) == 1 {
+                // BS_NULL 브러시로 만든 펜
+                Pen::null()
+            } else {
+                let style = u(20);
+                let geometric = style & 0x0001_0000 != 0;
+                Pen {
+                    style,
+                    width: if geometric { i(24) } else { 0.0 },
+                    color: u(32),
+                }
+            };
+            p.set_object(u(0) as usize, GdiObject::Pen(pen));
+        }
+        // CREATEBRUSHINDIRECT
+        39 => p.set_object(
+            u(0) as usize,
+            GdiObject::Brush(Brush {
+                style: u(4),
+                color: u(8),
+                hatch: u(12),
+            }),
+        ),
+        // CREATEMONOBRUSH / CREATEDIBPATTERNBRUSHPT
+        93 | 94 => p.set_object(
+            u(0) as usize,
+            GdiObject::Brush(Brush {
+                style: 3,
+                color: 0,
+                hatch: 0,
+            }),
+        ),
+        // CREATEPALETTE
+        49 => p.set_object(u(0) as usize, GdiObject::Other),
+        // EXTCREATEFONTINDIRECTW
+        82 => p.set_object(u(0) as usize, GdiObject::Font(font(rec))),
+        // ELLIPSE / RECTANGLE / ROUNDRECT
+        42 => p.ellipse(i(0), i(4), i(8), i(12)),
+        43 => p.rectangle(i(0), i(4), i(8), i(12)),
+        44 => p.round_rect(i(0), i(4), i(8), i(12), i(16), i(20)),
+        // ARC / CHORD / PIE
+        45..=47 => p.arc(rect(), (i(16), i(20)), (i(24), i(28)), (kind - 45) as u8),
+        // 경로
+        59 => p.begin_path(),
+        60 => p.end_path(),
+        61 => p.close_figure(),
+        62 => p.draw_path(true, false),
+        63 => p.draw_path(true, true),
+        64 => p.draw_path(false, true),
+        67 | 68 => p.abort_path(),
+        // EXTSELECTCLIPRGN: 빈 영역 + RGN_COPY = 클립 해제
+        75 if u(0) == 0 && u(4) == 5 => p.reset_clip(),
+        // BITBLT / STRETCHBLT
+        76 | 77 => {
+            let (x, y, w, h) = (i(16), i(20), i(24), i(28));
+            let (off_bmi, cb_bmi, off_bits, cb_bits) = (u(76), u(80), u(84), u(88));
+            if cb_bmi == 0 {
+                blt_fill(p, u(32), x, y, w, h);
+            } else {
+                image(p, (x, y, w, h), rec, (off_bmi, cb_bmi, off_bits, cb_bits));
+            }
+        }
+        // SETDIBITSTODEVICE (원본 크기 그대로)
+        80 => image(
+            p,
+            (i(16), i(20), i(32), i(36)),
+            rec,
+            (u(40), u(44), u(48), u(52)),
+        ),
+        // STRETCHDIBITS
+        81 => image(
+            p,
+            (i(16), i(20), i(64), i(68)),
+            rec,
+            (u(40), u(44), u(48), u(52)),
+        ),
+        // EXTTEXTOUTA / EXTTEXTOUTW
+        83 | 84 => ext_text_out(p, rec, kind == 84),
+        _ => {}
+    }
+}
+
+fn ext_text_out(p: &mut Player, rec: &[u8], wide: bool) {
+    let (x, y) = (f64::from(i32_at(rec, 36)), f64::from(i32_at(rec, 40)));
+    let count = u32_at(rec, 44) as usize;
+    let off_string = u32_at(rec, 48) as usize;
+    let options = u32_at(rec, 52);
+    let off_dx = u32_at(rec, 72) as usize;
+    let text = if wide {
+        let Some(bytes) = rec.get(off_string..off_string + count * 2) else {
+            return;
+        };
+        let units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect();
+        String::from_utf16_lossy(&units)
+    } else {
+        let Some(bytes) = rec.get(off_string..off_string + count) else {
+            return;
+        };
+        decode_ansi(bytes, p.dc.font.charset)
+    };
+    // ETO_PDY(0x2000)이면 (dx, dy) 쌍
+    let stride = if options & 0x2000 != 0 { 8 } else { 4 };
+    let dx = (off_dx > 0 && off_dx + count * stride <= rec.len()).then(|| {
+        (0..count)
+            .map(|n| f64::from(i32_at(rec, off_dx + n * stride)))
+            .collect::<Vec<_>>()
+    });
+    // 서로게이트 쌍은 전진 폭이 두 칸이므로 글자 수와 다르면 dx 생략
+    let dx = dx.filter(|dx| dx.len() == text.chars().count());
+    let opaque = (options & 0x0002 != 0).then(|| {
+        let r = |o: usize| f64::from(i32_at(rec, 56 + o));
+        (r(0), r(4), r(8), r(12))
+    });
+    p.text(x, y, &text, dx.as_deref(), opaque);
+}
+
+/// LOGFONTW (레코드 오프셋 12부터)
+fn font(rec: &[u8]) -> Font {
+    let byte = |i: usize| rec.get(i).copied().unwrap_or(0);
+    let face: Vec<u16> = (0..32)
+        .map(|n| super::u16_at(rec, 40 + n * 2))
+        .take_while(|c| *c != 0)
+        .collect();
+    Font {
+        height: f64::from(i32_at(rec, 12)),
+        escapement: f64::from(i32_at(rec, 20)),
+        weight: u32_at(rec, 28),
+        italic: byte(32) != 0,
+        underline: byte(33) != 0,
+        strike_out: byte(34) != 0,
+        charset: byte(35),
+        face: String::from_utf16_lossy(&face),
+    }
+}
+
+fn stock_object(index: u32) -> Option<GdiObject> {
+    Some(match index {
+        0 => GdiObject::Brush(Brush::solid(0xFFFFFF)),
+        1 => GdiObject::Brush(Brush::solid(0xC0C0C0)),
+        2 => GdiObject::Brush(Brush::solid(0x808080)),
+        3 => GdiObject::Brush(Brush::solid(0x404040)),
+        4 => GdiObject::Brush(Brush::solid(0)),
+        5 => GdiObject::Brush(Brush::null()),
+        6 => GdiObject::Pen(Pen::solid(0xFFFFFF)),
+        7 => GdiObject::Pen(Pen::solid(0)),
+        8 => GdiObject::Pen(Pen::null()),
+        10..=17 => GdiObject::Font(Font::default()),
+        _ => return None,
+    })
+}
+
+/// XFORM (f32 6개)
+fn xform(rec: &[u8], offset: usize) -> [f64; 6] {
+    [0, 4, 8, 12, 16, 20].map(|i| f64::from(f32_at(rec, offset + i)))
+}
+
+/// 행 벡터 기준 a × b
+fn multiply(a: [f64; 6], b: [f64; 6]) -> [f64; 6] {
+    [
+        a[0] * b[0] + a[1] * b[2],
+        a[0] * b[1] + a[1] * b[3],
+        a[2] * b[0] + a[3] * b[2],
+        a[2] * b[1] + a[3] * b[3],
+        a[4] * b[0] + a[5] * b[2] + b[4],
+        a[4] * b[1] + a[5] * b[3] + b[5],
+    ]
+}
+
+/// 점 n개 (32비트 또는 16비트, 레코드 범위 안에서만)
+fn points(rec: &[u8], offset: usize, count: usize, wide: bool) -> Vec<(f64, f64)> {
+    let stride = if wide { 8 } else { 4 };
+    let available = rec.len().saturating_sub(offset) / stride;
+    (0..count.min(available))
+        .map(|n| {
+            let at = offset + n * stride;
+            if wide {
+                (f64::from(i32_at(rec, at)), f64::from(i32_at(rec, at + 4)))
+            } else {
+                (f64::from(i16_at(rec, at)), f64::from(i16_at(rec, at + 2)))
+            }
+        })
+        .collect()
+}
+
+/// 비트맵 없는 BitBlt: 래스터 연산에 따라 사각형 채우기
+fn blt_fill(p: &mut Player, rop: u32, x: f64, y: f64, w: f64, h: f64) {
+    match rop {
+        PATCOPY => p.pattern_rect(x, y, x + w, y + h),
+        BLACKNESS => p.fill_rect(x, y, x + w, y + h, 0),
+        WHITENESS => p.fill_rect(x, y, x + w, y + h, 0xFFFFFF),
+        _ => {}
+    }
+}
+
+/// 레코드 안의 BITMAPINFO + 픽셀 (오프셋은 레코드 시작 기준)
+fn image(
+    p: &mut Player,
+    (x, y, w, h): (f64, f64, f64, f64),
+    rec: &[u8],
+    (off_bmi, cb_bmi, off_bits, cb_bits): (u32, u32, u32, u32),
+) {
+    let slice = |offset: u32, len: u32| {
+        rec.get(offset as usize..(offset as usize).checked_add(len as usize)?)
+    };
+    let (Some(info), Some(bits)) = (slice(off_bmi, cb_bmi), slice(off_bits, cb_bits)) else {
+        return;
+    };
+    if let Some(bmp) = bitmap_to_bmp(info, bits) {
+        p.image(x, y, w, h, &bmp);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(kind: u32, params: &[i32]) -> Vec<u8> {
+        let mut rec = kind.to_le_bytes().to_vec();
+        rec.extend_from_slice(&(8 + params.len() as u32 * 4).to_le_bytes());
+        for v in params {
+            rec.extend_from_slice(&v.to_le_bytes());
+        }
+        rec
+    }
+
+    /// 96dpi 장치, 프레임 (0,0)-(10mm, 5mm)
+    fn emf(records: &[Vec<u8>]) -> Vec<u8> {
+        let mut header = vec![0, 0, 37, 18, 0, 0, 1000, 500, 0x464D_4520, 0x10000];
+        header.extend([0, 0, 0, 0, 0, 0, 1024, 768, 270, 203]);
+        let mut data = record(1, &header);
+        for rec in records {
+            data.extend_from_slice(rec);
+        }
+        data.extend(record(14, &[0, 16, 20]));
+        data
+    }
+
+    #[test]
+    fn test_emf_header_and_shapes() {
+        let data = emf(&[
+            record(39, &[1, 0, 0x00FF00, 0]),
+            record(37, &[1]),
+            // NULL_PEN 스톡 개체
+            record(37, &[0x8000_0008u32 as i32]),
+            record(43, &[0, 0, 10, 10]),
+            record(27, &[0, 0]),
+            record(54, &[20, 0]),
+            record(37, &[0x8000_0007u32 as i32]),
+            record(54, &[20, 10]),
+        ]);
+        assert!(super::super::is_emf(&data));
+        let svg = emf_to_svg(&data).unwrap();
+        // 1024px / 270mm 배율로 프레임 환산
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="10mm" height="5mm" viewBox="0 0 37.93 18.92""#), "{svg}");
+        assert!(svg.contains(r##"<path d="M0 0L10 0L10 10L0 10Z" fill="#00FF00" fill-rule="evenodd" stroke="none"/>"##));
+        // 펜이 없으면 선은 그리지 않고, 검은 펜으로 바꾼 뒤의 선만 남음
+        assert!(!svg.contains("M0 0L20 0"));
+        assert!(svg.contains(r##"d="M20 0L20 10" fill="none" stroke="#000000""##));
+    }
+
+    #[test]
+    fn test_emf_world_transform_and_path() {
+        let mut xf = record(35, &[]);
+        for v in [2.0f32, 0.0, 0.0, 2.0, 5.0, 5.0] {
+            xf.extend_from_slice(&v.to_le_bytes());
+        }
+        xf[4] = 32;
+        let data = emf(&[
+            xf,
+            record(59, &[]),
+            record(27, &[0, 0]),
+            record(54, &[10, 0]),
+            record(54, &[10, 10]),
+            record(61, &[]),
+            record(60, &[]),
+            record(62, &[0, 0, 0, 0]),
+        ]);
+        let svg = emf_to_svg(&data).unwrap();
+        assert!(
+            svg.contains(r##"<path d="M5 5L25 5L25 25Z" fill="#FFFFFF""##),
+            "{svg}"
+        );
+    }
+
+    #[test]
+    fn test_emf_text() {
+        let mut font = record(82, &[1, -20, 0, 0, 0, 400, 0, 0]);
+        let mut face = [0u16; 32];
+        for (slot, c) in face.iter_mut().zip("바탕".encode_utf16()) {
+            *slot = c;
+        }
+        for c in face {
+            font.extend_from_slice(&c.to_le_bytes());
+        }
+        let len = font.len() as u32;
+        font[4..8].copy_from_slice(&len.to_le_bytes());
+
+        // EMRTEXT: 기준점 (30, 40), 글자 2개, 문자열 오프셋 76, dx 오프셋 80
+        let mut text = record(84, &[0, 0, 0, 0, 1, 0, 0, 30, 40, 2, 76, 0, 0, 0, 0, 0, 80]);
+        for c in "AB".encode_utf16() {
+            text.extend_from_slice(&c.to_le_bytes());
+        }
+        for dx in [12i32, 8] {
+            text.extend_from_slice(&dx.to_le_bytes());
+        }
+        let len = text.len() as u32;
+        text[4..8].copy_from_slice(&len.to_le_bytes());
+
+        let data = emf(&[font, record(37, &[1]), record(22, &[6]), text]);
+        let svg = emf_to_svg(&data).unwrap();
+        assert!(svg.contains(r##"<text x="20 32" y="57.2" font-size="20" font-family="'바탕', sans-serif" fill="#000000" xml:space="preserve">AB</text>"##), "{svg}");
+    }
+}
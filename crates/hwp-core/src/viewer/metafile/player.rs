@@ -0,0 +1,970 @@
+/// 메타파일 재생기: GDI 장치 컨텍스트 상태 + SVG 출력
+/// WMF/EMF 레코드 해석기가 공통으로 사용합니다.
+use base64::Engine;
+use std::f64::consts::PI;
+use std::fmt::Write;
+
+use crate::viewer::doc_utils::html_escape;
+
+pub(super) const MM_TEXT: u32 = 1;
+pub(super) const MM_ISOTROPIC: u32 = 7;
+pub(super) const MM_ANISOTROPIC: u32 = 8;
+
+const TA_UPDATECP: u32 = 0x0001;
+const TA_RIGHT: u32 = 0x0002;
+const TA_CENTER: u32 = 0x0006;
+const TA_BOTTOM: u32 = 0x0008;
+const TA_BASELINE: u32 = 0x0018;
+
+const OPAQUE: u32 = 2;
+const ALTERNATE: u32 = 1;
+
+#[derive(Debug, Clone)]
+pub(super) struct Pen {
+    /// PS_* 스타일 (하위 4비트: 선 종류, 0x0F00: 끝 모양, 0xF000: 꺾임)
+    pub style: u32,
+    /// 논리 단위 굵기 (0이면 가는 선)
+    pub width: f64,
+    pub color: u32,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Brush {
+    /// BS_SOLID(0), BS_NULL(1), BS_HATCHED(2), 패턴(3, 5, 6)
+    pub style: u32,
+    pub color: u32,
+    pub hatch: u32,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Font {
+    /// 논리 단위 높이 (음수: 글자 높이, 양수: 셀 높이)
+    pub height: f64,
+    /// 기울기 (0.1도, 반시계 방향)
+    pub escapement: f64,
+    pub weight: u32,
+    pub italic: bool,
+    pub underline: bool,
+    pub strike_out: bool,
+    pub charset: u8,
+    pub face: String,
+}
+
+#[derive(Debug, Clone)]
+pub(super) enum GdiObject {
+    Pen(Pen),
+    Brush(Brush),
+    Font(Font),
+    /// 팔레트/영역 등 그리기에 쓰지 않는 개체 (번호만 차지)
+    Other,
+}
+
+impl Pen {
+    pub fn solid(color: u32) -> Self {
+        Pen {
+            style: 0,
+            width: 0.0,
+            color,
+        }
+    }
+
+    pub fn null() -> Self {
+        Pen {
+            style: 5,
+            width: 0.0,
+            color: 0,
+        }
+    }
+}
+
+impl Brush {
+    pub fn solid(color: u32) -> Self {
+        Brush {
+            style: 0,
+            color,
+            hatch: 0,
+        }
+    }
+
+    pub fn null() -> Self {
+        Brush {
+            style: 1,
+            color: 0,
+            hatch: 0,
+        }
+    }
+}
+
+impl Default for Font {
+    fn default() -> Self {
+        Font {
+            height: 0.0,
+            escapement: 0.0,
+            weight: 400,
+            italic: false,
+            underline: false,
+            strike_out: false,
+            charset: 1,
+            face: String::new(),
+        }
+    }
+}
+
+/// 장치 컨텍스트 (SaveDC/RestoreDC 단위)
+#[derive(Debug, Clone)]
+pub(super) struct Dc {
+    pub map_mode: u32,
+    pub window_org: (f64, f64),
+    pub window_ext: (f64, f64),
+    pub viewport_org: (f64, f64),
+    pub viewport_ext: (f64, f64),
+    /// EMF 월드 변환 (eM11, eM12, eM21, eM22, eDx, eDy)
+    pub world: [f64; 6],
+    pub pen: Pen,
+    pub brush: Brush,
+    pub font: Font,
+    pub text_color: u32,
+    pub bk_color: u32,
+    pub bk_mode: u32,
+    pub text_align: u32,
+    pub poly_fill_mode: u32,
+    /// 현재 위치 (논리 좌표)
+    pub position: (f64, f64),
+    /// 클립 사각형 (장치 좌표 x, y, w, h)과 clipPath id
+    clip: Option<(usize, [f64; 4])>,
+}
+
+impl Default for Dc {
+    fn default() -> Self {
+        Dc {
+            map_mode: MM_TEXT,
+            window_org: (0.0, 0.0),
+            window_ext: (1.0, 1.0),
+            viewport_org: (0.0, 0.0),
+            viewport_ext: (1.0, 1.0),
+            world: [1.0, 0.0, 0.0, 1.0, 0.0, 0.0],
+            pen: Pen::solid(0),
+            brush: Brush::solid(0xFFFFFF),
+            font: Font::default(),
+            text_color: 0,
+            bk_color: 0xFFFFFF,
+            bk_mode: OPAQUE,
+            text_align: 0,
+            poly_fill_mode: ALTERNATE,
+            position: (0.0, 0.0),
+            clip: None,
+        }
+    }
+}
+
+/// 메타파일 하나의 재생 상태와 SVG 버퍼
+pub(super) struct Player {
+    pub dc: Dc,
+    saved: Vec<Dc>,
+    pub objects: Vec<Option<GdiObject>>,
+    /// 출력 영역 (장치 좌표 x, y, w, h)
+    frame: [f64; 4],
+    /// 장치 단위/mm (가는 선 굵기, 고정 매핑 모드 배율)
+    units_per_mm: (f64, f64),
+    defs: String,
+    body: String,
+    clip_count: usize,
+    hatch_count: usize,
+    /// BeginPath ~ EndPath 사이에 모으는 경로
+    path: Option<String>,
+    /// EndPath로 확정된 경로 (FillPath/StrokePath 대상)
+    closed_path: Option<String>,
+}
+
+impl Player {
+    pub fn new(frame: [f64; 4], units_per_mm: (f64, f64)) -> Self {
+        Player {
+            dc: Dc::default(),
+            saved: Vec::new(),
+            objects: Vec::new(),
+            frame,
+            units_per_mm,
+            defs: String::new(),
+            body: String::new(),
+            clip_count: 0,
+            hatch_count: 0,
+            path: None,
+            closed_path: None,
+        }
+    }
+
+    /// `<svg>` 문서로 마무리 (크기는 mm)
+    pub fn finish(self, width_mm: f64, height_mm: f64) -> String {
+        let [x, y, w, h] = self.frame;
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}mm" height="{}mm" viewBox="{} {} {} {}" preserveAspectRatio="none">"#,
+            num(width_mm),
+            num(height_mm),
+            num(x),
+            num(y),
+            num(w),
+            num(h)
+        );
+        if !self.defs.is_empty() {
+            let _ = write!(svg, "<defs>{}</defs>", self.defs);
+        }
+        svg.push_str(&self.body);
+        svg.push_str("</svg>");
+        svg
+    }
+
+    // ── 좌표 매핑 ──
+
+    /// 논리 단위 → 장치 단위 배율 (가로, 세로)
+    fn scale(&self) -> (f64, f64) {
+        let dc = &self.dc;
+        let ratio = |v: f64, w: f64| if w == 0.0 { 1.0 } else { v / w };
+        // 고정 매핑 모드: 논리 단위의 mm 크기 (y축은 위쪽이 +)
+        let fixed = |mm: f64| (mm * self.units_per_mm.0, -mm * self.units_per_mm.1);
+        match dc.map_mode {
+            MM_ANISOTROPIC => (
+                ratio(dc.viewport_ext.0, dc.window_ext.0),
+                ratio(dc.viewport_ext.1, dc.window_ext.1),
+            ),
+            MM_ISOTROPIC => {
+                let sx = ratio(dc.viewport_ext.0, dc.window_ext.0);
+                let sy = ratio(dc.viewport_ext.1, dc.window_ext.1);
+                let s = sx.abs().min(sy.abs());
+                (s.copysign(sx), s.copysign(sy))
+            }
+            2 => fixed(0.1),
+            3 => fixed(0.01),
+            4 => fixed(0.254),
+            5 => fixed(0.0254),
+            6 => fixed(25.4 / 1440.0),
+            _ => (1.0, 1.0),
+        }
+    }
+
+    /// 논리 좌표 → 장치 좌표 (월드 변환 → 창/뷰포트 매핑)
+    pub fn to_device(&self, x: f64, y: f64) -> (f64, f64) {
+        let [m11, m12, m21, m22, dx, dy] = self.dc.world;
+        let (wx, wy) = (m11 * x + m21 * y + dx, m12 * x + m22 * y + dy);
+        let (sx, sy) = self.scale();
+        (
+            (wx - self.dc.window_org.0) * sx + self.dc.viewport_org.0,
+            (wy - self.dc.window_org.1) * sy + self.dc.viewport_org.1,
+        )
+    }
+
+    /// 논리 길이 → 장치 길이 (면적 배율의 제곱근)
+    fn length(&self, value: f64) -> f64 {
+        let [m11, m12, m21, m22, ..] = self.dc.world;
+        let (sx, sy) = self.scale();
+        value * ((m11 * m22 - m12 * m21) * sx * sy).abs().sqrt()
+    }
+
+    /// 논리 세로 길이 → 장치 길이 (글자 크기용)
+    fn height(&self, value: f64) -> f64 {
+        let [_, _, m21, m22, ..] = self.dc.world;
+        let (sx, sy) = self.scale();
+        value * (m21 * sx).hypot(m22 * sy)
+    }
+
+    fn width(&self, value: f64) -> f64 {
+        let [m11, m12, ..] = self.dc.world;
+        let (sx, sy) = self.scale();
+        value * (m11 * sx).hypot(m12 * sy)
+    }
+
+    // ── DC 상태 ──
+
+    pub fn save_dc(&mut self) {
+        self.saved.push(self.dc.clone());
+    }
+
+    /// RestoreDC: 음수는 최근부터의 상대 위치, 양수는 절대 위치
+    pub fn restore_dc(&mut self, index: i32) {
+        let keep = if index < 0 {
+            self.saved.len().checked_sub(index.unsigned_abs() as usize)
+        } else {
+            (index as usize).checked_sub(1)
+        };
+        if let Some(keep) = keep.filter(|k| *k < self.saved.len()) {
+            self.saved.truncate(keep + 1);
+            if let Some(dc) = self.saved.pop() {
+                self.dc = dc;
+            }
+        }
+    }
+
+    pub fn select(&mut self, object: &GdiObject) {
+        match object {
+            GdiObject::Pen(pen) => self.dc.pen = pen.clone(),
+            GdiObject::Brush(brush) => self.dc.brush = brush.clone(),
+            GdiObject::Font(font) => self.dc.font = font.clone(),
+            GdiObject::Other => {}
+        }
+    }
+
+    pub fn select_index(&mut self, index: usize) {
+        if let Some(Some(object)) = self.objects.get(index).cloned() {
+            self.select(&object);
+        }
+    }
+
+    /// 지정한 번호에 개체 생성 (EMF)
+    pub fn set_object(&mut self, index: usize, object: GdiObject) {
+        if index >= 0x1_0000 {
+            return;
+        }
+        if self.objects.len() <= index {
+            self.objects.resize(index + 1, None);
+        }
+        self.objects[index] = Some(object);
+    }
+
+    /// 비어 있는 가장 작은 번호에 개체 생성 (WMF)
+    pub fn add_object(&mut self, object: GdiObject) {
+        match self.objects.iter().position(Option::is_none) {
+            Some(index) => self.objects[index] = Some(object),
+            None => self.objects.push(Some(object)),
+        }
+    }
+
+    pub fn delete_object(&mut self, index: usize) {
+        if let Some(slot) = self.objects.get_mut(index) {
+            *slot = None;
+        }
+    }
+
+    /// IntersectClipRect (논리 좌표)
+    pub fn intersect_clip(&mut self, left: f64, top: f64, right: f64, bottom: f64) {
+        let (x1, y1) = self.to_device(left, top);
+        let (x2, y2) = self.to_device(right, bottom);
+        let mut rect = [x1.min(x2), y1.min(y2), (x2 - x1).abs(), (y2 - y1).abs()];
+        if let Some((_, [cx, cy, cw, ch])) = self.dc.clip {
+            let (l, t) = (rect[0].max(cx), rect[1].max(cy));
+            let r = (rect[0] + rect[2]).min(cx + cw);
+            let b = (rect[1] + rect[3]).min(cy + ch);
+            rect = [l, t, (r - l).max(0.0), (b - t).max(0.0)];
+        }
+        self.clip_count += 1;
+        let _ = write!(
+            self.defs,
+            r#"<clipPath id="c{}"><rect x="{}" y="{}" width="{}" height="{}"/></clipPath>"#,
+            self.clip_count,
+            num(rect[0]),
+            num(rect[1]),
+            num(rect[2]),
+            num(rect[3])
+        );
+        self.dc.clip = Some((self.clip_count, rect));
+    }
+
+    pub fn reset_clip(&mut self) {
+        self.dc.clip = None;
+    }
+
+    fn clip_attr(&self) -> String {
+        match self.dc.clip {
+            Some((id, _)) => format!(r#" clip-path="url(#c{})""#, id),
+            None => String::new(),
+        }
+    }
+
+    // ── 경로 ──
+
+    pub fn begin_path(&mut self) {
+        self.path = Some(String::new());
+        self.closed_path = None;
+    }
+
+    pub fn end_path(&mut self) {
+        self.closed_path = self.path.take();
+    }
+
+    pub fn abort_path(&mut self) {
+        self.path = None;
+        self.closed_path = None;
+    }
+
+    pub fn close_figure(&mut self) {
+        if let Some(path) = &mut self.path {
+            path.push('Z');
+        }
+    }
+
+    /// FillPath/StrokePath/StrokeAndFillPath
+    pub fn draw_path(&mut self, fill: bool, stroke: bool) {
+        if let Some(d) = self.closed_path.take().filter(|d| !d.is_empty()) {
+            self.draw(&d, fill, stroke);
+        }
+    }
+
+    // ── 그리기 ──
+
+    /// 경로를 그림 (BeginPath 중이면 경로에 덧붙임)
+    pub fn draw(&mut self, d: &str, fill: bool, stroke: bool) {
+        if let Some(path) = &mut self.path {
+            path.push_str(d);
+            return;
+        }
+        let fill = if fill {
+            self.fill_attr()
+        } else {
+            r#"fill="none""#.to_string()
+        };
+        let stroke = if stroke {
+            self.stroke_attr()
+        } else {
+            r#"stroke="none""#.to_string()
+        };
+        if fill == r#"fill="none""# && stroke == r#"stroke="none""# {
+            return;
+        }
+        let clip = self.clip_attr();
+        let _ = write!(
+            self.body,
+            r#"<path d="{}" {} {}{}/>"#,
+            d, fill, stroke, clip
+        );
+    }
+
+    fn fill_attr(&mut self) -> String {
+        let brush = self.dc.brush.clone();
+        let rule = if self.dc.poly_fill_mode == ALTERNATE {
+            r#" fill-rule="evenodd""#
+        } else {
+            ""
+        };
+        match brush.style {
+            0 => format!(r#"fill="{}"{}"#, color(brush.color), rule),
+            1 => r#"fill="none""#.to_string(),
+            2 => {
+                let id = self.hatch(&brush);
+                format!(r#"fill="url(#h{})"{}"#, id, rule)
+            }
+            // 패턴 브러시: 무늬 대신 회색
+            _ => format!(r##"fill="#C0C0C0"{}"##, rule),
+        }
+    }
+
+    /// 빗금 브러시 패턴 (1mm 간격)
+    fn hatch(&mut self, brush: &Brush) -> usize {
+        self.hatch_count += 1;
+        let size = self.units_per_mm.0.max(f64::EPSILON);
+        let lines: &[(f64, f64, f64, f64)] = match brush.hatch {
+            0 => &[(0.0, 0.5, 1.0, 0.5)],
+            1 => &[(0.5, 0.0, 0.5, 1.0)],
+            2 => &[(0.0, 0.0, 1.0, 1.0)],
+            3 => &[(0.0, 1.0, 1.0, 0.0)],
+            4 => &[(0.0, 0.5, 1.0, 0.5), (0.5, 0.0, 0.5, 1.0)],
+            _ => &[(0.0, 0.0, 1.0, 1.0), (0.0, 1.0, 1.0, 0.0)],
+        };
+        let _ = write!(
+            self.defs,
+            r#"<pattern id="h{}" width="{}" height="{}" patternUnits="userSpaceOnUse">"#,
+            self.hatch_count,
+            num(size),
+            num(size)
+        );
+        if self.dc.bk_mode == OPAQUE {
+            let _ = write!(
+                self.defs,
+                r#"<rect width="{}" height="{}" fill="{}"/>"#,
+                num(size),
+                num(size),
+                color(self.dc.bk_color)
+            );
+        }
+        for (x1, y1, x2, y2) in lines {
+            let _ = write!(
+                self.defs,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="{}"/>"#,
+                num(x1 * size),
+                num(y1 * size),
+                num(x2 * size),
+                num(y2 * size),
+                color(brush.color),
+                num(size / 8.0)
+            );
+        }
+        self.defs.push_str("</pattern>");
+        self.hatch_count
+    }
+
+    fn stroke_attr(&self) -> String {
+        let pen = &self.dc.pen;
+        let kind = pen.style & 0x0F;
+        if kind == 5 {
+            return r#"stroke="none""#.to_string();
+        }
+        // 가는 선(굵기 0, 코스메틱 펜)은 0.1mm
+        let min = 0.1 * self.units_per_mm.0;
+        let width = if pen.width <= 0.0 {
+            min
+        } else {
+            self.length(pen.width).max(min)
+        };
+        let mut attrs = format!(
+            r#"stroke="{}" stroke-width="{}""#,
+            color(pen.color),
+            num(width)
+        );
+        let dash: &[f64] = match kind {
+            1 => &[3.0, 1.0],
+            2 => &[1.0, 1.0],
+            3 => &[3.0, 1.0, 1.0, 1.0],
+            4 => &[3.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+            _ => &[],
+        };
+        if !dash.is_empty() {
+            let unit = width.max(min * 2.0);
+            let dash: Vec<String> = dash.iter().map(|d| num(d * unit)).collect();
+            let _ = write!(attrs, r#" stroke-dasharray="{}""#, dash.join(" "));
+        }
+        attrs.push_str(match pen.style & 0x0F00 {
+            0x0100 => r#" stroke-linecap="square""#,
+            0x0200 => "",
+            _ => r#" stroke-linecap="round""#,
+        });
+        attrs.push_str(match pen.style & 0xF000 {
+            0x1000 => r#" stroke-linejoin="bevel""#,
+            0x2000 => "",
+            _ => r#" stroke-linejoin="round""#,
+        });
+        attrs
+    }
+
+    /// 논리 좌표 점들 → `M x y L ...` (close면 Z)
+    pub fn poly_d(&self, points: &[(f64, f64)], close: bool) -> String {
+        let mut d = String::new();
+        for (i, (x, y)) in points.iter().enumerate() {
+            let (x, y) = self.to_device(*x, *y);
+            let _ = write!(d, "{}{} {}", if i == 0 { 'M' } else { 'L' }, num(x), num(y));
+        }
+        if close && !d.is_empty() {
+            d.push('Z');
+        }
+        d
+    }
+
+    pub fn polygon(&mut self, points: &[(f64, f64)]) {
+        if points.len() >= 2 {
+            let d = self.poly_d(points, true);
+            self.draw(&d, true, true);
+        }
+    }
+
+    pub fn polyline(&mut self, points: &[(f64, f64)]) {
+        if points.len() >= 2 {
+            let d = self.poly_d(points, false);
+            self.draw(&d, false, true);
+        }
+    }
+
+    /// PolyPolygon (부분 다각형을 한 경로로)
+    pub fn poly_polygon(&mut self, polygons: &[Vec<(f64, f64)>]) {
+        let d: String = polygons
+            .iter()
+            .filter(|p| p.len() >= 2)
+            .map(|p| self.poly_d(p, true))
+            .collect();
+        if !d.is_empty() {
+            self.draw(&d, true, true);
+        }
+    }
+
+    pub fn poly_polyline(&mut self, lines: &[Vec<(f64, f64)>]) {
+        for line in lines {
+            self.polyline(line);
+        }
+    }
+
+    /// PolyBezier: 시작점 + (제어점, 제어점, 끝점) 반복
+    pub fn poly_bezier(&mut self, points: &[(f64, f64)]) {
+        if let Some((first, rest)) = points.split_first() {
+            let d = format!("{}{}", self.poly_d(&[*first], false), self.bezier_d(rest));
+            self.draw(&d, false, true);
+            self.dc.position = *points.last().unwrap_or(first);
+        }
+    }
+
+    /// PolyBezierTo: 현재 위치에서 시작
+    pub fn poly_bezier_to(&mut self, points: &[(f64, f64)]) {
+        let start = self.dc.position;
+        let segments = self.bezier_d(points);
+        self.append_or_stroke(start, &segments);
+        if let Some(last) = points.chunks_exact(3).last() {
+            self.dc.position = last[2];
+        }
+    }
+
+    pub fn poly_line_to(&mut self, points: &[(f64, f64)]) {
+        let start = self.dc.position;
+        let mut segments = String::new();
+        for (x, y) in points {
+            let (x, y) = self.to_device(*x, *y);
+            let _ = write!(segments, "L{} {}", num(x), num(y));
+        }
+        self.append_or_stroke(start, &segments);
+        if let Some(last) = points.last() {
+            self.dc.position = *last;
+        }
+    }
+
+    fn bezier_d(&self, points: &[(f64, f64)]) -> String {
+        let mut d = String::new();
+        for chunk in points.chunks_exact(3) {
+            let p: Vec<(f64, f64)> = chunk.iter().map(|(x, y)| self.to_device(*x, *y)).collect();
+            let _ = write!(
+                d,
+                "C{} {} {} {} {} {}",
+                num(p[0].0),
+                num(p[0].1),
+                num(p[1].0),
+                num(p[1].1),
+                num(p[2].0),
+                num(p[2].1)
+            );
+        }
+        d
+    }
+
+    /// 경로 기록 중이면 이어 붙이고, 아니면 현재 위치에서 시작하는 선으로 그림
+    fn append_or_stroke(&mut self, start: (f64, f64), segments: &str) {
+        if segments.is_empty() {
+            return;
+        }
+        let start = self.poly_d(&[start], false);
+        if let Some(path) = &mut self.path {
+            // 새 도형은 현재 위치에서 시작
+            if path.is_empty() || path.ends_with('Z') {
+                path.push_str(&start);
+            }
+            path.push_str(segments);
+            return;
+        }
+        let d = format!("{}{}", start, segments);
+        self.draw(&d, false, true);
+    }
+
+    pub fn move_to(&mut self, x: f64, y: f64) {
+        self.dc.position = (x, y);
+        if self.path.is_some() {
+            let (dx, dy) = self.to_device(x, y);
+            if let Some(path) = &mut self.path {
+                let _ = write!(path, "M{} {}", num(dx), num(dy));
+            }
+        }
+    }
+
+    pub fn line_to(&mut self, x: f64, y: f64) {
+        self.poly_line_to(&[(x, y)]);
+    }
+
+    pub fn rectangle(&mut self, left: f64, top: f64, right: f64, bottom: f64) {
+        self.polygon(&[(left, top), (right, top), (right, bottom), (left, bottom)]);
+    }
+
+    pub fn round_rect(&mut self, l: f64, t: f64, r: f64, b: f64, w: f64, h: f64) {
+        let (l, r) = (l.min(r), l.max(r));
+        let (t, b) = (t.min(b), t.max(b));
+        let rx = (w.abs() / 2.0).min((r - l) / 2.0);
+        let ry = (h.abs() / 2.0).min((b - t) / 2.0);
+        if rx <= 0.0 || ry <= 0.0 {
+            return self.rectangle(l, t, r, b);
+        }
+        let mut d = self.poly_d(&[(l + rx, t), (r - rx, t)], false);
+        d.push_str(&self.arc_segments((r - rx, t + ry), (rx, ry), -PI / 2.0, PI / 2.0));
+        d.push_str(&self.line_d(r, b - ry));
+        d.push_str(&self.arc_segments((r - rx, b - ry), (rx, ry), 0.0, PI / 2.0));
+        d.push_str(&self.line_d(l + rx, b));
+        d.push_str(&self.arc_segments((l + rx, b - ry), (rx, ry), PI / 2.0, PI / 2.0));
+        d.push_str(&self.line_d(l, t + ry));
+        d.push_str(&self.arc_segments((l + rx, t + ry), (rx, ry), PI, PI / 2.0));
+        d.push('Z');
+        self.draw(&d, true, true);
+    }
+
+    fn line_d(&self, x: f64, y: f64) -> String {
+        let (x, y) = self.to_device(x, y);
+        format!("L{} {}", num(x), num(y))
+    }
+
+    pub fn ellipse(&mut self, l: f64, t: f64, r: f64, b: f64) {
+        let (center, radius) = ellipse_geometry(l, t, r, b);
+        let start = self.to_device(center.0 + radius.0, center.1);
+        let mut d = format!("M{} {}", num(start.0), num(start.1));
+        d.push_str(&self.arc_segments(center, radius, 0.0, 2.0 * PI));
+        d.push('Z');
+        self.draw(&d, true, true);
+    }
+
+    /// Arc/Chord/Pie: 시작 반지름 점에서 끝 반지름 점까지 반시계 방향
+    /// kind: 0 = 호, 1 = 현(양끝 연결), 2 = 부채꼴(중심 연결)
+    pub fn arc(
+        &mut self,
+        rect: (f64, f64, f64, f64),
+        start: (f64, f64),
+        end: (f64, f64),
+        kind: u8,
+    ) {
+        let (center, radius) = ellipse_geometry(rect.0, rect.1, rect.2, rect.3);
+        if radius.0 <= 0.0 || radius.1 <= 0.0 {
+            return;
+        }
+        let angle =
+            |(x, y): (f64, f64)| ((y - center.1) / radius.1).atan2((x - center.0) / radius.0);
+        let (a1, a2) = (angle(start), angle(end));
+        // 화면(y 아래쪽)에서 반시계 방향 = 각도 감소
+        let mut sweep = a1 - a2;
+        if sweep <= 1e-9 {
+            sweep += 2.0 * PI;
+        }
+        let first = (
+            center.0 + radius.0 * a1.cos(),
+            center.1 + radius.1 * a1.sin(),
+        );
+        let mut d = self.poly_d(&[first], false);
+        d.push_str(&self.arc_segments(center, radius, a1, -sweep));
+        match kind {
+            1 => d.push('Z'),
+            2 => {
+                d.push_str(&self.line_d(center.0, center.1));
+                d.push('Z');
+            }
+            _ => {}
+        }
+        self.draw(&d, kind != 0, true);
+    }
+
+    /// 타원 호를 3차 베지어로 (90도 이하 조각, 시작점 제외)
+    fn arc_segments(
+        &self,
+        center: (f64, f64),
+        radius: (f64, f64),
+        start: f64,
+        sweep: f64,
+    ) -> String {
+        let count = (sweep.abs() / (PI / 2.0)).ceil().max(1.0) as usize;
+        let step = sweep / count as f64;
+        let k = 4.0 / 3.0 * (step / 4.0).tan();
+        let point = |a: f64| (center.0 + radius.0 * a.cos(), center.1 + radius.1 * a.sin());
+        let mut d = String::new();
+        for i in 0..count {
+            let (a1, a2) = (start + step * i as f64, start + step * (i + 1) as f64);
+            let (p1, p2) = (point(a1), point(a2));
+            let c1 = (
+                p1.0 - k * radius.0 * a1.sin(),
+                p1.1 + k * radius.1 * a1.cos(),
+            );
+            let c2 = (
+                p2.0 + k * radius.0 * a2.sin(),
+                p2.1 - k * radius.1 * a2.cos(),
+            );
+            let [c1, c2, p2] = [c1, c2, p2].map(|(x, y)| self.to_device(x, y));
+            let _ = write!(
+                d,
+                "C{} {} {} {} {} {}",
+                num(c1.0),
+                num(c1.1),
+                num(c2.0),
+                num(c2.1),
+                num(p2.0),
+                num(p2.1)
+            );
+        }
+        d
+    }
+
+    /// 사각형 채우기 (PatBlt, SetPixel 등, 펜 없이)
+    pub fn fill_rect(&mut self, l: f64, t: f64, r: f64, b: f64, fill: u32) {
+        let d = self.poly_d(&[(l, t), (r, t), (r, b), (l, b)], true);
+        let clip = self.clip_attr();
+        let _ = write!(
+            self.body,
+            r#"<path d="{}" fill="{}"{}/>"#,
+            d,
+            color(fill),
+            clip
+        );
+    }
+
+    /// PatBlt: 현재 브러시로 사각형 채우기
+    pub fn pattern_rect(&mut self, l: f64, t: f64, r: f64, b: f64) {
+        let d = self.poly_d(&[(l, t), (r, t), (r, b), (l, b)], true);
+        self.draw(&d, true, false);
+    }
+
+    /// 비트맵 (BMP 파일 바이트)을 논리 사각형에 늘려 그림
+    pub fn image(&mut self, x: f64, y: f64, w: f64, h: f64, bmp: &[u8]) {
+        let (x1, y1) = self.to_device(x, y);
+        let (x2, y2) = self.to_device(x + w, y + h);
+        let clip = self.clip_attr();
+        let _ = write!(
+            self.body,
+            r#"<image x="{}" y="{}" width="{}" height="{}" preserveAspectRatio="none" href="data:image/bmp;base64,{}"{}/>"#,
+            num(x1.min(x2)),
+            num(y1.min(y2)),
+            num((x2 - x1).abs()),
+            num((y2 - y1).abs()),
+            base64::engine::general_purpose::STANDARD.encode(bmp),
+            clip
+        );
+    }
+
+    /// 글자 출력
+    /// `dx`: 글자별 전진 폭 (논리 단위), `opaque`: ETO_OPAQUE 배경 사각형
+    pub fn text(
+        &mut self,
+        x: f64,
+        y: f64,
+        text: &str,
+        dx: Option<&[f64]>,
+        opaque: Option<(f64, f64, f64, f64)>,
+    ) {
+        if let Some((l, t, r, b)) = opaque {
+            self.fill_rect(l, t, r, b, self.dc.bk_color);
+        }
+        if text.trim().is_empty() {
+            return;
+        }
+        let align = self.dc.text_align;
+        let reference = if align & TA_UPDATECP != 0 {
+            self.dc.position
+        } else {
+            (x, y)
+        };
+        let (px, py) = self.to_device(reference.0, reference.1);
+        let font = self.dc.font.clone();
+        let em = if font.height < 0.0 {
+            self.height(-font.height)
+        } else if font.height > 0.0 {
+            self.height(font.height) * 0.85
+        } else {
+            // 기본 글꼴 12pt
+            12.0 / 72.0 * 25.4 * self.units_per_mm.1
+        };
+        if em <= 0.0 {
+            return;
+        }
+        // 기준선 보정: 위쪽 맞춤은 어센트, 아래쪽 맞춤은 디센트만큼
+        let baseline = match align & TA_BASELINE {
+            TA_BASELINE => 0.0,
+            TA_BOTTOM => -0.14 * em,
+            _ => 0.86 * em,
+        };
+        let chars: Vec<char> = text.chars().collect();
+        let advances: Option<Vec<f64>> = dx
+            .filter(|dx| dx.len() >= chars.len())
+            .map(|dx| dx[..chars.len()].iter().map(|d| self.width(*d)).collect());
+        let total: f64 = advances.as_ref().map_or(0.0, |a| a.iter().sum());
+        if align & TA_UPDATECP != 0 {
+            let advance = total / self.width(1.0).max(f64::EPSILON);
+            self.dc.position.0 += if align & TA_CENTER == TA_RIGHT {
+                -advance
+            } else {
+                advance
+            };
+        }
+
+        let mut attrs = format!(
+            r#"font-size="{}" font-family="{}" fill="{}""#,
+            num(em),
+            font_family(&font.face),
+            color(self.dc.text_color)
+        );
+        if font.weight >= 600 {
+            attrs.push_str(r#" font-weight="bold""#);
+        }
+        if font.italic {
+            attrs.push_str(r#" font-style="italic""#);
+        }
+        match (font.underline, font.strike_out) {
+            (true, true) => attrs.push_str(r#" text-decoration="underline line-through""#),
+            (true, false) => attrs.push_str(r#" text-decoration="underline""#),
+            (false, true) => attrs.push_str(r#" text-decoration="line-through""#),
+            _ => {}
+        }
+        let x_attr = match &advances {
+            Some(advances) => {
+                let start = match align & TA_CENTER {
+                    TA_CENTER => px - total / 2.0,
+                    TA_RIGHT => px - total,
+                    _ => px,
+                };
+                let mut pos = start;
+                let xs: Vec<String> = advances
+                    .iter()
+                    .map(|a| {
+                        let x = num(pos);
+                        pos += a;
+                        x
+                    })
+                    .collect();
+                xs.join(" ")
+            }
+            None => {
+                match align & TA_CENTER {
+                    TA_CENTER => attrs.push_str(r#" text-anchor="middle""#),
+                    TA_RIGHT => attrs.push_str(r#" text-anchor="end""#),
+                    _ => {}
+                }
+                num(px)
+            }
+        };
+        if font.escapement != 0.0 {
+            let _ = write!(
+                attrs,
+                r#" transform="rotate({} {} {})""#,
+                num(-font.escapement / 10.0),
+                num(px),
+                num(py)
+            );
+        }
+        let clip = self.clip_attr();
+        let _ = write!(
+            self.body,
+            r#"<text x="{}" y="{}" {} xml:space="preserve"{}>{}</text>"#,
+            x_attr,
+            num(py + baseline),
+            attrs,
+            clip,
+            html_escape(text)
+        );
+    }
+}
+
+/// 외접 사각형 → (중심, 반지름)
+fn ellipse_geometry(l: f64, t: f64, r: f64, b: f64) -> ((f64, f64), (f64, f64)) {
+    (
+        ((l + r) / 2.0, (t + b) / 2.0),
+        ((r - l).abs() / 2.0, (b - t).abs() / 2.0),
+    )
+}
+
+/// COLORREF(0x00BBGGRR) → #RRGGBB
+pub(super) fn color(colorref: u32) -> String {
+    format!(
+        "#{:02X}{:02X}{:02X}",
+        colorref & 0xFF,
+        (colorref >> 8) & 0xFF,
+        (colorref >> 16) & 0xFF
+    )
+}
+
+fn font_family(face: &str) -> String {
+    let face = face.trim().replace(['\'', '"', '<', '>', '&'], "");
+    if face.is_empty() {
+        "sans-serif".to_string()
+    } else {
+        format!("'{}', sans-serif", face)
+    }
+}
+
+/// 불필요한 0 없이 소수 둘째 자리까지
+pub(super) fn num(value: f64) -> String {
+    let value = (value * 100.0).round() / 100.0;
+    if value == 0.0 {
+        "0".to_string()
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,414 @@
+/// WMF(Windows Metafile) 레코드 해석
+/// placeable 헤더의 경계 사각형을 출력 영역으로 삼고, 레코드의 창(window) 좌표를 그 영역에 매핑합니다.
+use super::player::{Brush, Font, GdiObject, Pen, Player, MM_ANISOTROPIC};
+use super::{decode_ansi, i16_at, u16_at, u32_at};
+
+/// placeable WMF 헤더 키
+const PLACEABLE_KEY: u32 = 0x9AC6_CDD7;
+
+/// 래스터 연산: 브러시로 채우기 / 검정 / 흰색
+const PATCOPY: u32 = 0x00F0_0021;
+const BLACKNESS: u32 = 0x0000_0042;
+const WHITENESS: u32 = 0x00FF_0062;
+
+/// WMF 바이트 → SVG 문서
+/// Convert a WMF (with or without the placeable header) to SVG
+pub fn wmf_to_svg(data: &[u8]) -> Option<String> {
+    let (bounds, inch, header) = if u32_at(data, 0) == PLACEABLE_KEY {
+        let bounds = [
+            i16_at(data, 6),
+            i16_at(data, 8),
+            i16_at(data, 10),
+            i16_at(data, 12),
+        ]
+        .map(f64::from);
+        let inch = match u16_at(data, 14) {
+            0 => 1440,
+            inch => inch,
+        };
+        (Some(bounds), f64::from(inch), 22)
+    } else {
+        // placeable 헤더가 없으면 창 크기를 twip 단위로 봄
+        (None, 1440.0, 0)
+    };
+    if !matches!(u16_at(data, header), 1 | 2) || u16_at(data, header + 2) != 9 {
+        return None;
+    }
+    let records = header + 18;
+    let [left, top, right, bottom] = match bounds {
+        Some(bounds) => bounds,
+        None => window_bounds(data, records)?,
+    };
+    let (x, y) = (left.min(right), top.min(bottom));
+    let (width, height) = ((right - left).abs(), (bottom - top).abs());
+    if width == 0.0 || height == 0.0 {
+        return None;
+    }
+
+    let units_per_mm = inch / 25.4;
+    let mut player = Player::new([x, y, width, height], (units_per_mm, units_per_mm));
+    player.dc.map_mode = MM_ANISOTROPIC;
+    player.dc.window_org = (x, y);
+    player.dc.window_ext = (width, height);
+    player.dc.viewport_org = (x, y);
+    player.dc.viewport_ext = (width, height);
+
+    for (function, params) in Records::new(data, records) {
+        if function == 0 {
+            break;
+        }
+        play(&mut player, function, params);
+    }
+    Some(player.finish(width / units_per_mm, height / units_per_mm))
+}
+
+/// 레코드 순회: (함수 번호, 매개변수 바이트)
+struct Records<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Records<'a> {
+    fn new(data: &'a [u8], pos: usize) -> Self {
+        Records { data, pos }
+    }
+}
+
+impl<'a> Iterator for Records<'a> {
+    type Item = (u16, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let size = u32_at(self.data, self.pos) as usize * 2;
+        if size < 6 || self.pos + size > self.data.len() {
+            return None;
+        }
+        let function = u16_at(self.data, self.pos + 4);
+        let params = &self.data[self.pos + 6..self.pos + size];
+        self.pos += size;
+        Some((function, params))
+    }
+}
+
+/// placeable 헤더가 없을 때: 마지막 SetWindowOrg/SetWindowExt로 경계 추정
+fn window_bounds(data: &[u8], records: usize) -> Option<[f64; 4]> {
+    let mut org = (0.0, 0.0);
+    let mut ext = None;
+    for (function, params) in Records::new(data, records) {
+        let v = |i: usize| f64::from(i16_at(params, i * 2));
+        match function {
+            0x020B => org = (v(1), v(0)),
+            0x020C => ext = Some((v(1), v(0))),
+            _ => {}
+        }
+    }
+    let (w, h) = ext?;
+    Some([org.0, org.1, org.0 + w, org.1 + h])
+}
+
+fn play(p: &mut Player, function: u16, params: &[u8]) {
+    let v = |i: usize| f64::from(i16_at(params, i * 2));
+    match function {
+        // SETBKCOLOR / SETBKMODE / SETPOLYFILLMODE / SETTEXTCOLOR / SETTEXTALIGN
+        0x0201 => p.dc.bk_color = u32_at(params, 0),
+        0x0102 => p.dc.bk_mode = u32::from(u16_at(params, 0)),
+        0x0106 => p.dc.poly_fill_mode = u32::from(u16_at(params, 0)),
+        0x0209 => p.dc.text_color = u32_at(params, 0),
+        0x012E => p.dc.text_align = u32::from(u16_at(params, 0)),
+        // SETWINDOWORG / SETWINDOWEXT / OFFSETWINDOWORG / SCALEWINDOWEXT
+        // (매핑 모드와 뷰포트는 출력 영역에 고정)
+        0x020B => p.dc.window_org = (v(1), v(0)),
+        0x020C => p.dc.window_ext = (v(1), v(0)),
+        0x020F => {
+            p.dc.window_org.0 += v(1);
+            p.dc.window_org.1 += v(0);
+        }
+        0x0410 if v(2) != 0.0 && v(0) != 0.0 => {
+            p.dc.window_ext.0 = p.dc.window_ext.0 * v(3) / v(2);
+            p.dc.window_ext.1 = p.dc.window_ext.1 * v(1) / v(0);
+        }
+        // SAVEDC / RESTOREDC
+        0x001E => p.save_dc(),
+        0x0127 => p.restore_dc(i32::from(i16_at(params, 0))),
+        // INTERSECTCLIPRECT
+        0x0416 => p.intersect_clip(v(3), v(2), v(1), v(0)),
+        // MOVETO / LINETO
+        0x0214 => p.move_to(v(1), v(0)),
+        0x0213 => p.line_to(v(1), v(0)),
+        // ELLIPSE / RECTANGLE / ROUNDRECT
+        0x0418 => p.ellipse(v(3), v(2), v(1), v(0)),
+        0x041B => p.rectangle(v(3), v(2), v(1), v(0)),
+        0x061C => p.round_rect(v(5), v(4), v(3), v(2), v(1), v(0)),
+        // ARC / PIE / CHORD
+        0x0817 | 0x081A | 0x0830 => {
+            let kind = match function {
+                0x0830 => 1,
+                0x081A => 2,
+                _ => 0,
+            };
+            p.arc((v(7), v(6), v(5), v(4)), (v(3), v(2)), (v(1), v(0)), kind);
+        }
+        // SETPIXEL
+        0x041F => {
+            let (x, y) = (v(3), v(2));
+            p.fill_rect(x, y, x + 1.0, y + 1.0, u32_at(params, 0));
+        }
+        // POLYGON / POLYLINE / POLYPOLYGON
+        0x0324 => p.polygon(&points(params, 2, usize::from(u16_at(params, 0)))),
+        0x0325 => p.polyline(&points(params, 2, usize::from(u16_at(params, 0)))),
+        0x0538 => {
+            let count = usize::from(u16_at(params, 0));
+            let mut offset = 2 + count * 2;
+            let polygons: Vec<_> = (0..count)
+                .map(|i| {
+                    let n = usize::from(u16_at(params, 2 + i * 2));
+                    let polygon = points(params, offset, n);
+                    offset += n * 4;
+                    polygon
+                })
+                .collect();
+            p.poly_polygon(&polygons);
+        }
+        // TEXTOUT / EXTTEXTOUT
+        0x0521 => {
+            let len = usize::from(u16_at(params, 0));
+            let Some(bytes) = params.get(2..2 + len) else {
+                return;
+            };
+            let after = 2 + len + len % 2;
+            let y = f64::from(i16_at(params, after));
+            let x = f64::from(i16_at(params, after + 2));
+            let text = decode_ansi(bytes, p.dc.font.charset);
+            p.text(x, y, &text, None, None);
+        }
+        0x0A32 => ext_text_out(p, params),
+        // SELECTOBJECT / DELETEOBJECT
+        0x012D => p.select_index(usize::from(u16_at(params, 0))),
+        0x01F0 => p.delete_object(usize::from(u16_at(params, 0))),
+        // CREATEPENINDIRECT
+        0x02FA => p.add_object(GdiObject::Pen(Pen {
+            style: u32::from(u16_at(params, 0)),
+            width: v(1),
+            color: u32_at(params, 6),
+        })),
+        // CREATEBRUSHINDIRECT
+        0x02FC => p.add_object(GdiObject::Brush(Brush {
+            style: u32::from(u16_at(params, 0)),
+            color: u32_at(params, 2),
+            hatch: u32::from(u16_at(params, 6)),
+        })),
+        // CREATEFONTINDIRECT
+        0x02FB => p.add_object(GdiObject::Font(font(params))),
+        // CREATEPATTERNBRUSH / DIBCREATEPATTERNBRUSH
+        0x01F9 | 0x0142 => p.add_object(GdiObject::Brush(Brush {
+            style: 3,
+            color: 0,
+            hatch: 0,
+        })),
+        // CREATEPALETTE / CREATEREGION: 번호만 차지
+        0x00F7 | 0x06FF => p.add_object(GdiObject::Other),
+        // PATBLT
+        0x061D => blt_fill(p, u32_at(params, 0), v(5), v(4), v(3), v(2)),
+        // STRETCHDIB
+        0x0F43 => image(p, (v(10), v(9), v(8), v(7)), params.get(22..)),
+        // DIBSTRETCHBLT (비트맵 없는 형식은 매개변수가 22바이트)
+        0x0B41 if params.len() == 22 => blt_fill(p, u32_at(params, 0), v(10), v(9), v(8), v(7)),
+        0x0B41 => image(p, (v(9), v(8), v(7), v(6)), params.get(20..)),
+        // DIBBITBLT (비트맵 없는 형식은 매개변수가 18바이트)
+        0x0940 if params.len() == 18 => blt_fill(p, u32_at(params, 0), v(8), v(7), v(6), v(5)),
+        0x0940 => image(p, (v(7), v(6), v(5), v(4)), params.get(16..)),
+        // SETDIBTODEV
+        0x0D33 => image(p, (v(8), v(7), v(6), v(5)), params.get(18..)),
+        _ => {}
+    }
+}
+
+fn ext_text_out(p: &mut Player, params: &[u8]) {
+    let y = f64::from(i16_at(params, 0));
+    let x = f64::from(i16_at(params, 2));
+    let count = usize::from(u16_at(params, 4));
+    let options = u16_at(params, 6);
+    let mut offset = 8;
+    let mut opaque = None;
+    // ETO_OPAQUE(0x2) / ETO_CLIPPED(0x4)이면 사각형이 따라옴
+    if options & 0x0006 != 0 {
+        let rect = [0, 2, 4, 6].map(|i| f64::from(i16_at(params, offset + i)));
+        if options & 0x0002 != 0 {
+            opaque = Some((rect[0], rect[1], rect[2], rect[3]));
+        }
+        offset += 8;
+    }
+    let Some(bytes) = params.get(offset..offset + count) else {
+        return;
+    };
+    let charset = p.dc.font.charset;
+    let text = decode_ansi(bytes, charset);
+    let dx_offset = offset + count + count % 2;
+    let dx = (params.len() >= dx_offset + count * 2).then(|| {
+        let dx: Vec<f64> = (0..count)
+            .map(|i| f64::from(i16_at(params, dx_offset + i * 2)))
+            .collect();
+        char_advances(bytes, &dx, charset)
+    });
+    p.text(x, y, &text, dx.as_deref(), opaque);
+}
+
+/// 바이트별 전진 폭 → 글자별 전진 폭 (DBCS 두 바이트 글자는 합침)
+fn char_advances(bytes: &[u8], dx: &[f64], charset: u8) -> Vec<f64> {
+    let dbcs = matches!(charset, 0 | 1 | 128..=136);
+    let mut advances = Vec::with_capacity(dx.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == 0 {
+            break;
+        }
+        let width = if dbcs && bytes[i] >= 0x81 && i + 1 < bytes.len() {
+            2
+        } else {
+            1
+        };
+        advances.push(dx[i..i + width].iter().sum());
+        i += width;
+    }
+    advances
+}
+
+fn font(params: &[u8]) -> Font {
+    let byte = |i: usize| params.get(i).copied().unwrap_or(0);
+    let charset = byte(13);
+    Font {
+        height: f64::from(i16_at(params, 0)),
+        escapement: f64::from(i16_at(params, 4)),
+        weight: u32::from(u16_at(params, 8)),
+        italic: byte(10) != 0,
+        underline: byte(11) != 0,
+        strike_out: byte(12) != 0,
+        charset,
+        face: decode_ansi(params.get(18..).unwrap_or_default(), charset),
+    }
+}
+
+/// (x, y) 16비트 점 n개 (매개변수 범위 안에서만)
+fn points(params: &[u8], offset: usize, count: usize) -> Vec<(f64, f64)> {
+    let available = params.len().saturating_sub(offset) / 4;
+    (0..count.min(available))
+        .map(|i| {
+            (
+                f64::from(i16_at(params, offset + i * 4)),
+                f64::from(i16_at(params, offset + i * 4 + 2)),
+            )
+        })
+        .collect()
+}
+
+/// 비트맵 없는 BitBlt 계열: 래스터 연산에 따라 사각형 채우기
+fn blt_fill(p: &mut Player, rop: u32, x: f64, y: f64, w: f64, h: f64) {
+    match rop {
+        PATCOPY => p.pattern_rect(x, y, x + w, y + h),
+        BLACKNESS => p.fill_rect(x, y, x + w, y + h, 0),
+        WHITENESS => p.fill_rect(x, y, x + w, y + h, 0xFFFFFF),
+        _ => {}
+    }
+}
+
+/// DIB 레코드: 대상 사각형 (x, y, w, h)에 비트맵을 그림
+fn image(p: &mut Player, (x, y, w, h): (f64, f64, f64, f64), dib: Option<&[u8]>) {
+    if let Some(bmp) = dib.and_then(crate::ole::dib_to_bmp) {
+        p.image(x, y, w, h, &bmp);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// placeable WMF 조립: 경계 (0, 0)-(1000, 500), 1440 단위/인치
+    fn wmf(records: &[(u16, Vec<i16>)]) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&PLACEABLE_KEY.to_le_bytes());
+        for v in [0i16, 0, 0, 1000, 500, 1440] {
+            data.extend_from_slice(&v.to_le_bytes());
+        }
+        data.extend_from_slice(&[0; 6]);
+        for v in [1u16, 9, 0x300, 0, 0, 0, 0, 0, 0] {
+            data.extend_from_slice(&v.to_le_bytes());
+        }
+        for (function, params) in records.iter().chain([(0u16, vec![])].iter()) {
+            data.extend_from_slice(&(3 + params.len() as u32).to_le_bytes());
+            data.extend_from_slice(&function.to_le_bytes());
+            for v in params {
+                data.extend_from_slice(&v.to_le_bytes());
+            }
+        }
+        data
+    }
+
+    #[test]
+    fn test_wmf_shapes() {
+        let data = wmf(&[
+            // 창: (0,0)-(2000,1000) → 출력 영역의 두 배 좌표계
+            (0x020B, vec![0, 0]),
+            (0x020C, vec![1000, 2000]),
+            // 빨간 실선 펜, 파란 브러시
+            (0x02FA, vec![0, 20, 0, 0xFF, 0]),
+            (0x02FC, vec![0, 0, 0xFF, 0]),
+            (0x012D, vec![0]),
+            (0x012D, vec![1]),
+            (0x041B, vec![400, 400, 200, 200]),
+            (0x0324, vec![3, 0, 0, 100, 0, 100, 100]),
+            (0x0418, vec![1000, 2000, 0, 0]),
+        ]);
+        let svg = wmf_to_svg(&data).unwrap();
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="17.64mm" height="8.82mm" viewBox="0 0 1000 500""#));
+        assert!(svg.contains(r##"<path d="M100 100L200 100L200 200L100 200Z" fill="#0000FF" fill-rule="evenodd" stroke="#FF0000" stroke-width="10""##));
+        assert!(svg.contains(r#"d="M0 0L50 0L50 50Z""#));
+        // 타원은 베지어 4조각
+        let ellipse = svg.split("<path").nth(3).unwrap();
+        assert_eq!(ellipse.matches('C').count(), 4);
+        assert!(ellipse.starts_with(r#" d="M1000 250C"#));
+        assert!(svg.ends_with("</svg>"));
+    }
+
+    #[test]
+    fn test_wmf_text_and_objects() {
+        // "가A" (CP949) + 글꼴 (높이 -100, 굴림)
+        let mut font = vec![
+            -100i16,
+            0,
+            0,
+            0,
+            700,
+            0,
+            i16::from_le_bytes([0, 0x81]),
+            0,
+            0,
+        ];
+        let face = encoding_rs::EUC_KR.encode("굴림").0.to_vec();
+        font.extend(face.chunks(2).map(|c| i16::from_le_bytes([c[0], c[1]])));
+        font.push(0);
+        let mut text = encoding_rs::EUC_KR.encode("가A").0.to_vec();
+        let mut ext = vec![300, 100, text.len() as i16, 0];
+        text.push(0);
+        ext.extend(text.chunks(2).map(|c| i16::from_le_bytes([c[0], c[1]])));
+        ext.extend([60, 0, 40]);
+        let data = wmf(&[
+            (0x02FB, font),
+            (0x012D, vec![0]),
+            (0x0209, vec![0xFF, 0]),
+            (0x012E, vec![24]),
+            (0x0A32, ext),
+            // 삭제된 번호는 다시 사용
+            (0x01F0, vec![0]),
+            (0x02FA, vec![5, 0, 0, 0, 0]),
+            (0x012D, vec![0]),
+            (0x041B, vec![10, 10, 0, 0]),
+        ]);
+        let svg = wmf_to_svg(&data).unwrap();
+        assert!(svg.contains(r##"<text x="100 160" y="300" font-size="100" font-family="'굴림', sans-serif" fill="#FF0000" font-weight="bold" xml:space="preserve">가A</text>"##), "{svg}");
+        assert!(svg.contains(r#"stroke="none""#));
+    }
+
+    #[test]
+    fn test_not_wmf() {
+        assert!(wmf_to_svg(b"GIF89a").is_none());
+    }
+}
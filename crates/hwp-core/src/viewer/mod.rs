@@ -1,4 +1,4 @@
-#[allow(missing_docs)] // TODO: Implement Canvas viewer
+/// Document(hwp-model) 기반 Canvas display list — 웹/React Native 뷰어가 재생하는 그리기 명령
 pub mod canvas;
 /// Viewer module for converting HWP documents to various formats
 /// HWP 문서를 다양한 형식으로 변환하는 뷰어 모듈
@@ -13,12 +13,34 @@ pub mod doc_html;
 pub mod doc_markdown;
 /// Document 기반 viewer 공통 유틸리티
 pub(crate) mod doc_utils;
+/// Document(hwp-model) 기반 DOCX 내보내기 — 문단/글자 모양, 표, 그림, 머리말/꼬리말, 각주/미주
+pub mod docx;
+/// 한글 수식 스크립트 → MathML/LaTeX 변환
+pub mod equation;
 pub mod html;
 pub mod markdown;
+/// WMF/EMF 메타파일 → SVG 변환 (브라우저에 표시할 수 없는 그림/OLE 미리보기용)
+pub mod metafile;
+/// Document(hwp-model) 기반 PDF 내보내기 — 글꼴 서브셋 임베딩, 표, 이미지, 쪽 테두리
+pub mod pdf;
+/// Document(hwp-model) 기반 CPU 래스터라이저 — 페이지 PNG 썸네일 (`raster` 기능)
+#[cfg(feature = "raster")]
+pub mod raster;
+/// 변경 추적 보기 모드 (최종본/원본/변경 표시)
+pub mod revision;
+/// Document(hwp-model) 기반 페이지별 SVG 내보내기 — 썸네일/인쇄 미리보기
+pub mod svg;
+pub use canvas::doc_to_canvas;
 pub use core::renderer::{DocumentParts, Renderer, TextStyles};
 pub use doc_html::doc_to_html;
 pub use doc_markdown::doc_to_markdown;
+pub use docx::{to_docx, DocxOptions};
 #[allow(deprecated)]
 pub use html::{to_html, to_html_pages, HtmlOptions, HtmlPages};
 #[allow(deprecated)]
 pub use markdown::{to_markdown, MarkdownOptions};
+pub use pdf::{to_pdf, PdfOptions};
+#[cfg(feature = "raster")]
+pub use raster::{doc_page_to_png, RasterOptions, Rasterizer};
+pub use revision::RevisionView;
+pub use svg::{doc_to_svg_pages, SvgOptions};
@@ -0,0 +1,359 @@
+use super::writer::{self, ObjectId, PdfWriter};
+/// 글꼴 선택과 PDF 글꼴 객체 / Font selection and PDF font objects
+///
+/// 글꼴 디렉터리의 TrueType 글꼴을 모두 읽어 두고, 글자마다
+/// CharShape 글꼴 이름과 굵게/기울임이 가장 잘 맞는 글꼴 → 그 글자를 가진 다른 글꼴 순으로 고릅니다.
+/// 사용한 글꼴은 서브셋으로 만들어 Type0(CIDFontType2, Identity-H) 글꼴로 넣습니다.
+///
+/// 글꼴 디렉터리가 없거나 읽을 수 있는 글꼴이 없으면 표준 14 글꼴(Helvetica)로 대신하며,
+/// 이 경우 WinAnsi 밖의 글자는 `?`로 출력됩니다.
+use crate::font::{load_dir, normalize_family, TrueTypeFont};
+use crate::viewer::canvas::CanvasFont;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
+
+/// 표준 14 글꼴 대체 (보통, 굵게, 기울임, 굵게 기울임)
+const STANDARD_FONTS: [&str; 4] = [
+    "Helvetica",
+    "Helvetica-Bold",
+    "Helvetica-Oblique",
+    "Helvetica-BoldOblique",
+];
+/// ToUnicode CMap의 bfchar 블록당 최대 항목 수
+const BFCHAR_LIMIT: usize = 100;
+
+/// PDF 리소스의 글꼴 / Font resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum FontRef {
+    /// 임베딩한 TrueType 글꼴 (FontSet 안의 번호)
+    Embedded(usize),
+    /// 표준 14 글꼴 (STANDARD_FONTS 번호)
+    Standard(usize),
+}
+
+impl FontRef {
+    /// 리소스 사전의 이름 / Name in the resource dictionary
+    pub(super) fn resource_name(&self) -> String {
+        match self {
+            FontRef::Embedded(index) => format!("F{}", index + 1),
+            FontRef::Standard(index) => format!("S{}", index + 1),
+        }
+    }
+}
+
+/// 같은 글꼴로 연속해서 출력하는 글자들 / Characters shown with the same font
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct TextRun {
+    pub font: FontRef,
+    /// Tj에 넘길 16진 문자열 (꺾쇠 제외)
+    pub hex: String,
+    /// 글자 폭 합 (1000 단위, 표준 글꼴은 0)
+    pub width: f64,
+    /// 글꼴에 굵은 모양이 없어 획을 덧그려야 하는지
+    pub fake_bold: bool,
+    /// 글꼴에 기울임 모양이 없어 기울여야 하는지
+    pub fake_italic: bool,
+}
+
+/// 문서에 쓸 글꼴 모음 / Fonts available to the document
+pub(super) struct FontSet {
+    fonts: Vec<TrueTypeFont>,
+    /// 글꼴별 사용 glyph → 글자 (서브셋/ToUnicode용)
+    used: Vec<BTreeMap<u16, char>>,
+    standard_used: [bool; 4],
+    /// (정규화한 이름, 굵게, 기울임) → 이름이 맞는 글꼴
+    primary_cache: HashMap<(String, bool, bool), Option<usize>>,
+}
+
+impl FontSet {
+    /// 디렉터리의 글꼴을 파일 이름 순으로 읽음 (하위 디렉터리 제외)
+    pub(super) fn load(dir: Option<&Path>) -> Self {
+        Self::new(dir.map(load_dir).unwrap_or_default())
+    }
+
+    fn new(fonts: Vec<TrueTypeFont>) -> Self {
+        Self {
+            used: vec![BTreeMap::new(); fonts.len()],
+            fonts,
+            standard_used: [false; 4],
+            primary_cache: HashMap::new(),
+        }
+    }
+
+    /// 글자를 글꼴별 구간으로 나누고 인코딩 / Split text into per-font runs
+    pub(super) fn runs(&mut self, text: &str, font: &CanvasFont) -> Vec<TextRun> {
+        let mut runs: Vec<TextRun> = Vec::new();
+        if self.fonts.is_empty() {
+            let style = font.bold as usize + font.italic as usize * 2;
+            self.standard_used[style] = true;
+            let mut hex = String::new();
+            for ch in text.chars() {
+                hex.push_str(&format!("{:02X}", win_ansi(ch)));
+            }
+            runs.push(TextRun {
+                font: FontRef::Standard(style),
+                hex,
+                width: 0.0,
+                fake_bold: false,
+                fake_italic: false,
+            });
+            return runs;
+        }
+
+        let primary = self.primary(&font.family, font.bold, font.italic);
+        for ch in text.chars() {
+            let (index, gid) = self.glyph_for(ch, primary, font.bold, font.italic);
+            self.used[index].entry(gid).or_insert(ch);
+            let candidate = &self.fonts[index];
+            let fake_bold = font.bold && !candidate.bold;
+            let fake_italic = font.italic && !candidate.italic;
+            let code = format!("{:04X}", gid);
+            let advance = candidate.advance(gid);
+            match runs.last_mut() {
+                Some(run)
+                    if run.font == FontRef::Embedded(index)
+                        && run.fake_bold == fake_bold
+                        && run.fake_italic == fake_italic =>
+                {
+                    run.hex.push_str(&code);
+                    run.width += advance;
+                }
+                _ => runs.push(TextRun {
+                    font: FontRef::Embedded(index),
+                    hex: code,
+                    width: advance,
+                    fake_bold,
+                    fake_italic,
+                }),
+            }
+        }
+        runs
+    }
+
+    /// 이름이 맞는 글꼴 중 스타일이 가장 가까운 것
+    fn primary(&mut self, family: &str, bold: bool, italic: bool) -> Option<usize> {
+        let key = (normalize_family(family), bold, italic);
+        if let Some(cached) = self.primary_cache.get(&key) {
+            return *cached;
+        }
+        let found = self.best(|font| font.families.contains(&key.0), bold, italic);
+        self.primary_cache.insert(key, found);
+        found
+    }
+
+    /// 조건을 만족하는 글꼴 중 스타일 점수가 가장 높은 (동점이면 앞선) 글꼴
+    fn best(
+        &self,
+        filter: impl Fn(&TrueTypeFont) -> bool,
+        bold: bool,
+        italic: bool,
+    ) -> Option<usize> {
+        let mut best: Option<(usize, u8)> = None;
+        for (index, font) in self.fonts.iter().enumerate() {
+            if !filter(font) {
+                continue;
+            }
+            let score = (font.bold == bold) as u8 * 2 + (font.italic == italic) as u8;
+            if best.map_or(true, |(_, s)| score > s) {
+                best = Some((index, score));
+            }
+        }
+        best.map(|(index, _)| index)
+    }
+
+    /// 글자를 그릴 글꼴과 glyph — 어떤 글꼴에도 없으면 .notdef(0)
+    fn glyph_for(
+        &self,
+        ch: char,
+        primary: Option<usize>,
+        bold: bool,
+        italic: bool,
+    ) -> (usize, u16) {
+        if let Some(gid) = primary.and_then(|index| self.fonts[index].glyph(ch)) {
+            return (primary.unwrap_or(0), gid);
+        }
+        if let Some(index) = self.best(|font| font.glyph(ch).is_some(), bold, italic) {
+            return (index, self.fonts[index].glyph(ch).unwrap_or(0));
+        }
+        let index = primary
+            .or_else(|| self.best(|_| true, bold, italic))
+            .unwrap_or(0);
+        (index, 0)
+    }
+
+    /// 사용한 글꼴 객체를 기록하고 리소스 사전의 `/Font` 항목을 돌려줌
+    pub(super) fn write(&self, pdf: &mut PdfWriter) -> Vec<(String, ObjectId)> {
+        let mut entries = Vec::new();
+        for (index, name) in STANDARD_FONTS.iter().enumerate() {
+            if self.standard_used[index] {
+                let id = pdf.add(format!(
+                    "<< /Type /Font /Subtype /Type1 /BaseFont /{} /Encoding /WinAnsiEncoding >>",
+                    name
+                ));
+                entries.push((FontRef::Standard(index).resource_name(), id));
+            }
+        }
+        for (index, font) in self.fonts.iter().enumerate() {
+            let used = &self.used[index];
+            if used.is_empty() {
+                continue;
+            }
+            let id = write_type0(pdf, font, used, index);
+            entries.push((FontRef::Embedded(index).resource_name(), id));
+        }
+        entries
+    }
+}
+
+/// Type0 글꼴 + CIDFontType2 + FontDescriptor + FontFile2 + ToUnicode
+fn write_type0(
+    pdf: &mut PdfWriter,
+    font: &TrueTypeFont,
+    used: &BTreeMap<u16, char>,
+    index: usize,
+) -> ObjectId {
+    let glyphs: BTreeSet<u16> = used.keys().copied().collect();
+    let mut base_name = writer::name(&font.postscript_name);
+    if base_name.is_empty() {
+        base_name = format!("Font{}", index + 1);
+    }
+    let base_name = format!("{}+{}", subset_tag(&glyphs, index), base_name);
+
+    let font_file = pdf.add_deflated("", &font.subset(&glyphs));
+
+    let mut flags = 32; // Nonsymbolic
+    if font.italic {
+        flags |= 64;
+    }
+    let [x_min, y_min, x_max, y_max] = font.bbox;
+    let descriptor = pdf.add(format!(
+        "<< /Type /FontDescriptor /FontName /{} /Flags {} /FontBBox [{} {} {} {}] \
+         /ItalicAngle {} /Ascent {} /Descent {} /CapHeight {} /StemV {} /FontFile2 {} >>",
+        base_name,
+        flags,
+        writer::num(font.scale(x_min)),
+        writer::num(font.scale(y_min)),
+        writer::num(font.scale(x_max)),
+        writer::num(font.scale(y_max)),
+        writer::num(font.italic_angle),
+        writer::num(font.scale(font.ascent)),
+        writer::num(font.scale(font.descent)),
+        writer::num(font.scale(font.cap_height)),
+        if font.bold { 120 } else { 80 },
+        writer::reference(font_file)
+    ));
+
+    let widths: Vec<String> = glyphs
+        .iter()
+        .map(|&gid| format!("{} [{}]", gid, writer::num(font.advance(gid).round())))
+        .collect();
+    let cid_font = pdf.add(format!(
+        "<< /Type /Font /Subtype /CIDFontType2 /BaseFont /{} \
+         /CIDSystemInfo << /Registry (Adobe) /Ordering (Identity) /Supplement 0 >> \
+         /FontDescriptor {} /W [{}] /CIDToGIDMap /Identity >>",
+        base_name,
+        writer::reference(descriptor),
+        widths.join(" ")
+    ));
+
+    let to_unicode = pdf.add_deflated("", to_unicode_cmap(used).as_bytes());
+    pdf.add(format!(
+        "<< /Type /Font /Subtype /Type0 /BaseFont /{} /Encoding /Identity-H \
+         /DescendantFonts [{}] /ToUnicode {} >>",
+        base_name,
+        writer::reference(cid_font),
+        writer::reference(to_unicode)
+    ))
+}
+
+/// glyph id → 유니코드 CMap (텍스트 추출/검색용)
+fn to_unicode_cmap(used: &BTreeMap<u16, char>) -> String {
+    let mut cmap = String::from(
+        "/CIDInit /ProcSet findresource begin\n12 dict begin\nbegincmap\n\
+         /CIDSystemInfo << /Registry (Adobe) /Ordering (UCS) /Supplement 0 >> def\n\
+         /CMapName /Adobe-Identity-UCS def\n/CMapType 2 def\n\
+         1 begincodespacerange\n<0000> <FFFF>\nendcodespacerange\n",
+    );
+    // .notdef는 여러 글자가 공유하므로 매핑하지 않음
+    let entries: Vec<(&u16, &char)> = used.iter().filter(|(&gid, _)| gid != 0).collect();
+    for chunk in entries.chunks(BFCHAR_LIMIT) {
+        cmap.push_str(&format!("{} beginbfchar\n", chunk.len()));
+        for (gid, ch) in chunk {
+            let mut units = [0u16; 2];
+            let hex: String = ch
+                .encode_utf16(&mut units)
+                .iter()
+                .map(|u| format!("{:04X}", u))
+                .collect();
+            cmap.push_str(&format!("<{:04X}> <{}>\n", gid, hex));
+        }
+        cmap.push_str("endbfchar\n");
+    }
+    cmap.push_str("endcmap\nCMapName currentdict /CMap defineresource pop\nend\nend\n");
+    cmap
+}
+
+/// 서브셋 글꼴 이름 앞의 대문자 6자 태그 (사용 glyph로부터 결정적으로 계산)
+fn subset_tag(glyphs: &BTreeSet<u16>, index: usize) -> String {
+    let mut hash: u32 = 2_166_136_261 ^ index as u32;
+    for gid in glyphs {
+        hash = (hash ^ *gid as u32).wrapping_mul(16_777_619);
+    }
+    (0..6)
+        .map(|i| (b'A' + ((hash >> (i * 5)) % 26) as u8) as char)
+        .collect()
+}
+
+/// 글자 → WinAnsiEncoding 코드 (없으면 `?`)
+fn win_ansi(ch: char) -> u8 {
+    let mut buf = [0u8; 4];
+    let (bytes, _, had_errors) = encoding_rs::WINDOWS_1252.encode(ch.encode_utf8(&mut buf));
+    match bytes.first() {
+        Some(&b) if !had_errors && bytes.len() == 1 => b,
+        _ => b'?',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canvas_font(bold: bool) -> CanvasFont {
+        CanvasFont {
+            family: "serif".to_string(),
+            size: 3.5,
+            bold,
+            italic: false,
+        }
+    }
+
+    #[test]
+    fn test_standard_font_fallback() {
+        let mut set = FontSet::new(Vec::new());
+        let runs = set.runs("A한", &canvas_font(true));
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].font, FontRef::Standard(1));
+        assert_eq!(runs[0].hex, "413F");
+        let mut pdf = PdfWriter::new();
+        let entries = set.write(&mut pdf);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].0, "S2");
+    }
+
+    #[test]
+    fn test_to_unicode_cmap_skips_notdef() {
+        let used: BTreeMap<u16, char> = [(0, 'x'), (5, '가'), (6, '😀')].into_iter().collect();
+        let cmap = to_unicode_cmap(&used);
+        assert!(cmap.contains("2 beginbfchar"));
+        assert!(cmap.contains("<0005> <AC00>"));
+        assert!(cmap.contains("<0006> <D83DDE00>"));
+        assert!(!cmap.contains("<0000> <0078>"));
+    }
+
+    #[test]
+    fn test_subset_tag_is_uppercase() {
+        let tag = subset_tag(&[1, 2, 3].into_iter().collect(), 0);
+        assert_eq!(tag.len(), 6);
+        assert!(tag.chars().all(|c| c.is_ascii_uppercase()));
+    }
+}
@@ -0,0 +1,300 @@
+use super::writer::{ObjectId, PdfWriter};
+/// 이미지 → PDF 이미지 XObject / Images to PDF image XObjects
+///
+/// 형식은 BinaryItem의 확장자가 아니라 파일 앞부분(매직 바이트)으로 판단합니다.
+/// - JPEG: 그대로 DCTDecode 스트림
+/// - PNG: 알파가 없으면 IDAT를 그대로 FlateDecode(PNG predictor), 알파가 있으면 풀어서 SMask 분리
+/// - BMP: 압축 없는 1/4/8/24/32비트를 RGB로 풀어서 FlateDecode
+///
+/// 그 밖의 형식(GIF, WMF 등)은 None을 돌려주며 호출자가 자리 표시 사각형을 그립니다.
+use crate::viewer::doc_utils;
+use flate2::read::ZlibDecoder;
+use std::io::Read;
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// 이미지 객체를 기록 (지원하지 않는 형식이면 None)
+pub(super) fn add_image(pdf: &mut PdfWriter, data: &[u8]) -> Option<ObjectId> {
+    if data.starts_with(&[0xFF, 0xD8]) {
+        add_jpeg(pdf, data)
+    } else if data.starts_with(PNG_SIGNATURE) {
+        add_png(pdf, data)
+    } else if data.starts_with(b"BM") {
+        add_bmp(pdf, data)
+    } else {
+        None
+    }
+}
+
+fn image_dict(width: u32, height: u32, color_space: &str, bits: u8) -> String {
+    format!(
+        "/Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace {} /BitsPerComponent {}",
+        width, height, color_space, bits
+    )
+}
+
+// ── JPEG ──
+
+fn add_jpeg(pdf: &mut PdfWriter, data: &[u8]) -> Option<ObjectId> {
+    let mut pos = 2;
+    let mut adobe = false;
+    while pos + 4 <= data.len() {
+        if data[pos] != 0xFF {
+            return None;
+        }
+        let marker = data[pos + 1];
+        if marker == 0xFF {
+            pos += 1;
+            continue;
+        }
+        if (0xD0..=0xD9).contains(&marker) || marker == 0x01 {
+            pos += 2;
+            continue;
+        }
+        let length = u16::from_be_bytes([data[pos + 2], data[pos + 3]]) as usize;
+        let segment = data.get(pos + 4..pos + 2 + length)?;
+        match marker {
+            0xEE if segment.starts_with(b"Adobe") => adobe = true,
+            0xC0..=0xC3 | 0xC5..=0xC7 | 0xC9..=0xCB | 0xCD..=0xCF => {
+                let height = u16::from_be_bytes([*segment.get(1)?, *segment.get(2)?]) as u32;
+                let width = u16::from_be_bytes([*segment.get(3)?, *segment.get(4)?]) as u32;
+                let color_space = match segment.get(5)? {
+                    1 => "/DeviceGray",
+                    3 => "/DeviceRGB",
+                    4 => "/DeviceCMYK",
+                    _ => return None,
+                };
+                if width == 0 || height == 0 {
+                    return None;
+                }
+                let mut dict = image_dict(width, height, color_space, 8);
+                // Adobe CMYK JPEG는 반전된 값으로 저장됨
+                if adobe && color_space == "/DeviceCMYK" {
+                    dict.push_str(" /Decode [1 0 1 0 1 0 1 0]");
+                }
+                dict.push_str(" /Filter /DCTDecode");
+                return Some(pdf.add_stream(&dict, data));
+            }
+            _ => {}
+        }
+        pos += 2 + length;
+    }
+    None
+}
+
+// ── PNG ──
+
+struct PngInfo {
+    width: u32,
+    height: u32,
+    bit_depth: u8,
+    color_type: u8,
+    palette: Vec<u8>,
+    idat: Vec<u8>,
+}
+
+fn parse_png(data: &[u8]) -> Option<PngInfo> {
+    let mut pos = PNG_SIGNATURE.len();
+    let mut info: Option<PngInfo> = None;
+    while pos + 8 <= data.len() {
+        let length = u32::from_be_bytes(data[pos..pos + 4].try_into().ok()?) as usize;
+        let kind = &data[pos + 4..pos + 8];
+        let body = data.get(pos + 8..pos + 8 + length)?;
+        match kind {
+            b"IHDR" if body.len() >= 13 => {
+                // 인터레이스 PNG는 지원하지 않음
+                if body[12] != 0 {
+                    return None;
+                }
+                info = Some(PngInfo {
+                    width: u32::from_be_bytes(body[0..4].try_into().ok()?),
+                    height: u32::from_be_bytes(body[4..8].try_into().ok()?),
+                    bit_depth: body[8],
+                    color_type: body[9],
+                    palette: Vec::new(),
+                    idat: Vec::new(),
+                });
+            }
+            b"PLTE" => info.as_mut()?.palette = body.to_vec(),
+            b"IDAT" => info.as_mut()?.idat.extend_from_slice(body),
+            b"IEND" => break,
+            _ => {}
+        }
+        pos += 12 + length;
+    }
+    info.filter(|i| i.width > 0 && i.height > 0 && !i.idat.is_empty())
+}
+
+fn add_png(pdf: &mut PdfWriter, data: &[u8]) -> Option<ObjectId> {
+    let png = parse_png(data)?;
+    let (colors, color_space) = match png.color_type {
+        0 | 4 => (1, "/DeviceGray".to_string()),
+        2 | 6 => (3, "/DeviceRGB".to_string()),
+        3 => {
+            let count = png.palette.len() / 3;
+            if count == 0 {
+                return None;
+            }
+            let hex: String = png.palette[..count * 3]
+                .iter()
+                .map(|b| format!("{:02X}", b))
+                .collect();
+            (1, format!("[/Indexed /DeviceRGB {} <{}>]", count - 1, hex))
+        }
+        _ => return None,
+    };
+
+    if png.color_type == 4 || png.color_type == 6 {
+        return add_png_with_alpha(pdf, &png, colors, &color_space);
+    }
+
+    // 알파가 없으면 압축된 IDAT를 그대로 사용 (PNG predictor)
+    let dict = format!(
+        "{} /Filter /FlateDecode /DecodeParms << /Predictor 15 /Colors {} /BitsPerComponent {} /Columns {} >>",
+        image_dict(png.width, png.height, &color_space, png.bit_depth),
+        colors,
+        png.bit_depth,
+        png.width
+    );
+    Some(pdf.add_stream(&dict, &png.idat))
+}
+
+/// 회색+알파 / RGBA PNG: 필터를 풀고 색과 알파(SMask)를 나눔 (8비트로 변환)
+fn add_png_with_alpha(
+    pdf: &mut PdfWriter,
+    png: &PngInfo,
+    colors: usize,
+    color_space: &str,
+) -> Option<ObjectId> {
+    let sample_bytes = (png.bit_depth as usize / 8).max(1);
+    let bpp = (colors + 1) * sample_bytes;
+    let pixels = unfilter(
+        &inflate(&png.idat)?,
+        png.width as usize,
+        png.height as usize,
+        bpp,
+    )?;
+
+    let count = png.width as usize * png.height as usize;
+    let mut color = Vec::with_capacity(count * colors);
+    let mut alpha = Vec::with_capacity(count);
+    for pixel in pixels.chunks_exact(bpp) {
+        // 16비트 샘플은 상위 바이트만 사용
+        for channel in 0..colors {
+            color.push(pixel[channel * sample_bytes]);
+        }
+        alpha.push(pixel[colors * sample_bytes]);
+    }
+
+    let smask = pdf.add_deflated(&image_dict(png.width, png.height, "/DeviceGray", 8), &alpha);
+    let dict = format!(
+        "{} /SMask {} 0 R",
+        image_dict(png.width, png.height, color_space, 8),
+        smask
+    );
+    Some(pdf.add_deflated(&dict, &color))
+}
+
+fn inflate(data: &[u8]) -> Option<Vec<u8>> {
+    let mut out = Vec::new();
+    ZlibDecoder::new(data).read_to_end(&mut out).ok()?;
+    Some(out)
+}
+
+/// PNG 행 필터(None/Sub/Up/Average/Paeth) 풀기
+fn unfilter(data: &[u8], width: usize, height: usize, bpp: usize) -> Option<Vec<u8>> {
+    let stride = width * bpp;
+    let mut out = vec![0u8; stride * height];
+    for row in 0..height {
+        let src = data.get(row * (stride + 1)..(row + 1) * (stride + 1))?;
+        let (filter, line) = (src[0], &src[1..]);
+        let (done, rest) = out.split_at_mut(row * stride);
+        let prev = if row > 0 {
+            &done[(row - 1) * stride..]
+        } else {
+            &[][..]
+        };
+        let cur = &mut rest[..stride];
+        for i in 0..stride {
+            let a = if i >= bpp { cur[i - bpp] } else { 0 };
+            let b = prev.get(i).copied().unwrap_or(0);
+            let c = if i >= bpp {
+                prev.get(i - bpp).copied().unwrap_or(0)
+            } else {
+                0
+            };
+            let predicted = match filter {
+                0 => 0,
+                1 => a,
+                2 => b,
+                3 => ((a as u16 + b as u16) / 2) as u8,
+                4 => paeth(a, b, c),
+                _ => return None,
+            };
+            cur[i] = line[i].wrapping_add(predicted);
+        }
+    }
+    Some(out)
+}
+
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = a as i16 + b as i16 - c as i16;
+    let (pa, pb, pc) = (
+        (p - a as i16).abs(),
+        (p - b as i16).abs(),
+        (p - c as i16).abs(),
+    );
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+// ── BMP ──
+
+fn add_bmp(pdf: &mut PdfWriter, data: &[u8]) -> Option<ObjectId> {
+    let (width, height, rgb) = doc_utils::decode_bmp(data)?;
+    Some(pdf.add_deflated(
+        &image_dict(width as u32, height as u32, "/DeviceRGB", 8),
+        &rgb,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unfilter_sub_and_up() {
+        // 2x2, 1바이트/픽셀: 첫 행 Sub, 둘째 행 Up
+        let data = [1, 10, 5, 2, 1, 1];
+        let out = unfilter(&data, 2, 2, 1).unwrap();
+        assert_eq!(out, vec![10, 15, 11, 16]);
+    }
+
+    #[test]
+    fn test_bmp_24bit() {
+        // 1x1 24비트 BMP (파란색 픽셀, 행은 4바이트 정렬)
+        let mut bmp = b"BM".to_vec();
+        bmp.extend_from_slice(&[0; 8]);
+        bmp.extend_from_slice(&54u32.to_le_bytes());
+        bmp.extend_from_slice(&40u32.to_le_bytes());
+        bmp.extend_from_slice(&1i32.to_le_bytes());
+        bmp.extend_from_slice(&1i32.to_le_bytes());
+        bmp.extend_from_slice(&1u16.to_le_bytes());
+        bmp.extend_from_slice(&24u16.to_le_bytes());
+        bmp.extend_from_slice(&[0; 24]);
+        bmp.extend_from_slice(&[255, 0, 0, 0]);
+        let mut pdf = PdfWriter::new();
+        assert!(add_image(&mut pdf, &bmp).is_some());
+    }
+
+    #[test]
+    fn test_unknown_format() {
+        let mut pdf = PdfWriter::new();
+        assert!(add_image(&mut pdf, b"GIF89a").is_none());
+    }
+}
@@ -0,0 +1,459 @@
+//! PDF export for HWP documents
+//! HWP 문서를 PDF로 내보내는 모듈
+//!
+//! `hwp_model::Document`를 Canvas display list([`crate::viewer::canvas`])와 같은 페이지 배치로
+//! 그린 뒤, 명령을 PDF 콘텐츠 스트림으로 옮겨 씁니다. 외부 프로그램(LibreOffice 등)이나
+//! 외부 PDF 라이브러리 없이 동작합니다.
+//!
+//! Renders the canvas display list of a `Document` into PDF content streams, without any
+//! external program or PDF library.
+//!
+//! # 글꼴 / Fonts
+//! [`PdfOptions::font_dir`]의 TrueType 글꼴(.ttf/.ttc, glyf 윤곽선)을 읽어, 실제로 쓴 글자만 남긴
+//! 서브셋을 임베딩합니다. 글자마다 CharShape 글꼴 이름이 맞는 글꼴을 먼저 쓰고, 그 글꼴에 없는
+//! 글자는 디렉터리의 다른 글꼴에서 찾습니다. 굵게/기울임 글꼴이 없으면 획 덧그리기/기울이기로 흉내 냅니다.
+//! 모든 글꼴에 ToUnicode를 넣어 텍스트 검색/복사가 가능합니다.
+//!
+//! 글꼴 디렉터리를 지정하지 않으면 표준 14 글꼴(Helvetica, 임베딩 안 함)을 쓰며
+//! 라틴 문자 밖의 글자는 `?`로 출력됩니다. 보존용(PDF/A 성격) 출력에는 글꼴 디렉터리가 필요합니다.
+//!
+//! # 출력 / Output
+//! 날짜 등 실행마다 바뀌는 값을 넣지 않으므로 같은 입력이면 같은 바이트가 나옵니다.
+//! 이미지는 JPEG/PNG/BMP를 넣고, 그 밖의 형식이나 `embed_images: false`이면 회색 자리 표시 사각형을 그립니다.
+mod fonts;
+mod image;
+mod summary;
+mod writer;
+
+pub use summary::{pdf_content_summary, pdf_styled_summary};
+
+use crate::viewer::canvas::{doc_to_canvas, CanvasPage, DrawCommand, PathOp};
+use fonts::FontSet;
+use hwp_model::document::Document;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+use writer::{num, reference, text_string, ObjectId, PdfWriter};
+
+/// 1mm = 72/25.4 pt
+const PT_PER_MM: f64 = 72.0 / 25.4;
+/// 페이지가 하나도 없을 때 넣는 빈 A4 페이지 크기 (mm)
+const A4_MM: (f64, f64) = (210.0, 297.0);
+/// 흉내 낸 기울임의 기울기 (약 12도)
+const FAKE_ITALIC_SKEW: f64 = 0.21;
+/// 흉내 낸 굵게의 덧그리는 선 굵기 (글자 크기 대비)
+const FAKE_BOLD_STROKE: f64 = 0.03;
+
+/// PDF 변환 옵션 / PDF conversion options
+#[derive(Debug, Clone)]
+pub struct PdfOptions {
+    /// TrueType 글꼴(.ttf/.ttc)이 있는 디렉터리 (None이면 임베딩하지 않는 표준 글꼴 사용)
+    /// Directory with TrueType fonts to embed (None = non-embedded standard fonts)
+    pub font_dir: Option<PathBuf>,
+    /// 이미지를 넣을지 여부 (false면 자리 표시 사각형) / Embed images (false = placeholders)
+    pub embed_images: bool,
+}
+
+impl Default for PdfOptions {
+    fn default() -> Self {
+        Self {
+            font_dir: None,
+            embed_images: true,
+        }
+    }
+}
+
+/// Document를 PDF 바이트로 변환 / Convert a Document to PDF bytes
+pub fn to_pdf(doc: &Document, options: &PdfOptions) -> Vec<u8> {
+    let mut pages = doc_to_canvas(doc);
+    if pages.is_empty() {
+        pages.push(CanvasPage {
+            width: A4_MM.0,
+            height: A4_MM.1,
+            commands: Vec::new(),
+        });
+    }
+
+    let mut pdf = PdfWriter::new();
+    let catalog = pdf.alloc();
+    let page_tree = pdf.alloc();
+    let resources = pdf.alloc();
+    let mut renderer = PageRenderer {
+        doc,
+        options,
+        fonts: FontSet::load(options.font_dir.as_deref()),
+        images: HashMap::new(),
+        image_entries: Vec::new(),
+    };
+
+    let mut kids = Vec::with_capacity(pages.len());
+    for page in &pages {
+        let content = renderer.render(page, &mut pdf);
+        let content_id = pdf.add_deflated("", content.as_bytes());
+        kids.push(pdf.add(format!(
+            "<< /Type /Page /Parent {} /MediaBox [0 0 {} {}] /Resources {} /Contents {} >>",
+            reference(page_tree),
+            num(page.width * PT_PER_MM),
+            num(page.height * PT_PER_MM),
+            reference(resources),
+            reference(content_id)
+        )));
+    }
+
+    let font_entries = renderer.fonts.write(&mut pdf);
+    let dict_entries = |entries: &[(String, ObjectId)]| -> String {
+        entries
+            .iter()
+            .map(|(name, id)| format!("/{} {}", name, reference(*id)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    pdf.set(
+        resources,
+        format!(
+            "<< /ProcSet [/PDF /Text /ImageB /ImageC /ImageI] /Font << {} >> /XObject << {} >> >>",
+            dict_entries(&font_entries),
+            dict_entries(&renderer.image_entries)
+        ),
+    );
+    pdf.set(
+        page_tree,
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            kids.iter()
+                .map(|id| reference(*id))
+                .collect::<Vec<_>>()
+                .join(" "),
+            kids.len()
+        ),
+    );
+
+    let meta = &doc.meta;
+    let metadata = pdf.add_stream(
+        "/Type /Metadata /Subtype /XML",
+        xmp_metadata(doc).as_bytes(),
+    );
+    let mut catalog_dict = format!(
+        "<< /Type /Catalog /Pages {} /Metadata {}",
+        reference(page_tree),
+        reference(metadata)
+    );
+    if let Some(language) = meta.language.as_deref().filter(|l| !l.is_empty()) {
+        let _ = write!(catalog_dict, " /Lang {}", text_string(language));
+    }
+    catalog_dict.push_str(" >>");
+    pdf.set(catalog, catalog_dict);
+
+    let mut info = String::from("<< /Producer (hwp-core)");
+    for (key, value) in [
+        ("Title", &meta.title),
+        ("Author", &meta.creator),
+        ("Subject", &meta.subject),
+        ("Keywords", &meta.keywords),
+    ] {
+        if let Some(value) = value.as_deref().filter(|v| !v.is_empty()) {
+            let _ = write!(info, " /{} {}", key, text_string(value));
+        }
+    }
+    info.push_str(" >>");
+    let info = pdf.add(info);
+
+    pdf.finish(catalog, info)
+}
+
+/// 페이지 명령 → 콘텐츠 스트림 / Canvas commands to a content stream
+struct PageRenderer<'a> {
+    doc: &'a Document,
+    options: &'a PdfOptions,
+    fonts: FontSet,
+    /// BinaryItem id → XObject 이름 (넣을 수 없는 이미지는 None)
+    images: HashMap<String, Option<String>>,
+    image_entries: Vec<(String, ObjectId)>,
+}
+
+impl PageRenderer<'_> {
+    fn render(&mut self, page: &CanvasPage, pdf: &mut PdfWriter) -> String {
+        let mut out = String::new();
+        // 좌표계: mm 단위, 원점은 페이지 좌상단, y는 아래로
+        let _ = writeln!(
+            out,
+            "{} 0 0 {} 0 {} cm",
+            num(PT_PER_MM),
+            num(-PT_PER_MM),
+            num(page.height * PT_PER_MM)
+        );
+        for command in &page.commands {
+            self.command(command, pdf, &mut out);
+        }
+        out
+    }
+
+    fn command(&mut self, command: &DrawCommand, pdf: &mut PdfWriter, out: &mut String) {
+        match command {
+            DrawCommand::Save => out.push_str("q\n"),
+            DrawCommand::Restore => out.push_str("Q\n"),
+            DrawCommand::Transform { a, b, c, d, e, f } => {
+                let _ = writeln!(
+                    out,
+                    "{} {} {} {} {} {} cm",
+                    num(*a),
+                    num(*b),
+                    num(*c),
+                    num(*d),
+                    num(*e),
+                    num(*f)
+                );
+            }
+            DrawCommand::Clip {
+                x,
+                y,
+                width,
+                height,
+            } => {
+                let _ = writeln!(out, "{} re W n", rect(*x, *y, *width, *height));
+            }
+            DrawCommand::FillRect {
+                x,
+                y,
+                width,
+                height,
+                color,
+            } => {
+                let _ = writeln!(
+                    out,
+                    "{} rg {} re f",
+                    rgb(color),
+                    rect(*x, *y, *width, *height)
+                );
+            }
+            DrawCommand::FillPath { path, color } => {
+                let _ = writeln!(out, "{} rg {} f", rgb(color), path_ops(path));
+            }
+            DrawCommand::StrokePath {
+                path,
+                color,
+                width,
+                dash,
+            } => {
+                let dash: Vec<String> = dash.iter().map(|v| num(*v)).collect();
+                let _ = writeln!(
+                    out,
+                    "{} RG {} w [{}] 0 d {} S",
+                    rgb(color),
+                    num(*width),
+                    dash.join(" "),
+                    path_ops(path)
+                );
+            }
+            DrawCommand::DrawText {
+                x,
+                y,
+                text,
+                font,
+                color,
+            } => {
+                let runs = self.fonts.runs(text, font);
+                let color = rgb(color);
+                let _ = writeln!(out, "BT {} rg {} RG", color, color);
+                let mut pen = *x;
+                for run in runs {
+                    let skew = if run.fake_italic {
+                        FAKE_ITALIC_SKEW
+                    } else {
+                        0.0
+                    };
+                    let _ = write!(
+                        out,
+                        "/{} {} Tf 1 0 {} -1 {} {} Tm ",
+                        run.font.resource_name(),
+                        num(font.size),
+                        num(skew),
+                        num(pen),
+                        num(*y)
+                    );
+                    if run.fake_bold {
+                        let _ = write!(out, "2 Tr {} w ", num(font.size * FAKE_BOLD_STROKE));
+                    } else {
+                        out.push_str("0 Tr ");
+                    }
+                    let _ = writeln!(out, "<{}> Tj", run.hex);
+                    pen += run.width / 1000.0 * font.size;
+                }
+                out.push_str("ET\n");
+            }
+            DrawCommand::DrawImage {
+                x,
+                y,
+                width,
+                height,
+                binary_id,
+                ..
+            } => match self.image(binary_id, pdf) {
+                Some(name) => {
+                    let _ = writeln!(
+                        out,
+                        "q {} 0 0 {} {} {} cm /{} Do Q",
+                        num(*width),
+                        num(-*height),
+                        num(*x),
+                        num(*y + *height),
+                        name
+                    );
+                }
+                None => placeholder(out, *x, *y, *width, *height),
+            },
+        }
+    }
+
+    /// 이미지 XObject 이름 (처음 쓸 때 기록)
+    fn image(&mut self, binary_id: &str, pdf: &mut PdfWriter) -> Option<String> {
+        if !self.options.embed_images {
+            return None;
+        }
+        if let Some(name) = self.images.get(binary_id) {
+            return name.clone();
+        }
+        let name = self
+            .doc
+            .binaries
+            .items
+            .iter()
+            .find(|item| item.id == binary_id)
+            .and_then(|item| image::add_image(pdf, &item.data))
+            .map(|id| {
+                let name = format!("Im{}", self.image_entries.len() + 1);
+                self.image_entries.push((name.clone(), id));
+                name
+            });
+        self.images.insert(binary_id.to_string(), name.clone());
+        name
+    }
+}
+
+/// 이미지 자리 표시: 회색 사각형 + 대각선
+fn placeholder(out: &mut String, x: f64, y: f64, width: f64, height: f64) {
+    let _ = writeln!(
+        out,
+        "q 0.9 g 0.6 G 0.2 w [] 0 d {r} re f {r} re S {} {} m {} {} l {} {} m {} {} l S Q",
+        num(x),
+        num(y),
+        num(x + width),
+        num(y + height),
+        num(x + width),
+        num(y),
+        num(x),
+        num(y + height),
+        r = rect(x, y, width, height)
+    );
+}
+
+fn rect(x: f64, y: f64, width: f64, height: f64) -> String {
+    format!("{} {} {} {}", num(x), num(y), num(width), num(height))
+}
+
+/// "#RRGGBB" → "r g b" (0~1)
+fn rgb(color: &str) -> String {
+    let hex = color.trim_start_matches('#');
+    let channel = |i: usize| {
+        hex.get(i..i + 2)
+            .and_then(|h| u8::from_str_radix(h, 16).ok())
+            .unwrap_or(0) as f64
+            / 255.0
+    };
+    format!(
+        "{} {} {}",
+        num(channel(0)),
+        num(channel(2)),
+        num(channel(4))
+    )
+}
+
+fn path_ops(path: &[PathOp]) -> String {
+    path.iter()
+        .map(|op| match op {
+            PathOp::MoveTo { x, y } => format!("{} {} m", num(*x), num(*y)),
+            PathOp::LineTo { x, y } => format!("{} {} l", num(*x), num(*y)),
+            PathOp::BezierTo {
+                cp1x,
+                cp1y,
+                cp2x,
+                cp2y,
+                x,
+                y,
+            } => format!(
+                "{} {} {} {} {} {} c",
+                num(*cp1x),
+                num(*cp1y),
+                num(*cp2x),
+                num(*cp2y),
+                num(*x),
+                num(*y)
+            ),
+            PathOp::Close => "h".to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 문서 정보 XMP 메타데이터 / XMP metadata packet
+fn xmp_metadata(doc: &Document) -> String {
+    let escape = |s: &str| {
+        s.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+    };
+    let mut dc = String::new();
+    if let Some(title) = doc.meta.title.as_deref().filter(|t| !t.is_empty()) {
+        let _ = write!(
+            dc,
+            "<dc:title><rdf:Alt><rdf:li xml:lang=\"x-default\">{}</rdf:li></rdf:Alt></dc:title>",
+            escape(title)
+        );
+    }
+    if let Some(creator) = doc.meta.creator.as_deref().filter(|c| !c.is_empty()) {
+        let _ = write!(
+            dc,
+            "<dc:creator><rdf:Seq><rdf:li>{}</rdf:li></rdf:Seq></dc:creator>",
+            escape(creator)
+        );
+    }
+    format!(
+        "<?xpacket begin=\"\u{FEFF}\" id=\"W5M0MpCehiHzreSzNTczkc9d\"?>\n\
+         <x:xmpmeta xmlns:x=\"adobe:ns:meta/\"><rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\">\
+         <rdf:Description rdf:about=\"\" xmlns:dc=\"http://purl.org/dc/elements/1.1/\" \
+         xmlns:pdf=\"http://ns.adobe.com/pdf/1.3/\"><dc:format>application/pdf</dc:format>{}\
+         <pdf:Producer>hwp-core</pdf:Producer></rdf:Description></rdf:RDF></x:xmpmeta>\n\
+         <?xpacket end=\"w\"?>",
+        dc
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rgb() {
+        assert_eq!(rgb("#FF0000"), "1 0 0");
+        assert_eq!(rgb("#000000"), "0 0 0");
+    }
+
+    #[test]
+    fn test_path_ops() {
+        let path = vec![
+            PathOp::MoveTo { x: 0.0, y: 1.5 },
+            PathOp::LineTo { x: 2.0, y: 3.0 },
+            PathOp::Close,
+        ];
+        assert_eq!(path_ops(&path), "0 1.5 m 2 3 l h");
+    }
+
+    #[test]
+    fn test_empty_document_is_valid_pdf() {
+        let pdf = to_pdf(&Document::default(), &PdfOptions::default());
+        assert!(pdf.starts_with(b"%PDF-1.7"));
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(text.contains("/Count 1"));
+        assert_eq!(to_pdf(&Document::default(), &PdfOptions::default()), pdf);
+    }
+}
@@ -0,0 +1,303 @@
+/// PDF에 들어갈 요소 요약 (스냅샷 테스트/디버깅용)
+/// Summary of the elements that go into the PDF (for snapshot tests and debugging)
+///
+/// 본문 문단 순서대로 `paragraph:` / `table:` / `image:` 한 줄씩 나열합니다.
+/// 문단 텍스트 뒤에 문단 안 개체(캡션/글상자 문단 포함), 그 뒤에 각주/미주 문단이 옵니다.
+/// 두 번째 구역부터는 구역 시작에 `page_break:` 줄을 넣습니다. 머리말/꼬리말은 제외합니다.
+use super::PdfOptions;
+use crate::viewer::doc_html::flat_text;
+use crate::viewer::doc_html::styles::hwpunit_to_mm;
+use hwp_model::control::Control;
+use hwp_model::document::Document;
+use hwp_model::paragraph::{Paragraph, RunContent};
+use hwp_model::section::PageDef;
+use hwp_model::shape::{ShapeCommon, ShapeObject};
+use hwp_model::table::Table;
+use hwp_model::types::{HAlign, Landscape, LineSpacingType};
+
+/// 문단 텍스트 요약 최대 글자 수
+const MAX_TEXT_CHARS: usize = 80;
+
+/// 요소 목록 (서식 없음) / Element list without formatting
+pub fn pdf_content_summary(doc: &Document, options: &PdfOptions) -> Vec<String> {
+    Summary {
+        doc,
+        options,
+        styled: false,
+        items: Vec::new(),
+    }
+    .run()
+}
+
+/// 요소 목록 + CharShape/ParaShape 요약 / Element list with style information
+pub fn pdf_styled_summary(doc: &Document, options: &PdfOptions) -> Vec<String> {
+    Summary {
+        doc,
+        options,
+        styled: true,
+        items: Vec::new(),
+    }
+    .run()
+}
+
+struct Summary<'a> {
+    doc: &'a Document,
+    options: &'a PdfOptions,
+    styled: bool,
+    items: Vec<String>,
+}
+
+impl Summary<'_> {
+    fn run(mut self) -> Vec<String> {
+        for (index, section) in self.doc.sections.iter().enumerate() {
+            if index > 0 {
+                self.items.push(format!(
+                    "page_break: new_page ({})",
+                    page_description(&section.definition.page)
+                ));
+            }
+            for para in &section.paragraphs {
+                self.paragraph(para);
+            }
+        }
+        if self.items.is_empty() {
+            self.items.push("(empty)".to_string());
+        }
+        self.items
+    }
+
+    fn paragraph(&mut self, para: &Paragraph) {
+        let text = paragraph_text(para);
+        if !text.is_empty() {
+            let style = if self.styled {
+                format!("{} ", self.paragraph_style(para))
+            } else {
+                String::new()
+            };
+            self.items
+                .push(format!("paragraph: {}{}", style, truncate(&text)));
+        }
+
+        let mut notes: Vec<&Paragraph> = Vec::new();
+        for run in &para.runs {
+            for content in &run.contents {
+                match content {
+                    RunContent::Object(object) => self.object(object),
+                    RunContent::Control(Control::FootNote(note))
+                    | RunContent::Control(Control::EndNote(note)) => {
+                        notes.extend(&note.content.paragraphs)
+                    }
+                    _ => {}
+                }
+            }
+        }
+        for note in notes {
+            self.paragraph(note);
+        }
+    }
+
+    fn object(&mut self, object: &ShapeObject) {
+        let draw_text = match object {
+            ShapeObject::Rectangle(o) => o.draw_text.as_ref(),
+            ShapeObject::Ellipse(o) => o.draw_text.as_ref(),
+            ShapeObject::Polygon(o) => o.draw_text.as_ref(),
+            ShapeObject::Curve(o) => o.draw_text.as_ref(),
+            _ => None,
+        };
+        for para in draw_text.iter().flat_map(|text| &text.paragraphs) {
+            self.paragraph(para);
+        }
+
+        match object {
+            ShapeObject::Picture(pic) => {
+                let item = if self.options.embed_images {
+                    format!("image: bindata_id={}", pic.img.binary_item_id)
+                } else {
+                    "image: [placeholder]".to_string()
+                };
+                self.items.push(item);
+            }
+            ShapeObject::Table(table) => {
+                let mut item = format!("table: {}x{}", table.row_count, table.col_count);
+                if self.styled {
+                    item.push_str(&format!(" (cols: {})", column_widths(table)));
+                }
+                self.items.push(item);
+            }
+            ShapeObject::Container(container) => {
+                for child in &container.children {
+                    self.object(child);
+                }
+            }
+            _ => {}
+        }
+
+        if let Some(caption) = common(object).and_then(|c| c.caption.as_ref()) {
+            for para in &caption.content.paragraphs {
+                self.paragraph(para);
+            }
+        }
+    }
+
+    /// `[cs36 15pt rgb(0,0,0)] [ps: justify ind=-16.8mm ls=160%]`
+    fn paragraph_style(&self, para: &Paragraph) -> String {
+        let resources = &self.doc.resources;
+        let cs_id = para.runs.first().map(|run| run.char_shape_id).unwrap_or(0);
+        let char_style = match resources.char_shapes.get(cs_id as usize) {
+            Some(cs) => {
+                let mut flags = String::new();
+                for (on, name) in [
+                    (cs.bold, "bold"),
+                    (cs.italic, "italic"),
+                    (cs.underline.is_some(), "underline"),
+                    (cs.strikeout.is_some(), "strikeout"),
+                ] {
+                    if on {
+                        flags.push_str(name);
+                        flags.push(' ');
+                    }
+                }
+                let color = cs.text_color.unwrap_or(0);
+                format!(
+                    "[cs{} {}{}pt rgb({},{},{})]",
+                    cs_id,
+                    flags,
+                    cs.height as f64 / 100.0,
+                    (color >> 16) & 0xFF,
+                    (color >> 8) & 0xFF,
+                    color & 0xFF
+                )
+            }
+            None => format!("[cs{}]", cs_id),
+        };
+
+        let para_style = match resources.para_shapes.get(para.para_shape_id as usize) {
+            Some(ps) => {
+                let mut parts = vec![align_name(&ps.align.horizontal).to_string()];
+                for (name, value) in [
+                    ("ind", ps.margin.indent.value),
+                    ("lm", ps.margin.left.value),
+                    ("rm", ps.margin.right.value),
+                ] {
+                    if value != 0 {
+                        parts.push(format!("{}={:.1}mm", name, hwpunit_to_mm(value)));
+                    }
+                }
+                let spacing = &ps.line_spacing;
+                parts.push(match spacing.spacing_type {
+                    LineSpacingType::Percent => format!("ls={}%", spacing.value),
+                    _ => format!("ls={:.1}mm", hwpunit_to_mm(spacing.value)),
+                });
+                format!("[ps: {}]", parts.join(" "))
+            }
+            None => format!("[ps{}]", para.para_shape_id),
+        };
+        format!("{} {}", char_style, para_style)
+    }
+}
+
+/// 문단의 표시 텍스트 (탭은 그대로, 줄바꿈은 공백, 그 밖의 제어 문자는 제외)
+fn paragraph_text(para: &Paragraph) -> String {
+    flat_text::extract_flat_text(para)
+        .text
+        .chars()
+        .filter_map(|c| match c {
+            '\t' => Some('\t'),
+            '\n' => Some(' '),
+            c if c.is_control() => None,
+            c => Some(c),
+        })
+        .collect()
+}
+
+/// `210x297mm margins: 30/30/35/30` (좌/우/위+머리말/아래+꼬리말)
+fn page_description(page: &PageDef) -> String {
+    let mm = |value: i32| hwpunit_to_mm(value).round();
+    let (width, height) = match page.landscape {
+        Landscape::Landscape => (page.height, page.width),
+        _ => (page.width, page.height),
+    };
+    let margin = &page.margin;
+    format!(
+        "{}x{}mm margins: {}/{}/{}/{}",
+        mm(width),
+        mm(height),
+        mm(margin.left),
+        mm(margin.right),
+        mm(margin.top + margin.header),
+        mm(margin.bottom + margin.footer)
+    )
+}
+
+/// 개체 공통 속성 / Common properties of an object
+fn common(object: &ShapeObject) -> Option<&ShapeCommon> {
+    Some(match object {
+        ShapeObject::Table(o) => &o.common,
+        ShapeObject::Picture(o) => &o.common,
+        ShapeObject::Rectangle(o) => &o.common,
+        ShapeObject::Ellipse(o) => &o.common,
+        ShapeObject::Polygon(o) => &o.common,
+        ShapeObject::Curve(o) => &o.common,
+        ShapeObject::Container(o) => &o.common,
+        ShapeObject::Ole(o) => &o.common,
+        ShapeObject::Equation(o) => &o.common,
+        _ => return None,
+    })
+}
+
+fn truncate(text: &str) -> String {
+    if text.chars().count() > MAX_TEXT_CHARS {
+        let head: String = text.chars().take(MAX_TEXT_CHARS).collect();
+        format!("{}...", head)
+    } else {
+        text.to_string()
+    }
+}
+
+fn align_name(align: &HAlign) -> &'static str {
+    match align {
+        HAlign::Justify => "justify",
+        HAlign::Left => "left",
+        HAlign::Right => "right",
+        HAlign::Center => "center",
+        HAlign::Distribute => "distribute",
+        HAlign::DistributeSpace => "divide",
+        _ => "left",
+    }
+}
+
+/// 열 너비 (mm, 병합되지 않은 셀 기준, 알 수 없으면 0)
+fn column_widths(table: &Table) -> String {
+    let mut widths = vec![0.0f64; table.col_count as usize];
+    for cell in table.rows.iter().flat_map(|row| &row.cells) {
+        if cell.col_span <= 1 {
+            if let Some(width) = widths.get_mut(cell.col as usize) {
+                *width = width.max(hwpunit_to_mm(cell.width));
+            }
+        }
+    }
+    widths
+        .iter()
+        .map(|w| format!("{:.0}mm", w))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate() {
+        let long = "가".repeat(MAX_TEXT_CHARS + 1);
+        assert_eq!(truncate(&long).chars().count(), MAX_TEXT_CHARS + 3);
+        assert_eq!(truncate("abc"), "abc");
+    }
+
+    #[test]
+    fn test_empty_document_summary() {
+        let doc = Document::default();
+        let options = PdfOptions::default();
+        assert_eq!(pdf_content_summary(&doc, &options), vec!["(empty)"]);
+    }
+}
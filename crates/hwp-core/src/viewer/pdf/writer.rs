@@ -0,0 +1,212 @@
+/// PDF 객체 직렬화 / PDF object serialization
+///
+/// 객체 번호를 먼저 예약(`alloc`)하고 나중에 내용을 채울 수 있어,
+/// 페이지를 그리는 동안 글꼴/이미지 객체를 참조만 해두고 마지막에 기록할 수 있습니다.
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use std::io::Write;
+
+/// PDF 객체 번호 / PDF object number
+pub(super) type ObjectId = u32;
+
+/// PDF 파일 작성기 / PDF file writer
+pub(super) struct PdfWriter {
+    /// 객체 번호(1부터) → 직렬화된 객체 본문
+    objects: Vec<Option<Vec<u8>>>,
+}
+
+impl PdfWriter {
+    pub(super) fn new() -> Self {
+        Self {
+            objects: Vec::new(),
+        }
+    }
+
+    /// 객체 번호 예약 / Reserve an object number
+    pub(super) fn alloc(&mut self) -> ObjectId {
+        self.objects.push(None);
+        self.objects.len() as ObjectId
+    }
+
+    /// 예약한 객체에 사전/배열 등 본문 기록
+    pub(super) fn set(&mut self, id: ObjectId, body: impl Into<Vec<u8>>) {
+        self.objects[id as usize - 1] = Some(body.into());
+    }
+
+    /// 새 객체를 만들고 본문 기록 / Allocate and write an object
+    pub(super) fn add(&mut self, body: impl Into<Vec<u8>>) -> ObjectId {
+        let id = self.alloc();
+        self.set(id, body);
+        id
+    }
+
+    /// 스트림 객체 기록 — `dict`는 `<<`, `>>` 안쪽 항목 (Length는 자동)
+    pub(super) fn set_stream(&mut self, id: ObjectId, dict: &str, data: &[u8]) {
+        let mut body = format!("<< {} /Length {} >>\nstream\n", dict, data.len()).into_bytes();
+        body.extend_from_slice(data);
+        body.extend_from_slice(b"\nendstream");
+        self.set(id, body);
+    }
+
+    pub(super) fn add_stream(&mut self, dict: &str, data: &[u8]) -> ObjectId {
+        let id = self.alloc();
+        self.set_stream(id, dict, data);
+        id
+    }
+
+    /// FlateDecode로 압축한 스트림 객체 기록
+    pub(super) fn add_deflated(&mut self, dict: &str, data: &[u8]) -> ObjectId {
+        let id = self.alloc();
+        self.set_deflated(id, dict, data);
+        id
+    }
+
+    pub(super) fn set_deflated(&mut self, id: ObjectId, dict: &str, data: &[u8]) {
+        let dict = if dict.is_empty() {
+            "/Filter /FlateDecode".to_string()
+        } else {
+            format!("{} /Filter /FlateDecode", dict)
+        };
+        self.set_stream(id, &dict, &deflate(data));
+    }
+
+    /// 파일 완성: 헤더, 객체, xref, trailer
+    /// 파일 ID는 내용에서 계산하므로 같은 입력이면 같은 바이트가 나옴
+    pub(super) fn finish(self, root: ObjectId, info: ObjectId) -> Vec<u8> {
+        // 이진 데이터가 있음을 알리는 주석 (ASCII 밖 바이트 4개)
+        let mut out: Vec<u8> = b"%PDF-1.7\n%\xE2\xE3\xCF\xD3\n".to_vec();
+        let mut offsets = Vec::with_capacity(self.objects.len());
+        for (index, body) in self.objects.iter().enumerate() {
+            offsets.push(out.len());
+            out.extend_from_slice(format!("{} 0 obj\n", index + 1).as_bytes());
+            // 예약만 하고 채우지 않은 객체는 null로 둠
+            out.extend_from_slice(body.as_deref().unwrap_or(b"null"));
+            out.extend_from_slice(b"\nendobj\n");
+        }
+
+        let file_id = file_id(&out);
+        let xref = out.len();
+        out.extend_from_slice(format!("xref\n0 {}\n", offsets.len() + 1).as_bytes());
+        out.extend_from_slice(b"0000000000 65535 f \n");
+        for offset in offsets {
+            out.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+        }
+        out.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root {} 0 R /Info {} 0 R /ID [<{}> <{}>] >>\nstartxref\n{}\n%%EOF\n",
+                self.objects.len() + 1,
+                root,
+                info,
+                file_id,
+                file_id,
+                xref
+            )
+            .as_bytes(),
+        );
+        out
+    }
+}
+
+/// 내용 해시로 만든 16바이트 파일 ID (16진) / Content-derived file identifier
+fn file_id(data: &[u8]) -> String {
+    // FNV-1a 64비트 두 개 (서로 다른 시작값)
+    let hash = |seed: u64| {
+        data.iter().fold(seed, |h, &b| {
+            (h ^ b as u64).wrapping_mul(0x0000_0100_0000_01B3)
+        })
+    };
+    format!(
+        "{:016X}{:016X}",
+        hash(0xCBF2_9CE4_8422_2325),
+        hash(0x6C62_272E_07BB_0142)
+    )
+}
+
+/// zlib 압축 / zlib (FlateDecode) compression
+pub(super) fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    // Vec<u8>에 쓰는 것은 실패하지 않음
+    let _ = encoder.write_all(data);
+    encoder.finish().unwrap_or_default()
+}
+
+/// 객체 참조 문자열 / Indirect reference
+pub(super) fn reference(id: ObjectId) -> String {
+    format!("{} 0 R", id)
+}
+
+/// PDF 문자열 리터럴 — 비 ASCII는 UTF-16BE(BOM) 16진 문자열
+pub(super) fn text_string(text: &str) -> String {
+    if text.chars().all(|c| c.is_ascii() && !c.is_ascii_control()) {
+        let escaped = text
+            .replace('\\', "\\\\")
+            .replace('(', "\\(")
+            .replace(')', "\\)");
+        format!("({})", escaped)
+    } else {
+        let mut hex = String::from("<FEFF");
+        for unit in text.encode_utf16() {
+            hex.push_str(&format!("{:04X}", unit));
+        }
+        hex.push('>');
+        hex
+    }
+}
+
+/// PDF 이름 객체에 쓸 수 있도록 정리 (공백/구분자 제거)
+pub(super) fn name(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_ascii_graphic() && !"()<>[]{}/%#".contains(*c))
+        .collect()
+}
+
+/// 숫자를 짧게 출력 (소수점 이하 최대 4자리, 끝의 0 제거)
+pub(super) fn num(value: f64) -> String {
+    if !value.is_finite() {
+        return "0".to_string();
+    }
+    let mut s = format!("{:.4}", value);
+    if s.contains('.') {
+        while s.ends_with('0') {
+            s.pop();
+        }
+        if s.ends_with('.') {
+            s.pop();
+        }
+    }
+    if s == "-0" {
+        s = "0".to_string();
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_num_trims_zeros() {
+        assert_eq!(num(1.0), "1");
+        assert_eq!(num(0.25), "0.25");
+        assert_eq!(num(-0.00001), "0");
+        assert_eq!(num(2.834_645_669), "2.8346");
+    }
+
+    #[test]
+    fn test_text_string() {
+        assert_eq!(text_string("a(b)"), "(a\\(b\\))");
+        assert_eq!(text_string("한"), "<FEFFD55C>");
+    }
+
+    #[test]
+    fn test_xref_offsets() {
+        let mut w = PdfWriter::new();
+        let root = w.add("<< /Type /Catalog >>");
+        let info = w.add("<< >>");
+        let out = w.finish(root, info);
+        let start = out.windows(7).position(|w| w == b"1 0 obj").unwrap();
+        let text = String::from_utf8_lossy(&out);
+        assert!(text.contains(&format!("{:010} 00000 n", start)));
+        assert!(text.ends_with("%%EOF\n"));
+    }
+}
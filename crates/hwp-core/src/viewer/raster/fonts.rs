@@ -0,0 +1,262 @@
+/// 래스터라이저용 글꼴 모음과 글자 윤곽선 / Fonts and glyph outlines for the rasterizer
+///
+/// 글꼴 선택 규칙은 PDF 내보내기와 같습니다: CharShape 글꼴 이름이 맞는 글꼴을 먼저 쓰고,
+/// 그 글꼴에 없는 글자는 디렉터리의 다른 글꼴에서 찾으며, 어디에도 없으면 .notdef를 그립니다.
+use crate::font::{normalize_family, read_font_files};
+use crate::viewer::canvas::CanvasFont;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use tiny_skia::{Path as SkPath, PathBuilder};
+use ttf_parser::{Face, GlyphId, OutlineBuilder};
+
+/// 글꼴 파일의 한 글꼴(face) / One face of a font file
+struct FontFace {
+    data: Arc<Vec<u8>>,
+    index: u32,
+    /// 정규화한 글꼴 이름 (모든 언어의 family / typographic family)
+    families: Vec<String>,
+    bold: bool,
+    italic: bool,
+}
+
+/// 디렉터리에서 읽은 글꼴 모음 / Fonts loaded from a directory
+pub(super) struct FontLibrary {
+    faces: Vec<FontFace>,
+}
+
+impl FontLibrary {
+    /// 디렉터리의 글꼴을 파일 이름 순으로 읽음 (하위 디렉터리 제외)
+    pub(super) fn load(dir: Option<&Path>) -> Self {
+        let mut faces = Vec::new();
+        for data in dir.map(read_font_files).unwrap_or_default() {
+            let data = Arc::new(data);
+            let count = ttf_parser::fonts_in_collection(&data).unwrap_or(1);
+            for index in 0..count {
+                let Ok(face) = Face::parse(&data, index) else {
+                    continue;
+                };
+                let mut families: Vec<String> = face
+                    .names()
+                    .into_iter()
+                    .filter(|name| {
+                        name.name_id == ttf_parser::name_id::FAMILY
+                            || name.name_id == ttf_parser::name_id::TYPOGRAPHIC_FAMILY
+                    })
+                    .filter_map(|name| name.to_string())
+                    .map(|name| normalize_family(&name))
+                    .collect();
+                families.sort();
+                families.dedup();
+                faces.push(FontFace {
+                    data: Arc::clone(&data),
+                    index,
+                    families,
+                    bold: face.is_bold(),
+                    italic: face.is_italic() || face.is_oblique(),
+                });
+            }
+        }
+        Self { faces }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.faces.is_empty()
+    }
+}
+
+/// 한 글자의 위치 / A positioned glyph
+pub(super) struct PlacedGlyph {
+    /// 글꼴 번호 / Face index
+    pub(super) face: usize,
+    pub(super) glyph: u16,
+    /// 글자 시작 x (글자 크기 단위, 1 = 1em) / Pen position in em
+    pub(super) x: f32,
+    pub(super) fake_bold: bool,
+    pub(super) fake_italic: bool,
+}
+
+/// 페이지를 그리는 동안 쓰는 글꼴/윤곽선 캐시 / Per-render glyph cache
+pub(super) struct GlyphCache<'a> {
+    library: &'a FontLibrary,
+    /// 글꼴별로 처음 쓸 때 파싱한 Face
+    faces: Vec<Option<Face<'a>>>,
+    /// (글꼴, glyph) → 윤곽선 (글꼴 단위, y 위쪽)
+    outlines: HashMap<(usize, u16), Option<SkPath>>,
+    /// (정규화한 이름, 굵게, 기울임) → 이름이 맞는 글꼴
+    primary_cache: HashMap<(String, bool, bool), Option<usize>>,
+}
+
+impl<'a> GlyphCache<'a> {
+    pub(super) fn new(library: &'a FontLibrary) -> Self {
+        Self {
+            faces: library
+                .faces
+                .iter()
+                .map(|face| Face::parse(&face.data, face.index).ok())
+                .collect(),
+            library,
+            outlines: HashMap::new(),
+            primary_cache: HashMap::new(),
+        }
+    }
+
+    /// 글자마다 글꼴/glyph/위치를 정함 / Choose a face and glyph for every character
+    pub(super) fn layout(&mut self, text: &str, font: &CanvasFont) -> Vec<PlacedGlyph> {
+        let mut placed = Vec::new();
+        if self.library.is_empty() {
+            return placed;
+        }
+        let primary = self.primary(&font.family, font.bold, font.italic);
+        let mut pen = 0.0f32;
+        for ch in text.chars() {
+            let (index, gid) = self.glyph_for(ch, primary, font.bold, font.italic);
+            let style = &self.library.faces[index];
+            placed.push(PlacedGlyph {
+                face: index,
+                glyph: gid,
+                x: pen,
+                fake_bold: font.bold && !style.bold,
+                fake_italic: font.italic && !style.italic,
+            });
+            pen += self.advance(index, gid);
+        }
+        placed
+    }
+
+    /// 글꼴 단위 → em 배율 / Scale from font units to em
+    pub(super) fn units_per_em(&self, face: usize) -> f32 {
+        self.faces[face]
+            .as_ref()
+            .map(|f| f.units_per_em() as f32)
+            .filter(|upem| *upem > 0.0)
+            .unwrap_or(1000.0)
+    }
+
+    /// 윤곽선 (글꼴 단위, y 위쪽) — 공백 등 윤곽선이 없으면 None
+    pub(super) fn outline(&mut self, face: usize, glyph: u16) -> Option<&SkPath> {
+        let faces = &self.faces;
+        self.outlines
+            .entry((face, glyph))
+            .or_insert_with(|| {
+                let mut builder = OutlineToPath(PathBuilder::new());
+                faces[face]
+                    .as_ref()?
+                    .outline_glyph(GlyphId(glyph), &mut builder)?;
+                builder.0.finish()
+            })
+            .as_ref()
+    }
+
+    /// 글자 너비 (em) / Advance width in em
+    fn advance(&self, face: usize, glyph: u16) -> f32 {
+        self.faces[face]
+            .as_ref()
+            .and_then(|f| f.glyph_hor_advance(GlyphId(glyph)))
+            .map_or(0.0, |advance| advance as f32 / self.units_per_em(face))
+    }
+
+    fn glyph(&self, face: usize, ch: char) -> Option<u16> {
+        self.faces[face]
+            .as_ref()?
+            .glyph_index(ch)
+            .map(|gid| gid.0)
+            .filter(|gid| *gid != 0)
+    }
+
+    /// 이름이 맞는 글꼴 중 스타일이 가장 가까운 것
+    fn primary(&mut self, family: &str, bold: bool, italic: bool) -> Option<usize> {
+        let key = (normalize_family(family), bold, italic);
+        if let Some(cached) = self.primary_cache.get(&key) {
+            return *cached;
+        }
+        let found = self.best(|_, face| face.families.contains(&key.0), bold, italic);
+        self.primary_cache.insert(key, found);
+        found
+    }
+
+    /// 조건을 만족하는 글꼴 중 스타일 점수가 가장 높은 (동점이면 앞선) 글꼴
+    fn best(
+        &self,
+        filter: impl Fn(usize, &FontFace) -> bool,
+        bold: bool,
+        italic: bool,
+    ) -> Option<usize> {
+        let mut best: Option<(usize, u8)> = None;
+        for (index, face) in self.library.faces.iter().enumerate() {
+            if self.faces[index].is_none() || !filter(index, face) {
+                continue;
+            }
+            let score = (face.bold == bold) as u8 * 2 + (face.italic == italic) as u8;
+            if best.map_or(true, |(_, s)| score > s) {
+                best = Some((index, score));
+            }
+        }
+        best.map(|(index, _)| index)
+    }
+
+    /// 글자를 그릴 글꼴과 glyph — 어떤 글꼴에도 없으면 .notdef(0)
+    fn glyph_for(
+        &self,
+        ch: char,
+        primary: Option<usize>,
+        bold: bool,
+        italic: bool,
+    ) -> (usize, u16) {
+        if let Some(index) = primary {
+            if let Some(gid) = self.glyph(index, ch) {
+                return (index, gid);
+            }
+        }
+        if let Some(index) = self.best(|index, _| self.glyph(index, ch).is_some(), bold, italic) {
+            return (index, self.glyph(index, ch).unwrap_or(0));
+        }
+        let index = primary
+            .or_else(|| self.best(|_, _| true, bold, italic))
+            .unwrap_or(0);
+        (index, 0)
+    }
+}
+
+/// ttf-parser 윤곽선 → tiny-skia 경로
+struct OutlineToPath(PathBuilder);
+
+impl OutlineBuilder for OutlineToPath {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.0.move_to(x, y);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.0.line_to(x, y);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.0.quad_to(x1, y1, x, y);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.0.cubic_to(x1, y1, x2, y2, x, y);
+    }
+
+    fn close(&mut self) {
+        self.0.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_library_places_nothing() {
+        let library = FontLibrary::load(None);
+        let mut cache = GlyphCache::new(&library);
+        let font = CanvasFont {
+            family: "Any".to_string(),
+            size: 3.5,
+            bold: false,
+            italic: false,
+        };
+        assert!(cache.layout("abc", &font).is_empty());
+    }
+}
@@ -0,0 +1,539 @@
+//! CPU rasterizer for HWP documents
+//! HWP 문서 페이지를 브라우저 없이 PNG로 그리는 모듈 (`raster` 기능)
+//!
+//! `hwp_model::Document`를 Canvas display list([`crate::viewer::canvas`], doc_html 레이아웃
+//! 모드와 같은 페이지 배치)로 만든 뒤, 명령을 CPU에서 픽셀로 그립니다. 문서에 들어 있는
+//! `PrvImage` 미리보기가 없거나 오래된 경우에도 실제 내용으로 썸네일을 만들 수 있습니다.
+//!
+//! Replays the canvas display list of a `Document` on the CPU and encodes the page as PNG,
+//! so thumbnails do not depend on the embedded (often missing or stale) `PrvImage` stream.
+//!
+//! # 글꼴 / Fonts
+//! [`RasterOptions::font_dir`]의 TrueType/OpenType 글꼴로 글자 윤곽선을 그립니다. 글꼴 선택은
+//! PDF 내보내기와 같고, 굵게/기울임 글꼴이 없으면 획 덧그리기/기울이기로 흉내 냅니다.
+//! 글꼴 디렉터리가 없거나 비어 있으면 글자는 그리지 않습니다.
+//!
+//! # 이미지 / Images
+//! PNG/JPEG/BMP를 그리고, 그 밖의 형식(GIF, WMF 등)은 회색 자리 표시 사각형으로 그립니다.
+mod fonts;
+
+use crate::viewer::canvas::{doc_to_canvas, CanvasFont, CanvasPage, DrawCommand, PathOp};
+use crate::viewer::doc_utils;
+use fonts::{FontLibrary, GlyphCache};
+use hwp_model::document::Document;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tiny_skia::{
+    Color, FillRule, FilterQuality, Mask, Paint, Path, PathBuilder, Pattern, Pixmap, Rect,
+    SpreadMode, Stroke, StrokeDash, Transform,
+};
+
+/// 1인치 = 25.4mm
+const MM_PER_INCH: f32 = 25.4;
+/// 흉내 낸 기울임의 기울기 (약 12도)
+const FAKE_ITALIC_SKEW: f32 = 0.21;
+/// 흉내 낸 굵게의 덧그리는 선 굵기 (글자 크기 대비)
+const FAKE_BOLD_STROKE: f32 = 0.03;
+/// 한 변의 최대 픽셀 수 (지나치게 큰 DPI로 메모리를 다 쓰지 않도록)
+const MAX_DIMENSION: f32 = 20_000.0;
+
+/// 래스터 변환 옵션 / Raster conversion options
+#[derive(Debug, Clone)]
+pub struct RasterOptions {
+    /// 해상도 (인치당 픽셀) / Resolution in pixels per inch
+    pub dpi: f32,
+    /// TrueType/OpenType 글꼴 디렉터리 (None이면 글자를 그리지 않음)
+    /// Directory with TrueType/OpenType fonts (None = text is not drawn)
+    pub font_dir: Option<PathBuf>,
+    /// 이미지를 그릴지 여부 (false면 자리 표시 사각형) / Draw images (false = placeholders)
+    pub embed_images: bool,
+}
+
+impl Default for RasterOptions {
+    fn default() -> Self {
+        Self {
+            dpi: 96.0,
+            font_dir: None,
+            embed_images: true,
+        }
+    }
+}
+
+/// 글꼴을 한 번 읽어 두고 여러 문서/페이지를 그리는 래스터라이저
+/// Rasterizer that loads fonts once and renders many documents
+pub struct Rasterizer {
+    options: RasterOptions,
+    fonts: FontLibrary,
+}
+
+impl Rasterizer {
+    /// 옵션의 글꼴 디렉터리를 읽어 래스터라이저 생성 / Create a rasterizer and load fonts
+    pub fn new(options: RasterOptions) -> Self {
+        let fonts = FontLibrary::load(options.font_dir.as_deref());
+        Self { options, fonts }
+    }
+
+    /// `page_index`(0부터) 페이지를 PNG로 — 페이지가 없으면 None
+    /// Render one page (0-based) to PNG; None if the page does not exist
+    pub fn render_page(&self, doc: &Document, page_index: usize) -> Option<Vec<u8>> {
+        let pages = doc_to_canvas(doc);
+        self.render(doc, pages.get(page_index)?)
+    }
+
+    /// 모든 페이지를 PNG로 / Render every page to PNG
+    pub fn render_pages(&self, doc: &Document) -> Vec<Vec<u8>> {
+        doc_to_canvas(doc)
+            .iter()
+            .filter_map(|page| self.render(doc, page))
+            .collect()
+    }
+
+    fn render(&self, doc: &Document, page: &CanvasPage) -> Option<Vec<u8>> {
+        let pixmap = self.render_pixmap(doc, page)?;
+        pixmap.encode_png().ok()
+    }
+
+    fn render_pixmap(&self, doc: &Document, page: &CanvasPage) -> Option<Pixmap> {
+        let scale = self.options.dpi / MM_PER_INCH;
+        if !scale.is_finite() || scale <= 0.0 {
+            return None;
+        }
+        let width = (page.width as f32 * scale).ceil().clamp(1.0, MAX_DIMENSION);
+        let height = (page.height as f32 * scale)
+            .ceil()
+            .clamp(1.0, MAX_DIMENSION);
+        let mut pixmap = Pixmap::new(width as u32, height as u32)?;
+        pixmap.fill(Color::WHITE);
+
+        let mut painter = Painter {
+            doc,
+            options: &self.options,
+            pixmap,
+            glyphs: GlyphCache::new(&self.fonts),
+            images: HashMap::new(),
+            state: State {
+                transform: Transform::from_scale(scale, scale),
+                clip: None,
+            },
+            stack: Vec::new(),
+        };
+        for command in &page.commands {
+            painter.command(command);
+        }
+        Some(painter.pixmap)
+    }
+}
+
+/// 한 페이지를 한 번 그리는 데 쓰는 편의 함수 / Render a single page to PNG
+///
+/// 여러 문서를 그릴 때는 글꼴을 다시 읽지 않도록 [`Rasterizer`]를 재사용하세요.
+pub fn doc_page_to_png(
+    doc: &Document,
+    page_index: usize,
+    options: &RasterOptions,
+) -> Option<Vec<u8>> {
+    Rasterizer::new(options.clone()).render_page(doc, page_index)
+}
+
+/// 그리기 상태 (`save`/`restore` 대상) / Graphics state
+#[derive(Clone)]
+struct State {
+    transform: Transform,
+    clip: Option<Mask>,
+}
+
+struct Painter<'a> {
+    doc: &'a Document,
+    options: &'a RasterOptions,
+    pixmap: Pixmap,
+    glyphs: GlyphCache<'a>,
+    /// BinaryItem id → 디코딩한 이미지 (그릴 수 없는 이미지는 None)
+    images: HashMap<String, Option<Pixmap>>,
+    state: State,
+    stack: Vec<State>,
+}
+
+impl Painter<'_> {
+    fn command(&mut self, command: &DrawCommand) {
+        match command {
+            DrawCommand::Save => self.stack.push(self.state.clone()),
+            DrawCommand::Restore => {
+                if let Some(state) = self.stack.pop() {
+                    self.state = state;
+                }
+            }
+            DrawCommand::Transform { a, b, c, d, e, f } => {
+                let matrix = Transform::from_row(
+                    *a as f32, *b as f32, *c as f32, *d as f32, *e as f32, *f as f32,
+                );
+                self.state.transform = self.state.transform.pre_concat(matrix);
+            }
+            DrawCommand::Clip {
+                x,
+                y,
+                width,
+                height,
+            } => self.clip(*x, *y, *width, *height),
+            DrawCommand::FillRect {
+                x,
+                y,
+                width,
+                height,
+                color,
+            } => {
+                if let Some(rect) = rect(*x, *y, *width, *height) {
+                    let paint = solid(color);
+                    let clip = self.state.clip.as_ref();
+                    self.pixmap
+                        .fill_rect(rect, &paint, self.state.transform, clip);
+                }
+            }
+            DrawCommand::FillPath { path, color } => {
+                if let Some(path) = build_path(path) {
+                    let paint = solid(color);
+                    let clip = self.state.clip.as_ref();
+                    self.pixmap.fill_path(
+                        &path,
+                        &paint,
+                        FillRule::Winding,
+                        self.state.transform,
+                        clip,
+                    );
+                }
+            }
+            DrawCommand::StrokePath {
+                path,
+                color,
+                width,
+                dash,
+            } => {
+                if let Some(path) = build_path(path) {
+                    let stroke = Stroke {
+                        width: *width as f32,
+                        dash: StrokeDash::new(dash.iter().map(|v| *v as f32).collect(), 0.0),
+                        ..Default::default()
+                    };
+                    let paint = solid(color);
+                    let clip = self.state.clip.as_ref();
+                    self.pixmap
+                        .stroke_path(&path, &paint, &stroke, self.state.transform, clip);
+                }
+            }
+            DrawCommand::DrawText {
+                x,
+                y,
+                text,
+                font,
+                color,
+            } => self.text(*x as f32, *y as f32, text, font, color),
+            DrawCommand::DrawImage {
+                x,
+                y,
+                width,
+                height,
+                binary_id,
+                ..
+            } => self.image(binary_id, *x, *y, *width, *height),
+        }
+    }
+
+    /// 현재 클립과 사각형의 교집합으로 클립 갱신
+    fn clip(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        let path = rect(x, y, width, height).map(PathBuilder::from_rect);
+        let transform = self.state.transform;
+        match (&mut self.state.clip, path) {
+            (Some(mask), Some(path)) => {
+                mask.intersect_path(&path, FillRule::Winding, true, transform)
+            }
+            (clip @ None, Some(path)) => {
+                if let Some(mut mask) = Mask::new(self.pixmap.width(), self.pixmap.height()) {
+                    mask.fill_path(&path, FillRule::Winding, true, transform);
+                    *clip = Some(mask);
+                }
+            }
+            // 넓이가 없는 클립: 이후로는 아무것도 보이지 않음
+            (clip, None) => *clip = Mask::new(self.pixmap.width(), self.pixmap.height()),
+        }
+    }
+
+    fn text(&mut self, x: f32, y: f32, text: &str, font: &CanvasFont, color: &str) {
+        let size = font.size as f32;
+        let paint = solid(color);
+        for placed in self.glyphs.layout(text, font) {
+            let units = self.glyphs.units_per_em(placed.face);
+            let mut transform = self
+                .state
+                .transform
+                .pre_translate(x + placed.x * size, y)
+                .pre_scale(size / units, -size / units);
+            if placed.fake_italic {
+                transform = transform.pre_concat(Transform::from_row(
+                    1.0,
+                    0.0,
+                    FAKE_ITALIC_SKEW,
+                    1.0,
+                    0.0,
+                    0.0,
+                ));
+            }
+            let Some(outline) = self.glyphs.outline(placed.face, placed.glyph) else {
+                continue;
+            };
+            let clip = self.state.clip.as_ref();
+            self.pixmap
+                .fill_path(outline, &paint, FillRule::Winding, transform, clip);
+            if placed.fake_bold {
+                let stroke = Stroke {
+                    width: FAKE_BOLD_STROKE * units,
+                    ..Default::default()
+                };
+                self.pixmap
+                    .stroke_path(outline, &paint, &stroke, transform, clip);
+            }
+        }
+    }
+
+    fn image(&mut self, binary_id: &str, x: f64, y: f64, width: f64, height: f64) {
+        let Some(area) = rect(x, y, width, height) else {
+            return;
+        };
+        if !self.images.contains_key(binary_id) {
+            let decoded = if self.options.embed_images {
+                doc_utils::find_binary_item(binary_id, &self.doc.binaries)
+                    .and_then(|item| decode_image(&item.data))
+            } else {
+                None
+            };
+            self.images.insert(binary_id.to_string(), decoded);
+        }
+        let clip = self.state.clip.as_ref();
+        match self.images.get(binary_id).and_then(Option::as_ref) {
+            Some(image) => {
+                // 이미지 픽셀 좌표 → 그릴 사각형 (늘려 채움)
+                let pattern_transform = Transform::from_scale(
+                    area.width() / image.width() as f32,
+                    area.height() / image.height() as f32,
+                )
+                .post_translate(area.x(), area.y());
+                let paint = Paint {
+                    shader: Pattern::new(
+                        image.as_ref(),
+                        SpreadMode::Pad,
+                        FilterQuality::Bilinear,
+                        1.0,
+                        pattern_transform,
+                    ),
+                    ..Default::default()
+                };
+                self.pixmap
+                    .fill_rect(area, &paint, self.state.transform, clip);
+            }
+            None => {
+                // 자리 표시: 회색 사각형 + 대각선
+                self.pixmap
+                    .fill_rect(area, &solid("#e6e6e6"), self.state.transform, clip);
+                let mut builder = PathBuilder::new();
+                builder.push_rect(area);
+                builder.move_to(area.left(), area.top());
+                builder.line_to(area.right(), area.bottom());
+                builder.move_to(area.right(), area.top());
+                builder.line_to(area.left(), area.bottom());
+                if let Some(path) = builder.finish() {
+                    let stroke = Stroke {
+                        width: 0.2,
+                        ..Default::default()
+                    };
+                    self.pixmap.stroke_path(
+                        &path,
+                        &solid("#999999"),
+                        &stroke,
+                        self.state.transform,
+                        clip,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// 이미지 바이트 → 픽셀 (형식은 매직 바이트로 판단)
+fn decode_image(data: &[u8]) -> Option<Pixmap> {
+    if data.starts_with(b"\x89PNG") {
+        return Pixmap::decode_png(data).ok();
+    }
+    let (width, height, rgb) = if data.starts_with(&[0xFF, 0xD8]) {
+        decode_jpeg(data)?
+    } else if data.starts_with(b"BM") {
+        doc_utils::decode_bmp(data)?
+    } else {
+        return None;
+    };
+    let mut pixmap = Pixmap::new(width as u32, height as u32)?;
+    for (pixel, source) in pixmap.pixels_mut().iter_mut().zip(rgb.chunks_exact(3)) {
+        *pixel = tiny_skia::ColorU8::from_rgba(source[0], source[1], source[2], 255).premultiply();
+    }
+    Some(pixmap)
+}
+
+/// JPEG → (너비, 높이, RGB)
+fn decode_jpeg(data: &[u8]) -> Option<(usize, usize, Vec<u8>)> {
+    let mut decoder = jpeg_decoder::Decoder::new(data);
+    let pixels = decoder.decode().ok()?;
+    let info = decoder.info()?;
+    let rgb = match info.pixel_format {
+        jpeg_decoder::PixelFormat::RGB24 => pixels,
+        jpeg_decoder::PixelFormat::L8 => pixels.iter().flat_map(|&l| [l, l, l]).collect(),
+        jpeg_decoder::PixelFormat::L16 => pixels
+            .chunks_exact(2)
+            .flat_map(|l| [l[0], l[0], l[0]])
+            .collect(),
+        // Adobe CMYK JPEG는 반전되어 저장됨
+        jpeg_decoder::PixelFormat::CMYK32 => pixels
+            .chunks_exact(4)
+            .flat_map(|p| {
+                let k = p[3] as u16;
+                [
+                    (p[0] as u16 * k / 255) as u8,
+                    (p[1] as u16 * k / 255) as u8,
+                    (p[2] as u16 * k / 255) as u8,
+                ]
+            })
+            .collect(),
+    };
+    Some((info.width as usize, info.height as usize, rgb))
+}
+
+fn rect(x: f64, y: f64, width: f64, height: f64) -> Option<Rect> {
+    Rect::from_xywh(x as f32, y as f32, width as f32, height as f32)
+}
+
+/// "#RRGGBB" 단색 / Solid paint from "#RRGGBB"
+fn solid(color: &str) -> Paint<'static> {
+    let hex = color.trim_start_matches('#');
+    let channel = |i: usize| {
+        hex.get(i..i + 2)
+            .and_then(|h| u8::from_str_radix(h, 16).ok())
+            .unwrap_or(0)
+    };
+    let mut paint = Paint::default();
+    paint.set_color_rgba8(channel(0), channel(2), channel(4), 255);
+    paint.anti_alias = true;
+    paint
+}
+
+fn build_path(ops: &[PathOp]) -> Option<Path> {
+    let mut builder = PathBuilder::new();
+    for op in ops {
+        match op {
+            PathOp::MoveTo { x, y } => builder.move_to(*x as f32, *y as f32),
+            PathOp::LineTo { x, y } => builder.line_to(*x as f32, *y as f32),
+            PathOp::BezierTo {
+                cp1x,
+                cp1y,
+                cp2x,
+                cp2y,
+                x,
+                y,
+            } => builder.cubic_to(
+                *cp1x as f32,
+                *cp1y as f32,
+                *cp2x as f32,
+                *cp2y as f32,
+                *x as f32,
+                *y as f32,
+            ),
+            PathOp::Close => builder.close(),
+        }
+    }
+    builder.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(commands: Vec<DrawCommand>) -> Pixmap {
+        let doc = Document::default();
+        let rasterizer = Rasterizer::new(RasterOptions {
+            dpi: 25.4,
+            ..Default::default()
+        });
+        let page = CanvasPage {
+            width: 10.0,
+            height: 10.0,
+            commands,
+        };
+        rasterizer.render_pixmap(&doc, &page).unwrap()
+    }
+
+    fn pixel(pixmap: &Pixmap, x: u32, y: u32) -> (u8, u8, u8) {
+        let p = pixmap.pixel(x, y).unwrap().demultiply();
+        (p.red(), p.green(), p.blue())
+    }
+
+    #[test]
+    fn test_fill_rect_and_clip() {
+        // 1mm = 1px. 클립 (0,0)-(5,10) 안에서만 빨간 사각형이 보여야 함
+        let pixmap = render(vec![
+            DrawCommand::Save,
+            DrawCommand::Clip {
+                x: 0.0,
+                y: 0.0,
+                width: 5.0,
+                height: 10.0,
+            },
+            DrawCommand::FillRect {
+                x: 0.0,
+                y: 0.0,
+                width: 10.0,
+                height: 10.0,
+                color: "#FF0000".to_string(),
+            },
+            DrawCommand::Restore,
+        ]);
+        assert_eq!(pixmap.width(), 10);
+        assert_eq!(pixel(&pixmap, 2, 5), (255, 0, 0));
+        assert_eq!(pixel(&pixmap, 7, 5), (255, 255, 255));
+    }
+
+    #[test]
+    fn test_transform_and_placeholder() {
+        let pixmap = render(vec![
+            DrawCommand::Transform {
+                a: 1.0,
+                b: 0.0,
+                c: 0.0,
+                d: 1.0,
+                e: 5.0,
+                f: 5.0,
+            },
+            DrawCommand::DrawImage {
+                x: 0.0,
+                y: 0.0,
+                width: 5.0,
+                height: 5.0,
+                binary_id: "missing".to_string(),
+                mime: "image/png".to_string(),
+            },
+        ]);
+        assert_eq!(pixel(&pixmap, 1, 1), (255, 255, 255));
+        assert_ne!(pixel(&pixmap, 8, 6), (255, 255, 255));
+    }
+
+    #[test]
+    fn test_decode_bmp_image() {
+        let mut bmp = b"BM".to_vec();
+        bmp.extend_from_slice(&[0; 8]);
+        bmp.extend_from_slice(&54u32.to_le_bytes());
+        bmp.extend_from_slice(&40u32.to_le_bytes());
+        bmp.extend_from_slice(&1i32.to_le_bytes());
+        bmp.extend_from_slice(&1i32.to_le_bytes());
+        bmp.extend_from_slice(&1u16.to_le_bytes());
+        bmp.extend_from_slice(&24u16.to_le_bytes());
+        bmp.extend_from_slice(&[0; 24]);
+        bmp.extend_from_slice(&[255, 0, 0, 0]);
+        let image = decode_image(&bmp).unwrap();
+        assert_eq!(pixel(&image, 0, 0), (0, 0, 255));
+        assert!(decode_image(b"GIF89a").is_none());
+    }
+}
@@ -0,0 +1,277 @@
+//! 변경 추적 보기 모드
+//!
+//! 검토 문서의 삽입/삭제 구간을 어떻게 보여줄지 정한다. 최종본/원본은 모델에서 변경을
+//! 반영·취소한 사본을 그리고, 변경 표시 모드는 구간을 문단 단위로 닫아 뷰어가 문단 안에서만
+//! 삽입/삭제 상태를 추적하면 되도록 정리한다.
+
+use std::borrow::Cow;
+
+use hwp_model::document::Document;
+use hwp_model::paragraph::{Paragraph, Run, RunContent, TextContent, TextElement};
+use hwp_model::query::{ParagraphPath, VisitorMut};
+use hwp_model::resources::Resources;
+use hwp_model::revision::{RevisionKind, RevisionMark};
+
+/// 변경 추적 보기 모드
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RevisionView {
+    /// 최종본: 모든 변경을 반영 (삽입 유지, 삭제 제거)
+    Final,
+    /// 원본: 모든 변경을 취소 (삽입 제거, 삭제 유지)
+    Original,
+    /// 변경 표시: 삽입은 밑줄, 삭제는 취소선으로 작성자 색을 입혀 표시
+    #[default]
+    Markup,
+}
+
+impl RevisionView {
+    /// "final" / "original" / "markup" 문자열 → 보기 모드 (대소문자 무시)
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "final" => Some(Self::Final),
+            "original" => Some(Self::Original),
+            "markup" => Some(Self::Markup),
+            _ => None,
+        }
+    }
+}
+
+/// 보기 모드에 맞춘 문서. 변경 표시가 없으면 원본을 그대로 빌려준다.
+pub fn apply_revision_view(doc: &Document, view: RevisionView) -> Cow<'_, Document> {
+    if !doc.has_revisions() {
+        return Cow::Borrowed(doc);
+    }
+    let mut doc = doc.clone();
+    match view {
+        RevisionView::Final => doc.accept_revisions(),
+        RevisionView::Original => doc.reject_revisions(),
+        RevisionView::Markup => doc.walk_mut(&mut SplitAtParagraphs),
+    }
+    Cow::Owned(doc)
+}
+
+/// 목록마다 [`split_at_paragraphs`]를 적용한다
+struct SplitAtParagraphs;
+
+impl VisitorMut for SplitAtParagraphs {
+    fn visit_list_mut(&mut self, _path: &ParagraphPath, paragraphs: &mut Vec<Paragraph>) {
+        split_at_paragraphs(paragraphs);
+    }
+}
+
+/// 문단 끝에서 열려 있는 구간을 닫고 다음 문단 처음에 다시 연다
+fn split_at_paragraphs(paragraphs: &mut [Paragraph]) {
+    let mut open: Vec<TextElement> = Vec::new();
+    for para in paragraphs.iter_mut() {
+        if !open.is_empty() {
+            para.runs.insert(
+                0,
+                Run {
+                    char_shape_id: para.runs.first().map_or(0, |r| r.char_shape_id),
+                    contents: vec![mark_content(open.clone())],
+                },
+            );
+        }
+        // 다시 연 표시를 포함해 문단을 처음부터 훑는다
+        open.clear();
+        for element in para
+            .runs
+            .iter()
+            .flat_map(|run| &run.contents)
+            .filter_map(|content| match content {
+                RunContent::Text(tc) => Some(&tc.elements),
+                _ => None,
+            })
+            .flatten()
+        {
+            if let Some(mark) = element.revision_mark() {
+                if mark.begin {
+                    open.push(element.clone());
+                } else if let Some(pos) = open
+                    .iter()
+                    .rposition(|e| e.revision_mark().is_some_and(|m| m.id == mark.id))
+                {
+                    open.remove(pos);
+                }
+            }
+        }
+        if !open.is_empty() {
+            let closing = open.iter().rev().map(end_mark).collect();
+            match para.runs.last_mut() {
+                Some(run) => run.contents.push(mark_content(closing)),
+                None => para.runs.push(Run {
+                    char_shape_id: 0,
+                    contents: vec![mark_content(closing)],
+                }),
+            }
+        }
+    }
+}
+
+fn mark_content(elements: Vec<TextElement>) -> RunContent {
+    RunContent::Text(TextContent {
+        char_shape_id: None,
+        elements,
+    })
+}
+
+/// 시작 표시에 대응하는 끝 표시
+fn end_mark(begin: &TextElement) -> TextElement {
+    match begin.clone() {
+        TextElement::InsertBegin {
+            id,
+            tc_id,
+            para_end,
+        } => TextElement::InsertEnd {
+            id,
+            tc_id,
+            para_end,
+        },
+        TextElement::DeleteBegin {
+            id,
+            tc_id,
+            para_end,
+        } => TextElement::DeleteEnd {
+            id,
+            tc_id,
+            para_end,
+        },
+        other => other,
+    }
+}
+
+/// 변경 표시 모드에서 구간 하나를 그리는 데 필요한 정보
+pub(crate) struct RevisionStyle {
+    pub kind: RevisionKind,
+    pub author: Option<String>,
+    pub date: Option<String>,
+    /// 작성자 색 (작성자가 없거나 색이 없으면 종류별 기본색)
+    pub color: u32,
+}
+
+impl RevisionStyle {
+    pub fn new(mark: &RevisionMark<'_>, resources: &Resources) -> Self {
+        let track_change = mark.tc_id.and_then(|id| resources.track_change(id));
+        let author = track_change.and_then(|tc| resources.track_change_author(tc));
+        let default_color = match mark.kind {
+            RevisionKind::Insert => 0x0000FF,
+            RevisionKind::Delete => 0xFF0000,
+        };
+        Self {
+            kind: mark.kind,
+            author: author
+                .map(|a| a.name.clone())
+                .filter(|name| !name.is_empty()),
+            date: track_change.and_then(|tc| tc.date.clone()),
+            color: author.and_then(|a| a.color).unwrap_or(default_color),
+        }
+    }
+
+    /// 툴팁용 "작성자 (일시)"
+    pub fn title(&self) -> String {
+        match (&self.author, &self.date) {
+            (Some(author), Some(date)) => format!("{} ({})", author, date),
+            (Some(author), None) => author.clone(),
+            (None, Some(date)) => date.clone(),
+            (None, None) => String::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hwp_model::section::Section;
+
+    fn para(elements: Vec<TextElement>) -> Paragraph {
+        Paragraph {
+            runs: vec![Run {
+                char_shape_id: 0,
+                contents: vec![mark_content(elements)],
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn text(s: &str) -> TextElement {
+        TextElement::Text(s.to_string())
+    }
+
+    fn delete(begin: bool) -> TextElement {
+        let (id, tc_id, para_end) = ("1".to_string(), Some("1".to_string()), false);
+        if begin {
+            TextElement::DeleteBegin {
+                id,
+                tc_id,
+                para_end,
+            }
+        } else {
+            TextElement::DeleteEnd {
+                id,
+                tc_id,
+                para_end,
+            }
+        }
+    }
+
+    fn doc(paragraphs: Vec<Paragraph>) -> Document {
+        Document {
+            sections: vec![Section {
+                paragraphs,
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn marks(para: &Paragraph) -> Vec<(RevisionKind, bool)> {
+        para.runs
+            .iter()
+            .flat_map(|r| &r.contents)
+            .filter_map(|c| match c {
+                RunContent::Text(tc) => Some(&tc.elements),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|e| e.revision_mark().map(|m| (m.kind, m.begin)))
+            .collect()
+    }
+
+    #[test]
+    fn parse_view_names() {
+        assert_eq!(RevisionView::parse("Final"), Some(RevisionView::Final));
+        assert_eq!(
+            RevisionView::parse("original"),
+            Some(RevisionView::Original)
+        );
+        assert_eq!(RevisionView::parse("markup"), Some(RevisionView::Markup));
+        assert_eq!(RevisionView::parse("simple"), None);
+    }
+
+    #[test]
+    fn document_without_marks_is_borrowed() {
+        let d = doc(vec![para(vec![text("본문")])]);
+        assert!(matches!(
+            apply_revision_view(&d, RevisionView::Final),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn markup_closes_ranges_at_paragraph_end() {
+        let d = doc(vec![
+            para(vec![text("a"), delete(true), text("b")]),
+            para(vec![text("c"), delete(false), text("d")]),
+        ]);
+        let view = apply_revision_view(&d, RevisionView::Markup);
+        let paragraphs = &view.sections[0].paragraphs;
+        assert_eq!(
+            marks(&paragraphs[0]),
+            vec![(RevisionKind::Delete, true), (RevisionKind::Delete, false)]
+        );
+        assert_eq!(
+            marks(&paragraphs[1]),
+            vec![(RevisionKind::Delete, true), (RevisionKind::Delete, false)]
+        );
+    }
+}
@@ -0,0 +1,471 @@
+//! SVG export for HWP documents
+//! HWP 문서를 페이지별 SVG로 내보내는 모듈
+//!
+//! `hwp_model::Document`를 Canvas display list([`crate::viewer::canvas`])와 같은 페이지 배치로
+//! 그린 뒤, 명령을 페이지마다 독립된 SVG 문서로 옮겨 씁니다. 글자는 `LineSegmentInfo`에서 계산한
+//! 기준선 위치의 `<text>`, 이미지는 `<image>`, 표 테두리/도형은 `<path>`/`<rect>`가 됩니다.
+//! 브라우저 레이아웃 엔진을 거치지 않으므로 썸네일/인쇄 미리보기가 어느 브라우저에서나 같게 보입니다.
+//!
+//! Renders the canvas display list of a `Document` into one standalone SVG per page. Text is
+//! placed at the baselines computed from `LineSegmentInfo`, so the result does not depend on a
+//! browser's text layout.
+//!
+//! # 좌표 / Coordinates
+//! `viewBox`는 mm 단위(페이지 좌상단 원점)이고 `width`/`height`는 `mm` 단위 실제 크기입니다.
+use crate::viewer::canvas::{doc_to_canvas, CanvasFont, CanvasPage, DrawCommand, PathOp};
+use crate::viewer::doc_utils;
+use crate::viewer::doc_utils::html_escape;
+use base64::Engine;
+use hwp_model::document::{BinaryItem, Document};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// SVG 변환 옵션 / SVG conversion options
+#[derive(Debug, Clone)]
+pub struct SvgOptions {
+    /// 이미지를 파일로 저장할 디렉토리 경로 (None이면 base64 데이터 URI로 임베드)
+    /// Directory to write images to (None = embed as base64 data URIs)
+    pub image_output_dir: Option<String>,
+    /// 이미지를 넣을지 여부 (false면 자리 표시 사각형) / Include images (false = placeholders)
+    pub embed_images: bool,
+    /// 종이 배경을 흰색으로 칠할지 여부 (false면 투명) / Paint a white paper background
+    pub background: bool,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            image_output_dir: None,
+            embed_images: true,
+            background: true,
+        }
+    }
+}
+
+/// Document를 페이지별 SVG 문자열로 변환
+/// Convert a Document into one standalone SVG document per page
+pub fn doc_to_svg_pages(doc: &Document, options: &SvgOptions) -> Vec<String> {
+    let mut renderer = SvgRenderer {
+        doc,
+        options,
+        images: HashMap::new(),
+        clip_count: 0,
+    };
+    doc_to_canvas(doc)
+        .iter()
+        .map(|page| renderer.page(page))
+        .collect()
+}
+
+/// 페이지 명령 → SVG / Canvas commands to SVG
+struct SvgRenderer<'a> {
+    doc: &'a Document,
+    options: &'a SvgOptions,
+    /// BinaryItem id → `<image>` href (그릴 수 없는 이미지는 None)
+    images: HashMap<String, Option<String>>,
+    /// clipPath id 번호 (여러 페이지를 한 HTML에 넣어도 겹치지 않도록 문서 전체에서 증가)
+    clip_count: usize,
+}
+
+impl SvgRenderer<'_> {
+    fn page(&mut self, page: &CanvasPage) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" width="{w}mm" height="{h}mm" viewBox="0 0 {w} {h}">"#,
+            w = num(page.width),
+            h = num(page.height)
+        );
+        if self.options.background {
+            let _ = writeln!(
+                out,
+                r##"<rect width="{}" height="{}" fill="#ffffff"/>"##,
+                num(page.width),
+                num(page.height)
+            );
+        }
+
+        let mut groups = GroupStack::default();
+        for command in &page.commands {
+            match command {
+                DrawCommand::Save => groups.save(),
+                DrawCommand::Restore => groups.restore(&mut out),
+                DrawCommand::Transform { a, b, c, d, e, f } => {
+                    let _ = writeln!(
+                        out,
+                        r#"<g transform="matrix({} {} {} {} {} {})">"#,
+                        num(*a),
+                        num(*b),
+                        num(*c),
+                        num(*d),
+                        num(*e),
+                        num(*f)
+                    );
+                    groups.open();
+                }
+                DrawCommand::Clip {
+                    x,
+                    y,
+                    width,
+                    height,
+                } => {
+                    // clipPath는 참조하는 요소의 좌표계를 쓰므로 현재 변환 안쪽에 둠
+                    self.clip_count += 1;
+                    let _ = writeln!(
+                        out,
+                        r#"<clipPath id="c{n}"><rect {}/></clipPath><g clip-path="url(#c{n})">"#,
+                        rect_attrs(*x, *y, *width, *height),
+                        n = self.clip_count
+                    );
+                    groups.open();
+                }
+                DrawCommand::FillRect {
+                    x,
+                    y,
+                    width,
+                    height,
+                    color,
+                } => {
+                    let _ = writeln!(
+                        out,
+                        r#"<rect {} fill="{}"/>"#,
+                        rect_attrs(*x, *y, *width, *height),
+                        html_escape(color)
+                    );
+                }
+                DrawCommand::FillPath { path, color } => {
+                    let _ = writeln!(
+                        out,
+                        r#"<path d="{}" fill="{}"/>"#,
+                        path_data(path),
+                        html_escape(color)
+                    );
+                }
+                DrawCommand::StrokePath {
+                    path,
+                    color,
+                    width,
+                    dash,
+                } => {
+                    let _ = write!(
+                        out,
+                        r#"<path d="{}" fill="none" stroke="{}" stroke-width="{}""#,
+                        path_data(path),
+                        html_escape(color),
+                        num(*width)
+                    );
+                    if !dash.is_empty() {
+                        let dash: Vec<String> = dash.iter().map(|v| num(*v)).collect();
+                        let _ = write!(out, r#" stroke-dasharray="{}""#, dash.join(" "));
+                    }
+                    out.push_str("/>\n");
+                }
+                DrawCommand::DrawText {
+                    x,
+                    y,
+                    text,
+                    font,
+                    color,
+                } => {
+                    let _ = writeln!(
+                        out,
+                        r#"<text x="{}" y="{}" {} fill="{}" xml:space="preserve">{}</text>"#,
+                        num(*x),
+                        num(*y),
+                        font_attrs(font),
+                        html_escape(color),
+                        html_escape(text)
+                    );
+                }
+                DrawCommand::DrawImage {
+                    x,
+                    y,
+                    width,
+                    height,
+                    binary_id,
+                    ..
+                } => match self.image(binary_id) {
+                    Some(href) => {
+                        let _ = writeln!(
+                            out,
+                            r#"<image {} preserveAspectRatio="none" xlink:href="{}"/>"#,
+                            rect_attrs(*x, *y, *width, *height),
+                            html_escape(&href)
+                        );
+                    }
+                    None => placeholder(&mut out, *x, *y, *width, *height),
+                },
+            }
+        }
+        groups.close_all(&mut out);
+        out.push_str("</svg>\n");
+        out
+    }
+
+    /// `<image>`에 쓸 href (처음 쓸 때 만들어 둠)
+    fn image(&mut self, binary_id: &str) -> Option<String> {
+        if !self.options.embed_images {
+            return None;
+        }
+        if let Some(href) = self.images.get(binary_id) {
+            return href.clone();
+        }
+        let href = doc_utils::find_binary_item(binary_id, &self.doc.binaries)
+            .and_then(|item| image_href(item, self.options.image_output_dir.as_deref()));
+        self.images.insert(binary_id.to_string(), href.clone());
+        href
+    }
+}
+
+/// 저장(Save)마다 그 뒤로 연 `<g>` 수를 기억해 두었다가 복원(Restore) 때 닫음
+#[derive(Default)]
+struct GroupStack {
+    /// 맨 앞은 Save 밖(최상위)에서 연 그룹 수
+    levels: Vec<usize>,
+}
+
+impl GroupStack {
+    fn save(&mut self) {
+        if self.levels.is_empty() {
+            self.levels.push(0);
+        }
+        self.levels.push(0);
+    }
+
+    fn open(&mut self) {
+        if self.levels.is_empty() {
+            self.levels.push(0);
+        }
+        if let Some(top) = self.levels.last_mut() {
+            *top += 1;
+        }
+    }
+
+    fn restore(&mut self, out: &mut String) {
+        // 최상위 수준은 Restore로 닫지 않음 (짝이 맞지 않는 Restore 무시)
+        if self.levels.len() > 1 {
+            let opened = self.levels.pop().unwrap_or(0);
+            out.push_str(&"</g>".repeat(opened));
+            if opened > 0 {
+                out.push('\n');
+            }
+        }
+    }
+
+    fn close_all(&mut self, out: &mut String) {
+        let opened: usize = self.levels.drain(..).sum();
+        if opened > 0 {
+            out.push_str(&"</g>".repeat(opened));
+            out.push('\n');
+        }
+    }
+}
+
+/// 브라우저가 그릴 수 있는 이미지면 href (data URI 또는 저장한 파일 경로)
+fn image_href(item: &BinaryItem, image_output_dir: Option<&str>) -> Option<String> {
+    if item.data.is_empty() {
+        return (!item.src.is_empty()).then(|| item.src.clone());
+    }
+    let mime = doc_utils::image_format_to_mime(&item.format);
+    let ext = match mime {
+        "image/png" => "png",
+        "image/jpeg" => "jpg",
+        "image/gif" => "gif",
+        "image/bmp" => "bmp",
+        "image/svg+xml" => "svg",
+        // WMF/EMF/TIFF 등은 브라우저가 그리지 못하므로 자리 표시로 대신함
+        _ => return None,
+    };
+    if let Some(dir) = image_output_dir {
+        let file_path = std::path::Path::new(dir).join(format!("{}.{}", item.id, ext));
+        if std::fs::write(&file_path, &item.data).is_ok() {
+            return Some(file_path.display().to_string());
+        }
+        // 파일 저장 실패 시 base64 fallback
+    }
+    Some(format!(
+        "data:{};base64,{}",
+        mime,
+        base64::engine::general_purpose::STANDARD.encode(&item.data)
+    ))
+}
+
+/// 이미지 자리 표시: 회색 사각형 + 대각선
+fn placeholder(out: &mut String, x: f64, y: f64, width: f64, height: f64) {
+    let _ = writeln!(
+        out,
+        r##"<g fill="#e6e6e6" stroke="#999999" stroke-width="0.2"><rect {}/><path d="M{} {}L{} {}M{} {}L{} {}" fill="none"/></g>"##,
+        rect_attrs(x, y, width, height),
+        num(x),
+        num(y),
+        num(x + width),
+        num(y + height),
+        num(x + width),
+        num(y),
+        num(x),
+        num(y + height)
+    );
+}
+
+fn rect_attrs(x: f64, y: f64, width: f64, height: f64) -> String {
+    format!(
+        r#"x="{}" y="{}" width="{}" height="{}""#,
+        num(x),
+        num(y),
+        num(width.max(0.0)),
+        num(height.max(0.0))
+    )
+}
+
+/// `font-family`/`font-size`/굵게/기울임 속성
+fn font_attrs(font: &CanvasFont) -> String {
+    let mut attrs = format!(
+        r#"font-family="{}" font-size="{}""#,
+        html_escape(&format!(
+            "'{}', sans-serif",
+            doc_utils::escape_css_font_name(&font.family)
+        )),
+        num(font.size)
+    );
+    if font.bold {
+        attrs.push_str(r#" font-weight="bold""#);
+    }
+    if font.italic {
+        attrs.push_str(r#" font-style="italic""#);
+    }
+    attrs
+}
+
+fn path_data(path: &[PathOp]) -> String {
+    let mut d = String::new();
+    for op in path {
+        match op {
+            PathOp::MoveTo { x, y } => {
+                let _ = write!(d, "M{} {}", num(*x), num(*y));
+            }
+            PathOp::LineTo { x, y } => {
+                let _ = write!(d, "L{} {}", num(*x), num(*y));
+            }
+            PathOp::BezierTo {
+                cp1x,
+                cp1y,
+                cp2x,
+                cp2y,
+                x,
+                y,
+            } => {
+                let _ = write!(
+                    d,
+                    "C{} {} {} {} {} {}",
+                    num(*cp1x),
+                    num(*cp1y),
+                    num(*cp2x),
+                    num(*cp2y),
+                    num(*x),
+                    num(*y)
+                );
+            }
+            PathOp::Close => d.push('Z'),
+        }
+    }
+    d
+}
+
+/// 숫자를 짧게 출력 (소수점 이하 최대 3자리, 끝의 0 제거)
+fn num(value: f64) -> String {
+    if !value.is_finite() {
+        return "0".to_string();
+    }
+    let mut s = format!("{:.3}", value);
+    if s.contains('.') {
+        while s.ends_with('0') {
+            s.pop();
+        }
+        if s.ends_with('.') {
+            s.pop();
+        }
+    }
+    if s == "-0" {
+        s = "0".to_string();
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(commands: Vec<DrawCommand>) -> String {
+        let doc = Document::default();
+        let options = SvgOptions::default();
+        let mut renderer = SvgRenderer {
+            doc: &doc,
+            options: &options,
+            images: HashMap::new(),
+            clip_count: 0,
+        };
+        renderer.page(&CanvasPage {
+            width: 210.0,
+            height: 297.0,
+            commands,
+        })
+    }
+
+    #[test]
+    fn test_num_trims_zeros() {
+        assert_eq!(num(1.0), "1");
+        assert_eq!(num(0.25), "0.25");
+        assert_eq!(num(-0.0001), "0");
+        assert_eq!(num(2.834_645), "2.835");
+    }
+
+    #[test]
+    fn test_groups_balanced() {
+        let svg = render(vec![
+            DrawCommand::Save,
+            DrawCommand::Transform {
+                a: 1.0,
+                b: 0.0,
+                c: 0.0,
+                d: 1.0,
+                e: 10.0,
+                f: 20.0,
+            },
+            DrawCommand::Clip {
+                x: 0.0,
+                y: 0.0,
+                width: 5.0,
+                height: 5.0,
+            },
+            DrawCommand::Restore,
+            // 짝 없는 Restore와 닫히지 않은 Transform
+            DrawCommand::Restore,
+            DrawCommand::Transform {
+                a: 1.0,
+                b: 0.0,
+                c: 0.0,
+                d: 1.0,
+                e: 0.0,
+                f: 0.0,
+            },
+        ]);
+        assert_eq!(svg.matches("<g ").count(), svg.matches("</g>").count());
+        assert!(svg.contains(r#"<clipPath id="c1">"#));
+        assert!(svg.contains(r#"viewBox="0 0 210 297""#));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn test_missing_image_placeholder() {
+        let svg = render(vec![DrawCommand::DrawImage {
+            x: 1.0,
+            y: 2.0,
+            width: 3.0,
+            height: 4.0,
+            binary_id: "BIN0001".to_string(),
+            mime: "image/png".to_string(),
+        }]);
+        assert!(!svg.contains("<image"));
+        assert!(svg.contains(r#"<rect x="1" y="2" width="3" height="4"/>"#));
+    }
+}
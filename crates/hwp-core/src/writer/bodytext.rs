@@ -0,0 +1,1632 @@
+/// BodyText/SectionN 스트림 작성
+///
+/// 스펙 문서 4.3: 본문 레코드. 문단은 PARA_HEADER(레벨 n) 아래에 PARA_TEXT, PARA_CHAR_SHAPE,
+/// PARA_LINE_SEG, PARA_RANGE_TAG, CTRL_HEADER(레벨 n+1)가 오고, 컨트롤의 하위 레코드는 n+2에 둔다.
+/// LIST_HEADER 뒤의 문단 리스트는 LIST_HEADER와 같은 레벨에 기록한다.
+use super::docinfo::{border_width, fill_brush, image_info, line_type2, line_type3};
+use super::record::{ctrl_id, Bytes, RecordWriter};
+use super::BinIds;
+use crate::document::bodytext::HwpTag;
+use hwp_model::control::*;
+use hwp_model::paragraph::*;
+use hwp_model::section::{ColumnDef, FillArea, PageBorderRef, Section, SectionDef};
+use hwp_model::shape::*;
+use hwp_model::table::{Table, TableCell};
+use hwp_model::types::*;
+
+/// 문단 머리 정보의 문단 병합 필드가 추가된 버전 (5.0.3.2)
+const VERSION_PARA_MERGE: u32 = 0x05000302;
+
+/// 제어 문자 코드 (스펙 문서 표 6)
+mod ch {
+    pub const FIELD_END: u16 = 4;
+    pub const SECTION_COLUMN: u16 = 2;
+    pub const FIELD_BEGIN: u16 = 3;
+    pub const TITLE_MARK: u16 = 8;
+    pub const TAB: u16 = 9;
+    pub const LINE_BREAK: u16 = 10;
+    pub const OBJECT: u16 = 11;
+    pub const PARA_BREAK: u16 = 13;
+    pub const HIDDEN_DESC: u16 = 15;
+    pub const HEADER_FOOTER: u16 = 16;
+    pub const NOTE: u16 = 17;
+    pub const AUTO_NUMBER: u16 = 18;
+    pub const PAGE_CONTROL: u16 = 21;
+    pub const BOOKMARK: u16 = 22;
+    pub const OVERLAP: u16 = 23;
+    pub const HYPHEN: u16 = 24;
+    pub const NB_SPACE: u16 = 30;
+    pub const FW_SPACE: u16 = 31;
+}
+
+pub fn write_section(section: &Section, bin_ids: &BinIds, version: u32) -> Vec<u8> {
+    let mut w = SectionWriter {
+        w: RecordWriter::new(),
+        bin_ids,
+        version,
+    };
+    w.paragraphs(&section.paragraphs, 0, Some(&section.definition));
+    w.w.finish()
+}
+
+/// 문단 텍스트에 위치가 정해진 확장 컨트롤 (PARA_TEXT 이후 CTRL_HEADER로 기록)
+enum Ctrl<'a> {
+    Section(&'a SectionDef),
+    Column(Option<&'a ColumnControl>, Option<&'a ColumnDef>),
+    Control(&'a Control),
+    Object(&'a ShapeObject),
+}
+
+// ═══════════════════════════════════════════
+// PARA_TEXT 조립
+// ═══════════════════════════════════════════
+
+/// WCHAR 배열과 글자 모양 위치, 확장 컨트롤 목록을 함께 쌓는다.
+#[derive(Default)]
+struct TextBuilder<'a> {
+    units: Vec<u16>,
+    char_shapes: Vec<(u32, u32)>,
+    shape: u16,
+    ctrls: Vec<Ctrl<'a>>,
+    mask: u32,
+    /// 변환 가능한 제어 문자 뒤에 파서가 덧붙이는 표시 문자열 (중복 기록 방지)
+    echo: Option<&'static str>,
+}
+
+impl<'a> TextBuilder<'a> {
+    /// 현재 글자 모양으로 WCHAR 추가
+    fn push(&mut self, units: &[u16]) {
+        let pos = self.units.len() as u32;
+        let shape = self.shape as u32;
+        match self.char_shapes.last_mut() {
+            Some(last) if last.1 == shape => {}
+            Some(last) if last.0 == pos => last.1 = shape,
+            _ => self.char_shapes.push((pos, shape)),
+        }
+        self.units.extend_from_slice(units);
+        self.echo = None;
+    }
+
+    /// 문자 컨트롤 (1 WCHAR)
+    fn char_ctrl(&mut self, code: u16) {
+        self.push(&[code]);
+        self.mask |= 1 << code;
+    }
+
+    /// 인라인/확장 컨트롤 (8 WCHAR: 코드 + 12바이트 + 코드)
+    fn wide_ctrl(&mut self, code: u16, params: [u8; 12]) {
+        let mut units = [0u16; 8];
+        units[0] = code;
+        for (i, unit) in units[1..7].iter_mut().enumerate() {
+            *unit = u16::from_le_bytes([params[i * 2], params[i * 2 + 1]]);
+        }
+        units[7] = code;
+        self.push(&units);
+        self.mask |= 1 << code;
+    }
+
+    fn extended(&mut self, code: u16, id: &[u8; 4], ctrl: Ctrl<'a>) {
+        let mut params = [0u8; 12];
+        params[..4].copy_from_slice(&ctrl_id(id).to_le_bytes());
+        self.wide_ctrl(code, params);
+        self.ctrls.push(ctrl);
+    }
+
+    fn tab(&mut self, width: HwpUnit, leader: &LineType2, tab_type: &TabType) {
+        let mut params = [0u8; 12];
+        params[..4].copy_from_slice(&width.to_le_bytes());
+        params[4] = line_type2(leader);
+        params[5] = match tab_type {
+            TabType::Left => 0,
+            TabType::Right => 1,
+            TabType::Center => 2,
+            TabType::Decimal => 3,
+        };
+        self.wide_ctrl(ch::TAB, params);
+        self.echo = Some("\t");
+    }
+
+    fn text(&mut self, s: &str) {
+        if self.echo.take() == Some(s) {
+            return;
+        }
+        let mut buf = [0u16; 2];
+        for c in s.chars() {
+            match c {
+                '\t' => self.tab(0, &LineType2::None, &TabType::Left),
+                '\n' => self.char_ctrl(ch::LINE_BREAK),
+                c if (c as u32) < 0x20 => {}
+                c => {
+                    let units = c.encode_utf16(&mut buf);
+                    self.push(units);
+                }
+            }
+        }
+    }
+
+    fn element(&mut self, el: &TextElement) {
+        match el {
+            TextElement::Text(s) => self.text(s),
+            TextElement::Tab {
+                width,
+                leader,
+                tab_type,
+            } => self.tab(*width, leader, tab_type),
+            TextElement::LineBreak => self.char_ctrl(ch::LINE_BREAK),
+            TextElement::Hyphen => {
+                self.char_ctrl(ch::HYPHEN);
+                self.echo = echoed_text(el);
+            }
+            TextElement::NbSpace => {
+                self.char_ctrl(ch::NB_SPACE);
+                self.echo = echoed_text(el);
+            }
+            TextElement::FwSpace => {
+                self.char_ctrl(ch::FW_SPACE);
+                self.echo = echoed_text(el);
+            }
+            TextElement::TitleMark { .. } => self.wide_ctrl(ch::TITLE_MARK, [0; 12]),
+            // 형광펜과 변경 추적은 PARA_RANGE_TAG/변경 추적 레코드 영역이므로 텍스트에 남기지 않는다
+            TextElement::MarkpenBegin { .. }
+            | TextElement::MarkpenEnd
+            | TextElement::InsertBegin { .. }
+            | TextElement::InsertEnd { .. }
+            | TextElement::DeleteBegin { .. }
+            | TextElement::DeleteEnd { .. } => {}
+        }
+    }
+
+    fn control(&mut self, control: &'a Control) {
+        let (code, id): (u16, &[u8; 4]) = match control {
+            Control::FieldEnd => {
+                self.wide_ctrl(ch::FIELD_END, [0; 12]);
+                return;
+            }
+            Control::Column(_) => (ch::SECTION_COLUMN, b"cold"),
+            Control::FieldBegin(field) => (ch::FIELD_BEGIN, field_ctrl_id(&field.field_type)),
+            Control::Bookmark(_) => (ch::BOOKMARK, b"bokm"),
+            Control::Header(_) => (ch::HEADER_FOOTER, b"head"),
+            Control::Footer(_) => (ch::HEADER_FOOTER, b"foot"),
+            Control::FootNote(_) => (ch::NOTE, b"fn  "),
+            Control::EndNote(_) => (ch::NOTE, b"en  "),
+            Control::AutoNum(_) => (ch::AUTO_NUMBER, b"atno"),
+            Control::NewNum(_) => (ch::PAGE_CONTROL, b"nwno"),
+            Control::PageNumCtrl(_) => (ch::PAGE_CONTROL, b"pgnp"),
+            Control::PageHiding(_) => (ch::PAGE_CONTROL, b"pghd"),
+            Control::Compose(_) => (ch::OVERLAP, b"tcps"),
+            Control::Dutmal(_) => (ch::OVERLAP, b"tdut"),
+            Control::HiddenDesc(_) => (ch::HIDDEN_DESC, b"tcmt"),
+        };
+        let ctrl = match control {
+            Control::Column(col) => Ctrl::Column(Some(col), None),
+            _ => Ctrl::Control(control),
+        };
+        self.extended(code, id, ctrl);
+    }
+
+    fn object(&mut self, object: &'a ShapeObject) {
+        let id: &[u8; 4] = match object {
+            ShapeObject::Table(_) => b"tbl ",
+            ShapeObject::Equation(_) => b"eqed",
+            ShapeObject::Picture(_)
+            | ShapeObject::Line(_)
+            | ShapeObject::Rectangle(_)
+            | ShapeObject::Ellipse(_)
+            | ShapeObject::Arc(_)
+            | ShapeObject::Polygon(_)
+            | ShapeObject::Curve(_)
+            | ShapeObject::Container(_) => b"gso ",
+            // 글맵시/OLE/차트/동영상/연결선/양식 개체는 원본 데이터가 모델에 없어 기록하지 않는다
+            ShapeObject::ConnectLine(_)
+            | ShapeObject::TextArt(_)
+            | ShapeObject::Ole(_)
+            | ShapeObject::Chart(_)
+            | ShapeObject::Video(_)
+            | ShapeObject::Form(_) => return,
+        };
+        self.extended(ch::OBJECT, id, Ctrl::Object(object));
+    }
+}
+
+/// 변환 가능한 제어 문자 요소 뒤에 파서가 덧붙이는 표시 문자열.
+/// 원본 PARA_TEXT에는 제어 문자만 있으므로 바로 뒤의 같은 텍스트는 WCHAR를 차지하지 않는다
+pub(crate) fn echoed_text(element: &TextElement) -> Option<&'static str> {
+    match element {
+        TextElement::Tab { .. } => Some("\t"),
+        TextElement::Hyphen => Some("-"),
+        TextElement::NbSpace | TextElement::FwSpace => Some(" "),
+        _ => None,
+    }
+}
+
+fn field_ctrl_id(field_type: &FieldType) -> &'static [u8; 4] {
+    match field_type {
+        FieldType::ClickHere => b"%clk",
+        FieldType::Hyperlink => b"%hlk",
+        FieldType::Bookmark => b"%bmk",
+        FieldType::Formula => b"%fmu",
+        FieldType::Summary => b"%smr",
+        FieldType::UserInfo => b"%usr",
+        FieldType::Date => b"%dte",
+        FieldType::DocDate => b"%ddt",
+        FieldType::Path => b"%pat",
+        FieldType::CrossRef => b"%xrf",
+        FieldType::MailMerge => b"%mmg",
+        FieldType::Memo => b"%%me",
+        FieldType::PrivateInfo => b"%cpr",
+        FieldType::Outline => b"%out",
+        _ => b"%unk",
+    }
+}
+
+// ═══════════════════════════════════════════
+// 레코드 작성기
+// ═══════════════════════════════════════════
+
+struct SectionWriter<'a> {
+    w: RecordWriter,
+    bin_ids: &'a BinIds,
+    version: u32,
+}
+
+impl SectionWriter<'_> {
+    /// 문단 리스트. 비어 있으면 빈 문단 하나를 기록한다 (리스트는 최소 한 문단).
+    fn paragraphs(&mut self, paras: &[Paragraph], level: u16, section: Option<&SectionDef>) {
+        if paras.is_empty() {
+            self.paragraph(&Paragraph::default(), level, true, section);
+            return;
+        }
+        for (i, para) in paras.iter().enumerate() {
+            let section = if i == 0 { section } else { None };
+            self.paragraph(para, level, i + 1 == paras.len(), section);
+        }
+    }
+
+    /// 문단 하나. `section`이 있으면 구역 정의/단 정의 컨트롤을 앞에 둔다.
+    fn paragraph(
+        &mut self,
+        para: &Paragraph,
+        level: u16,
+        last: bool,
+        section: Option<&SectionDef>,
+    ) {
+        let mut text = TextBuilder::default();
+
+        if let Some(def) = section {
+            text.extended(ch::SECTION_COLUMN, b"secd", Ctrl::Section(def));
+            let has_column = para.runs.iter().any(|run| {
+                run.contents
+                    .iter()
+                    .any(|c| matches!(c, RunContent::Control(Control::Column(_))))
+            });
+            if !has_column {
+                text.extended(
+                    ch::SECTION_COLUMN,
+                    b"cold",
+                    Ctrl::Column(None, def.columns.as_ref()),
+                );
+            }
+        }
+
+        for run in &para.runs {
+            for content in &run.contents {
+                text.shape = run.char_shape_id;
+                match content {
+                    RunContent::Text(tc) => {
+                        text.shape = tc.char_shape_id.unwrap_or(run.char_shape_id);
+                        for el in &tc.elements {
+                            text.element(el);
+                        }
+                    }
+                    RunContent::Control(control) => text.control(control),
+                    RunContent::Object(object) => text.object(object),
+                }
+            }
+        }
+        if let Some(shape) = para.runs.last().map(|run| run.char_shape_id) {
+            text.shape = shape;
+        }
+        text.push(&[ch::PARA_BREAK]);
+
+        // 구역의 첫 단 정의에 ColumnDef(단 너비/구분선)를 합친다
+        if let Some(def) = section {
+            for ctrl in text.ctrls.iter_mut() {
+                if let Ctrl::Column(col, column_def @ None) = ctrl {
+                    if col.is_some() {
+                        *column_def = def.columns.as_ref();
+                        break;
+                    }
+                }
+            }
+        }
+
+        let hints = para.hwp_hints.as_ref();
+        let mask = (text.mask & !(1 << ch::PARA_BREAK)) | hints.map_or(0, |h| h.control_mask);
+        let line_segments = if para.line_segments.is_empty() {
+            hints
+                .map(|h| h.line_segments.as_slice())
+                .unwrap_or_default()
+        } else {
+            para.line_segments.as_slice()
+        };
+        let range_tags = hints
+            .map(|h| h.range_tags_raw.as_slice())
+            .unwrap_or_default();
+
+        // 표 58 문단 머리 정보
+        let mut nchars = text.units.len() as u32;
+        if last {
+            nchars |= 0x8000_0000;
+        }
+        let mut break_type = 0u8;
+        if section.is_some() && level == 0 {
+            break_type |= 0x01 | 0x02;
+        }
+        if para.page_break {
+            break_type |= 0x04;
+        }
+        if para.column_break {
+            break_type |= 0x08;
+        }
+        let mut b = Bytes::new();
+        b.u32(nchars)
+            .u32(mask)
+            .u16(para.para_shape_id)
+            .u8(para.style_id as u8)
+            .u8(break_type)
+            .u16(text.char_shapes.len() as u16)
+            .u16((range_tags.len() / 12) as u16)
+            .u16(line_segments.len() as u16)
+            .u32(para.id as u32);
+        if self.version >= VERSION_PARA_MERGE {
+            b.u16(para.merged as u16);
+        }
+        self.w.record(HwpTag::PARA_HEADER, level, &b);
+
+        // 문단 끝 문자만 있으면 PARA_TEXT를 생략한다
+        if text.units.len() > 1 {
+            let data: Vec<u8> = text.units.iter().flat_map(|u| u.to_le_bytes()).collect();
+            self.w.record(HwpTag::PARA_TEXT, level + 1, &data);
+        }
+
+        let mut b = Bytes::new();
+        for (pos, id) in &text.char_shapes {
+            b.u32(*pos).u32(*id);
+        }
+        self.w.record(HwpTag::PARA_CHAR_SHAPE, level + 1, &b);
+
+        if !line_segments.is_empty() {
+            let mut b = Bytes::new();
+            for seg in line_segments {
+                b.u32(seg.text_start_pos)
+                    .i32(seg.vertical_pos)
+                    .i32(seg.line_height)
+                    .i32(seg.text_height)
+                    .i32(seg.baseline_distance)
+                    .i32(seg.line_spacing)
+                    .i32(seg.column_start_pos)
+                    .i32(seg.segment_width)
+                    .u32(seg.flags);
+            }
+            self.w.record(HwpTag::PARA_LINE_SEG, level + 1, &b);
+        }
+
+        if !range_tags.is_empty() {
+            self.w.record(HwpTag::PARA_RANGE_TAG, level + 1, range_tags);
+        }
+        for record in hints
+            .map(|h| h.extra_records.as_slice())
+            .unwrap_or_default()
+        {
+            self.w
+                .record(record.tag_id, level + record.level, &record.data);
+        }
+
+        for ctrl in &text.ctrls {
+            self.ctrl(ctrl, level + 1);
+        }
+    }
+
+    fn ctrl(&mut self, ctrl: &Ctrl, level: u16) {
+        match ctrl {
+            Ctrl::Section(def) => self.section_def(def, level),
+            Ctrl::Column(col, def) => {
+                let data = column_def(*col, *def);
+                self.w.record(HwpTag::CTRL_HEADER, level, &data);
+            }
+            Ctrl::Control(control) => self.control(control, level),
+            Ctrl::Object(object) => self.object(object, level),
+        }
+    }
+
+    /// 문단 리스트 헤더 (표 65) + 문단 리스트
+    fn sub_list(&mut self, level: u16, paras: &[Paragraph], attr: u32, extra: &[u8]) {
+        let mut b = Bytes::new();
+        b.u16(paras.len().max(1) as u16)
+            .u16(0)
+            .u32(attr)
+            .bytes(extra);
+        self.w.record(HwpTag::LIST_HEADER, level, &b);
+        self.paragraphs(paras, level, None);
+    }
+
+    // ── 구역 정의 ──
+
+    /// 표 129 구역 정의 + 용지 설정, 각주/미주 모양, 쪽 테두리/배경
+    fn section_def(&mut self, def: &SectionDef, level: u16) {
+        let mut attr = 0u32;
+        if let Some(v) = &def.visibility {
+            attr |= v.hide_first_header as u32;
+            attr |= (v.hide_first_footer as u32) << 1;
+            attr |= (v.hide_first_master_page as u32) << 2;
+            attr |= match v.border {
+                VisibilityValue::HideFirst => 1 << 3,
+                _ => 0,
+            };
+            attr |= match v.fill {
+                VisibilityValue::HideFirst => 1 << 4,
+                _ => 0,
+            };
+            attr |= (v.hide_first_page_num as u32) << 5;
+            attr |= (v.hide_first_empty_line as u32) << 19;
+            attr |= (v.show_line_number as u32) << 20;
+        }
+        if def.text_direction != TextDirection::Horizontal {
+            attr |= 1 << 16;
+        }
+        let start = def.start_num.clone().unwrap_or_default();
+        attr |= match start.page_starts_on {
+            PageStartsOn::Both => 0,
+            PageStartsOn::Even => 1,
+            PageStartsOn::Odd => 2,
+        } << 22;
+        let grid = def.grid.clone().unwrap_or_default();
+
+        let mut b = Bytes::new();
+        b.u32(ctrl_id(b"secd"))
+            .u32(attr)
+            .u16(def.space_columns as u16)
+            .u16(grid.line_grid)
+            .u16(grid.char_grid)
+            .u32(def.tab_stop as u32)
+            .u16(def.outline_shape_id.unwrap_or(0))
+            .u16(start.page)
+            .u16(start.pic)
+            .u16(start.tbl)
+            .u16(start.equation)
+            .u16(0);
+        b.zeros(47 - b.len());
+        self.w.record(HwpTag::CTRL_HEADER, level, &b);
+
+        // 표 131 용지 설정
+        let page = &def.page;
+        let mut attr = 0u32;
+        if page.landscape != Landscape::Portrait {
+            attr |= 0x01;
+        }
+        attr |= match page.gutter_type {
+            GutterType::LeftOnly => 0,
+            GutterType::LeftRight => 1,
+            GutterType::TopBottom => 2,
+        } << 1;
+        let m = &page.margin;
+        let mut b = Bytes::new();
+        for v in [
+            page.width,
+            page.height,
+            m.left,
+            m.right,
+            m.top,
+            m.bottom,
+            m.header,
+            m.footer,
+            m.gutter,
+        ] {
+            b.u32(v as u32);
+        }
+        b.u32(attr);
+        self.w.record(HwpTag::PAGE_DEF, level + 1, &b);
+
+        // 표 133 각주/미주 모양
+        let footnote = def.footnote.as_ref().map(|f| NoteShape {
+            number_format: &f.number_format,
+            placement: match f.placement {
+                FootnotePlacement::EachColumn => 0,
+                FootnotePlacement::MergedColumn => 1,
+                FootnotePlacement::RightMostColumn => 2,
+            },
+            numbering: match f.numbering_type {
+                FootnoteNumbering::Continuous => 0,
+                FootnoteNumbering::OnSection => 1,
+                FootnoteNumbering::OnPage => 2,
+            },
+            superscript: f.superscript,
+            beneath_text: f.beneath_text,
+            user_char: f.user_char,
+            prefix_char: f.prefix_char,
+            suffix_char: f.suffix_char,
+            start_number: f.start_number,
+            note_line: f.note_line.as_ref(),
+            note_spacing: f.note_spacing.as_ref(),
+        });
+        let endnote = def.endnote.as_ref().map(|e| NoteShape {
+            number_format: &e.number_format,
+            placement: match e.placement {
+                EndnotePlacement::EndOfDocument => 0,
+                EndnotePlacement::EndOfSection => 1,
+            },
+            numbering: match e.numbering_type {
+                EndnoteNumbering::Continuous => 0,
+                EndnoteNumbering::OnSection => 1,
+            },
+            superscript: e.superscript,
+            beneath_text: e.beneath_text,
+            user_char: e.user_char,
+            prefix_char: e.prefix_char,
+            suffix_char: e.suffix_char,
+            start_number: e.start_number,
+            note_line: e.note_line.as_ref(),
+            note_spacing: e.note_spacing.as_ref(),
+        });
+        for shape in [footnote, endnote] {
+            self.w.record(
+                HwpTag::FOOTNOTE_SHAPE,
+                level + 1,
+                &note_shape(shape.as_ref()),
+            );
+        }
+
+        // 표 135 쪽 테두리/배경 (양쪽, 짝수쪽, 홀수쪽)
+        for i in 0..3 {
+            let mut b = Bytes::new();
+            match def.page_border_fills.get(i) {
+                Some(pbf) => {
+                    let mut attr = 0u32;
+                    if pbf.text_border == PageBorderRef::Paper {
+                        attr |= 0x01;
+                    }
+                    attr |= (pbf.header_inside as u32) << 1;
+                    attr |= (pbf.footer_inside as u32) << 2;
+                    attr |= match pbf.fill_area {
+                        FillArea::Paper => 0,
+                        FillArea::Text => 1,
+                        FillArea::PaperLine => 2,
+                    } << 3;
+                    let o = &pbf.offset;
+                    b.u32(attr)
+                        .i16(o.left as i16)
+                        .i16(o.right as i16)
+                        .i16(o.top as i16)
+                        .i16(o.bottom as i16)
+                        .u16(pbf.border_fill_id);
+                }
+                None => {
+                    // 한글 기본값: 종이 기준, 5mm 간격, 테두리/배경 없음
+                    b.u32(0x01).i16(1417).i16(1417).i16(1417).i16(1417).u16(0);
+                }
+            }
+            self.w.record(HwpTag::PAGE_BORDER_FILL, level + 1, &b);
+        }
+
+        // 바탕쪽: 쪽 테두리/배경 뒤에 양쪽/짝수쪽/홀수쪽 순서의 문단 리스트
+        for mp in &def.master_pages {
+            let content = &mp.content;
+            let mut extra = Bytes::new();
+            extra
+                .u32(content.text_width.unwrap_or(0) as u32)
+                .u32(content.text_height.unwrap_or(0) as u32)
+                .u8(content.has_text_ref as u8)
+                .u8(content.has_num_ref as u8);
+            self.sub_list(level + 1, &content.paragraphs, list_attr(content), &extra);
+        }
+    }
+
+    // ── 일반 컨트롤 ──
+
+    fn control(&mut self, control: &Control, level: u16) {
+        match control {
+            Control::Header(hf) | Control::Footer(hf) => {
+                let id = if matches!(control, Control::Header(_)) {
+                    b"head"
+                } else {
+                    b"foot"
+                };
+                let apply = match hf.apply_page_type {
+                    PageApplyType::Even => 1,
+                    PageApplyType::Odd => 2,
+                    _ => 0,
+                };
+                let content = &hf.content;
+                let mut b = Bytes::new();
+                b.u32(ctrl_id(id))
+                    .u32(apply)
+                    .u32(content.text_width.unwrap_or(0) as u32)
+                    .u32(content.text_height.unwrap_or(0) as u32)
+                    .u8(content.has_text_ref as u8)
+                    .u8(content.has_num_ref as u8);
+                self.w.record(HwpTag::CTRL_HEADER, level, &b);
+
+                let mut extra = Bytes::new();
+                extra
+                    .u32(content.text_width.unwrap_or(0) as u32)
+                    .u32(content.text_height.unwrap_or(0) as u32)
+                    .u8(content.has_text_ref as u8)
+                    .u8(content.has_num_ref as u8);
+                extra.zeros(26 - extra.len());
+                self.sub_list(level + 1, &content.paragraphs, list_attr(content), &extra);
+            }
+            Control::FootNote(note) | Control::EndNote(note) => {
+                let id = if matches!(control, Control::FootNote(_)) {
+                    b"fn  "
+                } else {
+                    b"en  "
+                };
+                let mut b = Bytes::new();
+                b.u32(ctrl_id(id))
+                    .u32(note.number.unwrap_or(0) as u32)
+                    .wchar(None)
+                    .wchar(Some(')'))
+                    .u32(0)
+                    .u32(note.id as u32);
+                self.w.record(HwpTag::CTRL_HEADER, level, &b);
+                self.sub_list(
+                    level + 1,
+                    &note.content.paragraphs,
+                    list_attr(&note.content),
+                    &[0u8; 8],
+                );
+            }
+            Control::AutoNum(an) => {
+                let kind = match an.num_type {
+                    AutoNumType::Page => 0,
+                    AutoNumType::Footnote => 1,
+                    AutoNumType::Endnote => 2,
+                    AutoNumType::Picture => 3,
+                    AutoNumType::Table => 4,
+                    AutoNumType::Equation => 5,
+                    AutoNumType::TotalPage => 6,
+                };
+                let first = |s: &Option<String>| s.as_deref().and_then(|s| s.chars().next());
+                let mut b = Bytes::new();
+                b.u32(ctrl_id(b"atno"))
+                    .u32(kind | (number_type1(&an.number_type) << 4))
+                    .u16(an.num)
+                    .wchar(first(&an.user_char))
+                    .wchar(first(&an.prefix_char))
+                    .wchar(first(&an.suffix_char));
+                self.w.record(HwpTag::CTRL_HEADER, level, &b);
+            }
+            Control::NewNum(nn) => {
+                let kind = match nn.num_type {
+                    NumberingType::None => 0,
+                    NumberingType::Picture => 3,
+                    NumberingType::Table => 4,
+                    NumberingType::Equation => 5,
+                };
+                let mut b = Bytes::new();
+                b.u32(ctrl_id(b"nwno")).u32(kind).u16(nn.num);
+                self.w.record(HwpTag::CTRL_HEADER, level, &b);
+            }
+            Control::PageNumCtrl(_) => {
+                // 표 149 쪽 번호 위치: 아래 가운데, 아라비아 숫자
+                let mut b = Bytes::new();
+                b.u32(ctrl_id(b"pgnp"))
+                    .u32(5 << 8)
+                    .wchar(None)
+                    .wchar(None)
+                    .wchar(None)
+                    .wchar(Some('-'));
+                self.w.record(HwpTag::CTRL_HEADER, level, &b);
+            }
+            Control::PageHiding(ph) => {
+                let attr = ph.hide_header as u32
+                    | (ph.hide_footer as u32) << 1
+                    | (ph.hide_master_page as u32) << 2
+                    | (ph.hide_border as u32) << 3
+                    | (ph.hide_fill as u32) << 4
+                    | (ph.hide_page_num as u32) << 5;
+                let mut b = Bytes::new();
+                b.u32(ctrl_id(b"pghd")).u32(attr);
+                self.w.record(HwpTag::CTRL_HEADER, level, &b);
+            }
+            Control::FieldBegin(field) => {
+                let attr = field.editable as u32 | (field.dirty as u32) << 1;
+                let mut b = Bytes::new();
+                b.u32(ctrl_id(field_ctrl_id(&field.field_type)))
+                    .u32(attr)
+                    .u8(0)
+                    .string(field.name.as_deref().unwrap_or_default())
+                    .u32(field.field_id.unwrap_or(field.id as u32));
+                self.w.record(HwpTag::CTRL_HEADER, level, &b);
+            }
+            Control::Bookmark(bm) => {
+                let mut b = Bytes::new();
+                b.u32(ctrl_id(b"bokm"));
+                self.w.record(HwpTag::CTRL_HEADER, level, &b);
+                // 표 153 책갈피: CTRL_DATA의 ParameterSet에 이름을 둔다
+                let mut b = Bytes::new();
+                b.u16(0x021B)
+                    .i16(1)
+                    .u16(0)
+                    .u16(0x4000)
+                    .u16(1)
+                    .string(&bm.name);
+                self.w.record(HwpTag::CTRL_DATA, level + 1, &b);
+            }
+            Control::Dutmal(d) => {
+                let align = match d.alignment {
+                    HAlign::Justify => 0,
+                    HAlign::Left => 1,
+                    HAlign::Right => 2,
+                    HAlign::Center => 3,
+                    HAlign::Distribute => 4,
+                    HAlign::DistributeSpace => 5,
+                    _ => 0,
+                };
+                let position = match d.position {
+                    DutmalPosition::Top => 0,
+                    DutmalPosition::Bottom => 1,
+                    DutmalPosition::Center => 2,
+                };
+                let mut b = Bytes::new();
+                b.u32(ctrl_id(b"tdut"))
+                    .string(&d.main_text)
+                    .string(&d.sub_text)
+                    .u32(position)
+                    .u32(d.sz_ratio.unwrap_or(0) as u32)
+                    .u32(d.option.unwrap_or(0))
+                    .u32(d.style_id_ref.unwrap_or(0) as u32)
+                    .u32(align);
+                self.w.record(HwpTag::CTRL_HEADER, level, &b);
+            }
+            Control::Compose(c) => {
+                let border = match c.circle_type.as_deref() {
+                    Some("SHAPE_CIRCLE") => 1,
+                    Some("SHAPE_REVERSAL_CIRCLE") => 2,
+                    Some("SHAPE_RECTANGLE") => 3,
+                    Some("SHAPE_REVERSAL_RECTANGLE") => 4,
+                    Some("SHAPE_TRIANGLE") => 5,
+                    Some("SHAPE_REVERSAL_TIRANGLE") | Some("SHAPE_REVERSAL_TRIANGLE") => 6,
+                    Some("SHAPE_LIGHT") => 7,
+                    Some("SHAPE_RHOMBUS") => 8,
+                    Some("SHAPE_REVERSAL_RHOMBUS") => 9,
+                    Some("SHAPE_ROUNDED_RECTANGLE") => 10,
+                    Some("SHAPE_EMPTY_CIRCULATE_TRIANGLE") => 11,
+                    Some("SHAPE_THIN_CIRCULATE_TRIANGLE") => 12,
+                    _ => 0,
+                };
+                let spread = match c.compose_type.as_deref() {
+                    Some("OVERLAP") => 1,
+                    _ => 0,
+                };
+                let mut b = Bytes::new();
+                b.u32(ctrl_id(b"tcps"))
+                    .string(c.compose_text.as_deref().unwrap_or_default())
+                    .u8(border)
+                    .i8(c.char_sz.unwrap_or(0) as i8)
+                    .u8(spread)
+                    .u8(c.char_pr_refs.len() as u8);
+                for id in &c.char_pr_refs {
+                    b.u32(*id);
+                }
+                self.w.record(HwpTag::CTRL_HEADER, level, &b);
+            }
+            Control::HiddenDesc(hd) => {
+                let mut b = Bytes::new();
+                b.u32(ctrl_id(b"tcmt"));
+                self.w.record(HwpTag::CTRL_HEADER, level, &b);
+                self.sub_list(level + 1, &hd.paragraphs, 0, &[0u8; 8]);
+            }
+            // 단 정의는 Ctrl::Column, 필드 끝은 인라인 문자로 기록된다
+            Control::Column(_) | Control::FieldEnd => {}
+        }
+    }
+
+    // ── 개체 ──
+
+    fn object(&mut self, object: &ShapeObject, level: u16) {
+        match object {
+            ShapeObject::Table(table) => self.table(table, level),
+            ShapeObject::Equation(eq) => {
+                self.w.record(
+                    HwpTag::CTRL_HEADER,
+                    level,
+                    &object_common(b"eqed", &eq.common),
+                );
+                self.caption(&eq.common, level + 1);
+                let mut b = Bytes::new();
+                b.u32(match eq.line_mode {
+                    EquationLineMode::Line => 1,
+                    EquationLineMode::Char => 0,
+                })
+                .string(&eq.script)
+                .u32(eq.base_unit as u32)
+                .color(eq.text_color)
+                .i16(eq.baseline)
+                .u16(0)
+                .string(eq.version.as_deref().unwrap_or("Equation Version 60"))
+                .string(eq.font.as_deref().unwrap_or_default());
+                self.w.record(HwpTag::EQEDIT, level + 1, &b);
+            }
+            _ => {
+                let Some(common) = shape_common(object) else {
+                    return;
+                };
+                self.w
+                    .record(HwpTag::CTRL_HEADER, level, &object_common(b"gso ", common));
+                self.caption(common, level + 1);
+                self.shape_component(object, level + 1, true);
+            }
+        }
+    }
+
+    /// 캡션 (표 71 문단 리스트 헤더 + 표 72 캡션)
+    fn caption(&mut self, common: &ShapeCommon, level: u16) {
+        let Some(caption) = &common.caption else {
+            return;
+        };
+        let side = match caption.side {
+            CaptionSide::Left => 0,
+            CaptionSide::Right => 1,
+            CaptionSide::Top => 2,
+            CaptionSide::Bottom => 3,
+        };
+        let mut extra = Bytes::new();
+        extra
+            .u32(side | (caption.full_size as u32) << 2)
+            .u32(caption.width as u32)
+            .i16(caption.gap as i16)
+            .u32(caption.last_width.unwrap_or(common.size.width) as u32);
+        self.sub_list(
+            level,
+            &caption.content.paragraphs,
+            list_attr(&caption.content),
+            &extra,
+        );
+    }
+
+    /// 표 74 표 개체 + 셀 리스트
+    fn table(&mut self, table: &Table, level: u16) {
+        self.w.record(
+            HwpTag::CTRL_HEADER,
+            level,
+            &object_common(b"tbl ", &table.common),
+        );
+        self.caption(&table.common, level + 1);
+
+        let mut attr = match table.page_break {
+            TablePageBreak::None => 0,
+            TablePageBreak::Cell => 1,
+            TablePageBreak::Table => 2,
+        };
+        if table.repeat_header {
+            attr |= 0x04;
+        }
+        let row_count = table.row_count.max(table.rows.len() as u16);
+        let pad = &table.in_margin;
+        let mut b = Bytes::new();
+        b.u32(attr)
+            .u16(row_count)
+            .u16(table.col_count)
+            .i16(table.cell_spacing as i16)
+            .i16(pad.left as i16)
+            .i16(pad.right as i16)
+            .i16(pad.top as i16)
+            .i16(pad.bottom as i16);
+        for i in 0..row_count as usize {
+            b.u16(table.rows.get(i).map_or(0, |r| r.cells.len() as u16));
+        }
+        b.u16(table.border_fill_id);
+        b.u16(table.cell_zones.len() as u16);
+        for z in &table.cell_zones {
+            b.u16(z.start_col)
+                .u16(z.start_row)
+                .u16(z.end_col)
+                .u16(z.end_row)
+                .u16(z.border_fill_id);
+        }
+        self.w.record(HwpTag::TABLE, level + 1, &b);
+
+        for row in &table.rows {
+            for cell in &row.cells {
+                self.table_cell(cell, level + 1);
+            }
+        }
+    }
+
+    /// 표 80 셀 속성
+    fn table_cell(&mut self, cell: &TableCell, level: u16) {
+        let m = &cell.cell_margin;
+        let mut extra = Bytes::new();
+        extra
+            .u16(cell.col)
+            .u16(cell.row)
+            .u16(cell.col_span.max(1))
+            .u16(cell.row_span.max(1))
+            .u32(cell.width as u32)
+            .u32(cell.height as u32)
+            .i16(m.left as i16)
+            .i16(m.right as i16)
+            .i16(m.top as i16)
+            .i16(m.bottom as i16)
+            .u16(cell.border_fill_id)
+            .u32(cell.content.text_width.unwrap_or(cell.width) as u32);
+        self.sub_list(
+            level,
+            &cell.content.paragraphs,
+            list_attr(&cell.content),
+            &extra,
+        );
+    }
+
+    /// 표 81 개체 요소 + 도형별 레코드
+    fn shape_component(&mut self, object: &ShapeObject, level: u16, top: bool) {
+        let Some(common) = shape_common(object) else {
+            return;
+        };
+        let (id, component): (&[u8; 4], Option<&ShapeComponentData>) = match object {
+            ShapeObject::Picture(p) => (b"$pic", Some(&p.component)),
+            ShapeObject::Line(s) => (b"$lin", Some(&s.component)),
+            ShapeObject::Rectangle(s) => (b"$rec", Some(&s.component)),
+            ShapeObject::Ellipse(s) => (b"$ell", Some(&s.component)),
+            ShapeObject::Arc(s) => (b"$arc", Some(&s.component)),
+            ShapeObject::Polygon(s) => (b"$pol", Some(&s.component)),
+            ShapeObject::Curve(s) => (b"$cur", Some(&s.component)),
+            ShapeObject::Container(s) => (b"$con", Some(&s.component)),
+            _ => return,
+        };
+
+        let mut b = Bytes::new();
+        b.u32(ctrl_id(id));
+        if top {
+            b.u32(ctrl_id(id));
+        }
+        shape_component_common(&mut b, common, component);
+
+        // 그리기 개체 공통 속성 (표 87): 그림/묶음 개체 제외
+        let draw = match object {
+            ShapeObject::Line(s) => Some((&s.line_shape, None, s.shadow.as_ref())),
+            ShapeObject::Rectangle(s) => Some((&s.line_shape, s.fill.as_ref(), s.shadow.as_ref())),
+            ShapeObject::Ellipse(s) => Some((&s.line_shape, s.fill.as_ref(), s.shadow.as_ref())),
+            ShapeObject::Arc(s) => Some((&s.line_shape, s.fill.as_ref(), s.shadow.as_ref())),
+            ShapeObject::Polygon(s) => Some((&s.line_shape, s.fill.as_ref(), s.shadow.as_ref())),
+            ShapeObject::Curve(s) => Some((&s.line_shape, s.fill.as_ref(), s.shadow.as_ref())),
+            _ => None,
+        };
+        if let Some((line, fill, shadow)) = draw {
+            line_info(&mut b, line);
+            fill_brush(&mut b, fill, self.bin_ids, true);
+            let shadow = shadow.cloned().unwrap_or_default();
+            b.u32(shadow_type(&shadow.shadow_type))
+                .color(shadow.color)
+                .i32(shadow.offset_x)
+                .i32(shadow.offset_y)
+                .u32(0)
+                .u8(0)
+                .u8(shadow.alpha);
+        }
+        if let ShapeObject::Container(c) = object {
+            b.u16(c.children.len() as u16);
+            for child in &c.children {
+                b.u32(child_ctrl_id(child));
+            }
+        }
+        // 묶음 개체 속성(표 120)도 SHAPE_COMPONENT 레코드 뒤쪽에 이어서 기록된다
+        self.w.record(HwpTag::SHAPE_COMPONENT, level, &b);
+
+        let child = level + 1;
+        let size = &common.size;
+        match object {
+            ShapeObject::Rectangle(rect) => {
+                if let Some(draw_text) = &rect.draw_text {
+                    self.draw_text(draw_text, child, size.width);
+                }
+                let mut b = Bytes::new();
+                b.u8(rect.ratio);
+                let points = if rect.points.iter().all(|p| *p == Point::default()) {
+                    corner_points(size.width, size.height)
+                } else {
+                    rect.points.clone()
+                };
+                for p in &points {
+                    b.i32(p.x).i32(p.y);
+                }
+                self.w.record(HwpTag::SHAPE_COMPONENT_RECTANGLE, child, &b);
+            }
+            ShapeObject::Line(line) => {
+                if let Some(draw_text) = &line.draw_text {
+                    self.draw_text(draw_text, child, size.width);
+                }
+                let mut b = Bytes::new();
+                b.i32(line.start_pt.x)
+                    .i32(line.start_pt.y)
+                    .i32(line.end_pt.x)
+                    .i32(line.end_pt.y)
+                    .u32(line.is_reverse_hv.unwrap_or(false) as u32);
+                self.w.record(HwpTag::SHAPE_COMPONENT_LINE, child, &b);
+            }
+            ShapeObject::Ellipse(e) => {
+                if let Some(draw_text) = &e.draw_text {
+                    self.draw_text(draw_text, child, size.width);
+                }
+                let mut attr = e.interval_dirty.unwrap_or(false) as u32;
+                attr |= (e.has_arc as u32) << 1;
+                attr |= arc_type(&e.arc_type) << 2;
+                let mut b = Bytes::new();
+                b.u32(attr);
+                for p in [
+                    &e.center, &e.axis1, &e.axis2, &e.start1, &e.end1, &e.start2, &e.end2,
+                ] {
+                    b.i32(p.x).i32(p.y);
+                }
+                self.w.record(HwpTag::SHAPE_COMPONENT_ELLIPSE, child, &b);
+            }
+            ShapeObject::Arc(a) => {
+                if let Some(draw_text) = &a.draw_text {
+                    self.draw_text(draw_text, child, size.width);
+                }
+                let mut b = Bytes::new();
+                b.u32(arc_type(&a.arc_type));
+                for p in [&a.center, &a.axis1, &a.axis2] {
+                    b.i32(p.x).i32(p.y);
+                }
+                self.w.record(HwpTag::SHAPE_COMPONENT_ARC, child, &b);
+            }
+            ShapeObject::Polygon(p) => {
+                if let Some(draw_text) = &p.draw_text {
+                    self.draw_text(draw_text, child, size.width);
+                }
+                let mut b = Bytes::new();
+                b.i16(p.points.len() as i16);
+                for pt in &p.points {
+                    b.i32(pt.x);
+                }
+                for pt in &p.points {
+                    b.i32(pt.y);
+                }
+                self.w.record(HwpTag::SHAPE_COMPONENT_POLYGON, child, &b);
+            }
+            ShapeObject::Curve(c) => {
+                if let Some(draw_text) = &c.draw_text {
+                    self.draw_text(draw_text, child, size.width);
+                }
+                // 세그먼트 끝점을 이어 점 목록으로 만든다 (첫 점 + 각 세그먼트의 끝점)
+                let mut points: Vec<(i32, i32)> = Vec::new();
+                if let Some(first) = c.segments.first() {
+                    points.push((first.x1, first.y1));
+                }
+                points.extend(c.segments.iter().map(|s| (s.x2, s.y2)));
+                let mut b = Bytes::new();
+                b.i16(points.len() as i16);
+                for (x, _) in &points {
+                    b.i32(*x);
+                }
+                for (_, y) in &points {
+                    b.i32(*y);
+                }
+                for s in &c.segments {
+                    b.u8(match s.segment_type {
+                        CurveSegmentType::Line => 0,
+                        CurveSegmentType::Curve => 1,
+                    });
+                }
+                self.w.record(HwpTag::SHAPE_COMPONENT_CURVE, child, &b);
+            }
+            ShapeObject::Picture(pic) => {
+                let b = self.picture(pic);
+                self.w.record(HwpTag::SHAPE_COMPONENT_PICTURE, child, &b);
+            }
+            ShapeObject::Container(c) => {
+                for obj in &c.children {
+                    self.shape_component(obj, child, false);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// 글상자 (표 85 문단 리스트 헤더 + 글상자 속성)
+    fn draw_text(&mut self, draw_text: &SubList, level: u16, width: HwpUnit) {
+        let mut extra = Bytes::new();
+        // 안쪽 여백 1mm(283) 기본
+        extra
+            .i16(283)
+            .i16(283)
+            .i16(283)
+            .i16(283)
+            .u32(draw_text.text_width.unwrap_or(width) as u32)
+            .zeros(13);
+        self.sub_list(level, &draw_text.paragraphs, list_attr(draw_text), &extra);
+    }
+
+    /// 표 107 그림 개체 속성
+    fn picture(&self, pic: &Picture) -> Bytes {
+        let size = &pic.common.size;
+        let mut b = Bytes::new();
+        match &pic.line_shape {
+            Some(line) => {
+                b.color(line.color).i32(line.width).u32(line_attr(line));
+            }
+            None => {
+                b.u32(0).i32(0).u32(0);
+            }
+        }
+        let rect = pic
+            .img_rect
+            .clone()
+            .unwrap_or_else(|| corner_points(size.width, size.height));
+        for p in &rect {
+            b.i32(p.x).i32(p.y);
+        }
+        let clip = pic.img_clip.clone().unwrap_or(Margin {
+            left: 0,
+            top: 0,
+            right: size.width,
+            bottom: size.height,
+        });
+        b.i32(clip.left)
+            .i32(clip.top)
+            .i32(clip.right)
+            .i32(clip.bottom);
+        let inner = pic.in_margin.clone().unwrap_or_default();
+        b.i16(inner.left as i16)
+            .i16(inner.right as i16)
+            .i16(inner.top as i16)
+            .i16(inner.bottom as i16);
+        image_info(&mut b, pic.img.bright, pic.img.contrast, &pic.img.effect);
+        b.u16(self.bin_ids.resolve(&pic.img.binary_item_id))
+            .u8(pic.img.alpha)
+            .u32(pic.common.id as u32)
+            .u32(0);
+        let dim = pic.img_dim.clone().unwrap_or(Size {
+            width: size.width,
+            height: size.height,
+        });
+        b.u32(dim.width as u32).u32(dim.height as u32).u8(0);
+        b
+    }
+}
+
+// ═══════════════════════════════════════════
+// 레코드 데이터 헬퍼
+// ═══════════════════════════════════════════
+
+/// 각주/미주 모양을 공통으로 기록하기 위한 뷰
+struct NoteShape<'a> {
+    number_format: &'a NumberType1,
+    placement: u32,
+    numbering: u32,
+    superscript: bool,
+    beneath_text: bool,
+    user_char: Option<char>,
+    prefix_char: Option<char>,
+    suffix_char: Option<char>,
+    start_number: u16,
+    note_line: Option<&'a hwp_model::section::NoteLine>,
+    note_spacing: Option<&'a hwp_model::section::NoteSpacing>,
+}
+
+/// 표 133 각주/미주 모양 (28바이트). 정의가 없으면 한글 기본값
+fn note_shape(shape: Option<&NoteShape>) -> Bytes {
+    let mut b = Bytes::new();
+    let Some(s) = shape else {
+        b.u32(0)
+            .wchar(None)
+            .wchar(None)
+            .wchar(Some(')'))
+            .u16(1)
+            .u32(u32::MAX)
+            .u16(850)
+            .u16(567)
+            .u16(283)
+            .u8(line_type3(&LineType3::Solid))
+            .u8(border_width("0.12mm"))
+            .color(Some(0));
+        return b;
+    };
+    let attr = number_type1(s.number_format)
+        | s.placement << 8
+        | s.numbering << 10
+        | (s.superscript as u32) << 12
+        | (s.beneath_text as u32) << 13;
+    let spacing = s.note_spacing.cloned().unwrap_or_default();
+    b.u32(attr)
+        .wchar(s.user_char)
+        .wchar(s.prefix_char)
+        .wchar(s.suffix_char)
+        .u16(s.start_number)
+        .u32(s.note_line.map_or(u32::MAX, |l| l.length as u32))
+        .u16(spacing.above_line)
+        .u16(spacing.below_line)
+        .u16(spacing.between_notes);
+    match s.note_line {
+        Some(line) => {
+            b.u8(line_type3(&line.line_type))
+                .u8(border_width(&line.width))
+                .color(line.color);
+        }
+        None => {
+            b.u8(line_type3(&LineType3::Solid))
+                .u8(border_width("0.12mm"))
+                .color(Some(0));
+        }
+    }
+    b
+}
+
+/// 표 138 단 정의
+fn column_def(col: Option<&ColumnControl>, def: Option<&ColumnDef>) -> Bytes {
+    let (column_type, count, layout, same_size, gap) = match (col, def) {
+        (Some(c), _) => (
+            &c.column_type,
+            c.col_count,
+            &c.layout,
+            c.same_size,
+            c.same_gap,
+        ),
+        (None, Some(d)) => (
+            &d.column_type,
+            d.col_count,
+            &d.layout,
+            d.same_size,
+            d.same_gap,
+        ),
+        (None, None) => (&ColumnType::Newspaper, 1, &ColumnLayout::Left, true, 0),
+    };
+    let count = count.max(1);
+    let widths: Vec<HwpUnit> = def
+        .map(|d| d.col_sizes.iter().map(|s| s.width).collect())
+        .unwrap_or_default();
+    let same_size = same_size || widths.len() != count as usize;
+
+    let mut attr = match column_type {
+        ColumnType::Newspaper => 0,
+        ColumnType::BalancedNewspaper => 1,
+        ColumnType::Parallel => 2,
+    };
+    attr |= (count & 0xFF) << 2;
+    attr |= match layout {
+        ColumnLayout::Left => 0,
+        ColumnLayout::Right => 1,
+        ColumnLayout::Mirror => 2,
+    } << 10;
+    if same_size {
+        attr |= 0x1000;
+    }
+
+    let mut b = Bytes::new();
+    b.u32(ctrl_id(b"cold")).u16(attr).i16(gap as i16);
+    if !same_size {
+        for w in &widths {
+            b.i16(*w as i16);
+        }
+    }
+    b.u16(0);
+    match def.and_then(|d| d.col_line.as_ref()) {
+        Some(line) => {
+            b.u8(line_type3(&line.line_type) + 1)
+                .u8(border_width(&line.width))
+                .color(line.color);
+        }
+        None => {
+            b.u8(0).u8(0).u32(0);
+        }
+    }
+    b
+}
+
+/// 문단 리스트 헤더 속성: 텍스트 방향(bit 0-2), 세로 정렬(bit 5-6)
+fn list_attr(list: &SubList) -> u32 {
+    let direction = match list.text_direction {
+        TextDirection::Horizontal => 0,
+        _ => 1,
+    };
+    let valign = match list.vert_align {
+        VAlign::Center => 1,
+        VAlign::Bottom => 2,
+        _ => 0,
+    };
+    direction | valign << 5
+}
+
+/// 표 69 개체 공통 속성
+fn object_common(id: &[u8; 4], common: &ShapeCommon) -> Bytes {
+    let pos = &common.position;
+    let size = &common.size;
+
+    let mut attr = pos.treat_as_char as u32;
+    attr |= (pos.affect_line_spacing as u32) << 2;
+    attr |= match pos.vert_rel_to {
+        RelativeTo::Paper => 0,
+        RelativeTo::Page => 1,
+        RelativeTo::Column | RelativeTo::Para => 2,
+    } << 3;
+    attr |= match pos.vert_align {
+        VAlign::Center => 1,
+        VAlign::Bottom => 2,
+        VAlign::Inside => 3,
+        VAlign::Outside => 4,
+        _ => 0,
+    } << 5;
+    attr |= match pos.horz_rel_to {
+        RelativeTo::Paper => 0,
+        RelativeTo::Page => 1,
+        RelativeTo::Column => 2,
+        RelativeTo::Para => 3,
+    } << 8;
+    attr |= match pos.horz_align {
+        HAlign::Center => 1,
+        HAlign::Right => 2,
+        HAlign::Inside => 3,
+        HAlign::Outside => 4,
+        _ => 0,
+    } << 10;
+    attr |= (pos.flow_with_text as u32) << 13;
+    attr |= (pos.allow_overlap as u32) << 14;
+    attr |= match size.width_rel_to {
+        SizeRelation::Paper => 0,
+        SizeRelation::Page => 1,
+        SizeRelation::Column => 2,
+        SizeRelation::Para => 3,
+        SizeRelation::Absolute | SizeRelation::Percent => 4,
+    } << 15;
+    attr |= match size.height_rel_to {
+        SizeRelation::Paper => 0,
+        SizeRelation::Page => 1,
+        _ => 2,
+    } << 18;
+    attr |= (size.protect as u32) << 20;
+    attr |= match common.text_wrap {
+        TextWrap::Square => 0,
+        TextWrap::Tight => 1,
+        TextWrap::Through => 2,
+        TextWrap::TopAndBottom => 3,
+        TextWrap::BehindText => 4,
+        TextWrap::InFrontOfText => 5,
+    } << 21;
+    attr |= match common.text_flow {
+        TextFlow::BothSides => 0,
+        TextFlow::LeftOnly => 1,
+        TextFlow::RightOnly => 2,
+        TextFlow::LargestOnly => 3,
+    } << 24;
+    attr |= match common.numbering_type {
+        NumberingType::None => 0,
+        NumberingType::Picture => 1,
+        NumberingType::Table => 2,
+        NumberingType::Equation => 3,
+    } << 26;
+
+    // 바깥 여백은 파서(`parse_object_common`)가 읽는 순서대로 기록한다
+    let margin = common.out_margin.clone().unwrap_or_default();
+    let mut b = Bytes::new();
+    b.u32(ctrl_id(id))
+        .u32(attr)
+        .i32(pos.vert_offset)
+        .i32(pos.horz_offset)
+        .u32(size.width as u32)
+        .u32(size.height as u32)
+        .i32(common.z_order)
+        .i16(margin.bottom as i16)
+        .i16(margin.left as i16)
+        .i16(margin.right as i16)
+        .i16(margin.top as i16)
+        .u32(common.id as u32)
+        .i32(0)
+        .string(common.comment.as_deref().unwrap_or_default());
+    b
+}
+
+/// 표 82 개체 요소 속성 (크기/위치/회전/변환 행렬)
+fn shape_component_common(
+    b: &mut Bytes,
+    common: &ShapeCommon,
+    component: Option<&ShapeComponentData>,
+) {
+    let default = ShapeComponentData::default();
+    let c = component.unwrap_or(&default);
+    let size = &common.size;
+    let offset = c.offset.clone().unwrap_or_default();
+    let cur = c.cur_size.clone().unwrap_or(Size {
+        width: size.width,
+        height: size.height,
+    });
+    let org = c.org_size.clone().unwrap_or_else(|| cur.clone());
+    let flip = c.flip.clone().unwrap_or_default();
+    let rotation = c.rotation.clone().unwrap_or(Rotation {
+        center_x: cur.width / 2,
+        center_y: cur.height / 2,
+        ..Default::default()
+    });
+    let attr = flip.horizontal as u32 | (flip.vertical as u32) << 1;
+
+    b.i32(offset.x)
+        .i32(offset.y)
+        .u16(c.group_level as u16)
+        .u16(1)
+        .u32(org.width as u32)
+        .u32(org.height as u32)
+        .u32(cur.width as u32)
+        .u32(cur.height as u32)
+        .u32(attr)
+        .i16(rotation.angle as i16)
+        .i32(rotation.center_x)
+        .i32(rotation.center_y);
+
+    // 행렬: 이동 행렬 1개 + (크기, 회전) 쌍 1개
+    const IDENTITY: [f32; 6] = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+    let (trans, scale, rot) = match &c.rendering_info {
+        Some(info) => (info.trans_matrix, info.sca_matrix, info.rot_matrix),
+        None => {
+            let mut trans = IDENTITY;
+            trans[2] = offset.x as f32;
+            trans[5] = offset.y as f32;
+            (trans, IDENTITY, IDENTITY)
+        }
+    };
+    b.u16(1);
+    for m in [trans, scale, rot] {
+        for v in m {
+            b.f64(v as f64);
+        }
+    }
+}
+
+/// 표 88 테두리 선 정보 속성
+fn line_attr(line: &ShapeLineInfo) -> u32 {
+    let style = match line.style {
+        LineType1::None => 0,
+        LineType1::Solid | LineType1::Thick => 1,
+        LineType1::Dot => 2,
+        LineType1::Dash => 3,
+        LineType1::DashDot => 4,
+        LineType1::DashDotDot => 5,
+    };
+    let cap = match line.end_cap {
+        LineEndCap::Round => 0,
+        LineEndCap::Flat => 1,
+    };
+    style
+        | cap << 6
+        | arrow_type(&line.head_style) << 10
+        | arrow_type(&line.tail_style) << 16
+        | arrow_size(&line.head_size) << 22
+        | arrow_size(&line.tail_size) << 26
+        | (line.head_fill as u32) << 30
+        | (line.tail_fill as u32) << 31
+}
+
+/// 표 87 테두리 선 정보 (색, 굵기, 속성, 외곽선 스타일)
+fn line_info(b: &mut Bytes, line: &ShapeLineInfo) {
+    b.color(line.color)
+        .i32(line.width)
+        .u32(line_attr(line))
+        .u8(match line.outline_style {
+            LineOutlineStyle::Normal => 0,
+            LineOutlineStyle::Outer => 1,
+            LineOutlineStyle::Inner => 2,
+        });
+}
+
+fn arrow_type(v: &ArrowType) -> u32 {
+    match v {
+        ArrowType::Normal => 0,
+        ArrowType::Arrow => 1,
+        ArrowType::Spear => 2,
+        ArrowType::ConcaveArrow => 3,
+        ArrowType::EmptyDiamond | ArrowType::FilledDiamond => 4,
+        ArrowType::EmptyCircle | ArrowType::FilledCircle => 5,
+        ArrowType::EmptyBox | ArrowType::FilledBox => 6,
+    }
+}
+
+fn arrow_size(v: &ArrowSize) -> u32 {
+    match v {
+        ArrowSize::SmallSmall => 0,
+        ArrowSize::SmallMedium => 1,
+        ArrowSize::SmallLarge => 2,
+        ArrowSize::MediumSmall => 3,
+        ArrowSize::MediumMedium => 4,
+        ArrowSize::MediumLarge => 5,
+        ArrowSize::LargeSmall => 6,
+        ArrowSize::LargeMedium => 7,
+        ArrowSize::LargeLarge => 8,
+    }
+}
+
+fn shadow_type(v: &ShapeShadowType) -> u32 {
+    match v {
+        ShapeShadowType::None => 0,
+        ShapeShadowType::ParellelLeftTop => 1,
+        ShapeShadowType::ParellelRightTop => 2,
+        ShapeShadowType::ParellelLeftBottom => 3,
+        ShapeShadowType::ParellelRightBottom => 4,
+        ShapeShadowType::ShearLeftTop => 5,
+        ShapeShadowType::ShearRightTop => 6,
+        ShapeShadowType::ShearLeftBottom => 7,
+        ShapeShadowType::ShearRightBottom => 8,
+        ShapeShadowType::PersLeftTop => 9,
+        ShapeShadowType::PersRightTop => 10,
+        ShapeShadowType::PersLeftBottom => 11,
+        ShapeShadowType::PersRightBottom => 12,
+        ShapeShadowType::ScaleNarrow => 13,
+        ShapeShadowType::ScaleEnlarge => 14,
+    }
+}
+
+fn arc_type(v: &ArcType) -> u32 {
+    match v {
+        ArcType::Normal => 0,
+        ArcType::Pie => 1,
+        ArcType::Chord => 2,
+    }
+}
+
+fn number_type1(v: &NumberType1) -> u32 {
+    match v {
+        NumberType1::Digit => 0,
+        NumberType1::CircledDigit => 1,
+        NumberType1::RomanCapital => 2,
+        NumberType1::RomanSmall => 3,
+        NumberType1::LatinCapital => 4,
+        NumberType1::LatinSmall => 5,
+        NumberType1::CircledLatinCapital => 6,
+        NumberType1::CircledLatinSmall => 7,
+        NumberType1::HangulSyllable => 8,
+        NumberType1::CircledHangulSyllable => 9,
+        NumberType1::HangulJamo => 10,
+        NumberType1::CircledHangulJamo => 11,
+        NumberType1::HangulPhonetic => 12,
+        NumberType1::Ideograph => 13,
+        NumberType1::CircledIdeograph => 14,
+    }
+}
+
+/// 크기(w, h) 사각형의 네 꼭짓점 (좌상, 우상, 우하, 좌하)
+fn corner_points(width: HwpUnit, height: HwpUnit) -> [Point; 4] {
+    [
+        Point { x: 0, y: 0 },
+        Point { x: width, y: 0 },
+        Point {
+            x: width,
+            y: height,
+        },
+        Point { x: 0, y: height },
+    ]
+}
+
+fn shape_common(object: &ShapeObject) -> Option<&ShapeCommon> {
+    Some(match object {
+        ShapeObject::Table(s) => &s.common,
+        ShapeObject::Picture(s) => &s.common,
+        ShapeObject::Line(s) => &s.common,
+        ShapeObject::Rectangle(s) => &s.common,
+        ShapeObject::Ellipse(s) => &s.common,
+        ShapeObject::Arc(s) => &s.common,
+        ShapeObject::Polygon(s) => &s.common,
+        ShapeObject::Curve(s) => &s.common,
+        ShapeObject::Container(s) => &s.common,
+        ShapeObject::Equation(s) => &s.common,
+        ShapeObject::ConnectLine(_)
+        | ShapeObject::TextArt(_)
+        | ShapeObject::Ole(_)
+        | ShapeObject::Chart(_)
+        | ShapeObject::Video(_)
+        | ShapeObject::Form(_) => return None,
+    })
+}
+
+/// 묶음 개체 하위 요소의 컨트롤 ID
+fn child_ctrl_id(object: &ShapeObject) -> u32 {
+    ctrl_id(match object {
+        ShapeObject::Picture(_) => b"$pic",
+        ShapeObject::Line(_) => b"$lin",
+        ShapeObject::Rectangle(_) => b"$rec",
+        ShapeObject::Ellipse(_) => b"$ell",
+        ShapeObject::Arc(_) => b"$arc",
+        ShapeObject::Polygon(_) => b"$pol",
+        ShapeObject::Curve(_) => b"$cur",
+        ShapeObject::Container(_) => b"$con",
+        _ => b"$unk",
+    })
+}
@@ -0,0 +1,875 @@
+/// DocInfo 스트림 작성
+///
+/// 스펙 문서 4.2: 문서 정보 레코드. 파서(`document::docinfo`)가 읽는 순서와 레벨에 맞춰
+/// DOCUMENT_PROPERTIES, ID_MAPPINGS는 레벨 0, 나머지 ID 매핑 대상은 레벨 1로 기록한다.
+use super::record::{Bytes, RecordWriter};
+use super::{bin_compression, BinIds};
+use crate::document::docinfo::HwpTag;
+use hwp_model::document::{Document, ImageFormat};
+use hwp_model::hints::{HwpBinCompression, HwpRawRecord};
+use hwp_model::resources::*;
+use hwp_model::types::*;
+
+pub fn write_docinfo(doc: &Document, bin_ids: &BinIds) -> Vec<u8> {
+    let res = &doc.resources;
+    let mut w = RecordWriter::new();
+
+    w.record(HwpTag::DOCUMENT_PROPERTIES, 0, &document_properties(doc));
+    w.record(HwpTag::ID_MAPPINGS, 0, &id_mappings(doc, bin_ids));
+
+    for (id, item) in bin_ids.ordered(&doc.binaries) {
+        // 속성: 타입 EMBEDDING(1), bit 4-5 압축 방식
+        let compression = doc
+            .hwp_hints
+            .as_ref()
+            .map(|hints| bin_compression(hints, &item.id))
+            .unwrap_or_default();
+        let attributes = 0x0001
+            | match compression {
+                HwpBinCompression::StorageDefault => 0x0000,
+                HwpBinCompression::Compress => 0x0010,
+                HwpBinCompression::NoCompress => 0x0020,
+            };
+        let mut b = Bytes::new();
+        b.u16(attributes).u16(id).string(&binary_extension(item));
+        w.record(HwpTag::BIN_DATA, 1, &b);
+    }
+
+    for font in font_lists(&res.fonts).into_iter().flatten() {
+        w.record(HwpTag::FACE_NAME, 1, &face_name(font));
+    }
+    for bf in &res.border_fills {
+        w.record(HwpTag::BORDER_FILL, 1, &border_fill(bf, bin_ids));
+    }
+    for cs in &res.char_shapes {
+        w.record(HwpTag::CHAR_SHAPE, 1, &char_shape(cs));
+    }
+    for td in &res.tab_defs {
+        w.record(HwpTag::TAB_DEF, 1, &tab_def(td));
+    }
+    for n in &res.numberings {
+        w.record(HwpTag::NUMBERING, 1, &numbering(n));
+    }
+    for b in &res.bullets {
+        w.record(HwpTag::BULLET, 1, &bullet(b, bin_ids));
+    }
+    for ps in &res.para_shapes {
+        w.record(HwpTag::PARA_SHAPE, 1, &para_shape(ps));
+    }
+    for s in &res.styles {
+        w.record(HwpTag::STYLE, 1, &style(s));
+    }
+    for m in &res.memo_shapes {
+        w.record(HwpTag::MEMO_SHAPE, 1, &memo_shape(m));
+    }
+    for record in extra_records(doc) {
+        w.record(record.tag_id, record.level, &record.data);
+    }
+
+    if let Some(compat) = &doc.compatible_document {
+        let target = match compat {
+            CompatibleDocument::Hwp201X => 0u32,
+            CompatibleDocument::Hwp200X => 1,
+            CompatibleDocument::MsWord => 2,
+        };
+        w.record(HwpTag::COMPATIBLE_DOCUMENT, 0, &target.to_le_bytes());
+        w.record(HwpTag::LAYOUT_COMPATIBILITY, 1, &[0u8; 20]);
+    }
+
+    w.finish()
+}
+
+/// 표 14 문서 속성 (26바이트)
+fn document_properties(doc: &Document) -> Bytes {
+    let s = &doc.settings;
+    let hints = doc.hwp_hints.as_ref();
+    let mut b = Bytes::new();
+    b.u16(doc.sections.len().max(1) as u16)
+        .u16(s.page_start)
+        .u16(s.footnote_start)
+        .u16(s.endnote_start)
+        .u16(s.picture_start)
+        .u16(s.table_start)
+        .u16(s.equation_start)
+        .u32(hints.and_then(|h| h.caret_list_id).unwrap_or(0))
+        .u32(hints.and_then(|h| h.caret_para_id).unwrap_or(0))
+        .u32(hints.and_then(|h| h.caret_char_pos).unwrap_or(0));
+    b
+}
+
+/// 표 15 아이디 매핑 헤더 (INT32 × 18)
+fn id_mappings(doc: &Document, bin_ids: &BinIds) -> Bytes {
+    let res = &doc.resources;
+    let mut b = Bytes::new();
+    b.i32(bin_ids.len() as i32);
+    for list in font_lists(&res.fonts) {
+        b.i32(list.len() as i32);
+    }
+    for count in [
+        res.border_fills.len(),
+        res.char_shapes.len(),
+        res.tab_defs.len(),
+        res.numberings.len(),
+        res.bullets.len(),
+        res.para_shapes.len(),
+        res.styles.len(),
+        res.memo_shapes.len(),
+        extra_count(doc, HwpTag::TRACK_CHANGE),
+        extra_count(doc, HwpTag::TRACK_CHANGE_AUTHOR),
+    ] {
+        b.i32(count as i32);
+    }
+    b
+}
+
+/// writer가 모델에서 다시 만드는 태그. 배포용 문서 데이터는 배포용 속성을 지우므로 함께 뺀다
+pub(crate) fn is_generated_tag(tag_id: u16) -> bool {
+    matches!(
+        tag_id,
+        HwpTag::DOCUMENT_PROPERTIES
+            | HwpTag::ID_MAPPINGS
+            | HwpTag::BIN_DATA
+            | HwpTag::FACE_NAME
+            | HwpTag::BORDER_FILL
+            | HwpTag::CHAR_SHAPE
+            | HwpTag::TAB_DEF
+            | HwpTag::NUMBERING
+            | HwpTag::BULLET
+            | HwpTag::PARA_SHAPE
+            | HwpTag::STYLE
+            | HwpTag::MEMO_SHAPE
+            | HwpTag::COMPATIBLE_DOCUMENT
+            | HwpTag::LAYOUT_COMPATIBILITY
+            | HwpTag::DISTRIBUTE_DOC_DATA
+    )
+}
+
+/// 모델에 없는 원본 DocInfo 레코드 (원본 파서 `preserve_raw_records`로 읽은 경우)
+fn extra_records(doc: &Document) -> &[HwpRawRecord] {
+    doc.hwp_hints
+        .as_ref()
+        .map(|h| h.extra_doc_info.as_slice())
+        .unwrap_or_default()
+}
+
+fn extra_count(doc: &Document, tag_id: u16) -> usize {
+    extra_records(doc)
+        .iter()
+        .filter(|r| r.tag_id == tag_id)
+        .count()
+}
+
+/// 언어별 글꼴 목록 (한글, 영어, 한자, 일어, 기타, 기호, 사용자 순)
+fn font_lists(fonts: &FontFaces) -> [&Vec<Font>; 7] {
+    [
+        &fonts.hangul,
+        &fonts.latin,
+        &fonts.hanja,
+        &fonts.japanese,
+        &fonts.other,
+        &fonts.symbol,
+        &fonts.user,
+    ]
+}
+
+/// 표 19 글꼴
+fn face_name(font: &Font) -> Bytes {
+    let mut attr = 0u8;
+    if font.subst_font.is_some() {
+        attr |= 0x80;
+    }
+    if font.type_info.is_some() {
+        attr |= 0x40;
+    }
+    if font.default_font_name.is_some() {
+        attr |= 0x20;
+    }
+
+    let mut b = Bytes::new();
+    b.u8(attr).string(&font.face);
+    if let Some(subst) = &font.subst_font {
+        let alt_type = match subst.font_type {
+            FontType::Hft => 2,
+            FontType::Rep => 0,
+            _ => 1,
+        };
+        b.u8(alt_type).string(&subst.face);
+    }
+    if let Some(ti) = &font.type_info {
+        let family = match ti.family_type {
+            FontCategory::Myungjo => 1,
+            FontCategory::Gothic => 2,
+            FontCategory::SSerif => 3,
+            FontCategory::BrushScript => 4,
+            FontCategory::NonRectMj => 5,
+            FontCategory::NonRectGt => 6,
+            FontCategory::Unknown => 0,
+        };
+        b.u8(family)
+            .u8(0) // 세리프 유형
+            .u8(ti.weight)
+            .u8(ti.proportion)
+            .u8(ti.contrast)
+            .u8(ti.stroke_variation)
+            .u8(ti.arm_style)
+            .u8(ti.letterform)
+            .u8(ti.midline)
+            .u8(ti.x_height);
+    }
+    if let Some(name) = &font.default_font_name {
+        b.string(name);
+    }
+    b
+}
+
+/// 표 23 테두리/배경
+fn border_fill(bf: &BorderFill, bin_ids: &BinIds) -> Bytes {
+    let slash = bf.slash.as_ref();
+    let back_slash = bf.back_slash.as_ref();
+
+    let mut attr = 0u16;
+    if bf.three_d {
+        attr |= 0x0001;
+    }
+    if bf.shadow {
+        attr |= 0x0002;
+    }
+    attr |= (slash.map(|s| slash_shape(&s.slash_type)).unwrap_or(0) as u16) << 2;
+    attr |= (back_slash.map(|s| slash_shape(&s.slash_type)).unwrap_or(0) as u16) << 5;
+    if slash.is_some_and(|s| s.crooked) {
+        attr |= 0x0100;
+    }
+    if back_slash.is_some_and(|s| s.crooked) {
+        attr |= 0x0400;
+    }
+    if slash.is_some_and(|s| s.is_counter) {
+        attr |= 0x0800;
+    }
+    if back_slash.is_some_and(|s| s.is_counter) {
+        attr |= 0x1000;
+    }
+    if !matches!(bf.center_line, CenterLineType::None) {
+        attr |= 0x2000;
+    }
+
+    let mut b = Bytes::new();
+    b.u16(attr);
+    for border in [
+        &bf.left_border,
+        &bf.right_border,
+        &bf.top_border,
+        &bf.bottom_border,
+    ] {
+        line_spec(&mut b, border.as_ref());
+    }
+    line_spec(&mut b, bf.diagonal.as_ref());
+    fill_brush(&mut b, bf.fill.as_ref(), bin_ids, false);
+    b
+}
+
+fn slash_shape(slash_type: &SlashType) -> u8 {
+    match slash_type {
+        SlashType::None => 0,
+        SlashType::Center => 1,
+        SlashType::CenterBelow => 2,
+        SlashType::CenterAbove => 3,
+        SlashType::All => 4,
+    }
+}
+
+/// 테두리선 (종류 BYTE, 굵기 BYTE, 색상 COLORREF)
+fn line_spec(b: &mut Bytes, spec: Option<&LineSpec>) {
+    match spec.filter(|s| s.line_type != LineType3::None) {
+        Some(s) => {
+            b.u8(line_type3(&s.line_type))
+                .u8(border_width(&s.width))
+                .color(s.color);
+        }
+        None => {
+            b.u8(0).u8(0).u32(0);
+        }
+    }
+}
+
+/// 채우기 정보 (표 28).
+///
+/// `shape`가 true면 그리기 개체 형식으로, 추가 속성 뒤에 채우기 종류별 투명도 바이트를 덧붙인다.
+pub(crate) fn fill_brush(b: &mut Bytes, fill: Option<&FillBrush>, bin_ids: &BinIds, shape: bool) {
+    let (win, grad, image) = match fill {
+        None => (None, None, None),
+        Some(f @ FillBrush::WinBrush { .. }) => (Some(f), None, None),
+        Some(f @ FillBrush::Gradation { .. }) => (None, Some(f), None),
+        Some(f @ FillBrush::ImageBrush { .. }) => (None, None, Some(f)),
+        Some(FillBrush::Combined {
+            win_brush,
+            gradation,
+            image_brush,
+        }) => (
+            win_brush.as_deref(),
+            gradation.as_deref(),
+            image_brush.as_deref(),
+        ),
+    };
+
+    let mut fill_type = 0u32;
+    if win.is_some() {
+        fill_type |= 0x1;
+    }
+    if image.is_some() {
+        fill_type |= 0x2;
+    }
+    if grad.is_some() {
+        fill_type |= 0x4;
+    }
+    b.u32(fill_type);
+
+    if let Some(FillBrush::WinBrush {
+        face_color,
+        hatch_color,
+        hatch_style,
+        ..
+    }) = win
+    {
+        // 무늬 없음은 -1
+        let pattern = match hatch_style {
+            None => -1,
+            Some(HatchStyle::Horizontal) => 0,
+            Some(HatchStyle::Vertical) => 1,
+            Some(HatchStyle::BackSlash) => 2,
+            Some(HatchStyle::Slash) => 3,
+            Some(HatchStyle::Cross) => 4,
+            Some(HatchStyle::CrossDiagonal) => 5,
+        };
+        b.color(*face_color).color(*hatch_color).i32(pattern);
+    }
+    let mut step_center = None;
+    if let Some(FillBrush::Gradation {
+        grad_type,
+        angle,
+        center_x,
+        center_y,
+        step,
+        colors,
+        step_center: center,
+        ..
+    }) = grad
+    {
+        let kind = match grad_type {
+            GradationType::Linear => 1,
+            GradationType::Radial => 2,
+            GradationType::Conical => 3,
+            GradationType::Square => 4,
+        };
+        let count = colors.len();
+        b.u8(kind)
+            .i32(*angle as i32)
+            .i32(*center_x as i32)
+            .i32(*center_y as i32)
+            .i32(*step as i32)
+            .i32(count as i32);
+        // 색상이 3개 이상일 때만 위치 목록이 온다
+        if count > 2 {
+            for i in 0..count {
+                b.i32((i * 100 / (count - 1)) as i32);
+            }
+        }
+        for color in colors {
+            b.color(*color);
+        }
+        step_center = Some(*center);
+    }
+    if let Some(FillBrush::ImageBrush { mode, img }) = image {
+        b.u8(image_brush_mode(mode));
+        image_info(b, img.bright, img.contrast, &img.effect);
+        b.u16(bin_ids.resolve(&img.binary_item_id));
+    }
+
+    // 추가 채우기 속성: 그러데이션 번짐 정도 중심 (BYTE)
+    match step_center {
+        Some(center) => {
+            b.u32(1).u8(center);
+        }
+        None => {
+            b.u32(0);
+        }
+    }
+
+    if shape {
+        for _ in [win, grad, image].iter().flatten() {
+            b.u8(0);
+        }
+    }
+}
+
+/// 그림 정보 (밝기, 명암, 효과) — BinData ID는 호출자가 이어서 기록
+pub(crate) fn image_info(b: &mut Bytes, bright: i8, contrast: i8, effect: &ImageEffect) {
+    b.i8(bright).i8(contrast).u8(image_effect(effect));
+}
+
+pub(crate) fn image_effect(effect: &ImageEffect) -> u8 {
+    match effect {
+        ImageEffect::RealPic => 0,
+        ImageEffect::GrayScale => 1,
+        ImageEffect::BlackWhite => 2,
+    }
+}
+
+fn image_brush_mode(mode: &ImageBrushMode) -> u8 {
+    match mode {
+        ImageBrushMode::Tile => 0,
+        ImageBrushMode::TileHorzTop => 1,
+        ImageBrushMode::TileHorzBottom => 2,
+        ImageBrushMode::TileVertLeft => 3,
+        ImageBrushMode::TileVertRight => 4,
+        ImageBrushMode::Total => 5,
+        ImageBrushMode::Center => 6,
+        ImageBrushMode::CenterTop => 7,
+        ImageBrushMode::CenterBottom => 8,
+        ImageBrushMode::LeftCenter => 9,
+        ImageBrushMode::LeftTop => 10,
+        ImageBrushMode::LeftBottom => 11,
+        ImageBrushMode::RightCenter => 12,
+        ImageBrushMode::RightTop => 13,
+        ImageBrushMode::RightBottom => 14,
+        ImageBrushMode::Zoom => 15,
+    }
+}
+
+/// 표 33 글자 모양 (74바이트)
+fn char_shape(cs: &CharShape) -> Bytes {
+    let mut b = Bytes::new();
+    for id in lang_values(&cs.font_ref) {
+        b.u16(id);
+    }
+    for v in lang_values(&cs.ratio) {
+        b.u8(v);
+    }
+    for v in lang_values(&cs.spacing) {
+        b.i8(v);
+    }
+    for v in lang_values(&cs.rel_size) {
+        b.u8(v);
+    }
+    for v in lang_values(&cs.offset) {
+        b.i8(v);
+    }
+    b.i32(cs.height);
+
+    let mut attr = 0u32;
+    if cs.italic {
+        attr |= 0x1;
+    }
+    if cs.bold {
+        attr |= 0x2;
+    }
+    if let Some(u) = &cs.underline {
+        let kind = match u.underline_type {
+            UnderlineType::Bottom => 1,
+            UnderlineType::Center => 2,
+            UnderlineType::Top => 3,
+        };
+        attr |= kind << 2;
+        attr |= (line_type3(&u.shape) as u32 & 0x0F) << 4;
+    }
+    if let Some(outline) = &cs.outline {
+        let kind = match outline {
+            OutlineType::None => 0,
+            OutlineType::Solid => 1,
+            OutlineType::Dot => 2,
+            OutlineType::Thick => 3,
+            OutlineType::Dash => 4,
+            OutlineType::DashDot => 5,
+            OutlineType::DashDotDot => 6,
+        };
+        attr |= kind << 8;
+    }
+    if let Some(shadow) = &cs.shadow {
+        let kind = match shadow.shadow_type {
+            CharShadowType::None => 0,
+            CharShadowType::Drop => 1,
+            CharShadowType::Continuous => 2,
+        };
+        attr |= kind << 11;
+    }
+    if cs.emboss {
+        attr |= 0x2000;
+    }
+    if cs.engrave {
+        attr |= 0x4000;
+    }
+    if cs.superscript {
+        attr |= 0x8000;
+    }
+    if cs.subscript {
+        attr |= 0x10000;
+    }
+    if let Some(s) = &cs.strikeout {
+        attr |= 1 << 18;
+        attr |= (line_type3(&s.shape) as u32 & 0x0F) << 26;
+    }
+    let mark = match cs.sym_mark {
+        SymMark::DotAbove => 1,
+        SymMark::RingAbove => 2,
+        SymMark::Tilde => 3,
+        _ => 0,
+    };
+    attr |= mark << 21;
+    if cs.use_font_space {
+        attr |= 0x0200_0000;
+    }
+    if cs.use_kerning {
+        attr |= 0x4000_0000;
+    }
+    b.u32(attr);
+
+    let (shadow_x, shadow_y, shadow_color) = match &cs.shadow {
+        Some(s) => (s.offset_x, s.offset_y, s.color),
+        None => (0, 0, Some(0xB2B2B2)),
+    };
+    b.i8(shadow_x)
+        .i8(shadow_y)
+        .color(cs.text_color)
+        .color(cs.underline.as_ref().and_then(|u| u.color).or(Some(0)))
+        .color(cs.shade_color)
+        .color(shadow_color)
+        .u16(cs.border_fill_id.unwrap_or(0))
+        .color(cs.strikeout.as_ref().and_then(|s| s.color).or(Some(0)));
+    b
+}
+
+fn lang_values<T: Copy>(g: &LangGroup<T>) -> [T; 7] {
+    [
+        g.hangul, g.latin, g.hanja, g.japanese, g.other, g.symbol, g.user,
+    ]
+}
+
+/// 표 36 탭 정의
+fn tab_def(td: &TabDef) -> Bytes {
+    let mut attr = 0u32;
+    if td.auto_tab_left {
+        attr |= 0x1;
+    }
+    if td.auto_tab_right {
+        attr |= 0x2;
+    }
+    let mut b = Bytes::new();
+    b.u32(attr).i16(td.items.len() as i16);
+    for item in &td.items {
+        let kind = match item.tab_type {
+            TabType::Left => 0,
+            TabType::Right => 1,
+            TabType::Center => 2,
+            TabType::Decimal => 3,
+        };
+        b.u32(item.pos as u32)
+            .u8(kind)
+            .u8(line_type2(&item.leader))
+            .zeros(2);
+    }
+    b
+}
+
+/// 표 38 문단 번호 (7수준)
+fn numbering(n: &Numbering) -> Bytes {
+    const NUM_LEVELS: usize = 7;
+    let level = |i: usize| n.levels.iter().find(|l| l.level as usize == i);
+
+    let mut b = Bytes::new();
+    for i in 0..NUM_LEVELS {
+        let Some(l) = level(i) else {
+            b.u32(0).i16(0).i16(0).u32(0).string("");
+            continue;
+        };
+        let mut attr = halign_bits(&l.align);
+        if l.use_inst_width {
+            attr |= 0x4;
+        }
+        if l.auto_indent {
+            attr |= 0x8;
+        }
+        if l.text_offset_type == ValueUnit::HwpUnit {
+            attr |= 0x10;
+        }
+        let num_type = match l.num_format {
+            NumberType2::Digit => 0,
+            NumberType2::CircledDigit => 1,
+            NumberType2::RomanCapital => 2,
+            NumberType2::RomanSmall => 3,
+            NumberType2::LatinCapital => 4,
+            NumberType2::LatinSmall => 5,
+            NumberType2::HangulSyllable => 8,
+            NumberType2::CircledHangulSyllable => 9,
+            _ => 0,
+        };
+        attr |= num_type << 5;
+        b.u32(attr)
+            .i16(l.width_adjust as i16)
+            .i16(l.text_offset as i16)
+            .u32(l.char_shape_id.unwrap_or(0))
+            .string(&l.format_string);
+    }
+    for i in 0..NUM_LEVELS {
+        b.u16(level(i).map(|l| l.start).unwrap_or(1));
+    }
+    for i in 0..NUM_LEVELS {
+        b.u32(level(i).map(|l| l.start as u32).unwrap_or(1));
+    }
+    b
+}
+
+/// 표 42 글머리표
+fn bullet(bl: &Bullet, bin_ids: &BinIds) -> Bytes {
+    let head = &bl.para_head;
+    let mut attr = halign_bits(&head.align);
+    if head.use_inst_width {
+        attr |= 0x4;
+    }
+    if head.auto_indent {
+        attr |= 0x8;
+    }
+    if head.text_offset_type == ValueUnit::HwpUnit {
+        attr |= 0x10;
+    }
+
+    let mut b = Bytes::new();
+    b.u32(attr)
+        .i16(head.width_adjust as i16)
+        .i16(head.text_offset as i16)
+        .i32(head.char_shape_id.unwrap_or(0) as i32)
+        .wchar(Some(bl.bullet_char));
+    match bl.image.as_ref().filter(|_| bl.use_image) {
+        Some(img) => {
+            b.i32(1);
+            image_info(&mut b, img.bright, img.contrast, &img.effect);
+            b.u8(bin_ids.resolve(&img.binary_item_id) as u8);
+        }
+        None => {
+            b.i32(0).zeros(4);
+        }
+    }
+    b.wchar(bl.checked_char);
+    b
+}
+
+fn halign_bits(align: &HAlign) -> u32 {
+    match align {
+        HAlign::Center => 1,
+        HAlign::Right => 2,
+        _ => 0,
+    }
+}
+
+/// 표 43 문단 모양 (54바이트)
+fn para_shape(ps: &ParaShape) -> Bytes {
+    let bs = &ps.break_setting;
+    let heading = ps.heading.as_ref();
+    let border = ps.border.as_ref();
+
+    let mut attr1 = match ps.line_spacing.spacing_type {
+        LineSpacingType::Fixed => 1,
+        LineSpacingType::Between => 2,
+        _ => 0,
+    };
+    let align = match ps.align.horizontal {
+        HAlign::Left => 1,
+        HAlign::Right => 2,
+        HAlign::Center => 3,
+        HAlign::Distribute => 4,
+        HAlign::DistributeSpace => 5,
+        _ => 0,
+    };
+    attr1 |= align << 2;
+    attr1 |= match bs.break_latin_word {
+        BreakLatinWord::KeepWord => 0,
+        BreakLatinWord::Hyphenation => 1,
+        BreakLatinWord::BreakWord => 2,
+    } << 5;
+    if bs.break_non_latin_word == BreakNonLatinWord::BreakWord {
+        attr1 |= 0x80;
+    }
+    if ps.snap_to_grid {
+        attr1 |= 0x100;
+    }
+    attr1 |= (ps.condense as u32 & 0x7F) << 9;
+    if bs.widow_orphan {
+        attr1 |= 0x1_0000;
+    }
+    if bs.keep_with_next {
+        attr1 |= 0x2_0000;
+    }
+    if bs.keep_lines {
+        attr1 |= 0x4_0000;
+    }
+    if bs.page_break_before {
+        attr1 |= 0x8_0000;
+    }
+    attr1 |= match ps.align.vertical {
+        VAlign::Top => 1,
+        VAlign::Center => 2,
+        VAlign::Bottom => 3,
+        _ => 0,
+    } << 20;
+    if ps.font_line_height {
+        attr1 |= 0x40_0000;
+    }
+    if let Some(h) = heading {
+        attr1 |= match h.heading_type {
+            HeadingType::None => 0,
+            HeadingType::Outline => 1,
+            HeadingType::Number => 2,
+            HeadingType::Bullet => 3,
+        } << 23;
+        attr1 |= (h.level as u32 & 0x7) << 25;
+    }
+    if border.is_some_and(|b| b.connect) {
+        attr1 |= 0x1000_0000;
+    }
+    if border.is_some_and(|b| b.ignore_margin) {
+        attr1 |= 0x2000_0000;
+    }
+
+    let mut attr2 = match bs.line_wrap {
+        LineWrap::Break => 0,
+        LineWrap::Squeeze => 1,
+        LineWrap::Keep => 2,
+    };
+    if ps.auto_spacing.east_asian_eng {
+        attr2 |= 0x10;
+    }
+    if ps.auto_spacing.east_asian_num {
+        attr2 |= 0x20;
+    }
+    let attr3: u32 = match ps.line_spacing.spacing_type {
+        LineSpacingType::Percent => 0,
+        LineSpacingType::Fixed => 1,
+        LineSpacingType::Between => 2,
+        LineSpacingType::AtLeast => 3,
+    };
+
+    // 파서는 heading.id_ref를 number_bullet_id + 1로 복원한다 (1-based)
+    let number_bullet_id = heading.map(|h| h.id_ref.saturating_sub(1)).unwrap_or(0);
+
+    let mut b = Bytes::new();
+    b.u32(attr1)
+        .i32(ps.margin.left.value)
+        .i32(ps.margin.right.value)
+        .i32(ps.margin.indent.value)
+        .i32(ps.margin.prev.value)
+        .i32(ps.margin.next.value)
+        .i32(ps.line_spacing.value)
+        .u16(ps.tab_def_id.unwrap_or(0))
+        .u16(number_bullet_id)
+        .u16(border.map(|b| b.border_fill_id).unwrap_or(0))
+        .i16(border.map(|b| b.offset_left as i16).unwrap_or(0))
+        .i16(border.map(|b| b.offset_right as i16).unwrap_or(0))
+        .i16(border.map(|b| b.offset_top as i16).unwrap_or(0))
+        .i16(border.map(|b| b.offset_bottom as i16).unwrap_or(0))
+        .u32(attr2)
+        .u32(attr3)
+        .i32(ps.line_spacing.value);
+    b
+}
+
+/// 표 47 스타일
+fn style(s: &Style) -> Bytes {
+    let kind = match s.style_type {
+        StyleType::Para => 0,
+        StyleType::Char => 1,
+    };
+    let mut b = Bytes::new();
+    b.string(&s.name)
+        .string(&s.eng_name)
+        .u8(kind)
+        .u8(s.next_style_id.unwrap_or(s.id) as u8)
+        .i16(s.lang_id.unwrap_or(0x0412) as i16)
+        .u16(s.para_shape_id.unwrap_or(0))
+        .u16(s.char_shape_id.unwrap_or(0));
+    b
+}
+
+/// 메모 모양 (22바이트)
+fn memo_shape(m: &MemoShape) -> Bytes {
+    let mut b = Bytes::new();
+    b.u32(m.width as u32)
+        .u8(m.line_width)
+        .u8(line_type3(&m.line_type))
+        .color(m.line_color)
+        .color(m.fill_color)
+        .color(m.active_color)
+        .u32(match m.memo_type {
+            MemoType::Normal => 0,
+            MemoType::TrackChange => 1,
+        });
+    b
+}
+
+// ── 유틸 ──
+
+/// `convert::resources::convert_line_type3`의 역변환
+pub(crate) fn line_type3(v: &LineType3) -> u8 {
+    match v {
+        LineType3::Solid => 0,
+        LineType3::Dash => 1,
+        LineType3::Dot => 2,
+        LineType3::DashDot => 3,
+        LineType3::DashDotDot => 4,
+        LineType3::LongDash => 5,
+        LineType3::Circle => 6,
+        LineType3::DoubleSlim => 7,
+        LineType3::SlimThick => 8,
+        LineType3::ThickSlim => 9,
+        LineType3::SlimThickSlim => 10,
+        _ => 11,
+    }
+}
+
+pub(crate) fn line_type2(v: &LineType2) -> u8 {
+    match v {
+        LineType2::Solid => 1,
+        LineType2::Dot => 2,
+        LineType2::Dash => 3,
+        LineType2::DashDot => 4,
+        LineType2::DashDotDot => 5,
+        LineType2::LongDash => 6,
+        _ => 0,
+    }
+}
+
+/// 표 26 테두리선 굵기 문자열(mm) → 인덱스
+pub(crate) fn border_width(width: &str) -> u8 {
+    const WIDTHS: [f32; 16] = [
+        0.1, 0.12, 0.15, 0.2, 0.25, 0.3, 0.4, 0.5, 0.6, 0.7, 1.0, 1.5, 2.0, 3.0, 4.0, 5.0,
+    ];
+    let mm: f32 = width
+        .trim()
+        .trim_end_matches("mm")
+        .trim()
+        .parse()
+        .unwrap_or(0.1);
+    WIDTHS
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| (*a - mm).abs().total_cmp(&(*b - mm).abs()))
+        .map(|(i, _)| i as u8)
+        .unwrap_or(0)
+}
+
+pub(crate) fn binary_extension(item: &hwp_model::document::BinaryItem) -> String {
+    if let Some((_, ext)) = item.src.rsplit_once('.') {
+        if !ext.is_empty() && !ext.contains(['/', '\\']) {
+            return ext.to_string();
+        }
+    }
+    match &item.format {
+        ImageFormat::Png => "png",
+        ImageFormat::Jpg => "jpg",
+        ImageFormat::Bmp => "bmp",
+        ImageFormat::Gif => "gif",
+        ImageFormat::Tiff => "tif",
+        ImageFormat::Wmf => "wmf",
+        ImageFormat::Emf => "emf",
+        ImageFormat::Svg => "svg",
+        ImageFormat::Unknown(ext) => ext,
+    }
+    .to_string()
+}
@@ -0,0 +1,319 @@
+/// HWP 5.0 writer
+///
+/// 공통 Document 모델을 HWP 5.0 CFB 컨테이너로 직렬화한다.
+/// 스펙 문서 표 2의 스토리지/스트림 중 FileHeader, DocInfo, BodyText/SectionN,
+/// BinData, PrvText, PrvImage, Scripts를 작성한다.
+mod bodytext;
+mod docinfo;
+mod record;
+
+pub(crate) use bodytext::echoed_text;
+pub(crate) use docinfo::is_generated_tag;
+
+use crate::document::{HwpDocument, RawRecord};
+use crate::error::HwpError;
+use ::cfb::CompoundFile;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use hwp_model::document::{BinaryItem, BinaryStore, Document};
+use hwp_model::hints::{HwpBinCompression, HwpDocumentHints};
+use std::io::{Cursor, Read, Seek, Write};
+
+const SIGNATURE: &[u8] = b"HWP Document File";
+const FILE_HEADER_SIZE: usize = 256;
+/// 힌트가 없을 때 기록하는 파일 버전 (5.0.5.0)
+const DEFAULT_VERSION: u32 = 0x05000500;
+/// 문서 속성: 압축 / 스크립트 저장
+const FLAG_COMPRESSED: u32 = 0x01;
+const FLAG_SCRIPT: u32 = 0x08;
+/// writer가 재현하지 않는 문서 속성 (암호, 배포용, DRM, XMLTemplate, 이력, 전자 서명, 공인인증서)
+const UNSUPPORTED_FLAGS: u32 = 0x7F6;
+
+/// 공통 Document 모델을 HWP 5.0 바이너리로 직렬화한다.
+///
+/// `Document::hwp_hints`가 있으면 FileHeader, 압축 여부, 스크립트, 미리보기 이미지를
+/// 그대로 복원하고, 없으면 압축된 5.0.5.0 문서로 작성한다.
+pub struct HwpWriter;
+
+impl HwpWriter {
+    /// Document → HWP 바이트
+    pub fn write(doc: &Document) -> Result<Vec<u8>, HwpError> {
+        let mut cursor = Cursor::new(Vec::new());
+        Self::write_to(doc, &mut cursor)?;
+        Ok(cursor.into_inner())
+    }
+
+    /// Read+Write+Seek를 구현하는 대상에 HWP 작성
+    pub fn write_to<W: Read + Write + Seek>(doc: &Document, writer: W) -> Result<(), HwpError> {
+        let hints = doc.hwp_hints.clone().unwrap_or_else(default_hints);
+        let version = file_version(&hints);
+        let bin_ids = BinIds::new(&doc.binaries);
+        let stream = |data: Vec<u8>| -> Result<Vec<u8>, HwpError> {
+            if hints.compressed {
+                compress(&data)
+            } else {
+                Ok(data)
+            }
+        };
+
+        let mut cfb = CompoundFile::create(writer).map_err(io_error)?;
+
+        write_stream(&mut cfb, "/FileHeader", &file_header(&hints, version))?;
+        write_stream(
+            &mut cfb,
+            "/DocInfo",
+            &stream(docinfo::write_docinfo(doc, &bin_ids))?,
+        )?;
+
+        cfb.create_storage("/BodyText").map_err(io_error)?;
+        for (i, section) in doc.sections.iter().enumerate() {
+            let data = bodytext::write_section(section, &bin_ids, version);
+            write_stream(&mut cfb, &format!("/BodyText/Section{}", i), &stream(data)?)?;
+        }
+
+        if !doc.binaries.items.is_empty() {
+            cfb.create_storage("/BinData").map_err(io_error)?;
+            for (id, item) in bin_ids.ordered(&doc.binaries) {
+                let name = format!("/BinData/{}", binary_stream_name(id, item));
+                let compressed = match bin_compression(&hints, &item.id) {
+                    HwpBinCompression::StorageDefault => hints.compressed,
+                    HwpBinCompression::Compress => true,
+                    HwpBinCompression::NoCompress => false,
+                };
+                let data = if compressed {
+                    compress(&item.data)?
+                } else {
+                    item.data.clone()
+                };
+                write_stream(&mut cfb, &name, &data)?;
+            }
+        }
+
+        let preview: Vec<u8> = doc
+            .preview_text()
+            .encode_utf16()
+            .flat_map(|u| u.to_le_bytes())
+            .collect();
+        write_stream(&mut cfb, "/PrvText", &preview)?;
+        if let Some(image) = &hints.preview_image {
+            write_stream(&mut cfb, "/PrvImage", image)?;
+        }
+        if let Some(script) = &hints.scripts {
+            cfb.create_storage("/Scripts").map_err(io_error)?;
+            write_stream(&mut cfb, "/Scripts/DefaultJScript", script)?;
+        }
+        for (path, data) in &hints.extra_streams {
+            if is_generated_stream(path) {
+                continue;
+            }
+            create_parent_storages(&mut cfb, path)?;
+            write_stream(&mut cfb, path, data)?;
+        }
+
+        cfb.flush().map_err(io_error)?;
+        Ok(())
+    }
+
+    /// 원본 레코드 보존 모드로 파싱한 HwpDocument → HWP 바이트
+    ///
+    /// `HwpParser::preserve_raw_records(true)`로 보관한 레코드와 스트림을 그대로 기록하므로
+    /// 수정하지 않은 문서는 압축 해제한 스트림 기준으로 원본과 같은 바이트가 된다.
+    /// 파싱 후 HwpDocument를 고쳤으면 `HwpError::RawRecordsOutdated`를 반환한다
+    /// (고친 문서는 `convert::to_document` 후 `write`로 쓴다. 모델에 없는 레코드도 함께 기록된다).
+    pub fn write_raw(hwp: &HwpDocument) -> Result<Vec<u8>, HwpError> {
+        let mut cursor = Cursor::new(Vec::new());
+        Self::write_raw_to(hwp, &mut cursor)?;
+        Ok(cursor.into_inner())
+    }
+
+    /// Read+Write+Seek를 구현하는 대상에 원본 레코드 기반 HWP 작성
+    pub fn write_raw_to<W: Read + Write + Seek>(
+        hwp: &HwpDocument,
+        writer: W,
+    ) -> Result<(), HwpError> {
+        let raw = hwp.raw.as_ref().ok_or(HwpError::RawRecordsNotPreserved)?;
+        if !raw.matches(hwp) {
+            return Err(HwpError::RawRecordsOutdated);
+        }
+        let stream = |records: &[RawRecord]| -> Result<Vec<u8>, HwpError> {
+            let data = RawRecord::write_stream(records);
+            if hwp.file_header.is_compressed() {
+                compress(&data)
+            } else {
+                Ok(data)
+            }
+        };
+
+        let mut cfb = CompoundFile::create(writer).map_err(io_error)?;
+        for item in &raw.streams {
+            create_parent_storages(&mut cfb, &item.path)?;
+            write_stream(&mut cfb, &item.path, &item.data)?;
+        }
+        // 풀 수 없던 레코드 스트림은 streams에 저장된 바이트로 이미 기록됨
+        if !raw.doc_info.is_empty() {
+            write_stream(&mut cfb, "/DocInfo", &stream(&raw.doc_info)?)?;
+        }
+        if !raw.sections.is_empty() {
+            create_parent_storages(&mut cfb, "/BodyText/Section0")?;
+        }
+        for (i, records) in raw.sections.iter().enumerate() {
+            write_stream(
+                &mut cfb,
+                &format!("/BodyText/Section{}", i),
+                &stream(records)?,
+            )?;
+        }
+
+        cfb.flush().map_err(io_error)?;
+        Ok(())
+    }
+}
+
+/// `write`가 모델에서 다시 만드는 스트림. 배포용 본문(ViewText)은 BodyText로 쓰므로 함께 뺀다
+pub(crate) fn is_generated_stream(path: &str) -> bool {
+    matches!(
+        path,
+        "/FileHeader" | "/DocInfo" | "/PrvText" | "/PrvImage" | "/Scripts/DefaultJScript"
+    ) || ["/BodyText/", "/BinData/", "/ViewText/"]
+        .iter()
+        .any(|prefix| path.starts_with(prefix))
+}
+
+/// 경로의 상위 스토리지가 없으면 만든다 (예: "/BinData/BIN0001.png" → "/BinData")
+fn create_parent_storages<F: Read + Write + Seek>(
+    cfb: &mut CompoundFile<F>,
+    path: &str,
+) -> Result<(), HwpError> {
+    let parts: Vec<&str> = path.split('/').filter(|p| !p.is_empty()).collect();
+    let mut storage = String::new();
+    for part in parts.iter().take(parts.len().saturating_sub(1)) {
+        storage.push('/');
+        storage.push_str(part);
+        if !cfb.is_storage(&storage) {
+            cfb.create_storage(&storage).map_err(io_error)?;
+        }
+    }
+    Ok(())
+}
+
+/// BinData ID 매핑.
+///
+/// DocInfo의 BIN_DATA 레코드와 BinData 스토리지는 1부터 시작하는 번호로 연결되므로
+/// BinaryStore의 순서대로 번호를 매기고, 본문에서는 BinaryItem.id로 번호를 찾는다.
+pub(crate) struct BinIds {
+    ids: Vec<String>,
+}
+
+impl BinIds {
+    fn new(store: &BinaryStore) -> Self {
+        Self {
+            ids: store.items.iter().map(|item| item.id.clone()).collect(),
+        }
+    }
+
+    pub(crate) fn ordered<'a>(
+        &self,
+        store: &'a BinaryStore,
+    ) -> impl Iterator<Item = (u16, &'a BinaryItem)> {
+        store
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| (i as u16 + 1, item))
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// BinaryItem.id → BinData ID (없으면 0)
+    pub(crate) fn resolve(&self, id: &str) -> u16 {
+        self.ids
+            .iter()
+            .position(|item| item == id)
+            .map(|i| i as u16 + 1)
+            .unwrap_or(0)
+    }
+}
+
+/// BinData 항목의 압축 방식 (힌트에 없으면 스토리지 기본값)
+pub(crate) fn bin_compression(hints: &HwpDocumentHints, id: &str) -> HwpBinCompression {
+    hints
+        .bin_compression
+        .iter()
+        .find(|(item, _)| item == id)
+        .map(|(_, compression)| *compression)
+        .unwrap_or_default()
+}
+
+fn default_hints() -> HwpDocumentHints {
+    HwpDocumentHints {
+        compressed: true,
+        ..Default::default()
+    }
+}
+
+/// 힌트의 버전 (0xMMnnPPrr). 5.0 미만이면 기본 버전을 사용한다.
+fn file_version(hints: &HwpDocumentHints) -> u32 {
+    let (major, minor, build, revision) = hints.version;
+    let version = u32::from_be_bytes([major, minor, build, revision]);
+    if major == 5 {
+        version
+    } else {
+        DEFAULT_VERSION
+    }
+}
+
+/// 스펙 문서 표 3: 파일 인식 정보 (256바이트)
+///
+/// 원본 FileHeader가 있으면 예약 영역과 라이선스 정보를 유지하고,
+/// 압축/스크립트 비트만 현재 문서에 맞게 다시 계산한다.
+fn file_header(hints: &HwpDocumentHints, version: u32) -> Vec<u8> {
+    let mut data = if hints.file_header_raw.len() == FILE_HEADER_SIZE {
+        hints.file_header_raw.clone()
+    } else {
+        let mut data = vec![0u8; FILE_HEADER_SIZE];
+        data[..SIGNATURE.len()].copy_from_slice(SIGNATURE);
+        // 암호 버전: 한/글 7.0 이후 (4)
+        data[44..48].copy_from_slice(&4u32.to_le_bytes());
+        data
+    };
+
+    let raw_flags = u32::from_le_bytes([data[36], data[37], data[38], data[39]]);
+    let mut flags = raw_flags & !(UNSUPPORTED_FLAGS | FLAG_COMPRESSED | FLAG_SCRIPT);
+    if hints.compressed {
+        flags |= FLAG_COMPRESSED;
+    }
+    if hints.scripts.is_some() {
+        flags |= FLAG_SCRIPT;
+    }
+
+    data[32..36].copy_from_slice(&version.to_le_bytes());
+    data[36..40].copy_from_slice(&flags.to_le_bytes());
+    data
+}
+
+/// raw deflate 압축 (`decompress_deflate`의 역변환)
+fn compress(data: &[u8]) -> Result<Vec<u8>, HwpError> {
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data).map_err(io_error)?;
+    encoder.finish().map_err(io_error)
+}
+
+fn write_stream<F: Read + Write + Seek>(
+    cfb: &mut CompoundFile<F>,
+    path: &str,
+    data: &[u8],
+) -> Result<(), HwpError> {
+    let mut stream = cfb.create_stream(path).map_err(io_error)?;
+    stream.write_all(data).map_err(io_error)
+}
+
+fn io_error(e: std::io::Error) -> HwpError {
+    HwpError::Io(e.to_string())
+}
+
+/// BinData 스트림 이름 (예: BIN0001.png)
+fn binary_stream_name(id: u16, item: &BinaryItem) -> String {
+    format!("BIN{:04X}.{}", id, docinfo::binary_extension(item))
+}
@@ -0,0 +1,181 @@
+/// 레코드 직렬화 유틸리티
+///
+/// 스펙 문서 4.1: 레코드 헤더는 Tag ID(10비트), Level(10비트), Size(12비트)로 구성되며
+/// Size가 0xFFF 이상이면 뒤에 4바이트 크기가 추가된다.
+use hwp_model::types::Color;
+
+const EXTENDED_SIZE: u32 = 0xFFF;
+
+/// 레코드 스트림 작성기 (DocInfo / BodyText/SectionN 공용)
+#[derive(Default)]
+pub struct RecordWriter {
+    buf: Vec<u8>,
+}
+
+impl RecordWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 레코드 하나를 헤더와 함께 추가
+    pub fn record(&mut self, tag_id: u16, level: u16, data: &[u8]) {
+        let size = data.len() as u32;
+        let base = (tag_id as u32 & 0x3FF) | ((level as u32 & 0x3FF) << 10);
+        if size >= EXTENDED_SIZE {
+            self.buf
+                .extend_from_slice(&(base | (EXTENDED_SIZE << 20)).to_le_bytes());
+            self.buf.extend_from_slice(&size.to_le_bytes());
+        } else {
+            self.buf
+                .extend_from_slice(&(base | (size << 20)).to_le_bytes());
+        }
+        self.buf.extend_from_slice(data);
+    }
+
+    pub fn finish(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+/// 리틀 엔디안 바이트 버퍼
+#[derive(Default)]
+pub struct Bytes(Vec<u8>);
+
+impl Bytes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn u8(&mut self, v: u8) -> &mut Self {
+        self.0.push(v);
+        self
+    }
+
+    pub fn i8(&mut self, v: i8) -> &mut Self {
+        self.u8(v as u8)
+    }
+
+    pub fn u16(&mut self, v: u16) -> &mut Self {
+        self.0.extend_from_slice(&v.to_le_bytes());
+        self
+    }
+
+    pub fn i16(&mut self, v: i16) -> &mut Self {
+        self.0.extend_from_slice(&v.to_le_bytes());
+        self
+    }
+
+    pub fn u32(&mut self, v: u32) -> &mut Self {
+        self.0.extend_from_slice(&v.to_le_bytes());
+        self
+    }
+
+    pub fn i32(&mut self, v: i32) -> &mut Self {
+        self.0.extend_from_slice(&v.to_le_bytes());
+        self
+    }
+
+    pub fn f64(&mut self, v: f64) -> &mut Self {
+        self.0.extend_from_slice(&v.to_le_bytes());
+        self
+    }
+
+    pub fn bytes(&mut self, v: &[u8]) -> &mut Self {
+        self.0.extend_from_slice(v);
+        self
+    }
+
+    /// 0으로 채운 n바이트
+    pub fn zeros(&mut self, n: usize) -> &mut Self {
+        self.0.resize(self.0.len() + n, 0);
+        self
+    }
+
+    /// COLORREF (0x00BBGGRR). 색상이 없으면 0xFFFFFFFF
+    pub fn color(&mut self, color: Color) -> &mut Self {
+        self.u32(colorref(color))
+    }
+
+    /// WCHAR 하나 (BMP 밖의 문자는 0)
+    pub fn wchar(&mut self, c: Option<char>) -> &mut Self {
+        let code = c
+            .and_then(|c| u16::try_from(c as u32).ok())
+            .unwrap_or_default();
+        self.u16(code)
+    }
+
+    /// WORD 길이 + UTF-16LE 문자열
+    pub fn string(&mut self, s: &str) -> &mut Self {
+        let units: Vec<u16> = s.encode_utf16().collect();
+        self.u16(units.len() as u16);
+        for unit in units {
+            self.u16(unit);
+        }
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn into_vec(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl std::ops::Deref for Bytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// RGB Color(0x00RRGGBB) → COLORREF(0x00BBGGRR)
+///
+/// `COLORREF::to_rgb`의 역변환.
+pub fn colorref(color: Color) -> u32 {
+    match color {
+        Some(rgb) => {
+            let r = (rgb >> 16) & 0xFF;
+            let g = (rgb >> 8) & 0xFF;
+            let b = rgb & 0xFF;
+            (b << 16) | (g << 8) | r
+        }
+        None => 0xFFFFFFFF,
+    }
+}
+
+/// 4문자 컨트롤 ID → UINT32 (예: "secd" → 0x73656364)
+///
+/// 파서는 UINT32를 리틀 엔디안으로 읽은 뒤 바이트를 뒤집어 문자열로 만든다.
+pub fn ctrl_id(id: &[u8; 4]) -> u32 {
+    u32::from_be_bytes(*id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{RecordHeader, COLORREF};
+
+    #[test]
+    fn test_record_header_roundtrip() {
+        for size in [0usize, 10, 0xFFE, 0xFFF, 5000] {
+            let mut w = RecordWriter::new();
+            w.record(66, 2, &vec![0u8; size]);
+            let data = w.finish();
+            let (header, header_len) = RecordHeader::parse(&data).unwrap();
+            assert_eq!(header.tag_id, 66);
+            assert_eq!(header.level, 2);
+            assert_eq!(header.size as usize, size);
+            assert_eq!(header_len + size, data.len());
+        }
+    }
+
+    #[test]
+    fn test_colorref_roundtrip() {
+        for rgb in [0x000000, 0xFFFFFF, 0xFF0000, 0x0000FF, 0x123456] {
+            assert_eq!(COLORREF(colorref(Some(rgb))).to_rgb(), rgb);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+/// Canvas display list 통합 테스트
+/// Canvas display list integration tests
+mod common;
+use hwp_core::convert::to_document;
+use hwp_core::viewer::canvas::{doc_to_canvas, CanvasPage, DrawCommand};
+use hwp_core::viewer::doc_html::DocHtmlOptions;
+use hwp_core::viewer::doc_to_html;
+use hwp_core::HwpParser;
+use hwp_model::document::Document;
+
+fn read_document(name: &str) -> Option<Document> {
+    let path = common::find_fixture_file(name)?;
+    let data = std::fs::read(path).ok()?;
+    let hwp = HwpParser::new().parse(&data).ok()?;
+    Some(to_document(&hwp))
+}
+
+fn layout_page_count(doc: &Document) -> usize {
+    let options = DocHtmlOptions {
+        layout: true,
+        keep_metafile: false,
+        ..Default::default()
+    };
+    doc_to_html(doc, &options)
+        .matches(r#"<div class="hpa""#)
+        .count()
+}
+
+fn texts(page: &CanvasPage) -> Vec<&str> {
+    page.commands
+        .iter()
+        .filter_map(|c| match c {
+            DrawCommand::DrawText { text, .. } => Some(text.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_canvas_pages_match_layout_html() {
+    for path in common::find_all_hwp_files() {
+        let name = std::path::Path::new(&path)
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .to_string();
+        let Some(doc) = read_document(&name) else {
+            continue;
+        };
+        let pages = doc_to_canvas(&doc);
+        assert_eq!(pages.len(), layout_page_count(&doc), "{}", name);
+    }
+}
+
+#[test]
+fn test_canvas_text_and_page_size() {
+    let Some(doc) = read_document("noori.hwp") else {
+        return;
+    };
+    let pages = doc_to_canvas(&doc);
+    assert!(!pages.is_empty());
+    for page in &pages {
+        assert!(page.width > 0.0 && page.height > 0.0);
+        for command in &page.commands {
+            if let DrawCommand::DrawText { x, y, font, .. } = command {
+                assert!(font.size > 0.0);
+                assert!(*x >= 0.0 && *y >= 0.0);
+            }
+        }
+    }
+    assert!(pages.iter().any(|page| !texts(page).is_empty()));
+}
+
+#[test]
+fn test_canvas_table_borders_and_clip() {
+    let Some(doc) = read_document("table.hwp") else {
+        return;
+    };
+    let commands: Vec<DrawCommand> = doc_to_canvas(&doc)
+        .into_iter()
+        .flat_map(|page| page.commands)
+        .collect();
+    assert!(commands
+        .iter()
+        .any(|c| matches!(c, DrawCommand::StrokePath { .. })));
+    assert!(commands
+        .iter()
+        .any(|c| matches!(c, DrawCommand::Clip { .. })));
+    // Save/Restore 짝이 맞아야 재생 시 상태가 새지 않음
+    let saves = commands
+        .iter()
+        .filter(|c| matches!(c, DrawCommand::Save))
+        .count();
+    let restores = commands
+        .iter()
+        .filter(|c| matches!(c, DrawCommand::Restore))
+        .count();
+    assert_eq!(saves, restores);
+}
+
+#[test]
+fn test_canvas_images_reference_binaries() {
+    let Some(doc) = read_document("sample-5017-pics.hwp") else {
+        return;
+    };
+    let images: Vec<String> = doc_to_canvas(&doc)
+        .into_iter()
+        .flat_map(|page| page.commands)
+        .filter_map(|c| match c {
+            DrawCommand::DrawImage { binary_id, .. } => Some(binary_id),
+            _ => None,
+        })
+        .collect();
+    assert!(!images.is_empty());
+    for id in images {
+        assert!(doc.binaries.items.iter().any(|item| item.id == id));
+    }
+}
+
+#[test]
+fn test_canvas_json_roundtrip() {
+    let Some(doc) = read_document("noori.hwp") else {
+        return;
+    };
+    let pages = doc_to_canvas(&doc);
+    let json = serde_json::to_string(&pages).unwrap();
+    assert!(json.contains(r#""op":"drawText""#));
+    let back: Vec<CanvasPage> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, pages);
+}
@@ -0,0 +1,76 @@
+mod common;
+use hwp_core::viewer::doc_html::{doc_to_html, DocHtmlOptions};
+use hwp_core::viewer::doc_markdown::{doc_to_markdown, DocMarkdownOptions};
+use hwp_model::chart::{Chart, ChartSeries, ChartType};
+use hwp_model::paragraph::RunContent;
+use hwp_model::query::{ContentPath, Visitor};
+use hwp_model::shape::{ChartObject, ShapeObject};
+
+#[test]
+fn chart_fixture_parses_and_renders() {
+    // chart.hwpx 유형 1의 묶은 세로 막대형 차트를 완성한 문서 (Chart/chart1.xml 파트)
+    let doc = common::load_fixture_document("chart-column.hwpx");
+
+    #[derive(Default)]
+    struct Charts<'a>(Vec<&'a ChartObject>);
+    impl<'a> Visitor<'a> for Charts<'a> {
+        fn visit_shape(&mut self, _path: &ContentPath, shape: &'a ShapeObject) {
+            if let ShapeObject::Chart(chart) = shape {
+                self.0.push(chart);
+            }
+        }
+    }
+    let mut charts = Charts::default();
+    doc.walk(&mut charts);
+    assert_eq!(charts.0.len(), 1);
+    let chart = charts.0[0]
+        .chart
+        .as_ref()
+        .expect("chart part should be loaded");
+    assert_eq!(chart.chart_type, ChartType::Column);
+    assert_eq!(chart.categories, ["10대", "20대", "30대", "40대 이상"]);
+    assert_eq!(chart.series.len(), 3);
+    assert_eq!(chart.series[2].name.as_deref(), Some("2013년"));
+    assert_eq!(chart.series[2].values[1], Some(41.6));
+
+    let markdown = doc_to_markdown(&doc, &DocMarkdownOptions::default());
+    assert!(
+        markdown.contains("| 연령대별 비율 | 2015년 | 2014년 | 2013년 |"),
+        "{markdown}"
+    );
+    assert!(markdown.contains("| 20대 | 24.7 | 25.5 | 41.6 |"));
+    let html = doc_to_html(&doc, &DocHtmlOptions::default());
+    assert!(html.contains("연령대별 비율"));
+}
+
+#[test]
+fn chart_markdown_table_leaves_missing_values_blank() {
+    let series = |name: &str, values: Vec<Option<f64>>| ChartSeries {
+        name: Some(name.to_string()),
+        values,
+        color: None,
+    };
+    let chart = ChartObject {
+        chart: Some(Chart {
+            title: Some("분기별 매출".to_string()),
+            categories: vec!["1분기".to_string(), "2분기".to_string()],
+            series: vec![
+                series("국내", vec![Some(10.0), Some(12.5)]),
+                series("해외", vec![Some(3.0), None]),
+            ],
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let doc = common::document(vec![common::paragraph(vec![RunContent::Object(
+        ShapeObject::Chart(Box::new(chart)),
+    )])]);
+
+    let markdown = doc_to_markdown(&doc, &DocMarkdownOptions::default());
+    assert!(
+        markdown.contains(
+            "| 분기별 매출 | 국내 | 해외 |\n|---|---|---|\n| 1분기 | 10 | 3 |\n| 2분기 | 12.5 |  |"
+        ),
+        "{markdown}"
+    );
+}
@@ -129,3 +129,53 @@ pub fn find_fixture_file(filename: &str) -> Option<String> {
     }
     None
 }
+
+/// fixture를 공통 문서 모델로 읽기 (.hwp는 `to_document`로 변환, .hwpx는 HwpxParser)
+/// Load a fixture into `hwp_model::Document`
+#[allow(dead_code)]
+pub fn load_fixture_document(filename: &str) -> hwp_model::document::Document {
+    let path =
+        find_fixture_file(filename).unwrap_or_else(|| panic!("missing fixture {}", filename));
+    let data = std::fs::read(&path).unwrap();
+    if filename.ends_with(".hwpx") {
+        hwpx_parser::HwpxParser::parse(&data).unwrap()
+    } else {
+        let parsed = hwp_core::HwpParser::new().parse(&data).unwrap();
+        hwp_core::convert::to_document(&parsed)
+    }
+}
+
+/// 텍스트 하나짜리 run 내용 / Run content holding one text element
+#[allow(dead_code)]
+pub fn text(s: &str) -> hwp_model::paragraph::RunContent {
+    hwp_model::paragraph::RunContent::Text(hwp_model::paragraph::TextContent {
+        char_shape_id: None,
+        elements: vec![hwp_model::paragraph::TextElement::Text(s.to_string())],
+    })
+}
+
+/// run 하나짜리 문단 / Paragraph with a single run
+#[allow(dead_code)]
+pub fn paragraph(
+    contents: Vec<hwp_model::paragraph::RunContent>,
+) -> hwp_model::paragraph::Paragraph {
+    hwp_model::paragraph::Paragraph {
+        runs: vec![hwp_model::paragraph::Run {
+            char_shape_id: 0,
+            contents,
+        }],
+        ..Default::default()
+    }
+}
+
+/// 구역 하나짜리 문서 / Document with a single section
+#[allow(dead_code)]
+pub fn document(paragraphs: Vec<hwp_model::paragraph::Paragraph>) -> hwp_model::document::Document {
+    hwp_model::document::Document {
+        sections: vec![hwp_model::section::Section {
+            paragraphs,
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
@@ -551,6 +551,179 @@ fn convert_binaries_from_pics() {
     }
 }
 
+#[test]
+fn convert_master_pages() {
+    let hwp_doc = parse_hwp("table-bug.hwp");
+    let doc = to_document(&hwp_doc);
+
+    let def = &doc.sections[0].definition;
+    assert_eq!(def.master_pages.len(), 1);
+    let master = &def.master_pages[0];
+    assert_eq!(
+        master.apply_page_type,
+        hwp_model::types::PageApplyType::Both
+    );
+    // 바탕쪽 글 영역 = 용지 - 좌우 여백 - 제본 여백
+    let page = &def.page;
+    assert_eq!(
+        master.content.text_width,
+        Some(page.width - page.margin.left - page.margin.right - page.margin.gutter)
+    );
+    assert!(!master.content.paragraphs.is_empty());
+}
+
+#[test]
+fn convert_equations() {
+    use hwp_core::viewer::doc_markdown::{doc_to_markdown, DocMarkdownOptions};
+    use hwp_model::paragraph::RunContent;
+    use hwp_model::shape::ShapeObject;
+
+    let hwp_doc = parse_hwp("latex.hwp");
+    let doc = to_document(&hwp_doc);
+
+    let scripts: Vec<&str> = doc
+        .sections
+        .iter()
+        .flat_map(|s| &s.paragraphs)
+        .flat_map(|p| &p.runs)
+        .flat_map(|r| &r.contents)
+        .filter_map(|c| match c {
+            RunContent::Object(ShapeObject::Equation(eq)) => Some(eq.script.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert!(!scripts.is_empty(), "latex.hwp should have equations");
+    assert!(scripts
+        .iter()
+        .any(|s| s.contains("sqrt {{gL} over {2 pi }}")));
+
+    let markdown = doc_to_markdown(&doc, &DocMarkdownOptions::default());
+    assert!(markdown.contains(r"$Y=\sqrt{\frac{gL}{2\pi}}=\frac{gT}{2\pi}$"));
+}
+
+#[test]
+fn equation_markdown_escapes_markup() {
+    use hwp_core::viewer::doc_markdown::{doc_to_markdown, DocMarkdownOptions};
+    use hwp_model::paragraph::{Paragraph, Run, RunContent};
+    use hwp_model::shape::{EquationObject, ShapeObject};
+
+    let equation = EquationObject {
+        script: "a < b & c <script>alert(1)</script>".to_string(),
+        ..Default::default()
+    };
+    let mut doc = hwp_model::document::Document::default();
+    doc.sections.push(hwp_model::section::Section {
+        paragraphs: vec![Paragraph {
+            runs: vec![Run {
+                contents: vec![RunContent::Object(ShapeObject::Equation(Box::new(
+                    equation,
+                )))],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+        ..Default::default()
+    });
+
+    let markdown = doc_to_markdown(&doc, &DocMarkdownOptions::default());
+    assert!(!markdown.contains("<script"), "{}", markdown);
+    assert!(markdown.contains("&lt;script&gt;"), "{}", markdown);
+    assert!(markdown.contains("&amp;"), "{}", markdown);
+}
+
+#[test]
+fn line_points_scale_to_current_size() {
+    use hwp_core::viewer::doc_html::{doc_to_html, DocHtmlOptions};
+
+    // 선 좌표는 생성 시 크기 기준: HWP도 HWPX처럼 현재 크기에 맞게 늘어나야 한다
+    let hwpx = std::fs::read(common::find_fixture_file("shapeline.hwpx").unwrap()).unwrap();
+    let docs = [
+        to_document(&parse_hwp("shapeline.hwp")),
+        hwpx_parser::HwpxParser::parse(&hwpx).unwrap(),
+    ];
+    for doc in docs {
+        let html = doc_to_html(&doc, &DocHtmlOptions::default());
+        assert!(html.contains(r#"d="M0,0 L52.21,7.58""#));
+        assert!(!html.contains("L8.47,8.29"));
+    }
+}
+
+#[test]
+fn gradient_types_match_hwpx() {
+    use hwp_model::document::Document;
+    use hwp_model::resources::FillBrush;
+    use hwp_model::types::GradationType;
+
+    // 그러데이션 유형 (표 30): 1 줄무늬형, 2 원형, 3 원뿔형, 4 사각형
+    fn gradation_types(doc: &Document) -> Vec<GradationType> {
+        doc.resources
+            .border_fills
+            .iter()
+            .filter_map(|bf| match &bf.fill {
+                Some(FillBrush::Gradation { grad_type, .. }) => Some(grad_type.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    for (name, expected) in [
+        ("sample-5017", GradationType::Radial),
+        ("borderfill", GradationType::Linear),
+    ] {
+        let hwpx =
+            std::fs::read(common::find_fixture_file(&format!("{}.hwpx", name)).unwrap()).unwrap();
+        let hwpx_types = gradation_types(&hwpx_parser::HwpxParser::parse(&hwpx).unwrap());
+        assert!(hwpx_types.contains(&expected), "{}", name);
+        // 같은 BorderFill이 HWP에 중복으로 들어 있을 수 있어 종류만 비교
+        let hwp_types = gradation_types(&to_document(&parse_hwp(&format!("{}.hwp", name))));
+        assert!(!hwp_types.is_empty(), "{}", name);
+        assert!(
+            hwp_types.iter().all(|t| hwpx_types.contains(t)),
+            "{}: {:?} vs {:?}",
+            name,
+            hwp_types,
+            hwpx_types
+        );
+    }
+}
+
+#[test]
+fn auto_number_types_match_hwpx() {
+    use hwp_model::control::Control;
+    use hwp_model::document::Document;
+    use hwp_model::query::{ContentPath, Visitor};
+
+    #[derive(Default)]
+    struct Numbers(Vec<String>);
+    impl<'a> Visitor<'a> for Numbers {
+        fn visit_control(&mut self, _path: &ContentPath, control: &'a Control) {
+            if let Control::AutoNum(num) = control {
+                self.0
+                    .push(format!("{:?} {:?}", num.num_type, num.number_type));
+            }
+        }
+    }
+    fn numbers(doc: &Document) -> Vec<String> {
+        let mut numbers = Numbers::default();
+        doc.walk(&mut numbers);
+        numbers.0
+    }
+
+    for (name, expected) in [
+        ("footnote-endnote", "Footnote Digit"),
+        ("headerfooter", "Page Digit"),
+        ("multicolumns-in-common-controls", "Picture Digit"),
+        ("textbox", "Picture Digit"),
+    ] {
+        let hwpx =
+            std::fs::read(common::find_fixture_file(&format!("{}.hwpx", name)).unwrap()).unwrap();
+        let hwpx_numbers = numbers(&hwpx_parser::HwpxParser::parse(&hwpx).unwrap());
+        assert!(hwpx_numbers.iter().any(|n| n == expected), "{}", name);
+        let hwp_numbers = numbers(&to_document(&parse_hwp(&format!("{}.hwp", name))));
+        assert_eq!(hwp_numbers, hwpx_numbers, "{}", name);
+    }
+}
+
 fn extract_all_text(doc: &hwp_model::document::Document) -> String {
     let mut text = String::new();
     for sec in &doc.sections {
@@ -27,6 +27,9 @@ fn doc_markdown_options() -> DocMarkdownOptions {
         use_html: false,
         include_version: Some(false),
         include_page_info: Some(false),
+        revision_view: Default::default(),
+        evaluate_fields: true,
+        table_of_contents: false,
     }
 }
 
@@ -115,6 +118,8 @@ fn html_options() -> HtmlOptions {
         include_page_info: Some(false),
         css_class_prefix: "hwp-".to_string(),
         layout: false,
+        keep_metafile: false,
+        revision_view: Default::default(),
     }
 }
 
@@ -124,6 +129,10 @@ fn doc_html_options() -> DocHtmlOptions {
         inline_style: true,
         image_output_dir: None,
         layout: false,
+        keep_metafile: false,
+        revision_view: Default::default(),
+        evaluate_fields: true,
+        table_of_contents: false,
     }
 }
 
@@ -142,6 +151,7 @@ fn test_layout_mode_produces_hpa() {
     // layout=true로 HTML 생성
     let options = HtmlOptions {
         layout: true,
+        keep_metafile: false,
         ..html_options()
     };
     let html = hwp_doc.to_html(&options);
@@ -158,6 +168,64 @@ fn test_layout_mode_produces_hpa() {
     assert!(html.contains("<style>"), "Layout mode should include CSS");
 }
 
+#[test]
+fn test_layout_mode_master_page() {
+    use hwp_model::paragraph::{Run, RunContent, TextContent, TextElement};
+    use hwp_model::section::MasterPage;
+
+    let data = std::fs::read(find_fixture_file("table-bug.hwpx").unwrap()).unwrap();
+    let mut document = hwpx_parser::HwpxParser::parse(&data).unwrap();
+    // 빈 바탕쪽 문단(줄 배치 정보 포함)에 글자 추가
+    document.sections[0].definition.master_pages[0]
+        .content
+        .paragraphs[0]
+        .runs = vec![Run {
+        char_shape_id: 0,
+        contents: vec![RunContent::Text(TextContent {
+            char_shape_id: None,
+            elements: vec![TextElement::Text("바탕쪽 워터마크".into())],
+        })],
+    }];
+
+    let options = DocHtmlOptions {
+        layout: true,
+        keep_metafile: false,
+        ..doc_html_options()
+    };
+    let html = doc_to_html(&document, &options);
+
+    // 양쪽 바탕쪽은 모든 쪽에 본문보다 먼저(뒤에 깔리도록) 그린다
+    let pages: Vec<&str> = html.split(r#"<div class="hpa""#).skip(1).collect();
+    assert!(pages.len() > 1);
+    for page in &pages {
+        let master_pos = page
+            .find("바탕쪽 워터마크")
+            .expect("master page on every page");
+        let first_hcd = page.find(r#"class="hcD""#).unwrap();
+        assert!(first_hcd <= master_pos);
+        assert_eq!(page.matches("바탕쪽 워터마크").count(), 1);
+    }
+
+    // 첫 쪽 바탕쪽 감추기
+    document.sections[0]
+        .definition
+        .visibility
+        .get_or_insert_with(Default::default)
+        .hide_first_master_page = true;
+    let html = doc_to_html(&document, &options);
+    let pages: Vec<&str> = html.split(r#"<div class="hpa""#).skip(1).collect();
+    assert!(!pages[0].contains("바탕쪽 워터마크"));
+    assert!(pages[1].contains("바탕쪽 워터마크"));
+
+    // 홀수쪽 바탕쪽은 짝수 쪽에 그리지 않는다
+    let master: &mut MasterPage = &mut document.sections[0].definition.master_pages[0];
+    master.apply_page_type = hwp_model::types::PageApplyType::Odd;
+    let html = doc_to_html(&document, &options);
+    let pages: Vec<&str> = html.split(r#"<div class="hpa""#).skip(1).collect();
+    assert!(!pages[1].contains("바탕쪽 워터마크"));
+    assert!(pages[2].contains("바탕쪽 워터마크"));
+}
+
 #[test]
 fn test_layout_mode_hwpx() {
     let hwpx_files = common::find_all_hwpx_files();
@@ -169,6 +237,7 @@ fn test_layout_mode_hwpx() {
 
     let options = DocHtmlOptions {
         layout: true,
+        keep_metafile: false,
         ..doc_html_options()
     };
     let html = doc_to_html(&document, &options);
@@ -332,6 +401,7 @@ fn compare_old_vs_new_layout_html() {
             &hwp_doc,
             &HtmlOptions {
                 layout: false,
+                keep_metafile: false,
                 ..html_options()
             },
         );
@@ -342,6 +412,7 @@ fn compare_old_vs_new_layout_html() {
             &document,
             &DocHtmlOptions {
                 layout: true,
+                keep_metafile: false,
                 ..doc_html_options()
             },
         );
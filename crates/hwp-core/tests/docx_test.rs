@@ -0,0 +1,126 @@
+/// DOCX 내보내기 통합 테스트
+/// DOCX export integration tests
+mod common;
+use hwp_core::convert::to_document;
+use hwp_core::viewer::{to_docx, DocxOptions};
+use hwp_core::HwpParser;
+use hwp_model::document::Document;
+use std::io::{Cursor, Read};
+
+fn read_document(name: &str) -> Option<Document> {
+    let path = common::find_fixture_file(name)?;
+    let data = std::fs::read(path).ok()?;
+    let hwp = HwpParser::new().parse(&data).ok()?;
+    Some(to_document(&hwp))
+}
+
+/// DOCX 패키지의 파트 하나를 문자열로 읽음
+fn read_part(docx: &[u8], name: &str) -> Option<String> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(docx)).ok()?;
+    let mut file = archive.by_name(name).ok()?;
+    let mut text = String::new();
+    file.read_to_string(&mut text).ok()?;
+    Some(text)
+}
+
+fn part_names(docx: &[u8]) -> Vec<String> {
+    let archive = zip::ZipArchive::new(Cursor::new(docx)).unwrap();
+    archive.file_names().map(str::to_string).collect()
+}
+
+#[test]
+fn test_docx_package_parts_for_all_fixtures() {
+    for path in common::find_all_hwp_files() {
+        let name = std::path::Path::new(&path)
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .to_string();
+        let Some(doc) = read_document(&name) else {
+            continue;
+        };
+        let docx = to_docx(&doc, &DocxOptions::default()).unwrap();
+        let names = part_names(&docx);
+        for part in [
+            "[Content_Types].xml",
+            "_rels/.rels",
+            "word/document.xml",
+            "word/_rels/document.xml.rels",
+            "word/styles.xml",
+            "word/settings.xml",
+            "word/footnotes.xml",
+            "word/endnotes.xml",
+        ] {
+            assert!(names.iter().any(|n| n == part), "{}: {}", name, part);
+        }
+        let document = read_part(&docx, "word/document.xml").unwrap();
+        for tag in ["w:tbl", "w:tr", "w:tc"] {
+            assert_eq!(
+                document.matches(&format!("<{}>", tag)).count(),
+                document.matches(&format!("</{}>", tag)).count(),
+                "{}: {}",
+                name,
+                tag
+            );
+        }
+        assert!(
+            document.ends_with("</w:sectPr></w:body></w:document>"),
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn test_docx_text_and_images() {
+    let Some(doc) = read_document("noori.hwp") else {
+        return;
+    };
+    let docx = to_docx(&doc, &DocxOptions::default()).unwrap();
+    let document = read_part(&docx, "word/document.xml").unwrap();
+    assert!(document.contains("누리"));
+    assert!(document.contains("<wp:inline"));
+    assert!(part_names(&docx)
+        .iter()
+        .any(|n| n.starts_with("word/media/")));
+
+    let options = DocxOptions {
+        embed_images: false,
+    };
+    let docx = to_docx(&doc, &options).unwrap();
+    assert!(!part_names(&docx)
+        .iter()
+        .any(|n| n.starts_with("word/media/")));
+}
+
+#[test]
+fn test_docx_notes_and_headers() {
+    if let Some(doc) = read_document("footnote-endnote.hwp") {
+        let docx = to_docx(&doc, &DocxOptions::default()).unwrap();
+        let document = read_part(&docx, "word/document.xml").unwrap();
+        assert!(document.contains(r#"<w:footnoteReference w:id="1"/>"#));
+        let footnotes = read_part(&docx, "word/footnotes.xml").unwrap();
+        assert!(footnotes.contains(r#"<w:footnote w:id="1">"#));
+        assert!(footnotes.contains("<w:footnoteRef/>"));
+    }
+    if let Some(doc) = read_document("headerfooter.hwp") {
+        let docx = to_docx(&doc, &DocxOptions::default()).unwrap();
+        let document = read_part(&docx, "word/document.xml").unwrap();
+        assert!(document.contains("<w:headerReference "));
+        assert!(document.contains("<w:footerReference "));
+        assert!(read_part(&docx, "word/header1.xml").is_some());
+    }
+}
+
+#[test]
+fn test_docx_from_hwpx() {
+    let Some(path) = common::find_fixture_file("table.hwpx") else {
+        return;
+    };
+    let data = std::fs::read(path).unwrap();
+    let doc = hwpx_parser::HwpxParser::parse(&data).unwrap();
+    let docx = to_docx(&doc, &DocxOptions::default()).unwrap();
+    let document = read_part(&docx, "word/document.xml").unwrap();
+    assert!(document.contains("<w:tbl>"));
+    assert!(document.contains("<w:gridCol "));
+}
@@ -0,0 +1,260 @@
+mod common;
+use common::{paragraph, text};
+use hwp_core::viewer::doc_html::{doc_to_html, DocHtmlOptions};
+use hwp_model::control::{AutoNum, Bookmark, Control, Field, FieldParameter, NewNum};
+use hwp_model::document::Document;
+use hwp_model::field::{FieldContext, FieldDate};
+use hwp_model::paragraph::{RunContent, SubList};
+use hwp_model::shape::{Caption, ContainerObject, ShapeCommon, ShapeObject};
+use hwp_model::table::{Table, TableCell, TableRow};
+use hwp_model::types::{AutoNumType, FieldType, NumberingType};
+
+/// 문단 안 필드 구간 (FieldBegin ~ FieldEnd)의 종류와 텍스트 (문서 순서)
+fn field_results(doc: &Document) -> Vec<(FieldType, String)> {
+    let mut results = Vec::new();
+    doc.for_each_paragraph_list(&mut |paragraphs| {
+        for para in paragraphs {
+            let mut open: Option<(FieldType, String)> = None;
+            for content in para.runs.iter().flat_map(|run| &run.contents) {
+                match content {
+                    RunContent::Control(Control::FieldBegin(field)) => {
+                        open = Some((field.field_type.clone(), String::new()))
+                    }
+                    RunContent::Control(Control::FieldEnd) => results.extend(open.take()),
+                    RunContent::Text(text) => {
+                        if let Some((_, s)) = &mut open {
+                            s.extend(text.elements.iter().filter_map(|e| e.plain_text()));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    });
+    results
+}
+
+/// 필드 구간: 시작, 이전 결과, 끝 / Field begin, stale result, field end
+fn field(field_type: FieldType, command: &str, parameters: Vec<FieldParameter>) -> Vec<RunContent> {
+    vec![
+        RunContent::Control(Control::FieldBegin(Field {
+            field_type,
+            name: Some(command.to_string()),
+            parameters,
+            ..Default::default()
+        })),
+        text("(이전 값)"),
+        RunContent::Control(Control::FieldEnd),
+    ]
+}
+
+fn formula(command: &str) -> Vec<RunContent> {
+    field(FieldType::Formula, command, Vec::new())
+}
+
+/// 문단 하나짜리 표 셀 / Table cell holding one paragraph
+fn cell(row: u16, col: u16, contents: Vec<RunContent>) -> TableCell {
+    TableCell {
+        row,
+        col,
+        col_span: 1,
+        row_span: 1,
+        content: SubList {
+            paragraphs: vec![paragraph(contents)],
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn field_formulas_match_cached_results() {
+    for name in ["chart.hwp", "chart.hwpx"] {
+        let mut doc = common::load_fixture_document(name);
+        let before = field_results(&doc);
+        let formulas: Vec<_> = before
+            .iter()
+            .filter(|(field_type, _)| *field_type == FieldType::Formula)
+            .map(|(_, text)| text.as_str())
+            .collect();
+        assert_eq!(formulas.len(), 13, "{}: {:?}", name, before);
+        assert!(formulas.contains(&"659,100"), "{:?}", formulas);
+
+        // 한/글이 저장한 결과와 같게 계산되면 바뀌는 필드가 없다
+        assert_eq!(doc.evaluate_fields(&FieldContext::default()), 0);
+        assert_eq!(field_results(&doc), before);
+    }
+}
+
+#[test]
+fn field_evaluation_tables_dates_summary_and_cross_references() {
+    let table = Table {
+        common: ShapeCommon {
+            numbering_type: NumberingType::Table,
+            caption: Some(Caption {
+                content: SubList {
+                    paragraphs: vec![paragraph(vec![
+                        text("표 "),
+                        RunContent::Control(Control::AutoNum(AutoNum {
+                            num_type: AutoNumType::Table,
+                            ..Default::default()
+                        })),
+                        text(" 매출"),
+                    ])],
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        row_count: 3,
+        col_count: 3,
+        rows: vec![
+            TableRow {
+                cells: vec![
+                    cell(0, 0, vec![text("10")]),
+                    cell(0, 1, vec![text("20")]),
+                    cell(0, 2, formula("=SUM(LEFT)")),
+                ],
+            },
+            TableRow {
+                cells: vec![
+                    cell(1, 0, vec![text("1,000")]),
+                    cell(1, 1, vec![text("2.5")]),
+                    cell(1, 2, formula("=SUM(LEFT)??%.2f,;;0")),
+                ],
+            },
+            TableRow {
+                cells: vec![
+                    cell(2, 0, formula("=SUM(ABOVE)")),
+                    cell(2, 1, formula("=AVG(ABOVE)")),
+                    cell(2, 2, formula("=C1+C2*2??%g,")),
+                ],
+            },
+        ],
+        ..Default::default()
+    };
+
+    let mut conclusion = vec![RunContent::Control(Control::Bookmark(Bookmark {
+        name: "결론".to_string(),
+    }))];
+    conclusion.push(text("매출이 늘었다."));
+    let string = |name: &str, value: &str| FieldParameter::String {
+        name: name.to_string(),
+        value: value.to_string(),
+    };
+    let mut doc = common::document(vec![
+        paragraph(vec![
+            RunContent::Control(Control::NewNum(NewNum {
+                num_type: NumberingType::Table,
+                num: 5,
+            })),
+            RunContent::Object(ShapeObject::Table(Box::new(table))),
+        ]),
+        paragraph(field(FieldType::Summary, "$title", Vec::new())),
+        paragraph(field(FieldType::Date, "YYYY년 M월 D일 (ddd)", Vec::new())),
+        paragraph(field(FieldType::DocDate, "YYYY-MM-DD HH:mm", Vec::new())),
+        paragraph(conclusion),
+        paragraph(field(FieldType::CrossRef, "?#결론", Vec::new())),
+        paragraph(field(
+            FieldType::CrossRef,
+            "",
+            vec![string("RefPath", "표5")],
+        )),
+        paragraph(field(
+            FieldType::CrossRef,
+            "",
+            vec![
+                string("RefPath", "5"),
+                string("RefType", "TARGET_TABLE"),
+                string("RefContentType", "OBJECT_TYPE_CAPTION"),
+            ],
+        )),
+    ]);
+    doc.meta.title = Some("2024 사업 보고서".to_string());
+    doc.meta.created_date = Some("2012년 5월 29일 화요일 오후 12:32:40".to_string());
+
+    // 기본 HTML 변환은 계산식을 다시 계산한다 (날짜 필드는 저장된 값 유지)
+    let html = doc_to_html(&doc, &DocHtmlOptions::default());
+    assert!(html.contains("2,035"), "{html}");
+
+    let context = FieldContext {
+        now: Some(FieldDate::from_unix(1_709_640_000)),
+        file_path: None,
+    };
+    assert_eq!(doc.evaluate_fields(&context), 11);
+    let results: Vec<String> = field_results(&doc).into_iter().map(|(_, s)| s).collect();
+    assert_eq!(
+        results,
+        [
+            "2024 사업 보고서",
+            "2024년 3월 5일 (화)",
+            "2012-05-29 12:32",
+            "매출이 늘었다.",
+            "5",
+            "표 5 매출",
+            // 표 셀 (본문 다음에 방문)
+            "30",
+            "1,002.50",
+            "1010",
+            "11.25",
+            "2,035",
+        ]
+    );
+    // 새 결과는 명령의 마지막 결과에도 기록한다
+    let RunContent::Object(ShapeObject::Table(table)) =
+        &doc.sections[0].paragraphs[0].runs[0].contents[1]
+    else {
+        panic!("expected table");
+    };
+    let RunContent::Control(Control::FieldBegin(f)) =
+        &table.rows[1].cells[2].content.paragraphs[0].runs[0].contents[0]
+    else {
+        panic!("expected field");
+    };
+    assert_eq!(f.name.as_deref(), Some("=SUM(LEFT)??%.2f,;;1,002.50"));
+    assert_eq!(doc.evaluate_fields(&context), 0);
+}
+
+#[test]
+fn field_formulas_in_grouped_tables_use_cell_scope() {
+    let row = |contents: Vec<Vec<RunContent>>| {
+        let cells: Vec<TableCell> = contents
+            .into_iter()
+            .enumerate()
+            .map(|(col, contents)| cell(0, col as u16, contents))
+            .collect();
+        ShapeObject::Table(Box::new(Table {
+            row_count: 1,
+            col_count: cells.len() as u16,
+            rows: vec![TableRow { cells }],
+            ..Default::default()
+        }))
+    };
+    let group = |children: Vec<ShapeObject>| {
+        ShapeObject::Container(Box::new(ContainerObject {
+            children,
+            ..Default::default()
+        }))
+    };
+
+    // 묶음 개체 안의 표, 그리고 표 셀 안 묶음 개체의 표
+    let inner = row(vec![
+        vec![text("5")],
+        vec![text("6")],
+        formula("=SUM(LEFT)"),
+    ]);
+    let outer = row(vec![
+        vec![text("1")],
+        vec![text("2")],
+        formula("=SUM(LEFT)"),
+        vec![RunContent::Object(group(vec![inner]))],
+    ]);
+    let mut doc = common::document(vec![paragraph(vec![RunContent::Object(group(vec![
+        outer,
+    ]))])]);
+
+    assert_eq!(doc.evaluate_fields(&FieldContext::default()), 2);
+    let results: Vec<String> = field_results(&doc).into_iter().map(|(_, s)| s).collect();
+    assert_eq!(results, ["3", "11"]);
+}
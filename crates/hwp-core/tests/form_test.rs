@@ -0,0 +1,97 @@
+mod common;
+use hwp_core::viewer::doc_html::{doc_to_html, DocHtmlOptions};
+use hwp_model::paragraph::RunContent;
+use hwp_model::shape::{FormCheckState, FormListItem, FormObject, FormType, ShapeObject};
+use std::collections::BTreeMap;
+
+// fixture 중에는 양식 개체를 담은 문서가 없다
+
+#[test]
+fn form_controls_render_and_fill() {
+    let form = |form_type: FormType, name: &str| FormObject {
+        form_type,
+        name: name.to_string(),
+        enabled: true,
+        ..Default::default()
+    };
+    let objects = vec![
+        FormObject {
+            value: Some("홍길동".to_string()),
+            max_length: Some(20),
+            ..form(FormType::Edit, "성명")
+        },
+        FormObject {
+            caption: Some("개인정보 수집에 동의합니다".to_string()),
+            check_state: FormCheckState::Checked,
+            ..form(FormType::CheckBox, "동의")
+        },
+        FormObject {
+            group_name: Some("성별".to_string()),
+            caption: Some("남".to_string()),
+            ..form(FormType::RadioButton, "남")
+        },
+        FormObject {
+            group_name: Some("성별".to_string()),
+            caption: Some("여".to_string()),
+            check_state: FormCheckState::Checked,
+            ..form(FormType::RadioButton, "여")
+        },
+        FormObject {
+            value: Some("seoul".to_string()),
+            items: vec![
+                FormListItem {
+                    display_text: "서울".to_string(),
+                    value: "seoul".to_string(),
+                },
+                FormListItem {
+                    display_text: "부산".to_string(),
+                    value: "busan".to_string(),
+                },
+            ],
+            ..form(FormType::ComboBox, "지역")
+        },
+        FormObject {
+            caption: Some("제출".to_string()),
+            ..form(FormType::PushButton, "submit")
+        },
+    ];
+    let mut doc = common::document(vec![common::paragraph(
+        objects
+            .into_iter()
+            .map(|f| RunContent::Object(ShapeObject::Form(Box::new(f))))
+            .collect(),
+    )]);
+
+    let html = doc_to_html(&doc, &DocHtmlOptions::default());
+    assert!(
+        html.contains("type=\"text\" name=\"성명\" value=\"홍길동\" maxlength=\"20\""),
+        "{html}"
+    );
+    assert!(html.contains(
+        "<input type=\"checkbox\" name=\"동의\" value=\"on\" checked> 개인정보 수집에 동의합니다</label>"
+    ));
+    assert!(html.contains("<input type=\"radio\" name=\"성별\" value=\"남\">"));
+    assert!(html.contains("<input type=\"radio\" name=\"성별\" value=\"여\" checked>"));
+    assert!(html.contains("<option value=\"seoul\" selected>서울</option><option value=\"busan\">부산</option></select>"));
+    assert!(html.contains("type=\"button\" name=\"submit\""));
+
+    let values = doc.form_values();
+    assert_eq!(values.len(), 4);
+    assert_eq!(values["성명"], "홍길동");
+    assert_eq!(values["동의"], "on");
+    assert_eq!(values["성별"], "여");
+    assert_eq!(values["지역"], "seoul");
+
+    let filled: BTreeMap<String, String> = [
+        ("성명", "김민원"),
+        ("동의", "off"),
+        ("성별", "남"),
+        ("지역", "busan"),
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect();
+    // 성명, 동의, 남/여 두 단추, 지역
+    assert_eq!(doc.apply_form_values(&filled), 5);
+    assert_eq!(doc.form_values(), filled);
+}
@@ -0,0 +1,112 @@
+/// HWPML(.hml) 파서 통합 테스트
+/// HWPML (.hml) parser integration tests
+use hwp_core::hml::{is_hml, parse_hml};
+use hwp_core::viewer::doc_html::{doc_to_html, DocHtmlOptions};
+use hwp_model::control::Control;
+use hwp_model::paragraph::RunContent;
+use hwp_model::shape::ShapeObject;
+use hwp_model::types::{FontType, HAlign, Landscape, NumberingType, TextWrap};
+
+mod common;
+
+fn load() -> Vec<u8> {
+    let path = common::find_fixture_file("aligns.hml").expect("aligns.hml fixture");
+    std::fs::read(path).unwrap()
+}
+
+#[test]
+fn test_detect_hml() {
+    assert!(is_hml(&load()));
+}
+
+#[test]
+fn test_parse_hml_head() {
+    let doc = parse_hml(&load()).unwrap();
+    assert_eq!(doc.meta.creator.as_deref(), Some("mete0r"));
+    assert_eq!(doc.settings.page_start, 1);
+
+    let fonts = &doc.resources.fonts;
+    assert_eq!(fonts.hangul[0].face, "굴림");
+    assert_eq!(fonts.hangul[1].face, "바탕");
+    assert_eq!(fonts.hangul[0].font_type, FontType::Ttf);
+
+    let char_shape = &doc.resources.char_shapes[1];
+    assert_eq!(char_shape.height, 1000);
+    assert_eq!(char_shape.text_color, Some(0));
+    assert_eq!(char_shape.shade_color, None);
+    assert_eq!(char_shape.font_ref.hangul, 1);
+    assert_eq!(char_shape.ratio.latin, 100);
+
+    let para_shape = &doc.resources.para_shapes[0];
+    assert_eq!(para_shape.align.horizontal, HAlign::Justify);
+    assert_eq!(para_shape.margin.indent.value, -2620);
+    assert!(doc.resources.para_shapes[4].heading.is_some());
+
+    let border_fill = &doc.resources.border_fills[0];
+    assert!(border_fill.left_border.is_none());
+    assert_eq!(
+        border_fill.diagonal.as_ref().map(|d| d.width.as_str()),
+        Some("0.1mm")
+    );
+    assert!(!doc.resources.styles.is_empty());
+}
+
+#[test]
+fn test_parse_hml_body() {
+    let doc = parse_hml(&load()).unwrap();
+    let section = &doc.sections[0];
+
+    let page = &section.definition.page;
+    assert_eq!(page.width, 59528);
+    assert_eq!(page.height, 84188);
+    assert_eq!(page.landscape, Landscape::Portrait);
+    assert_eq!(page.margin.left, 8504);
+    assert_eq!(page.margin.top, 5668);
+    assert_eq!(section.definition.columns.as_ref().unwrap().col_count, 1);
+    assert_eq!(section.definition.page_border_fills.len(), 3);
+    assert!(section.paragraphs[0].runs[0]
+        .contents
+        .iter()
+        .any(|c| matches!(c, RunContent::Control(Control::Column(_)))));
+
+    let rects: Vec<_> = section
+        .paragraphs
+        .iter()
+        .flat_map(|p| &p.runs)
+        .flat_map(|r| &r.contents)
+        .filter_map(|c| match c {
+            RunContent::Object(ShapeObject::Rectangle(rect)) => Some(rect),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(rects.len(), 16);
+    let first = rects[0];
+    assert_eq!(first.common.numbering_type, NumberingType::Picture);
+    assert_eq!(first.common.text_wrap, TextWrap::InFrontOfText);
+    assert_eq!(first.common.size.width, 8504);
+    assert_eq!(rects[1].common.position.horz_offset, 2835);
+    assert_eq!(first.points[2].x, 11235);
+    assert_eq!(first.line_shape.width, 33);
+
+    let text: String = first.draw_text.as_ref().unwrap().paragraphs[0]
+        .runs
+        .iter()
+        .flat_map(|r| &r.contents)
+        .filter_map(|c| match c {
+            RunContent::Text(t) => Some(t),
+            _ => None,
+        })
+        .flat_map(|t| &t.elements)
+        .filter_map(|e| e.plain_text())
+        .collect();
+    assert_eq!(text, "left 0");
+    assert!(section.paragraphs[2].page_break);
+}
+
+#[test]
+fn test_hml_to_html() {
+    let doc = parse_hml(&load()).unwrap();
+    let html = doc_to_html(&doc, &DocHtmlOptions::default());
+    assert!(html.contains("left 0"));
+    assert!(html.contains("bottom 10"));
+}
@@ -0,0 +1,394 @@
+/// HWP 3.x 파서 통합 테스트 (합성 문서, 표준 조합형 코드표 fixture)
+/// HWP 3.x parser integration tests using synthetic documents
+use hwp_core::hwp3::{is_hwp3, parse_hwp3, HWP3_SIGNATURE};
+use hwp_core::viewer::doc_html::{doc_to_html, DocHtmlOptions};
+use hwp_core::viewer::doc_markdown::{doc_to_markdown, DocMarkdownOptions};
+use hwp_core::HwpError;
+use hwp_model::control::Control;
+use hwp_model::paragraph::{RunContent, TextElement};
+use hwp_model::shape::ShapeObject;
+use std::fs;
+use std::io::Write;
+
+/// 표준 조합형 코드표 (KS C 5601-1992 부속서 3)의 한글 음절/자모와 hchar 코드.
+/// 파서의 변환 표와 별개로 만든 fixture이므로 표가 틀리면 여기서 드러난다
+fn johab_table() -> Vec<(char, u16)> {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+    let codes = fs::read(format!("{}/hwp3-johab-hangul.bin", dir)).unwrap();
+    let text = fs::read_to_string(format!("{}/hwp3-johab-hangul.txt", dir)).unwrap();
+    text.chars()
+        .zip(
+            codes
+                .chunks_exact(2)
+                .map(|c| u16::from_le_bytes([c[0], c[1]])),
+        )
+        .collect()
+}
+
+/// 한글/ASCII → HWP 3.x hchar (조합형)
+fn hchars(text: &str) -> Vec<u16> {
+    let table = johab_table();
+    text.chars()
+        .map(|c| {
+            if c.is_ascii() {
+                return c as u16;
+            }
+            table
+                .iter()
+                .find(|(ch, _)| *ch == c)
+                .map(|&(_, code)| code)
+                .unwrap_or_else(|| panic!("no johab code for {:?}", c))
+        })
+        .collect()
+}
+
+fn put16(out: &mut Vec<u8>, v: u16) {
+    out.extend_from_slice(&v.to_le_bytes());
+}
+
+fn put32(out: &mut Vec<u8>, v: u32) {
+    out.extend_from_slice(&v.to_le_bytes());
+}
+
+fn fixed(text: &str, len: usize) -> Vec<u8> {
+    let mut raw = text.as_bytes().to_vec();
+    raw.resize(len, 0);
+    raw
+}
+
+/// 글자 모양 31바이트 (크기 pt, 속성 비트)
+fn char_shape(pt: u16, attr: u8) -> Vec<u8> {
+    let mut raw = Vec::new();
+    put16(&mut raw, pt * 25);
+    raw.extend_from_slice(&[0; 7]);
+    raw.extend_from_slice(&[100; 7]);
+    raw.extend_from_slice(&[0; 7]);
+    raw.extend_from_slice(&[0, 0, 0, attr, 0, 0, 0, 0]);
+    assert_eq!(raw.len(), 31);
+    raw
+}
+
+/// 문단 모양 187바이트 (정렬)
+fn para_shape(align: u8) -> Vec<u8> {
+    let mut raw = vec![0u8; 187];
+    raw[6..8].copy_from_slice(&160u16.to_le_bytes());
+    raw[11] = align;
+    raw
+}
+
+/// 문단 하나: 머리 + 대표 글자 모양 + 문단 모양 + 줄 정보 + 본문
+fn paragraph(out: &mut Vec<u8>, char_count: u16, body: &[u8]) {
+    out.push(0);
+    put16(out, char_count);
+    put16(out, 1);
+    out.push(0);
+    out.push(0);
+    put32(out, 0);
+    out.push(0);
+    out.extend(char_shape(10, 0));
+    out.extend(para_shape(0));
+    out.extend_from_slice(&[0; 14]);
+    out.extend_from_slice(body);
+    put16(out, 13);
+}
+
+fn text_paragraph(out: &mut Vec<u8>, text: &str) {
+    let codes = hchars(text);
+    let body: Vec<u8> = codes.iter().flat_map(|c| c.to_le_bytes()).collect();
+    paragraph(out, codes.len() as u16 + 1, &body);
+}
+
+/// 문단 리스트 끝 (글자 수 0, 이전 문단 모양 사용)
+fn end_of_list(out: &mut Vec<u8>) {
+    out.push(1);
+    out.extend_from_slice(&[0; 11]);
+    out.extend(char_shape(10, 0));
+}
+
+/// 글상자/그림 공통 개체 정보 62바이트
+fn floating_box(out: &mut Vec<u8>, anchor: u8, width: u16, height: u16) {
+    out.push(anchor);
+    out.push(0);
+    out.extend_from_slice(&[0; 8]);
+    out.extend_from_slice(&[0; 24]);
+    put16(out, width);
+    put16(out, height);
+    out.extend_from_slice(&[0; 24]);
+}
+
+/// 2×2 표 (첫 행은 두 칸 병합된 1셀)
+fn table(out: &mut Vec<u8>) {
+    put16(out, 10);
+    put32(out, 0);
+    put16(out, 10);
+    floating_box(out, 2, 2000, 1000);
+    out.extend_from_slice(&[0; 8]);
+    put16(out, 0); // 표
+    put16(out, 3);
+    put16(out, 0);
+    for (x, y, w) in [(0u16, 0u16, 2000u16), (0, 500, 1000), (1000, 500, 1000)] {
+        let mut cell = vec![0u8; 27];
+        cell[4..6].copy_from_slice(&x.to_le_bytes());
+        cell[6..8].copy_from_slice(&y.to_le_bytes());
+        cell[8..10].copy_from_slice(&w.to_le_bytes());
+        cell[10..12].copy_from_slice(&500u16.to_le_bytes());
+        cell[20..24].copy_from_slice(&[1, 1, 1, 1]);
+        out.extend(cell);
+    }
+    for text in ["머리", "왼쪽", "오른쪽"] {
+        text_paragraph(out, text);
+        end_of_list(out);
+    }
+    text_paragraph(out, "표 캡션");
+    end_of_list(out);
+}
+
+fn footnote(out: &mut Vec<u8>) {
+    put16(out, 17);
+    put32(out, 0);
+    put16(out, 17);
+    out.extend_from_slice(&[0; 8]);
+    put16(out, 1);
+    put16(out, 0);
+    put16(out, 0);
+    text_paragraph(out, "각주 내용");
+    end_of_list(out);
+}
+
+fn picture(out: &mut Vec<u8>) {
+    put16(out, 11);
+    put32(out, 0);
+    put16(out, 11);
+    floating_box(out, 2, 1000, 1000);
+    out.extend_from_slice(&[0; 4]);
+    out.push(2);
+    out.extend_from_slice(&[0; 8]);
+    out.extend(fixed("pic1.png", 256));
+    out.extend_from_slice(&[0; 9]);
+    end_of_list(out);
+}
+
+const PNG: &[u8] = b"\x89PNG\r\n\x1a\nfake";
+
+/// 글꼴 (7개 언어 × 1개)과 스타일 1개
+fn fonts_and_styles(out: &mut Vec<u8>) {
+    for _ in 0..7 {
+        put16(out, 1);
+        out.extend(fixed("Batang", 40));
+    }
+    put16(out, 1);
+    out.extend(fixed("Normal", 20));
+    out.extend(char_shape(10, 0));
+    out.extend(para_shape(0));
+}
+
+/// 압축 대상 부분: 글꼴, 스타일, 문단 리스트, 추가 정보 블록
+fn body() -> Vec<u8> {
+    let mut out = Vec::new();
+    fonts_and_styles(&mut out);
+
+    text_paragraph(&mut out, "안녕하세요 HWP3");
+
+    // 진하게 글자 모양이 섞인 문단: "가" + 탭 + "나"
+    out.push(0);
+    put16(&mut out, 7);
+    put16(&mut out, 1);
+    out.push(1);
+    out.push(0);
+    put32(&mut out, 0);
+    out.push(0);
+    out.extend(char_shape(10, 0));
+    out.extend(para_shape(3));
+    out.extend_from_slice(&[0; 14]);
+    out.push(0);
+    out.extend(char_shape(10, 0x02));
+    out.extend_from_slice(&[1; 6]);
+    put16(&mut out, hchars("가")[0]);
+    for v in [9u16, 800, 0, 9] {
+        put16(&mut out, v);
+    }
+    put16(&mut out, hchars("나")[0]);
+    put16(&mut out, 13);
+
+    let mut objects = Vec::new();
+    table(&mut objects);
+    picture(&mut objects);
+    paragraph(&mut out, 9, &objects);
+
+    // 각주가 있는 문단 (뷰어는 이런 문단의 본문을 생략)
+    let mut note = Vec::new();
+    footnote(&mut note);
+    paragraph(&mut out, 5, &note);
+    end_of_list(&mut out);
+
+    put32(&mut out, 1);
+    put32(&mut out, (32 + PNG.len()) as u32);
+    out.extend(fixed("pic1.png", 16));
+    out.extend(fixed("png", 16));
+    out.extend_from_slice(PNG);
+    put32(&mut out, 0);
+    put32(&mut out, 0);
+    out
+}
+
+fn build(compressed: bool) -> Vec<u8> {
+    build_with(compressed, body())
+}
+
+fn build_with(compressed: bool, body: Vec<u8>) -> Vec<u8> {
+    let mut data = HWP3_SIGNATURE.to_vec();
+    let mut info = vec![0u8; 128];
+    // A4: 297mm × 210mm (hunit = 1/1800 inch)
+    info[6..8].copy_from_slice(&21046u16.to_le_bytes());
+    info[8..10].copy_from_slice(&14882u16.to_le_bytes());
+    info[14..16].copy_from_slice(&1000u16.to_le_bytes());
+    info[124] = compressed as u8;
+    info[126..128].copy_from_slice(&4u16.to_le_bytes());
+    data.extend(info);
+
+    let mut summary = vec![0u8; 1008];
+    for (i, code) in hchars("제목").iter().enumerate() {
+        summary[i * 2..i * 2 + 2].copy_from_slice(&code.to_le_bytes());
+    }
+    data.extend(summary);
+    data.extend_from_slice(&[0xAA; 4]);
+
+    if compressed {
+        let mut encoder =
+            flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&body).unwrap();
+        data.extend(encoder.finish().unwrap());
+    } else {
+        data.extend(body);
+    }
+    data
+}
+
+#[test]
+fn test_hwp3_signature() {
+    assert!(is_hwp3(&build(false)));
+    assert!(!is_hwp3(b"PK\x03\x04"));
+
+    let mut v2 = build(false);
+    v2[19..23].copy_from_slice(b"V2.1");
+    assert!(matches!(
+        parse_hwp3(&v2),
+        Err(HwpError::UnsupportedVersion { .. })
+    ));
+    assert!(matches!(
+        parse_hwp3(b"not a document at all..."),
+        Err(HwpError::InvalidSignature { .. })
+    ));
+    assert!(parse_hwp3(&build(false)[..200]).is_err());
+}
+
+#[test]
+fn test_hwp3_document_structure() {
+    for compressed in [false, true] {
+        let doc = parse_hwp3(&build(compressed)).unwrap();
+        assert_eq!(doc.meta.title.as_deref(), Some("제목"));
+        assert_eq!(doc.resources.fonts.hangul[0].face, "Batang");
+        assert_eq!(doc.resources.styles[0].name, "Normal");
+        assert_eq!(doc.sections.len(), 1);
+        let section = &doc.sections[0];
+        assert_eq!(section.definition.page.margin.left, 4000);
+
+        let paragraphs = &section.paragraphs;
+        assert_eq!(paragraphs.len(), 4);
+
+        // 둘째 문단: 진하게 "가" / 보통 탭 + "나"
+        let runs = &paragraphs[1].runs;
+        assert_eq!(runs.len(), 2);
+        assert!(doc.resources.char_shapes[runs[0].char_shape_id as usize].bold);
+        assert!(!doc.resources.char_shapes[runs[1].char_shape_id as usize].bold);
+        let RunContent::Text(text) = &runs[1].contents[0] else {
+            panic!("expected text");
+        };
+        assert!(matches!(
+            text.elements[0],
+            TextElement::Tab { width: 3200, .. }
+        ));
+
+        let contents: Vec<&RunContent> = paragraphs[2]
+            .runs
+            .iter()
+            .flat_map(|r| &r.contents)
+            .collect();
+        let Some(RunContent::Object(ShapeObject::Table(table))) = contents.first() else {
+            panic!("expected table");
+        };
+        assert_eq!((table.row_count, table.col_count), (2, 2));
+        assert_eq!(table.rows[0].cells[0].col_span, 2);
+        assert_eq!(table.rows[1].cells[1].col, 1);
+        assert!(table.common.caption.is_some());
+        let RunContent::Object(ShapeObject::Picture(picture)) = contents[1] else {
+            panic!("expected picture");
+        };
+        assert_eq!(doc.binaries.items.len(), 1);
+        assert_eq!(doc.binaries.items[0].id, picture.img.binary_item_id);
+        assert_eq!(doc.binaries.items[0].data, PNG);
+        assert!(matches!(
+            paragraphs[3].runs[0].contents[0],
+            RunContent::Control(Control::FootNote(_))
+        ));
+    }
+}
+
+#[test]
+fn test_hwp3_viewers() {
+    let doc = parse_hwp3(&build(true)).unwrap();
+    let markdown = doc_to_markdown(
+        &doc,
+        &DocMarkdownOptions {
+            image_output_dir: None,
+            use_html: false,
+            include_version: Some(false),
+            include_page_info: Some(false),
+            revision_view: Default::default(),
+            evaluate_fields: true,
+            table_of_contents: false,
+        },
+    );
+    for text in ["안녕하세요 HWP3", "머리", "오른쪽", "각주 내용"] {
+        assert!(markdown.contains(text), "{}: {}", text, markdown);
+    }
+    let html = doc_to_html(&doc, &DocHtmlOptions::default());
+    assert!(html.contains("안녕하세요 HWP3"));
+    assert!(html.contains("<table"));
+}
+
+#[test]
+fn test_hwp3_johab_table() {
+    // 표준 조합형 코드표의 모든 한글 음절과 자모를 한 문단에 넣어 변환 결과를 비교
+    let table = johab_table();
+    assert_eq!(
+        table
+            .iter()
+            .filter(|(c, _)| ('가'..='힣').contains(c))
+            .count(),
+        11172
+    );
+    let codes: Vec<u8> = table.iter().flat_map(|(_, c)| c.to_le_bytes()).collect();
+    let mut body = Vec::new();
+    fonts_and_styles(&mut body);
+    paragraph(&mut body, table.len() as u16 + 1, &codes);
+    end_of_list(&mut body);
+    put32(&mut body, 0);
+    put32(&mut body, 0);
+
+    let doc = parse_hwp3(&build_with(false, body)).unwrap();
+    let mut text = String::new();
+    for run in &doc.sections[0].paragraphs[0].runs {
+        for content in &run.contents {
+            if let RunContent::Text(tc) = content {
+                for el in &tc.elements {
+                    if let TextElement::Text(s) = el {
+                        text.push_str(s);
+                    }
+                }
+            }
+        }
+    }
+    let expected: String = table.iter().map(|(c, _)| *c).collect();
+    assert_eq!(text, expected);
+}
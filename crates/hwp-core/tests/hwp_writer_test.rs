@@ -0,0 +1,429 @@
+use hwp_core::convert::to_document;
+use hwp_core::document::bodytext::ParagraphRecord;
+use hwp_core::document::docinfo::HwpTag;
+use hwp_core::{
+    decompress_deflate, CfbParser, HwpError, HwpParser, HwpWriter, RawDocument, RawRecord,
+    RawStream, RecordHeader,
+};
+use hwp_model::control::Control;
+use hwp_model::document::Document;
+use hwp_model::hints::HwpBinCompression;
+use hwp_model::paragraph::{Paragraph, RunContent, TextElement};
+use hwp_model::shape::ShapeObject;
+use hwpx_parser::HwpxParser;
+use std::fs;
+
+fn fixture(name: &str) -> Vec<u8> {
+    let path = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+    fs::read(&path).unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e))
+}
+
+fn fixture_names(ext: &str) -> Vec<String> {
+    let dir = format!("{}/tests/fixtures", env!("CARGO_MANIFEST_DIR"));
+    let mut names: Vec<String> = fs::read_dir(&dir)
+        .unwrap()
+        .filter_map(|e| e.ok())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .filter(|n| n.ends_with(ext))
+        .collect();
+    names.sort();
+    names
+}
+
+fn read_hwp(data: &[u8]) -> Result<Document, String> {
+    HwpParser::new()
+        .parse(data)
+        .map(|hwp| to_document(&hwp))
+        .map_err(|e| e.to_string())
+}
+
+/// 문단 텍스트 수집 (표 셀, 머리글/꼬리말, 각주/미주, 글상자, 캡션 포함)
+fn collect_text(paras: &[Paragraph], out: &mut Vec<String>) {
+    for para in paras {
+        let mut line = String::new();
+        for run in &para.runs {
+            for content in &run.contents {
+                match content {
+                    RunContent::Text(tc) => {
+                        for el in &tc.elements {
+                            // HWP 변환은 탭 컨트롤 뒤에 표시 문자 "\t"를 함께 넣으므로 비교에서 제외
+                            if let TextElement::Text(s) = el {
+                                line.extend(s.chars().filter(|c| *c != '\t'));
+                            }
+                        }
+                    }
+                    RunContent::Control(control) => match control {
+                        Control::Header(hf) | Control::Footer(hf) => {
+                            collect_text(&hf.content.paragraphs, out)
+                        }
+                        Control::FootNote(note) | Control::EndNote(note) => {
+                            collect_text(&note.content.paragraphs, out)
+                        }
+                        Control::HiddenDesc(hd) => collect_text(&hd.paragraphs, out),
+                        _ => {}
+                    },
+                    RunContent::Object(object) => collect_object_text(object, out),
+                }
+            }
+        }
+        out.push(line);
+    }
+}
+
+fn collect_object_text(object: &ShapeObject, out: &mut Vec<String>) {
+    match object {
+        ShapeObject::Table(table) => {
+            for row in &table.rows {
+                for cell in &row.cells {
+                    collect_text(&cell.content.paragraphs, out);
+                }
+            }
+        }
+        ShapeObject::Rectangle(rect) => {
+            if let Some(text) = &rect.draw_text {
+                collect_text(&text.paragraphs, out);
+            }
+        }
+        ShapeObject::Container(c) => {
+            for child in &c.children {
+                collect_object_text(child, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn document_text(doc: &Document) -> Vec<String> {
+    let mut out = Vec::new();
+    for section in &doc.sections {
+        collect_text(&section.paragraphs, &mut out);
+    }
+    out
+}
+
+// ═══════════════════════════════════════════
+// roundtrip
+// ═══════════════════════════════════════════
+
+#[test]
+fn roundtrip_all_hwp_fixtures() {
+    let mut checked = 0;
+    for name in fixture_names(".hwp") {
+        // 암호화/배포용 문서 등 파싱되지 않는 fixture는 제외
+        let Ok(doc) = read_hwp(&fixture(&name)) else {
+            continue;
+        };
+        let written = HwpWriter::write(&doc).unwrap_or_else(|e| panic!("{}: {}", name, e));
+        let reparsed = read_hwp(&written).unwrap_or_else(|e| panic!("{}: {}", name, e));
+
+        assert_eq!(doc.sections.len(), reparsed.sections.len(), "{}", name);
+        assert_eq!(
+            document_text(&doc),
+            document_text(&reparsed),
+            "{} text mismatch",
+            name
+        );
+        for (a, b) in doc.sections.iter().zip(&reparsed.sections) {
+            assert_eq!(
+                format!("{:?}", a.definition.page),
+                format!("{:?}", b.definition.page),
+                "{} page mismatch",
+                name
+            );
+        }
+        let (a, b) = (&doc.resources, &reparsed.resources);
+        assert_eq!(a.char_shapes.len(), b.char_shapes.len(), "{}", name);
+        assert_eq!(a.para_shapes.len(), b.para_shapes.len(), "{}", name);
+        assert_eq!(a.border_fills.len(), b.border_fills.len(), "{}", name);
+        assert_eq!(a.styles.len(), b.styles.len(), "{}", name);
+        checked += 1;
+    }
+    assert!(checked > 0);
+}
+
+#[test]
+fn roundtrip_binaries() {
+    let doc = read_hwp(&fixture("sample-5017-pics.hwp")).unwrap();
+    assert!(!doc.binaries.items.is_empty());
+
+    let reparsed = read_hwp(&HwpWriter::write(&doc).unwrap()).unwrap();
+    assert_eq!(doc.binaries.items.len(), reparsed.binaries.items.len());
+    for (a, b) in doc.binaries.items.iter().zip(&reparsed.binaries.items) {
+        assert_eq!(a.id, b.id);
+        assert_eq!(a.data, b.data);
+    }
+}
+
+#[test]
+fn roundtrip_file_header_hints() {
+    let doc = read_hwp(&fixture("example.hwp")).unwrap();
+    let written = HwpWriter::write(&doc).unwrap();
+    let hwp = HwpParser::new().parse(&written).unwrap();
+    let reparsed = to_document(&hwp);
+
+    let a = doc.hwp_hints.as_ref().unwrap();
+    let b = reparsed.hwp_hints.as_ref().unwrap();
+    assert_eq!(a.version, b.version);
+    assert_eq!(a.compressed, b.compressed);
+    assert_eq!(a.preview_image, b.preview_image);
+}
+
+#[test]
+fn write_uncompressed_when_hint_is_unset() {
+    let mut doc = read_hwp(&fixture("example.hwp")).unwrap();
+    doc.hwp_hints.as_mut().unwrap().compressed = false;
+
+    let written = HwpWriter::write(&doc).unwrap();
+    let hwp = HwpParser::new().parse(&written).unwrap();
+    assert!(!hwp.file_header.is_compressed());
+
+    // 비압축 스트림은 레코드 헤더로 바로 시작한다
+    let mut cfb = CfbParser::parse(&written).unwrap();
+    let section = CfbParser::read_nested_stream(&mut cfb, "BodyText", "Section0").unwrap();
+    let (header, _) = hwp_core::RecordHeader::parse(&section).unwrap();
+    assert_eq!(header.tag_id, 66);
+    assert_eq!(document_text(&doc), document_text(&to_document(&hwp)));
+}
+
+#[test]
+fn write_bin_data_with_item_compression() {
+    // latex.hwp는 압축 문서지만 BIN0001.png는 "무조건 압축하지 않음"으로 저장되어 있다
+    let original = fixture("latex.hwp");
+    let mut cfb = CfbParser::parse(&original).unwrap();
+    let stored = CfbParser::read_nested_stream(&mut cfb, "BinData", "BIN0001.png").unwrap();
+    assert!(stored.starts_with(b"\x89PNG"));
+
+    let mut doc = read_hwp(&original).unwrap();
+    let written = HwpWriter::write(&doc).unwrap();
+    let mut cfb = CfbParser::parse(&written).unwrap();
+    let image = CfbParser::read_nested_stream(&mut cfb, "BinData", "BIN0001.png").unwrap();
+    assert_eq!(image, stored);
+
+    // 비압축 문서라도 "무조건 압축" 항목은 압축한다
+    let hints = doc.hwp_hints.as_mut().unwrap();
+    hints.compressed = false;
+    hints.bin_compression = vec![("BIN0001".into(), HwpBinCompression::Compress)];
+    let written = HwpWriter::write(&doc).unwrap();
+    let mut cfb = CfbParser::parse(&written).unwrap();
+    let image = CfbParser::read_nested_stream(&mut cfb, "BinData", "BIN0001.png").unwrap();
+    assert_eq!(decompress_deflate(&image).unwrap(), stored);
+    let reread = read_hwp(&written).unwrap();
+    assert_eq!(reread.binaries.items[0].data, stored);
+}
+
+#[test]
+fn write_streams() {
+    let doc = read_hwp(&fixture("example.hwp")).unwrap();
+    let written = HwpWriter::write(&doc).unwrap();
+    let mut cfb = CfbParser::parse(&written).unwrap();
+
+    for stream in ["FileHeader", "DocInfo", "PrvText"] {
+        assert!(
+            CfbParser::read_stream(&mut cfb, stream).is_ok(),
+            "{} missing",
+            stream
+        );
+    }
+    let header = CfbParser::read_stream(&mut cfb, "FileHeader").unwrap();
+    assert_eq!(header.len(), 256);
+    assert!(header.starts_with(b"HWP Document File"));
+}
+
+// ═══════════════════════════════════════════
+// HWPX → HWP
+// ═══════════════════════════════════════════
+
+#[test]
+fn hwpx_to_hwp_preserves_text() {
+    let mut checked = 0;
+    for name in fixture_names(".hwpx") {
+        let Ok(doc) = HwpxParser::parse(&fixture(&name)) else {
+            continue;
+        };
+        let written = HwpWriter::write(&doc).unwrap_or_else(|e| panic!("{}: {}", name, e));
+        let reparsed = read_hwp(&written).unwrap_or_else(|e| panic!("{}: {}", name, e));
+
+        assert_eq!(doc.sections.len(), reparsed.sections.len(), "{}", name);
+        assert_eq!(
+            document_text(&doc),
+            document_text(&reparsed),
+            "{} text mismatch",
+            name
+        );
+        checked += 1;
+    }
+    assert!(checked > 0);
+}
+
+// ═══════════════════════════════════════════
+// 원본 레코드 보존
+// ═══════════════════════════════════════════
+
+fn parse_raw(data: &[u8]) -> Result<hwp_core::HwpDocument, String> {
+    HwpParser::new()
+        .preserve_raw_records(true)
+        .parse(data)
+        .map_err(|e| e.to_string())
+}
+
+/// CFB의 모든 스트림 (DocInfo/BodyText는 압축 해제)
+fn streams(data: &[u8], compressed: bool) -> Vec<(String, Vec<u8>)> {
+    let mut cfb = CfbParser::parse(data).unwrap();
+    let paths: Vec<String> = cfb
+        .walk()
+        .filter(|e| e.is_stream())
+        .map(|e| e.path().to_string_lossy().to_string())
+        .collect();
+    let mut out: Vec<(String, Vec<u8>)> = paths
+        .into_iter()
+        .map(|path| {
+            let mut buf = Vec::new();
+            std::io::Read::read_to_end(&mut cfb.open_stream(&path).unwrap(), &mut buf).unwrap();
+            let is_records = path == "/DocInfo" || path.starts_with("/BodyText/");
+            if compressed && is_records {
+                buf = decompress_deflate(&buf).unwrap();
+            }
+            (path, buf)
+        })
+        .collect();
+    out.sort();
+    out
+}
+
+#[test]
+fn raw_roundtrip_is_byte_identical() {
+    let mut checked = 0;
+    for name in fixture_names(".hwp") {
+        let data = fixture(&name);
+        let Ok(hwp) = parse_raw(&data) else {
+            continue;
+        };
+        let written = HwpWriter::write_raw(&hwp).unwrap_or_else(|e| panic!("{}: {}", name, e));
+        let compressed = hwp.file_header.is_compressed();
+        assert_eq!(
+            streams(&data, compressed),
+            streams(&written, compressed),
+            "{}",
+            name
+        );
+        checked += 1;
+    }
+    assert!(checked > 0);
+}
+
+#[test]
+fn raw_records_are_opt_in() {
+    let data = fixture("example.hwp");
+    let hwp = HwpParser::new().parse(&data).unwrap();
+    assert!(hwp.raw.is_none());
+    assert!(matches!(
+        HwpWriter::write_raw(&hwp),
+        Err(HwpError::RawRecordsNotPreserved)
+    ));
+}
+
+#[test]
+fn raw_write_rejects_edited_document() {
+    let mut hwp = parse_raw(&fixture("example.hwp")).unwrap();
+    assert!(HwpWriter::write_raw(&hwp).is_ok());
+    hwp.doc_info
+        .document_properties
+        .as_mut()
+        .unwrap()
+        .page_start_number += 1;
+    assert!(matches!(
+        HwpWriter::write_raw(&hwp),
+        Err(HwpError::RawRecordsOutdated)
+    ));
+}
+
+#[test]
+fn raw_keeps_undecodable_streams_as_stored() {
+    // 암호 문서의 DocInfo/BodyText는 복호화 전에는 레코드로 풀 수 없다
+    let data = fixture("password-12345.hwp");
+    let mut cfb = CfbParser::parse(&data).unwrap();
+    let raw = RawDocument::read(&mut cfb, true).unwrap();
+    assert!(raw.doc_info.is_empty());
+    assert!(raw.sections.is_empty());
+    let stored = streams(&data, false);
+    for path in ["/DocInfo", "/BodyText/Section0"] {
+        let original = stored.iter().find(|(p, _)| p == path).map(|(_, d)| d);
+        assert_eq!(raw.stream(path).map(|s| &s.data), original, "{}", path);
+    }
+}
+
+#[test]
+fn typed_write_keeps_unmodeled_records() {
+    let mut hwp = parse_raw(&fixture("example.hwp")).unwrap();
+    let raw = hwp.raw.as_mut().unwrap();
+    let unknown = RawRecord {
+        header: RecordHeader {
+            tag_id: 0x3F0,
+            level: 0,
+            size: 3,
+            has_extended_size: false,
+        },
+        data: vec![1, 2, 3],
+    };
+    raw.doc_info.push(unknown.clone());
+    raw.streams.push(RawStream {
+        path: "/Custom/Data".to_string(),
+        data: vec![4, 5, 6],
+    });
+    let unmodeled = |records: &[RawRecord]| -> Vec<RawRecord> {
+        records
+            .iter()
+            .filter(|r| {
+                matches!(
+                    r.header.tag_id,
+                    HwpTag::DOC_DATA | HwpTag::TRACKCHANGE | HwpTag::FORBIDDEN_CHAR | 0x3F0
+                )
+            })
+            .cloned()
+            .collect()
+    };
+    let expected = unmodeled(&raw.doc_info);
+    // 문단 하위의 알 수 없는 레코드
+    hwp.body_text.sections[0].paragraphs[0]
+        .records
+        .push(ParagraphRecord::Other {
+            tag_id: 0x3F1,
+            data: vec![7, 8],
+        });
+
+    let written = HwpWriter::write(&to_document(&hwp)).unwrap();
+    let reparsed = parse_raw(&written).unwrap();
+    let raw = reparsed.raw.as_ref().unwrap();
+    assert!(expected.len() > 1);
+    assert_eq!(unmodeled(&raw.doc_info), expected);
+    assert!(raw.doc_info.contains(&unknown));
+    assert_eq!(
+        raw.stream("/Custom/Data").map(|s| s.data.as_slice()),
+        Some(&[4u8, 5, 6][..])
+    );
+    assert!(raw.stream("/DocOptions/_LinkDoc").is_some());
+    assert!(reparsed.body_text.sections[0].paragraphs[0]
+        .records
+        .iter()
+        .any(|r| matches!(r, ParagraphRecord::Other { tag_id: 0x3F1, data } if data == &[7, 8])));
+}
+
+#[test]
+fn raw_records_keep_unknown_tags() {
+    let mut hwp = parse_raw(&fixture("example.hwp")).unwrap();
+    let unknown = RawRecord {
+        header: RecordHeader {
+            tag_id: 0x3F0,
+            level: 0,
+            size: 3,
+            has_extended_size: false,
+        },
+        data: vec![1, 2, 3],
+    };
+    hwp.raw.as_mut().unwrap().doc_info.push(unknown.clone());
+
+    let reparsed = parse_raw(&HwpWriter::write_raw(&hwp).unwrap()).unwrap();
+    let raw = reparsed.raw.unwrap();
+    assert_eq!(raw.doc_info.last(), Some(&unknown));
+    assert_eq!(raw.sections, hwp.raw.unwrap().sections);
+}
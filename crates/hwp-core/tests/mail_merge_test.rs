@@ -0,0 +1,127 @@
+mod common;
+use common::text;
+use hwp_core::mail_merge::{merge_csv, merge_json};
+use hwp_core::viewer::doc_html::{doc_to_html, DocHtmlOptions};
+use hwp_core::HwpError;
+use hwp_model::control::{Control, Field, FieldParameter};
+use hwp_model::mail_merge::FieldEntry;
+use hwp_model::paragraph::{Paragraph, Run, RunContent};
+use hwp_model::types::FieldType;
+use std::collections::HashMap;
+
+// fixture 중에는 누름틀/메일 머지 필드를 담은 문서가 없다
+
+#[test]
+fn click_here_and_mail_merge_fields_fill() {
+    let begin = |field: Field| RunContent::Control(Control::FieldBegin(field));
+    let end = || RunContent::Control(Control::FieldEnd);
+    // HWP: 명령 문자열 안에 안내문과 이름
+    let hwp_click_here = Field {
+        field_type: FieldType::ClickHere,
+        name: Some(
+            "Clickhere:set:61:Direction:wstring:8:이름을 넣으세요 HelpState:wstring:0: Name:wstring:2:성명 "
+                .to_string(),
+        ),
+        ..Default::default()
+    };
+    // HWPX: name 속성과 Command 파라미터
+    let hwpx_click_here = Field {
+        field_type: FieldType::ClickHere,
+        name: Some("주소".to_string()),
+        parameters: vec![FieldParameter::String {
+            name: "Command".to_string(),
+            value: "Clickhere:set:40:Direction:wstring:5:주소 입력 HelpState:wstring:0: "
+                .to_string(),
+        }],
+        ..Default::default()
+    };
+    let mail_merge = Field {
+        field_type: FieldType::MailMerge,
+        name: Some("회원번호".to_string()),
+        ..Default::default()
+    };
+
+    let doc = common::document(vec![Paragraph {
+        runs: vec![
+            Run {
+                char_shape_id: 1,
+                contents: vec![text("성명: "), begin(hwp_click_here)],
+            },
+            // 기존 결과는 다른 글자 모양 run에 있다
+            Run {
+                char_shape_id: 7,
+                contents: vec![text("(이름)"), end()],
+            },
+            Run {
+                char_shape_id: 1,
+                contents: vec![
+                    text(" 주소: "),
+                    begin(hwpx_click_here),
+                    end(),
+                    text(" 번호: "),
+                    begin(mail_merge),
+                    text("0000"),
+                    end(),
+                ],
+            },
+        ],
+        ..Default::default()
+    }]);
+
+    let entry =
+        |name: &str, field_type: FieldType, text: &str, direction: Option<&str>| FieldEntry {
+            name: name.to_string(),
+            field_type,
+            text: text.to_string(),
+            direction: direction.map(str::to_string),
+        };
+    assert_eq!(
+        doc.fields(),
+        [
+            entry(
+                "성명",
+                FieldType::ClickHere,
+                "(이름)",
+                Some("이름을 넣으세요")
+            ),
+            entry("주소", FieldType::ClickHere, "", Some("주소 입력")),
+            entry("회원번호", FieldType::MailMerge, "0000", None),
+        ]
+    );
+
+    let mut filled = doc.clone();
+    let values: HashMap<String, String> = [("성명", "홍길동"), ("주소", "서울시\n중구")]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    assert_eq!(filled.fill_fields(&values), 2);
+    let texts: Vec<String> = filled.fields().into_iter().map(|f| f.text).collect();
+    assert_eq!(texts, ["홍길동", "서울시\n중구", "0000"]);
+    // 채운 값은 기존 결과의 글자 모양(7)을 따른다
+    let RunContent::Text(name) = &filled.sections[0].paragraphs[0].runs[0].contents[2] else {
+        panic!("expected filled text");
+    };
+    assert_eq!(name.char_shape_id, Some(7));
+    assert!(filled.sections[0].paragraphs[0].runs[1].contents.len() == 1);
+
+    let merged = merge_csv(&doc, "성명,회원번호\n홍길동,A-1\n김철수,A-2\n").unwrap();
+    assert_eq!(merged.len(), 2);
+    let html = doc_to_html(&merged[1], &DocHtmlOptions::default());
+    assert!(html.contains("김철수") && html.contains("A-2"), "{html}");
+    assert!(!html.contains("(이름)"), "{html}");
+}
+
+#[test]
+fn mail_merge_rejects_bad_data_sources() {
+    let doc = common::document(Vec::new());
+    let ragged = merge_csv(&doc, "성명,회원번호\n홍길동\n").unwrap_err();
+    assert!(
+        matches!(ragged, HwpError::DataSourceError { .. }),
+        "{ragged}"
+    );
+    assert!(ragged.to_string().contains("line 2"), "{ragged}");
+    assert!(matches!(
+        merge_json(&doc, "{\"성명\": "),
+        Err(HwpError::DataSourceError { .. })
+    ));
+}
@@ -0,0 +1,56 @@
+mod common;
+use common::{paragraph, text};
+use hwp_core::viewer::doc_html::{doc_to_html, DocHtmlOptions};
+use hwp_core::viewer::doc_markdown::{doc_to_markdown, DocMarkdownOptions};
+use hwp_model::control::Control;
+use hwp_model::memo::Memo;
+use hwp_model::paragraph::RunContent;
+
+// fixture 중에는 메모를 담은 문서가 없다
+
+#[test]
+fn memo_margin_comments() {
+    let memo = Memo {
+        id: Some("memo0".to_string()),
+        number: Some(1),
+        author: Some("이검토".to_string()),
+        date: Some("2024-05-02 09:30:00".to_string()),
+        content: vec![paragraph(vec![text("근거 자료 확인 필요")])],
+        ..Default::default()
+    };
+    let doc = common::document(vec![paragraph(vec![
+        text("매출은 "),
+        RunContent::Control(Control::FieldBegin(memo.to_field(1))),
+        text("20% 증가"),
+        RunContent::Control(Control::FieldEnd),
+        text("했다."),
+    ])]);
+
+    let memos = doc.memos();
+    assert_eq!(memos.len(), 1);
+    assert_eq!(memos[0].anchor_text, "20% 증가");
+    assert_eq!(memos[0].author.as_deref(), Some("이검토"));
+    assert_eq!(memos[0].date.as_deref(), Some("2024-05-02 09:30:00"));
+
+    let html = doc_to_html(&doc, &DocHtmlOptions::default());
+    assert!(
+        html.contains("<aside class=\"hwp-memo\" id=\"memo-1\""),
+        "{html}"
+    );
+    assert!(html.contains("<span class=\"hwp-memo-author\">이검토</span>"));
+    assert!(html.contains("근거 자료 확인 필요"));
+    assert!(
+        html.contains("data-memo=\"1\" style=\"background-color: #fff5c0\">20% 증가</span>했다."),
+        "{html}"
+    );
+    // 메모 상자는 문단보다 먼저 나와 문단 옆 여백에 뜬다
+    assert!(html.find("<aside").unwrap() < html.find("매출은").unwrap());
+
+    let markdown = doc_to_markdown(&doc, &DocMarkdownOptions::default());
+    assert!(
+        markdown.contains(
+            "매출은 20% 증가[메모 1]했다.\n\n> **[메모 1]** 이검토 (2024-05-02 09:30:00)\n> 근거 자료 확인 필요"
+        ),
+        "{markdown}"
+    );
+}
@@ -0,0 +1,44 @@
+mod common;
+use hwp_core::viewer::doc_html::{doc_to_html, DocHtmlOptions};
+use hwp_model::document::{BinaryItem, ImageFormat};
+use hwp_model::paragraph::RunContent;
+use hwp_model::shape::{Picture, ShapeObject};
+
+/// placeable WMF: 경계 (0,0)-(100,100), 타원 하나
+/// (fixture 중에는 WMF/EMF 그림을 담은 문서가 없다)
+fn ellipse_wmf() -> Vec<u8> {
+    let mut wmf = vec![0xD7, 0xCD, 0xC6, 0x9A, 0, 0];
+    for v in [0i16, 0, 100, 100, 1440, 0, 0, 0] {
+        wmf.extend_from_slice(&v.to_le_bytes());
+    }
+    for v in [1u16, 9, 0x300, 0, 0, 0, 0, 0, 0] {
+        wmf.extend_from_slice(&v.to_le_bytes());
+    }
+    for v in [7u16, 0, 0x0418, 100, 100, 0, 0, 3, 0, 0] {
+        wmf.extend_from_slice(&v.to_le_bytes());
+    }
+    wmf
+}
+
+#[test]
+fn metafile_picture_html() {
+    let mut picture = Picture::default();
+    picture.img.binary_item_id = "BIN0001".to_string();
+    let mut doc = common::document(vec![common::paragraph(vec![RunContent::Object(
+        ShapeObject::Picture(Box::new(picture)),
+    )])]);
+    doc.binaries.items.push(BinaryItem {
+        id: "BIN0001".to_string(),
+        src: "BIN0001.wmf".to_string(),
+        format: ImageFormat::Wmf,
+        data: ellipse_wmf(),
+    });
+
+    let html = doc_to_html(&doc, &DocHtmlOptions::default());
+    assert!(html.contains("data:image/svg+xml;base64,"), "{html}");
+    let options = DocHtmlOptions {
+        keep_metafile: true,
+        ..Default::default()
+    };
+    assert!(doc_to_html(&doc, &options).contains("data:image/x-wmf;base64,"));
+}
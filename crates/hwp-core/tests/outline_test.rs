@@ -0,0 +1,67 @@
+mod common;
+use hwp_core::viewer::doc_html::{doc_to_html, DocHtmlOptions};
+use hwp_core::viewer::doc_markdown::{doc_to_markdown, DocMarkdownOptions};
+
+#[test]
+fn outline_tree_and_table_of_contents() {
+    for name in ["outline.hwp", "outline.hwpx"] {
+        let doc = common::load_fixture_document(name);
+        let outline = doc.outline();
+        // 1. 개요 1 (가. 개요2, 나. 개요2-2), 2. 개요 1-2
+        assert_eq!(outline.len(), 2);
+        assert_eq!(outline[0].title, "개요 1");
+        assert_eq!(outline[1].title, "개요 1-2");
+        assert_eq!(outline[1].number, Some(2));
+        let level2: Vec<_> = outline[0]
+            .children
+            .iter()
+            .map(|e| (e.level, e.number, e.title.as_str()))
+            .collect();
+        assert_eq!(level2, [(2, Some(1), "개요2"), (2, Some(2), "개요2-2")]);
+        let flat: Vec<_> = outline.iter().flat_map(|e| e.iter()).collect();
+        assert_eq!(flat.len(), 15);
+        assert_eq!(flat[5].title, "개요 5-2");
+        assert_eq!((flat[5].level, flat[5].number), (5, Some(2)));
+        assert_eq!(flat[7].title, "개요 6");
+
+        // 차례는 옵션으로만 넣는다
+        let plain = doc_to_html(&doc, &DocHtmlOptions::default());
+        assert!(!plain.contains("<nav") && !plain.contains("toc-1"));
+
+        let html = doc_to_html(
+            &doc,
+            &DocHtmlOptions {
+                table_of_contents: true,
+                ..Default::default()
+            },
+        );
+        assert!(html.contains("<nav class=\"hwp-toc\">"), "{html}");
+        assert!(
+            html.contains(
+                "<a href=\"#hwp-toc-2\"><span class=\"hwp-outline-number\">가.</span> 개요2</a><ul>"
+            ),
+            "{html}"
+        );
+        assert!(html.contains("<h1 id=\"hwp-toc-1\" class=\"hwp-outline-1\">"));
+        assert!(html.contains("<h1 id=\"hwp-toc-15\" class=\"hwp-outline-1\">"));
+        assert!(html.find("<nav").unwrap() < html.find("<div class=\"hwp-body\">").unwrap());
+
+        let md = doc_to_markdown(
+            &doc,
+            &DocMarkdownOptions {
+                table_of_contents: true,
+                ..Default::default()
+            },
+        );
+        let toc = &md[md.find("## 차례").expect("toc heading")..];
+        assert!(
+            toc.contains("- [1. 개요 1](#1-개요-1)\n  - [가. 개요2](#가-개요2)\n"),
+            "{md}"
+        );
+        assert!(toc.find("- [2. 개요 1-2]").unwrap() < toc.find("# 1. 개요 1").unwrap());
+        // Markdown 제목이 아닌 7 수준은 링크 없이 적는다
+        assert!(md.contains("\n      - ① 개요7\n"), "{md}");
+        assert!(md.contains("\n- [2. 개요 1-2](#2-개요-1-2)"), "{md}");
+        assert!(!doc_to_markdown(&doc, &DocMarkdownOptions::default()).contains("## 차례"));
+    }
+}
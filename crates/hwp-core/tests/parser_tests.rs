@@ -103,3 +103,86 @@ fn test_distribution_document_parsing() {
         section.paragraphs.len()
     );
 }
+
+#[test]
+fn test_hwp_parser_password_protected() {
+    use crate::common::find_fixture_file;
+
+    // 암호 "12345"로 저장한 한글 문서
+    let Some(path) = find_fixture_file("password-12345.hwp") else {
+        return;
+    };
+    let data = std::fs::read(path).unwrap();
+    let parser = HwpParser::new();
+
+    let document = parser.parse(&data);
+    assert!(matches!(document, Err(HwpError::PasswordRequired)));
+}
+
+/// LIST_HEADER(표 셀, 각주 등) 문단의 텍스트를 문서 순서로 모은다
+fn list_header_texts(records: &[hwp_core::document::ParagraphRecord], out: &mut Vec<String>) {
+    use hwp_core::document::{Paragraph, ParagraphRecord};
+
+    fn push_paragraphs(paragraphs: &[Paragraph], out: &mut Vec<String>) {
+        for para in paragraphs {
+            for record in &para.records {
+                if let ParagraphRecord::ParaText { text, .. } = record {
+                    out.push(text.trim().to_string());
+                }
+            }
+            list_header_texts(&para.records, out);
+        }
+    }
+
+    for record in records {
+        match record {
+            ParagraphRecord::ListHeader { paragraphs, .. } => push_paragraphs(paragraphs, out),
+            ParagraphRecord::Table { table } => {
+                for cell in &table.cells {
+                    push_paragraphs(&cell.paragraphs, out);
+                }
+            }
+            ParagraphRecord::CtrlHeader {
+                children,
+                paragraphs,
+                ..
+            } => {
+                list_header_texts(children, out);
+                for para in paragraphs {
+                    list_header_texts(&para.records, out);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test_list_header_paragraphs_follow_their_own_header() {
+    use crate::common::find_fixture_file;
+
+    // 같은 바이트의 LIST_HEADER가 여러 개여도 각자 바로 뒤의 문단을 가져야 한다
+    for (name, expected) in [
+        (
+            "footnote-endnote.hwp",
+            ["각주입니다.", "각주 두 번째입니다."],
+        ),
+        ("chart.hwp", ["일자마라톤", "많이 넘기"]),
+    ] {
+        let Some(path) = find_fixture_file(name) else {
+            continue;
+        };
+        let document = HwpParser::new()
+            .parse(&std::fs::read(path).unwrap())
+            .unwrap();
+        let mut texts = Vec::new();
+        for section in &document.body_text.sections {
+            for para in &section.paragraphs {
+                list_header_texts(&para.records, &mut texts);
+            }
+        }
+        for text in expected {
+            assert!(texts.iter().any(|t| t == text), "{}: {}", name, text);
+        }
+    }
+}
@@ -1,49 +1,42 @@
 //! PDF export tests.
-//! 현재 PDF 기능은 보류 상태입니다. 스냅샷 테스트는 추후 활성화 예정.
 //!
-//! Requires fixture noori.hwp and LiberationSans font (or font_dir pointing to TTF files).
-//! Test is skipped when fixture is missing or when font loading fails.
+//! Requires fixtures (noori.hwp, table.hwp 등). 픽스처가 없으면 스킵합니다.
 //!
-//! ## 폰트 가져오기
-//! printpdf는 폰트 디렉터리에서 다음 이름의 TTF 파일을 찾습니다:
-//! `LiberationSans-Regular.ttf`, `LiberationSans-Bold.ttf`, `LiberationSans-Italic.ttf`, `LiberationSans-BoldItalic.ttf`.
+//! ## 폰트
+//! `PdfOptions::font_dir`의 TrueType 글꼴(.ttf/.ttc)을 서브셋으로 임베딩합니다.
+//! 테스트는 `tests/fixtures/fonts/`(LiberationSans 네 개)를 `font_dir`로 사용합니다.
+//! `font_dir`이 없으면 표준 14 글꼴(Helvetica)로 출력합니다.
 //!
-//! - **다운로드**: <https://github.com/liberationfonts/liberation-fonts/releases> 에서
-//!   `Liberation-fonts-*.tar.gz` 받아 압축 해제 후 `LiberationSans-*.ttf` 네 개를 한 디렉터리에 둠.
+//! - **다운로드**: <https://github.com/liberationfonts/liberation-fonts/releases>
 //! - **Linux**: `fonts-liberation` 패키지 설치 후 `/usr/share/fonts/truetype/liberation/` 등에 있음.
-//! - **macOS**: 위에서 받은 TTF를 `~/Library/Fonts/` 또는 임의 폴더에 넣고, 테스트/CLI 호출 시
-//!   `PdfOptions { font_dir: Some("/path/to/dir".into()), .. }` 로 지정.
-//! - **테스트에서 자동 사용**: `tests/fixtures/fonts/`에 `LiberationSans-Regular.ttf` 등 네 개를 두면
-//!   테스트가 해당 경로를 `font_dir`로 사용함. (없으면 스킵)
+//! - 한글 본문을 제대로 보려면 한글 글꼴(예: Noto Sans KR)이 있는 디렉터리를 지정하세요.
 //!
 //! ## 스냅샷 종류
 //! - **content_summary** (`*_content_summary.snap`): PDF에 넣을 요소 목록만(문단/테이블/이미지). 서식·폰트 없음. 폰트 불필요.
 //! - **styled_summary** (`*_styled_summary.snap`): CharShape 스타일 정보 포함 요약. 폰트 불필요.
 //! - **pdf_generated** 테스트: 실제 PDF 생성 후 유효성 검사 및 `pdf_export__{이름}.pdf` 파일로 저장.
-//!   (PDF 메타데이터 비결정성으로 바이트 스냅샷은 사용하지 않음.)
+//!   (출력은 결정적이지만 바이너리라 바이트 스냅샷은 사용하지 않음.)
 //!
 //! ## 실제 PDF 파일 출력
 //! `pdf_generated` 테스트 실행 시 생성된 PDF를 스냅샷과 같은 디렉터리에 `pdf_export__{이름}.pdf` 형식으로 씁니다.
 //! `pdftoppm`(poppler-utils)이 설치되어 있으면 첫 페이지를 `pdf_export__{이름}-1.png`로 저장해 결과를 눈으로 확인할 수 있음.
 
-// NOTE: PDF 기능 보류 — 모든 테스트 비활성화
-// 추후 PDF 기능 활성화 시 아래 주석을 해제하세요.
-
-/*
 mod common;
 
+use hwp_core::convert::to_document;
 use hwp_core::viewer::pdf::{pdf_content_summary, pdf_styled_summary, to_pdf, PdfOptions};
 use hwp_core::HwpParser;
+use hwp_model::document::Document;
 use insta::{assert_snapshot, with_settings};
 use std::io::Read;
 
-fn load_document(fixture_name: &str) -> Option<hwp_core::HwpDocument> {
+fn load_document(fixture_name: &str) -> Option<Document> {
     let path = common::find_fixture_file(fixture_name)?;
     let mut f = std::fs::File::open(&path).ok()?;
     let mut buf = Vec::new();
     f.read_to_end(&mut buf).ok()?;
     let parser = HwpParser::new();
-    parser.parse(&buf).ok()
+    parser.parse(&buf).ok().map(|hwp| to_document(&hwp))
 }
 
 fn snapshots_dir() -> std::path::PathBuf {
@@ -95,14 +88,7 @@ fn to_pdf_returns_valid_pdf_bytes() {
     };
     let options = PdfOptions::default();
 
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| to_pdf(&doc, &options)));
-    let pdf = match result {
-        Ok(bytes) => bytes,
-        Err(_) => {
-            println!("font loading failed (e.g. LiberationSans not found), skipping");
-            return;
-        }
-    };
+    let pdf = to_pdf(&doc, &options);
 
     assert!(!pdf.is_empty());
     assert!(pdf.starts_with(b"%PDF"), "PDF magic bytes");
@@ -185,14 +171,7 @@ fn pdf_generated_noori_snapshot() {
         font_dir: common::find_font_dir(),
         ..PdfOptions::default()
     };
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| to_pdf(&doc, &options)));
-    let pdf = match result {
-        Ok(bytes) => bytes,
-        Err(_) => {
-            // 폰트를 찾을 수 없으면 스킵
-            return;
-        }
-    };
+    let pdf = to_pdf(&doc, &options);
     write_pdf_to_file("noori_pdf_generated", &pdf);
     assert!(!pdf.is_empty());
     assert!(pdf.starts_with(b"%PDF"), "PDF magic bytes");
@@ -212,14 +191,7 @@ fn pdf_generated_table_snapshot() {
         font_dir: common::find_font_dir(),
         ..PdfOptions::default()
     };
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| to_pdf(&doc, &options)));
-    let pdf = match result {
-        Ok(bytes) => bytes,
-        Err(_) => {
-            println!("font loading failed, skipping");
-            return;
-        }
-    };
+    let pdf = to_pdf(&doc, &options);
     write_pdf_to_file("table_pdf_generated", &pdf);
     assert!(!pdf.is_empty());
     assert!(pdf.starts_with(b"%PDF"), "PDF magic bytes");
@@ -286,14 +258,7 @@ fn pdf_structure_noori() {
         font_dir: common::find_font_dir(),
         ..PdfOptions::default()
     };
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| to_pdf(&doc, &options)));
-    let pdf = match result {
-        Ok(bytes) => bytes,
-        Err(_) => {
-            println!("font loading failed, skipping");
-            return;
-        }
-    };
+    let pdf = to_pdf(&doc, &options);
 
     let lopdf_doc = lopdf::Document::load_mem(&pdf).expect("lopdf should parse the PDF");
 
@@ -336,21 +301,18 @@ fn pdf_structure_table() {
         font_dir: common::find_font_dir(),
         ..PdfOptions::default()
     };
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| to_pdf(&doc, &options)));
-    let pdf = match result {
-        Ok(bytes) => bytes,
-        Err(_) => {
-            println!("font loading failed, skipping");
-            return;
-        }
-    };
+    let pdf = to_pdf(&doc, &options);
 
     let lopdf_doc = lopdf::Document::load_mem(&pdf).expect("lopdf should parse the PDF");
     let pages = lopdf_doc.get_pages();
     assert!(!pages.is_empty(), "PDF should have at least one page");
 
-    // 테이블이 있으므로 선(Line) 관련 명령이 PDF 스트림에 있어야 함
-    let has_line_commands = pdf.windows(2).any(|w| w == b" l" || w == b" m");
+    // 테이블이 있으므로 선(Line) 관련 명령이 PDF 스트림에 있어야 함 (압축 해제한 콘텐츠 기준)
+    let content: Vec<u8> = pages
+        .values()
+        .flat_map(|&page_id| lopdf_doc.get_page_content(page_id).unwrap_or_default())
+        .collect();
+    let has_line_commands = content.windows(2).any(|w| w == b" l" || w == b" m");
     assert!(
         has_line_commands,
         "PDF with tables should contain line drawing commands"
@@ -374,14 +336,7 @@ fn pdf_page_count_noori() {
         font_dir: common::find_font_dir(),
         ..PdfOptions::default()
     };
-    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| to_pdf(&doc, &options)));
-    let pdf = match result {
-        Ok(bytes) => bytes,
-        Err(_) => {
-            println!("font loading failed, skipping");
-            return;
-        }
-    };
+    let pdf = to_pdf(&doc, &options);
 
     let lopdf_doc = lopdf::Document::load_mem(&pdf).expect("lopdf should parse the PDF");
     let pages = lopdf_doc.get_pages();
@@ -416,7 +371,7 @@ fn test_all_fixtures_pdf_summary() {
         }
         let parser = HwpParser::new();
         let doc = match parser.parse(&buf) {
-            Ok(d) => d,
+            Ok(d) => to_document(&d),
             Err(_) => continue,
         };
         let options = PdfOptions::default();
@@ -463,7 +418,7 @@ fn test_all_fixtures_styled_summary_snapshots() {
         }
         let parser = HwpParser::new();
         let doc = match parser.parse(&buf) {
-            Ok(d) => d,
+            Ok(d) => to_document(&d),
             Err(_) => continue,
         };
         let options = PdfOptions::default();
@@ -477,4 +432,47 @@ fn test_all_fixtures_styled_summary_snapshots() {
         });
     }
 }
-*/
+
+// ============================================================
+// 글꼴 임베딩 / 결정성
+// ============================================================
+
+#[test]
+fn pdf_embeds_subset_fonts() {
+    let (Some(doc), Some(font_dir)) = (load_document("noori.hwp"), common::find_font_dir()) else {
+        return;
+    };
+    let options = PdfOptions {
+        font_dir: Some(font_dir),
+        ..PdfOptions::default()
+    };
+    let pdf = to_pdf(&doc, &options);
+    let lopdf_doc = lopdf::Document::load_mem(&pdf).expect("lopdf should parse the PDF");
+
+    let mut has_font_file = false;
+    let mut has_to_unicode = false;
+    for object in lopdf_doc.objects.values() {
+        if let Ok(dict) = object.as_dict() {
+            has_font_file |= dict.has(b"FontFile2");
+            has_to_unicode |= dict.has(b"ToUnicode");
+            if let Ok(base_font) = dict.get(b"BaseFont").and_then(|o| o.as_name()) {
+                // 표준 글꼴을 쓰지 않고 서브셋 태그(ABCDEF+)가 붙은 글꼴만 사용
+                assert_eq!(base_font.get(6), Some(&b'+'));
+            }
+        }
+    }
+    assert!(has_font_file, "subset TrueType font should be embedded");
+    assert!(has_to_unicode, "fonts should carry a ToUnicode CMap");
+}
+
+#[test]
+fn pdf_output_is_deterministic() {
+    let Some(doc) = load_document("noori.hwp") else {
+        return;
+    };
+    let options = PdfOptions {
+        font_dir: common::find_font_dir(),
+        ..PdfOptions::default()
+    };
+    assert_eq!(to_pdf(&doc, &options), to_pdf(&doc, &options));
+}
@@ -0,0 +1,219 @@
+mod common;
+use common::{paragraph, text};
+use hwp_model::document::Document;
+use hwp_model::paragraph::{Paragraph, RunContent};
+use hwp_model::query::{ContentPath, ParagraphPath, Visitor, VisitorMut};
+use hwp_model::shape::{ContainerObject, Picture, ShapeObject};
+use hwp_model::table::{Table, TableCell, TableRow};
+
+#[test]
+fn document_query_walk_and_paths() {
+    fn cell(paragraphs: Vec<Paragraph>) -> TableCell {
+        let mut cell = TableCell::default();
+        cell.content.paragraphs = paragraphs;
+        cell
+    }
+
+    // 본문: [표 (셀 0: "A", 셀 1: 묶음 개체 안 그림 + "B")], ["끝"]
+    let group = RunContent::Object(ShapeObject::Container(Box::new(ContainerObject {
+        children: vec![
+            ShapeObject::Picture(Box::default()),
+            ShapeObject::Picture(Box::default()),
+        ],
+        ..Default::default()
+    })));
+    let table = ShapeObject::Table(Box::new(Table {
+        rows: vec![TableRow {
+            cells: vec![
+                cell(vec![paragraph(vec![text("A")])]),
+                cell(vec![paragraph(vec![group]), paragraph(vec![text("B")])]),
+            ],
+        }],
+        ..Default::default()
+    }));
+    let doc = common::document(vec![
+        paragraph(vec![RunContent::Object(table)]),
+        paragraph(vec![text("끝")]),
+    ]);
+
+    // 문단은 문서 순서 (표 셀 안 문단이 표 다음 본문 문단보다 먼저)
+    let paths: Vec<String> = doc.paragraphs().map(|(path, _)| path.to_string()).collect();
+    assert_eq!(
+        paths,
+        ["0/0", "0/0.0.0.0/0", "0/0.0.0.1/0", "0/0.0.0.1/1", "0/1"]
+    );
+
+    let tables = doc.find_tables();
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].0.to_string(), "0/0:0.0");
+    let pictures: Vec<String> = doc
+        .find_pictures()
+        .iter()
+        .map(|(path, _)| path.to_string())
+        .collect();
+    assert_eq!(pictures, ["0/0.0.0.1/0:0.0>0", "0/0.0.0.1/0:0.0>1"]);
+
+    // 경로 문자열과 JSON 직렬화를 거쳐도 같은 노드를 가리킨다
+    for (path, paragraph) in doc.paragraphs() {
+        let parsed: ParagraphPath = path.to_string().parse().unwrap();
+        let json: ParagraphPath =
+            serde_json::from_str(&serde_json::to_string(&path).unwrap()).unwrap();
+        assert_eq!(parsed, path);
+        assert_eq!(json, path);
+        assert!(std::ptr::eq(doc.paragraph_at(&parsed).unwrap(), paragraph));
+    }
+    let second: ContentPath = pictures[1].parse().unwrap();
+    let (found_path, found) = &doc.find_pictures()[1];
+    assert_eq!(&second, found_path);
+    let Some(ShapeObject::Picture(picture)) = doc.shape_at(&second) else {
+        panic!("picture path should resolve");
+    };
+    assert!(std::ptr::eq::<Picture>(&**picture, *found));
+    assert!(matches!(
+        doc.content_at(&second),
+        Some(RunContent::Object(ShapeObject::Container(_)))
+    ));
+    assert!("0/x".parse::<ParagraphPath>().is_err());
+    assert!("0/0.0/1".parse::<ParagraphPath>().is_err());
+    assert!(doc.paragraph_at(&"0/0.0.0.5/0".parse().unwrap()).is_none());
+
+    // 가변 접근
+    let mut edited = doc.clone();
+    let path: ParagraphPath = "0/0.0.0.0/0".parse().unwrap();
+    edited.paragraph_at_mut(&path).unwrap().runs[0].contents = vec![text("수정")];
+    let cell_text = |doc: &Document| -> String {
+        doc.paragraph_at(&path).unwrap().runs[0]
+            .contents
+            .iter()
+            .filter_map(|c| match c {
+                RunContent::Text(t) => Some(t.elements.iter().filter_map(|e| e.plain_text())),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    };
+    assert_eq!(
+        (cell_text(&edited).as_str(), cell_text(&doc).as_str()),
+        ("수정", "A")
+    );
+
+    // 방문자: 필요한 메서드만 구현
+    #[derive(Default)]
+    struct Counter {
+        paragraphs: usize,
+        shapes: usize,
+        texts: Vec<String>,
+    }
+    impl<'a> Visitor<'a> for Counter {
+        fn visit_paragraph(&mut self, _path: &ParagraphPath, _paragraph: &'a Paragraph) {
+            self.paragraphs += 1;
+        }
+        fn visit_content(&mut self, _path: &ContentPath, content: &'a RunContent) {
+            if let RunContent::Text(t) = content {
+                self.texts.extend(
+                    t.elements
+                        .iter()
+                        .filter_map(|e| e.plain_text())
+                        .map(String::from),
+                );
+            }
+        }
+        fn visit_shape(&mut self, _path: &ContentPath, _shape: &'a ShapeObject) {
+            self.shapes += 1;
+        }
+    }
+    let mut counter = Counter::default();
+    doc.walk(&mut counter);
+    assert_eq!(counter.paragraphs, 5);
+    // 표, 묶음 개체, 그림 2개
+    assert_eq!(counter.shapes, 4);
+    assert_eq!(counter.texts, ["A", "B", "끝"]);
+
+    // 가변 방문자: 목록 → 문단 → 개체 순서, 목록에서 지운 문단은 방문하지 않는다
+    #[derive(Default)]
+    struct Editor {
+        lists: Vec<String>,
+        paragraphs: Vec<String>,
+        shapes: usize,
+    }
+    impl VisitorMut for Editor {
+        fn visit_list_mut(&mut self, path: &ParagraphPath, paragraphs: &mut Vec<Paragraph>) {
+            self.lists.push(path.to_string());
+            if paragraphs.len() > 1 && path.steps.is_empty() {
+                paragraphs.pop();
+            }
+        }
+        fn visit_paragraph_mut(&mut self, path: &ParagraphPath, _paragraph: &mut Paragraph) {
+            self.paragraphs.push(path.to_string());
+        }
+        fn visit_shape_mut(&mut self, _path: &ContentPath, shape: &mut ShapeObject) {
+            if let ShapeObject::Picture(picture) = shape {
+                picture.common.id = 7;
+            }
+            self.shapes += 1;
+        }
+    }
+    let mut edited = doc.clone();
+    let mut editor = Editor::default();
+    edited.walk_mut(&mut editor);
+    assert_eq!(editor.lists, ["0/0", "0/0.0.0.0/0", "0/0.0.0.1/0"]);
+    assert_eq!(
+        editor.paragraphs,
+        ["0/0", "0/0.0.0.0/0", "0/0.0.0.1/0", "0/0.0.0.1/1"]
+    );
+    assert_eq!(editor.shapes, 4);
+    assert_eq!(edited.sections[0].paragraphs.len(), 1);
+    assert!(edited.find_pictures().iter().all(|(_, p)| p.common.id == 7));
+}
+
+#[test]
+fn fixture_query_paths_resolve() {
+    // 실제 문서: 모든 문단 경로가 되돌아간다 (표 셀, 캡션, 글상자, 머리말, 각주)
+    for name in [
+        "table-caption.hwp",
+        "table-caption.hwpx",
+        "textbox.hwp",
+        "textbox.hwpx",
+        "headerfooter.hwp",
+        "headerfooter.hwpx",
+        "footnote-endnote.hwp",
+        "footnote-endnote.hwpx",
+        "sample-5017-pics.hwp",
+        "shapecontainer-2.hwpx",
+    ] {
+        let doc = common::load_fixture_document(name);
+        let mut nested = 0;
+        for (path, paragraph) in doc.paragraphs() {
+            nested += usize::from(!path.steps.is_empty());
+            let again = doc.paragraph_at(&path.to_string().parse().unwrap());
+            assert!(std::ptr::eq(again.unwrap(), paragraph), "{name}: {path}");
+        }
+        assert!(
+            nested > 0 || name.starts_with("sample-5017") || name.starts_with("shapecontainer"),
+            "{name}"
+        );
+        for (path, table) in doc.find_tables() {
+            assert!(
+                matches!(doc.shape_at(&path), Some(ShapeObject::Table(t)) if std::ptr::eq(&**t, table)),
+                "{name}: {path}"
+            );
+        }
+        for (path, picture) in doc.find_pictures() {
+            assert!(
+                matches!(doc.shape_at(&path), Some(ShapeObject::Picture(p)) if std::ptr::eq(&**p, picture)),
+                "{name}: {path}"
+            );
+        }
+    }
+
+    let pictures = common::load_fixture_document("sample-5017-pics.hwp");
+    assert_eq!(pictures.find_pictures().len(), 11);
+    // 묶음 개체 안 그림은 하위 개체 색인까지 경로에 담긴다
+    let grouped = common::load_fixture_document("shapecontainer-2.hwpx");
+    let paths: Vec<String> = grouped
+        .find_pictures()
+        .iter()
+        .map(|(path, _)| path.to_string())
+        .collect();
+    assert_eq!(paths, ["0/0:1.0>1"]);
+}
@@ -0,0 +1,59 @@
+//! CPU 래스터라이저 통합 테스트 (`cargo test --features raster`)
+//! CPU rasterizer integration tests
+#![cfg(feature = "raster")]
+mod common;
+use hwp_core::convert::to_document;
+use hwp_core::viewer::canvas::doc_to_canvas;
+use hwp_core::viewer::{RasterOptions, Rasterizer};
+use hwp_core::HwpParser;
+use hwp_model::document::Document;
+
+fn read_document(name: &str) -> Option<Document> {
+    let path = common::find_fixture_file(name)?;
+    let data = std::fs::read(path).ok()?;
+    let hwp = HwpParser::new().parse(&data).ok()?;
+    Some(to_document(&hwp))
+}
+
+/// PNG IHDR의 (너비, 높이)
+fn png_size(png: &[u8]) -> (u32, u32) {
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+    let width = u32::from_be_bytes(png[16..20].try_into().unwrap());
+    let height = u32::from_be_bytes(png[20..24].try_into().unwrap());
+    (width, height)
+}
+
+#[test]
+fn test_raster_page_size_follows_dpi() {
+    let Some(doc) = read_document("noori.hwp") else {
+        return;
+    };
+    let rasterizer = Rasterizer::new(RasterOptions {
+        dpi: 96.0,
+        font_dir: common::find_font_dir(),
+        ..Default::default()
+    });
+    let png = rasterizer.render_page(&doc, 0).unwrap();
+    // A4 210x297mm @ 96dpi
+    assert_eq!(png_size(&png), (794, 1123));
+
+    let half = Rasterizer::new(RasterOptions {
+        dpi: 48.0,
+        ..Default::default()
+    });
+    assert_eq!(png_size(&half.render_page(&doc, 0).unwrap()), (397, 562));
+}
+
+#[test]
+fn test_raster_all_pages() {
+    let Some(doc) = read_document("table.hwp") else {
+        return;
+    };
+    let rasterizer = Rasterizer::new(RasterOptions {
+        dpi: 36.0,
+        ..Default::default()
+    });
+    let pages = rasterizer.render_pages(&doc);
+    assert_eq!(pages.len(), doc_to_canvas(&doc).len());
+    assert!(rasterizer.render_page(&doc, pages.len()).is_none());
+}
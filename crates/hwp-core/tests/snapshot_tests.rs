@@ -512,6 +512,8 @@ fn test_document_html_snapshot() {
             include_page_info: Some(true),
             css_class_prefix: "hwp-".to_string(),
             layout: false,
+            keep_metafile: false,
+            revision_view: Default::default(),
         };
         let html = document.to_html(&options);
         assert_snapshot_with_path!(snapshot_name_html.as_str(), html);
@@ -564,6 +566,8 @@ fn test_headerfooter_html() {
                         include_page_info: Some(true),
                         css_class_prefix: "hwp-".to_string(),
                         layout: false,
+                        keep_metafile: false,
+                        revision_view: Default::default(),
                     };
 
                     let html = document.to_html(&options);
@@ -629,6 +633,8 @@ fn test_footnote_endnote_html_snapshot() {
         include_page_info: Some(false),
         css_class_prefix: "hwp-".to_string(),
         layout: false,
+        keep_metafile: false,
+        revision_view: Default::default(),
     };
     let html = document.to_html(&options);
 
@@ -693,6 +699,8 @@ fn test_outline_number_in_html_when_document_has_outline() {
             include_page_info: Some(false),
             css_class_prefix: "hwp-".to_string(),
             layout: false,
+            keep_metafile: false,
+            revision_view: Default::default(),
         };
         let html = document.to_html(&options);
         // 새 viewer: outline-number 클래스로 개요 마커가 렌더링됨
@@ -741,6 +749,8 @@ fn test_all_fixtures_html_snapshots() {
                             include_page_info: Some(true),
                             css_class_prefix: "hwp-".to_string(),
                             layout: false,
+                            keep_metafile: false,
+                            revision_view: Default::default(),
                         };
                         if file_name == "table" {
                             eprintln!("DEBUG: Processing table.hwp file");
@@ -838,6 +848,8 @@ fn test_table2_html_snapshot() {
                         include_page_info: Some(true),
                         css_class_prefix: "hwp-".to_string(),
                         layout: false,
+                        keep_metafile: false,
+                        revision_view: Default::default(),
                     };
                     eprintln!("DEBUG: Processing table2.hwp file");
                     let html = document.to_html(&options);
@@ -898,6 +910,8 @@ fn test_table_bug_cell_positions_match_hancom() {
         include_page_info: Some(true),
         css_class_prefix: "hwp-".to_string(),
         layout: false,
+        keep_metafile: false,
+        revision_view: Default::default(),
     };
     let css_filename = "table-bug_style.css";
     #[allow(deprecated)]
@@ -993,6 +1007,8 @@ fn test_nested_table_rendering() {
         include_page_info: Some(true),
         css_class_prefix: "hwp-".to_string(),
         layout: false,
+        keep_metafile: false,
+        revision_view: Default::default(),
     };
     let css_filename = "table-bug_style.css";
     #[allow(deprecated)]
@@ -1844,6 +1860,8 @@ fn test_debug_table_bug_page10() {
         include_page_info: Some(true),
         css_class_prefix: "hwp-".to_string(),
         layout: false,
+        keep_metafile: false,
+        revision_view: Default::default(),
     };
     let css_filename = "table-bug_style.css";
     #[allow(deprecated)]
@@ -0,0 +1,212 @@
+/// 페이지별 SVG 내보내기 통합 테스트
+/// Per-page SVG export integration tests
+mod common;
+use hwp_core::convert::to_document;
+use hwp_core::viewer::canvas::doc_to_canvas;
+use hwp_core::viewer::{doc_to_svg_pages, SvgOptions};
+use hwp_core::HwpParser;
+use hwp_model::document::Document;
+use hwp_model::paragraph::{Paragraph, Run, RunContent};
+use hwp_model::section::Section;
+use hwp_model::shape::{
+    ArcObject, ChartObject, ConnectLineObject, ConnectPoint, CurveObject, CurveSegment,
+    PolygonObject, ShapeCommon, ShapeComponentData, ShapeLineInfo, ShapeObject, TextArtObject,
+    VideoObject,
+};
+use hwp_model::types::{ArcType, ConnectLineType, CurveSegmentType, LineType1, Point, Size};
+
+fn read_document(name: &str) -> Option<Document> {
+    let path = common::find_fixture_file(name)?;
+    let data = std::fs::read(path).ok()?;
+    let hwp = HwpParser::new().parse(&data).ok()?;
+    Some(to_document(&hwp))
+}
+
+#[test]
+fn test_svg_one_document_per_canvas_page() {
+    for path in common::find_all_hwp_files() {
+        let name = std::path::Path::new(&path)
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .to_string();
+        let Some(doc) = read_document(&name) else {
+            continue;
+        };
+        let pages = doc_to_svg_pages(&doc, &SvgOptions::default());
+        assert_eq!(pages.len(), doc_to_canvas(&doc).len(), "{}", name);
+        for svg in &pages {
+            assert!(svg.starts_with("<svg xmlns="), "{}", name);
+            assert!(svg.ends_with("</svg>\n"), "{}", name);
+            assert_eq!(
+                svg.matches("<g ").count(),
+                svg.matches("</g>").count(),
+                "{}",
+                name
+            );
+        }
+    }
+}
+
+#[test]
+fn test_svg_text_and_page_size() {
+    let Some(doc) = read_document("noori.hwp") else {
+        return;
+    };
+    let pages = doc_to_svg_pages(&doc, &SvgOptions::default());
+    assert!(!pages.is_empty());
+    let first = &pages[0];
+    assert!(first.contains(r#"width="210mm" height="297mm" viewBox="0 0 210 297""#));
+    assert!(first.contains("<text "));
+    assert!(first.contains("누리"));
+}
+
+#[test]
+fn test_svg_images() {
+    let Some(doc) = read_document("noori.hwp") else {
+        return;
+    };
+    let embedded = doc_to_svg_pages(&doc, &SvgOptions::default()).concat();
+    assert!(embedded.contains(r#"xlink:href="data:image/"#));
+
+    let options = SvgOptions {
+        embed_images: false,
+        ..Default::default()
+    };
+    let placeholders = doc_to_svg_pages(&doc, &options).concat();
+    assert!(!placeholders.contains("<image "));
+}
+
+fn shape_document(objects: Vec<ShapeObject>) -> Document {
+    let paragraph = Paragraph {
+        runs: vec![Run {
+            char_shape_id: 0,
+            contents: objects.into_iter().map(RunContent::Object).collect(),
+        }],
+        ..Default::default()
+    };
+    let mut doc = Document::default();
+    doc.sections.push(Section {
+        paragraphs: vec![paragraph],
+        ..Default::default()
+    });
+    doc
+}
+
+fn common(width: i32, height: i32) -> ShapeCommon {
+    let mut common = ShapeCommon::default();
+    common.size.width = width;
+    common.size.height = height;
+    common
+}
+
+fn solid_line() -> ShapeLineInfo {
+    ShapeLineInfo {
+        style: LineType1::Solid,
+        width: 283, // 1mm
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_svg_shapes_from_shape_object() {
+    // 원본 크기의 절반으로 줄인 다각형
+    let polygon = PolygonObject {
+        common: common(3600, 3600),
+        component: ShapeComponentData {
+            org_size: Some(Size {
+                width: 7200,
+                height: 7200,
+            }),
+            ..Default::default()
+        },
+        points: vec![
+            Point { x: 0, y: 0 },
+            Point { x: 7200, y: 0 },
+            Point { x: 7200, y: 7200 },
+            Point { x: 0, y: 0 },
+        ],
+        line_shape: solid_line(),
+        ..Default::default()
+    };
+    let arc = ArcObject {
+        common: common(7200, 7200),
+        arc_type: ArcType::Chord,
+        center: Point { x: 0, y: 0 },
+        axis1: Point { x: 7200, y: 0 },
+        axis2: Point { x: 0, y: 7200 },
+        line_shape: solid_line(),
+        ..Default::default()
+    };
+    let segment = |segment_type, x1, y1, x2, y2| CurveSegment {
+        segment_type,
+        x1,
+        y1,
+        x2,
+        y2,
+    };
+    let curve = CurveObject {
+        common: common(7200, 7200),
+        segments: vec![
+            segment(CurveSegmentType::Curve, 0, 7200, 0, 0),
+            segment(CurveSegmentType::Curve, 0, 0, 7200, 0),
+            segment(CurveSegmentType::Curve, 7200, 0, 7200, 7200),
+        ],
+        line_shape: solid_line(),
+        ..Default::default()
+    };
+    let connect = ConnectLineObject {
+        common: common(7200, 3600),
+        connect_type: ConnectLineType::StraightOneWay,
+        start_pt: ConnectPoint {
+            x: 0,
+            y: 0,
+            ..Default::default()
+        },
+        end_pt: ConnectPoint {
+            x: 7200,
+            y: 3600,
+            ..Default::default()
+        },
+        line_shape: solid_line(),
+        ..Default::default()
+    };
+    let text_art = TextArtObject {
+        common: common(7200, 3600),
+        text: "글맵시".to_string(),
+        ..Default::default()
+    };
+    let doc = shape_document(vec![
+        ShapeObject::Polygon(Box::new(polygon)),
+        ShapeObject::Arc(Box::new(arc)),
+        ShapeObject::Curve(Box::new(curve)),
+        ShapeObject::ConnectLine(Box::new(connect)),
+        ShapeObject::TextArt(Box::new(text_art)),
+        ShapeObject::Ole(Box::default()),
+        ShapeObject::Chart(Box::new(ChartObject {
+            common: common(7200, 3600),
+            ..Default::default()
+        })),
+        ShapeObject::Video(Box::new(VideoObject {
+            common: common(7200, 3600),
+            ..Default::default()
+        })),
+    ]);
+    let svg = doc_to_svg_pages(&doc, &SvgOptions::default()).concat();
+
+    // 다각형 점은 현재 크기로 줄어듦
+    assert!(svg.contains(r##"<path d="M0 0L12.7 0L12.7 12.7L0 0Z" fill="none" stroke="#000000""##));
+    // 호(활꼴)는 베지어로 바꿔 닫음
+    assert!(svg.contains(r#"<path d="M25.4 0C25.4 14.03 14.03 25.4 0 25.4Z""#));
+    assert!(svg.contains(r#"<path d="M0 25.4C0 0 25.4 0 25.4 25.4""#));
+    // 한쪽 화살표 연결선: 선 + 채운 화살표
+    assert!(svg.contains(r#"<path d="M0 0L25.4 12.7""#));
+    assert!(svg.contains(r#"<path d="M25.4 12.7L"#));
+    assert!(svg.contains(r#"xml:space="preserve">글맵시</text>"#));
+    for label in ["OLE", "차트", "동영상"] {
+        assert!(svg.contains(&format!(
+            r##"fill="#7F7F7F" xml:space="preserve">{}</text>"##,
+            label
+        )));
+    }
+}
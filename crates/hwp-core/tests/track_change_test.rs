@@ -0,0 +1,109 @@
+mod common;
+use hwp_core::viewer::doc_html::{doc_to_html, DocHtmlOptions};
+use hwp_core::viewer::doc_markdown::{doc_to_markdown, DocMarkdownOptions};
+use hwp_core::viewer::RevisionView;
+use hwp_model::paragraph::{RunContent, TextContent, TextElement};
+use hwp_model::resources::{TrackChange, TrackChangeAuthor, TrackChangeType};
+
+// fixture 중에는 변경 추적 내용을 담은 문서가 없다
+
+#[test]
+fn track_change_review_modes() {
+    let mark = |kind: &str, tc_id: &str| {
+        let (id, tc_id, para_end) = (tc_id.to_string(), Some(tc_id.to_string()), false);
+        match kind {
+            "ins" => TextElement::InsertBegin {
+                id,
+                tc_id,
+                para_end,
+            },
+            "/ins" => TextElement::InsertEnd {
+                id,
+                tc_id,
+                para_end,
+            },
+            "del" => TextElement::DeleteBegin {
+                id,
+                tc_id,
+                para_end,
+            },
+            _ => TextElement::DeleteEnd {
+                id,
+                tc_id,
+                para_end,
+            },
+        }
+    };
+    let text = |s: &str| TextElement::Text(s.to_string());
+
+    let mut doc = common::document(vec![common::paragraph(vec![RunContent::Text(
+        TextContent {
+            char_shape_id: None,
+            elements: vec![
+                text("기간은 "),
+                mark("del", "1"),
+                text("1년"),
+                mark("/del", "1"),
+                mark("ins", "2"),
+                text("2년"),
+                mark("/ins", "2"),
+                text("이다"),
+            ],
+        },
+    )])]);
+    doc.resources.track_changes = vec![
+        TrackChange {
+            id: 1,
+            change_type: TrackChangeType::Delete,
+            date: Some("2024-03-01".to_string()),
+            author_id: 1,
+            ..Default::default()
+        },
+        TrackChange {
+            id: 2,
+            change_type: TrackChangeType::Insert,
+            author_id: 1,
+            ..Default::default()
+        },
+    ];
+    doc.resources.track_change_authors.push(TrackChangeAuthor {
+        id: 1,
+        name: "김검토".to_string(),
+        mark: true,
+        color: Some(0x008000),
+    });
+
+    let revisions = doc.revisions();
+    assert_eq!(revisions.len(), 2);
+    assert_eq!(revisions[0].text, "1년");
+    assert_eq!(revisions[0].author.as_deref(), Some("김검토"));
+    assert_eq!(revisions[0].date.as_deref(), Some("2024-03-01"));
+    assert_eq!(revisions[1].text, "2년");
+
+    let html = |view| {
+        doc_to_html(
+            &doc,
+            &DocHtmlOptions {
+                revision_view: view,
+                ..Default::default()
+            },
+        )
+    };
+    let markup = html(RevisionView::Markup);
+    assert!(markup.contains("<del class=\"hwp-revision\""), "{markup}");
+    assert!(markup.contains("<ins class=\"hwp-revision\""), "{markup}");
+    assert!(markup.contains("color: #008000"), "{markup}");
+    assert!(markup.contains("title=\"김검토 (2024-03-01)\""), "{markup}");
+
+    let final_html = html(RevisionView::Final);
+    assert!(final_html.contains("2년") && !final_html.contains("1년"));
+    assert!(!final_html.contains("<ins"));
+    let original_html = html(RevisionView::Original);
+    assert!(original_html.contains("1년") && !original_html.contains("2년"));
+
+    let markdown = doc_to_markdown(&doc, &DocMarkdownOptions::default());
+    assert!(
+        markdown.contains("기간은 {--1년--}{++2년++}이다"),
+        "{markdown}"
+    );
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::Color;
+
+// ═══════════════════════════════════════════
+// 차트 데이터 (OOXML DrawingML 차트 c:chartSpace)
+// HWP: OLE 스토리지의 OOXMLChartContents 스트림, HWPX: Chart/chartN.xml
+// ═══════════════════════════════════════════
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Chart {
+    /// 첫 번째 그래프 종류 (혼합형이면 첫 plot 기준)
+    pub chart_type: ChartType,
+    pub grouping: ChartGrouping,
+    pub title: Option<String>,
+    /// 항목 축 이름 (분산형은 X 값)
+    pub categories: Vec<String>,
+    pub series: Vec<ChartSeries>,
+    pub category_axis_title: Option<String>,
+    pub value_axis_title: Option<String>,
+    /// 범례 표시 여부
+    pub legend: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChartSeries {
+    pub name: Option<String>,
+    /// 항목별 값 (빈 값은 None)
+    pub values: Vec<Option<f64>>,
+    /// 계열 채우기/선 색 (RGB)
+    pub color: Color,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum ChartType {
+    /// 세로 막대형
+    #[default]
+    Column,
+    /// 가로 막대형
+    Bar,
+    Line,
+    Area,
+    Pie,
+    Doughnut,
+    /// 분산형 (XY)
+    Scatter,
+    /// 방사형
+    Radar,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum ChartGrouping {
+    /// 묶은 막대
+    #[default]
+    Clustered,
+    /// 표준 (꺾은선/영역)
+    Standard,
+    /// 누적
+    Stacked,
+    /// 100% 기준 누적
+    PercentStacked,
+}
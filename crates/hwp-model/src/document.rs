@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::hints::{HwpDocumentHints, HwpxDocumentHints};
+use crate::paragraph::{Paragraph, RunContent, TextElement};
+use crate::query::{ParagraphPath, Visitor, VisitorMut};
 use crate::resources::Resources;
 use crate::section::Section;
 use crate::types::CompatibleDocument;
@@ -21,6 +23,55 @@ pub struct Document {
     pub hwpx_hints: Option<HwpxDocumentHints>,
 }
 
+impl Document {
+    /// 본문, 바탕쪽과 모든 하위 목록(표 셀, 글상자, 머리말, 각주 등)의 문단 목록을 문서 순서로 방문.
+    /// [`Document::walk`]에서 목록만 받는 간단한 형태
+    pub fn for_each_paragraph_list<'a>(&'a self, f: &mut dyn FnMut(&'a [Paragraph])) {
+        struct Lists<'f, 'a>(&'f mut dyn FnMut(&'a [Paragraph]));
+        impl<'a> Visitor<'a> for Lists<'_, 'a> {
+            fn visit_list(&mut self, _path: &ParagraphPath, paragraphs: &'a [Paragraph]) {
+                (self.0)(paragraphs);
+            }
+        }
+        self.walk(&mut Lists(f));
+    }
+
+    /// [`Document::for_each_paragraph_list`]의 가변 버전 ([`Document::walk_mut`]).
+    /// 목록을 먼저 넘긴 뒤(문단 병합/삭제 가능) 남은 문단의 하위 목록을 방문한다.
+    pub fn for_each_paragraph_list_mut(&mut self, f: &mut dyn FnMut(&mut Vec<Paragraph>)) {
+        struct Lists<'f>(&'f mut dyn FnMut(&mut Vec<Paragraph>));
+        impl VisitorMut for Lists<'_> {
+            fn visit_list_mut(&mut self, _path: &ParagraphPath, paragraphs: &mut Vec<Paragraph>) {
+                (self.0)(paragraphs);
+            }
+        }
+        self.walk_mut(&mut Lists(f));
+    }
+
+    /// 미리보기 텍스트 (HWP PrvText, HWPX Preview/PrvText.txt).
+    /// 본문 문단의 텍스트만 모아 문단마다 CRLF로 끝낸다
+    pub fn preview_text(&self) -> String {
+        let mut out = String::new();
+        for section in &self.sections {
+            for para in &section.paragraphs {
+                for run in &para.runs {
+                    for content in &run.contents {
+                        if let RunContent::Text(tc) = content {
+                            for el in &tc.elements {
+                                if let TextElement::Text(s) = el {
+                                    out.push_str(s);
+                                }
+                            }
+                        }
+                    }
+                }
+                out.push_str("\r\n");
+            }
+        }
+        out
+    }
+}
+
 /// Dublin Core 메타데이터 + HWP 공통
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DocumentMeta {
@@ -0,0 +1,1168 @@
+use std::collections::HashMap;
+
+use crate::control::{Control, Field, FieldParameter};
+use crate::document::{Document, DocumentMeta};
+use crate::paragraph::{
+    shape_paragraph_lists_mut, Paragraph, RunContent, TextContent, TextElement,
+};
+use crate::query::{ContentPath, ParagraphPath, Visitor};
+use crate::shape::ShapeObject;
+use crate::table::Table;
+use crate::types::{FieldType, NumberingType};
+
+// ═══════════════════════════════════════════
+// 필드 결과 계산 (FieldBegin ~ FieldEnd 사이의 표시 텍스트)
+// ═══════════════════════════════════════════
+//
+// - 계산식: `=SUM(LEFT)`, `=AVG(ABOVE)`, `=B2*C2`, `=SUM(B?:E?)` 등 표 셀 값으로 계산하고
+//   결과 형식(`%g`, `%.2f`, 끝에 `,`이면 천 단위 구분)에 맞춰 쓴다
+// - 날짜: 만든 날짜(문서 정보)나 `FieldContext::now`를 명령의 날짜 형식으로 쓴다
+// - 문서 요약: 제목/주제/지은이/키워드/설명 등 `DocumentMeta` 값
+// - 상호 참조: 책갈피가 걸린 문단 텍스트, 또는 캡션 달린 표/그림/수식의 번호
+// - 계산할 수 없는 필드(값 없음, 잘못된 식)는 저장된 결과를 그대로 둔다
+
+/// 필드 파라미터 이름 (HWPX `hp:fieldBegin`의 `hp:parameters`)
+const PARAM_COMMAND: &str = "Command";
+const PARAM_FORMULA: &str = "Formula";
+const PARAM_RESULT_FORMAT: &str = "ResultFormat";
+const PARAM_LAST_RESULT: &str = "LastResult";
+const PARAM_REF_PATH: &str = "RefPath";
+const PARAM_REF_TYPE: &str = "RefType";
+const PARAM_REF_CONTENT_TYPE: &str = "RefContentType";
+
+/// 날짜 형식이 없는 날짜 필드의 기본 형식
+const DEFAULT_DATE_FORMAT: &str = "YYYY-MM-DD";
+
+/// 필드 계산에 쓰는 문서 밖 정보
+#[derive(Debug, Clone, Default)]
+pub struct FieldContext {
+    /// 현재 시각 (날짜 필드). None이면 날짜 필드는 저장된 결과를 유지한다
+    pub now: Option<FieldDate>,
+    /// 문서 파일 경로 (파일 경로 필드)
+    pub file_path: Option<String>,
+}
+
+/// 날짜 필드에 쓰는 일시 (시간대 없음)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FieldDate {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+/// 계산식 필드 명령 (`=SUM(B?:E?)??%g,;;67.5` → 식, 결과 형식, 마지막 결과)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Formula {
+    /// `=`로 시작하는 식
+    pub expression: String,
+    /// 결과 형식 (`%g`, `%.2f,` 등. 비어 있으면 `%g`)
+    pub result_format: String,
+    /// 저장된 마지막 결과
+    pub last_result: Option<String>,
+}
+
+// ── 필드 명령 ──
+
+impl Field {
+    /// 필드 명령 문자열. HWPX는 `Command` 파라미터, HWP는 필드 이름 자리에 명령을 저장한다
+    pub fn command(&self) -> Option<String> {
+        self.parameter_str(PARAM_COMMAND)
+            .or_else(|| self.name.clone())
+    }
+
+    /// 계산식 필드의 식과 결과 형식. 계산식 필드가 아니면 None
+    pub fn formula(&self) -> Option<Formula> {
+        if self.field_type != FieldType::Formula {
+            return None;
+        }
+        let command = self.command().unwrap_or_default();
+        let (expression, rest) = command.split_once("??").unwrap_or((&command, ""));
+        let (result_format, last_result) = match rest.split_once(";;") {
+            Some((format, last)) => (format, Some(last.to_string())),
+            None => (rest, None),
+        };
+        Some(Formula {
+            expression: self
+                .parameter_str(PARAM_FORMULA)
+                .unwrap_or_else(|| expression.to_string()),
+            result_format: self
+                .parameter_str(PARAM_RESULT_FORMAT)
+                .unwrap_or_else(|| result_format.to_string()),
+            last_result: self.parameter_str(PARAM_LAST_RESULT).or(last_result),
+        })
+    }
+
+    /// 계산식 필드에 새 결과를 기록한다 (`LastResult` 파라미터와 명령의 `;;` 뒤)
+    fn store_last_result(&mut self, result: &str) {
+        for param in &mut self.parameters {
+            if let FieldParameter::String { name, value } = param {
+                if name == PARAM_LAST_RESULT {
+                    *value = result.to_string();
+                } else if name == PARAM_COMMAND {
+                    *value = with_last_result(value, result);
+                }
+            }
+        }
+        if self.parameter_str(PARAM_COMMAND).is_none() {
+            if let Some(name) = &mut self.name {
+                *name = with_last_result(name, result);
+            }
+        }
+    }
+}
+
+fn with_last_result(command: &str, result: &str) -> String {
+    match command.split_once(";;") {
+        Some((head, _)) => format!("{};;{}", head, result),
+        None => command.to_string(),
+    }
+}
+
+// ── 날짜 ──
+
+impl FieldDate {
+    /// 1970-01-01 00:00:00 기준 초 → 일시 (UTC)
+    pub fn from_unix(seconds: i64) -> Self {
+        let days = seconds.div_euclid(86_400);
+        let secs = seconds.rem_euclid(86_400);
+        // 그레고리력 일수 → 연월일 (Howard Hinnant, civil_from_days)
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u8;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+        Self {
+            year,
+            month,
+            day,
+            hour: (secs / 3600) as u8,
+            minute: (secs / 60 % 60) as u8,
+            second: (secs % 60) as u8,
+        }
+    }
+
+    /// 문서 정보의 날짜 문자열 → 일시.
+    /// ISO 8601(`2012-05-29T12:32:40Z`)과 한글 표기(`2012년 5월 29일 화요일 오후 12:32:40`)를 읽는다.
+    pub fn parse(s: &str) -> Option<Self> {
+        let numbers: Vec<u32> = s
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|part| !part.is_empty())
+            .take(6)
+            .map(|part| part.parse().ok())
+            .collect::<Option<_>>()?;
+        if numbers.len() < 3 || !(1..=12).contains(&numbers[1]) || !(1..=31).contains(&numbers[2]) {
+            return None;
+        }
+        let at = |i: usize| numbers.get(i).copied().unwrap_or(0).min(59) as u8;
+        let mut hour = numbers.get(3).copied().unwrap_or(0).min(23) as u8;
+        if (s.contains("오후") || s.contains("PM")) && hour < 12 {
+            hour += 12;
+        } else if (s.contains("오전") || s.contains("AM")) && hour == 12 {
+            hour = 0;
+        }
+        Some(Self {
+            year: numbers[0] as i32,
+            month: numbers[1] as u8,
+            day: numbers[2] as u8,
+            hour,
+            minute: at(4),
+            second: at(5),
+        })
+    }
+
+    /// 요일 (0 = 일요일)
+    pub fn weekday(&self) -> u8 {
+        const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+        let month = self.month.clamp(1, 12) as usize;
+        let year = if month < 3 { self.year - 1 } else { self.year };
+        let day = year + year.div_euclid(4) - year.div_euclid(100)
+            + year.div_euclid(400)
+            + OFFSETS[month - 1]
+            + self.day as i32;
+        day.rem_euclid(7) as u8
+    }
+
+    /// 날짜 형식 문자열로 쓴다.
+    ///
+    /// `YYYY`/`YY` 연, `MM`/`M` 월, `DD`/`D` 일, `DDDD`/`DDD` 요일(`화요일`/`화`),
+    /// `HH`/`H` 시(24시간), `hh`/`h` 시(12시간), `mm`/`m` 분, `ss`/`s` 초, `AP` 오전/오후.
+    /// 연·일·요일은 소문자(`yyyy`, `dd`, `ddd`)도 받는다. 그 밖의 글자는 그대로 쓴다.
+    pub fn format(&self, pattern: &str) -> String {
+        const WEEKDAYS: [&str; 7] = ["일", "월", "화", "수", "목", "금", "토"];
+        let chars: Vec<char> = pattern.chars().collect();
+        let mut out = String::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let run = chars[i..].iter().take_while(|&&x| x == c).count();
+            if c == 'A' && chars.get(i + 1) == Some(&'P') {
+                out.push_str(if self.hour < 12 { "오전" } else { "오후" });
+                i += 2;
+                continue;
+            }
+            let (text, used) = match c {
+                'Y' | 'y' if run >= 4 => (format!("{:04}", self.year), 4),
+                'Y' | 'y' if run >= 2 => (format!("{:02}", self.year.rem_euclid(100)), 2),
+                'M' if run >= 2 => (format!("{:02}", self.month), 2),
+                'M' => (self.month.to_string(), 1),
+                'D' | 'd' if run >= 4 => (format!("{}요일", WEEKDAYS[self.weekday() as usize]), 4),
+                'D' | 'd' if run == 3 => (WEEKDAYS[self.weekday() as usize].to_string(), 3),
+                'D' | 'd' if run == 2 => (format!("{:02}", self.day), 2),
+                'D' | 'd' => (self.day.to_string(), 1),
+                'H' if run >= 2 => (format!("{:02}", self.hour), 2),
+                'H' => (self.hour.to_string(), 1),
+                'h' if run >= 2 => (format!("{:02}", self.hour_12()), 2),
+                'h' => (self.hour_12().to_string(), 1),
+                'm' if run >= 2 => (format!("{:02}", self.minute), 2),
+                'm' => (self.minute.to_string(), 1),
+                's' if run >= 2 => (format!("{:02}", self.second), 2),
+                's' => (self.second.to_string(), 1),
+                _ => (c.to_string(), 1),
+            };
+            out.push_str(&text);
+            i += used;
+        }
+        out
+    }
+
+    fn hour_12(&self) -> u8 {
+        match self.hour % 12 {
+            0 => 12,
+            h => h,
+        }
+    }
+}
+
+/// 명령에 날짜 형식 기호가 있는지 (없으면 기본 형식을 쓴다)
+fn is_date_pattern(pattern: &str) -> bool {
+    ["YY", "yy", "MM", "DD", "dd"]
+        .iter()
+        .any(|token| pattern.contains(token))
+}
+
+// ── 문서 ──
+
+impl Document {
+    /// 계산해서 채우는 필드(계산식, 날짜, 문서 요약, 파일 경로, 상호 참조)가 있는지
+    pub fn has_computed_fields(&self) -> bool {
+        #[derive(Default)]
+        struct Computed(bool);
+        impl Visitor<'_> for Computed {
+            fn visit_control(&mut self, _path: &ContentPath, control: &Control) {
+                if let Control::FieldBegin(field) = control {
+                    self.0 = self.0 || is_computed(&field.field_type);
+                }
+            }
+        }
+        let mut computed = Computed::default();
+        self.walk(&mut computed);
+        computed.0
+    }
+
+    /// 필드 결과를 다시 계산해 FieldBegin ~ FieldEnd 사이 텍스트를 바꾼다.
+    /// 결과가 바뀐 필드 수를 돌려준다.
+    ///
+    /// 표 계산식은 문서 순서대로 계산하므로 뒤의 식은 앞에서 새로 계산한 셀 값을 본다.
+    pub fn evaluate_fields(&mut self, context: &FieldContext) -> usize {
+        let env = Env {
+            context,
+            meta: self.meta.clone(),
+            targets: Targets::collect(self),
+        };
+        // 표 계산식은 셀 주소와 앞 셀의 새 값을 보므로 표 단위로 직접 내려간다
+        let mut changed = 0;
+        for section in &mut self.sections {
+            evaluate_list(&mut section.paragraphs, None, &env, &mut changed);
+            for master in &mut section.definition.master_pages {
+                evaluate_list(&mut master.content.paragraphs, None, &env, &mut changed);
+            }
+        }
+        changed
+    }
+}
+
+fn is_computed(field_type: &FieldType) -> bool {
+    matches!(
+        field_type,
+        FieldType::Formula
+            | FieldType::Date
+            | FieldType::DocDate
+            | FieldType::Summary
+            | FieldType::Path
+            | FieldType::CrossRef
+    )
+}
+
+/// 계산에 필요한 문서 전체 정보
+struct Env<'a> {
+    context: &'a FieldContext,
+    meta: DocumentMeta,
+    targets: Targets,
+}
+
+fn evaluate_list(
+    paragraphs: &mut [Paragraph],
+    cell: Option<&CellScope>,
+    env: &Env,
+    changed: &mut usize,
+) {
+    for para in paragraphs.iter_mut() {
+        *changed += evaluate_paragraph(para, cell, env);
+        for content in para.runs.iter_mut().flat_map(|run| &mut run.contents) {
+            match content {
+                RunContent::Object(shape) => evaluate_shape(shape, env, changed),
+                _ => {
+                    for list in content.paragraph_lists_mut() {
+                        evaluate_list(list, None, env, changed);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 개체 안 문단 목록. 묶음 개체 안의 표도 표 단위로 내려가 셀 주소를 갖게 한다
+fn evaluate_shape(shape: &mut ShapeObject, env: &Env, changed: &mut usize) {
+    match shape {
+        ShapeObject::Table(table) => evaluate_table(table, env, changed),
+        ShapeObject::Container(container) => {
+            for child in &mut container.children {
+                evaluate_shape(child, env, changed);
+            }
+            if let Some(caption) = &mut container.common.caption {
+                evaluate_list(&mut caption.content.paragraphs, None, env, changed);
+            }
+        }
+        _ => {
+            let mut lists = Vec::new();
+            shape_paragraph_lists_mut(shape, &mut lists);
+            for list in lists {
+                evaluate_list(list, None, env, changed);
+            }
+        }
+    }
+}
+
+fn evaluate_table(table: &mut Table, env: &Env, changed: &mut usize) {
+    let mut grid = CellGrid::new(table);
+    for cell in table.rows.iter_mut().flat_map(|row| &mut row.cells) {
+        let scope = CellScope {
+            grid: &grid,
+            row: cell.row,
+            col: cell.col,
+        };
+        evaluate_list(&mut cell.content.paragraphs, Some(&scope), env, changed);
+        grid.set(cell.row, cell.col, &cell.content.paragraphs);
+    }
+    if let Some(caption) = &mut table.common.caption {
+        evaluate_list(&mut caption.content.paragraphs, None, env, changed);
+    }
+}
+
+/// 문단 안의 필드를 계산한다
+fn evaluate_paragraph(para: &mut Paragraph, cell: Option<&CellScope>, env: &Env) -> usize {
+    rewrite_fields(para, |field, current| {
+        let result = field_result(field, cell, env)?;
+        if field.field_type == FieldType::Formula && result != current {
+            field.store_last_result(&result);
+        }
+        Some(result)
+    })
+}
+
+// ── 필드 구간 바꾸기 ──
+
+/// 문단 안 필드 구간 위치 (run 번호, contents 번호)
+type Position = (usize, usize);
+
+/// 문단 안 필드 구간(FieldBegin ~ FieldEnd)을 `result`가 돌려준 텍스트로 바꾼다.
+/// `result`는 필드와 지금 구간 텍스트를 받고, None이면 그대로 둔다.
+/// 텍스트가 바뀐 필드 수를 돌려준다.
+pub(crate) fn rewrite_fields(
+    para: &mut Paragraph,
+    mut result: impl FnMut(&mut Field, &str) -> Option<String>,
+) -> usize {
+    let mut spans = field_spans(para);
+    let mut changed = 0;
+    let mut resized = false;
+    // 뒤쪽 구간부터 바꿔야 앞쪽 구간의 위치가 유지된다
+    spans.sort();
+    for (begin, end) in spans.into_iter().rev() {
+        let current = span_text(para, begin, end);
+        let RunContent::Control(Control::FieldBegin(field)) =
+            &mut para.runs[begin.0].contents[begin.1]
+        else {
+            continue;
+        };
+        let Some(text) = result(field, &current) else {
+            continue;
+        };
+        if current == text {
+            continue;
+        }
+        resized |= current.chars().count() != text.chars().count();
+        replace_span(para, begin, end, &text);
+        changed += 1;
+    }
+    if resized {
+        // 줄 배치 캐시는 글자 위치 기준이라 길이가 바뀌면 맞지 않는다
+        para.line_segments.clear();
+    }
+    changed
+}
+
+/// 문단 안 필드 구간 (FieldBegin 위치, FieldEnd 위치). 다른 필드를 품은 필드는 뺀다
+pub(crate) fn field_spans(para: &Paragraph) -> Vec<(Position, Position)> {
+    let mut spans = Vec::new();
+    let mut open: Vec<(Position, bool)> = Vec::new();
+    for (r, run) in para.runs.iter().enumerate() {
+        for (c, content) in run.contents.iter().enumerate() {
+            match content {
+                RunContent::Control(Control::FieldBegin(_)) => {
+                    if let Some(outer) = open.last_mut() {
+                        outer.1 = true;
+                    }
+                    open.push(((r, c), false));
+                }
+                RunContent::Control(Control::FieldEnd) => {
+                    if let Some((begin, nested)) = open.pop() {
+                        if !nested {
+                            spans.push((begin, (r, c)));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    spans
+}
+
+fn contents_between(
+    para: &Paragraph,
+    begin: Position,
+    end: Position,
+) -> impl Iterator<Item = (u16, &RunContent)> {
+    para.runs
+        .iter()
+        .enumerate()
+        .skip(begin.0)
+        .take(end.0 - begin.0 + 1)
+        .flat_map(move |(r, run)| {
+            let from = if r == begin.0 { begin.1 + 1 } else { 0 };
+            let to = if r == end.0 {
+                end.1
+            } else {
+                run.contents.len()
+            };
+            run.contents[from..to.max(from)]
+                .iter()
+                .map(move |content| (run.char_shape_id, content))
+        })
+}
+
+/// 필드 구간의 평문 (필드 결과)
+pub(crate) fn span_text(para: &Paragraph, begin: Position, end: Position) -> String {
+    contents_between(para, begin, end)
+        .filter_map(|(_, content)| match content {
+            RunContent::Text(text) => Some(text_of(text)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// 구간 내용을 텍스트 하나로 바꾼다. 글자 모양은 구간의 첫 텍스트를 따르고 '\n'은 줄바꿈으로 넣는다
+fn replace_span(para: &mut Paragraph, begin: Position, end: Position, text: &str) {
+    let run_shape = para.runs[begin.0].char_shape_id;
+    let char_shape = contents_between(para, begin, end)
+        .find_map(|(run_shape, content)| match content {
+            RunContent::Text(text) => Some(text.char_shape_id.unwrap_or(run_shape)),
+            _ => None,
+        })
+        .unwrap_or(run_shape);
+
+    if begin.0 == end.0 {
+        para.runs[begin.0].contents.drain(begin.1 + 1..end.1);
+    } else {
+        para.runs[end.0].contents.drain(..end.1);
+        para.runs[begin.0].contents.truncate(begin.1 + 1);
+        for run in &mut para.runs[begin.0 + 1..end.0] {
+            run.contents.clear();
+        }
+    }
+    if text.is_empty() {
+        return;
+    }
+    let mut elements = Vec::new();
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            elements.push(TextElement::LineBreak);
+        }
+        if !line.is_empty() {
+            elements.push(TextElement::Text(line.to_string()));
+        }
+    }
+    para.runs[begin.0].contents.insert(
+        begin.1 + 1,
+        RunContent::Text(TextContent {
+            char_shape_id: (char_shape != run_shape).then_some(char_shape),
+            elements,
+        }),
+    );
+}
+
+fn text_of(text: &TextContent) -> String {
+    text.elements
+        .iter()
+        .filter_map(|e| e.plain_text())
+        .collect()
+}
+
+pub(crate) fn paragraph_text(para: &Paragraph) -> String {
+    para.runs
+        .iter()
+        .flat_map(|run| &run.contents)
+        .filter_map(|content| match content {
+            RunContent::Text(text) => Some(text_of(text)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn field_result(field: &Field, cell: Option<&CellScope>, env: &Env) -> Option<String> {
+    match field.field_type {
+        FieldType::Formula => {
+            let formula = field.formula()?;
+            let value = evaluate_formula(&formula.expression, cell)?;
+            Some(format_number(value, &formula.result_format))
+        }
+        FieldType::Date => {
+            let now = env.context.now?;
+            Some(now.format(&date_pattern(field)))
+        }
+        FieldType::DocDate => {
+            let created = env.meta.created_date.as_deref()?;
+            Some(FieldDate::parse(created)?.format(&date_pattern(field)))
+        }
+        FieldType::Summary => summary_value(&field.command()?, &env.meta),
+        FieldType::Path => env.context.file_path.clone(),
+        FieldType::CrossRef => env.targets.resolve(field),
+        _ => None,
+    }
+}
+
+fn date_pattern(field: &Field) -> String {
+    field
+        .command()
+        .filter(|command| is_date_pattern(command))
+        .unwrap_or_else(|| DEFAULT_DATE_FORMAT.to_string())
+}
+
+/// 문서 요약 필드 키 (`$title`, `제목` 등) → 문서 정보 값
+fn summary_value(command: &str, meta: &DocumentMeta) -> Option<String> {
+    let key = command.trim().trim_start_matches('$').to_lowercase();
+    let value = match key.as_str() {
+        "title" | "제목" => &meta.title,
+        "subject" | "주제" => &meta.subject,
+        "author" | "creator" | "지은이" | "작성자" => &meta.creator,
+        "keyword" | "keywords" | "키워드" => &meta.keywords,
+        "comment" | "comments" | "description" | "설명" | "기타" => &meta.description,
+        "publisher" | "발행자" => &meta.publisher,
+        "created" | "createdate" | "만든 날짜" => &meta.created_date,
+        "lastsaved" | "modified" | "고친 날짜" => &meta.modified_date,
+        _ => return None,
+    };
+    Some(value.clone().unwrap_or_default())
+}
+
+// ═══════════════════════════════════════════
+// 상호 참조 대상
+// ═══════════════════════════════════════════
+
+/// 캡션 번호가 붙는 개체 (표/그림/수식)
+struct NumberedObject {
+    kind: NumberingType,
+    number: u32,
+    id: u64,
+    inst_id: Option<u64>,
+    caption: String,
+}
+
+/// 책갈피 이름 → 문단 텍스트, 번호 붙는 개체 목록 (문서 순서)
+#[derive(Default)]
+struct Targets {
+    bookmarks: HashMap<String, String>,
+    objects: Vec<NumberedObject>,
+}
+
+impl Targets {
+    fn collect(doc: &Document) -> Self {
+        let settings = &doc.settings;
+        let mut collector = TargetCollector {
+            targets: Targets::default(),
+            next: [
+                settings.picture_start.max(1) as u32,
+                settings.table_start.max(1) as u32,
+                settings.equation_start.max(1) as u32,
+            ],
+        };
+        doc.walk(&mut collector);
+        collector.targets
+    }
+
+    /// 상호 참조 필드 → 표시할 텍스트.
+    ///
+    /// 대상 경로(`RefPath`, 없으면 명령)는 `?#이름`, `#이름`, `이름` 꼴이다. 책갈피 이름이면
+    /// 그 문단 텍스트, `표 3`/`Table3`처럼 종류와 번호이거나 개체 ID면 개체 번호를 돌려준다.
+    /// `RefContentType`이 캡션(`CAPTION`/`CONTENTS`)을 가리키면 캡션 전체를 돌려준다.
+    fn resolve(&self, field: &Field) -> Option<String> {
+        let path = field
+            .parameter_str(PARAM_REF_PATH)
+            .or_else(|| field.command())?;
+        let name = path.trim().trim_start_matches('?').trim_start_matches('#');
+        if let Some(text) = self.bookmarks.get(name) {
+            return Some(text.clone());
+        }
+
+        let ref_type = field
+            .parameter_str(PARAM_REF_TYPE)
+            .unwrap_or_default()
+            .to_ascii_uppercase();
+        let label = name.trim_end_matches(|c: char| c.is_ascii_digit());
+        let digits = &name[label.len()..];
+        let number: u64 = digits.parse().ok()?;
+        let kind = object_kind(&ref_type).or_else(|| object_kind(label.trim()));
+        let object = match kind {
+            Some(kind) => self
+                .objects
+                .iter()
+                .find(|o| o.kind == kind && o.number as u64 == number),
+            None => self
+                .objects
+                .iter()
+                .find(|o| o.id == number || o.inst_id == Some(number)),
+        }?;
+
+        let content = field
+            .parameter_str(PARAM_REF_CONTENT_TYPE)
+            .unwrap_or_default()
+            .to_ascii_uppercase();
+        if (content.contains("CAPTION") || content.contains("CONTENTS"))
+            && !object.caption.is_empty()
+        {
+            Some(object.caption.clone())
+        } else {
+            Some(object.number.to_string())
+        }
+    }
+}
+
+/// 번호 종류 → 카운터 자리
+/// 문서 순서로 책갈피와 번호 붙는 개체를 모은다
+struct TargetCollector {
+    targets: Targets,
+    /// 다음 번호 (그림, 표, 수식)
+    next: [u32; 3],
+}
+
+impl<'a> Visitor<'a> for TargetCollector {
+    fn visit_paragraph(&mut self, _path: &ParagraphPath, para: &'a Paragraph) {
+        for content in para.runs.iter().flat_map(|run| &run.contents) {
+            let name = match content {
+                RunContent::Control(Control::Bookmark(bookmark)) => Some(bookmark.name.clone()),
+                RunContent::Control(Control::FieldBegin(field))
+                    if field.field_type == FieldType::Bookmark =>
+                {
+                    field.command()
+                }
+                _ => None,
+            };
+            if let Some(name) = name {
+                self.targets
+                    .bookmarks
+                    .entry(name)
+                    .or_insert_with(|| paragraph_text(para).trim().to_string());
+            }
+        }
+    }
+
+    fn visit_control(&mut self, _path: &ContentPath, control: &'a Control) {
+        if let Control::NewNum(new_num) = control {
+            if let Some(slot) = counter(&new_num.num_type) {
+                self.next[slot] = new_num.num as u32;
+            }
+        }
+    }
+
+    fn visit_content(&mut self, _path: &ContentPath, content: &'a RunContent) {
+        let RunContent::Object(shape) = content else {
+            return;
+        };
+        let common = shape.common();
+        let Some(slot) = counter(&common.numbering_type) else {
+            return;
+        };
+        let number = self.next[slot];
+        self.next[slot] += 1;
+        self.targets.objects.push(NumberedObject {
+            kind: common.numbering_type.clone(),
+            number,
+            id: common.id,
+            inst_id: component_inst_id(shape),
+            caption: common
+                .caption
+                .as_ref()
+                .map(|c| caption_text(&c.content.paragraphs, number))
+                .unwrap_or_default(),
+        });
+    }
+}
+
+fn counter(kind: &NumberingType) -> Option<usize> {
+    match kind {
+        NumberingType::None => None,
+        NumberingType::Picture => Some(0),
+        NumberingType::Table => Some(1),
+        NumberingType::Equation => Some(2),
+    }
+}
+
+/// `TARGET_TABLE`, `표`, `Table` 등 → 번호 종류
+fn object_kind(label: &str) -> Option<NumberingType> {
+    let label = label.to_ascii_uppercase();
+    if label.contains("TABLE") || label.contains('표') {
+        Some(NumberingType::Table)
+    } else if label.contains("EQUATION") || label.contains("수식") {
+        Some(NumberingType::Equation)
+    } else if label.contains("PICTURE") || label.contains("IMAGE") || label.contains("그림") {
+        Some(NumberingType::Picture)
+    } else {
+        None
+    }
+}
+
+fn component_inst_id(shape: &ShapeObject) -> Option<u64> {
+    match shape {
+        ShapeObject::Picture(p) => p.component.inst_id,
+        _ => None,
+    }
+}
+
+/// 캡션 문단 텍스트. 캡션 안 자동 번호는 개체 번호로 채운다
+fn caption_text(paragraphs: &[Paragraph], number: u32) -> String {
+    let mut text = String::new();
+    for para in paragraphs {
+        for content in para.runs.iter().flat_map(|run| &run.contents) {
+            match content {
+                RunContent::Text(t) => text.push_str(&text_of(t)),
+                RunContent::Control(Control::AutoNum(_)) => text.push_str(&number.to_string()),
+                _ => {}
+            }
+        }
+    }
+    text.trim().to_string()
+}
+
+// ═══════════════════════════════════════════
+// 표 계산식
+// ═══════════════════════════════════════════
+//
+// - 셀 주소: 열은 A부터, 행은 1부터 (`B3`). `?`는 식이 있는 셀의 행/열 (`B?`, `?2`)
+// - 범위: `B2:E2`, 방향: `LEFT`/`RIGHT`/`ABOVE`/`BELOW` (같은 행/열에서 해당 방향의 모든 셀)
+// - 함수: SUM, AVG(AVERAGE), PRODUCT, MIN, MAX, COUNT, ABS, INT, SQRT, ROUND(값, 자릿수)
+// - 목록 인자에서는 숫자가 아닌 셀을 건너뛰고, 산술식의 빈 셀은 0으로 본다
+
+/// 표의 셀 값 (병합 셀은 왼쪽 위 주소)
+struct CellGrid {
+    values: HashMap<(u16, u16), Option<f64>>,
+}
+
+impl CellGrid {
+    fn new(table: &Table) -> Self {
+        let mut grid = CellGrid {
+            values: HashMap::new(),
+        };
+        for cell in table.rows.iter().flat_map(|row| &row.cells) {
+            grid.set(cell.row, cell.col, &cell.content.paragraphs);
+        }
+        grid
+    }
+
+    fn set(&mut self, row: u16, col: u16, paragraphs: &[Paragraph]) {
+        let text: String = paragraphs.iter().map(paragraph_text).collect();
+        self.values.insert((row, col), parse_number(&text));
+    }
+
+    /// 조건에 맞는 셀 중 숫자인 값 (행, 열 순서)
+    fn numbers(&self, filter: impl Fn(u16, u16) -> bool) -> Vec<f64> {
+        let mut cells: Vec<_> = self
+            .values
+            .iter()
+            .filter(|((row, col), _)| filter(*row, *col))
+            .filter_map(|(address, value)| value.map(|v| (*address, v)))
+            .collect();
+        cells.sort_by_key(|(address, _)| *address);
+        cells.into_iter().map(|(_, v)| v).collect()
+    }
+}
+
+/// 식이 있는 셀과 그 표
+struct CellScope<'a> {
+    grid: &'a CellGrid,
+    row: u16,
+    col: u16,
+}
+
+/// 셀 텍스트 → 숫자 (천 단위 쉼표와 공백 무시)
+fn parse_number(text: &str) -> Option<f64> {
+    let s: String = text
+        .chars()
+        .filter(|c| *c != ',' && !c.is_whitespace())
+        .collect();
+    let numeric = !s.is_empty()
+        && s.chars().any(|c| c.is_ascii_digit())
+        && s.chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+'));
+    if numeric {
+        s.parse().ok()
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Name(String),
+    Colon,
+    Comma,
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Open,
+    Close,
+}
+
+fn tokenize(expression: &str) -> Option<Vec<Token>> {
+    let chars: Vec<char> = expression.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let token = match c {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '0'..='9' | '.' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                tokens.push(Token::Number(text.parse().ok()?));
+                continue;
+            }
+            c if c.is_ascii_alphabetic() || c == '?' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '?') {
+                    i += 1;
+                }
+                tokens.push(Token::Name(chars[start..i].iter().collect()));
+                continue;
+            }
+            ':' => Token::Colon,
+            ',' | ';' => Token::Comma,
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '/' => Token::Slash,
+            '(' => Token::Open,
+            ')' => Token::Close,
+            _ => return None,
+        };
+        tokens.push(token);
+        i += 1;
+    }
+    Some(tokens)
+}
+
+/// `=`로 시작하는 계산식 → 값. 계산할 수 없으면 None
+fn evaluate_formula(expression: &str, cell: Option<&CellScope>) -> Option<f64> {
+    let expression = expression.trim().strip_prefix('=')?;
+    let mut parser = FormulaParser {
+        tokens: tokenize(expression)?,
+        pos: 0,
+        cell,
+    };
+    let value = parser.expr()?;
+    (parser.pos == parser.tokens.len() && value.is_finite()).then_some(value)
+}
+
+struct FormulaParser<'a> {
+    tokens: Vec<Token>,
+    pos: usize,
+    cell: Option<&'a CellScope<'a>>,
+}
+
+impl FormulaParser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expr(&mut self) -> Option<f64> {
+        let mut value = self.term()?;
+        loop {
+            if self.eat(&Token::Plus) {
+                value += self.term()?;
+            } else if self.eat(&Token::Minus) {
+                value -= self.term()?;
+            } else {
+                return Some(value);
+            }
+        }
+    }
+
+    fn term(&mut self) -> Option<f64> {
+        let mut value = self.factor()?;
+        loop {
+            if self.eat(&Token::Star) {
+                value *= self.factor()?;
+            } else if self.eat(&Token::Slash) {
+                let divisor = self.factor()?;
+                if divisor == 0.0 {
+                    return None;
+                }
+                value /= divisor;
+            } else {
+                return Some(value);
+            }
+        }
+    }
+
+    fn factor(&mut self) -> Option<f64> {
+        match self.tokens.get(self.pos)?.clone() {
+            Token::Minus => {
+                self.pos += 1;
+                Some(-self.factor()?)
+            }
+            Token::Plus => {
+                self.pos += 1;
+                self.factor()
+            }
+            Token::Number(value) => {
+                self.pos += 1;
+                Some(value)
+            }
+            Token::Open => {
+                self.pos += 1;
+                let value = self.expr()?;
+                self.eat(&Token::Close).then_some(value)
+            }
+            Token::Name(name) => {
+                self.pos += 1;
+                if self.eat(&Token::Open) {
+                    self.function(&name)
+                } else {
+                    let (row, col) = self.address(&name)?;
+                    Some(
+                        self.numbers_in(row, col, row, col)
+                            .first()
+                            .copied()
+                            .unwrap_or(0.0),
+                    )
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// 함수 호출 (여는 괄호 다음부터)
+    fn function(&mut self, name: &str) -> Option<f64> {
+        let mut values = Vec::new();
+        if !self.eat(&Token::Close) {
+            loop {
+                values.extend(self.argument()?);
+                if self.eat(&Token::Close) {
+                    break;
+                }
+                if !self.eat(&Token::Comma) {
+                    return None;
+                }
+            }
+        }
+        let first = values.first().copied();
+        match name.to_ascii_uppercase().as_str() {
+            "SUM" => Some(values.iter().sum()),
+            "AVG" | "AVERAGE" => {
+                (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+            }
+            "PRODUCT" => Some(values.iter().product()),
+            "MIN" => values.into_iter().reduce(f64::min),
+            "MAX" => values.into_iter().reduce(f64::max),
+            "COUNT" => Some(values.len() as f64),
+            "ABS" => first.map(f64::abs),
+            "INT" => first.map(f64::trunc),
+            "SQRT" => first.filter(|v| *v >= 0.0).map(f64::sqrt),
+            "ROUND" => {
+                let scale = 10f64.powi(values.get(1).copied().unwrap_or(0.0) as i32);
+                first.map(|v| (v * scale).round() / scale)
+            }
+            _ => None,
+        }
+    }
+
+    /// 함수 인자 하나: 방향, 범위, 셀 하나(숫자가 아니면 빈 목록) 또는 식
+    fn argument(&mut self) -> Option<Vec<f64>> {
+        if let Some(Token::Name(name)) = self.peek().cloned() {
+            let end = self.tokens.get(self.pos + 1);
+            if let Some(values) = self.direction(&name) {
+                self.pos += 1;
+                return Some(values);
+            }
+            if end == Some(&Token::Colon) {
+                let Some(Token::Name(to)) = self.tokens.get(self.pos + 2).cloned() else {
+                    return None;
+                };
+                self.pos += 3;
+                let (r1, c1) = self.address(&name)?;
+                let (r2, c2) = self.address(&to)?;
+                return Some(self.numbers_in(r1.min(r2), c1.min(c2), r1.max(r2), c1.max(c2)));
+            }
+            if matches!(end, Some(Token::Comma) | Some(Token::Close)) {
+                self.pos += 1;
+                let (row, col) = self.address(&name)?;
+                return Some(self.numbers_in(row, col, row, col));
+            }
+        }
+        Some(vec![self.expr()?])
+    }
+
+    /// LEFT/RIGHT/ABOVE/BELOW → 같은 행/열에서 그 방향 셀의 숫자들
+    fn direction(&self, name: &str) -> Option<Vec<f64>> {
+        let upper = name.to_ascii_uppercase();
+        if !matches!(upper.as_str(), "LEFT" | "RIGHT" | "ABOVE" | "BELOW") {
+            return None;
+        }
+        let cell = self.cell?;
+        let (row, col) = (cell.row, cell.col);
+        Some(match upper.as_str() {
+            "LEFT" => cell.grid.numbers(|r, c| r == row && c < col),
+            "RIGHT" => cell.grid.numbers(|r, c| r == row && c > col),
+            "ABOVE" => cell.grid.numbers(|r, c| c == col && r < row),
+            _ => cell.grid.numbers(|r, c| c == col && r > row),
+        })
+    }
+
+    /// `B3`, `B?`, `?2` → (행, 열) 0부터
+    fn address(&self, name: &str) -> Option<(u16, u16)> {
+        let cell = self.cell?;
+        let (letters, rest) = match name.strip_prefix('?') {
+            Some(rest) => ("?", rest),
+            None => name.split_at(name.find(|c: char| !c.is_ascii_alphabetic())?),
+        };
+        let col = if letters == "?" {
+            cell.col
+        } else {
+            let number = letters.chars().try_fold(0u32, |acc, c| {
+                c.is_ascii_alphabetic()
+                    .then(|| acc * 26 + (c.to_ascii_uppercase() as u32 - 'A' as u32 + 1))
+            })?;
+            u16::try_from(number.checked_sub(1)?).ok()?
+        };
+        let row = if rest == "?" {
+            cell.row
+        } else {
+            rest.parse::<u16>().ok()?.checked_sub(1)?
+        };
+        Some((row, col))
+    }
+
+    fn numbers_in(&self, row1: u16, col1: u16, row2: u16, col2: u16) -> Vec<f64> {
+        self.cell.map_or_else(Vec::new, |cell| {
+            cell.grid
+                .numbers(|r, c| (row1..=row2).contains(&r) && (col1..=col2).contains(&c))
+        })
+    }
+}
+
+/// 결과 형식(`%g`, `%.2f`, `%d`, 끝에 `,`이면 천 단위 구분)으로 숫자를 쓴다
+fn format_number(value: f64, format: &str) -> String {
+    let spec = format.trim();
+    let grouping = spec.ends_with(',');
+    let spec = spec.trim_end_matches(',').trim_start_matches('%');
+    let (precision, conversion) = match spec.strip_prefix('.') {
+        Some(rest) => {
+            let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+            (
+                digits.parse::<usize>().ok(),
+                rest[digits.len()..].chars().next(),
+            )
+        }
+        None => (None, spec.chars().next()),
+    };
+    let mut text = match conversion {
+        Some('f') => format!("{:.*}", precision.unwrap_or(6), value),
+        Some('d') => format!("{:.0}", value),
+        _ => format_general(value),
+    };
+    if text.starts_with('-') && text[1..].chars().all(|c| c == '0' || c == '.') {
+        text.remove(0);
+    }
+    if grouping {
+        text = group_thousands(&text);
+    }
+    text
+}
+
+/// 유효 숫자 10자리에서 반올림하고 뒤쪽 0을 지운다 (지수 표기 없음)
+fn format_general(value: f64) -> String {
+    let magnitude = if value == 0.0 {
+        0
+    } else {
+        value.abs().log10().floor() as i32
+    };
+    let decimals = (9 - magnitude).clamp(0, 9) as usize;
+    let text = format!("{:.*}", decimals, value);
+    if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        text
+    }
+}
+
+fn group_thousands(text: &str) -> String {
+    let (sign, digits) = match text.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", text),
+    };
+    let (int, frac) = match digits.find('.') {
+        Some(i) => digits.split_at(i),
+        None => (digits, ""),
+    };
+    let mut grouped = String::new();
+    for (i, c) in int.chars().enumerate() {
+        if i > 0 && (int.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    format!("{}{}{}", sign, grouped, frac)
+}
@@ -0,0 +1,132 @@
+use std::collections::BTreeMap;
+
+use crate::document::Document;
+use crate::query::{ContentPath, Visitor, VisitorMut};
+use crate::shape::{FormCheckState, FormObject, FormType, ShapeObject};
+
+// ═══════════════════════════════════════════
+// 양식 개체 값 (HTML 양식 제출과 같은 이름 → 값 규칙)
+// ═══════════════════════════════════════════
+//
+// - 선택 상자: 이름 → "on" / "off"
+// - 라디오 단추: 묶음 이름(없으면 단추 이름) → 선택된 단추 이름 (없으면 "")
+// - 입력 상자 / 목록 상자 / 스크롤 막대: 이름 → 값
+// - 명령 단추: 값 없음
+
+impl FormObject {
+    /// 값을 읽고 쓸 때의 키 (라디오 단추는 묶음 이름)
+    pub fn value_key(&self) -> &str {
+        match self.form_type {
+            FormType::RadioButton => self.group_name.as_deref().unwrap_or(&self.name),
+            _ => &self.name,
+        }
+    }
+
+    /// 현재 값. 명령 단추와 선택되지 않은 라디오 단추는 `None`
+    pub fn form_value(&self) -> Option<String> {
+        match self.form_type {
+            FormType::PushButton => None,
+            FormType::CheckBox => Some(
+                if self.check_state == FormCheckState::Checked {
+                    "on"
+                } else {
+                    "off"
+                }
+                .to_string(),
+            ),
+            FormType::RadioButton => {
+                (self.check_state == FormCheckState::Checked).then(|| self.name.clone())
+            }
+            FormType::ComboBox | FormType::ListBox | FormType::Edit | FormType::ScrollBar => {
+                Some(self.value.clone().unwrap_or_default())
+            }
+        }
+    }
+
+    /// 키에 대응하는 값을 반영한다. 바뀌었으면 true
+    pub fn set_form_value(&mut self, value: &str) -> bool {
+        match self.form_type {
+            FormType::PushButton => false,
+            FormType::CheckBox | FormType::RadioButton => {
+                let checked = if self.form_type == FormType::CheckBox {
+                    !matches!(
+                        value.trim().to_ascii_lowercase().as_str(),
+                        "" | "0" | "off" | "false"
+                    )
+                } else {
+                    value == self.name
+                };
+                let state = if checked {
+                    FormCheckState::Checked
+                } else {
+                    FormCheckState::Unchecked
+                };
+                let changed = self.check_state != state;
+                self.check_state = state;
+                changed
+            }
+            FormType::ComboBox | FormType::ListBox | FormType::Edit | FormType::ScrollBar => {
+                let changed = self.value.as_deref() != Some(value);
+                self.value = Some(value.to_string());
+                changed
+            }
+        }
+    }
+}
+
+impl Document {
+    /// 문서의 모든 양식 개체 (문서 순서)
+    pub fn form_objects(&self) -> Vec<&FormObject> {
+        #[derive(Default)]
+        struct Forms<'a>(Vec<&'a FormObject>);
+        impl<'a> Visitor<'a> for Forms<'a> {
+            fn visit_shape(&mut self, _path: &ContentPath, shape: &'a ShapeObject) {
+                if let ShapeObject::Form(form) = shape {
+                    self.0.push(form);
+                }
+            }
+        }
+        let mut forms = Forms::default();
+        self.walk(&mut forms);
+        forms.0
+    }
+
+    /// 입력된 양식 값 (키 → 값)
+    pub fn form_values(&self) -> BTreeMap<String, String> {
+        let mut values = BTreeMap::new();
+        for form in self.form_objects() {
+            if form.form_type == FormType::PushButton {
+                continue;
+            }
+            let entry = values.entry(form.value_key().to_string()).or_default();
+            if let Some(value) = form.form_value() {
+                *entry = value;
+            }
+        }
+        values
+    }
+
+    /// 양식 값을 채운다. 값이 바뀐 양식 개체 수를 돌려준다.
+    /// `values`에 키가 없는 양식 개체는 그대로 둔다.
+    pub fn apply_form_values(&mut self, values: &BTreeMap<String, String>) -> usize {
+        struct Apply<'v> {
+            values: &'v BTreeMap<String, String>,
+            changed: usize,
+        }
+        impl VisitorMut for Apply<'_> {
+            fn visit_shape_mut(&mut self, _path: &ContentPath, shape: &mut ShapeObject) {
+                let ShapeObject::Form(form) = shape else {
+                    return;
+                };
+                if let Some(value) = self.values.get(form.value_key()) {
+                    if form.set_form_value(value) {
+                        self.changed += 1;
+                    }
+                }
+            }
+        }
+        let mut apply = Apply { values, changed: 0 };
+        self.walk_mut(&mut apply);
+        apply.changed
+    }
+}
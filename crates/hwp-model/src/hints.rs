@@ -14,6 +14,36 @@ pub struct HwpDocumentHints {
     pub caret_list_id: Option<u32>,
     pub caret_para_id: Option<u32>,
     pub caret_char_pos: Option<u32>,
+    /// 모델에 없는 DocInfo 레코드 (변경 추적, 문서 데이터, 알 수 없는 태그 등)
+    #[serde(skip)]
+    pub extra_doc_info: Vec<HwpRawRecord>,
+    /// writer가 만들지 않는 스트림 (경로, 저장된 바이트)
+    #[serde(skip)]
+    pub extra_streams: Vec<(String, Vec<u8>)>,
+    /// BinData 항목별 압축 방식 (BinaryItem.id, BIN_DATA 속성). 없는 항목은 기본값
+    #[serde(default)]
+    pub bin_compression: Vec<(String, HwpBinCompression)>,
+}
+
+/// BIN_DATA 속성의 압축 방식 (bit 4-5)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HwpBinCompression {
+    /// 스토리지 기본값 (FileHeader 압축 여부)을 따른다
+    #[default]
+    StorageDefault,
+    /// 무조건 압축
+    Compress,
+    /// 무조건 압축하지 않음
+    NoCompress,
+}
+
+/// 모델에 옮기지 않은 HWP 5.0 레코드 (writer가 그대로 다시 기록)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HwpRawRecord {
+    pub tag_id: u16,
+    /// 레코드 레벨 (문단 레코드는 문단 머리 기준 상대 레벨)
+    pub level: u16,
+    pub data: Vec<u8>,
 }
 
 /// HWP 5.0 roundtrip용 섹션 힌트
@@ -34,6 +64,9 @@ pub struct HwpParagraphHints {
     #[serde(skip)]
     pub range_tags_raw: Vec<u8>,
     pub tail_shape: Option<u8>,
+    /// 모델에 없는 문단 하위 레코드 (알 수 없는 태그)
+    #[serde(skip)]
+    pub extra_records: Vec<HwpRawRecord>,
 }
 
 /// ParaLineSeg 보존 (HWP 5.0 레이아웃 캐시)
@@ -92,4 +125,7 @@ pub struct ManifestEntry {
     pub id: String,
     pub href: String,
     pub media_type: String,
+    /// 파트 원본 내용. writer가 다시 만들지 않는 파트는 이 내용을 그대로 쓴다
+    #[serde(skip)]
+    pub data: Vec<u8>,
 }
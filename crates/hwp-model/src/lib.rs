@@ -1,8 +1,16 @@
+pub mod chart;
 pub mod control;
 pub mod document;
+pub mod field;
+pub mod form;
 pub mod hints;
+pub mod mail_merge;
+pub mod memo;
+pub mod outline;
 pub mod paragraph;
+pub mod query;
 pub mod resources;
+pub mod revision;
 pub mod section;
 pub mod shape;
 pub mod table;
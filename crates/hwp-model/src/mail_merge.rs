@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::control::{Control, Field};
+use crate::document::Document;
+use crate::field::{field_spans, rewrite_fields, span_text};
+use crate::paragraph::{Paragraph, RunContent};
+use crate::query::{ParagraphPath, Visitor, VisitorMut};
+use crate::types::FieldType;
+
+// ═══════════════════════════════════════════
+// 누름틀 / 메일 머지 필드 채우기
+// ═══════════════════════════════════════════
+//
+// - 필드 이름: HWPX는 `name` 속성(명령은 `Command` 파라미터), HWP는 명령 문자열 안의
+//   `Name:wstring:길이:이름` 항목 (누름틀 명령 예: `Clickhere:set:..:Direction:wstring:5:이름 입력 Name:wstring:2:성명 `)
+// - 채우기: FieldBegin ~ FieldEnd 사이를 값 텍스트 하나로 바꾼다. 글자 모양은 구간의 첫 텍스트
+//   (비어 있으면 FieldBegin이 있는 run)을 따르고, 값의 '\n'은 줄바꿈으로 넣는다
+// - 같은 이름의 필드가 여러 개면 모두 같은 값으로 채운다
+
+/// 필드 파라미터 이름
+const PARAM_COMMAND: &str = "Command";
+const PARAM_FIELD_NAME: &str = "FieldName";
+const PARAM_DIRECTION: &str = "Direction";
+
+/// 명령 문자열 안의 이름 항목 (HWP)
+const COMMAND_NAME_KEYS: [&str; 2] = ["Name", "FieldName"];
+
+/// 채울 수 있는 필드 한 개 (누름틀, 메일 머지)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldEntry {
+    /// 필드 이름 (`fill_fields`의 키)
+    pub name: String,
+    pub field_type: FieldType,
+    /// 지금 들어 있는 텍스트 (문단 경계 없음)
+    pub text: String,
+    /// 누름틀 안내문 (비어 있을 때 회색으로 보이는 글)
+    pub direction: Option<String>,
+}
+
+/// 메일 머지 레코드 한 개 (필드 이름 → 값)
+pub type FieldRecord = HashMap<String, String>;
+
+impl Field {
+    /// 누름틀/메일 머지 필드 이름
+    pub fn field_name(&self) -> Option<String> {
+        let non_empty = |s: Option<String>| s.filter(|s| !s.trim().is_empty());
+        if self.parameter_str(PARAM_COMMAND).is_some() {
+            if let Some(name) = non_empty(self.name.clone()) {
+                return Some(name);
+            }
+        }
+        if let Some(name) = non_empty(self.parameter_str(PARAM_FIELD_NAME)) {
+            return Some(name);
+        }
+        let command = self.command()?;
+        COMMAND_NAME_KEYS
+            .iter()
+            .find_map(|key| non_empty(command_value(&command, key)))
+            .or_else(|| non_empty((!is_set_command(&command)).then_some(command)))
+    }
+
+    /// 누름틀 안내문
+    pub fn direction(&self) -> Option<String> {
+        self.parameter_str(PARAM_DIRECTION)
+            .or_else(|| command_value(&self.command()?, PARAM_DIRECTION))
+            .filter(|s| !s.is_empty())
+    }
+}
+
+/// 값을 채울 수 있는 필드 종류
+fn is_fillable(field_type: &FieldType) -> bool {
+    matches!(field_type, FieldType::ClickHere | FieldType::MailMerge)
+}
+
+/// `Clickhere:set:66:...`처럼 항목을 나열한 명령인지
+fn is_set_command(command: &str) -> bool {
+    command
+        .split(':')
+        .nth(1)
+        .is_some_and(|part| part.eq_ignore_ascii_case("set"))
+}
+
+/// 명령 문자열의 `키:wstring:길이:값` 항목 값 (길이는 글자 수)
+fn command_value(command: &str, key: &str) -> Option<String> {
+    let pattern = format!("{}:wstring:", key);
+    let mut rest = command;
+    loop {
+        let at = rest.find(&pattern)?;
+        // 다른 키의 끝부분(`FieldName`의 `Name`)과 맞은 것은 건너뛴다
+        let boundary = rest[..at]
+            .chars()
+            .next_back()
+            .map_or(true, |c| c == ' ' || c == ':');
+        let after = &rest[at + pattern.len()..];
+        if boundary {
+            let (len, value) = after.split_once(':')?;
+            let len: usize = len.trim().parse().ok()?;
+            return Some(value.chars().take(len).collect());
+        }
+        rest = after;
+    }
+}
+
+impl Document {
+    /// 채울 수 있는 필드(누름틀, 메일 머지) 목록 (문서 순서, 표 셀 등 하위 목록 포함)
+    pub fn fields(&self) -> Vec<FieldEntry> {
+        #[derive(Default)]
+        struct Fields(Vec<FieldEntry>);
+        impl<'a> Visitor<'a> for Fields {
+            fn visit_paragraph(&mut self, _path: &ParagraphPath, para: &'a Paragraph) {
+                let entries = &mut self.0;
+                for (begin, end) in field_spans(para) {
+                    let RunContent::Control(Control::FieldBegin(field)) =
+                        &para.runs[begin.0].contents[begin.1]
+                    else {
+                        continue;
+                    };
+                    if !is_fillable(&field.field_type) {
+                        continue;
+                    }
+                    if let Some(name) = field.field_name() {
+                        entries.push(FieldEntry {
+                            name,
+                            field_type: field.field_type.clone(),
+                            text: span_text(para, begin, end),
+                            direction: field.direction(),
+                        });
+                    }
+                }
+            }
+        }
+        let mut fields = Fields::default();
+        self.walk(&mut fields);
+        fields.0
+    }
+
+    /// 이름이 `values`에 있는 누름틀/메일 머지 필드를 값으로 채운다.
+    /// 텍스트가 바뀐 필드 수를 돌려준다. `values`에 없는 필드는 그대로 둔다.
+    pub fn fill_fields(&mut self, values: &HashMap<String, String>) -> usize {
+        struct Fill<'v> {
+            values: &'v HashMap<String, String>,
+            changed: usize,
+        }
+        impl VisitorMut for Fill<'_> {
+            fn visit_paragraph_mut(&mut self, _path: &ParagraphPath, para: &mut Paragraph) {
+                let values = self.values;
+                self.changed += rewrite_fields(para, |field, _| {
+                    if !is_fillable(&field.field_type) {
+                        return None;
+                    }
+                    values.get(&field.field_name()?).cloned()
+                });
+            }
+        }
+        let mut fill = Fill { values, changed: 0 };
+        self.walk_mut(&mut fill);
+        fill.changed
+    }
+
+    /// 레코드마다 이 문서를 복사해 채운 문서 목록 (메일 머지)
+    pub fn fill_fields_batch(&self, records: &[FieldRecord]) -> Vec<Document> {
+        records
+            .iter()
+            .map(|record| {
+                let mut doc = self.clone();
+                doc.fill_fields(record);
+                doc
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,161 @@
+use serde::{Deserialize, Serialize};
+
+use crate::control::{Control, Field, FieldParameter};
+use crate::document::Document;
+use crate::paragraph::{Paragraph, RunContent, SubList};
+use crate::query::{ParagraphPath, Visitor};
+use crate::types::FieldType;
+
+// ═══════════════════════════════════════════
+// 메모 (MEMO 필드가 감싸는 본문 구간에 단 설명)
+// ═══════════════════════════════════════════
+
+/// 메모 필드 파라미터 이름 (HWPX `hp:fieldBegin type="MEMO"`의 `hp:parameters`)
+const PARAM_ID: &str = "ID";
+const PARAM_NUMBER: &str = "Number";
+const PARAM_DATE: &str = "CreateDateTime";
+const PARAM_AUTHOR: &str = "Author";
+const PARAM_MEMO_SHAPE: &str = "MemoShapeID";
+
+/// 메모 한 개.
+/// 본문에서는 MEMO 필드(`FieldBegin` ~ `FieldEnd`)로 구간을 잡고, 메모 정보는 필드 파라미터에,
+/// 메모 내용은 필드의 `sub_list`에 담는다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Memo {
+    /// 메모 ID (`ID`)
+    pub id: Option<String>,
+    /// 메모 번호 (`Number`, 1부터)
+    pub number: Option<u32>,
+    pub author: Option<String>,
+    /// 작성 일시 (원본 문자열)
+    pub date: Option<String>,
+    /// 메모 모양 (`Resources::memo_shapes`의 id)
+    pub memo_shape_id: Option<u16>,
+    /// 메모가 달린 본문 텍스트 (문단 경계는 '\n')
+    pub anchor_text: String,
+    /// 메모 내용
+    pub content: Vec<Paragraph>,
+}
+
+impl Memo {
+    /// 메모를 담는 MEMO 필드 (본문에는 이 필드와 `Control::FieldEnd` 사이에 구간 텍스트를 둔다)
+    pub fn to_field(&self, id: u64) -> Field {
+        let mut parameters = Vec::new();
+        let mut string = |name: &str, value: &Option<String>| {
+            if let Some(value) = value {
+                parameters.push(FieldParameter::String {
+                    name: name.to_string(),
+                    value: value.clone(),
+                });
+            }
+        };
+        string(PARAM_ID, &self.id);
+        string(PARAM_DATE, &self.date);
+        string(PARAM_AUTHOR, &self.author);
+        string(PARAM_MEMO_SHAPE, &self.memo_shape_id.map(|v| v.to_string()));
+        if let Some(number) = self.number {
+            parameters.push(FieldParameter::Integer {
+                name: PARAM_NUMBER.to_string(),
+                value: number as i64,
+            });
+        }
+        Field {
+            id,
+            field_type: FieldType::Memo,
+            editable: true,
+            parameters,
+            sub_list: Some(SubList {
+                paragraphs: self.content.clone(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+impl Field {
+    /// 최상위 파라미터 값을 문자열로 (정수 파라미터는 10진수)
+    pub fn parameter_str(&self, name: &str) -> Option<String> {
+        self.parameters.iter().find_map(|param| match param {
+            FieldParameter::String { name: n, value } if n == name => Some(value.clone()),
+            FieldParameter::Integer { name: n, value } if n == name => Some(value.to_string()),
+            _ => None,
+        })
+    }
+
+    /// MEMO 필드이면 메모 정보를 돌려준다 (`anchor_text`는 비어 있음)
+    pub fn memo(&self) -> Option<Memo> {
+        if self.field_type != FieldType::Memo {
+            return None;
+        }
+        let non_empty = |name: &str| self.parameter_str(name).filter(|v| !v.trim().is_empty());
+        Some(Memo {
+            id: non_empty(PARAM_ID),
+            number: non_empty(PARAM_NUMBER).and_then(|v| v.trim().parse().ok()),
+            author: non_empty(PARAM_AUTHOR),
+            date: non_empty(PARAM_DATE),
+            memo_shape_id: non_empty(PARAM_MEMO_SHAPE).and_then(|v| v.trim().parse().ok()),
+            anchor_text: String::new(),
+            content: self
+                .sub_list
+                .as_ref()
+                .map(|sl| sl.paragraphs.clone())
+                .unwrap_or_default(),
+        })
+    }
+}
+
+impl Document {
+    /// 문서의 모든 메모를 구간 텍스트와 함께 모은다.
+    /// 번호가 없는 메모는 모은 순서(1부터)로 번호를 매긴다.
+    pub fn memos(&self) -> Vec<Memo> {
+        #[derive(Default)]
+        struct Memos(Vec<Memo>);
+        impl<'a> Visitor<'a> for Memos {
+            // 필드 구간은 같은 목록 안에서 문단을 넘어 이어진다
+            fn visit_list(&mut self, _path: &ParagraphPath, paragraphs: &'a [Paragraph]) {
+                let memos = &mut self.0;
+                // 열린 필드: MEMO 필드이면 memos 인덱스
+                let mut open: Vec<Option<usize>> = Vec::new();
+                for (i, para) in paragraphs.iter().enumerate() {
+                    if i > 0 {
+                        for index in open.iter().flatten() {
+                            memos[*index].anchor_text.push('\n');
+                        }
+                    }
+                    for content in para.runs.iter().flat_map(|run| &run.contents) {
+                        match content {
+                            RunContent::Text(tc) => {
+                                let text: String =
+                                    tc.elements.iter().filter_map(|e| e.plain_text()).collect();
+                                for index in open.iter().flatten() {
+                                    memos[*index].anchor_text.push_str(&text);
+                                }
+                            }
+                            RunContent::Control(Control::FieldBegin(field)) => match field.memo() {
+                                Some(memo) => {
+                                    open.push(Some(memos.len()));
+                                    memos.push(memo);
+                                }
+                                None => open.push(None),
+                            },
+                            RunContent::Control(Control::FieldEnd) => {
+                                open.pop();
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+        let mut collected = Memos::default();
+        self.walk(&mut collected);
+        let mut memos = collected.0;
+        for (i, memo) in memos.iter_mut().enumerate() {
+            if memo.number.is_none() {
+                memo.number = Some(i as u32 + 1);
+            }
+        }
+        memos
+    }
+}
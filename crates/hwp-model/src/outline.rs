@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+
+use crate::document::Document;
+use crate::field::paragraph_text;
+use crate::paragraph::{Paragraph, RunContent};
+use crate::resources::Resources;
+use crate::types::HeadingType;
+
+// ═══════════════════════════════════════════
+// 개요 (차례)
+// ═══════════════════════════════════════════
+//
+// - 제목 문단: 문단 모양의 머리 종류가 개요인 문단 (수준 = heading.level + 1),
+//   또는 스타일 이름이 `개요 N` / `Outline N`인 문단 (번호 없음)
+// - 본문 문단만 본다 (표 셀, 글상자 안 문단은 차례에 넣지 않는다)
+// - 번호: 뷰어의 개요 번호와 같은 방식으로 센다 (문서 전체에서 이어지고,
+//   상위 수준이 나오면 하위 수준은 처음부터). 1~7 수준만 번호가 있다
+
+/// 번호를 매기는 개요 수준 수
+const NUMBERED_LEVELS: usize = 7;
+
+/// 개요 스타일 이름 접두사
+const OUTLINE_STYLE_PREFIXES: [&str; 2] = ["개요", "Outline"];
+
+/// 차례 항목 한 개 (제목 문단)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutlineEntry {
+    /// 개요 수준 (1부터)
+    pub level: u8,
+    /// 수준 안 번호 (1부터). 번호 없는 개요 문단이나 8 수준 이상이면 None
+    pub number: Option<u32>,
+    /// 제목 텍스트 (앞뒤 공백 제거, 탭/줄바꿈은 공백 하나로)
+    pub title: String,
+    /// 구역 인덱스
+    pub section: usize,
+    /// 구역 안 문단 인덱스
+    pub paragraph: usize,
+    /// 하위 제목
+    pub children: Vec<OutlineEntry>,
+}
+
+impl OutlineEntry {
+    /// 이 항목과 모든 하위 항목을 문서 순서로
+    pub fn iter(&self) -> impl Iterator<Item = &OutlineEntry> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let entry = stack.pop()?;
+            stack.extend(entry.children.iter().rev());
+            Some(entry)
+        })
+    }
+}
+
+impl Document {
+    /// 개요 수준 문단으로 만든 제목 트리 (차례)
+    pub fn outline(&self) -> Vec<OutlineEntry> {
+        let mut counters = [0u32; NUMBERED_LEVELS];
+        let mut flat = Vec::new();
+        for (section_index, section) in self.sections.iter().enumerate() {
+            for (para_index, para) in section.paragraphs.iter().enumerate() {
+                let Some((level, numbered)) = heading_level(para, &self.resources) else {
+                    continue;
+                };
+                // 뷰어는 내용이 있는 문단에서만 번호를 센다
+                if !has_content(para) {
+                    continue;
+                }
+                let number = if numbered {
+                    next_number(&mut counters, level)
+                } else {
+                    None
+                };
+                let title = normalize_title(&paragraph_text(para));
+                if title.is_empty() {
+                    continue;
+                }
+                flat.push(OutlineEntry {
+                    level,
+                    number,
+                    title,
+                    section: section_index,
+                    paragraph: para_index,
+                    children: Vec::new(),
+                });
+            }
+        }
+        nest(flat)
+    }
+}
+
+/// 제목 문단이면 (수준, 개요 번호 여부)
+fn heading_level(para: &Paragraph, resources: &Resources) -> Option<(u8, bool)> {
+    if let Some(heading) = resources
+        .para_shapes
+        .get(para.para_shape_id as usize)
+        .and_then(|ps| ps.heading.as_ref())
+    {
+        if heading.heading_type == HeadingType::Outline {
+            return Some((heading.level + 1, true));
+        }
+    }
+    let style = resources.styles.get(para.style_id as usize)?;
+    [&style.name, &style.eng_name]
+        .into_iter()
+        .find_map(|name| outline_style_level(name))
+        .map(|level| (level, false))
+}
+
+/// `개요 3`, `Outline 3` → 3
+fn outline_style_level(name: &str) -> Option<u8> {
+    OUTLINE_STYLE_PREFIXES.iter().find_map(|prefix| {
+        let level: u8 = name.strip_prefix(prefix)?.trim().parse().ok()?;
+        (level >= 1).then_some(level)
+    })
+}
+
+/// 수준의 다음 번호. 하위 수준 번호는 처음부터 다시 센다
+fn next_number(counters: &mut [u32; NUMBERED_LEVELS], level: u8) -> Option<u32> {
+    let index = (level as usize).checked_sub(1)?;
+    if index >= NUMBERED_LEVELS {
+        return None;
+    }
+    counters[index] += 1;
+    counters[index + 1..].iter_mut().for_each(|c| *c = 0);
+    Some(counters[index])
+}
+
+fn has_content(para: &Paragraph) -> bool {
+    para.runs
+        .iter()
+        .flat_map(|run| &run.contents)
+        .any(|content| match content {
+            RunContent::Text(text) => text
+                .elements
+                .iter()
+                .any(|e| e.plain_text().is_some_and(|s| !s.is_empty())),
+            RunContent::Object(_) => true,
+            RunContent::Control(_) => false,
+        })
+}
+
+fn normalize_title(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// 평평한 제목 목록을 수준에 따라 트리로 묶는다
+fn nest(flat: Vec<OutlineEntry>) -> Vec<OutlineEntry> {
+    let mut roots: Vec<OutlineEntry> = Vec::new();
+    // 아직 닫히지 않은 조상 (루트부터)
+    let mut open: Vec<OutlineEntry> = Vec::new();
+    for entry in flat {
+        while open.last().is_some_and(|last| last.level >= entry.level) {
+            close_last(&mut open, &mut roots);
+        }
+        open.push(entry);
+    }
+    while !open.is_empty() {
+        close_last(&mut open, &mut roots);
+    }
+    roots
+}
+
+fn close_last(open: &mut Vec<OutlineEntry>, roots: &mut Vec<OutlineEntry>) {
+    if let Some(done) = open.pop() {
+        match open.last_mut() {
+            Some(parent) => parent.children.push(done),
+            None => roots.push(done),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::control::Control;
-use crate::hints::LineSegmentInfo;
+use crate::hints::{HwpParagraphHints, LineSegmentInfo};
 use crate::shape::ShapeObject;
 use crate::types::*;
 
@@ -22,6 +22,9 @@ pub struct Paragraph {
     /// false이면 기존 viewer와 동일하게 bold/italic 스킵 + trailing space 보존
     #[serde(default)]
     pub has_char_shapes: bool,
+    /// HWP 5.0 roundtrip용 힌트 (HWP에서 읽은 문단만 존재)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hwp_hints: Option<HwpParagraphHints>,
 }
 
 /// 동일 글자 모양의 콘텐츠 단위
@@ -114,3 +117,150 @@ pub struct SubList {
     pub has_num_ref: bool,
     pub paragraphs: Vec<Paragraph>,
 }
+
+// ── 평문 ──
+
+impl TextElement {
+    /// 평문으로 옮긴 글자 (탭/줄바꿈/특수 공백 포함). 형광펜·변경 추적 등 표시 요소는 None
+    pub fn plain_text(&self) -> Option<&str> {
+        match self {
+            TextElement::Text(s) => Some(s),
+            TextElement::Tab { .. } => Some("\t"),
+            TextElement::LineBreak => Some("\n"),
+            TextElement::NbSpace | TextElement::FwSpace => Some(" "),
+            TextElement::Hyphen => Some("-"),
+            _ => None,
+        }
+    }
+}
+
+// ── 하위 문단 목록 ──
+
+impl RunContent {
+    /// 이 콘텐츠가 품고 있는 하위 문단 목록
+    /// (머리말/꼬리말, 각주/미주, 필드, 숨은 설명, 표 셀, 글상자, 캡션, 묶음 개체의 자식)
+    pub fn paragraph_lists(&self) -> Vec<&Vec<Paragraph>> {
+        let mut out = Vec::new();
+        match self {
+            RunContent::Text(_) => {}
+            RunContent::Control(control) => match control {
+                Control::Header(hf) | Control::Footer(hf) => out.push(&hf.content.paragraphs),
+                Control::FootNote(note) | Control::EndNote(note) => {
+                    out.push(&note.content.paragraphs)
+                }
+                Control::FieldBegin(field) => {
+                    if let Some(sub_list) = &field.sub_list {
+                        out.push(&sub_list.paragraphs);
+                    }
+                }
+                Control::HiddenDesc(desc) => out.push(&desc.paragraphs),
+                _ => {}
+            },
+            RunContent::Object(shape) => shape_paragraph_lists(shape, &mut out),
+        }
+        out
+    }
+
+    /// [`RunContent::paragraph_lists`]의 가변 버전
+    pub fn paragraph_lists_mut(&mut self) -> Vec<&mut Vec<Paragraph>> {
+        let mut out = Vec::new();
+        match self {
+            RunContent::Text(_) => {}
+            RunContent::Control(control) => match control {
+                Control::Header(hf) | Control::Footer(hf) => out.push(&mut hf.content.paragraphs),
+                Control::FootNote(note) | Control::EndNote(note) => {
+                    out.push(&mut note.content.paragraphs)
+                }
+                Control::FieldBegin(field) => {
+                    if let Some(sub_list) = &mut field.sub_list {
+                        out.push(&mut sub_list.paragraphs);
+                    }
+                }
+                Control::HiddenDesc(desc) => out.push(&mut desc.paragraphs),
+                _ => {}
+            },
+            RunContent::Object(shape) => shape_paragraph_lists_mut(shape, &mut out),
+        }
+        out
+    }
+}
+
+fn shape_paragraph_lists<'a>(shape: &'a ShapeObject, out: &mut Vec<&'a Vec<Paragraph>>) {
+    let (common, draw_text) = match shape {
+        ShapeObject::Table(t) => {
+            for cell in t.rows.iter().flat_map(|row| &row.cells) {
+                out.push(&cell.content.paragraphs);
+            }
+            (&t.common, None)
+        }
+        ShapeObject::Picture(p) => (&p.common, None),
+        ShapeObject::Line(l) => (&l.common, l.draw_text.as_ref()),
+        ShapeObject::Rectangle(r) => (&r.common, r.draw_text.as_ref()),
+        ShapeObject::Ellipse(e) => (&e.common, e.draw_text.as_ref()),
+        ShapeObject::Arc(a) => (&a.common, a.draw_text.as_ref()),
+        ShapeObject::Polygon(p) => (&p.common, p.draw_text.as_ref()),
+        ShapeObject::Curve(c) => (&c.common, c.draw_text.as_ref()),
+        ShapeObject::ConnectLine(c) => (&c.common, None),
+        ShapeObject::TextArt(t) => (&t.common, None),
+        ShapeObject::Container(c) => {
+            for child in &c.children {
+                shape_paragraph_lists(child, out);
+            }
+            (&c.common, None)
+        }
+        ShapeObject::Ole(o) => (&o.common, None),
+        ShapeObject::Equation(e) => (&e.common, None),
+        ShapeObject::Chart(c) => (&c.common, None),
+        ShapeObject::Video(v) => (&v.common, None),
+        ShapeObject::Form(f) => (&f.common, None),
+    };
+    if let Some(sub_list) = draw_text {
+        out.push(&sub_list.paragraphs);
+    }
+    if let Some(caption) = &common.caption {
+        out.push(&caption.content.paragraphs);
+    }
+}
+
+/// 개체의 하위 문단 목록 (표 셀, 글상자, 묶음 개체의 하위 개체, 캡션)
+pub(crate) fn shape_paragraph_lists_mut<'a>(
+    shape: &'a mut ShapeObject,
+    out: &mut Vec<&'a mut Vec<Paragraph>>,
+) {
+    let (common, draw_text) = match shape {
+        ShapeObject::Table(t) => {
+            let t = &mut **t;
+            for cell in t.rows.iter_mut().flat_map(|row| &mut row.cells) {
+                out.push(&mut cell.content.paragraphs);
+            }
+            (&mut t.common, None)
+        }
+        ShapeObject::Picture(p) => (&mut p.common, None),
+        ShapeObject::Line(l) => (&mut l.common, l.draw_text.as_mut()),
+        ShapeObject::Rectangle(r) => (&mut r.common, r.draw_text.as_mut()),
+        ShapeObject::Ellipse(e) => (&mut e.common, e.draw_text.as_mut()),
+        ShapeObject::Arc(a) => (&mut a.common, a.draw_text.as_mut()),
+        ShapeObject::Polygon(p) => (&mut p.common, p.draw_text.as_mut()),
+        ShapeObject::Curve(c) => (&mut c.common, c.draw_text.as_mut()),
+        ShapeObject::ConnectLine(c) => (&mut c.common, None),
+        ShapeObject::TextArt(t) => (&mut t.common, None),
+        ShapeObject::Container(c) => {
+            let c = &mut **c;
+            for child in &mut c.children {
+                shape_paragraph_lists_mut(child, out);
+            }
+            (&mut c.common, None)
+        }
+        ShapeObject::Ole(o) => (&mut o.common, None),
+        ShapeObject::Equation(e) => (&mut e.common, None),
+        ShapeObject::Chart(c) => (&mut c.common, None),
+        ShapeObject::Video(v) => (&mut v.common, None),
+        ShapeObject::Form(f) => (&mut f.common, None),
+    };
+    if let Some(sub_list) = draw_text {
+        out.push(&mut sub_list.paragraphs);
+    }
+    if let Some(caption) = &mut common.caption {
+        out.push(&mut caption.content.paragraphs);
+    }
+}
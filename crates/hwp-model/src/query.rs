@@ -0,0 +1,467 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::control::Control;
+use crate::document::Document;
+use crate::paragraph::{Paragraph, Run, RunContent};
+use crate::shape::{Picture, ShapeObject};
+use crate::table::Table;
+
+// ═══════════════════════════════════════════
+// 문서 탐색 (방문자, 경로 주소)
+// ═══════════════════════════════════════════
+//
+// - 순서: 구역마다 본문 → 바탕쪽. 문단을 먼저 방문하고, 이어서 그 문단의 콘텐츠와
+//   콘텐츠가 품은 하위 목록(표 셀, 글상자, 캡션, 머리말/꼬리말, 각주/미주 등)을 깊이 우선으로
+// - 하위 목록 번호는 [`RunContent::paragraph_lists`] 순서 (표는 행 순서대로 센 셀 번호,
+//   이어서 캡션). 묶음 개체 안 개체의 목록도 같은 번호 체계에 이어서 센다
+// - 경로 문자열: `구역[m바탕쪽]/(문단.런.콘텐츠.목록)*/문단` — 예: `0/3.1.0.2/0`
+//   (0 구역 3번째 문단, 1번째 런의 0번째 콘텐츠(표)의 2번 셀, 그 안의 0번째 문단)
+//   콘텐츠 경로는 뒤에 `:런.콘텐츠`, 묶음 개체 안 개체는 `>자식` — 예: `0/3:0.1>2`
+
+/// 하위 목록 한 단계 (어느 문단의 어느 콘텐츠가 품은 몇 번째 문단 목록인지)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ListStep {
+    pub paragraph: usize,
+    pub run: usize,
+    pub content: usize,
+    /// [`RunContent::paragraph_lists`] 안의 번호 (표는 셀 번호)
+    pub list: usize,
+}
+
+/// 문단 주소
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ParagraphPath {
+    pub section: usize,
+    /// 바탕쪽 번호 (None이면 본문)
+    pub master_page: Option<usize>,
+    /// 본문(또는 바탕쪽)에서 이 문단이 든 목록까지 내려가는 단계
+    pub steps: Vec<ListStep>,
+    /// 목록 안 문단 번호
+    pub paragraph: usize,
+}
+
+/// 콘텐츠(글자 묶음, 컨트롤, 개체) 주소
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ContentPath {
+    pub paragraph: ParagraphPath,
+    pub run: usize,
+    pub content: usize,
+    /// 묶음 개체 안 개체 번호 (바깥부터). 비어 있으면 콘텐츠 자체
+    pub children: Vec<usize>,
+}
+
+/// 경로 문자열 읽기 실패
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathParseError(pub String);
+
+/// 문서 방문자. 필요한 메서드만 구현한다
+pub trait Visitor<'a> {
+    /// 문단 목록 (목록 안 문단보다 먼저, `path.paragraph`는 0)
+    fn visit_list(&mut self, _path: &ParagraphPath, _paragraphs: &'a [Paragraph]) {}
+    /// 문단 (콘텐츠보다 먼저)
+    fn visit_paragraph(&mut self, _path: &ParagraphPath, _paragraph: &'a Paragraph) {}
+    /// 런 안의 콘텐츠
+    fn visit_content(&mut self, _path: &ContentPath, _content: &'a RunContent) {}
+    /// 컨트롤 (머리말, 각주, 필드 등)
+    fn visit_control(&mut self, _path: &ContentPath, _control: &'a Control) {}
+    /// 개체 (묶음 개체 안 개체 포함)
+    fn visit_shape(&mut self, _path: &ContentPath, _shape: &'a ShapeObject) {}
+    /// 표 (`visit_shape` 다음)
+    fn visit_table(&mut self, _path: &ContentPath, _table: &'a Table) {}
+    /// 그림 (`visit_shape` 다음)
+    fn visit_picture(&mut self, _path: &ContentPath, _picture: &'a Picture) {}
+}
+
+/// 가변 문서 방문자. [`Visitor`]와 같은 순서로 넘기고, 넘긴 뒤의 내용을 따라 내려간다
+/// (목록에서 문단을 합치거나 지우면 남은 문단만 방문한다)
+pub trait VisitorMut {
+    /// 문단 목록 (문단 병합/삭제 가능, `path.paragraph`는 0)
+    fn visit_list_mut(&mut self, _path: &ParagraphPath, _paragraphs: &mut Vec<Paragraph>) {}
+    /// 문단 (콘텐츠보다 먼저)
+    fn visit_paragraph_mut(&mut self, _path: &ParagraphPath, _paragraph: &mut Paragraph) {}
+    /// 런 안의 콘텐츠
+    fn visit_content_mut(&mut self, _path: &ContentPath, _content: &mut RunContent) {}
+    /// 개체 (묶음 개체 안 개체 포함)
+    fn visit_shape_mut(&mut self, _path: &ContentPath, _shape: &mut ShapeObject) {}
+}
+
+impl Document {
+    /// 모든 문단과 콘텐츠를 문서 순서로 방문한다
+    pub fn walk<'a>(&'a self, visitor: &mut impl Visitor<'a>) {
+        for (section_index, section) in self.sections.iter().enumerate() {
+            let mut path = ParagraphPath {
+                section: section_index,
+                ..Default::default()
+            };
+            walk_list(&section.paragraphs, &mut path, visitor);
+            for (master_index, master) in section.definition.master_pages.iter().enumerate() {
+                path.master_page = Some(master_index);
+                walk_list(&master.content.paragraphs, &mut path, visitor);
+            }
+        }
+    }
+
+    /// [`Document::walk`]의 가변 버전
+    pub fn walk_mut(&mut self, visitor: &mut impl VisitorMut) {
+        for (section_index, section) in self.sections.iter_mut().enumerate() {
+            let mut path = ParagraphPath {
+                section: section_index,
+                ..Default::default()
+            };
+            walk_list_mut(&mut section.paragraphs, &mut path, visitor);
+            for (master_index, master) in section.definition.master_pages.iter_mut().enumerate() {
+                path.master_page = Some(master_index);
+                walk_list_mut(&mut master.content.paragraphs, &mut path, visitor);
+            }
+        }
+    }
+
+    /// 모든 문단 (표 셀, 글상자, 머리말/꼬리말, 각주/미주, 바탕쪽 포함)
+    pub fn paragraphs(&self) -> impl Iterator<Item = (ParagraphPath, &Paragraph)> {
+        #[derive(Default)]
+        struct Paragraphs<'a>(Vec<(ParagraphPath, &'a Paragraph)>);
+        impl<'a> Visitor<'a> for Paragraphs<'a> {
+            fn visit_paragraph(&mut self, path: &ParagraphPath, paragraph: &'a Paragraph) {
+                self.0.push((path.clone(), paragraph));
+            }
+        }
+        let mut found = Paragraphs::default();
+        self.walk(&mut found);
+        found.0.into_iter()
+    }
+
+    /// 모든 표 (셀 안 표 포함)
+    pub fn find_tables(&self) -> Vec<(ContentPath, &Table)> {
+        #[derive(Default)]
+        struct Tables<'a>(Vec<(ContentPath, &'a Table)>);
+        impl<'a> Visitor<'a> for Tables<'a> {
+            fn visit_table(&mut self, path: &ContentPath, table: &'a Table) {
+                self.0.push((path.clone(), table));
+            }
+        }
+        let mut found = Tables::default();
+        self.walk(&mut found);
+        found.0
+    }
+
+    /// 모든 그림 (묶음 개체, 표 셀, 글상자 안 그림 포함)
+    pub fn find_pictures(&self) -> Vec<(ContentPath, &Picture)> {
+        #[derive(Default)]
+        struct Pictures<'a>(Vec<(ContentPath, &'a Picture)>);
+        impl<'a> Visitor<'a> for Pictures<'a> {
+            fn visit_picture(&mut self, path: &ContentPath, picture: &'a Picture) {
+                self.0.push((path.clone(), picture));
+            }
+        }
+        let mut found = Pictures::default();
+        self.walk(&mut found);
+        found.0
+    }
+
+    /// 경로의 문단
+    pub fn paragraph_at(&self, path: &ParagraphPath) -> Option<&Paragraph> {
+        let section = self.sections.get(path.section)?;
+        let mut list = match path.master_page {
+            None => &section.paragraphs,
+            Some(index) => {
+                &section
+                    .definition
+                    .master_pages
+                    .get(index)?
+                    .content
+                    .paragraphs
+            }
+        };
+        for step in &path.steps {
+            let content = list
+                .get(step.paragraph)?
+                .runs
+                .get(step.run)?
+                .contents
+                .get(step.content)?;
+            list = content.paragraph_lists().into_iter().nth(step.list)?;
+        }
+        list.get(path.paragraph)
+    }
+
+    /// 경로의 문단 (가변)
+    pub fn paragraph_at_mut(&mut self, path: &ParagraphPath) -> Option<&mut Paragraph> {
+        let section = self.sections.get_mut(path.section)?;
+        let mut list = match path.master_page {
+            None => &mut section.paragraphs,
+            Some(index) => {
+                &mut section
+                    .definition
+                    .master_pages
+                    .get_mut(index)?
+                    .content
+                    .paragraphs
+            }
+        };
+        for step in &path.steps {
+            let content = list
+                .get_mut(step.paragraph)?
+                .runs
+                .get_mut(step.run)?
+                .contents
+                .get_mut(step.content)?;
+            list = content.paragraph_lists_mut().into_iter().nth(step.list)?;
+        }
+        list.get_mut(path.paragraph)
+    }
+
+    /// 경로의 콘텐츠 (묶음 개체 안 개체 경로는 그 개체를 품은 콘텐츠)
+    pub fn content_at(&self, path: &ContentPath) -> Option<&RunContent> {
+        self.paragraph_at(&path.paragraph)?
+            .runs
+            .get(path.run)?
+            .contents
+            .get(path.content)
+    }
+
+    /// 경로의 개체 (묶음 개체 안 개체 포함)
+    pub fn shape_at(&self, path: &ContentPath) -> Option<&ShapeObject> {
+        let RunContent::Object(shape) = self.content_at(path)? else {
+            return None;
+        };
+        let mut shape: &ShapeObject = shape;
+        for &child in &path.children {
+            let ShapeObject::Container(container) = shape else {
+                return None;
+            };
+            shape = container.children.get(child)?;
+        }
+        Some(shape)
+    }
+}
+
+fn walk_list<'a>(
+    paragraphs: &'a [Paragraph],
+    path: &mut ParagraphPath,
+    visitor: &mut impl Visitor<'a>,
+) {
+    path.paragraph = 0;
+    visitor.visit_list(path, paragraphs);
+    for (para_index, para) in paragraphs.iter().enumerate() {
+        path.paragraph = para_index;
+        visitor.visit_paragraph(path, para);
+        for (run_index, run) in para.runs.iter().enumerate() {
+            walk_run(run, run_index, path, visitor);
+        }
+    }
+}
+
+fn walk_run<'a>(
+    run: &'a Run,
+    run_index: usize,
+    path: &mut ParagraphPath,
+    visitor: &mut impl Visitor<'a>,
+) {
+    for (content_index, content) in run.contents.iter().enumerate() {
+        let mut content_path = ContentPath {
+            paragraph: path.clone(),
+            run: run_index,
+            content: content_index,
+            children: Vec::new(),
+        };
+        visitor.visit_content(&content_path, content);
+        match content {
+            RunContent::Control(control) => visitor.visit_control(&content_path, control),
+            RunContent::Object(shape) => walk_shape(shape, &mut content_path, visitor),
+            RunContent::Text(_) => {}
+        }
+        let paragraph = path.paragraph;
+        for (list_index, list) in content.paragraph_lists().into_iter().enumerate() {
+            path.steps.push(ListStep {
+                paragraph,
+                run: run_index,
+                content: content_index,
+                list: list_index,
+            });
+            walk_list(list, path, visitor);
+            path.steps.pop();
+        }
+        path.paragraph = paragraph;
+    }
+}
+
+fn walk_shape<'a>(shape: &'a ShapeObject, path: &mut ContentPath, visitor: &mut impl Visitor<'a>) {
+    visitor.visit_shape(path, shape);
+    match shape {
+        ShapeObject::Table(table) => visitor.visit_table(path, table),
+        ShapeObject::Picture(picture) => visitor.visit_picture(path, picture),
+        ShapeObject::Container(container) => {
+            for (child_index, child) in container.children.iter().enumerate() {
+                path.children.push(child_index);
+                walk_shape(child, path, visitor);
+                path.children.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+fn walk_list_mut(
+    paragraphs: &mut Vec<Paragraph>,
+    path: &mut ParagraphPath,
+    visitor: &mut impl VisitorMut,
+) {
+    path.paragraph = 0;
+    visitor.visit_list_mut(path, paragraphs);
+    for (para_index, para) in paragraphs.iter_mut().enumerate() {
+        path.paragraph = para_index;
+        visitor.visit_paragraph_mut(path, para);
+        for (run_index, run) in para.runs.iter_mut().enumerate() {
+            walk_run_mut(run, run_index, path, visitor);
+        }
+    }
+}
+
+fn walk_run_mut(
+    run: &mut Run,
+    run_index: usize,
+    path: &mut ParagraphPath,
+    visitor: &mut impl VisitorMut,
+) {
+    for (content_index, content) in run.contents.iter_mut().enumerate() {
+        let mut content_path = ContentPath {
+            paragraph: path.clone(),
+            run: run_index,
+            content: content_index,
+            children: Vec::new(),
+        };
+        visitor.visit_content_mut(&content_path, content);
+        if let RunContent::Object(shape) = content {
+            walk_shape_mut(shape, &mut content_path, visitor);
+        }
+        let paragraph = path.paragraph;
+        for (list_index, list) in content.paragraph_lists_mut().into_iter().enumerate() {
+            path.steps.push(ListStep {
+                paragraph,
+                run: run_index,
+                content: content_index,
+                list: list_index,
+            });
+            walk_list_mut(list, path, visitor);
+            path.steps.pop();
+        }
+        path.paragraph = paragraph;
+    }
+}
+
+fn walk_shape_mut(shape: &mut ShapeObject, path: &mut ContentPath, visitor: &mut impl VisitorMut) {
+    visitor.visit_shape_mut(path, shape);
+    if let ShapeObject::Container(container) = shape {
+        for (child_index, child) in container.children.iter_mut().enumerate() {
+            path.children.push(child_index);
+            walk_shape_mut(child, path, visitor);
+            path.children.pop();
+        }
+    }
+}
+
+// ── 경로 문자열 ──
+
+impl fmt::Display for ParagraphPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.section)?;
+        if let Some(master_page) = self.master_page {
+            write!(f, "m{}", master_page)?;
+        }
+        for step in &self.steps {
+            write!(
+                f,
+                "/{}.{}.{}.{}",
+                step.paragraph, step.run, step.content, step.list
+            )?;
+        }
+        write!(f, "/{}", self.paragraph)
+    }
+}
+
+impl FromStr for ParagraphPath {
+    type Err = PathParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('/');
+        let root = parts.next().unwrap_or_default();
+        let (section, master_page) = match root.split_once('m') {
+            Some((section, master)) => (number(section, s)?, Some(number(master, s)?)),
+            None => (number(root, s)?, None),
+        };
+        let parts: Vec<&str> = parts.collect();
+        let Some((last, steps)) = parts.split_last() else {
+            return Err(PathParseError(s.to_string()));
+        };
+        let steps = steps
+            .iter()
+            .map(|step| match numbers(step, '.', s)?.as_slice() {
+                &[paragraph, run, content, list] => Ok(ListStep {
+                    paragraph,
+                    run,
+                    content,
+                    list,
+                }),
+                _ => Err(PathParseError(s.to_string())),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(ParagraphPath {
+            section,
+            master_page,
+            steps,
+            paragraph: number(last, s)?,
+        })
+    }
+}
+
+impl fmt::Display for ContentPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}.{}", self.paragraph, self.run, self.content)?;
+        for child in &self.children {
+            write!(f, ">{}", child)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ContentPath {
+    type Err = PathParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (paragraph, rest) = s
+            .rsplit_once(':')
+            .ok_or_else(|| PathParseError(s.to_string()))?;
+        let mut parts = rest.split('>');
+        let (run, content) = match numbers(parts.next().unwrap_or_default(), '.', s)?.as_slice() {
+            &[run, content] => (run, content),
+            _ => return Err(PathParseError(s.to_string())),
+        };
+        Ok(ContentPath {
+            paragraph: paragraph.parse()?,
+            run,
+            content,
+            children: parts
+                .map(|part| number(part, s))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl fmt::Display for PathParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "잘못된 문서 경로: {}", self.0)
+    }
+}
+
+impl std::error::Error for PathParseError {}
+
+fn number(part: &str, whole: &str) -> Result<usize, PathParseError> {
+    part.parse().map_err(|_| PathParseError(whole.to_string()))
+}
+
+fn numbers(part: &str, separator: char, whole: &str) -> Result<Vec<usize>, PathParseError> {
+    part.split(separator).map(|n| number(n, whole)).collect()
+}
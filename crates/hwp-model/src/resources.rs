@@ -14,6 +14,8 @@ pub struct Resources {
     pub para_shapes: Vec<ParaShape>,
     pub styles: Vec<Style>,
     pub memo_shapes: Vec<MemoShape>,
+    pub track_changes: Vec<TrackChange>,
+    pub track_change_authors: Vec<TrackChangeAuthor>,
 }
 
 // ── 글꼴 ──
@@ -381,3 +383,40 @@ pub enum MemoType {
     Normal,
     TrackChange,
 }
+
+// ── 변경 추적 ──
+
+/// 변경 추적 항목 (`hh:trackChange`). 본문의 삽입/삭제 표시가 `tc_id`로 참조한다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TrackChange {
+    pub id: u32,
+    pub change_type: TrackChangeType,
+    /// 변경 일시 (원본 문자열 그대로, 예: "2024-03-05T09:12:00Z")
+    pub date: Option<String>,
+    pub author_id: u32,
+    pub char_shape_id: Option<u16>,
+    pub para_shape_id: Option<u16>,
+    /// 변경 내용 숨김 여부
+    pub hide: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum TrackChangeType {
+    #[default]
+    Unknown,
+    Insert,
+    Delete,
+    CharShape,
+    ParaShape,
+}
+
+/// 변경 추적 작성자 (`hh:trackChangeAuthor`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TrackChangeAuthor {
+    pub id: u32,
+    pub name: String,
+    /// 작성자별 표시 사용 여부
+    pub mark: bool,
+    /// 작성자 표시 색
+    pub color: Color,
+}
@@ -0,0 +1,309 @@
+use serde::{Deserialize, Serialize};
+
+use crate::document::Document;
+use crate::paragraph::{Paragraph, RunContent, TextElement};
+use crate::query::{ParagraphPath, Visitor, VisitorMut};
+use crate::resources::{Resources, TrackChange, TrackChangeAuthor};
+use crate::types::Color;
+
+// ═══════════════════════════════════════════
+// 변경 추적 (검토 문서의 삽입/삭제 구간)
+// ═══════════════════════════════════════════
+
+/// 변경 구간의 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RevisionKind {
+    Insert,
+    Delete,
+}
+
+/// 본문의 삽입/삭제 표시 한 쌍이 감싸는 변경 구간
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Revision {
+    pub kind: RevisionKind,
+    /// 본문 표시 ID (`Id`)
+    pub id: String,
+    /// 변경 추적 항목 ID (`TcId` → `Resources::track_changes`)
+    pub tc_id: Option<String>,
+    pub author: Option<String>,
+    /// 변경 일시 (원본 문자열)
+    pub date: Option<String>,
+    /// 작성자 표시 색
+    pub color: Color,
+    /// 구간 안의 텍스트 (문단 경계는 '\n')
+    pub text: String,
+}
+
+/// `TextElement`의 변경 추적 표시 (InsertBegin/End, DeleteBegin/End)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RevisionMark<'a> {
+    pub kind: RevisionKind,
+    /// 시작 표시이면 true, 끝 표시이면 false
+    pub begin: bool,
+    pub id: &'a str,
+    pub tc_id: Option<&'a str>,
+    /// 문단 끝(문단 나누기)이 구간에 포함되는지 여부
+    pub para_end: bool,
+}
+
+impl TextElement {
+    /// 변경 추적 표시이면 그 내용을 돌려준다
+    pub fn revision_mark(&self) -> Option<RevisionMark<'_>> {
+        let (kind, begin, id, tc_id, para_end) = match self {
+            TextElement::InsertBegin {
+                id,
+                tc_id,
+                para_end,
+            } => (RevisionKind::Insert, true, id, tc_id, para_end),
+            TextElement::InsertEnd {
+                id,
+                tc_id,
+                para_end,
+            } => (RevisionKind::Insert, false, id, tc_id, para_end),
+            TextElement::DeleteBegin {
+                id,
+                tc_id,
+                para_end,
+            } => (RevisionKind::Delete, true, id, tc_id, para_end),
+            TextElement::DeleteEnd {
+                id,
+                tc_id,
+                para_end,
+            } => (RevisionKind::Delete, false, id, tc_id, para_end),
+            _ => return None,
+        };
+        Some(RevisionMark {
+            kind,
+            begin,
+            id,
+            tc_id: tc_id.as_deref(),
+            para_end: *para_end,
+        })
+    }
+}
+
+impl Resources {
+    /// `TcId` 문자열로 변경 추적 항목 찾기
+    pub fn track_change(&self, tc_id: &str) -> Option<&TrackChange> {
+        let id: u32 = tc_id.trim().parse().ok()?;
+        self.track_changes.iter().find(|tc| tc.id == id)
+    }
+
+    /// 변경 추적 항목의 작성자 찾기
+    pub fn track_change_author(&self, track_change: &TrackChange) -> Option<&TrackChangeAuthor> {
+        self.track_change_authors
+            .iter()
+            .find(|a| a.id == track_change.author_id)
+    }
+
+    /// 본문 표시의 `TcId`로 작성자 찾기 (항목이나 작성자가 없으면 None)
+    pub fn revision_author(&self, tc_id: Option<&str>) -> Option<&TrackChangeAuthor> {
+        self.track_change(tc_id?)
+            .and_then(|tc| self.track_change_author(tc))
+    }
+}
+
+impl Document {
+    /// 본문 표시 여부 (삽입/삭제 표시가 하나라도 있으면 true)
+    pub fn has_revisions(&self) -> bool {
+        #[derive(Default)]
+        struct Found(bool);
+        impl Visitor<'_> for Found {
+            fn visit_paragraph(&mut self, _path: &ParagraphPath, para: &Paragraph) {
+                self.0 = self.0 || marks(para).next().is_some();
+            }
+        }
+        let mut found = Found::default();
+        self.walk(&mut found);
+        found.0
+    }
+
+    /// 문서 순서대로 모든 변경 구간을 작성자/일시/텍스트와 함께 모은다
+    pub fn revisions(&self) -> Vec<Revision> {
+        struct Revisions<'r> {
+            resources: &'r Resources,
+            revisions: Vec<Revision>,
+        }
+        impl<'a> Visitor<'a> for Revisions<'_> {
+            // 변경 구간은 같은 목록 안에서 문단을 넘어 이어진다
+            fn visit_list(&mut self, _path: &ParagraphPath, paragraphs: &'a [Paragraph]) {
+                let resources = self.resources;
+                let revisions = &mut self.revisions;
+                // 열린 구간: (본문 표시 ID, revisions 인덱스)
+                let mut open: Vec<(&str, usize)> = Vec::new();
+                for (i, para) in paragraphs.iter().enumerate() {
+                    if i > 0 {
+                        for (_, index) in &open {
+                            revisions[*index].text.push('\n');
+                        }
+                    }
+                    for element in para
+                        .runs
+                        .iter()
+                        .flat_map(|run| &run.contents)
+                        .filter_map(|content| match content {
+                            RunContent::Text(tc) => Some(&tc.elements),
+                            _ => None,
+                        })
+                        .flatten()
+                    {
+                        if let Some(mark) = element.revision_mark() {
+                            if mark.begin {
+                                let track_change =
+                                    mark.tc_id.and_then(|id| resources.track_change(id));
+                                let author =
+                                    track_change.and_then(|tc| resources.track_change_author(tc));
+                                open.push((mark.id, revisions.len()));
+                                revisions.push(Revision {
+                                    kind: mark.kind,
+                                    id: mark.id.to_string(),
+                                    tc_id: mark.tc_id.map(str::to_string),
+                                    author: author.map(|a| a.name.clone()),
+                                    date: track_change.and_then(|tc| tc.date.clone()),
+                                    color: author.and_then(|a| a.color),
+                                    text: String::new(),
+                                });
+                            } else if let Some(pos) =
+                                open.iter().rposition(|(id, _)| *id == mark.id)
+                            {
+                                open.remove(pos);
+                            }
+                            continue;
+                        }
+                        let Some(text) = element.plain_text() else {
+                            continue;
+                        };
+                        for (_, index) in &open {
+                            revisions[*index].text.push_str(text);
+                        }
+                    }
+                }
+            }
+        }
+        let mut collected = Revisions {
+            resources: &self.resources,
+            revisions: Vec::new(),
+        };
+        self.walk(&mut collected);
+        collected.revisions
+    }
+
+    /// 모든 변경을 반영한다 (삽입 유지, 삭제 구간 제거, 표시 제거)
+    pub fn accept_revisions(&mut self) {
+        self.walk_mut(&mut Resolve(RevisionKind::Delete));
+    }
+
+    /// 모든 변경을 되돌린다 (삭제 유지, 삽입 구간 제거, 표시 제거)
+    pub fn reject_revisions(&mut self) {
+        self.walk_mut(&mut Resolve(RevisionKind::Insert));
+    }
+}
+
+/// 목록마다 한 종류의 구간을 지운다 (지운 뒤 남은 문단의 하위 목록을 방문)
+struct Resolve(RevisionKind);
+
+impl VisitorMut for Resolve {
+    fn visit_list_mut(&mut self, _path: &ParagraphPath, paragraphs: &mut Vec<Paragraph>) {
+        resolve_list(paragraphs, self.0);
+    }
+}
+
+fn marks(para: &Paragraph) -> impl Iterator<Item = RevisionMark<'_>> {
+    para.runs
+        .iter()
+        .flat_map(|run| &run.contents)
+        .filter_map(|content| match content {
+            RunContent::Text(tc) => Some(&tc.elements),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(TextElement::revision_mark)
+}
+
+/// 문단 목록에서 `remove` 종류의 구간을 지우고 모든 표시를 없앤다.
+/// 지워지는 구간이 문단 끝을 포함하면 다음 문단을 이어 붙인다.
+fn resolve_list(paragraphs: &mut Vec<Paragraph>, remove: RevisionKind) {
+    if !paragraphs.iter().any(|p| marks(p).next().is_some()) {
+        return;
+    }
+    let mut open: Vec<(String, RevisionKind)> = Vec::new();
+    let mut resolved: Vec<Paragraph> = Vec::with_capacity(paragraphs.len());
+    let mut join_next = false;
+    for mut para in paragraphs.drain(..) {
+        let joins = resolve_paragraph(&mut para, &mut open, remove);
+        match resolved.last_mut() {
+            Some(prev) if join_next => {
+                prev.runs.append(&mut para.runs);
+                prev.line_segments.clear();
+            }
+            _ => resolved.push(para),
+        }
+        join_next = joins;
+    }
+    *paragraphs = resolved;
+}
+
+/// 문단 하나를 정리하고, 문단 끝이 지워져 다음 문단과 합쳐야 하면 true를 돌려준다
+fn resolve_paragraph(
+    para: &mut Paragraph,
+    open: &mut Vec<(String, RevisionKind)>,
+    remove: RevisionKind,
+) -> bool {
+    let removing = |open: &[(String, RevisionKind)]| open.iter().any(|(_, k)| *k == remove);
+    let mut changed = false;
+    let mut para_end_removed = false;
+
+    for run in &mut para.runs {
+        let mut contents = Vec::with_capacity(run.contents.len());
+        for content in run.contents.drain(..) {
+            match content {
+                RunContent::Text(mut tc) => {
+                    let mut elements = Vec::with_capacity(tc.elements.len());
+                    for element in tc.elements.drain(..) {
+                        if let Some(mark) = element.revision_mark() {
+                            changed = true;
+                            if mark.kind == remove && mark.para_end {
+                                para_end_removed = true;
+                            }
+                            if mark.begin {
+                                open.push((mark.id.to_string(), mark.kind));
+                            } else if let Some(pos) = open.iter().rposition(|(id, _)| id == mark.id)
+                            {
+                                open.remove(pos);
+                            }
+                        } else if removing(open) {
+                            changed = true;
+                        } else {
+                            elements.push(element);
+                        }
+                    }
+                    if !elements.is_empty() {
+                        tc.elements = elements;
+                        contents.push(RunContent::Text(tc));
+                    }
+                }
+                other => {
+                    if removing(open) {
+                        changed = true;
+                    } else {
+                        contents.push(other);
+                    }
+                }
+            }
+        }
+        run.contents = contents;
+    }
+
+    if changed {
+        // 줄 배치 캐시와 HWP 영역 태그 위치는 더 이상 맞지 않는다
+        para.line_segments.clear();
+        if let Some(hints) = &mut para.hwp_hints {
+            hints.range_tags_raw.clear();
+        }
+        if para.runs.len() > 1 {
+            para.runs.retain(|run| !run.contents.is_empty());
+        }
+    }
+
+    para_end_removed || removing(open)
+}
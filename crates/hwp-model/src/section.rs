@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::paragraph::Paragraph;
+use crate::paragraph::{Paragraph, SubList};
 use crate::types::*;
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -200,7 +200,15 @@ pub enum LineNumberRestart {
 
 // ── 바탕쪽 ──
 
+/// 바탕쪽: 적용되는 쪽의 본문 뒤에 그리는 내용 (로고, 워터마크 등)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MasterPage {
     pub id_ref: Option<u16>,
+    /// 양쪽/짝수쪽/홀수쪽/첫 쪽 (HWPX 임의 쪽은 `First` + `page_number`)
+    pub apply_page_type: PageApplyType,
+    /// `First`가 적용되는 쪽 번호 (0이면 구역 첫 쪽)
+    pub page_number: u16,
+    pub page_duplicate: bool,
+    pub page_front: bool,
+    pub content: SubList,
 }
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use crate::chart::Chart;
 use crate::paragraph::SubList;
 use crate::resources::{FillBrush, ImageRef};
 use crate::table::Table;
@@ -153,6 +154,31 @@ pub enum ShapeObject {
     Equation(Box<EquationObject>),
     Chart(Box<ChartObject>),
     Video(Box<VideoObject>),
+    Form(Box<FormObject>),
+}
+
+impl ShapeObject {
+    /// 모든 개체가 공유하는 공통 속성 (크기, 위치, 캡션 등)
+    pub fn common(&self) -> &ShapeCommon {
+        match self {
+            ShapeObject::Table(t) => &t.common,
+            ShapeObject::Picture(p) => &p.common,
+            ShapeObject::Line(l) => &l.common,
+            ShapeObject::Rectangle(r) => &r.common,
+            ShapeObject::Ellipse(e) => &e.common,
+            ShapeObject::Arc(a) => &a.common,
+            ShapeObject::Polygon(p) => &p.common,
+            ShapeObject::Curve(c) => &c.common,
+            ShapeObject::ConnectLine(c) => &c.common,
+            ShapeObject::TextArt(t) => &t.common,
+            ShapeObject::Container(c) => &c.common,
+            ShapeObject::Ole(o) => &o.common,
+            ShapeObject::Equation(e) => &e.common,
+            ShapeObject::Chart(c) => &c.common,
+            ShapeObject::Video(v) => &v.common,
+            ShapeObject::Form(f) => &f.common,
+        }
+    }
 }
 
 // ═══════════════════════════════════════════
@@ -433,6 +459,9 @@ pub struct EquationObject {
 pub struct ChartObject {
     pub common: ShapeCommon,
     pub component: ShapeComponentData,
+    /// HWPX 차트 파트 경로 (chartIDRef, 예: Chart/chart1.xml)
+    pub chart_id_ref: Option<String>,
+    pub chart: Option<Chart>,
 }
 
 // ── 비디오 ──
@@ -452,3 +481,59 @@ pub enum VideoType {
     Local,
     Web,
 }
+
+// ── 양식 개체 ──
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FormObject {
+    pub common: ShapeCommon,
+    pub form_type: FormType,
+    /// 양식 개체 이름 (값을 읽고 쓸 때의 키)
+    pub name: String,
+    /// 단추 / 선택 상자의 표시 문자열
+    pub caption: Option<String>,
+    /// 입력 상자 문자열, 목록 상자의 선택 값
+    pub value: Option<String>,
+    /// 선택 상자 / 라디오 단추의 선택 상태
+    pub check_state: FormCheckState,
+    /// 라디오 단추 묶음 이름
+    pub group_name: Option<String>,
+    /// 목록 상자 항목
+    pub items: Vec<FormListItem>,
+    pub fore_color: Color,
+    pub back_color: Color,
+    pub enabled: bool,
+    pub read_only: bool,
+    pub multi_line: bool,
+    pub max_length: Option<u32>,
+    pub password_char: Option<String>,
+    pub tab_order: Option<u32>,
+    /// 글자 모양 (charPrIDRef)
+    pub char_shape_id: Option<u16>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FormType {
+    #[default]
+    PushButton,
+    CheckBox,
+    RadioButton,
+    ComboBox,
+    ListBox,
+    Edit,
+    ScrollBar,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FormCheckState {
+    #[default]
+    Unchecked,
+    Checked,
+    Indeterminate,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FormListItem {
+    pub display_text: String,
+    pub value: String,
+}
@@ -61,6 +61,7 @@ fn parse_paragraph(
         runs: Vec::new(),
         line_segments: Vec::new(),
         has_char_shapes: true, // HWPX는 항상 Run별 charPrIDRef가 있으므로 true
+        hwp_hints: None,
     };
 
     let mut sec_def: Option<SectionDef> = None;
@@ -194,6 +195,12 @@ fn parse_run(
                             parse_ole_object(e, reader)?,
                         ))));
                 }
+                b"chart" => {
+                    run.contents
+                        .push(RunContent::Object(ShapeObject::Chart(Box::new(
+                            parse_chart_object(e, reader)?,
+                        ))));
+                }
                 b"connectLine" => {
                     run.contents
                         .push(RunContent::Object(ShapeObject::ConnectLine(Box::new(
@@ -212,6 +219,14 @@ fn parse_run(
                             parse_video_object(e, reader)?,
                         ))));
                 }
+                name @ (b"btn" | b"checkBtn" | b"radioBtn" | b"comboBox" | b"listBox" | b"edit"
+                | b"scrollBar") => {
+                    let form_type = parse_form_type(name);
+                    run.contents
+                        .push(RunContent::Object(ShapeObject::Form(Box::new(
+                            parse_form_object(e, reader, form_type)?,
+                        ))));
+                }
                 _ => {
                     skip_element(reader, e.name().as_ref())?;
                 }
@@ -313,6 +328,17 @@ fn parse_sec_pr(
                     sd.page_border_fills
                         .push(parse_page_border_fill(e, reader)?);
                 }
+                b"masterPage" => {
+                    // idRef="masterpage0" → 0. 내용은 Contents/masterpageN.xml에서 채운다
+                    let id_ref = attr_str(e, b"idRef").and_then(|s| {
+                        let digits = s.trim_start_matches(|c: char| !c.is_ascii_digit());
+                        digits.parse::<u16>().ok()
+                    });
+                    sd.master_pages.push(MasterPage {
+                        id_ref,
+                        ..Default::default()
+                    });
+                }
                 _ => {}
             },
             Event::End(ref e) => {
@@ -329,6 +355,54 @@ fn parse_sec_pr(
     Ok(sd)
 }
 
+/// Contents/masterpageN.xml → MasterPage
+///
+/// 마지막 쪽(LAST_PAGE) 바탕쪽은 모델에 대응 값이 없어 `None`을 반환한다.
+pub fn parse_master_page<R: Read + Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    path: &str,
+) -> Result<Option<MasterPage>, HwpxError> {
+    let xml = read_zip_entry_string(archive, path)?;
+    let mut reader = Reader::from_str(&xml);
+    reader.config_mut().trim_text(true);
+
+    let mut master: Option<MasterPage> = None;
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(ref e) | Event::Empty(ref e) => match local_name(e.name().as_ref()) {
+                b"masterPage" => {
+                    let page_number = attr_u16(e, b"pageNumber").unwrap_or(0);
+                    let apply_page_type = match attr_str(e, b"type").as_deref() {
+                        Some("LAST_PAGE") => return Ok(None),
+                        Some("OPTIONAL_PAGE") => PageApplyType::First,
+                        Some(t) => parse_page_apply_type(t),
+                        None => PageApplyType::Both,
+                    };
+                    master = Some(MasterPage {
+                        apply_page_type,
+                        page_number,
+                        page_duplicate: attr_bool(e, b"pageDuplicate").unwrap_or(false),
+                        page_front: attr_bool(e, b"pageFront").unwrap_or(false),
+                        ..Default::default()
+                    });
+                }
+                b"subList" => {
+                    if let Some(ref mut mp) = master {
+                        mp.content = parse_sublist(e, &mut reader)?;
+                    }
+                }
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(master)
+}
+
 fn parse_page_pr(
     start: &quick_xml::events::BytesStart,
     reader: &mut Reader<&[u8]>,
@@ -648,7 +722,7 @@ fn parse_note(
     let tag_name = local_name(start.name().as_ref()).to_vec();
     let mut note = Note {
         id: attr_u64(start, b"id").unwrap_or(0),
-        number: attr_u16(start, b"num"),
+        number: attr_u16(start, b"number").or_else(|| attr_u16(start, b"num")),
         content: SubList::default(),
     };
 
@@ -2630,6 +2704,121 @@ fn parse_video_object(
     Ok(obj)
 }
 
+// ═══════════════════════════════════════════
+// 양식 개체 파서 (btn, checkBtn, radioBtn, comboBox, listBox, edit, scrollBar)
+// ═══════════════════════════════════════════
+
+fn parse_form_type(name: &[u8]) -> FormType {
+    match name {
+        b"checkBtn" => FormType::CheckBox,
+        b"radioBtn" => FormType::RadioButton,
+        b"comboBox" => FormType::ComboBox,
+        b"listBox" => FormType::ListBox,
+        b"edit" => FormType::Edit,
+        b"scrollBar" => FormType::ScrollBar,
+        _ => FormType::PushButton,
+    }
+}
+
+fn parse_form_object(
+    start: &quick_xml::events::BytesStart,
+    reader: &mut Reader<&[u8]>,
+    form_type: FormType,
+) -> Result<FormObject, HwpxError> {
+    let value = match form_type {
+        FormType::ComboBox | FormType::ListBox => attr_str(start, b"selectedValue"),
+        FormType::ScrollBar => attr_str(start, b"value"),
+        _ => None,
+    };
+    let check_state = match attr_str(start, b"value").as_deref() {
+        Some("CHECKED") => FormCheckState::Checked,
+        Some("INDETERMINATE") => FormCheckState::Indeterminate,
+        _ => FormCheckState::Unchecked,
+    };
+    let mut obj = FormObject {
+        common: parse_shape_common_attrs(start),
+        form_type,
+        name: attr_str(start, b"name").unwrap_or_default(),
+        caption: attr_str(start, b"captionText"),
+        value,
+        check_state,
+        group_name: attr_str(start, b"radioGroupName").or_else(|| attr_str(start, b"groupName")),
+        items: Vec::new(),
+        fore_color: attr_str(start, b"foreColor").and_then(|c| parse_color(&c)),
+        back_color: attr_str(start, b"backColor").and_then(|c| parse_color(&c)),
+        enabled: attr_bool(start, b"enabled").unwrap_or(true),
+        read_only: attr_bool(start, b"readOnly").unwrap_or(false),
+        multi_line: attr_bool(start, b"multiLine").unwrap_or(false),
+        max_length: attr_u32(start, b"maxLength").filter(|&n| n > 0),
+        password_char: attr_str(start, b"passwordChar").filter(|c| !c.is_empty()),
+        tab_order: attr_u32(start, b"tabOrder"),
+        char_shape_id: None,
+    };
+    let end_tag = local_name(start.name().as_ref()).to_vec();
+
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(ref e) if local_name(e.name().as_ref()) == b"text" => {
+                // 입력 상자 내용
+                let mut tbuf = Vec::new();
+                if let Ok(Event::Text(t)) = reader.read_event_into(&mut tbuf) {
+                    obj.value = Some(t.unescape().unwrap_or_default().to_string());
+                }
+            }
+            Event::Empty(ref e) | Event::Start(ref e) => match local_name(e.name().as_ref()) {
+                b"sz" => parse_shape_size(&mut obj.common, e),
+                b"pos" => parse_shape_pos(&mut obj.common, e),
+                b"outMargin" => obj.common.out_margin = Some(parse_margin_attrs(e)),
+                b"formCharPr" => obj.char_shape_id = attr_u16(e, b"charPrIDRef"),
+                b"listItem" => obj.items.push(FormListItem {
+                    display_text: attr_str(e, b"displayText").unwrap_or_default(),
+                    value: attr_str(e, b"value").unwrap_or_default(),
+                }),
+                _ => {}
+            },
+            Event::End(ref e) if local_name(e.name().as_ref()) == end_tag.as_slice() => break,
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+    Ok(obj)
+}
+
+// ═══════════════════════════════════════════
+// 차트 파서 (차트 본문은 lib.rs에서 chartIDRef 파트를 읽어 채움)
+// ═══════════════════════════════════════════
+
+fn parse_chart_object(
+    start: &quick_xml::events::BytesStart,
+    reader: &mut Reader<&[u8]>,
+) -> Result<ChartObject, HwpxError> {
+    let mut obj = ChartObject {
+        common: parse_shape_common_attrs(start),
+        component: parse_shape_component_attrs(start),
+        chart_id_ref: attr_str(start, b"chartIDRef"),
+        chart: None,
+    };
+
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Empty(ref e) | Event::Start(ref e) => match local_name(e.name().as_ref()) {
+                b"sz" => parse_shape_size(&mut obj.common, e),
+                b"pos" => parse_shape_pos(&mut obj.common, e),
+                b"outMargin" => obj.common.out_margin = Some(parse_margin_attrs(e)),
+                _ => {}
+            },
+            Event::End(ref e) if local_name(e.name().as_ref()) == b"chart" => break,
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+    Ok(obj)
+}
+
 // ═══════════════════════════════════════════
 // 덧말 파서
 // ═══════════════════════════════════════════
@@ -0,0 +1,273 @@
+use crate::error::HwpxError;
+use crate::utils::{attr_str, local_name};
+use hwp_model::chart::{Chart, ChartGrouping, ChartSeries, ChartType};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+/// OOXML(DrawingML) 차트 `c:chartSpace` XML → Chart
+///
+/// HWPX의 `Chart/chartN.xml`과 HWP 차트 OLE의 `OOXMLChartContents` 스트림이 이 형식이다.
+/// 계열 이름/항목/값은 `strCache`/`numCache`(또는 `strLit`/`numLit`)의 캐시 값을 읽고,
+/// 엑셀 시트 참조(`c:f`)는 계산하지 않는다.
+pub fn parse_chart_xml(xml: &str) -> Result<Chart, HwpxError> {
+    let mut reader = Reader::from_str(xml);
+    let mut state = ChartReader::default();
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(ref e) => {
+                state.element(e);
+                state.path.push(local_name(e.name().as_ref()).to_vec());
+            }
+            Event::Empty(ref e) => {
+                state.path.push(local_name(e.name().as_ref()).to_vec());
+                state.element(e);
+                state.path.pop();
+            }
+            Event::Text(ref t) => {
+                let text = t.unescape().unwrap_or_default();
+                state.text(&text);
+            }
+            Event::End(_) => {
+                let closed = state.path.pop();
+                if closed.as_deref() == Some(b"ser") {
+                    state.finish_series();
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+    Ok(state.chart)
+}
+
+#[derive(Default)]
+struct ChartReader {
+    chart: Chart,
+    /// 현재 요소까지의 local name 경로
+    path: Vec<Vec<u8>>,
+    /// 첫 번째 그래프 종류를 읽었는지
+    has_type: bool,
+    series: Option<ChartSeries>,
+    categories: Vec<String>,
+    /// 현재 `c:pt`의 idx
+    point: Option<usize>,
+}
+
+impl ChartReader {
+    fn inside(&self, name: &[u8]) -> bool {
+        self.path.iter().any(|p| p == name)
+    }
+
+    fn element(&mut self, e: &BytesStart) {
+        let qname = e.name();
+        let name = local_name(qname.as_ref());
+        let val = attr_str(e, b"val");
+        match name {
+            b"barChart" | b"bar3DChart" | b"lineChart" | b"line3DChart" | b"areaChart"
+            | b"area3DChart" | b"pieChart" | b"pie3DChart" | b"ofPieChart" | b"doughnutChart"
+            | b"scatterChart" | b"bubbleChart" | b"radarChart"
+                if !self.has_type =>
+            {
+                self.has_type = true;
+                self.chart.chart_type = match name {
+                    b"lineChart" | b"line3DChart" => ChartType::Line,
+                    b"areaChart" | b"area3DChart" => ChartType::Area,
+                    b"pieChart" | b"pie3DChart" | b"ofPieChart" => ChartType::Pie,
+                    b"doughnutChart" => ChartType::Doughnut,
+                    b"scatterChart" | b"bubbleChart" => ChartType::Scatter,
+                    b"radarChart" => ChartType::Radar,
+                    _ => ChartType::Column,
+                };
+                self.chart.grouping = match self.chart.chart_type {
+                    ChartType::Column => ChartGrouping::Clustered,
+                    _ => ChartGrouping::Standard,
+                };
+            }
+            b"barDir" if val.as_deref() == Some("bar") && self.chart.series.is_empty() => {
+                self.chart.chart_type = ChartType::Bar;
+            }
+            b"grouping" if self.chart.series.is_empty() && self.series.is_none() => {
+                self.chart.grouping = match val.as_deref() {
+                    Some("stacked") => ChartGrouping::Stacked,
+                    Some("percentStacked") => ChartGrouping::PercentStacked,
+                    Some("standard") => ChartGrouping::Standard,
+                    _ => ChartGrouping::Clustered,
+                };
+            }
+            b"legend" if !self.inside(b"ser") => self.chart.legend = true,
+            b"ser" => self.series = Some(ChartSeries::default()),
+            b"pt" => self.point = attr_str(e, b"idx").and_then(|v| v.parse().ok()),
+            b"ptCount" => {
+                let count: usize = val.and_then(|v| v.parse().ok()).unwrap_or(0);
+                if let Some(series) = self.series.as_mut() {
+                    if self.path.iter().any(|p| p == b"val" || p == b"yVal") {
+                        series.values.resize(count.min(10_000), None);
+                    }
+                }
+                if self.in_categories() && self.categories.len() < count {
+                    self.categories.resize(count.min(10_000), String::new());
+                }
+            }
+            b"srgbClr" => {
+                // 계열 도형 속성의 첫 단색만 (데이터 요소별 dPt 색은 무시)
+                let in_series_fill = self.inside(b"ser")
+                    && self.inside(b"spPr")
+                    && !self.inside(b"dPt")
+                    && !self.inside(b"dLbls");
+                if let Some(series) = self.series.as_mut().filter(|_| in_series_fill) {
+                    if series.color.is_none() {
+                        series.color = val.and_then(|v| u32::from_str_radix(&v, 16).ok());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn in_categories(&self) -> bool {
+        self.inside(b"ser") && (self.inside(b"cat") || self.inside(b"xVal"))
+    }
+
+    fn text(&mut self, text: &str) {
+        let Some(last) = self.path.last().map(|p| p.as_slice()) else {
+            return;
+        };
+        let (is_value, is_run) = (last == b"v", last == b"t");
+        if self.inside(b"ser") {
+            if !is_value {
+                return;
+            }
+            let in_cat = self.in_categories();
+            let in_val = self.inside(b"val") || self.inside(b"yVal");
+            let in_name = self.inside(b"tx");
+            let point = self.point;
+            let Some(series) = self.series.as_mut() else {
+                return;
+            };
+            if in_name {
+                series.name = Some(text.to_string());
+            } else if in_cat {
+                let idx = point.unwrap_or(self.categories.len());
+                if idx < 10_000 {
+                    if self.categories.len() <= idx {
+                        self.categories.resize(idx + 1, String::new());
+                    }
+                    self.categories[idx] = text.to_string();
+                }
+            } else if in_val {
+                let idx = point.unwrap_or(series.values.len());
+                if idx < 10_000 {
+                    if series.values.len() <= idx {
+                        series.values.resize(idx + 1, None);
+                    }
+                    series.values[idx] = text.trim().parse().ok();
+                }
+            }
+            return;
+        }
+        // 제목: 리치 텍스트(a:t) 또는 참조 캐시(c:v)
+        if (is_run || is_value) && self.inside(b"title") {
+            let target = if self.inside(b"catAx") || self.inside(b"dateAx") {
+                &mut self.chart.category_axis_title
+            } else if self.inside(b"valAx") {
+                &mut self.chart.value_axis_title
+            } else {
+                &mut self.chart.title
+            };
+            target.get_or_insert_with(String::new).push_str(text);
+        }
+    }
+
+    fn finish_series(&mut self) {
+        if let Some(series) = self.series.take() {
+            // 항목은 항목 값을 가진 첫 계열 기준
+            if self.chart.categories.is_empty() && !self.categories.is_empty() {
+                self.chart.categories = std::mem::take(&mut self.categories);
+            }
+            self.categories.clear();
+            self.chart.series.push(series);
+        }
+        self.point = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BAR_CHART: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<c:chartSpace xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+  <c:chart>
+    <c:title><c:tx><c:rich><a:p><a:r><a:t>연령대별 비율</a:t></a:r></a:p></c:rich></c:tx></c:title>
+    <c:plotArea>
+      <c:barChart>
+        <c:barDir val="col"/>
+        <c:grouping val="clustered"/>
+        <c:ser>
+          <c:idx val="0"/>
+          <c:tx><c:strRef><c:f>Sheet1!$B$1</c:f><c:strCache><c:ptCount val="1"/><c:pt idx="0"><c:v>2015년</c:v></c:pt></c:strCache></c:strRef></c:tx>
+          <c:spPr><a:solidFill><a:srgbClr val="4F81BD"/></a:solidFill></c:spPr>
+          <c:cat><c:strRef><c:strCache><c:ptCount val="3"/>
+            <c:pt idx="0"><c:v>10대</c:v></c:pt><c:pt idx="1"><c:v>20대</c:v></c:pt><c:pt idx="2"><c:v>30대</c:v></c:pt>
+          </c:strCache></c:strRef></c:cat>
+          <c:val><c:numRef><c:numCache><c:formatCode>General</c:formatCode><c:ptCount val="3"/>
+            <c:pt idx="0"><c:v>11.2</c:v></c:pt><c:pt idx="2"><c:v>16.3</c:v></c:pt>
+          </c:numCache></c:numRef></c:val>
+        </c:ser>
+        <c:ser>
+          <c:idx val="1"/>
+          <c:tx><c:v>2014년</c:v></c:tx>
+          <c:val><c:numLit><c:ptCount val="3"/><c:pt idx="0"><c:v>11</c:v></c:pt><c:pt idx="1"><c:v>25.5</c:v></c:pt><c:pt idx="2"><c:v>18.7</c:v></c:pt></c:numLit></c:val>
+        </c:ser>
+      </c:barChart>
+      <c:catAx><c:title><c:tx><c:rich><a:p><a:r><a:t>연령</a:t></a:r></a:p></c:rich></c:tx></c:title></c:catAx>
+      <c:valAx><c:title><c:tx><c:rich><a:p><a:r><a:t>%</a:t></a:r></a:p></c:rich></c:tx></c:title></c:valAx>
+    </c:plotArea>
+    <c:legend><c:legendPos val="r"/></c:legend>
+  </c:chart>
+</c:chartSpace>"#;
+
+    #[test]
+    fn test_parse_bar_chart() {
+        let chart = parse_chart_xml(BAR_CHART).unwrap();
+        assert_eq!(chart.chart_type, ChartType::Column);
+        assert_eq!(chart.grouping, ChartGrouping::Clustered);
+        assert_eq!(chart.title.as_deref(), Some("연령대별 비율"));
+        assert_eq!(chart.categories, vec!["10대", "20대", "30대"]);
+        assert_eq!(chart.series.len(), 2);
+        assert_eq!(chart.series[0].name.as_deref(), Some("2015년"));
+        assert_eq!(chart.series[0].values, vec![Some(11.2), None, Some(16.3)]);
+        assert_eq!(chart.series[0].color, Some(0x4F81BD));
+        assert_eq!(chart.series[1].name.as_deref(), Some("2014년"));
+        assert_eq!(
+            chart.series[1].values,
+            vec![Some(11.0), Some(25.5), Some(18.7)]
+        );
+        assert_eq!(chart.category_axis_title.as_deref(), Some("연령"));
+        assert_eq!(chart.value_axis_title.as_deref(), Some("%"));
+        assert!(chart.legend);
+    }
+
+    #[test]
+    fn test_parse_stacked_bar_and_pie() {
+        let xml = BAR_CHART
+            .replace(r#"<c:barDir val="col"/>"#, r#"<c:barDir val="bar"/>"#)
+            .replace(r#"val="clustered""#, r#"val="percentStacked""#);
+        let chart = parse_chart_xml(&xml).unwrap();
+        assert_eq!(chart.chart_type, ChartType::Bar);
+        assert_eq!(chart.grouping, ChartGrouping::PercentStacked);
+
+        let pie = r#"<c:chartSpace xmlns:c="c"><c:chart><c:plotArea><c:pieChart><c:varyColors val="1"/>
+            <c:ser><c:cat><c:strLit><c:pt idx="0"><c:v>A</c:v></c:pt><c:pt idx="1"><c:v>B</c:v></c:pt></c:strLit></c:cat>
+            <c:val><c:numLit><c:pt idx="0"><c:v>3</c:v></c:pt><c:pt idx="1"><c:v>1</c:v></c:pt></c:numLit></c:val></c:ser>
+            </c:pieChart></c:plotArea></c:chart></c:chartSpace>"#;
+        let chart = parse_chart_xml(pie).unwrap();
+        assert_eq!(chart.chart_type, ChartType::Pie);
+        assert_eq!(chart.categories, vec!["A", "B"]);
+        assert_eq!(chart.series[0].values, vec![Some(3.0), Some(1.0)]);
+        assert!(!chart.legend);
+        assert_eq!(chart.title, None);
+    }
+}
@@ -46,6 +46,15 @@ pub fn parse_header<R: Read + Seek>(
                 b"styles" => {
                     resources.styles = parse_styles(&mut reader)?;
                 }
+                b"memoProperties" => {
+                    resources.memo_shapes = parse_memo_properties(&mut reader)?;
+                }
+                b"trackChanges" => {
+                    resources.track_changes = parse_track_changes(&mut reader)?;
+                }
+                b"trackChangeAuthors" => {
+                    resources.track_change_authors = parse_track_change_authors(&mut reader)?;
+                }
                 _ => {}
             },
             Event::Eof => break,
@@ -1059,6 +1068,98 @@ fn parse_styles(reader: &mut Reader<&[u8]>) -> Result<Vec<Style>, HwpxError> {
     Ok(styles)
 }
 
+fn parse_memo_properties(reader: &mut Reader<&[u8]>) -> Result<Vec<MemoShape>, HwpxError> {
+    let mut memos = Vec::new();
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Empty(ref e) | Event::Start(ref e)
+                if local_name(e.name().as_ref()) == b"memoPr" =>
+            {
+                memos.push(MemoShape {
+                    id: attr_u16(e, b"id").unwrap_or(0),
+                    width: attr_i32(e, b"width").unwrap_or(0),
+                    line_width: attr_u8(e, b"lineWidth").unwrap_or(0),
+                    line_type: parse_line_type3(&attr_str(e, b"lineType").unwrap_or_default()),
+                    line_color: attr_str(e, b"lineColor").and_then(|s| parse_color(&s)),
+                    fill_color: attr_str(e, b"fillColor").and_then(|s| parse_color(&s)),
+                    active_color: attr_str(e, b"activeColor").and_then(|s| parse_color(&s)),
+                    memo_type: match attr_str(e, b"memoType").as_deref() {
+                        Some("USER_INSERT") | Some("USER_DELETE") | Some("USER_UPDATE") => {
+                            MemoType::TrackChange
+                        }
+                        _ => MemoType::Normal,
+                    },
+                });
+            }
+            Event::End(ref e) if local_name(e.name().as_ref()) == b"memoProperties" => break,
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(memos)
+}
+
+fn parse_track_changes(reader: &mut Reader<&[u8]>) -> Result<Vec<TrackChange>, HwpxError> {
+    let mut changes = Vec::new();
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Empty(ref e) | Event::Start(ref e)
+                if local_name(e.name().as_ref()) == b"trackChange" =>
+            {
+                changes.push(TrackChange {
+                    id: attr_u32(e, b"id").unwrap_or(0),
+                    change_type: parse_track_change_type(&attr_str(e, b"type").unwrap_or_default()),
+                    date: attr_str(e, b"date"),
+                    author_id: attr_u32(e, b"authorID").unwrap_or(0),
+                    char_shape_id: attr_u16(e, b"charshapeID"),
+                    para_shape_id: attr_u16(e, b"parashapeID"),
+                    hide: attr_bool(e, b"hide").unwrap_or(false),
+                });
+            }
+            Event::End(ref e) if local_name(e.name().as_ref()) == b"trackChanges" => break,
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(changes)
+}
+
+fn parse_track_change_authors(
+    reader: &mut Reader<&[u8]>,
+) -> Result<Vec<TrackChangeAuthor>, HwpxError> {
+    let mut authors = Vec::new();
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Empty(ref e) | Event::Start(ref e)
+                if local_name(e.name().as_ref()) == b"trackChangeAuthor" =>
+            {
+                authors.push(TrackChangeAuthor {
+                    id: attr_u32(e, b"id").unwrap_or(0),
+                    name: attr_str(e, b"name").unwrap_or_default(),
+                    mark: attr_bool(e, b"mark").unwrap_or(false),
+                    color: attr_str(e, b"color").and_then(|s| parse_color(&s)),
+                });
+            }
+            Event::End(ref e) if local_name(e.name().as_ref()) == b"trackChangeAuthors" => break,
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(authors)
+}
+
 #[allow(dead_code)]
 fn skip_to_end(reader: &mut Reader<&[u8]>, tag: &[u8]) -> Result<(), HwpxError> {
     let mut depth = 1u32;
@@ -1357,6 +1458,16 @@ fn parse_style_type(s: &str) -> StyleType {
     }
 }
 
+fn parse_track_change_type(s: &str) -> TrackChangeType {
+    match s.to_ascii_uppercase().as_str() {
+        "INSERT" => TrackChangeType::Insert,
+        "DELETE" => TrackChangeType::Delete,
+        "CHARSHAPE" => TrackChangeType::CharShape,
+        "PARASHAPE" => TrackChangeType::ParaShape,
+        _ => TrackChangeType::Unknown,
+    }
+}
+
 fn parse_number_type2(s: &str) -> NumberType2 {
     match s {
         "CIRCLED_DIGIT" => NumberType2::CircledDigit,
@@ -1,11 +1,15 @@
 mod body;
+pub mod chart;
 mod error;
 mod header;
 mod ocf;
 mod opf;
 mod utils;
+mod writer;
 
 pub use error::HwpxError;
+pub use writer::xml;
+pub use writer::HwpxWriter;
 
 use hwp_model::document::Document;
 use std::io::{Read, Seek};
@@ -34,8 +38,15 @@ impl HwpxParser {
         });
 
         // 2. content.hpf (OPF) → 메타데이터 + manifest
-        let opf = opf::parse_opf(&mut archive)?;
+        let mut opf = opf::parse_opf(&mut archive)?;
         document.meta = opf.metadata;
+        // 모델에 없는 파트는 writer가 그대로 다시 쓸 수 있게 내용을 보관
+        for entry in &mut opf.extra_items {
+            entry.data = utils::read_zip_entry_bytes(&mut archive, &entry.href).unwrap_or_default();
+        }
+        if let Some(ref mut hints) = document.hwpx_hints {
+            hints.extra_manifest_entries = opf.extra_items;
+        }
 
         // 3. header.xml → Resources
         let header_path = opf.header_path.as_deref().unwrap_or("Contents/header.xml");
@@ -44,7 +55,9 @@ impl HwpxParser {
 
         // 4. section*.xml → Sections
         for section_path in &opf.section_paths {
-            let section = body::parse_section(&mut archive, section_path)?;
+            let mut section = body::parse_section(&mut archive, section_path)?;
+            load_master_pages(&mut archive, &opf.master_page_items, &mut section)?;
+            load_charts(&mut archive, &mut section.paragraphs);
             document.sections.push(section);
         }
 
@@ -54,3 +67,86 @@ impl HwpxParser {
         Ok(document)
     }
 }
+
+/// secPr의 `<masterPage idRef>` 참조를 manifest의 masterpageN.xml 내용으로 채운다.
+fn load_master_pages<R: Read + Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    items: &[(String, String)],
+    section: &mut hwp_model::section::Section,
+) -> Result<(), HwpxError> {
+    let refs = std::mem::take(&mut section.definition.master_pages);
+    for master_ref in refs {
+        let Some(id_ref) = master_ref.id_ref else {
+            continue;
+        };
+        let id = format!("masterpage{}", id_ref);
+        let Some((_, href)) = items.iter().find(|(item_id, _)| *item_id == id) else {
+            continue;
+        };
+        if let Some(mut master) = body::parse_master_page(archive, href)? {
+            master.id_ref = Some(id_ref);
+            section.definition.master_pages.push(master);
+        }
+    }
+    Ok(())
+}
+
+/// `<hp:chart chartIDRef>`가 가리키는 차트 파트(Chart/chartN.xml)를 읽어 차트 데이터를 채운다.
+/// 파트가 없거나 읽을 수 없는 차트는 데이터 없이 둔다.
+fn load_charts<R: Read + Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    paragraphs: &mut [hwp_model::paragraph::Paragraph],
+) {
+    use hwp_model::control::Control;
+    use hwp_model::paragraph::RunContent;
+
+    for para in paragraphs {
+        for run in &mut para.runs {
+            for content in &mut run.contents {
+                match content {
+                    RunContent::Object(object) => load_object_charts(archive, object),
+                    RunContent::Control(Control::Header(hf) | Control::Footer(hf)) => {
+                        load_charts(archive, &mut hf.content.paragraphs)
+                    }
+                    RunContent::Control(Control::FootNote(note) | Control::EndNote(note)) => {
+                        load_charts(archive, &mut note.content.paragraphs)
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+fn load_object_charts<R: Read + Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    object: &mut hwp_model::shape::ShapeObject,
+) {
+    use hwp_model::shape::ShapeObject;
+
+    match object {
+        ShapeObject::Chart(chart) => {
+            if let Some(path) = chart.chart_id_ref.as_deref() {
+                chart.chart = utils::read_zip_entry_string(archive, path.trim_start_matches('/'))
+                    .ok()
+                    .and_then(|xml| chart::parse_chart_xml(&xml).ok());
+            }
+        }
+        ShapeObject::Table(table) => {
+            for cell in table.rows.iter_mut().flat_map(|row| row.cells.iter_mut()) {
+                load_charts(archive, &mut cell.content.paragraphs);
+            }
+        }
+        ShapeObject::Container(container) => {
+            for child in &mut container.children {
+                load_object_charts(archive, child);
+            }
+        }
+        ShapeObject::Rectangle(rect) => {
+            if let Some(list) = rect.draw_text.as_mut() {
+                load_charts(archive, &mut list.paragraphs);
+            }
+        }
+        _ => {}
+    }
+}
@@ -2,6 +2,7 @@ use crate::error::HwpxError;
 use crate::ocf::BinaryItemInfo;
 use crate::utils::{attr_str, local_name, read_zip_entry_string};
 use hwp_model::document::DocumentMeta;
+use hwp_model::hints::ManifestEntry;
 use quick_xml::events::Event;
 use quick_xml::Reader;
 use std::io::{Read, Seek};
@@ -11,6 +12,10 @@ pub struct OpfInfo {
     pub header_path: Option<String>,
     pub section_paths: Vec<String>,
     pub binary_items: Vec<BinaryItemInfo>,
+    /// 바탕쪽 파트: (manifest id, href)
+    pub master_page_items: Vec<(String, String)>,
+    /// header/section/BinData 외의 항목 (settings.xml 등)
+    pub extra_items: Vec<ManifestEntry>,
 }
 
 /// content.hpf (OPF) 파싱
@@ -27,6 +32,8 @@ pub fn parse_opf<R: Read + Seek>(archive: &mut zip::ZipArchive<R>) -> Result<Opf
         header_path: None,
         section_paths: Vec::new(),
         binary_items: Vec::new(),
+        master_page_items: Vec::new(),
+        extra_items: Vec::new(),
     };
 
     // manifest items 수집
@@ -138,6 +145,8 @@ pub fn parse_opf<R: Read + Seek>(archive: &mut zip::ZipArchive<R>) -> Result<Opf
     for (id, href, media_type) in &manifest_items {
         if id == "header" || href.contains("header.xml") {
             info.header_path = Some(href.clone());
+        } else if id.starts_with("masterpage") || href.contains("masterpage") {
+            info.master_page_items.push((id.clone(), href.clone()));
         } else if id.starts_with("section") || href.contains("section") {
             info.section_paths.push(href.clone());
         } else if href.starts_with("BinData/") || media_type.starts_with("image/") {
@@ -146,6 +155,13 @@ pub fn parse_opf<R: Read + Seek>(archive: &mut zip::ZipArchive<R>) -> Result<Opf
                 href: href.clone(),
                 media_type: media_type.clone(),
             });
+        } else {
+            info.extra_items.push(ManifestEntry {
+                id: id.clone(),
+                href: href.clone(),
+                media_type: media_type.clone(),
+                ..Default::default()
+            });
         }
     }
 
@@ -33,7 +33,10 @@ pub fn read_zip_entry_bytes<R: Read + Seek>(
 pub fn attr_str(e: &BytesStart, name: &[u8]) -> Option<String> {
     e.attributes().filter_map(|a| a.ok()).find_map(|a| {
         if a.key.as_ref() == name {
-            String::from_utf8(a.value.to_vec()).ok()
+            match a.unescape_value() {
+                Ok(v) => Some(v.into_owned()),
+                Err(_) => String::from_utf8(a.value.to_vec()).ok(),
+            }
         } else {
             None
         }
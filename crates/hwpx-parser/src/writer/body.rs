@@ -0,0 +1,1819 @@
+use super::header::{
+    halign_str, image_effect_str, line_type2_str, line_type3_str, write_fill_brush,
+};
+use super::xml::{Attrs, XmlWriter, OWPML_NAMESPACES};
+use hwp_model::control::*;
+use hwp_model::hints::LineSegmentInfo;
+use hwp_model::paragraph::*;
+use hwp_model::resources::FillBrush;
+use hwp_model::section::*;
+use hwp_model::shape::*;
+use hwp_model::table::*;
+use hwp_model::types::*;
+
+/// Section → section*.xml
+///
+/// `master_page_base`는 이 구역 첫 바탕쪽의 파트 번호 (masterpageN, 문서 전체에서 연속)
+pub fn write_section(section: &Section, master_page_base: usize) -> String {
+    let mut bw = BodyWriter {
+        w: XmlWriter::new(),
+        open_fields: Vec::new(),
+        container_depth: 0,
+        master_page_base,
+    };
+    bw.w.start("hs:sec", Attrs::new().namespaces(OWPML_NAMESPACES));
+
+    for (i, para) in section.paragraphs.iter().enumerate() {
+        let sec_def = if i == 0 {
+            Some(&section.definition)
+        } else {
+            None
+        };
+        bw.write_paragraph(para, sec_def);
+    }
+
+    // 문단이 하나도 없으면 secPr을 담을 빈 문단 생성
+    if section.paragraphs.is_empty() {
+        bw.write_paragraph(&Paragraph::default(), Some(&section.definition));
+    }
+
+    bw.w.end("hs:sec");
+    bw.w.finish()
+}
+
+/// MasterPage → Contents/masterpageN.xml
+pub fn write_master_page(master: &MasterPage, index: usize) -> String {
+    let mut bw = BodyWriter {
+        w: XmlWriter::new(),
+        open_fields: Vec::new(),
+        container_depth: 0,
+        master_page_base: 0,
+    };
+    // 첫 쪽 바탕쪽은 HWPX의 임의 쪽(OPTIONAL_PAGE)으로 기록
+    let (apply, page_number) = match master.apply_page_type {
+        PageApplyType::First => ("OPTIONAL_PAGE", master.page_number.max(1)),
+        ref t => (page_apply_type_str(t), 0),
+    };
+    bw.w.start(
+        "masterPage",
+        Attrs::new()
+            .namespaces(OWPML_NAMESPACES)
+            .add("id", format!("masterpage{}", index))
+            .add("type", apply)
+            .add("pageNumber", page_number)
+            .flag("pageDuplicate", master.page_duplicate)
+            .flag("pageFront", master.page_front),
+    );
+    bw.write_sublist(&master.content);
+    bw.w.end("masterPage");
+    bw.w.finish()
+}
+
+struct BodyWriter {
+    w: XmlWriter,
+    /// 열린 fieldBegin (id, fieldid) 스택 — fieldEnd의 beginIDRef 복원용
+    open_fields: Vec<(u64, Option<u32>)>,
+    /// 묶음 개체 중첩 깊이 — 자식 개체는 sz/pos를 갖지 않는다
+    container_depth: usize,
+    /// 이 구역 첫 바탕쪽의 파트 번호
+    master_page_base: usize,
+}
+
+impl BodyWriter {
+    // ═══════════════════════════════════════════
+    // 문단 / run / 텍스트
+    // ═══════════════════════════════════════════
+
+    fn write_paragraph(&mut self, para: &Paragraph, sec_def: Option<&SectionDef>) {
+        self.w.start(
+            "hp:p",
+            Attrs::new()
+                .add("id", para.id)
+                .add("paraPrIDRef", para.para_shape_id)
+                .add("styleIDRef", para.style_id)
+                .flag("pageBreak", para.page_break)
+                .flag("columnBreak", para.column_break)
+                .flag("merged", para.merged)
+                .opt("paraTcId", para.para_tc_id.as_ref()),
+        );
+
+        // secPr은 첫 문단의 첫 run 맨 앞에 위치
+        if let Some(sd) = sec_def {
+            let char_shape_id = para.runs.first().map(|r| r.char_shape_id).unwrap_or(0);
+            self.w
+                .start("hp:run", Attrs::new().add("charPrIDRef", char_shape_id));
+            self.write_sec_pr(sd);
+            let has_column_ctrl = para.runs.iter().any(|r| {
+                r.contents
+                    .iter()
+                    .any(|c| matches!(c, RunContent::Control(Control::Column(_))))
+            });
+            if let (Some(ref cols), false) = (&sd.columns, has_column_ctrl) {
+                self.w.start("hp:ctrl", Attrs::new());
+                self.write_col_pr(&ColumnControl {
+                    id: cols.id,
+                    column_type: cols.column_type.clone(),
+                    col_count: cols.col_count,
+                    layout: cols.layout.clone(),
+                    same_size: cols.same_size,
+                    same_gap: cols.same_gap,
+                });
+                self.w.end("hp:ctrl");
+            }
+            self.w.end("hp:run");
+        }
+
+        for run in &para.runs {
+            self.write_run(run);
+        }
+        if para.runs.is_empty() && sec_def.is_none() {
+            self.w.empty("hp:run", Attrs::new().add("charPrIDRef", 0));
+        }
+
+        if !para.line_segments.is_empty() {
+            self.write_linesegarray(&para.line_segments);
+        }
+        self.w.end("hp:p");
+    }
+
+    fn write_run(&mut self, run: &Run) {
+        let attrs = Attrs::new().add("charPrIDRef", run.char_shape_id);
+        if run.contents.is_empty() {
+            self.w.empty("hp:run", attrs);
+            return;
+        }
+        self.w.start("hp:run", attrs);
+        for content in &run.contents {
+            match content {
+                RunContent::Text(tc) => self.write_text_content(tc),
+                RunContent::Control(ctrl) => self.write_ctrl(ctrl),
+                RunContent::Object(obj) => self.write_shape_object(obj),
+            }
+        }
+        self.w.end("hp:run");
+    }
+
+    fn write_text_content(&mut self, tc: &TextContent) {
+        let attrs = Attrs::new().opt("charPrIDRef", tc.char_shape_id);
+        if tc.elements.is_empty() {
+            self.w.empty("hp:t", attrs);
+            return;
+        }
+        self.w.start("hp:t", attrs);
+        for el in &tc.elements {
+            match el {
+                TextElement::Text(s) => self.w.text(s),
+                TextElement::Tab {
+                    width,
+                    leader,
+                    tab_type,
+                } => self.w.empty(
+                    "hp:tab",
+                    Attrs::new()
+                        .add("width", width)
+                        .add("leader", line_type2_str(leader))
+                        .add("type", tab_type_str(tab_type)),
+                ),
+                TextElement::LineBreak => self.w.empty("hp:lineBreak", Attrs::new()),
+                TextElement::Hyphen => self.w.empty("hp:hyphen", Attrs::new()),
+                TextElement::NbSpace => self.w.empty("hp:nbSpace", Attrs::new()),
+                TextElement::FwSpace => self.w.empty("hp:fwSpace", Attrs::new()),
+                TextElement::MarkpenBegin { color } => self
+                    .w
+                    .empty("hp:markpenBegin", Attrs::new().color("color", *color)),
+                TextElement::MarkpenEnd => self.w.empty("hp:markpenEnd", Attrs::new()),
+                TextElement::TitleMark { ignore } => self
+                    .w
+                    .empty("hp:titleMark", Attrs::new().flag("ignore", *ignore)),
+                TextElement::InsertBegin {
+                    id,
+                    tc_id,
+                    para_end,
+                } => self.write_track_change_mark("hp:insertBegin", id, tc_id, *para_end),
+                TextElement::InsertEnd {
+                    id,
+                    tc_id,
+                    para_end,
+                } => self.write_track_change_mark("hp:insertEnd", id, tc_id, *para_end),
+                TextElement::DeleteBegin {
+                    id,
+                    tc_id,
+                    para_end,
+                } => self.write_track_change_mark("hp:deleteBegin", id, tc_id, *para_end),
+                TextElement::DeleteEnd {
+                    id,
+                    tc_id,
+                    para_end,
+                } => self.write_track_change_mark("hp:deleteEnd", id, tc_id, *para_end),
+            }
+        }
+        self.w.end("hp:t");
+    }
+
+    fn write_track_change_mark(
+        &mut self,
+        tag: &str,
+        id: &str,
+        tc_id: &Option<String>,
+        para_end: bool,
+    ) {
+        self.w.empty(
+            tag,
+            Attrs::new()
+                .add("Id", id)
+                .opt("TcId", tc_id.as_ref())
+                .flag("paraend", para_end),
+        );
+    }
+
+    fn write_linesegarray(&mut self, segments: &[LineSegmentInfo]) {
+        self.w.start("hp:linesegarray", Attrs::new());
+        for seg in segments {
+            self.w.empty(
+                "hp:lineseg",
+                Attrs::new()
+                    .add("textpos", seg.text_start_pos)
+                    .add("vertpos", seg.vertical_pos)
+                    .add("vertsize", seg.line_height)
+                    .add("textheight", seg.text_height)
+                    .add("baseline", seg.baseline_distance)
+                    .add("spacing", seg.line_spacing)
+                    .add("horzpos", seg.column_start_pos)
+                    .add("horzsize", seg.segment_width)
+                    .add("flags", seg.flags),
+            );
+        }
+        self.w.end("hp:linesegarray");
+    }
+
+    fn write_sublist(&mut self, sl: &SubList) {
+        self.w.start(
+            "hp:subList",
+            Attrs::new()
+                .add("id", sl.id)
+                .add("textDirection", text_direction_str(&sl.text_direction))
+                .add("lineWrap", "BREAK")
+                .add("vertAlign", valign_str(&sl.vert_align))
+                .add("linkListIDRef", sl.link_list_id.unwrap_or(0))
+                .add("linkListNextIDRef", sl.link_list_next_id.unwrap_or(0))
+                .add("textWidth", sl.text_width.unwrap_or(0))
+                .add("textHeight", sl.text_height.unwrap_or(0))
+                .flag("hasTextRef", sl.has_text_ref)
+                .flag("hasNumRef", sl.has_num_ref),
+        );
+        for para in &sl.paragraphs {
+            self.write_paragraph(para, None);
+        }
+        if sl.paragraphs.is_empty() {
+            self.write_paragraph(&Paragraph::default(), None);
+        }
+        self.w.end("hp:subList");
+    }
+
+    // ═══════════════════════════════════════════
+    // secPr
+    // ═══════════════════════════════════════════
+
+    fn write_sec_pr(&mut self, sd: &SectionDef) {
+        self.w.start(
+            "hp:secPr",
+            Attrs::new()
+                .add("id", sd.id)
+                .add("textDirection", text_direction_str(&sd.text_direction))
+                .add("spaceColumns", sd.space_columns)
+                .add("tabStop", sd.tab_stop)
+                .opt("outlineShapeIDRef", sd.outline_shape_id)
+                .opt("memoShapeIDRef", sd.memo_shape_id)
+                .add("textVerticalWidthHead", "0")
+                .opt(
+                    "masterPageCnt",
+                    sd.master_page_cnt
+                        .or((!sd.master_pages.is_empty()).then_some(sd.master_pages.len() as u16)),
+                ),
+        );
+
+        if let Some(ref g) = sd.grid {
+            self.w.empty(
+                "hp:grid",
+                Attrs::new()
+                    .add("lineGrid", g.line_grid)
+                    .add("charGrid", g.char_grid)
+                    .flag("wonggojiFormat", g.wonggoji_format),
+            );
+        }
+        if let Some(ref sn) = sd.start_num {
+            self.w.empty(
+                "hp:startNum",
+                Attrs::new()
+                    .add("pageStartsOn", page_starts_on_str(&sn.page_starts_on))
+                    .add("page", sn.page)
+                    .add("pic", sn.pic)
+                    .add("tbl", sn.tbl)
+                    .add("equation", sn.equation),
+            );
+        }
+        if let Some(ref v) = sd.visibility {
+            self.w.empty(
+                "hp:visibility",
+                Attrs::new()
+                    .flag("hideFirstHeader", v.hide_first_header)
+                    .flag("hideFirstFooter", v.hide_first_footer)
+                    .flag("hideFirstMasterPage", v.hide_first_master_page)
+                    .add("border", visibility_value_str(&v.border))
+                    .add("fill", visibility_value_str(&v.fill))
+                    .flag("hideFirstPageNum", v.hide_first_page_num)
+                    .flag("hideFirstEmptyLine", v.hide_first_empty_line)
+                    .flag("showLineNumber", v.show_line_number),
+            );
+        }
+        if let Some(ref ln) = sd.line_number {
+            self.w.empty(
+                "hp:lineNumberShape",
+                Attrs::new()
+                    .add("restartType", line_number_restart_str(&ln.restart_type))
+                    .add("countBy", ln.count_by)
+                    .add("distance", ln.distance)
+                    .add("startNumber", ln.start_number),
+            );
+        }
+
+        let page = &sd.page;
+        self.w.start(
+            "hp:pagePr",
+            Attrs::new()
+                .add("landscape", landscape_str(&page.landscape))
+                .add("width", page.width)
+                .add("height", page.height)
+                .add("gutterType", gutter_type_str(&page.gutter_type)),
+        );
+        let m = &page.margin;
+        self.w.empty(
+            "hp:margin",
+            Attrs::new()
+                .add("header", m.header)
+                .add("footer", m.footer)
+                .add("gutter", m.gutter)
+                .add("left", m.left)
+                .add("right", m.right)
+                .add("top", m.top)
+                .add("bottom", m.bottom),
+        );
+        self.w.end("hp:pagePr");
+
+        if let Some(ref fn_def) = sd.footnote {
+            self.w.start("hp:footNotePr", Attrs::new());
+            self.write_note_format(
+                &fn_def.number_format,
+                fn_def.user_char,
+                fn_def.prefix_char,
+                fn_def.suffix_char,
+                fn_def.superscript,
+            );
+            self.write_note_line_spacing(fn_def.note_line.as_ref(), fn_def.note_spacing.as_ref());
+            self.w.empty(
+                "hp:numbering",
+                Attrs::new()
+                    .add("type", footnote_numbering_str(&fn_def.numbering_type))
+                    .add("newNum", fn_def.start_number),
+            );
+            self.w.empty(
+                "hp:placement",
+                Attrs::new()
+                    .add("place", footnote_placement_str(&fn_def.placement))
+                    .flag("beneathText", fn_def.beneath_text),
+            );
+            self.w.end("hp:footNotePr");
+        }
+        if let Some(ref en_def) = sd.endnote {
+            self.w.start("hp:endNotePr", Attrs::new());
+            self.write_note_format(
+                &en_def.number_format,
+                en_def.user_char,
+                en_def.prefix_char,
+                en_def.suffix_char,
+                en_def.superscript,
+            );
+            self.write_note_line_spacing(en_def.note_line.as_ref(), en_def.note_spacing.as_ref());
+            self.w.empty(
+                "hp:numbering",
+                Attrs::new()
+                    .add("type", endnote_numbering_str(&en_def.numbering_type))
+                    .add("newNum", en_def.start_number),
+            );
+            self.w.empty(
+                "hp:placement",
+                Attrs::new()
+                    .add("place", endnote_placement_str(&en_def.placement))
+                    .flag("beneathText", en_def.beneath_text),
+            );
+            self.w.end("hp:endNotePr");
+        }
+
+        // 모델에는 type(BOTH/EVEN/ODD)이 없으므로 한글 저장 순서대로 복원
+        for (i, pbf) in sd.page_border_fills.iter().enumerate() {
+            let apply = ["BOTH", "EVEN", "ODD"].get(i).copied().unwrap_or("BOTH");
+            self.w.start(
+                "hp:pageBorderFill",
+                Attrs::new()
+                    .add("type", apply)
+                    .add("borderFillIDRef", pbf.border_fill_id)
+                    .add("textBorder", page_border_ref_str(&pbf.text_border))
+                    .flag("headerInside", pbf.header_inside)
+                    .flag("footerInside", pbf.footer_inside)
+                    .add("fillArea", fill_area_str(&pbf.fill_area)),
+            );
+            self.write_margin("hp:offset", &pbf.offset);
+            self.w.end("hp:pageBorderFill");
+        }
+
+        for i in 0..sd.master_pages.len() {
+            self.w.empty(
+                "hp:masterPage",
+                Attrs::new().add("idRef", format!("masterpage{}", self.master_page_base + i)),
+            );
+        }
+
+        self.w.end("hp:secPr");
+    }
+
+    fn write_note_format(
+        &mut self,
+        number_format: &NumberType1,
+        user_char: Option<char>,
+        prefix_char: Option<char>,
+        suffix_char: Option<char>,
+        superscript: bool,
+    ) {
+        let ch = |c: Option<char>| c.map(String::from).unwrap_or_default();
+        self.w.empty(
+            "hp:autoNumFormat",
+            Attrs::new()
+                .add("type", number_type1_str(number_format))
+                .add("userChar", ch(user_char))
+                .add("prefixChar", ch(prefix_char))
+                .add("suffixChar", ch(suffix_char))
+                .flag("supscript", superscript),
+        );
+    }
+
+    fn write_note_line_spacing(&mut self, line: Option<&NoteLine>, spacing: Option<&NoteSpacing>) {
+        if let Some(nl) = line {
+            self.w.empty(
+                "hp:noteLine",
+                Attrs::new()
+                    .add("length", nl.length)
+                    .add("type", line_type3_str(&nl.line_type))
+                    .add("width", &nl.width)
+                    .color("color", nl.color),
+            );
+        }
+        if let Some(ns) = spacing {
+            self.w.empty(
+                "hp:noteSpacing",
+                Attrs::new()
+                    .add("betweenNotes", ns.between_notes)
+                    .add("belowLine", ns.below_line)
+                    .add("aboveLine", ns.above_line),
+            );
+        }
+    }
+
+    // ═══════════════════════════════════════════
+    // ctrl
+    // ═══════════════════════════════════════════
+
+    fn write_ctrl(&mut self, ctrl: &Control) {
+        self.w.start("hp:ctrl", Attrs::new());
+        match ctrl {
+            Control::Column(col) => self.write_col_pr(col),
+            Control::Header(hf) => self.write_header_footer("hp:header", hf),
+            Control::Footer(hf) => self.write_header_footer("hp:footer", hf),
+            Control::FootNote(note) => self.write_note("hp:footNote", note),
+            Control::EndNote(note) => self.write_note("hp:endNote", note),
+            Control::AutoNum(an) => {
+                self.w.start(
+                    "hp:autoNum",
+                    Attrs::new()
+                        .add("num", an.num)
+                        .add("numType", auto_num_type_str(&an.num_type)),
+                );
+                self.w.empty(
+                    "hp:autoNumFormat",
+                    Attrs::new()
+                        .add("type", number_type1_str(&an.number_type))
+                        .add("userChar", an.user_char.clone().unwrap_or_default())
+                        .add("prefixChar", an.prefix_char.clone().unwrap_or_default())
+                        .add("suffixChar", an.suffix_char.clone().unwrap_or_default())
+                        .flag("supscript", false),
+                );
+                self.w.end("hp:autoNum");
+            }
+            Control::NewNum(nn) => self.w.empty(
+                "hp:newNum",
+                Attrs::new()
+                    .add("num", nn.num)
+                    .add("numType", numbering_type_str(&nn.num_type)),
+            ),
+            Control::PageNumCtrl(pnc) => self.w.empty(
+                "hp:pageNumCtrl",
+                Attrs::new().add(
+                    "pageStartsOn",
+                    page_starts_on_str(&pnc.page_starts_on.clone().unwrap_or_default()),
+                ),
+            ),
+            Control::PageHiding(ph) => self.w.empty(
+                "hp:pageHiding",
+                Attrs::new()
+                    .flag("hideHeader", ph.hide_header)
+                    .flag("hideFooter", ph.hide_footer)
+                    .flag("hideMasterPage", ph.hide_master_page)
+                    .flag("hideBorder", ph.hide_border)
+                    .flag("hideFill", ph.hide_fill)
+                    .flag("hidePageNum", ph.hide_page_num),
+            ),
+            Control::Bookmark(bm) => self
+                .w
+                .empty("hp:bookmark", Attrs::new().add("name", &bm.name)),
+            Control::FieldBegin(field) => self.write_field_begin(field),
+            Control::FieldEnd => {
+                let (begin_id, field_id) = self.open_fields.pop().unwrap_or((0, None));
+                self.w.empty(
+                    "hp:fieldEnd",
+                    Attrs::new()
+                        .add("beginIDRef", begin_id)
+                        .opt("fieldid", field_id),
+                );
+            }
+            Control::Compose(c) => {
+                let attrs = Attrs::new()
+                    .opt("circleType", c.circle_type.as_ref())
+                    .opt("charSz", c.char_sz)
+                    .opt("composeType", c.compose_type.as_ref())
+                    .add("charPrCnt", c.char_pr_refs.len())
+                    .opt("composeText", c.compose_text.as_ref());
+                if c.char_pr_refs.is_empty() {
+                    self.w.empty("hp:compose", attrs);
+                } else {
+                    self.w.start("hp:compose", attrs);
+                    for id in &c.char_pr_refs {
+                        self.w.empty("hp:charPr", Attrs::new().add("prIDRef", id));
+                    }
+                    self.w.end("hp:compose");
+                }
+            }
+            Control::Dutmal(dm) => {
+                self.w.start(
+                    "hp:dutmal",
+                    Attrs::new()
+                        .add("posType", dutmal_position_str(&dm.position))
+                        .opt("szRatio", dm.sz_ratio)
+                        .opt("option", dm.option)
+                        .opt("styleIDRef", dm.style_id_ref)
+                        .add("align", halign_str(&dm.alignment)),
+                );
+                self.w
+                    .text_element("hp:mainText", Attrs::new(), &dm.main_text);
+                self.w
+                    .text_element("hp:subText", Attrs::new(), &dm.sub_text);
+                self.w.end("hp:dutmal");
+            }
+            Control::HiddenDesc(hd) => {
+                self.w.start("hp:hiddenComment", Attrs::new());
+                self.write_sublist(&SubList {
+                    paragraphs: hd.paragraphs.clone(),
+                    ..Default::default()
+                });
+                self.w.end("hp:hiddenComment");
+            }
+        }
+        self.w.end("hp:ctrl");
+    }
+
+    fn write_col_pr(&mut self, col: &ColumnControl) {
+        self.w.empty(
+            "hp:colPr",
+            Attrs::new()
+                .add("id", col.id)
+                .add("type", column_type_str(&col.column_type))
+                .add("layout", column_layout_str(&col.layout))
+                .add("colCount", col.col_count)
+                .flag("sameSz", col.same_size)
+                .add("sameGap", col.same_gap),
+        );
+    }
+
+    fn write_header_footer(&mut self, tag: &str, hf: &HeaderFooter) {
+        self.w.start(
+            tag,
+            Attrs::new()
+                .add("id", hf.id)
+                .add("applyPageType", page_apply_type_str(&hf.apply_page_type)),
+        );
+        self.write_sublist(&hf.content);
+        self.w.end(tag);
+    }
+
+    fn write_note(&mut self, tag: &str, note: &Note) {
+        self.w.start(
+            tag,
+            Attrs::new().opt("number", note.number).add("id", note.id),
+        );
+        self.write_sublist(&note.content);
+        self.w.end(tag);
+    }
+
+    fn write_field_begin(&mut self, field: &Field) {
+        self.open_fields.push((field.id, field.field_id));
+        self.w.start(
+            "hp:fieldBegin",
+            Attrs::new()
+                .add("id", field.id)
+                .add("type", field_type_str(&field.field_type))
+                .add("name", field.name.clone().unwrap_or_default())
+                .flag("editable", field.editable)
+                .flag("dirty", field.dirty)
+                .opt("zorder", field.z_order)
+                .opt("fieldid", field.field_id),
+        );
+        if !field.parameters.is_empty() {
+            self.w.start(
+                "hp:parameters",
+                Attrs::new()
+                    .add("cnt", field.parameters.len())
+                    .add("name", ""),
+            );
+            for param in &field.parameters {
+                self.write_field_parameter(param);
+            }
+            self.w.end("hp:parameters");
+        }
+        if let Some(ref sl) = field.sub_list {
+            self.write_sublist(sl);
+        }
+        if let Some(ref tag) = field.meta_tag {
+            self.w.text_element("hp:metaTag", Attrs::new(), tag);
+        }
+        self.w.end("hp:fieldBegin");
+    }
+
+    fn write_field_parameter(&mut self, param: &FieldParameter) {
+        match param {
+            FieldParameter::Integer { name, value } => self.w.text_element(
+                "hp:integerParam",
+                Attrs::new().add("name", name),
+                &value.to_string(),
+            ),
+            FieldParameter::String { name, value } => {
+                self.w
+                    .text_element("hp:stringParam", Attrs::new().add("name", name), value)
+            }
+            FieldParameter::Element { name, children } => {
+                self.w.start(
+                    "hp:listParam",
+                    Attrs::new().add("cnt", children.len()).add("name", name),
+                );
+                for child in children {
+                    self.write_field_parameter(child);
+                }
+                self.w.end("hp:listParam");
+            }
+            FieldParameter::List { name, items } => {
+                self.w.start(
+                    "hp:listParam",
+                    Attrs::new().add("cnt", items.len()).add("name", name),
+                );
+                for item in items {
+                    self.write_field_parameter(item);
+                }
+                self.w.end("hp:listParam");
+            }
+        }
+    }
+
+    // ═══════════════════════════════════════════
+    // 개체
+    // ═══════════════════════════════════════════
+
+    fn write_shape_object(&mut self, obj: &ShapeObject) {
+        match obj {
+            ShapeObject::Table(t) => self.write_table(t),
+            ShapeObject::Picture(p) => self.write_picture(p),
+            ShapeObject::Line(o) => self.write_line(o),
+            ShapeObject::Rectangle(o) => self.write_rect(o),
+            ShapeObject::Ellipse(o) => self.write_ellipse(o),
+            ShapeObject::Arc(o) => self.write_arc(o),
+            ShapeObject::Polygon(o) => self.write_polygon(o),
+            ShapeObject::Curve(o) => self.write_curve(o),
+            ShapeObject::ConnectLine(o) => self.write_connect_line(o),
+            ShapeObject::TextArt(o) => self.write_textart(o),
+            ShapeObject::Container(o) => self.write_container(o),
+            ShapeObject::Ole(o) => self.write_ole(o),
+            ShapeObject::Equation(o) => self.write_equation(o),
+            ShapeObject::Chart(o) => self.write_chart(o),
+            ShapeObject::Video(o) => self.write_video(o),
+            ShapeObject::Form(o) => self.write_form(o),
+        }
+    }
+
+    fn write_table(&mut self, tbl: &Table) {
+        let attrs = common_attrs(&tbl.common)
+            .add("pageBreak", table_page_break_str(&tbl.page_break))
+            .flag("repeatHeader", tbl.repeat_header)
+            .add("rowCnt", tbl.row_count)
+            .add("colCnt", tbl.col_count)
+            .add("cellSpacing", tbl.cell_spacing)
+            .add("borderFillIDRef", tbl.border_fill_id)
+            .opt("noAdjust", tbl.no_adjust.map(|b| if b { "1" } else { "0" }));
+        self.w.start("hp:tbl", attrs);
+        self.write_common_layout(&tbl.common);
+        self.write_margin("hp:inMargin", &tbl.in_margin);
+        if !tbl.cell_zones.is_empty() {
+            self.w.start("hp:cellzoneList", Attrs::new());
+            for z in &tbl.cell_zones {
+                self.w.empty(
+                    "hp:cellzone",
+                    Attrs::new()
+                        .add("startRowAddr", z.start_row)
+                        .add("startColAddr", z.start_col)
+                        .add("endRowAddr", z.end_row)
+                        .add("endColAddr", z.end_col)
+                        .add("borderFillIDRef", z.border_fill_id),
+                );
+            }
+            self.w.end("hp:cellzoneList");
+        }
+        for row in &tbl.rows {
+            self.w.start("hp:tr", Attrs::new());
+            for cell in &row.cells {
+                self.write_table_cell(cell);
+            }
+            self.w.end("hp:tr");
+        }
+        self.w.end("hp:tbl");
+    }
+
+    fn write_table_cell(&mut self, cell: &TableCell) {
+        self.w.start(
+            "hp:tc",
+            Attrs::new()
+                .add("name", cell.name.clone().unwrap_or_default())
+                .flag("header", cell.header)
+                .opt(
+                    "hasMargin",
+                    cell.has_margin.map(|b| if b { "1" } else { "0" }),
+                )
+                .flag("protect", cell.protect)
+                .flag("editable", cell.editable)
+                .opt("dirty", cell.dirty.map(|b| if b { "1" } else { "0" }))
+                .add("borderFillIDRef", cell.border_fill_id),
+        );
+        self.write_sublist(&cell.content);
+        self.w.empty(
+            "hp:cellAddr",
+            Attrs::new()
+                .add("colAddr", cell.col)
+                .add("rowAddr", cell.row),
+        );
+        self.w.empty(
+            "hp:cellSpan",
+            Attrs::new()
+                .add("colSpan", cell.col_span.max(1))
+                .add("rowSpan", cell.row_span.max(1)),
+        );
+        self.w.empty(
+            "hp:cellSz",
+            Attrs::new()
+                .add("width", cell.width)
+                .add("height", cell.height),
+        );
+        self.write_margin("hp:cellMargin", &cell.cell_margin);
+        self.w.end("hp:tc");
+    }
+
+    fn write_picture(&mut self, pic: &Picture) {
+        let attrs = common_attrs(&pic.common)
+            .extend_component(&pic.component)
+            .opt("reverse", pic.reverse.map(|b| if b { "1" } else { "0" }));
+        self.w.start("hp:pic", attrs);
+        self.write_component(&pic.component);
+        if let Some(ref ls) = pic.line_shape {
+            self.write_line_shape(ls);
+        }
+        if let Some(ref pts) = pic.img_rect {
+            self.w.start("hp:imgRect", Attrs::new());
+            for (i, pt) in pts.iter().enumerate() {
+                self.write_point(PT_TAGS[i], pt);
+            }
+            self.w.end("hp:imgRect");
+        }
+        if let Some(ref clip) = pic.img_clip {
+            self.write_margin("hp:imgClip", clip);
+        }
+        if let Some(ref m) = pic.in_margin {
+            self.write_margin("hp:inMargin", m);
+        }
+        if let Some(ref dim) = pic.img_dim {
+            self.w.empty(
+                "hp:imgDim",
+                Attrs::new()
+                    .add("dimwidth", dim.width)
+                    .add("dimheight", dim.height),
+            );
+        }
+        self.w.empty(
+            "hc:img",
+            Attrs::new()
+                .add("binaryItemIDRef", &pic.img.binary_item_id)
+                .add("bright", pic.img.bright)
+                .add("contrast", pic.img.contrast)
+                .add("effect", image_effect_str(&pic.img.effect))
+                .add("alpha", pic.img.alpha),
+        );
+        match pic.effects {
+            Some(ref fx) => self.write_picture_effects(fx),
+            None => self.w.empty("hp:effects", Attrs::new()),
+        }
+        self.write_common_layout(&pic.common);
+        self.w.end("hp:pic");
+    }
+
+    fn write_picture_effects(&mut self, fx: &PictureEffects) {
+        self.w.start("hp:effects", Attrs::new());
+        if let Some(ref s) = fx.shadow {
+            self.w.empty(
+                "hp:shadow",
+                Attrs::new()
+                    .add(
+                        "style",
+                        match s.style {
+                            ShadowStyle::Outside => "OUTSIDE",
+                            ShadowStyle::Inside => "INSIDE",
+                        },
+                    )
+                    .add("alpha", s.alpha)
+                    .add("radius", s.radius)
+                    .add("direction", s.direction)
+                    .add("distance", s.distance),
+            );
+        }
+        if let Some(ref g) = fx.glow {
+            self.w.empty(
+                "hp:glow",
+                Attrs::new().add("alpha", g.alpha).add("radius", g.radius),
+            );
+        }
+        if let Some(ref se) = fx.soft_edge {
+            self.w
+                .empty("hp:softEdge", Attrs::new().add("radius", se.radius));
+        }
+        if let Some(ref r) = fx.reflection {
+            self.w.empty(
+                "hp:reflection",
+                Attrs::new()
+                    .add("align", halign_str(&r.align))
+                    .add("radius", r.radius)
+                    .add("direction", r.direction)
+                    .add("distance", r.distance),
+            );
+        }
+        self.w.end("hp:effects");
+    }
+
+    fn write_line(&mut self, o: &LineObject) {
+        let attrs = common_attrs(&o.common).extend_component(&o.component).opt(
+            "isReverseHV",
+            o.is_reverse_hv.map(|b| if b { "1" } else { "0" }),
+        );
+        self.w.start("hp:line", attrs);
+        self.write_component(&o.component);
+        self.write_line_shape(&o.line_shape);
+        self.write_shadow(o.shadow.as_ref());
+        self.write_draw_text(o.draw_text.as_ref());
+        self.write_point("hc:startPt", &o.start_pt);
+        self.write_point("hc:endPt", &o.end_pt);
+        self.write_common_layout(&o.common);
+        self.w.end("hp:line");
+    }
+
+    fn write_rect(&mut self, o: &RectObject) {
+        let attrs = common_attrs(&o.common)
+            .extend_component(&o.component)
+            .add("ratio", o.ratio);
+        self.w.start("hp:rect", attrs);
+        self.write_component(&o.component);
+        self.write_line_shape(&o.line_shape);
+        self.write_fill(o.fill.as_ref());
+        self.write_shadow(o.shadow.as_ref());
+        self.write_draw_text(o.draw_text.as_ref());
+        for (i, pt) in o.points.iter().enumerate() {
+            self.write_point(PT_TAGS[i], pt);
+        }
+        self.write_common_layout(&o.common);
+        self.w.end("hp:rect");
+    }
+
+    fn write_ellipse(&mut self, o: &EllipseObject) {
+        let attrs = common_attrs(&o.common)
+            .extend_component(&o.component)
+            .opt(
+                "intervalDirty",
+                o.interval_dirty.map(|b| if b { "1" } else { "0" }),
+            )
+            .flag("hasArcPr", o.has_arc)
+            .add("arcType", arc_type_str(&o.arc_type));
+        self.w.start("hp:ellipse", attrs);
+        self.write_component(&o.component);
+        self.write_line_shape(&o.line_shape);
+        self.write_fill(o.fill.as_ref());
+        self.write_shadow(o.shadow.as_ref());
+        self.write_draw_text(o.draw_text.as_ref());
+        self.write_point("hc:center", &o.center);
+        self.write_point("hc:ax1", &o.axis1);
+        self.write_point("hc:ax2", &o.axis2);
+        self.write_point("hc:start1", &o.start1);
+        self.write_point("hc:end1", &o.end1);
+        self.write_point("hc:start2", &o.start2);
+        self.write_point("hc:end2", &o.end2);
+        self.write_common_layout(&o.common);
+        self.w.end("hp:ellipse");
+    }
+
+    fn write_arc(&mut self, o: &ArcObject) {
+        let attrs = common_attrs(&o.common)
+            .extend_component(&o.component)
+            .add("type", arc_type_str(&o.arc_type));
+        self.w.start("hp:arc", attrs);
+        self.write_component(&o.component);
+        self.write_line_shape(&o.line_shape);
+        self.write_fill(o.fill.as_ref());
+        self.write_shadow(o.shadow.as_ref());
+        self.write_draw_text(o.draw_text.as_ref());
+        self.write_point("hc:center", &o.center);
+        self.write_point("hc:ax1", &o.axis1);
+        self.write_point("hc:ax2", &o.axis2);
+        self.write_common_layout(&o.common);
+        self.w.end("hp:arc");
+    }
+
+    fn write_polygon(&mut self, o: &PolygonObject) {
+        let attrs = common_attrs(&o.common).extend_component(&o.component);
+        self.w.start("hp:polygon", attrs);
+        self.write_component(&o.component);
+        self.write_line_shape(&o.line_shape);
+        self.write_fill(o.fill.as_ref());
+        self.write_shadow(o.shadow.as_ref());
+        self.write_draw_text(o.draw_text.as_ref());
+        for pt in &o.points {
+            self.write_point("hc:pt", pt);
+        }
+        self.write_common_layout(&o.common);
+        self.w.end("hp:polygon");
+    }
+
+    fn write_curve(&mut self, o: &CurveObject) {
+        let attrs = common_attrs(&o.common).extend_component(&o.component);
+        self.w.start("hp:curve", attrs);
+        self.write_component(&o.component);
+        self.write_line_shape(&o.line_shape);
+        self.write_fill(o.fill.as_ref());
+        self.write_shadow(o.shadow.as_ref());
+        self.write_draw_text(o.draw_text.as_ref());
+        for seg in &o.segments {
+            self.w.empty(
+                "hp:seg",
+                Attrs::new()
+                    .add(
+                        "type",
+                        match seg.segment_type {
+                            CurveSegmentType::Curve => "CURVE",
+                            CurveSegmentType::Line => "LINE",
+                        },
+                    )
+                    .add("x1", seg.x1)
+                    .add("y1", seg.y1)
+                    .add("x2", seg.x2)
+                    .add("y2", seg.y2),
+            );
+        }
+        self.write_common_layout(&o.common);
+        self.w.end("hp:curve");
+    }
+
+    fn write_connect_line(&mut self, o: &ConnectLineObject) {
+        let attrs = common_attrs(&o.common)
+            .extend_component(&o.component)
+            .add("type", connect_line_type_str(&o.connect_type));
+        self.w.start("hp:connectLine", attrs);
+        self.write_component(&o.component);
+        self.write_line_shape(&o.line_shape);
+        self.write_shadow(o.shadow.as_ref());
+        for (tag, pt) in [("hp:startPt", &o.start_pt), ("hp:endPt", &o.end_pt)] {
+            self.w.empty(
+                tag,
+                Attrs::new()
+                    .add("x", pt.x)
+                    .add("y", pt.y)
+                    .opt("subjectIDRef", pt.subject_id)
+                    .opt("subjectIdx", pt.subject_idx),
+            );
+        }
+        if !o.control_points.is_empty() {
+            self.w.start("hp:controlPoints", Attrs::new());
+            for cp in &o.control_points {
+                self.w.empty(
+                    "hp:point",
+                    Attrs::new()
+                        .add("x", cp.x)
+                        .add("y", cp.y)
+                        .add("type", cp.point_type),
+                );
+            }
+            self.w.end("hp:controlPoints");
+        }
+        self.write_common_layout(&o.common);
+        self.w.end("hp:connectLine");
+    }
+
+    fn write_textart(&mut self, o: &TextArtObject) {
+        let attrs = common_attrs(&o.common)
+            .extend_component(&o.component)
+            .add("text", &o.text);
+        self.w.start("hp:textart", attrs);
+        self.write_component(&o.component);
+        self.write_line_shape(&o.line_shape);
+        self.write_fill(o.fill.as_ref());
+        self.write_shadow(o.shadow.as_ref());
+        for (i, pt) in o.points.iter().enumerate() {
+            self.write_point(PT_TAGS[i], pt);
+        }
+        self.w.empty(
+            "hp:textartPr",
+            Attrs::new()
+                .opt("fontName", o.font_name.as_ref())
+                .opt("fontStyle", o.font_style.as_ref())
+                .opt("fontType", o.font_type.as_ref())
+                .opt("textShape", o.text_shape.as_ref())
+                .opt("lineSpacing", o.line_spacing)
+                .opt("charSpacing", o.char_spacing)
+                .opt("align", o.align.as_ref().map(halign_str)),
+        );
+        if !o.outline.is_empty() {
+            self.w
+                .start("hp:outline", Attrs::new().add("cnt", o.outline.len()));
+            for pt in &o.outline {
+                self.write_point("hc:pt", pt);
+            }
+            self.w.end("hp:outline");
+        }
+        self.write_common_layout(&o.common);
+        self.w.end("hp:textart");
+    }
+
+    fn write_container(&mut self, o: &ContainerObject) {
+        let attrs = common_attrs(&o.common).extend_component(&o.component);
+        self.w.start("hp:container", attrs);
+        self.write_component(&o.component);
+        self.container_depth += 1;
+        for child in &o.children {
+            self.write_shape_object(child);
+        }
+        self.container_depth -= 1;
+        self.write_common_layout(&o.common);
+        self.w.end("hp:container");
+    }
+
+    fn write_ole(&mut self, o: &OleObject) {
+        let attrs = common_attrs(&o.common)
+            .extend_component(&o.component)
+            .add("objectType", ole_type_str(&o.object_type))
+            .opt("binaryItemIDRef", o.binary_item_id.as_ref())
+            .flag("hasMoniker", o.has_moniker)
+            .add("drawAspect", ole_presentation_str(&o.presentation))
+            .opt("eqBaseLine", o.eq_baseline);
+        self.w.start("hp:ole", attrs);
+        self.write_component(&o.component);
+        self.w.empty(
+            "hc:extent",
+            Attrs::new()
+                .add("x", o.extent.width)
+                .add("y", o.extent.height),
+        );
+        if let Some(ref ls) = o.line_shape {
+            self.write_line_shape(ls);
+        }
+        self.write_common_layout(&o.common);
+        self.w.end("hp:ole");
+    }
+
+    fn write_equation(&mut self, o: &EquationObject) {
+        let attrs = common_attrs(&o.common)
+            .opt("version", o.version.as_ref())
+            .add("baseLine", o.baseline)
+            .color("textColor", o.text_color)
+            .add("baseUnit", o.base_unit)
+            .add(
+                "lineMode",
+                match o.line_mode {
+                    EquationLineMode::Line => "LINE",
+                    EquationLineMode::Char => "CHAR",
+                },
+            )
+            .opt("font", o.font.as_ref());
+        self.w.start("hp:equation", attrs);
+        self.write_common_layout(&o.common);
+        self.w.text_element("hp:script", Attrs::new(), &o.script);
+        self.w.end("hp:equation");
+    }
+
+    fn write_chart(&mut self, o: &ChartObject) {
+        // 차트 본문(OOXML)은 chartIDRef 파트로 따로 기록 (writer::chart)
+        let attrs = common_attrs(&o.common)
+            .extend_component(&o.component)
+            .opt("chartIDRef", o.chart_id_ref.as_ref());
+        self.w.start("hp:chart", attrs);
+        self.write_common_layout(&o.common);
+        self.w.end("hp:chart");
+    }
+
+    fn write_video(&mut self, o: &VideoObject) {
+        let attrs = common_attrs(&o.common)
+            .add(
+                "videotype",
+                match o.video_type {
+                    VideoType::Local => "Local",
+                    VideoType::Web => "Web",
+                },
+            )
+            .opt("fileIDRef", o.file_id_ref.as_ref())
+            .opt("imageIDRef", o.image_id_ref.as_ref())
+            .opt("tag", o.tag.as_ref());
+        self.w.start("hp:video", attrs);
+        self.write_common_layout(&o.common);
+        self.w.end("hp:video");
+    }
+
+    fn write_form(&mut self, o: &FormObject) {
+        let tag = match o.form_type {
+            FormType::PushButton => "hp:btn",
+            FormType::CheckBox => "hp:checkBtn",
+            FormType::RadioButton => "hp:radioBtn",
+            FormType::ComboBox => "hp:comboBox",
+            FormType::ListBox => "hp:listBox",
+            FormType::Edit => "hp:edit",
+            FormType::ScrollBar => "hp:scrollBar",
+        };
+        let mut attrs = common_attrs(&o.common)
+            .add("name", &o.name)
+            .color("foreColor", o.fore_color)
+            .color("backColor", o.back_color)
+            .flag("enabled", o.enabled)
+            .opt("tabOrder", o.tab_order);
+        attrs = match o.form_type {
+            FormType::PushButton | FormType::CheckBox | FormType::RadioButton => attrs
+                .opt("captionText", o.caption.as_ref())
+                .add(
+                    "value",
+                    match o.check_state {
+                        FormCheckState::Unchecked => "UNCHECKED",
+                        FormCheckState::Checked => "CHECKED",
+                        FormCheckState::Indeterminate => "INDETERMINATE",
+                    },
+                )
+                .opt("radioGroupName", o.group_name.as_ref()),
+            FormType::ComboBox | FormType::ListBox => attrs.opt("selectedValue", o.value.as_ref()),
+            FormType::Edit => attrs
+                .flag("multiLine", o.multi_line)
+                .opt("passwordChar", o.password_char.as_ref())
+                .add("maxLength", o.max_length.unwrap_or(0))
+                .flag("readOnly", o.read_only),
+            FormType::ScrollBar => attrs.opt("value", o.value.as_ref()),
+        };
+        self.w.start(tag, attrs);
+        self.write_common_layout(&o.common);
+        if let Some(id) = o.char_shape_id {
+            self.w
+                .empty("hp:formCharPr", Attrs::new().add("charPrIDRef", id));
+        }
+        for item in &o.items {
+            self.w.empty(
+                "hp:listItem",
+                Attrs::new()
+                    .add("displayText", &item.display_text)
+                    .add("value", &item.value),
+            );
+        }
+        if o.form_type == FormType::Edit {
+            let text = o.value.as_deref().unwrap_or_default();
+            self.w.text_element("hp:text", Attrs::new(), text);
+        }
+        self.w.end(tag);
+    }
+
+    // ═══════════════════════════════════════════
+    // 개체 공통 헬퍼
+    // ═══════════════════════════════════════════
+
+    /// sz, pos, outMargin, caption, shapeComment
+    fn write_common_layout(&mut self, common: &ShapeCommon) {
+        if self.container_depth > 0 {
+            return;
+        }
+        let sz = &common.size;
+        self.w.empty(
+            "hp:sz",
+            Attrs::new()
+                .add("width", sz.width)
+                .add("widthRelTo", size_relation_str(&sz.width_rel_to))
+                .add("height", sz.height)
+                .add("heightRelTo", size_relation_str(&sz.height_rel_to))
+                .flag("protect", sz.protect),
+        );
+        let pos = &common.position;
+        self.w.empty(
+            "hp:pos",
+            Attrs::new()
+                .flag("treatAsChar", pos.treat_as_char)
+                .flag("affectLSpacing", pos.affect_line_spacing)
+                .flag("flowWithText", pos.flow_with_text)
+                .flag("allowOverlap", pos.allow_overlap)
+                .flag("holdAnchorAndSO", pos.hold_anchor_and_so)
+                .add("vertRelTo", relative_to_str(&pos.vert_rel_to))
+                .add("horzRelTo", relative_to_str(&pos.horz_rel_to))
+                .add("vertAlign", valign_str(&pos.vert_align))
+                .add("horzAlign", halign_str(&pos.horz_align))
+                .add("vertOffset", pos.vert_offset)
+                .add("horzOffset", pos.horz_offset),
+        );
+        if let Some(ref m) = common.out_margin {
+            self.write_margin("hp:outMargin", m);
+        }
+        if let Some(ref cap) = common.caption {
+            self.w.start(
+                "hp:caption",
+                Attrs::new()
+                    .add("side", caption_side_str(&cap.side))
+                    .flag("fullSz", cap.full_size)
+                    .add("width", cap.width)
+                    .add("gap", cap.gap)
+                    .opt("lastWidth", cap.last_width),
+            );
+            self.write_sublist(&cap.content);
+            self.w.end("hp:caption");
+        }
+        if let Some(ref comment) = common.comment {
+            self.w
+                .text_element("hp:shapeComment", Attrs::new(), comment);
+        }
+    }
+
+    /// offset, orgSz, curSz, flip, rotationInfo, renderingInfo
+    fn write_component(&mut self, c: &ShapeComponentData) {
+        if let Some(ref p) = c.offset {
+            self.w
+                .empty("hp:offset", Attrs::new().add("x", p.x).add("y", p.y));
+        }
+        if let Some(ref s) = c.org_size {
+            self.write_size("hp:orgSz", s);
+        }
+        if let Some(ref s) = c.cur_size {
+            self.write_size("hp:curSz", s);
+        }
+        if let Some(ref f) = c.flip {
+            self.w.empty(
+                "hp:flip",
+                Attrs::new()
+                    .flag("horizontal", f.horizontal)
+                    .flag("vertical", f.vertical),
+            );
+        }
+        if let Some(ref r) = c.rotation {
+            self.w.empty(
+                "hp:rotationInfo",
+                Attrs::new()
+                    .add("angle", r.angle)
+                    .add("centerX", r.center_x)
+                    .add("centerY", r.center_y)
+                    .flag("rotateimage", r.rotate_image),
+            );
+        }
+        if let Some(ref ri) = c.rendering_info {
+            self.w.start("hp:renderingInfo", Attrs::new());
+            for (tag, m) in [
+                ("hc:transMatrix", &ri.trans_matrix),
+                ("hc:scaMatrix", &ri.sca_matrix),
+                ("hc:rotMatrix", &ri.rot_matrix),
+            ] {
+                self.w.empty(
+                    tag,
+                    Attrs::new()
+                        .add("e1", m[0])
+                        .add("e2", m[1])
+                        .add("e3", m[2])
+                        .add("e4", m[3])
+                        .add("e5", m[4])
+                        .add("e6", m[5]),
+                );
+            }
+            self.w.end("hp:renderingInfo");
+        }
+    }
+
+    fn write_line_shape(&mut self, ls: &ShapeLineInfo) {
+        self.w.empty(
+            "hp:lineShape",
+            Attrs::new()
+                .color("color", ls.color)
+                .add("width", ls.width)
+                .add("style", line_type1_str(&ls.style))
+                .add(
+                    "endCap",
+                    match ls.end_cap {
+                        LineEndCap::Flat => "FLAT",
+                        LineEndCap::Round => "ROUND",
+                    },
+                )
+                .add("headStyle", arrow_type_str(&ls.head_style))
+                .add("tailStyle", arrow_type_str(&ls.tail_style))
+                .flag("headfill", ls.head_fill)
+                .flag("tailfill", ls.tail_fill)
+                .add("headSz", arrow_size_str(&ls.head_size))
+                .add("tailSz", arrow_size_str(&ls.tail_size))
+                .add(
+                    "outlineStyle",
+                    match ls.outline_style {
+                        LineOutlineStyle::Normal => "NORMAL",
+                        LineOutlineStyle::Outer => "OUTER",
+                        LineOutlineStyle::Inner => "INNER",
+                    },
+                )
+                .add("alpha", ls.alpha),
+        );
+    }
+
+    fn write_fill(&mut self, fill: Option<&FillBrush>) {
+        if let Some(fill) = fill {
+            write_fill_brush(&mut self.w, fill);
+        }
+    }
+
+    fn write_shadow(&mut self, shadow: Option<&ShapeShadow>) {
+        if let Some(s) = shadow {
+            self.w.empty(
+                "hp:shadow",
+                Attrs::new()
+                    .add("type", shape_shadow_type_str(&s.shadow_type))
+                    .color("color", s.color)
+                    .add("offsetX", s.offset_x)
+                    .add("offsetY", s.offset_y)
+                    .add("alpha", s.alpha),
+            );
+        }
+    }
+
+    fn write_draw_text(&mut self, sl: Option<&SubList>) {
+        if let Some(sl) = sl {
+            self.w.start(
+                "hp:drawText",
+                Attrs::new()
+                    .add("lastWidth", sl.text_width.unwrap_or(0))
+                    .add("name", "")
+                    .flag("editable", false),
+            );
+            self.write_sublist(sl);
+            self.w.end("hp:drawText");
+        }
+    }
+
+    fn write_point(&mut self, tag: &str, pt: &Point) {
+        self.w
+            .empty(tag, Attrs::new().add("x", pt.x).add("y", pt.y));
+    }
+
+    fn write_size(&mut self, tag: &str, s: &Size) {
+        self.w.empty(
+            tag,
+            Attrs::new().add("width", s.width).add("height", s.height),
+        );
+    }
+
+    fn write_margin(&mut self, tag: &str, m: &Margin) {
+        self.w.empty(
+            tag,
+            Attrs::new()
+                .add("left", m.left)
+                .add("right", m.right)
+                .add("top", m.top)
+                .add("bottom", m.bottom),
+        );
+    }
+}
+
+const PT_TAGS: [&str; 4] = ["hc:pt0", "hc:pt1", "hc:pt2", "hc:pt3"];
+
+/// AbstractShapeObjectType 속성
+fn common_attrs(c: &ShapeCommon) -> Attrs {
+    Attrs::new()
+        .add("id", c.id)
+        .add("zOrder", c.z_order)
+        .add("numberingType", numbering_type_str(&c.numbering_type))
+        .add("textWrap", text_wrap_str(&c.text_wrap))
+        .add("textFlow", text_flow_str(&c.text_flow))
+        .flag("lock", c.lock)
+        .add(
+            "dropcapstyle",
+            dropcap_style_str(&c.dropcap_style.clone().unwrap_or_default()),
+        )
+}
+
+trait ComponentAttrs {
+    fn extend_component(self, c: &ShapeComponentData) -> Self;
+}
+
+impl ComponentAttrs for Attrs {
+    /// AbstractShapeComponentType 속성 (href, groupLevel, instid)
+    fn extend_component(self, c: &ShapeComponentData) -> Self {
+        self.add("href", c.href.clone().unwrap_or_default())
+            .add("groupLevel", c.group_level)
+            .opt("instid", c.inst_id)
+    }
+}
+
+// ═══════════════════════════════════════════
+// enum → 문자열 (body 파서의 역변환)
+// ═══════════════════════════════════════════
+
+fn text_direction_str(d: &TextDirection) -> &'static str {
+    match d {
+        TextDirection::Horizontal => "HORIZONTAL",
+        TextDirection::Vertical => "VERTICAL",
+        TextDirection::VerticalAll => "VERTICALALL",
+    }
+}
+
+/// OWPML은 세로(NARROWLY)/가로(WIDELY)로 용지 방향을 표기
+fn landscape_str(l: &Landscape) -> &'static str {
+    match l {
+        Landscape::Landscape => "WIDELY",
+        Landscape::Portrait | Landscape::Widely => "NARROWLY",
+    }
+}
+
+fn gutter_type_str(g: &GutterType) -> &'static str {
+    match g {
+        GutterType::LeftOnly => "LEFT_ONLY",
+        GutterType::LeftRight => "LEFT_RIGHT",
+        GutterType::TopBottom => "TOP_BOTTOM",
+    }
+}
+
+fn page_starts_on_str(p: &PageStartsOn) -> &'static str {
+    match p {
+        PageStartsOn::Both => "BOTH",
+        PageStartsOn::Even => "EVEN",
+        PageStartsOn::Odd => "ODD",
+    }
+}
+
+fn visibility_value_str(v: &VisibilityValue) -> &'static str {
+    match v {
+        VisibilityValue::HideFirst => "HIDE_FIRST",
+        VisibilityValue::ShowFirst => "SHOW_FIRST",
+        VisibilityValue::ShowAll => "SHOW_ALL",
+    }
+}
+
+fn line_number_restart_str(r: &LineNumberRestart) -> &'static str {
+    match r {
+        LineNumberRestart::RestartBySection => "0",
+        LineNumberRestart::RestartByPage => "1",
+        LineNumberRestart::KeepContinue => "2",
+    }
+}
+
+fn number_type1_str(t: &NumberType1) -> &'static str {
+    match t {
+        NumberType1::Digit => "DIGIT",
+        NumberType1::CircledDigit => "CIRCLED_DIGIT",
+        NumberType1::RomanCapital => "ROMAN_CAPITAL",
+        NumberType1::RomanSmall => "ROMAN_SMALL",
+        NumberType1::LatinCapital => "LATIN_CAPITAL",
+        NumberType1::LatinSmall => "LATIN_SMALL",
+        NumberType1::CircledLatinCapital => "CIRCLED_LATIN_CAPITAL",
+        NumberType1::CircledLatinSmall => "CIRCLED_LATIN_SMALL",
+        NumberType1::HangulSyllable => "HANGUL_SYLLABLE",
+        NumberType1::CircledHangulSyllable => "CIRCLED_HANGUL_SYLLABLE",
+        NumberType1::HangulJamo => "HANGUL_JAMO",
+        NumberType1::CircledHangulJamo => "CIRCLED_HANGUL_JAMO",
+        NumberType1::HangulPhonetic => "HANGUL_PHONETIC",
+        NumberType1::Ideograph => "IDEOGRAPH",
+        NumberType1::CircledIdeograph => "CIRCLED_IDEOGRAPH",
+    }
+}
+
+fn footnote_numbering_str(n: &FootnoteNumbering) -> &'static str {
+    match n {
+        FootnoteNumbering::Continuous => "CONTINUOUS",
+        FootnoteNumbering::OnSection => "ON_SECTION",
+        FootnoteNumbering::OnPage => "ON_PAGE",
+    }
+}
+
+fn footnote_placement_str(p: &FootnotePlacement) -> &'static str {
+    match p {
+        FootnotePlacement::EachColumn => "EACH_COLUMN",
+        FootnotePlacement::MergedColumn => "MERGED_COLUMN",
+        FootnotePlacement::RightMostColumn => "RIGHT_MOST_COLUMN",
+    }
+}
+
+fn endnote_numbering_str(n: &EndnoteNumbering) -> &'static str {
+    match n {
+        EndnoteNumbering::Continuous => "CONTINUOUS",
+        EndnoteNumbering::OnSection => "ON_SECTION",
+    }
+}
+
+fn endnote_placement_str(p: &EndnotePlacement) -> &'static str {
+    match p {
+        EndnotePlacement::EndOfDocument => "END_OF_DOCUMENT",
+        EndnotePlacement::EndOfSection => "END_OF_SECTION",
+    }
+}
+
+fn page_border_ref_str(r: &PageBorderRef) -> &'static str {
+    match r {
+        PageBorderRef::Paper => "PAPER",
+        PageBorderRef::Text => "TEXT",
+    }
+}
+
+fn fill_area_str(f: &FillArea) -> &'static str {
+    match f {
+        FillArea::Paper => "PAPER",
+        FillArea::Text => "TEXT",
+        FillArea::PaperLine => "PAPERLINE",
+    }
+}
+
+fn column_type_str(t: &ColumnType) -> &'static str {
+    match t {
+        ColumnType::Newspaper => "NEWSPAPER",
+        ColumnType::BalancedNewspaper => "BALANCED_NEWSPAPER",
+        ColumnType::Parallel => "PARALLEL",
+    }
+}
+
+fn column_layout_str(l: &ColumnLayout) -> &'static str {
+    match l {
+        ColumnLayout::Left => "LEFT",
+        ColumnLayout::Right => "RIGHT",
+        ColumnLayout::Mirror => "MIRROR",
+    }
+}
+
+fn page_apply_type_str(t: &PageApplyType) -> &'static str {
+    match t {
+        PageApplyType::Both => "BOTH",
+        PageApplyType::Even => "EVEN",
+        PageApplyType::Odd => "ODD",
+        PageApplyType::First => "FIRST",
+    }
+}
+
+fn auto_num_type_str(t: &AutoNumType) -> &'static str {
+    match t {
+        AutoNumType::Page => "PAGE",
+        AutoNumType::Footnote => "FOOTNOTE",
+        AutoNumType::Endnote => "ENDNOTE",
+        AutoNumType::Picture => "PICTURE",
+        AutoNumType::Table => "TABLE",
+        AutoNumType::Equation => "EQUATION",
+        AutoNumType::TotalPage => "TOTAL_PAGE",
+    }
+}
+
+fn numbering_type_str(t: &NumberingType) -> &'static str {
+    match t {
+        NumberingType::None => "NONE",
+        NumberingType::Picture => "PICTURE",
+        NumberingType::Table => "TABLE",
+        NumberingType::Equation => "EQUATION",
+    }
+}
+
+fn text_wrap_str(t: &TextWrap) -> &'static str {
+    match t {
+        TextWrap::Square => "SQUARE",
+        TextWrap::Tight => "TIGHT",
+        TextWrap::Through => "THROUGH",
+        TextWrap::TopAndBottom => "TOP_AND_BOTTOM",
+        TextWrap::BehindText => "BEHIND_TEXT",
+        TextWrap::InFrontOfText => "IN_FRONT_OF_TEXT",
+    }
+}
+
+fn text_flow_str(t: &TextFlow) -> &'static str {
+    match t {
+        TextFlow::BothSides => "BOTH_SIDES",
+        TextFlow::LeftOnly => "LEFT_ONLY",
+        TextFlow::RightOnly => "RIGHT_ONLY",
+        TextFlow::LargestOnly => "LARGEST_ONLY",
+    }
+}
+
+fn relative_to_str(r: &RelativeTo) -> &'static str {
+    match r {
+        RelativeTo::Paper => "PAPER",
+        RelativeTo::Page => "PAGE",
+        RelativeTo::Column => "COLUMN",
+        RelativeTo::Para => "PARA",
+    }
+}
+
+fn size_relation_str(s: &SizeRelation) -> &'static str {
+    match s {
+        SizeRelation::Absolute => "ABSOLUTE",
+        SizeRelation::Paper => "PAPER",
+        SizeRelation::Page => "PAGE",
+        SizeRelation::Column => "COLUMN",
+        SizeRelation::Para => "PARA",
+        SizeRelation::Percent => "PERCENT",
+    }
+}
+
+fn valign_str(a: &VAlign) -> &'static str {
+    match a {
+        VAlign::Top => "TOP",
+        VAlign::Center => "CENTER",
+        VAlign::Bottom => "BOTTOM",
+        VAlign::Baseline => "BASELINE",
+        VAlign::Inside => "INSIDE",
+        VAlign::Outside => "OUTSIDE",
+    }
+}
+
+fn table_page_break_str(t: &TablePageBreak) -> &'static str {
+    match t {
+        TablePageBreak::Table => "TABLE",
+        TablePageBreak::Cell => "CELL",
+        TablePageBreak::None => "NONE",
+    }
+}
+
+fn dropcap_style_str(d: &DropcapStyle) -> &'static str {
+    match d {
+        DropcapStyle::None => "None",
+        DropcapStyle::DoubleLine => "DoubleLine",
+        DropcapStyle::TripleLine => "TripleLine",
+        DropcapStyle::Margin => "Margin",
+    }
+}
+
+fn tab_type_str(t: &TabType) -> &'static str {
+    match t {
+        TabType::Left => "LEFT",
+        TabType::Right => "RIGHT",
+        TabType::Center => "CENTER",
+        TabType::Decimal => "DECIMAL",
+    }
+}
+
+fn line_type1_str(t: &LineType1) -> &'static str {
+    match t {
+        LineType1::None => "NONE",
+        LineType1::Solid => "SOLID",
+        LineType1::Dot => "DOT",
+        LineType1::Thick => "THICK",
+        LineType1::Dash => "DASH",
+        LineType1::DashDot => "DASH_DOT",
+        LineType1::DashDotDot => "DASH_DOT_DOT",
+    }
+}
+
+fn arrow_type_str(t: &ArrowType) -> &'static str {
+    match t {
+        ArrowType::Normal => "NORMAL",
+        ArrowType::Arrow => "ARROW",
+        ArrowType::Spear => "SPEAR",
+        ArrowType::ConcaveArrow => "CONCAVE_ARROW",
+        ArrowType::EmptyDiamond => "EMPTY_DIAMOND",
+        ArrowType::EmptyCircle => "EMPTY_CIRCLE",
+        ArrowType::EmptyBox => "EMPTY_BOX",
+        ArrowType::FilledDiamond => "FILLED_DIAMOND",
+        ArrowType::FilledCircle => "FILLED_CIRCLE",
+        ArrowType::FilledBox => "FILLED_BOX",
+    }
+}
+
+fn arrow_size_str(s: &ArrowSize) -> &'static str {
+    match s {
+        ArrowSize::SmallSmall => "SMALL_SMALL",
+        ArrowSize::SmallMedium => "SMALL_MEDIUM",
+        ArrowSize::SmallLarge => "SMALL_LARGE",
+        ArrowSize::MediumSmall => "MEDIUM_SMALL",
+        ArrowSize::MediumMedium => "MEDIUM_MEDIUM",
+        ArrowSize::MediumLarge => "MEDIUM_LARGE",
+        ArrowSize::LargeSmall => "LARGE_SMALL",
+        ArrowSize::LargeMedium => "LARGE_MEDIUM",
+        ArrowSize::LargeLarge => "LARGE_LARGE",
+    }
+}
+
+fn shape_shadow_type_str(t: &ShapeShadowType) -> &'static str {
+    match t {
+        ShapeShadowType::None => "NONE",
+        ShapeShadowType::ParellelLeftTop => "PARELLEL_LEFTTOP",
+        ShapeShadowType::ParellelRightTop => "PARELLEL_RIGHTTOP",
+        ShapeShadowType::ParellelLeftBottom => "PARELLEL_LEFTBOTTOM",
+        ShapeShadowType::ParellelRightBottom => "PARELLEL_RIGHTBOTTOM",
+        ShapeShadowType::ShearLeftTop => "SHEAR_LEFTTOP",
+        ShapeShadowType::ShearRightTop => "SHEAR_RIGHTTOP",
+        ShapeShadowType::ShearLeftBottom => "SHEAR_LEFTBOTTOM",
+        ShapeShadowType::ShearRightBottom => "SHEAR_RIGHTBOTTOM",
+        ShapeShadowType::PersLeftTop => "PERS_LEFTTOP",
+        ShapeShadowType::PersRightTop => "PERS_RIGHTTOP",
+        ShapeShadowType::PersLeftBottom => "PERS_LEFTBOTTOM",
+        ShapeShadowType::PersRightBottom => "PERS_RIGHTBOTTOM",
+        ShapeShadowType::ScaleNarrow => "SCALE_NARROW",
+        ShapeShadowType::ScaleEnlarge => "SCALE_ENLARGE",
+    }
+}
+
+fn arc_type_str(t: &ArcType) -> &'static str {
+    match t {
+        ArcType::Normal => "NORMAL",
+        ArcType::Pie => "PIE",
+        ArcType::Chord => "CHORD",
+    }
+}
+
+fn caption_side_str(s: &CaptionSide) -> &'static str {
+    match s {
+        CaptionSide::Left => "LEFT",
+        CaptionSide::Right => "RIGHT",
+        CaptionSide::Top => "TOP",
+        CaptionSide::Bottom => "BOTTOM",
+    }
+}
+
+fn ole_type_str(t: &OleObjectType) -> &'static str {
+    match t {
+        OleObjectType::Unknown => "UNKNOWN",
+        OleObjectType::Embedded => "EMBEDDED",
+        OleObjectType::Link => "LINK",
+        OleObjectType::Static => "STATIC",
+        OleObjectType::Equation => "EQUATION",
+    }
+}
+
+fn ole_presentation_str(p: &OlePresentation) -> &'static str {
+    match p {
+        OlePresentation::Content => "CONTENT",
+        OlePresentation::ThumbNail => "THUMB_NAIL",
+        OlePresentation::Icon => "ICON",
+        OlePresentation::DocPrint => "DOC_PRINT",
+    }
+}
+
+fn connect_line_type_str(t: &ConnectLineType) -> &'static str {
+    match t {
+        ConnectLineType::StraightNoArrow => "STRAIGHT_NOARROW",
+        ConnectLineType::StraightOneWay => "STRAIGHT_ONEWAY",
+        ConnectLineType::StraightBoth => "STRAIGHT_BOTH",
+        ConnectLineType::StrokeNoArrow => "STROKE_NOARROW",
+        ConnectLineType::StrokeOneWay => "STROKE_ONEWAY",
+        ConnectLineType::StrokeBoth => "STROKE_BOTH",
+        ConnectLineType::ArcNoArrow => "ARC_NOARROW",
+        ConnectLineType::ArcOneWay => "ARC_ONEWAY",
+        ConnectLineType::ArcBoth => "ARC_BOTH",
+    }
+}
+
+fn dutmal_position_str(p: &DutmalPosition) -> &'static str {
+    match p {
+        DutmalPosition::Top => "TOP",
+        DutmalPosition::Bottom => "BOTTOM",
+        DutmalPosition::Center => "CENTER",
+    }
+}
+
+fn field_type_str(t: &FieldType) -> &'static str {
+    match t {
+        FieldType::ClickHere => "CLICK_HERE",
+        FieldType::Hyperlink => "HYPERLINK",
+        FieldType::Bookmark => "BOOKMARK",
+        FieldType::Formula => "FORMULA",
+        FieldType::Summary => "SUMMERY",
+        FieldType::UserInfo => "USER_INFO",
+        FieldType::Date => "DATE",
+        FieldType::DocDate => "DOC_DATE",
+        FieldType::Path => "PATH",
+        FieldType::CrossRef => "CROSSREF",
+        FieldType::MailMerge => "MAILMERGE",
+        FieldType::Memo => "MEMO",
+        FieldType::ProofreadingMarks => "PROOFREADING_MARKS",
+        FieldType::PrivateInfo => "PRIVATE_INFO",
+        FieldType::MetaTag => "METATAG",
+        FieldType::Outline => "OUTLINE",
+    }
+}
@@ -0,0 +1,237 @@
+use super::xml::{Attrs, XmlWriter};
+use hwp_model::chart::{Chart, ChartGrouping, ChartType};
+use hwp_model::control::Control;
+use hwp_model::document::Document;
+use hwp_model::paragraph::{Paragraph, RunContent};
+use hwp_model::shape::ShapeObject;
+
+/// 문서의 차트 파트 (chartIDRef 경로, 차트 데이터). 같은 경로는 한 번만.
+pub fn chart_parts(doc: &Document) -> Vec<(&str, &Chart)> {
+    let mut parts = Vec::new();
+    for section in &doc.sections {
+        collect_paragraphs(&section.paragraphs, &mut parts);
+    }
+    let mut seen = std::collections::HashSet::new();
+    parts.retain(|(path, _)| seen.insert(*path));
+    parts
+}
+
+fn collect_paragraphs<'a>(paragraphs: &'a [Paragraph], parts: &mut Vec<(&'a str, &'a Chart)>) {
+    for content in paragraphs
+        .iter()
+        .flat_map(|p| &p.runs)
+        .flat_map(|r| &r.contents)
+    {
+        match content {
+            RunContent::Object(object) => collect_object(object, parts),
+            RunContent::Control(Control::Header(hf) | Control::Footer(hf)) => {
+                collect_paragraphs(&hf.content.paragraphs, parts)
+            }
+            RunContent::Control(Control::FootNote(note) | Control::EndNote(note)) => {
+                collect_paragraphs(&note.content.paragraphs, parts)
+            }
+            _ => {}
+        }
+    }
+}
+
+fn collect_object<'a>(object: &'a ShapeObject, parts: &mut Vec<(&'a str, &'a Chart)>) {
+    match object {
+        ShapeObject::Chart(chart) => {
+            if let (Some(path), Some(data)) = (chart.chart_id_ref.as_deref(), chart.chart.as_ref())
+            {
+                parts.push((path.trim_start_matches('/'), data));
+            }
+        }
+        ShapeObject::Table(table) => {
+            for cell in table.rows.iter().flat_map(|row| &row.cells) {
+                collect_paragraphs(&cell.content.paragraphs, parts);
+            }
+        }
+        ShapeObject::Container(container) => {
+            for child in &container.children {
+                collect_object(child, parts);
+            }
+        }
+        ShapeObject::Rectangle(rect) => {
+            if let Some(list) = &rect.draw_text {
+                collect_paragraphs(&list.paragraphs, parts);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Chart → OOXML `c:chartSpace` (계열 값은 numLit/strLit 캐시로 기록)
+pub fn write_chart_xml(chart: &Chart) -> String {
+    let mut w = XmlWriter::new();
+    w.start(
+        "c:chartSpace",
+        Attrs::new()
+            .add(
+                "xmlns:c",
+                "http://schemas.openxmlformats.org/drawingml/2006/chart",
+            )
+            .add(
+                "xmlns:a",
+                "http://schemas.openxmlformats.org/drawingml/2006/main",
+            ),
+    );
+    w.start("c:chart", Attrs::new());
+    if let Some(title) = &chart.title {
+        write_title(&mut w, title);
+    }
+    w.start("c:plotArea", Attrs::new());
+
+    let plot = match chart.chart_type {
+        ChartType::Column | ChartType::Bar => "c:barChart",
+        ChartType::Line => "c:lineChart",
+        ChartType::Area => "c:areaChart",
+        ChartType::Pie => "c:pieChart",
+        ChartType::Doughnut => "c:doughnutChart",
+        ChartType::Scatter => "c:scatterChart",
+        ChartType::Radar => "c:radarChart",
+    };
+    w.start(plot, Attrs::new());
+    match chart.chart_type {
+        ChartType::Column | ChartType::Bar => {
+            let dir = if chart.chart_type == ChartType::Bar {
+                "bar"
+            } else {
+                "col"
+            };
+            w.empty("c:barDir", Attrs::new().add("val", dir));
+            w.empty(
+                "c:grouping",
+                Attrs::new().add("val", grouping(&chart.grouping)),
+            );
+        }
+        ChartType::Line | ChartType::Area => {
+            w.empty(
+                "c:grouping",
+                Attrs::new().add("val", grouping(&chart.grouping)),
+            );
+        }
+        _ => {}
+    }
+    let scatter = chart.chart_type == ChartType::Scatter;
+    for (i, series) in chart.series.iter().enumerate() {
+        w.start("c:ser", Attrs::new());
+        w.empty("c:idx", Attrs::new().add("val", i));
+        w.empty("c:order", Attrs::new().add("val", i));
+        if let Some(name) = &series.name {
+            w.start("c:tx", Attrs::new());
+            w.text_element("c:v", Attrs::new(), name);
+            w.end("c:tx");
+        }
+        if let Some(color) = series.color {
+            w.start("c:spPr", Attrs::new());
+            w.start("a:solidFill", Attrs::new());
+            w.empty(
+                "a:srgbClr",
+                Attrs::new().add("val", format!("{:06X}", color & 0xFF_FFFF)),
+            );
+            w.end("a:solidFill");
+            w.end("c:spPr");
+        }
+        if !chart.categories.is_empty() {
+            let tag = if scatter { "c:xVal" } else { "c:cat" };
+            w.start(tag, Attrs::new());
+            w.start("c:strLit", Attrs::new());
+            w.empty("c:ptCount", Attrs::new().add("val", chart.categories.len()));
+            for (idx, category) in chart.categories.iter().enumerate() {
+                w.start("c:pt", Attrs::new().add("idx", idx));
+                w.text_element("c:v", Attrs::new(), category);
+                w.end("c:pt");
+            }
+            w.end("c:strLit");
+            w.end(tag);
+        }
+        let tag = if scatter { "c:yVal" } else { "c:val" };
+        w.start(tag, Attrs::new());
+        w.start("c:numLit", Attrs::new());
+        w.empty("c:ptCount", Attrs::new().add("val", series.values.len()));
+        for (idx, value) in series.values.iter().enumerate() {
+            if let Some(value) = value {
+                w.start("c:pt", Attrs::new().add("idx", idx));
+                w.text_element("c:v", Attrs::new(), &value.to_string());
+                w.end("c:pt");
+            }
+        }
+        w.end("c:numLit");
+        w.end(tag);
+        w.end("c:ser");
+    }
+    w.end(plot);
+
+    if !matches!(chart.chart_type, ChartType::Pie | ChartType::Doughnut) {
+        w.start("c:catAx", Attrs::new());
+        if let Some(title) = &chart.category_axis_title {
+            write_title(&mut w, title);
+        }
+        w.end("c:catAx");
+        w.start("c:valAx", Attrs::new());
+        if let Some(title) = &chart.value_axis_title {
+            write_title(&mut w, title);
+        }
+        w.end("c:valAx");
+    }
+    w.end("c:plotArea");
+    if chart.legend {
+        w.start("c:legend", Attrs::new());
+        w.empty("c:legendPos", Attrs::new().add("val", "r"));
+        w.end("c:legend");
+    }
+    w.end("c:chart");
+    w.end("c:chartSpace");
+    w.finish()
+}
+
+fn write_title(w: &mut XmlWriter, title: &str) {
+    w.start("c:title", Attrs::new());
+    w.start("c:tx", Attrs::new());
+    w.start("c:rich", Attrs::new());
+    w.start("a:p", Attrs::new());
+    w.start("a:r", Attrs::new());
+    w.text_element("a:t", Attrs::new(), title);
+    w.end("a:r");
+    w.end("a:p");
+    w.end("c:rich");
+    w.end("c:tx");
+    w.end("c:title");
+}
+
+fn grouping(grouping: &ChartGrouping) -> &'static str {
+    match grouping {
+        ChartGrouping::Clustered => "clustered",
+        ChartGrouping::Standard => "standard",
+        ChartGrouping::Stacked => "stacked",
+        ChartGrouping::PercentStacked => "percentStacked",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hwp_model::chart::ChartSeries;
+
+    #[test]
+    fn test_chart_xml_round_trip() {
+        let chart = Chart {
+            chart_type: ChartType::Bar,
+            grouping: ChartGrouping::Stacked,
+            title: Some("매출 & 비용".to_string()),
+            categories: vec!["1분기".to_string(), "2분기".to_string()],
+            series: vec![ChartSeries {
+                name: Some("매출".to_string()),
+                values: vec![Some(1.5), None],
+                color: Some(0x4F81BD),
+            }],
+            category_axis_title: Some("분기".to_string()),
+            value_axis_title: None,
+            legend: true,
+        };
+        let xml = write_chart_xml(&chart);
+        assert_eq!(crate::chart::parse_chart_xml(&xml).unwrap(), chart);
+    }
+}
@@ -0,0 +1,1009 @@
+use super::xml::{Attrs, XmlWriter, OWPML_NAMESPACES};
+use hwp_model::document::{Document, DocumentSettings};
+use hwp_model::resources::*;
+use hwp_model::types::*;
+
+/// Document → header.xml
+pub fn write_header(doc: &Document) -> String {
+    let mut w = XmlWriter::new();
+    w.start(
+        "hh:head",
+        Attrs::new()
+            .namespaces(OWPML_NAMESPACES)
+            .add("version", "1.2")
+            .add("secCnt", doc.sections.len()),
+    );
+
+    write_begin_num(&mut w, &doc.settings);
+
+    let res = &doc.resources;
+    w.start("hh:refList", Attrs::new());
+    write_fontfaces(&mut w, &res.fonts);
+    write_border_fills(&mut w, &res.border_fills);
+    write_char_properties(&mut w, &res.char_shapes);
+    write_tab_properties(&mut w, &res.tab_defs);
+    write_numberings(&mut w, &res.numberings);
+    write_bullets(&mut w, &res.bullets);
+    write_para_properties(&mut w, &res.para_shapes);
+    write_styles(&mut w, &res.styles);
+    write_memo_properties(&mut w, &res.memo_shapes);
+    write_track_changes(&mut w, &res.track_changes);
+    write_track_change_authors(&mut w, &res.track_change_authors);
+    w.end("hh:refList");
+
+    let target = match doc.compatible_document.clone().unwrap_or_default() {
+        CompatibleDocument::Hwp201X => "HWP201X",
+        CompatibleDocument::Hwp200X => "HWP200X",
+        CompatibleDocument::MsWord => "MS_WORD",
+    };
+    w.start(
+        "hh:compatibleDocument",
+        Attrs::new().add("targetProgram", target),
+    );
+    w.empty("hh:layoutCompatibility", Attrs::new());
+    w.end("hh:compatibleDocument");
+
+    w.start("hh:docOption", Attrs::new());
+    w.empty(
+        "hh:linkinfo",
+        Attrs::new()
+            .add("path", "")
+            .add("pageInherit", "0")
+            .add("footnoteInherit", "0"),
+    );
+    w.end("hh:docOption");
+
+    w.end("hh:head");
+    w.finish()
+}
+
+fn write_begin_num(w: &mut XmlWriter, settings: &DocumentSettings) {
+    // 0은 파서 기본값(1)과 구분이 안 되므로 1로 보정
+    let n = |v: u16| v.max(1);
+    w.empty(
+        "hh:beginNum",
+        Attrs::new()
+            .add("page", n(settings.page_start))
+            .add("footnote", n(settings.footnote_start))
+            .add("endnote", n(settings.endnote_start))
+            .add("pic", n(settings.picture_start))
+            .add("tbl", n(settings.table_start))
+            .add("equation", n(settings.equation_start)),
+    );
+}
+
+// ── fontfaces ──
+
+fn write_fontfaces(w: &mut XmlWriter, fonts: &FontFaces) {
+    let groups: [(&str, &Vec<Font>); 7] = [
+        ("HANGUL", &fonts.hangul),
+        ("LATIN", &fonts.latin),
+        ("HANJA", &fonts.hanja),
+        ("JAPANESE", &fonts.japanese),
+        ("OTHER", &fonts.other),
+        ("SYMBOL", &fonts.symbol),
+        ("USER", &fonts.user),
+    ];
+
+    w.start("hh:fontfaces", Attrs::new().add("itemCnt", groups.len()));
+    for (lang, list) in groups {
+        w.start(
+            "hh:fontface",
+            Attrs::new().add("lang", lang).add("fontCnt", list.len()),
+        );
+        for font in list.iter() {
+            write_font(w, font);
+        }
+        w.end("hh:fontface");
+    }
+    w.end("hh:fontfaces");
+}
+
+fn write_font(w: &mut XmlWriter, font: &Font) {
+    // 파서는 <hh:font>를 Start 이벤트로만 인식하므로 항상 열고 닫는다
+    w.start(
+        "hh:font",
+        Attrs::new()
+            .add("id", font.id)
+            .add("face", &font.face)
+            .add("type", font_type_str(&font.font_type))
+            .flag("isEmbedded", font.is_embedded)
+            .opt("binaryItemIDRef", font.binary_item_id.as_ref()),
+    );
+    if let Some(ref subst) = font.subst_font {
+        w.empty(
+            "hh:substFont",
+            Attrs::new()
+                .add("face", &subst.face)
+                .add("type", font_type_str(&subst.font_type))
+                .flag("isEmbedded", subst.is_embedded),
+        );
+    }
+    if let Some(ref info) = font.type_info {
+        w.empty(
+            "hh:typeInfo",
+            Attrs::new()
+                .add("familyType", font_category_str(&info.family_type))
+                .add("weight", info.weight)
+                .add("proportion", info.proportion)
+                .add("contrast", info.contrast)
+                .add("strokeVariation", info.stroke_variation)
+                .add("armStyle", info.arm_style)
+                .add("letterform", info.letterform)
+                .add("midline", info.midline)
+                .add("xHeight", info.x_height),
+        );
+    }
+    w.end("hh:font");
+}
+
+// ── borderFills ──
+
+fn write_border_fills(w: &mut XmlWriter, fills: &[BorderFill]) {
+    w.start("hh:borderFills", Attrs::new().add("itemCnt", fills.len()));
+    for bf in fills {
+        w.start(
+            "hh:borderFill",
+            Attrs::new()
+                .add("id", bf.id)
+                .flag("threeD", bf.three_d)
+                .flag("shadow", bf.shadow)
+                .add("centerLine", center_line_type_str(&bf.center_line))
+                .opt(
+                    "breakCellSeparateLine",
+                    bf.break_cell_separate_line
+                        .map(|b| if b { "1" } else { "0" }),
+                ),
+        );
+        write_slash(w, "hh:slash", bf.slash.as_ref());
+        write_slash(w, "hh:backSlash", bf.back_slash.as_ref());
+        write_line_spec(w, "hh:leftBorder", bf.left_border.as_ref());
+        write_line_spec(w, "hh:rightBorder", bf.right_border.as_ref());
+        write_line_spec(w, "hh:topBorder", bf.top_border.as_ref());
+        write_line_spec(w, "hh:bottomBorder", bf.bottom_border.as_ref());
+        write_line_spec(w, "hh:diagonal", bf.diagonal.as_ref());
+        if let Some(ref fill) = bf.fill {
+            write_fill_brush(w, fill);
+        }
+        w.end("hh:borderFill");
+    }
+    w.end("hh:borderFills");
+}
+
+fn write_slash(w: &mut XmlWriter, tag: &str, slash: Option<&SlashInfo>) {
+    let default = SlashInfo::default();
+    let s = slash.unwrap_or(&default);
+    w.empty(
+        tag,
+        Attrs::new()
+            .add("type", slash_type_str(&s.slash_type))
+            .flag("Crooked", s.crooked)
+            .flag("isCounter", s.is_counter),
+    );
+}
+
+/// 테두리 선. None이면 type="NONE" (파서에서 None으로 되돌아감)
+fn write_line_spec(w: &mut XmlWriter, tag: &str, spec: Option<&LineSpec>) {
+    match spec {
+        Some(spec) => w.empty(
+            tag,
+            Attrs::new()
+                .add("type", line_type3_str(&spec.line_type))
+                .add("width", line_width_str(&spec.width))
+                .color("color", spec.color),
+        ),
+        None => w.empty(
+            tag,
+            Attrs::new()
+                .add("type", "NONE")
+                .add("width", "0.1 mm")
+                .add("color", "#000000"),
+        ),
+    }
+}
+
+/// 파서가 공백을 제거한 "0.1mm"를 원래 표기 "0.1 mm"로 복원
+fn line_width_str(width: &str) -> String {
+    if width.is_empty() {
+        return "0.1 mm".to_string();
+    }
+    match width.strip_suffix("mm") {
+        Some(num) if !num.ends_with(' ') => format!("{} mm", num),
+        _ => width.to_string(),
+    }
+}
+
+/// hc:fillBrush (borderFill, 도형 공용)
+pub fn write_fill_brush(w: &mut XmlWriter, fill: &FillBrush) {
+    w.start("hc:fillBrush", Attrs::new());
+    write_fill_brush_items(w, fill);
+    w.end("hc:fillBrush");
+}
+
+fn write_fill_brush_items(w: &mut XmlWriter, fill: &FillBrush) {
+    match fill {
+        FillBrush::WinBrush {
+            face_color,
+            hatch_color,
+            hatch_style,
+            alpha,
+        } => {
+            w.empty(
+                "hc:winBrush",
+                Attrs::new()
+                    .color("faceColor", *face_color)
+                    .color("hatchColor", *hatch_color)
+                    .opt("hatchStyle", hatch_style.as_ref().map(hatch_style_str))
+                    .add("alpha", alpha),
+            );
+        }
+        FillBrush::Gradation {
+            grad_type,
+            angle,
+            center_x,
+            center_y,
+            step,
+            color_num,
+            step_center,
+            colors,
+            alpha,
+        } => {
+            w.start(
+                "hc:gradation",
+                Attrs::new()
+                    .add("type", gradation_type_str(grad_type))
+                    .add("angle", angle)
+                    .add("centerX", center_x)
+                    .add("centerY", center_y)
+                    .add("step", step)
+                    .add("colorNum", color_num)
+                    .add("stepCenter", step_center)
+                    .add("alpha", alpha),
+            );
+            for color in colors {
+                w.empty("hc:color", Attrs::new().color("value", *color));
+            }
+            w.end("hc:gradation");
+        }
+        FillBrush::ImageBrush { mode, img } => {
+            w.start(
+                "hc:imgBrush",
+                Attrs::new().add("mode", image_brush_mode_str(mode)),
+            );
+            w.empty(
+                "hc:img",
+                Attrs::new()
+                    .add("binaryItemIDRef", &img.binary_item_id)
+                    .add("bright", img.bright)
+                    .add("contrast", img.contrast)
+                    .add("effect", image_effect_str(&img.effect))
+                    .add("alpha", img.alpha),
+            );
+            w.end("hc:imgBrush");
+        }
+        FillBrush::Combined {
+            win_brush,
+            gradation,
+            image_brush,
+        } => {
+            for brush in [win_brush, gradation, image_brush].into_iter().flatten() {
+                write_fill_brush_items(w, brush);
+            }
+        }
+    }
+}
+
+// ── charProperties ──
+
+fn write_char_properties(w: &mut XmlWriter, shapes: &[CharShape]) {
+    w.start(
+        "hh:charProperties",
+        Attrs::new().add("itemCnt", shapes.len()),
+    );
+    for cs in shapes {
+        w.start(
+            "hh:charPr",
+            Attrs::new()
+                .add("id", cs.id)
+                .add("height", cs.height)
+                .color("textColor", cs.text_color)
+                .color("shadeColor", cs.shade_color)
+                .flag("useFontSpace", cs.use_font_space)
+                .flag("useKerning", cs.use_kerning)
+                .add("symMark", sym_mark_str(&cs.sym_mark))
+                .opt("borderFillIDRef", cs.border_fill_id),
+        );
+        write_lang_group(w, "hh:fontRef", &cs.font_ref);
+        write_lang_group(w, "hh:ratio", &cs.ratio);
+        write_lang_group(w, "hh:spacing", &cs.spacing);
+        write_lang_group(w, "hh:relSz", &cs.rel_size);
+        write_lang_group(w, "hh:offset", &cs.offset);
+        if cs.bold {
+            w.empty("hh:bold", Attrs::new());
+        }
+        if cs.italic {
+            w.empty("hh:italic", Attrs::new());
+        }
+        if let Some(ref ul) = cs.underline {
+            w.empty(
+                "hh:underline",
+                Attrs::new()
+                    .add("type", underline_type_str(&ul.underline_type))
+                    .add("shape", line_type3_str(&ul.shape))
+                    .color("color", ul.color),
+            );
+        }
+        if let Some(ref so) = cs.strikeout {
+            w.empty(
+                "hh:strikeout",
+                Attrs::new()
+                    .add("shape", line_type3_str(&so.shape))
+                    .color("color", so.color),
+            );
+        }
+        if let Some(ref outline) = cs.outline {
+            w.empty(
+                "hh:outline",
+                Attrs::new().add("type", outline_type_str(outline)),
+            );
+        }
+        if let Some(ref sh) = cs.shadow {
+            w.empty(
+                "hh:shadow",
+                Attrs::new()
+                    .add("type", char_shadow_type_str(&sh.shadow_type))
+                    .color("color", sh.color)
+                    .add("offsetX", sh.offset_x)
+                    .add("offsetY", sh.offset_y),
+            );
+        }
+        if cs.emboss {
+            w.empty("hh:emboss", Attrs::new());
+        }
+        if cs.engrave {
+            w.empty("hh:engrave", Attrs::new());
+        }
+        if cs.superscript {
+            w.empty("hh:supscript", Attrs::new());
+        }
+        if cs.subscript {
+            w.empty("hh:subscript", Attrs::new());
+        }
+        w.end("hh:charPr");
+    }
+    w.end("hh:charProperties");
+}
+
+fn write_lang_group<T: std::fmt::Display>(w: &mut XmlWriter, tag: &str, g: &LangGroup<T>) {
+    w.empty(
+        tag,
+        Attrs::new()
+            .add("hangul", &g.hangul)
+            .add("latin", &g.latin)
+            .add("hanja", &g.hanja)
+            .add("japanese", &g.japanese)
+            .add("other", &g.other)
+            .add("symbol", &g.symbol)
+            .add("user", &g.user),
+    );
+}
+
+// ── tabProperties ──
+
+fn write_tab_properties(w: &mut XmlWriter, defs: &[TabDef]) {
+    w.start("hh:tabProperties", Attrs::new().add("itemCnt", defs.len()));
+    for td in defs {
+        let attrs = Attrs::new()
+            .add("id", td.id)
+            .flag("autoTabLeft", td.auto_tab_left)
+            .flag("autoTabRight", td.auto_tab_right);
+        if td.items.is_empty() {
+            w.empty("hh:tabPr", attrs);
+            continue;
+        }
+        w.start("hh:tabPr", attrs);
+        for item in &td.items {
+            w.empty(
+                "hh:tabItem",
+                Attrs::new()
+                    .add("pos", item.pos)
+                    .add("type", tab_type_str(&item.tab_type))
+                    .add("leader", line_type2_str(&item.leader)),
+            );
+        }
+        w.end("hh:tabPr");
+    }
+    w.end("hh:tabProperties");
+}
+
+// ── numberings ──
+
+fn write_numberings(w: &mut XmlWriter, nums: &[Numbering]) {
+    w.start("hh:numberings", Attrs::new().add("itemCnt", nums.len()));
+    for num in nums {
+        w.start(
+            "hh:numbering",
+            Attrs::new().add("id", num.id).add("start", num.start),
+        );
+        for level in &num.levels {
+            let attrs = Attrs::new()
+                .add("start", level.start)
+                .add("level", level.level)
+                .add("align", halign_str(&level.align))
+                .flag("useInstWidth", level.use_inst_width)
+                .flag("autoIndent", level.auto_indent)
+                .add("widthAdjust", level.width_adjust)
+                .add(
+                    "textOffsetType",
+                    text_offset_type_str(&level.text_offset_type),
+                )
+                .add("textOffset", level.text_offset)
+                .add("numFormat", number_type2_str(&level.num_format))
+                .add("charPrIDRef", level.char_shape_id.unwrap_or(u32::MAX))
+                .flag("checkable", level.checkable);
+            if level.format_string.is_empty() {
+                w.empty("hh:paraHead", attrs);
+            } else {
+                w.text_element("hh:paraHead", attrs, &level.format_string);
+            }
+        }
+        w.end("hh:numbering");
+    }
+    w.end("hh:numberings");
+}
+
+// ── bullets ──
+
+fn write_bullets(w: &mut XmlWriter, bullets: &[Bullet]) {
+    w.start("hh:bullets", Attrs::new().add("itemCnt", bullets.len()));
+    for bullet in bullets {
+        w.start(
+            "hh:bullet",
+            Attrs::new()
+                .add("id", bullet.id)
+                .add("char", bullet.bullet_char)
+                .opt("checkedChar", bullet.checked_char)
+                .flag("useImg", bullet.use_image),
+        );
+        if let Some(ref img) = bullet.image {
+            w.empty(
+                "hc:img",
+                Attrs::new()
+                    .add("binaryItemIDRef", &img.binary_item_id)
+                    .add("bright", img.bright)
+                    .add("contrast", img.contrast)
+                    .add("effect", image_effect_str(&img.effect)),
+            );
+        }
+        let ph = &bullet.para_head;
+        w.empty(
+            "hh:paraHead",
+            Attrs::new()
+                .add("level", ph.level)
+                .add("align", halign_str(&ph.align))
+                .flag("useInstWidth", ph.use_inst_width)
+                .flag("autoIndent", ph.auto_indent)
+                .add("widthAdjust", ph.width_adjust)
+                .add("textOffsetType", text_offset_type_str(&ph.text_offset_type))
+                .add("textOffset", ph.text_offset)
+                .add("charPrIDRef", ph.char_shape_id.unwrap_or(u32::MAX)),
+        );
+        w.end("hh:bullet");
+    }
+    w.end("hh:bullets");
+}
+
+// ── paraProperties ──
+
+fn write_para_properties(w: &mut XmlWriter, shapes: &[ParaShape]) {
+    w.start(
+        "hh:paraProperties",
+        Attrs::new().add("itemCnt", shapes.len()),
+    );
+    for ps in shapes {
+        w.start(
+            "hh:paraPr",
+            Attrs::new()
+                .add("id", ps.id)
+                .opt("tabPrIDRef", ps.tab_def_id)
+                .add("condense", ps.condense)
+                .flag("fontLineHeight", ps.font_line_height)
+                .flag("snapToGrid", ps.snap_to_grid)
+                .opt(
+                    "suppressLineNumbers",
+                    ps.suppress_line_numbers.map(|b| if b { "1" } else { "0" }),
+                )
+                .add("checked", "0"),
+        );
+        w.empty(
+            "hh:align",
+            Attrs::new()
+                .add("horizontal", halign_str(&ps.align.horizontal))
+                .add("vertical", valign_str(&ps.align.vertical)),
+        );
+
+        // Document model의 heading idRef는 1-based, HWPX는 0-based → -1
+        let (heading_type, id_ref, level) = match ps.heading {
+            Some(ref h) => (
+                heading_type_str(&h.heading_type),
+                h.id_ref.saturating_sub(1),
+                h.level,
+            ),
+            None => ("NONE", 0, 0),
+        };
+        w.empty(
+            "hh:heading",
+            Attrs::new()
+                .add("type", heading_type)
+                .add("idRef", id_ref)
+                .add("level", level),
+        );
+
+        let bs = &ps.break_setting;
+        w.empty(
+            "hh:breakSetting",
+            Attrs::new()
+                .add("breakLatinWord", break_latin_word_str(&bs.break_latin_word))
+                .add(
+                    "breakNonLatinWord",
+                    break_non_latin_word_str(&bs.break_non_latin_word),
+                )
+                .flag("widowOrphan", bs.widow_orphan)
+                .flag("keepWithNext", bs.keep_with_next)
+                .flag("keepLines", bs.keep_lines)
+                .flag("pageBreakBefore", bs.page_break_before)
+                .add("lineWrap", line_wrap_str(&bs.line_wrap)),
+        );
+        w.empty(
+            "hh:autoSpacing",
+            Attrs::new()
+                .flag("eAsianEng", ps.auto_spacing.east_asian_eng)
+                .flag("eAsianNum", ps.auto_spacing.east_asian_num),
+        );
+
+        w.start("hh:margin", Attrs::new());
+        let m = &ps.margin;
+        for (tag, v) in [
+            ("hc:intent", &m.indent),
+            ("hc:left", &m.left),
+            ("hc:right", &m.right),
+            ("hc:prev", &m.prev),
+            ("hc:next", &m.next),
+        ] {
+            w.empty(
+                tag,
+                Attrs::new()
+                    .add("value", v.value)
+                    .add("unit", value_unit_str(&v.unit)),
+            );
+        }
+        w.end("hh:margin");
+
+        w.empty(
+            "hh:lineSpacing",
+            Attrs::new()
+                .add("type", line_spacing_type_str(&ps.line_spacing.spacing_type))
+                .add("value", ps.line_spacing.value)
+                .add("unit", value_unit_str(&ps.line_spacing.unit)),
+        );
+        if let Some(ref b) = ps.border {
+            w.empty(
+                "hh:border",
+                Attrs::new()
+                    .add("borderFillIDRef", b.border_fill_id)
+                    .add("offsetLeft", b.offset_left)
+                    .add("offsetRight", b.offset_right)
+                    .add("offsetTop", b.offset_top)
+                    .add("offsetBottom", b.offset_bottom)
+                    .flag("connect", b.connect)
+                    .flag("ignoreMargin", b.ignore_margin),
+            );
+        }
+        w.end("hh:paraPr");
+    }
+    w.end("hh:paraProperties");
+}
+
+// ── styles ──
+
+fn write_styles(w: &mut XmlWriter, styles: &[Style]) {
+    w.start("hh:styles", Attrs::new().add("itemCnt", styles.len()));
+    for s in styles {
+        w.empty(
+            "hh:style",
+            Attrs::new()
+                .add("id", s.id)
+                .add("type", style_type_str(&s.style_type))
+                .add("name", &s.name)
+                .add("engName", &s.eng_name)
+                .opt("paraPrIDRef", s.para_shape_id)
+                .opt("charPrIDRef", s.char_shape_id)
+                .opt("nextStyleIDRef", s.next_style_id)
+                .opt("langID", s.lang_id)
+                .opt("lockForm", s.lock_form.map(|b| if b { "1" } else { "0" })),
+        );
+    }
+    w.end("hh:styles");
+}
+
+// ── memoProperties ──
+
+fn write_memo_properties(w: &mut XmlWriter, memos: &[MemoShape]) {
+    if memos.is_empty() {
+        return;
+    }
+    w.start(
+        "hh:memoProperties",
+        Attrs::new().add("itemCnt", memos.len()),
+    );
+    for m in memos {
+        w.empty(
+            "hh:memoPr",
+            Attrs::new()
+                .add("id", m.id)
+                .add("width", m.width)
+                .add("lineWidth", m.line_width)
+                .add("lineType", line_type3_str(&m.line_type))
+                .color("lineColor", m.line_color)
+                .color("fillColor", m.fill_color)
+                .color("activeColor", m.active_color)
+                .add(
+                    "memoType",
+                    match m.memo_type {
+                        MemoType::Normal => "NOMAL",
+                        MemoType::TrackChange => "USER_INSERT",
+                    },
+                ),
+        );
+    }
+    w.end("hh:memoProperties");
+}
+
+// ── trackChanges / trackChangeAuthors ──
+
+fn write_track_changes(w: &mut XmlWriter, changes: &[TrackChange]) {
+    if changes.is_empty() {
+        return;
+    }
+    w.start(
+        "hh:trackChanges",
+        Attrs::new().add("itemCnt", changes.len()),
+    );
+    for tc in changes {
+        w.empty(
+            "hh:trackChange",
+            Attrs::new()
+                .add(
+                    "type",
+                    match tc.change_type {
+                        TrackChangeType::Unknown => "UnKnown",
+                        TrackChangeType::Insert => "Insert",
+                        TrackChangeType::Delete => "Delete",
+                        TrackChangeType::CharShape => "CharShape",
+                        TrackChangeType::ParaShape => "ParaShape",
+                    },
+                )
+                .opt("date", tc.date.as_ref())
+                .add("authorID", tc.author_id)
+                .opt("charshapeID", tc.char_shape_id)
+                .opt("parashapeID", tc.para_shape_id)
+                .flag("hide", tc.hide)
+                .add("id", tc.id),
+        );
+    }
+    w.end("hh:trackChanges");
+}
+
+fn write_track_change_authors(w: &mut XmlWriter, authors: &[TrackChangeAuthor]) {
+    if authors.is_empty() {
+        return;
+    }
+    w.start(
+        "hh:trackChangeAuthors",
+        Attrs::new().add("itemCnt", authors.len()),
+    );
+    for a in authors {
+        w.empty(
+            "hh:trackChangeAuthor",
+            Attrs::new()
+                .add("name", &a.name)
+                .flag("mark", a.mark)
+                .color("color", a.color)
+                .add("id", a.id),
+        );
+    }
+    w.end("hh:trackChangeAuthors");
+}
+
+// ── enum → 문자열 (header 파서의 역변환) ──
+
+fn font_type_str(t: &FontType) -> &'static str {
+    match t {
+        FontType::Rep => "REP",
+        FontType::Ttf => "TTF",
+        FontType::Ttc => "TTC",
+        FontType::Hft => "HFT",
+    }
+}
+
+fn font_category_str(c: &FontCategory) -> &'static str {
+    match c {
+        FontCategory::Unknown => "FCAT_UNKNOWN",
+        FontCategory::Myungjo => "FCAT_MYUNGJO",
+        FontCategory::Gothic => "FCAT_GOTHIC",
+        FontCategory::SSerif => "FCAT_SSERIF",
+        FontCategory::BrushScript => "FCAT_BRUSHSCRIPT",
+        FontCategory::NonRectMj => "FCAT_NONRECTMJ",
+        FontCategory::NonRectGt => "FCAT_NONRECTGT",
+    }
+}
+
+fn sym_mark_str(s: &SymMark) -> &'static str {
+    match s {
+        SymMark::None => "NONE",
+        SymMark::DotAbove => "DOT_ABOVE",
+        SymMark::RingAbove => "RING_ABOVE",
+        SymMark::Tilde => "TILDE",
+        SymMark::Caron => "CARON",
+        SymMark::Side => "SIDE",
+        SymMark::Colon => "COLON",
+        SymMark::GraveAccent => "GRAVE_ACCENT",
+        SymMark::AcuteAccent => "ACUTE_ACCENT",
+        SymMark::Circumflex => "CIRCUMFLEX",
+        SymMark::Macron => "MACRON",
+        SymMark::HookAbove => "HOOK_ABOVE",
+        SymMark::DotBelow => "DOT_BELOW",
+    }
+}
+
+fn underline_type_str(t: &UnderlineType) -> &'static str {
+    match t {
+        UnderlineType::Bottom => "BOTTOM",
+        UnderlineType::Center => "CENTER",
+        UnderlineType::Top => "TOP",
+    }
+}
+
+pub fn line_type3_str(t: &LineType3) -> &'static str {
+    match t {
+        LineType3::None => "NONE",
+        LineType3::Solid => "SOLID",
+        LineType3::Dot => "DOT",
+        LineType3::Dash => "DASH",
+        LineType3::DashDot => "DASH_DOT",
+        LineType3::DashDotDot => "DASH_DOT_DOT",
+        LineType3::LongDash => "LONG_DASH",
+        LineType3::Circle => "CIRCLE",
+        LineType3::DoubleSlim => "DOUBLE_SLIM",
+        LineType3::SlimThick => "SLIM_THICK",
+        LineType3::ThickSlim => "THICK_SLIM",
+        LineType3::SlimThickSlim => "SLIM_THICK_SLIM",
+        LineType3::Wave => "WAVE",
+        LineType3::DoubleWave => "DOUBLEWAVE",
+    }
+}
+
+pub fn line_type2_str(t: &LineType2) -> &'static str {
+    match t {
+        LineType2::None => "NONE",
+        LineType2::Solid => "SOLID",
+        LineType2::Dot => "DOT",
+        LineType2::Dash => "DASH",
+        LineType2::DashDot => "DASH_DOT",
+        LineType2::DashDotDot => "DASH_DOT_DOT",
+        LineType2::LongDash => "LONG_DASH",
+        LineType2::Circle => "CIRCLE",
+        LineType2::DoubleSlim => "DOUBLE_SLIM",
+        LineType2::SlimThick => "SLIM_THICK",
+        LineType2::ThickSlim => "THICK_SLIM",
+        LineType2::SlimThickSlim => "SLIM_THICK_SLIM",
+    }
+}
+
+fn outline_type_str(t: &OutlineType) -> &'static str {
+    match t {
+        OutlineType::None => "NONE",
+        OutlineType::Solid => "SOLID",
+        OutlineType::Dot => "DOT",
+        OutlineType::Thick => "THICK",
+        OutlineType::Dash => "DASH",
+        OutlineType::DashDot => "DASH_DOT",
+        OutlineType::DashDotDot => "DASH_DOT_DOT",
+    }
+}
+
+fn char_shadow_type_str(t: &CharShadowType) -> &'static str {
+    match t {
+        CharShadowType::None => "NONE",
+        CharShadowType::Drop => "DROP",
+        CharShadowType::Continuous => "CONTINUOUS",
+    }
+}
+
+fn center_line_type_str(t: &CenterLineType) -> &'static str {
+    match t {
+        CenterLineType::None => "NONE",
+        CenterLineType::Left => "LEFT",
+        CenterLineType::Right => "RIGHT",
+        CenterLineType::Both => "BOTH",
+    }
+}
+
+fn slash_type_str(t: &SlashType) -> &'static str {
+    match t {
+        SlashType::None => "NONE",
+        SlashType::Center => "CENTER",
+        SlashType::CenterBelow => "CENTER_BELOW",
+        SlashType::CenterAbove => "CENTER_ABOVE",
+        SlashType::All => "ALL",
+    }
+}
+
+fn hatch_style_str(s: &HatchStyle) -> &'static str {
+    match s {
+        HatchStyle::Horizontal => "HORIZONTAL",
+        HatchStyle::Vertical => "VERTICAL",
+        HatchStyle::BackSlash => "BACK_SLASH",
+        HatchStyle::Slash => "SLASH",
+        HatchStyle::Cross => "CROSS",
+        HatchStyle::CrossDiagonal => "CROSS_DIAGONAL",
+    }
+}
+
+fn gradation_type_str(t: &GradationType) -> &'static str {
+    match t {
+        GradationType::Linear => "LINEAR",
+        GradationType::Radial => "RADIAL",
+        GradationType::Conical => "CONICAL",
+        GradationType::Square => "SQUARE",
+    }
+}
+
+fn image_brush_mode_str(m: &ImageBrushMode) -> &'static str {
+    match m {
+        ImageBrushMode::Tile => "TILE",
+        ImageBrushMode::TileHorzTop => "TILE_HORZ_TOP",
+        ImageBrushMode::TileHorzBottom => "TILE_HORZ_BOTTOM",
+        ImageBrushMode::TileVertLeft => "TILE_VERT_LEFT",
+        ImageBrushMode::TileVertRight => "TILE_VERT_RIGHT",
+        ImageBrushMode::Total => "TOTAL",
+        ImageBrushMode::Center => "CENTER",
+        ImageBrushMode::CenterTop => "CENTER_TOP",
+        ImageBrushMode::CenterBottom => "CENTER_BOTTOM",
+        ImageBrushMode::LeftCenter => "LEFT_CENTER",
+        ImageBrushMode::LeftTop => "LEFT_TOP",
+        ImageBrushMode::LeftBottom => "LEFT_BOTTOM",
+        ImageBrushMode::RightCenter => "RIGHT_CENTER",
+        ImageBrushMode::RightTop => "RIGHT_TOP",
+        ImageBrushMode::RightBottom => "RIGHT_BOTTOM",
+        ImageBrushMode::Zoom => "ZOOM",
+    }
+}
+
+pub fn image_effect_str(e: &ImageEffect) -> &'static str {
+    match e {
+        ImageEffect::RealPic => "REAL_PIC",
+        ImageEffect::GrayScale => "GRAY_SCALE",
+        ImageEffect::BlackWhite => "BLACK_WHITE",
+    }
+}
+
+fn tab_type_str(t: &TabType) -> &'static str {
+    match t {
+        TabType::Left => "LEFT",
+        TabType::Right => "RIGHT",
+        TabType::Center => "CENTER",
+        TabType::Decimal => "DECIMAL",
+    }
+}
+
+pub fn halign_str(a: &HAlign) -> &'static str {
+    match a {
+        HAlign::Justify => "JUSTIFY",
+        HAlign::Left => "LEFT",
+        HAlign::Right => "RIGHT",
+        HAlign::Center => "CENTER",
+        HAlign::Distribute => "DISTRIBUTE",
+        HAlign::DistributeSpace => "DISTRIBUTE_SPACE",
+        HAlign::Inside => "INSIDE",
+        HAlign::Outside => "OUTSIDE",
+    }
+}
+
+fn valign_str(a: &VAlign) -> &'static str {
+    match a {
+        VAlign::Top => "TOP",
+        VAlign::Center => "CENTER",
+        VAlign::Bottom => "BOTTOM",
+        VAlign::Baseline => "BASELINE",
+        VAlign::Inside => "INSIDE",
+        VAlign::Outside => "OUTSIDE",
+    }
+}
+
+fn heading_type_str(t: &HeadingType) -> &'static str {
+    match t {
+        HeadingType::None => "NONE",
+        HeadingType::Outline => "OUTLINE",
+        HeadingType::Number => "NUMBER",
+        HeadingType::Bullet => "BULLET",
+    }
+}
+
+fn break_latin_word_str(b: &BreakLatinWord) -> &'static str {
+    match b {
+        BreakLatinWord::KeepWord => "KEEP_WORD",
+        BreakLatinWord::Hyphenation => "HYPHENATION",
+        BreakLatinWord::BreakWord => "BREAK_WORD",
+    }
+}
+
+fn break_non_latin_word_str(b: &BreakNonLatinWord) -> &'static str {
+    match b {
+        BreakNonLatinWord::KeepWord => "KEEP_WORD",
+        BreakNonLatinWord::BreakWord => "BREAK_WORD",
+    }
+}
+
+fn line_wrap_str(l: &LineWrap) -> &'static str {
+    match l {
+        LineWrap::Break => "BREAK",
+        LineWrap::Squeeze => "SQUEEZE",
+        LineWrap::Keep => "KEEP",
+    }
+}
+
+fn line_spacing_type_str(t: &LineSpacingType) -> &'static str {
+    match t {
+        LineSpacingType::Percent => "PERCENT",
+        LineSpacingType::Fixed => "FIXED",
+        LineSpacingType::AtLeast => "AT_LEAST",
+        LineSpacingType::Between => "BETWEEN_LINES",
+    }
+}
+
+fn value_unit_str(u: &ValueUnit) -> &'static str {
+    match u {
+        ValueUnit::HwpUnit => "HWPUNIT",
+        ValueUnit::Char => "CHAR",
+    }
+}
+
+/// 번호/글머리표 textOffsetType. 파서가 PERCENT를 HwpUnit으로 접으므로 기본값 PERCENT로 복원
+fn text_offset_type_str(u: &ValueUnit) -> &'static str {
+    match u {
+        ValueUnit::HwpUnit => "PERCENT",
+        ValueUnit::Char => "CHAR",
+    }
+}
+
+fn style_type_str(t: &StyleType) -> &'static str {
+    match t {
+        StyleType::Para => "PARA",
+        StyleType::Char => "CHAR",
+    }
+}
+
+fn number_type2_str(t: &NumberType2) -> &'static str {
+    match t {
+        NumberType2::Digit => "DIGIT",
+        NumberType2::CircledDigit => "CIRCLED_DIGIT",
+        NumberType2::RomanCapital => "ROMAN_CAPITAL",
+        NumberType2::RomanSmall => "ROMAN_SMALL",
+        NumberType2::LatinCapital => "LATIN_CAPITAL",
+        NumberType2::LatinSmall => "LATIN_SMALL",
+        NumberType2::CircledLatinCapital => "CIRCLED_LATIN_CAPITAL",
+        NumberType2::CircledLatinSmall => "CIRCLED_LATIN_SMALL",
+        NumberType2::HangulSyllable => "HANGUL_SYLLABLE",
+        NumberType2::CircledHangulSyllable => "CIRCLED_HANGUL_SYLLABLE",
+        NumberType2::HangulJamo => "HANGUL_JAMO",
+        NumberType2::CircledHangulJamo => "CIRCLED_HANGUL_JAMO",
+        NumberType2::HangulPhonetic => "HANGUL_PHONETIC",
+        NumberType2::Ideograph => "IDEOGRAPH",
+        NumberType2::CircledIdeograph => "CIRCLED_IDEOGRAPH",
+        NumberType2::DecagonCircle => "DECAGON_CIRCLE",
+        NumberType2::DecagonCircleHanja => "DECAGON_CIRCLE_HANJA",
+        NumberType2::Symbol => "SYMBOL",
+        NumberType2::UserChar => "USER_CHAR",
+    }
+}
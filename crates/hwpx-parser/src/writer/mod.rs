@@ -0,0 +1,411 @@
+mod body;
+mod chart;
+mod header;
+pub mod xml;
+
+use crate::error::HwpxError;
+use hwp_model::document::{BinaryItem, Document, ImageFormat};
+use hwp_model::hints::ManifestEntry;
+use hwp_model::section::MasterPage;
+use std::collections::HashSet;
+use std::io::{Cursor, Seek, Write};
+use xml::{Attrs, XmlWriter, OWPML_NAMESPACES};
+use zip::write::SimpleFileOptions;
+use zip::CompressionMethod;
+
+const MIMETYPE: &str = "application/hwp+zip";
+const DEFAULT_XML_VERSION: &str = "1.2";
+const DEFAULT_APP_VERSION: &str = "11.0.0.0";
+const SETTINGS_PATH: &str = "settings.xml";
+const PREVIEW_TEXT_PATH: &str = "Preview/PrvText.txt";
+
+/// 공통 Document 모델을 HWPX(ZIP/XML) 패키지로 직렬화한다.
+///
+/// `Document::hwpx_hints`가 있으면 version.xml의 버전 정보와
+/// content.hpf의 추가 manifest 항목을 그대로 복원한다.
+pub struct HwpxWriter;
+
+impl HwpxWriter {
+    /// Document → HWPX 바이트
+    pub fn write(doc: &Document) -> Result<Vec<u8>, HwpxError> {
+        let mut cursor = Cursor::new(Vec::new());
+        Self::write_to(doc, &mut cursor)?;
+        Ok(cursor.into_inner())
+    }
+
+    /// Write+Seek를 구현하는 대상에 HWPX 작성
+    pub fn write_to<W: Write + Seek>(doc: &Document, writer: W) -> Result<(), HwpxError> {
+        let mut zip = zip::ZipWriter::new(writer);
+        let stored = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+        let deflated = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        // mimetype은 압축하지 않은 첫 번째 항목이어야 한다 (OCF)
+        zip.start_file("mimetype", stored)?;
+        zip.write_all(MIMETYPE.as_bytes())?;
+
+        zip.start_file("version.xml", deflated)?;
+        zip.write_all(write_version(doc).as_bytes())?;
+
+        zip.start_file("Contents/header.xml", deflated)?;
+        zip.write_all(header::write_header(doc).as_bytes())?;
+
+        let mut master_page_base = 0;
+        for (i, section) in doc.sections.iter().enumerate() {
+            zip.start_file(format!("Contents/section{}.xml", i), deflated)?;
+            zip.write_all(body::write_section(section, master_page_base).as_bytes())?;
+            master_page_base += section.definition.master_pages.len();
+        }
+
+        for (i, master) in master_pages(doc).enumerate() {
+            zip.start_file(format!("Contents/masterpage{}.xml", i), deflated)?;
+            zip.write_all(body::write_master_page(master, i).as_bytes())?;
+        }
+
+        for (path, data) in chart::chart_parts(doc) {
+            zip.start_file(path, deflated)?;
+            zip.write_all(chart::write_chart_xml(data).as_bytes())?;
+        }
+
+        zip.start_file(PREVIEW_TEXT_PATH, deflated)?;
+        zip.write_all(doc.preview_text().as_bytes())?;
+
+        for entry in preserved_parts(doc) {
+            zip.start_file(entry.href.as_str(), deflated)?;
+            zip.write_all(&entry.data)?;
+        }
+
+        zip.start_file(SETTINGS_PATH, deflated)?;
+        zip.write_all(write_settings(doc).as_bytes())?;
+
+        zip.start_file("Contents/content.hpf", deflated)?;
+        zip.write_all(write_content_hpf(doc).as_bytes())?;
+
+        zip.start_file("META-INF/container.xml", deflated)?;
+        zip.write_all(write_container().as_bytes())?;
+
+        zip.start_file("META-INF/manifest.xml", deflated)?;
+        zip.write_all(write_manifest().as_bytes())?;
+
+        for item in &doc.binaries.items {
+            zip.start_file(binary_path(item), deflated)?;
+            zip.write_all(&item.data)?;
+        }
+
+        zip.finish()?;
+        Ok(())
+    }
+}
+
+/// version.xml
+fn write_version(doc: &Document) -> String {
+    let hints = doc.hwpx_hints.as_ref();
+    let xml_version = hints
+        .and_then(|h| h.xml_version.as_deref())
+        .filter(|v| !v.is_empty())
+        .unwrap_or(DEFAULT_XML_VERSION);
+    let app_version = hints
+        .and_then(|h| h.app_version.as_deref())
+        .filter(|v| !v.is_empty())
+        .unwrap_or(DEFAULT_APP_VERSION);
+
+    let mut w = XmlWriter::new();
+    w.empty(
+        "hv:HCFVersion",
+        Attrs::new()
+            .add("xmlns:hv", "http://www.hancom.co.kr/hwpml/2011/version")
+            .add("tagetApplication", "WORDPROCESSOR")
+            .add("major", 5)
+            .add("minor", 1)
+            .add("micro", 0)
+            .add("buildNumber", 1)
+            .add("os", 1)
+            .add("xmlVersion", xml_version)
+            .add("application", "Hancom Office Hangul")
+            .add("appVersion", app_version),
+    );
+    w.finish()
+}
+
+/// META-INF/container.xml
+fn write_container() -> String {
+    let mut w = XmlWriter::new();
+    w.start(
+        "ocf:container",
+        Attrs::new()
+            .add(
+                "xmlns:ocf",
+                "urn:oasis:names:tc:opendocument:xmlns:container",
+            )
+            .add("xmlns:hpf", "http://www.hancom.co.kr/schema/2011/hpf"),
+    );
+    w.start("ocf:rootfiles", Attrs::new());
+    for (path, media_type) in [
+        ("Contents/content.hpf", "application/hwpml-package+xml"),
+        (PREVIEW_TEXT_PATH, "text/plain"),
+    ] {
+        w.empty(
+            "ocf:rootfile",
+            Attrs::new()
+                .add("full-path", path)
+                .add("media-type", media_type),
+        );
+    }
+    w.end("ocf:rootfiles");
+    w.end("ocf:container");
+    w.finish()
+}
+
+/// META-INF/manifest.xml
+fn write_manifest() -> String {
+    let mut w = XmlWriter::new();
+    w.empty(
+        "odf:manifest",
+        Attrs::new().add(
+            "xmlns:odf",
+            "urn:oasis:names:tc:opendocument:xmlns:manifest:1.0",
+        ),
+    );
+    w.finish()
+}
+
+/// settings.xml (캐럿 위치)
+fn write_settings(doc: &Document) -> String {
+    let hints = doc.hwp_hints.as_ref();
+    let mut w = XmlWriter::new();
+    w.start(
+        "ha:HWPApplicationSetting",
+        Attrs::new()
+            .add("xmlns:ha", "http://www.hancom.co.kr/hwpml/2011/app")
+            .add(
+                "xmlns:config",
+                "urn:oasis:names:tc:opendocument:xmlns:config:1.0",
+            ),
+    );
+    w.empty(
+        "ha:CaretPosition",
+        Attrs::new()
+            .add(
+                "listIDRef",
+                hints.and_then(|h| h.caret_list_id).unwrap_or(0),
+            )
+            .add(
+                "paraIDRef",
+                hints.and_then(|h| h.caret_para_id).unwrap_or(0),
+            )
+            .add("pos", hints.and_then(|h| h.caret_char_pos).unwrap_or(0)),
+    );
+    w.end("ha:HWPApplicationSetting");
+    w.finish()
+}
+
+/// Contents/content.hpf (OPF 패키지: 메타데이터 + manifest + spine)
+fn write_content_hpf(doc: &Document) -> String {
+    let meta = &doc.meta;
+    let mut w = XmlWriter::new();
+    w.start(
+        "opf:package",
+        Attrs::new()
+            .namespaces(OWPML_NAMESPACES)
+            .add("version", "")
+            .add("unique-identifier", "")
+            .add("id", ""),
+    );
+
+    w.start("opf:metadata", Attrs::new());
+    w.text_element(
+        "opf:title",
+        Attrs::new(),
+        meta.title.as_deref().unwrap_or_default(),
+    );
+    w.text_element(
+        "opf:language",
+        Attrs::new(),
+        meta.language.as_deref().unwrap_or("ko"),
+    );
+    for (name, value) in [
+        ("creator", &meta.creator),
+        ("subject", &meta.subject),
+        ("description", &meta.description),
+        ("CreatedDate", &meta.created_date),
+        ("ModifiedDate", &meta.modified_date),
+        ("keyword", &meta.keywords),
+    ] {
+        let attrs = Attrs::new().add("name", name).add("content", "text");
+        match value {
+            Some(v) => w.text_element("opf:meta", attrs, v),
+            None => w.empty("opf:meta", attrs),
+        }
+    }
+    w.end("opf:metadata");
+
+    w.start("opf:manifest", Attrs::new());
+    let item = |w: &mut XmlWriter, id: &str, href: &str, media_type: &str| {
+        w.empty(
+            "opf:item",
+            Attrs::new()
+                .add("id", id)
+                .add("href", href)
+                .add("media-type", media_type),
+        );
+    };
+    item(&mut w, "header", "Contents/header.xml", "application/xml");
+    for i in 0..doc.sections.len() {
+        item(
+            &mut w,
+            &format!("section{}", i),
+            &format!("Contents/section{}.xml", i),
+            "application/xml",
+        );
+    }
+    for i in 0..master_pages(doc).count() {
+        item(
+            &mut w,
+            &format!("masterpage{}", i),
+            &format!("Contents/masterpage{}.xml", i),
+            "application/xml",
+        );
+    }
+    for bin in &doc.binaries.items {
+        let attrs = Attrs::new()
+            .add("id", &bin.id)
+            .add("href", binary_path(bin))
+            .add("media-type", image_media_type(&bin.format))
+            .add("isEmbeded", 1);
+        w.empty("opf:item", attrs);
+    }
+    for entry in extra_manifest_entries(doc) {
+        item(&mut w, &entry.id, &entry.href, &entry.media_type);
+    }
+    w.end("opf:manifest");
+
+    w.start("opf:spine", Attrs::new());
+    w.empty("opf:itemref", Attrs::new().add("idref", "header"));
+    for i in 0..doc.sections.len() {
+        w.empty(
+            "opf:itemref",
+            Attrs::new().add("idref", format!("section{}", i)),
+        );
+    }
+    w.end("opf:spine");
+
+    w.end("opf:package");
+    w.finish()
+}
+
+/// header/section/BinData 외의 manifest 항목.
+///
+/// 힌트의 항목 중 writer가 다시 만드는 파트(settings.xml, 차트)와 원본 내용을 그대로 쓰는
+/// 파트를 남긴다. settings.xml 항목이 없으면 추가한다.
+fn extra_manifest_entries(doc: &Document) -> Vec<ManifestEntry> {
+    let generated = generated_paths(doc);
+    let mut entries: Vec<ManifestEntry> = hint_entries(doc)
+        .filter(|e| generated.contains(e.href.as_str()) || !e.data.is_empty())
+        .map(|e| ManifestEntry {
+            id: e.id.clone(),
+            href: e.href.clone(),
+            media_type: e.media_type.clone(),
+            ..Default::default()
+        })
+        .collect();
+    if !entries.iter().any(|e| e.href == SETTINGS_PATH) {
+        entries.push(ManifestEntry {
+            id: "settings".to_string(),
+            href: SETTINGS_PATH.to_string(),
+            media_type: "application/xml".to_string(),
+            ..Default::default()
+        });
+    }
+    entries
+}
+
+/// 모델로 읽지 않은 파트 (스크립트, 사용자 정의 XML 등): 원본 내용을 그대로 다시 쓴다.
+/// writer가 직접 만드는 경로와 겹치면 새로 만든 쪽을 쓴다.
+fn preserved_parts(doc: &Document) -> Vec<&ManifestEntry> {
+    let generated = generated_paths(doc);
+    let mut seen = HashSet::new();
+    hint_entries(doc)
+        .filter(|e| !e.data.is_empty() && !generated.contains(e.href.as_str()))
+        .filter(|e| seen.insert(e.href.as_str()))
+        .collect()
+}
+
+fn hint_entries(doc: &Document) -> impl Iterator<Item = &ManifestEntry> {
+    doc.hwpx_hints
+        .iter()
+        .flat_map(|h| h.extra_manifest_entries.iter())
+}
+
+/// writer가 직접 만드는 패키지 경로
+fn generated_paths(doc: &Document) -> HashSet<String> {
+    let mut paths: HashSet<String> = [
+        "mimetype",
+        "version.xml",
+        "Contents/header.xml",
+        "Contents/content.hpf",
+        "META-INF/container.xml",
+        "META-INF/manifest.xml",
+        PREVIEW_TEXT_PATH,
+        SETTINGS_PATH,
+    ]
+    .into_iter()
+    .map(str::to_string)
+    .collect();
+    paths.extend((0..doc.sections.len()).map(|i| format!("Contents/section{}.xml", i)));
+    paths.extend((0..master_pages(doc).count()).map(|i| format!("Contents/masterpage{}.xml", i)));
+    paths.extend(
+        chart::chart_parts(doc)
+            .into_iter()
+            .map(|(path, _)| path.to_string()),
+    );
+    paths.extend(doc.binaries.items.iter().map(binary_path));
+    paths
+}
+
+/// 문서 전체 바탕쪽 (구역 순서, masterpageN 번호 순)
+fn master_pages(doc: &Document) -> impl Iterator<Item = &MasterPage> {
+    doc.sections
+        .iter()
+        .flat_map(|s| s.definition.master_pages.iter())
+}
+
+/// 패키지 내 바이너리 경로. HWPX에서 읽은 경로는 그대로, 그 외는 BinData/ 아래에 둔다.
+fn binary_path(item: &BinaryItem) -> String {
+    if item.src.starts_with("BinData/") || item.src.starts_with("Contents/") {
+        item.src.clone()
+    } else {
+        let name = item.src.rsplit(['/', '\\']).next().unwrap_or_default();
+        if name.is_empty() {
+            format!("BinData/{}.{}", item.id, image_extension(&item.format))
+        } else {
+            format!("BinData/{}", name)
+        }
+    }
+}
+
+fn image_extension(format: &ImageFormat) -> &str {
+    match format {
+        ImageFormat::Png => "png",
+        ImageFormat::Jpg => "jpg",
+        ImageFormat::Bmp => "bmp",
+        ImageFormat::Gif => "gif",
+        ImageFormat::Tiff => "tif",
+        ImageFormat::Wmf => "wmf",
+        ImageFormat::Emf => "emf",
+        ImageFormat::Svg => "svg",
+        ImageFormat::Unknown(ext) => ext,
+    }
+}
+
+fn image_media_type(format: &ImageFormat) -> String {
+    match format {
+        ImageFormat::Png => "image/png".to_string(),
+        ImageFormat::Jpg => "image/jpg".to_string(),
+        ImageFormat::Bmp => "image/bmp".to_string(),
+        ImageFormat::Gif => "image/gif".to_string(),
+        ImageFormat::Tiff => "image/tiff".to_string(),
+        ImageFormat::Wmf => "image/x-wmf".to_string(),
+        ImageFormat::Emf => "image/x-emf".to_string(),
+        ImageFormat::Svg => "image/svg+xml".to_string(),
+        ImageFormat::Unknown(ext) => format!("image/{}", ext),
+    }
+}
@@ -0,0 +1,218 @@
+//! 문자열 기반 XML 작성기. HWPX 파트와 hwp-core의 DOCX 파트가 함께 쓴다
+//! String-based XML writer shared by the HWPX writer and hwp-core's DOCX export
+
+use hwp_model::types::Color;
+
+/// XML 선언 (한글이 저장하는 형식과 동일)
+pub const XML_DECL: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?>"#;
+
+/// OWPML 루트 요소에 붙는 네임스페이스 선언
+pub const OWPML_NAMESPACES: &[(&str, &str)] = &[
+    ("xmlns:ha", "http://www.hancom.co.kr/hwpml/2011/app"),
+    ("xmlns:hp", "http://www.hancom.co.kr/hwpml/2011/paragraph"),
+    ("xmlns:hp10", "http://www.hancom.co.kr/hwpml/2016/paragraph"),
+    ("xmlns:hs", "http://www.hancom.co.kr/hwpml/2011/section"),
+    ("xmlns:hc", "http://www.hancom.co.kr/hwpml/2011/core"),
+    ("xmlns:hh", "http://www.hancom.co.kr/hwpml/2011/head"),
+    ("xmlns:hhs", "http://www.hancom.co.kr/hwpml/2011/history"),
+    ("xmlns:hm", "http://www.hancom.co.kr/hwpml/2011/master-page"),
+    ("xmlns:hpf", "http://www.hancom.co.kr/schema/2011/hpf"),
+    ("xmlns:dc", "http://purl.org/dc/elements/1.1/"),
+    ("xmlns:opf", "http://www.idpf.org/2007/opf/"),
+    (
+        "xmlns:ooxmlchart",
+        "http://www.hancom.co.kr/hwpml/2016/ooxmlchart",
+    ),
+    ("xmlns:epub", "http://www.idpf.org/2007/ops"),
+    (
+        "xmlns:config",
+        "urn:oasis:names:tc:opendocument:xmlns:config:1.0",
+    ),
+];
+
+/// 속성 목록. (이름, 값) 쌍을 순서대로 유지한다.
+#[derive(Default)]
+pub struct Attrs(Vec<(&'static str, String)>);
+
+impl Attrs {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn add(mut self, name: &'static str, value: impl ToString) -> Self {
+        self.0.push((name, value.to_string()));
+        self
+    }
+
+    /// 값이 있을 때만 속성 추가
+    pub fn opt<T: ToString>(self, name: &'static str, value: Option<T>) -> Self {
+        match value {
+            Some(v) => self.add(name, v),
+            None => self,
+        }
+    }
+
+    /// bool → "1"/"0"
+    pub fn flag(self, name: &'static str, value: bool) -> Self {
+        self.add(name, if value { "1" } else { "0" })
+    }
+
+    /// Color → "#BBGGRR" / "none"
+    pub fn color(self, name: &'static str, value: Color) -> Self {
+        self.add(name, color_str(value))
+    }
+
+    /// 네임스페이스 선언 추가
+    pub fn namespaces(mut self, ns: &[(&'static str, &'static str)]) -> Self {
+        for (name, uri) in ns {
+            self.0.push((name, uri.to_string()));
+        }
+        self
+    }
+}
+
+/// 문자열 기반 XML 작성기
+pub struct XmlWriter {
+    buf: String,
+}
+
+impl Default for XmlWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl XmlWriter {
+    /// XML 선언을 포함한 새 문서
+    pub fn new() -> Self {
+        Self::with_declaration(XML_DECL)
+    }
+
+    /// 다른 XML 선언으로 시작 (DOCX 등 다른 형식의 파트용)
+    pub fn with_declaration(declaration: &str) -> Self {
+        Self {
+            buf: String::from(declaration),
+        }
+    }
+
+    /// XML 선언 없이 시작 (조각 작성용)
+    pub fn fragment() -> Self {
+        Self { buf: String::new() }
+    }
+
+    pub fn start(&mut self, name: &str, attrs: Attrs) {
+        self.open_tag(name, attrs);
+        self.buf.push('>');
+    }
+
+    pub fn empty(&mut self, name: &str, attrs: Attrs) {
+        self.open_tag(name, attrs);
+        self.buf.push_str("/>");
+    }
+
+    pub fn end(&mut self, name: &str) {
+        self.buf.push_str("</");
+        self.buf.push_str(name);
+        self.buf.push('>');
+    }
+
+    /// 시작 태그 + 텍스트 + 종료 태그
+    pub fn text_element(&mut self, name: &str, attrs: Attrs, text: &str) {
+        self.start(name, attrs);
+        self.text(text);
+        self.end(name);
+    }
+
+    pub fn text(&mut self, text: &str) {
+        escape_into(&mut self.buf, text, false);
+    }
+
+    /// 다른 작성기에서 만든 조각을 그대로 덧붙임
+    pub fn raw(&mut self, xml: &str) {
+        self.buf.push_str(xml);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    pub fn finish(self) -> String {
+        self.buf
+    }
+
+    fn open_tag(&mut self, name: &str, attrs: Attrs) {
+        self.buf.push('<');
+        self.buf.push_str(name);
+        for (key, value) in attrs.0 {
+            self.buf.push(' ');
+            self.buf.push_str(key);
+            self.buf.push_str("=\"");
+            escape_into(&mut self.buf, &value, true);
+            self.buf.push('"');
+        }
+    }
+}
+
+/// XML 이스케이프. XML 1.0에서 쓸 수 없는 제어 문자는 버린다.
+fn escape_into(buf: &mut String, s: &str, attr: bool) {
+    for c in s.chars() {
+        match c {
+            '&' => buf.push_str("&amp;"),
+            '<' => buf.push_str("&lt;"),
+            '>' => buf.push_str("&gt;"),
+            '"' if attr => buf.push_str("&quot;"),
+            '\n' if attr => buf.push_str("&#10;"),
+            '\r' => buf.push_str("&#13;"),
+            '\t' if attr => buf.push_str("&#9;"),
+            '\t' | '\n' => buf.push(c),
+            c if (c as u32) < 0x20 || c == '\u{FFFE}' || c == '\u{FFFF}' => {}
+            _ => buf.push(c),
+        }
+    }
+}
+
+/// RGB Color(0x00RRGGBB) → HWPX 색상 문자열 (#BBGGRR, 없으면 "none")
+///
+/// `utils::parse_color`의 역변환.
+pub fn color_str(color: Color) -> String {
+    match color {
+        Some(rgb) => {
+            let r = (rgb >> 16) & 0xFF;
+            let g = (rgb >> 8) & 0xFF;
+            let b = rgb & 0xFF;
+            format!("#{:02X}{:02X}{:02X}", b, g, r)
+        }
+        None => "none".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::parse_color;
+
+    #[test]
+    fn test_color_str_roundtrip() {
+        for rgb in [0x000000, 0xFFFFFF, 0xFF0000, 0x0000FF, 0xE7E7A5] {
+            assert_eq!(parse_color(&color_str(Some(rgb))), Some(rgb));
+        }
+        assert_eq!(parse_color(&color_str(None)), None);
+    }
+
+    #[test]
+    fn test_escape_attr_and_text() {
+        let mut w = XmlWriter::fragment();
+        w.text_element("a", Attrs::new().add("v", "\"<&>\""), "x < y & z");
+        assert_eq!(
+            w.finish(),
+            r#"<a v="&quot;&lt;&amp;&gt;&quot;">x &lt; y &amp; z</a>"#
+        );
+    }
+
+    #[test]
+    fn test_control_chars_dropped() {
+        let mut w = XmlWriter::fragment();
+        w.text("a\u{0001}b\u{000B}c");
+        assert_eq!(w.finish(), "abc");
+    }
+}
@@ -1061,6 +1061,26 @@ fn extract_text_from_sublist(sl: &SubList) -> String {
     text
 }
 
+#[test]
+fn parse_master_page() {
+    let doc = HwpxParser::parse(&fixture("table-bug.hwpx")).unwrap();
+    let def = &doc.sections[0].definition;
+    assert_eq!(def.master_pages.len(), 1);
+
+    let master = &def.master_pages[0];
+    assert_eq!(master.id_ref, Some(0));
+    assert_eq!(master.apply_page_type, PageApplyType::Both);
+    assert_eq!(master.content.text_width, Some(48190));
+    assert_eq!(master.content.paragraphs.len(), 1);
+
+    // masterpage0.xml은 바탕쪽으로 읽었으므로 추가 manifest 항목에 남지 않는다
+    let hints = doc.hwpx_hints.as_ref().unwrap();
+    assert!(hints
+        .extra_manifest_entries
+        .iter()
+        .all(|e| !e.href.contains("masterpage")));
+}
+
 #[test]
 fn parse_all_fixtures_no_error() {
     let fixtures = [
@@ -0,0 +1,536 @@
+use hwp_model::document::Document;
+use hwp_model::paragraph::{Paragraph, Run, RunContent, TextContent, TextElement};
+use hwp_model::section::{MasterPage, Section, SectionDef};
+use hwp_model::types::PageApplyType;
+use hwpx_parser::{HwpxParser, HwpxWriter};
+use std::fs;
+use std::io::Read;
+
+fn fixture(name: &str) -> Vec<u8> {
+    let path = format!(
+        "{}/crates/hwp-core/tests/fixtures/{}",
+        env!("CARGO_MANIFEST_DIR").replace("/crates/hwpx-parser", ""),
+        name
+    );
+    fs::read(&path).unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e))
+}
+
+fn fixture_names() -> Vec<String> {
+    let dir = format!("{}/../hwp-core/tests/fixtures", env!("CARGO_MANIFEST_DIR"));
+    let mut names: Vec<String> = fs::read_dir(&dir)
+        .unwrap()
+        .filter_map(|e| e.ok())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .filter(|n| n.ends_with(".hwpx"))
+        .collect();
+    names.sort();
+    names
+}
+
+/// 모델 비교용 직렬화 (Debug 출력)
+fn model_dump(doc: &Document) -> String {
+    format!(
+        "{:#?}\n{:#?}\n{:#?}\n{:#?}",
+        doc.meta, doc.settings, doc.resources, doc.sections
+    )
+}
+
+fn zip_entry(data: &[u8], name: &str) -> String {
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(data)).unwrap();
+    let mut file = archive.by_name(name).unwrap();
+    let mut s = String::new();
+    file.read_to_string(&mut s).unwrap();
+    s
+}
+
+// ═══════════════════════════════════════════
+// roundtrip
+// ═══════════════════════════════════════════
+
+#[test]
+fn roundtrip_all_fixtures() {
+    let mut checked = 0;
+    for name in fixture_names() {
+        // 암호화 문서 등 파싱되지 않는 fixture는 제외
+        let Ok(doc) = HwpxParser::parse(&fixture(&name)) else {
+            continue;
+        };
+        let written = HwpxWriter::write(&doc).unwrap_or_else(|e| panic!("{}: {}", name, e));
+        let reparsed = HwpxParser::parse(&written).unwrap_or_else(|e| panic!("{}: {}", name, e));
+
+        assert_eq!(
+            model_dump(&doc),
+            model_dump(&reparsed),
+            "{} roundtrip mismatch",
+            name
+        );
+        checked += 1;
+    }
+    assert!(checked > 0);
+}
+
+#[test]
+fn roundtrip_binaries() {
+    let doc = HwpxParser::parse(&fixture("sample-5017-pics.hwpx")).unwrap();
+    assert!(!doc.binaries.items.is_empty());
+
+    let reparsed = HwpxParser::parse(&HwpxWriter::write(&doc).unwrap()).unwrap();
+    assert_eq!(doc.binaries.items.len(), reparsed.binaries.items.len());
+    for (a, b) in doc.binaries.items.iter().zip(&reparsed.binaries.items) {
+        assert_eq!(a.id, b.id);
+        assert_eq!(a.format, b.format);
+        assert_eq!(a.data, b.data);
+    }
+}
+
+#[test]
+fn roundtrip_version_hints() {
+    let doc = HwpxParser::parse(&fixture("example.hwpx")).unwrap();
+    let reparsed = HwpxParser::parse(&HwpxWriter::write(&doc).unwrap()).unwrap();
+
+    let a = doc.hwpx_hints.as_ref().unwrap();
+    let b = reparsed.hwpx_hints.as_ref().unwrap();
+    assert_eq!(a.xml_version, b.xml_version);
+    assert_eq!(a.app_version, b.app_version);
+    assert!(b
+        .extra_manifest_entries
+        .iter()
+        .any(|e| e.href == "settings.xml"));
+}
+
+/// fixture에 모델로 읽지 않는 파트(manifest 항목 포함)를 하나 더 넣은 패키지
+fn with_custom_part(data: &[u8], href: &str, content: &[u8]) -> Vec<u8> {
+    use std::io::Write;
+
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(data)).unwrap();
+    let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default();
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).unwrap();
+        let name = file.name().to_string();
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes).unwrap();
+        if name == "Contents/content.hpf" {
+            let item = format!(
+                r#"<opf:item id="custom" href="{}" media-type="application/xml"/></opf:manifest>"#,
+                href
+            );
+            bytes = String::from_utf8(bytes)
+                .unwrap()
+                .replace("</opf:manifest>", &item)
+                .into_bytes();
+        }
+        writer.start_file(name, options).unwrap();
+        writer.write_all(&bytes).unwrap();
+    }
+    writer.start_file(href, options).unwrap();
+    writer.write_all(content).unwrap();
+    writer.finish().unwrap().into_inner()
+}
+
+#[test]
+fn roundtrip_custom_manifest_part() {
+    let content = br#"<?xml version="1.0" encoding="UTF-8"?><custom>data</custom>"#;
+    let source = with_custom_part(&fixture("example.hwpx"), "Custom/data.xml", content);
+
+    let doc = HwpxParser::parse(&source).unwrap();
+    let written = HwpxWriter::write(&doc).unwrap();
+    assert_eq!(zip_entry(&written, "Custom/data.xml").as_bytes(), content);
+    let hpf = zip_entry(&written, "Contents/content.hpf");
+    assert!(hpf.contains(r#"<opf:item id="custom" href="Custom/data.xml""#));
+    assert!(hpf.contains(r#"href="settings.xml""#));
+
+    // 다시 읽고 써도 남는다
+    let rewritten = HwpxWriter::write(&HwpxParser::parse(&written).unwrap()).unwrap();
+    assert_eq!(zip_entry(&rewritten, "Custom/data.xml").as_bytes(), content);
+}
+
+// ═══════════════════════════════════════════
+// 패키지 구조
+// ═══════════════════════════════════════════
+
+#[test]
+fn write_mimetype_first_and_stored() {
+    let doc = HwpxParser::parse(&fixture("example.hwpx")).unwrap();
+    let written = HwpxWriter::write(&doc).unwrap();
+
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(&written)).unwrap();
+    let mut first = archive.by_index(0).unwrap();
+    assert_eq!(first.name(), "mimetype");
+    assert_eq!(first.compression(), zip::CompressionMethod::Stored);
+    let mut s = String::new();
+    first.read_to_string(&mut s).unwrap();
+    assert_eq!(s, "application/hwp+zip");
+}
+
+#[test]
+fn write_package_entries() {
+    let doc = HwpxParser::parse(&fixture("example.hwpx")).unwrap();
+    let written = HwpxWriter::write(&doc).unwrap();
+
+    let hpf = zip_entry(&written, "Contents/content.hpf");
+    assert!(hpf.contains(r#"href="Contents/header.xml""#));
+    assert!(hpf.contains(r#"href="Contents/section0.xml""#));
+    assert!(hpf.contains(r#"<opf:itemref idref="section0"/>"#));
+
+    let container = zip_entry(&written, "META-INF/container.xml");
+    assert!(container.contains("Contents/content.hpf"));
+
+    let version = zip_entry(&written, "version.xml");
+    assert!(version.contains("HCFVersion"));
+}
+
+#[test]
+fn write_document_from_scratch() {
+    let mut doc = Document::default();
+    doc.meta.title = Some("새 문서".into());
+    doc.sections.push(Section {
+        paragraphs: vec![Paragraph {
+            runs: vec![Run {
+                char_shape_id: 0,
+                contents: vec![RunContent::Text(TextContent {
+                    char_shape_id: None,
+                    elements: vec![TextElement::Text("A & B <C>".into())],
+                })],
+            }],
+            ..Default::default()
+        }],
+        ..Default::default()
+    });
+
+    let reparsed = HwpxParser::parse(&HwpxWriter::write(&doc).unwrap()).unwrap();
+    assert_eq!(reparsed.meta.title.as_deref(), Some("새 문서"));
+    assert_eq!(reparsed.sections.len(), 1);
+
+    let para = &reparsed.sections[0].paragraphs[0];
+    let text: String = para
+        .runs
+        .iter()
+        .flat_map(|r| &r.contents)
+        .filter_map(|c| match c {
+            RunContent::Text(tc) => Some(tc),
+            _ => None,
+        })
+        .flat_map(|tc| &tc.elements)
+        .filter_map(|e| match e {
+            TextElement::Text(s) => Some(s.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(text, "A & B <C>");
+}
+
+#[test]
+fn write_master_pages() {
+    let text_para = |s: &str| Paragraph {
+        runs: vec![Run {
+            char_shape_id: 0,
+            contents: vec![RunContent::Text(TextContent {
+                char_shape_id: None,
+                elements: vec![TextElement::Text(s.into())],
+            })],
+        }],
+        ..Default::default()
+    };
+    let master = |apply_page_type, page_number, text: &str| MasterPage {
+        apply_page_type,
+        page_number,
+        content: hwp_model::paragraph::SubList {
+            paragraphs: vec![text_para(text)],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let mut doc = Document::default();
+    for masters in [
+        vec![master(PageApplyType::Both, 0, "양쪽")],
+        vec![
+            master(PageApplyType::Odd, 0, "홀수쪽"),
+            master(PageApplyType::First, 3, "셋째 쪽"),
+        ],
+    ] {
+        doc.sections.push(Section {
+            definition: SectionDef {
+                master_pages: masters,
+                ..Default::default()
+            },
+            paragraphs: vec![text_para("본문")],
+        });
+    }
+
+    let written = HwpxWriter::write(&doc).unwrap();
+    assert!(zip_entry(&written, "Contents/section1.xml").contains(r#"idRef="masterpage2""#));
+    assert!(zip_entry(&written, "Contents/masterpage2.xml").contains(r#"type="OPTIONAL_PAGE""#));
+    assert!(zip_entry(&written, "Contents/content.hpf").contains("Contents/masterpage2.xml"));
+
+    let reparsed = HwpxParser::parse(&written).unwrap();
+    let masters = &reparsed.sections[1].definition.master_pages;
+    assert_eq!(masters.len(), 2);
+    assert_eq!(masters[0].apply_page_type, PageApplyType::Odd);
+    assert_eq!(masters[1].apply_page_type, PageApplyType::First);
+    assert_eq!(masters[1].page_number, 3);
+    assert_eq!(
+        format!("{:?}", masters[1].content.paragraphs[0].runs),
+        format!("{:?}", text_para("셋째 쪽").runs)
+    );
+}
+
+#[test]
+fn write_and_read_chart_parts() {
+    use hwp_model::chart::{Chart, ChartSeries, ChartType};
+    use hwp_model::shape::{ChartObject, ShapeObject};
+
+    let chart = Chart {
+        chart_type: ChartType::Line,
+        title: Some("월별 방문자".into()),
+        categories: vec!["1월".into(), "2월".into(), "3월".into()],
+        series: vec![ChartSeries {
+            name: Some("방문자".into()),
+            values: vec![Some(120.0), Some(98.5), Some(143.0)],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let mut doc = Document::default();
+    doc.sections.push(Section {
+        paragraphs: vec![Paragraph {
+            runs: vec![Run {
+                char_shape_id: 0,
+                contents: vec![RunContent::Object(ShapeObject::Chart(Box::new(
+                    ChartObject {
+                        chart_id_ref: Some("Chart/chart1.xml".into()),
+                        chart: Some(chart.clone()),
+                        ..Default::default()
+                    },
+                )))],
+            }],
+            ..Default::default()
+        }],
+        ..Default::default()
+    });
+
+    let written = HwpxWriter::write(&doc).unwrap();
+    assert!(
+        zip_entry(&written, "Contents/section0.xml").contains(r#"chartIDRef="Chart/chart1.xml""#)
+    );
+    assert!(zip_entry(&written, "Chart/chart1.xml").contains("<c:lineChart>"));
+
+    let reparsed = HwpxParser::parse(&written).unwrap();
+    let RunContent::Object(ShapeObject::Chart(read)) =
+        &reparsed.sections[0].paragraphs[0].runs[0].contents[0]
+    else {
+        panic!("chart object expected");
+    };
+    assert_eq!(read.chart.as_ref(), Some(&chart));
+}
+
+#[test]
+fn write_and_read_track_changes() {
+    use hwp_model::resources::{TrackChange, TrackChangeAuthor, TrackChangeType};
+
+    let mut doc = Document::default();
+    doc.resources.track_changes.push(TrackChange {
+        id: 1,
+        change_type: TrackChangeType::Delete,
+        date: Some("2024-03-01 10:20:00".into()),
+        author_id: 1,
+        ..Default::default()
+    });
+    doc.resources.track_change_authors.push(TrackChangeAuthor {
+        id: 1,
+        name: "홍길동".into(),
+        mark: true,
+        color: Some(0x00FF_8000),
+    });
+    doc.sections.push(Section {
+        paragraphs: vec![Paragraph {
+            runs: vec![Run {
+                char_shape_id: 0,
+                contents: vec![RunContent::Text(TextContent {
+                    char_shape_id: None,
+                    elements: vec![
+                        TextElement::Text("가".into()),
+                        TextElement::DeleteBegin {
+                            id: "1".into(),
+                            tc_id: Some("1".into()),
+                            para_end: false,
+                        },
+                        TextElement::Text("나".into()),
+                        TextElement::DeleteEnd {
+                            id: "1".into(),
+                            tc_id: Some("1".into()),
+                            para_end: false,
+                        },
+                    ],
+                })],
+            }],
+            ..Default::default()
+        }],
+        ..Default::default()
+    });
+
+    let reparsed = HwpxParser::parse(&HwpxWriter::write(&doc).unwrap()).unwrap();
+    let resources = &reparsed.resources;
+    assert_eq!(resources.track_changes.len(), 1);
+    assert_eq!(
+        resources.track_changes[0].change_type,
+        TrackChangeType::Delete
+    );
+    assert_eq!(
+        resources.track_changes[0].date.as_deref(),
+        Some("2024-03-01 10:20:00")
+    );
+    let author = resources.revision_author(Some("1")).unwrap();
+    assert_eq!(author.name, "홍길동");
+    assert_eq!(author.color, Some(0x00FF_8000));
+
+    let revisions = reparsed.revisions();
+    assert_eq!(revisions.len(), 1);
+    assert_eq!(revisions[0].text, "나");
+    assert_eq!(revisions[0].author.as_deref(), Some("홍길동"));
+}
+
+#[test]
+fn write_and_read_memos() {
+    use hwp_model::control::Control;
+    use hwp_model::memo::Memo;
+    use hwp_model::resources::MemoShape;
+
+    let text = |s: &str| {
+        RunContent::Text(TextContent {
+            char_shape_id: None,
+            elements: vec![TextElement::Text(s.into())],
+        })
+    };
+    let memo = Memo {
+        id: Some("0".into()),
+        number: Some(1),
+        author: Some("작성자".into()),
+        date: Some("2024-05-02 09:30:00".into()),
+        memo_shape_id: Some(0),
+        content: vec![Paragraph {
+            runs: vec![Run {
+                char_shape_id: 0,
+                contents: vec![text("메모 내용")],
+            }],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let mut doc = Document::default();
+    doc.resources.memo_shapes.push(MemoShape {
+        id: 0,
+        width: 15591,
+        line_color: Some(0x00B6_D7AE),
+        fill_color: Some(0x00F0_FFE9),
+        ..Default::default()
+    });
+    doc.sections.push(Section {
+        paragraphs: vec![Paragraph {
+            runs: vec![Run {
+                char_shape_id: 0,
+                contents: vec![
+                    RunContent::Control(Control::FieldBegin(memo.to_field(7))),
+                    text("대상"),
+                    RunContent::Control(Control::FieldEnd),
+                ],
+            }],
+            ..Default::default()
+        }],
+        ..Default::default()
+    });
+
+    let reparsed = HwpxParser::parse(&HwpxWriter::write(&doc).unwrap()).unwrap();
+    let shape = &reparsed.resources.memo_shapes[0];
+    assert_eq!(shape.width, 15591);
+    assert_eq!(shape.fill_color, Some(0x00F0_FFE9));
+
+    let memos = reparsed.memos();
+    assert_eq!(memos.len(), 1);
+    assert_eq!(memos[0].number, Some(1));
+    assert_eq!(memos[0].author.as_deref(), Some("작성자"));
+    assert_eq!(memos[0].memo_shape_id, Some(0));
+    assert_eq!(memos[0].anchor_text, "대상");
+    assert_eq!(memos[0].content.len(), 1);
+}
+
+#[test]
+fn write_and_read_form_objects() {
+    use hwp_model::shape::{FormCheckState, FormListItem, FormObject, FormType, ShapeObject};
+
+    let objects = vec![
+        FormObject {
+            form_type: FormType::Edit,
+            name: "주소".into(),
+            value: Some("서울특별시 종로구".into()),
+            multi_line: true,
+            max_length: Some(100),
+            enabled: true,
+            char_shape_id: Some(0),
+            ..Default::default()
+        },
+        FormObject {
+            form_type: FormType::RadioButton,
+            name: "우편".into(),
+            caption: Some("우편 수령".into()),
+            group_name: Some("수령방법".into()),
+            check_state: FormCheckState::Checked,
+            back_color: Some(0x00FF_EEDD),
+            enabled: true,
+            ..Default::default()
+        },
+        FormObject {
+            form_type: FormType::ListBox,
+            name: "민원".into(),
+            value: Some("b".into()),
+            items: vec![
+                FormListItem {
+                    display_text: "가".into(),
+                    value: "a".into(),
+                },
+                FormListItem {
+                    display_text: "나".into(),
+                    value: "b".into(),
+                },
+            ],
+            ..Default::default()
+        },
+    ];
+    let mut doc = Document::default();
+    doc.sections.push(Section {
+        paragraphs: vec![Paragraph {
+            runs: vec![Run {
+                char_shape_id: 0,
+                contents: objects
+                    .into_iter()
+                    .map(|f| RunContent::Object(ShapeObject::Form(Box::new(f))))
+                    .collect(),
+            }],
+            ..Default::default()
+        }],
+        ..Default::default()
+    });
+
+    let reparsed = HwpxParser::parse(&HwpxWriter::write(&doc).unwrap()).unwrap();
+    let forms = reparsed.form_objects();
+    assert_eq!(forms.len(), 3);
+
+    assert_eq!(forms[0].form_type, FormType::Edit);
+    assert_eq!(forms[0].value.as_deref(), Some("서울특별시 종로구"));
+    assert!(forms[0].multi_line);
+    assert_eq!(forms[0].max_length, Some(100));
+    assert_eq!(forms[0].char_shape_id, Some(0));
+
+    assert_eq!(forms[1].form_type, FormType::RadioButton);
+    assert_eq!(forms[1].caption.as_deref(), Some("우편 수령"));
+    assert_eq!(forms[1].group_name.as_deref(), Some("수령방법"));
+    assert_eq!(forms[1].check_state, FormCheckState::Checked);
+    assert_eq!(forms[1].back_color, Some(0x00FF_EEDD));
+
+    assert_eq!(forms[2].form_type, FormType::ListBox);
+    assert!(!forms[2].enabled);
+    assert_eq!(forms[2].items.len(), 2);
+    assert_eq!(forms[2].items[1].display_text, "나");
+    assert_eq!(reparsed.form_values()["민원"], "b");
+}
@@ -0,0 +1,57 @@
+import { Command } from 'commander';
+import { readFileSync, writeFileSync } from 'fs';
+import { resolve, relative, isAbsolute } from 'path';
+// CLI는 빌드된 NAPI 모듈을 사용합니다
+// @ts-ignore - 런타임에 dist/index.js에서 로드됨 (빌드 후 경로: ../../index)
+const { toDocx } = require('../../index');
+
+/** 출력 경로가 cwd 하위인지 검사. path traversal 방지. */
+function isOutputUnderCwd(outputPath: string): boolean {
+  const cwd = process.cwd();
+  const rel = relative(cwd, outputPath);
+  return rel !== '' && !rel.startsWith('..') && !isAbsolute(rel);
+}
+
+export function toDocxCommand(program: Command) {
+  program
+    .command('to-docx')
+    .description('Convert HWP file to DOCX')
+    .argument('<input>', 'Input HWP file path')
+    .option(
+      '-o, --output <file>',
+      'Output DOCX file path (required, must be under current directory)'
+    )
+    .option('--no-embed-images', 'Do not embed images in DOCX')
+    .action(
+      (
+        input: string,
+        options: {
+          output?: string;
+          embedImages?: boolean;
+        }
+      ) => {
+        try {
+          if (!options.output) {
+            console.error('Error: -o, --output <file> is required for to-docx');
+            process.exit(1);
+          }
+          const outputPath = resolve(options.output);
+          if (!isOutputUnderCwd(outputPath)) {
+            console.error(
+              'Error: --output path must be under the current directory (path traversal not allowed)'
+            );
+            process.exit(1);
+          }
+          const data = readFileSync(input);
+          const docx = toDocx(data, {
+            embedImages: options.embedImages,
+          });
+          writeFileSync(outputPath, docx);
+          console.log(`✓ Converted to DOCX: ${outputPath}`);
+        } catch (error) {
+          console.error('Error:', error instanceof Error ? error.message : String(error));
+          process.exit(1);
+        }
+      }
+    );
+}
@@ -65,8 +65,8 @@ export function toPdfCommand(program: Command) {
           const data = readFileSync(input);
           const fontDir = getDefaultFontDir(options.fontDir);
           const pdf = toPdf(data, {
-            font_dir: fontDir,
-            embed_images: options.embedImages,
+            fontDir,
+            embedImages: options.embedImages,
           });
           writeFileSync(outputPath, pdf);
           console.log(`✓ Converted to PDF: ${outputPath}`);
@@ -6,8 +6,8 @@ import { Command } from 'commander';
 import { toJsonCommand } from './commands/to-json';
 import { toMarkdownCommand } from './commands/to-markdown';
 import { toHtmlCommand } from './commands/to-html';
-// to-pdf 비활성화 (확장만 해둠)
-// import { toPdfCommand } from './commands/to-pdf';
+import { toPdfCommand } from './commands/to-pdf';
+import { toDocxCommand } from './commands/to-docx';
 import { infoCommand } from './commands/info';
 import { extractImagesCommand } from './commands/extract-images';
 import { batchCommand } from './commands/batch';
@@ -23,7 +23,8 @@ program.name('hwpjs').description('HWP/HWPX file parser CLI').version(version);
 toJsonCommand(program);
 toMarkdownCommand(program);
 toHtmlCommand(program);
-// toPdfCommand(program);
+toPdfCommand(program);
+toDocxCommand(program);
 infoCommand(program);
 extractImagesCommand(program);
 batchCommand(program);
@@ -53,6 +53,15 @@ pub struct ToMarkdownOptions {
     /// Whether to include page information
     /// 페이지 정보 포함 여부
     pub include_page_info: Option<bool>,
+    /// Track changes view: 'final' (apply changes), 'original' (reject changes), 'markup' (show insertions/deletions, default)
+    /// 변경 추적 보기: 'final'(최종본), 'original'(원본), 'markup'(변경 표시, 기본값)
+    pub revision_view: Option<String>,
+    /// Recompute formula, summary and cross-reference field results (default: true)
+    /// 계산식/문서 요약/상호 참조 필드 결과를 다시 계산할지 여부 (기본값: true)
+    pub evaluate_fields: Option<bool>,
+    /// Emit a linked table of contents built from outline headings (default: false)
+    /// 개요 제목으로 만든 차례(링크 포함)를 본문 앞에 넣을지 여부 (기본값: false)
+    pub table_of_contents: Option<bool>,
 }
 
 /// Image data structure
@@ -237,6 +246,25 @@ pub struct ToHtmlOptions {
     /// Layout mode: true for pixel-accurate layout HTML (hpa/hls/hcD), false for semantic HTML (default)
     /// 레이아웃 모드: true이면 pixel-accurate 레이아웃, false이면 시맨틱 HTML (기본값)
     pub layout: Option<bool>,
+    /// Keep WMF/EMF images as-is instead of converting them to SVG (default: false)
+    /// WMF/EMF 그림을 원본 그대로 유지할지 여부 (기본값: false - SVG로 변환)
+    pub keep_metafile: Option<bool>,
+    /// Track changes view: 'final' (apply changes), 'original' (reject changes), 'markup' (show insertions/deletions with author colors, default)
+    /// 변경 추적 보기: 'final'(최종본), 'original'(원본), 'markup'(작성자 색으로 삽입/삭제 표시, 기본값)
+    pub revision_view: Option<String>,
+    /// Recompute formula, summary and cross-reference field results (default: true)
+    /// 계산식/문서 요약/상호 참조 필드 결과를 다시 계산할지 여부 (기본값: true)
+    pub evaluate_fields: Option<bool>,
+    /// Emit a linked table of contents built from outline headings (default: false)
+    /// 개요 제목으로 만든 차례(링크 포함)를 본문 앞에 넣을지 여부 (기본값: false)
+    pub table_of_contents: Option<bool>,
+}
+
+/// 'final' / 'original' / 'markup' → RevisionView (잘못된 값은 기본값 markup)
+fn revision_view(value: Option<&String>) -> hwp_core::viewer::RevisionView {
+    value
+        .and_then(|s| hwp_core::viewer::RevisionView::parse(s))
+        .unwrap_or_default()
 }
 
 /// Convert HWP file to HTML format
@@ -264,6 +292,11 @@ pub fn to_html(data: Buffer, options: Option<ToHtmlOptions>) -> Result<String, n
             .and_then(|o| o.css_class_prefix.clone())
             .unwrap_or_default(),
         layout: options.as_ref().and_then(|o| o.layout).unwrap_or(false),
+        keep_metafile: options
+            .as_ref()
+            .and_then(|o| o.keep_metafile)
+            .unwrap_or(false),
+        revision_view: revision_view(options.as_ref().and_then(|o| o.revision_view.as_ref())),
     };
 
     // Convert to HTML
@@ -329,6 +362,19 @@ pub fn hwpx_to_html(data: Buffer, options: Option<ToHtmlOptions>) -> Result<Stri
         inline_style: true,
         image_output_dir: options.as_ref().and_then(|o| o.image_output_dir.clone()),
         layout: options.as_ref().and_then(|o| o.layout).unwrap_or(false),
+        keep_metafile: options
+            .as_ref()
+            .and_then(|o| o.keep_metafile)
+            .unwrap_or(false),
+        revision_view: revision_view(options.as_ref().and_then(|o| o.revision_view.as_ref())),
+        evaluate_fields: options
+            .as_ref()
+            .and_then(|o| o.evaluate_fields)
+            .unwrap_or(true),
+        table_of_contents: options
+            .as_ref()
+            .and_then(|o| o.table_of_contents)
+            .unwrap_or(false),
     };
 
     let html = hwp_core::viewer::doc_to_html(&document, &html_options);
@@ -357,6 +403,15 @@ pub fn hwpx_to_markdown(
         use_html: options.as_ref().and_then(|o| o.use_html).unwrap_or(false),
         include_version: options.as_ref().and_then(|o| o.include_version),
         include_page_info: options.as_ref().and_then(|o| o.include_page_info),
+        revision_view: revision_view(options.as_ref().and_then(|o| o.revision_view.as_ref())),
+        evaluate_fields: options
+            .as_ref()
+            .and_then(|o| o.evaluate_fields)
+            .unwrap_or(true),
+        table_of_contents: options
+            .as_ref()
+            .and_then(|o| o.table_of_contents)
+            .unwrap_or(false),
     };
 
     let markdown = hwp_core::viewer::doc_to_markdown(&document, &md_options);
@@ -365,12 +420,16 @@ pub fn hwpx_to_markdown(
 
 // ==================== 자동 감지 통합 API ====================
 
-/// 파일 형식 감지: HWP(CFB/OLE) vs HWPX(ZIP)
+/// 파일 형식 감지: HWP(CFB/OLE) vs HWPX(ZIP) vs HWP 3.x vs HWPML(XML)
 fn detect_format(data: &[u8]) -> &'static str {
     if data.len() >= 8 && data[0..8] == [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB4, 0x1A, 0xE1] {
         "hwp"
     } else if data.len() >= 4 && data[0..4] == [0x50, 0x4B, 0x03, 0x04] {
         "hwpx"
+    } else if hwp_core::hwp3::is_hwp3(data) {
+        "hwp3"
+    } else if hwp_core::hml::is_hml(data) {
+        "hml"
     } else {
         "unknown"
     }
@@ -386,16 +445,20 @@ fn parse_to_document(data: &[u8]) -> Result<hwp_model::document::Document, napi:
         }
         "hwpx" => hwpx_parser::HwpxParser::parse(data)
             .map_err(|e| napi::Error::from_reason(format!("Failed to parse HWPX: {}", e))),
+        "hwp3" => hwp_core::hwp3::parse_hwp3(data)
+            .map_err(|e| napi::Error::from_reason(format!("Failed to parse HWP 3.x: {}", e))),
+        "hml" => hwp_core::hml::parse_hml(data)
+            .map_err(|e| napi::Error::from_reason(format!("Failed to parse HWPML: {}", e))),
         _ => Err(napi::Error::from_reason(
-            "Unknown file format: expected HWP (CFB) or HWPX (ZIP)",
+            "Unknown file format: expected HWP (CFB), HWPX (ZIP), HWP 3.x or HWPML (XML)",
         )),
     }
 }
 
-/// Detect file format (HWP or HWPX)
+/// Detect file format (HWP, HWPX, HWP 3.x or HWPML)
 ///
 /// # Returns
-/// "hwp", "hwpx", or "unknown"
+/// "hwp", "hwpx", "hwp3", "hml", or "unknown"
 #[napi]
 pub fn detect(data: Buffer) -> String {
     let data_vec: Vec<u8> = data.into();
@@ -420,8 +483,20 @@ pub fn parse(data: Buffer) -> Result<String, napi::Error> {
             serde_json::to_string(&document)
                 .map_err(|e| napi::Error::from_reason(format!("Failed to serialize: {}", e)))
         }
+        "hwp3" => {
+            let document = hwp_core::hwp3::parse_hwp3(&data_vec)
+                .map_err(|e| napi::Error::from_reason(format!("Failed to parse HWP 3.x: {}", e)))?;
+            serde_json::to_string(&document)
+                .map_err(|e| napi::Error::from_reason(format!("Failed to serialize: {}", e)))
+        }
+        "hml" => {
+            let document = hwp_core::hml::parse_hml(&data_vec)
+                .map_err(|e| napi::Error::from_reason(format!("Failed to parse HWPML: {}", e)))?;
+            serde_json::to_string(&document)
+                .map_err(|e| napi::Error::from_reason(format!("Failed to serialize: {}", e)))
+        }
         _ => Err(napi::Error::from_reason(
-            "Unknown file format: expected HWP (CFB) or HWPX (ZIP)",
+            "Unknown file format: expected HWP (CFB), HWPX (ZIP), HWP 3.x or HWPML (XML)",
         )),
     }
 }
@@ -442,10 +517,215 @@ pub fn convert_to_html(
         inline_style: true,
         image_output_dir: options.as_ref().and_then(|o| o.image_output_dir.clone()),
         layout: options.as_ref().and_then(|o| o.layout).unwrap_or(false),
+        keep_metafile: options
+            .as_ref()
+            .and_then(|o| o.keep_metafile)
+            .unwrap_or(false),
+        revision_view: revision_view(options.as_ref().and_then(|o| o.revision_view.as_ref())),
+        evaluate_fields: options
+            .as_ref()
+            .and_then(|o| o.evaluate_fields)
+            .unwrap_or(true),
+        table_of_contents: options
+            .as_ref()
+            .and_then(|o| o.table_of_contents)
+            .unwrap_or(false),
     };
     Ok(hwp_core::viewer::doc_to_html(&document, &doc_options))
 }
 
+/// Convert HWP or HWPX file to a canvas display list (auto-detect format)
+///
+/// # Returns
+/// JSON array of pages (`{ width, height, commands }`, mm 단위). `drawImage`의 `binaryId`는
+/// 문서 BinData id이며 이미지 바이트는 별도로 얻어야 합니다.
+#[napi]
+pub fn convert_to_canvas(data: Buffer) -> Result<String, napi::Error> {
+    let data_vec: Vec<u8> = data.into();
+    let document = parse_to_document(&data_vec)?;
+    let pages = hwp_core::viewer::doc_to_canvas(&document);
+    serde_json::to_string(&pages)
+        .map_err(|e| napi::Error::from_reason(format!("Failed to serialize: {}", e)))
+}
+
+/// SVG conversion options
+#[napi(object)]
+pub struct ToSvgOptions {
+    /// Optional directory path to save images as files. If None, images are embedded as base64 data URIs.
+    /// 이미지를 파일로 저장할 디렉토리 경로 (선택). None이면 base64 데이터 URI로 임베드됩니다.
+    pub image_output_dir: Option<String>,
+    /// Whether to include images (default: true). If false, images are drawn as placeholder boxes.
+    /// 이미지 포함 여부 (기본값: true). false이면 자리 표시 상자로 그립니다.
+    pub embed_images: Option<bool>,
+    /// Whether to paint a white paper background (default: true)
+    /// 흰색 종이 배경 여부 (기본값: true)
+    pub background: Option<bool>,
+}
+
+/// Convert HWP or HWPX file to one standalone SVG per page (auto-detect format)
+///
+/// # Returns
+/// SVG strings in page order
+#[napi]
+pub fn convert_to_svg(
+    data: Buffer,
+    options: Option<ToSvgOptions>,
+) -> Result<Vec<String>, napi::Error> {
+    let data_vec: Vec<u8> = data.into();
+    let document = parse_to_document(&data_vec)?;
+    let svg_options = hwp_core::viewer::SvgOptions {
+        image_output_dir: options.as_ref().and_then(|o| o.image_output_dir.clone()),
+        embed_images: options
+            .as_ref()
+            .and_then(|o| o.embed_images)
+            .unwrap_or(true),
+        background: options.as_ref().and_then(|o| o.background).unwrap_or(true),
+    };
+    Ok(hwp_core::viewer::doc_to_svg_pages(&document, &svg_options))
+}
+
+/// PDF conversion options
+#[napi(object)]
+pub struct ToPdfOptions {
+    /// Directory containing TTF/TTC/OTF fonts to embed. If None, text uses the standard Helvetica font (ASCII only).
+    /// 임베드할 TTF/TTC/OTF 글꼴 디렉터리 (선택). None이면 표준 Helvetica(ASCII만)로 출력합니다.
+    pub font_dir: Option<String>,
+    /// Whether to embed images (default: true). If false, images are drawn as placeholder boxes.
+    /// 이미지 임베드 여부 (기본값: true). false이면 자리 표시 상자로 그립니다.
+    pub embed_images: Option<bool>,
+}
+
+/// Convert HWP or HWPX file to PDF (auto-detect format)
+///
+/// # Returns
+/// PDF file bytes
+#[napi]
+pub fn to_pdf(data: Buffer, options: Option<ToPdfOptions>) -> Result<Buffer, napi::Error> {
+    let data_vec: Vec<u8> = data.into();
+    let document = parse_to_document(&data_vec)?;
+    let pdf_options = hwp_core::viewer::PdfOptions {
+        font_dir: options
+            .as_ref()
+            .and_then(|o| o.font_dir.clone())
+            .map(std::path::PathBuf::from),
+        embed_images: options
+            .as_ref()
+            .and_then(|o| o.embed_images)
+            .unwrap_or(true),
+    };
+    Ok(hwp_core::viewer::to_pdf(&document, &pdf_options).into())
+}
+
+/// DOCX conversion options
+#[napi(object)]
+pub struct ToDocxOptions {
+    /// Whether to embed images (default: true). If false, pictures are left out.
+    /// 이미지 임베드 여부 (기본값: true). false이면 그림을 넣지 않습니다.
+    pub embed_images: Option<bool>,
+}
+
+/// Convert HWP or HWPX file to DOCX (auto-detect format)
+///
+/// # Returns
+/// DOCX file bytes
+#[napi]
+pub fn to_docx(data: Buffer, options: Option<ToDocxOptions>) -> Result<Buffer, napi::Error> {
+    let data_vec: Vec<u8> = data.into();
+    let document = parse_to_document(&data_vec)?;
+    let docx_options = hwp_core::viewer::DocxOptions {
+        embed_images: options
+            .as_ref()
+            .and_then(|o| o.embed_images)
+            .unwrap_or(true),
+    };
+    hwp_core::viewer::to_docx(&document, &docx_options)
+        .map(Buffer::from)
+        .map_err(|e| napi::Error::from_reason(format!("Failed to write DOCX: {}", e)))
+}
+
+/// Embedded OLE object data
+/// 문서에 포함된 OLE 개체 데이터
+#[napi(object)]
+pub struct OleObjectData {
+    /// BinData id (e.g., "BIN0001")
+    /// BinData id (예: "BIN0001")
+    pub binary_id: String,
+    /// Root storage CLSID (e.g., "{00020820-0000-0000-C000-000000000046}")
+    /// 루트 스토리지 CLSID
+    pub clsid: Option<String>,
+    /// ProgID (e.g., "Excel.Sheet.12")
+    pub prog_id: Option<String>,
+    /// User type name (e.g., "Microsoft Excel Worksheet")
+    /// 사용자 표시 형식 이름
+    pub user_type: Option<String>,
+    /// Native object bytes (e.g., xlsx/xls/pdf file)
+    /// 원본 개체 데이터 (예: xlsx/xls/pdf 파일)
+    pub native: Option<Buffer>,
+    /// File extension for the native data (e.g., "xlsx")
+    /// 원본 데이터 확장자 (예: "xlsx")
+    pub native_extension: Option<String>,
+    /// Cached preview bytes (WMF/EMF/BMP)
+    /// 미리보기 데이터 (WMF/EMF/BMP)
+    pub preview: Option<Buffer>,
+    /// Preview format ("wmf", "emf", "bmp")
+    /// 미리보기 형식 ("wmf", "emf", "bmp")
+    pub preview_format: Option<String>,
+}
+
+/// Extract embedded OLE objects from an HWP or HWPX file (auto-detect format)
+///
+/// # Returns
+/// OLE objects with their CLSID, native data and cached preview
+#[napi]
+pub fn extract_ole_objects(data: Buffer) -> Result<Vec<OleObjectData>, napi::Error> {
+    let data_vec: Vec<u8> = data.into();
+    let document = parse_to_document(&data_vec)?;
+    Ok(hwp_core::ole::extract_document_ole(&document.binaries)
+        .into_iter()
+        .map(|(binary_id, ole)| {
+            let (native, native_extension) = match ole.native {
+                Some(native) => (Some(Buffer::from(native.data)), Some(native.extension)),
+                None => (None, None),
+            };
+            let (preview, preview_format) = match ole.preview {
+                Some(preview) => {
+                    let format = match preview.format {
+                        hwp_model::document::ImageFormat::Wmf => "wmf",
+                        hwp_model::document::ImageFormat::Emf => "emf",
+                        _ => "bmp",
+                    };
+                    (Some(Buffer::from(preview.data)), Some(format.to_string()))
+                }
+                None => (None, None),
+            };
+            OleObjectData {
+                binary_id,
+                clsid: ole.clsid,
+                prog_id: ole.prog_id,
+                user_type: ole.user_type,
+                native,
+                native_extension,
+                preview,
+                preview_format,
+            }
+        })
+        .collect())
+}
+
+/// Read the values of form objects (check boxes, edit boxes, ...) from an HWP or HWPX file
+///
+/// # Returns
+/// JSON object of `name → value`. Check boxes are "on"/"off", radio buttons are keyed by
+/// their group name with the checked button's name as value.
+/// 양식 개체 값 (HTML 양식 제출과 같은 이름 → 값 규칙)
+#[napi]
+pub fn read_form_values(data: Buffer) -> Result<String, napi::Error> {
+    let data_vec: Vec<u8> = data.into();
+    let document = parse_to_document(&data_vec)?;
+    serde_json::to_string(&document.form_values())
+        .map_err(|e| napi::Error::from_reason(format!("Failed to serialize: {}", e)))
+}
+
 /// Convert HWP or HWPX file to Markdown (auto-detect format)
 #[napi]
 pub fn convert_to_markdown(
@@ -459,6 +739,15 @@ pub fn convert_to_markdown(
         use_html: options.as_ref().and_then(|o| o.use_html).unwrap_or(false),
         include_version: options.as_ref().and_then(|o| o.include_version),
         include_page_info: options.as_ref().and_then(|o| o.include_page_info),
+        revision_view: revision_view(options.as_ref().and_then(|o| o.revision_view.as_ref())),
+        evaluate_fields: options
+            .as_ref()
+            .and_then(|o| o.evaluate_fields)
+            .unwrap_or(true),
+        table_of_contents: options
+            .as_ref()
+            .and_then(|o| o.table_of_contents)
+            .unwrap_or(false),
     };
     Ok(hwp_core::viewer::doc_to_markdown(&document, &md_options))
 }